@@ -180,9 +180,34 @@ impl Client {
         }
     }
 
+    /// Reloads the client's application instance certificate, private key and trust lists from
+    /// the pki folder. Sessions created from now on use the new certificate and key while existing
+    /// sessions carry on with the ones they connected with. The client also reloads them by itself
+    /// when it creates a session after something in the pki folder has changed.
+    ///
+    /// # Errors
+    ///
+    /// A string description of any failure, in which case the client keeps its current
+    /// certificate and key.
+    ///
+    pub fn reload_certificates(&self) -> Result<(), String> {
+        let mut certificate_store = trace_write_lock_unwrap!(self.certificate_store);
+        certificate_store.reload()
+    }
+
     fn get_client_cert_and_key(&self) -> (Option<X509>, Option<PrivateKey>) {
+        let has_changed = {
+            let certificate_store = trace_read_lock_unwrap!(self.certificate_store);
+            certificate_store.has_changed()
+        };
+        if has_changed {
+            info!("The pki folder has changed so the certificate store will be reloaded");
+            if let Err(err) = self.reload_certificates() {
+                error!("Certificate store could not be reloaded, error = {}", err);
+            }
+        }
         let certificate_store = trace_read_lock_unwrap!(self.certificate_store);
-        if let Ok((cert, key)) = certificate_store.read_own_cert_and_pkey() {
+        if let Ok((cert, key)) = certificate_store.own_cert_and_pkey() {
             (Some(cert), Some(key))
         } else {
             (None, None)
@@ -131,19 +131,29 @@ impl TcpTransport {
         self.stream.is_some()
     }
 
-    /// Sets the security token info received from an issue / renew request
+    /// Sets the security token info received from an issue request
     pub fn set_security_token(&mut self, channel_token: ChannelSecurityToken) {
         trace!("Setting security token {:?}", channel_token);
         let mut secure_channel = trace_write_lock_unwrap!(self.secure_channel);
         secure_channel.set_security_token(channel_token);
     }
 
+    /// Sets the security token info received from a renew request and derives its keys from the
+    /// nonces. Responses secured with the previous token are still accepted until the server
+    /// switches to the new one.
+    pub fn renew_security_token(&mut self, channel_token: ChannelSecurityToken) -> Result<(), StatusCode> {
+        trace!("Renewing security token {:?}", channel_token);
+        let mut secure_channel = trace_write_lock_unwrap!(self.secure_channel);
+        secure_channel.renew_security_token(channel_token)
+    }
+
     /// Test if the secure channel token needs to be renewed. The algorithm determines it needs
-    /// to be renewed if the issue period has elapsed by 75% or more.
+    /// to be renewed if the issue period has elapsed by 75% or more. There is nothing to renew
+    /// if no token has been issued yet.
     pub fn should_renew_security_token(&self) -> bool {
         let secure_channel = trace_read_lock_unwrap!(self.secure_channel);
         if secure_channel.token_id() == 0 {
-            panic!("Shouldn't be asking this question, if there is no token id at all");
+            false
         } else {
             let now = chrono::Utc::now();
 
@@ -8,7 +8,7 @@ use std::sync::{Arc, RwLock};
 use opcua_core::crypto;
 use opcua_core::crypto::{CertificateStore, PrivateKey, SecurityPolicy, X509};
 use opcua_types::*;
-use opcua_types::node_ids::{ObjectId, MethodId};
+use opcua_types::node_ids::{ObjectId, MethodId, ReferenceTypeId};
 use opcua_types::service_types::*;
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
@@ -55,6 +55,7 @@ impl Into<SessionInfo> for (EndpointDescription, client::IdentityToken) {
 
 const DEFAULT_SESSION_TIMEOUT: u32 = 60 * 1000;
 const DEFAULT_REQUEST_TIMEOUT: u32 = 10 * 1000;
+const DEFAULT_SECURE_CHANNEL_LIFETIME: u32 = 60 * 1000;
 const SEND_BUFFER_SIZE: usize = 65536;
 const RECEIVE_BUFFER_SIZE: usize = 65536;
 const MAX_BUFFER_SIZE: usize = 65536;
@@ -67,6 +68,9 @@ pub struct SessionState {
     pub request_timeout: u32,
     /// Session timeout in milliseconds
     pub session_timeout: u32,
+    /// Lifetime in milliseconds requested for secure channel tokens. The token is renewed when
+    /// 75% of the lifetime revised by the server has elapsed.
+    pub secure_channel_lifetime: u32,
     /// Size of the send buffer
     pub send_buffer_size: usize,
     /// Size of the
@@ -84,6 +88,7 @@ impl SessionState {
         SessionState {
             session_timeout: DEFAULT_SESSION_TIMEOUT,
             request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            secure_channel_lifetime: DEFAULT_SECURE_CHANNEL_LIFETIME,
             send_buffer_size: SEND_BUFFER_SIZE,
             receive_buffer_size: RECEIVE_BUFFER_SIZE,
             max_message_size: MAX_BUFFER_SIZE,
@@ -850,6 +855,108 @@ impl Session {
         }
     }
 
+    /// Loads the OPC Binary type dictionaries of the server and the binary encoding ids of their
+    /// structured types into a registry. The registry decodes `ExtensionObject` values of the
+    /// server's custom types into a `DynamicStructure` of field names and values, whether they are
+    /// read with `read_nodes()` or arrive in the data change callback of a subscription, and
+    /// encodes structures into values for `write_value()`.
+    ///
+    /// Dictionaries are found under the OPC Binary type system. A dictionary that has no value or
+    /// cannot be parsed is skipped, so the types of the other dictionaries can still be used.
+    pub fn load_data_types(&mut self) -> Result<DataTypeRegistry, StatusCode> {
+        let mut registry = DataTypeRegistry::new();
+
+        // The dictionaries are components of the type system
+        let type_system_id: NodeId = ObjectId::OPCBinarySchema_TypeSystem.into();
+        let dictionary_ids = self.browse_all(vec![type_system_id], BrowseDirection::Forward, ReferenceTypeId::HasComponent)?
+            .remove(0).into_iter().map(|r| r.node_id.node_id).collect::<Vec<NodeId>>();
+        if dictionary_ids.is_empty() {
+            return Ok(registry);
+        }
+        let values = self.read_values(&dictionary_ids)?;
+
+        let mut dictionaries = Vec::new();
+        for (dictionary_id, value) in dictionary_ids.into_iter().zip(values.into_iter()) {
+            let xml = if let Some(Variant::ByteString(ByteString { value: Some(value) })) = value {
+                String::from_utf8(value).ok()
+            } else {
+                None
+            };
+            match xml.map(|xml| TypeDictionary::parse(&xml)) {
+                Some(Ok(dictionary)) => dictionaries.push((dictionary_id, dictionary)),
+                Some(Err(err)) => warn!("Type dictionary {:?} cannot be parsed, {:?}", dictionary_id, err),
+                None => debug!("Type dictionary {:?} has no value", dictionary_id),
+            }
+        }
+        if dictionaries.is_empty() {
+            return Ok(registry);
+        }
+
+        // Each type in a dictionary has a description variable whose value is the name of the
+        // type, and the description of a structured type is referred to by its binary encoding
+        let dictionary_ids = dictionaries.iter().map(|&(ref id, _)| id.clone()).collect::<Vec<NodeId>>();
+        let descriptions = self.browse_all(dictionary_ids, BrowseDirection::Forward, ReferenceTypeId::HasComponent)?;
+        for ((_, dictionary), descriptions) in dictionaries.into_iter().zip(descriptions.into_iter()) {
+            let description_ids = descriptions.into_iter().map(|r| r.node_id.node_id).collect::<Vec<NodeId>>();
+            if !description_ids.is_empty() {
+                let type_names = self.read_values(&description_ids)?;
+                let encodings = self.browse_all(description_ids, BrowseDirection::Inverse, ReferenceTypeId::HasDescription)?;
+                for (type_name, encodings) in type_names.into_iter().zip(encodings.into_iter()) {
+                    if let Some(Variant::String(type_name)) = type_name {
+                        let type_name = TypeName::new(&dictionary.target_namespace, type_name.as_ref());
+                        encodings.into_iter().for_each(|r| registry.add_encoding(r.node_id.node_id, type_name.clone()));
+                    }
+                }
+            }
+            registry.add_dictionary(dictionary);
+        }
+        Ok(registry)
+    }
+
+    /// Browses the references of a type from each node, following continuation points until
+    /// every reference is returned
+    fn browse_all(&mut self, node_ids: Vec<NodeId>, browse_direction: BrowseDirection, reference_type_id: ReferenceTypeId) -> Result<Vec<Vec<ReferenceDescription>>, StatusCode> {
+        let nodes_to_browse = node_ids.into_iter().map(|node_id| BrowseDescription {
+            node_id,
+            browse_direction,
+            reference_type_id: reference_type_id.into(),
+            include_subtypes: true,
+            node_class_mask: 0,
+            result_mask: 0x3f,
+        }).collect::<Vec<BrowseDescription>>();
+        let count = nodes_to_browse.len();
+        let results = self.browse(nodes_to_browse)?.unwrap_or_default();
+        if results.len() != count {
+            error!("Expecting {} browse results, got {}", count, results.len());
+            return Err(BadUnexpectedError);
+        }
+        let mut references = Vec::with_capacity(count);
+        for mut result in results {
+            let mut node_references = result.references.take().unwrap_or_default();
+            while !result.continuation_point.is_null() {
+                let mut results = self.browse_next(false, vec![result.continuation_point.clone()])?.unwrap_or_default();
+                if results.is_empty() {
+                    break;
+                }
+                result = results.remove(0);
+                node_references.extend(result.references.take().unwrap_or_default());
+            }
+            references.push(node_references);
+        }
+        Ok(references)
+    }
+
+    /// Reads the values of the nodes, where a value that cannot be read is None
+    fn read_values(&mut self, node_ids: &[NodeId]) -> Result<Vec<Option<Variant>>, StatusCode> {
+        let nodes_to_read = node_ids.iter().map(|node_id| ReadValueId::from(node_id)).collect::<Vec<ReadValueId>>();
+        let values = self.read_nodes(nodes_to_read)?.unwrap_or_default();
+        if values.len() != node_ids.len() {
+            error!("Expecting {} values, got {}", node_ids.len(), values.len());
+            return Err(BadUnexpectedError);
+        }
+        Ok(values.into_iter().map(|v| v.value).collect())
+    }
+
     // Test if the subscription by id exists
     fn subscription_exists(&self, subscription_id: UInt32) -> bool {
         let subscription_state = trace_read_lock_unwrap!(self.subscription_state);
@@ -995,12 +1102,17 @@ impl Session {
     fn issue_or_renew_secure_channel(&mut self, request_type: SecurityTokenRequestType) -> Result<(), StatusCode> {
         trace!("issue_or_renew_secure_channel({:?})", request_type);
 
-        const REQUESTED_LIFETIME: UInt32 = 60000; // TODO
-
         let (security_mode, security_policy, client_nonce) = {
             let mut secure_channel = trace_write_lock_unwrap!( self.transport.secure_channel);
-            let client_nonce = secure_channel.security_policy().nonce();
-            secure_channel.set_local_nonce(client_nonce.as_ref());
+            let client_nonce = if secure_channel.security_policy().is_ecc() {
+                // The nonce is the public key of a new ephemeral key pair
+                secure_channel.create_random_nonce();
+                secure_channel.local_nonce_as_byte_string()
+            } else {
+                let client_nonce = secure_channel.security_policy().nonce();
+                secure_channel.set_local_nonce(client_nonce.as_ref());
+                client_nonce
+            };
             (secure_channel.security_mode(), secure_channel.security_policy(), client_nonce)
         };
 
@@ -1008,7 +1120,10 @@ impl Session {
         info!("security_mode = {:?}", security_mode);
         info!("security_policy = {:?}", security_policy);
 
-        let requested_lifetime = REQUESTED_LIFETIME;
+        let requested_lifetime = {
+            let session_state = trace_read_lock_unwrap!(self.session_state);
+            session_state.secure_channel_lifetime
+        };
         let request = OpenSecureChannelRequest {
             request_header: self.make_request_header(),
             client_protocol_version: 0,
@@ -1020,11 +1135,21 @@ impl Session {
         let response = self.send_request(SupportedMessage::OpenSecureChannelRequest(request))?;
         if let SupportedMessage::OpenSecureChannelResponse(response) = response {
             debug!("Setting transport's security token");
-            self.transport.set_security_token(response.security_token);
-            if security_policy != SecurityPolicy::None && (security_mode == MessageSecurityMode::Sign || security_mode == MessageSecurityMode::SignAndEncrypt) {
-                let mut secure_channel = trace_write_lock_unwrap!( self.transport.secure_channel);
+            let derive_keys = security_policy != SecurityPolicy::None && (security_mode == MessageSecurityMode::Sign || security_mode == MessageSecurityMode::SignAndEncrypt);
+            if derive_keys {
+                let mut secure_channel = trace_write_lock_unwrap!(self.transport.secure_channel);
                 secure_channel.set_remote_nonce_from_byte_string(&response.server_nonce)?;
-                secure_channel.derive_keys();
+            }
+            if request_type == SecurityTokenRequestType::Renew {
+                // The keys of the renewed token are derived by the renew, which keeps the current
+                // token if they cannot be
+                self.transport.renew_security_token(response.security_token)?;
+            } else {
+                self.transport.set_security_token(response.security_token);
+                if derive_keys {
+                    let mut secure_channel = trace_write_lock_unwrap!(self.transport.secure_channel);
+                    secure_channel.derive_keys()?;
+                }
             }
             Ok(())
         } else {
@@ -1032,8 +1157,15 @@ impl Session {
         }
     }
 
-    /// Function that handles subscription
+    /// Function that handles subscription. It also renews the secure channel token when it is
+    /// due, so the token is kept alive even when the session is otherwise idle.
     pub fn subscription_timer(&mut self) {
+        if self.is_connected() {
+            if let Err(status_code) = self.ensure_secure_channel_token() {
+                error!("Subscription timer could not renew the secure channel token, error = {:?}", status_code);
+            }
+        }
+
         let have_subscriptions = {
             let subscription_state = trace_read_lock_unwrap!(self.subscription_state);
             !subscription_state.is_empty()
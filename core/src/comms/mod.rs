@@ -8,6 +8,10 @@ pub const CHUNK_MESSAGE: &'static [u8] = b"MSG";
 pub const OPEN_SECURE_CHANNEL_MESSAGE: &'static [u8] = b"OPN";
 pub const CLOSE_SECURE_CHANNEL_MESSAGE: &'static [u8] = b"CLO";
 
+/// The version of the OPC UA TCP protocol that is implemented. It is sent in the HELLO and ACK
+/// messages and in the OpenSecureChannel request and response.
+pub const PROTOCOL_VERSION: u32 = 0;
+
 /// The size of a chunk header, used by several places
 pub const MESSAGE_CHUNK_HEADER_SIZE: usize = 12;
 pub const SEQUENCE_HEADER_SIZE: usize = 8;
@@ -31,7 +35,7 @@ pub mod secure_channel;
 pub mod security_header;
 
 pub mod prelude {
-    pub use super::MAX_CHUNK_COUNT;
+    pub use super::{MAX_CHUNK_COUNT, PROTOCOL_VERSION};
     pub use super::chunker::*;
     pub use super::handshake::*;
     pub use super::message_buffer::*;
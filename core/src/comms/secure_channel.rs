@@ -9,14 +9,41 @@ use opcua_types::service_types::ChannelSecurityToken;
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 
+use comms::PROTOCOL_VERSION;
 use comms::message_chunk::{MessageChunk, MessageChunkHeader, MessageChunkType};
 use comms::security_header::{AsymmetricSecurityHeader, SecurityHeader, SymmetricSecurityHeader};
 use crypto::aeskey::AesKey;
 use crypto::CertificateStore;
+use crypto::EphemeralKey;
+use crypto::provider::provider;
 use crypto::pkey::{PrivateKey, PublicKey, KeySize};
 use crypto::SecurityPolicy;
 use crypto::x509::X509;
 
+/// The shortest lifetime in milliseconds of a security token. A shorter requested lifetime is
+/// revised up to it.
+pub const MIN_TOKEN_LIFETIME: UInt32 = 10_000;
+/// The longest lifetime in milliseconds of a security token. A longer requested lifetime, or a
+/// requested lifetime of 0, is revised down to it.
+pub const MAX_TOKEN_LIFETIME: UInt32 = 3_600_000;
+
+/// A renewed security token. Its keys stay valid until the other end sends a message secured with
+/// the new token or the lifetime of the old token runs out.
+#[derive(Debug)]
+struct PreviousToken {
+    token_id: UInt32,
+    created_at: DateTime,
+    lifetime: UInt32,
+    remote_keys: Option<(Vec<u8>, AesKey, Vec<u8>)>,
+    local_keys: Option<(Vec<u8>, AesKey, Vec<u8>)>,
+}
+
+impl PreviousToken {
+    fn has_expired(&self) -> bool {
+        SecureChannel::token_lifetime_has_expired(&self.created_at, self.lifetime)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Role {
     Unknown,
@@ -51,10 +78,14 @@ pub struct SecureChannel {
     remote_nonce: Vec<u8>,
     /// Our nonce generated while handling open secure channel
     local_nonce: Vec<u8>,
+    /// Our ephemeral key pair whose public key is the local nonce for the ECC policies
+    ephemeral_key: Option<EphemeralKey>,
     /// Client (i.e. other end's set of keys) Symmetric Signing Key, Encrypt Key, IV
     remote_keys: Option<(Vec<u8>, AesKey, Vec<u8>)>,
     /// Server (i.e. our end's set of keys) Symmetric Signing Key, Decrypt Key, IV
     local_keys: Option<(Vec<u8>, AesKey, Vec<u8>)>,
+    /// The token and keys that were replaced by the last renew, if they are still needed
+    previous_token: Option<PreviousToken>,
 }
 
 impl From<(SecurityPolicy, MessageSecurityMode)> for SecureChannel {
@@ -69,11 +100,13 @@ impl From<(SecurityPolicy, MessageSecurityMode)> for SecureChannel {
             token_lifetime: 0,
             local_nonce: Vec::new(),
             remote_nonce: Vec::new(),
+            ephemeral_key: None,
             cert: None,
             private_key: None,
             remote_cert: None,
             local_keys: None,
             remote_keys: None,
+            previous_token: None,
         }
     }
 }
@@ -88,7 +121,7 @@ impl SecureChannel {
     pub fn new(certificate_store: Arc<RwLock<CertificateStore>>, role: Role) -> SecureChannel {
         let (cert, private_key) = {
             let certificate_store = certificate_store.read().unwrap();
-            if let Ok((cert, pkey)) = certificate_store.read_own_cert_and_pkey() {
+            if let Ok((cert, pkey)) = certificate_store.own_cert_and_pkey() {
                 (Some(cert), Some(pkey))
             } else {
                 error!("Cannot read our own certificate and private key. Check paths. Crypto won't work");
@@ -105,14 +138,22 @@ impl SecureChannel {
             token_lifetime: 0,
             local_nonce: Vec::new(),
             remote_nonce: Vec::new(),
+            ephemeral_key: None,
             cert,
             private_key,
             remote_cert: None,
             local_keys: None,
             remote_keys: None,
+            previous_token: None,
         }
     }
 
+    /// For testing purposes only
+    #[cfg(test)]
+    pub fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
     pub fn is_client_role(&self) -> bool {
         self.role == Role::Client
     }
@@ -137,6 +178,10 @@ impl SecureChannel {
         self.private_key = private_key;
     }
 
+    pub fn private_key(&self) -> Option<PrivateKey> {
+        self.private_key.clone()
+    }
+
     pub fn security_mode(&self) -> MessageSecurityMode {
         self.security_mode
     }
@@ -153,13 +198,50 @@ impl SecureChannel {
         self.security_policy = security_policy;
     }
 
+    /// Sets the security token from an issue request. Any previous token is forgotten.
     pub fn set_security_token(&mut self, channel_token: ChannelSecurityToken) {
+        self.previous_token = None;
         self.secure_channel_id = channel_token.channel_id;
         self.token_id = channel_token.token_id;
         self.token_created_at = channel_token.created_at;
         self.token_lifetime = channel_token.revised_lifetime;
     }
 
+    /// Sets the security token from a renew request and derives its keys from the nonces, which
+    /// must already be set. The current token and its keys become the previous token. If the keys
+    /// cannot be derived, the error is returned and the current token and keys are kept.
+    ///
+    /// Messages secured with the previous token are still accepted until the first message
+    /// secured with the new token is verified or the previous token expires. Until then the server
+    /// also carries on securing the messages it sends with the previous token.
+    pub fn renew_security_token(&mut self, channel_token: ChannelSecurityToken) -> Result<(), StatusCode> {
+        let keys = if self.security_policy != SecurityPolicy::None && (self.security_mode == MessageSecurityMode::Sign || self.security_mode == MessageSecurityMode::SignAndEncrypt) {
+            Some(self.make_keys()?)
+        } else {
+            None
+        };
+        self.previous_token = Some(PreviousToken {
+            token_id: self.token_id,
+            created_at: self.token_created_at.clone(),
+            lifetime: self.token_lifetime,
+            remote_keys: self.remote_keys.take(),
+            local_keys: self.local_keys.take(),
+        });
+        self.secure_channel_id = channel_token.channel_id;
+        self.token_id = channel_token.token_id;
+        self.token_created_at = channel_token.created_at;
+        self.token_lifetime = channel_token.revised_lifetime;
+        if let Some((local_keys, remote_keys)) = keys {
+            self.set_keys(local_keys, remote_keys);
+        }
+        Ok(())
+    }
+
+    /// Tests if there is a previous token which is still valid after a renew
+    pub fn has_previous_token(&self) -> bool {
+        self.previous_token.as_ref().map_or(false, |t| !t.has_expired())
+    }
+
     pub fn set_secure_channel_id(&mut self, secure_channel_id: UInt32) {
         self.secure_channel_id = secure_channel_id;
     }
@@ -204,19 +286,32 @@ impl SecureChannel {
             }
             _ => {
                 SecurityHeader::Symmetric(SymmetricSecurityHeader {
-                    token_id: self.token_id,
+                    token_id: self.sending_token_id(),
                 })
             }
         }
     }
 
-    /// Creates a nonce for the connection. The nonce should be the same size as the symmetric key
+    /// Creates a nonce for the connection. The nonce length is set by the security policy. For
+    /// the ECC policies the nonce is the public key of a new ephemeral key pair.
     pub fn create_random_nonce(&mut self) {
         if self.security_policy != SecurityPolicy::None && (self.security_mode == MessageSecurityMode::Sign || self.security_mode == MessageSecurityMode::SignAndEncrypt) {
-            use ring::rand::{SystemRandom, SecureRandom};
-            let rng = SystemRandom::new();
-            self.local_nonce = vec![0u8; self.security_policy.symmetric_key_size()];
-            let _ = rng.fill(&mut self.local_nonce);
+            if self.security_policy.is_ecc() {
+                match EphemeralKey::new(self.security_policy) {
+                    Ok(ephemeral_key) => {
+                        self.local_nonce = ephemeral_key.public_key_bytes();
+                        self.ephemeral_key = Some(ephemeral_key);
+                    }
+                    Err(err) => {
+                        error!("Cannot create an ephemeral key for the nonce, error = {:?}", err);
+                        self.local_nonce.clear();
+                        self.ephemeral_key = None;
+                    }
+                }
+                return;
+            }
+            self.local_nonce = vec![0u8; self.security_policy.secure_channel_nonce_length()];
+            provider().random_bytes(&mut self.local_nonce);
         } else {
             self.local_nonce = vec![0u8; 1];
         }
@@ -241,12 +336,20 @@ impl SecureChannel {
         }
     }
 
-    /// Set their nonce which should be the same as the symmetric key
+    /// Set their nonce which should be the length set by the security policy
     pub fn set_remote_nonce_from_byte_string(&mut self, remote_nonce: &ByteString) -> Result<(), StatusCode> {
         if self.security_policy != SecurityPolicy::None && (self.security_mode == MessageSecurityMode::Sign || self.security_mode == MessageSecurityMode::SignAndEncrypt) {
             if let Some(ref remote_nonce) = remote_nonce.value {
-                if remote_nonce.len() != self.security_policy.symmetric_key_size() {
-                    error!("Remote nonce is invalid length {}, expecting {}. {:?}", remote_nonce.len(), self.security_policy.symmetric_key_size(), remote_nonce);
+                if remote_nonce.len() != self.security_policy.secure_channel_nonce_length() {
+                    error!("Remote nonce is invalid length {}, expecting {}. {:?}", remote_nonce.len(), self.security_policy.secure_channel_nonce_length(), remote_nonce);
+                    return Err(BadNonceInvalid);
+                }
+                if !Self::is_nonce_random(remote_nonce) {
+                    error!("Remote nonce does not look random. {:?}", remote_nonce);
+                    return Err(BadNonceInvalid);
+                }
+                if *remote_nonce == self.local_nonce {
+                    error!("Remote nonce is the same as our nonce");
                     return Err(BadNonceInvalid);
                 }
                 self.remote_nonce = remote_nonce.to_vec();
@@ -261,6 +364,73 @@ impl SecureChannel {
         }
     }
 
+    /// A sanity check on the entropy of a nonce, which should be random bytes or, for the ECC
+    /// policies, the coordinates of a random point. At least a quarter of the bytes must be
+    /// distinct, which catches nonces of zeros, repeated patterns or counters that are too short.
+    fn is_nonce_random(nonce: &[u8]) -> bool {
+        let mut seen = [false; 256];
+        let distinct = nonce.iter().filter(|b| {
+            let first = !seen[**b as usize];
+            seen[**b as usize] = true;
+            first
+        }).count();
+        distinct * 4 >= nonce.len()
+    }
+
+    /// Validates the protocol version of an OpenSecureChannel request. It must be the version
+    /// that the client sent in its HELLO, and a version that is supported.
+    pub fn validate_protocol_version(hello_protocol_version: UInt32, request_protocol_version: UInt32) -> Result<(), StatusCode> {
+        if request_protocol_version != hello_protocol_version {
+            error!("Client sent a different protocol version than it did in the HELLO - {} vs {}", request_protocol_version, hello_protocol_version);
+            Err(BadProtocolVersionUnsupported)
+        } else if request_protocol_version > PROTOCOL_VERSION {
+            error!("Client protocol version {} is newer than the supported version {}", request_protocol_version, PROTOCOL_VERSION);
+            Err(BadProtocolVersionUnsupported)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validates the key of the remote certificate against the security policy. When the channel
+    /// is secured the certificate must be present, have the kind of key the policy requires and a
+    /// key length between the policy's minimum and maximum asymmetric key lengths.
+    pub fn validate_remote_cert_key(&self) -> Result<(), StatusCode> {
+        if self.security_policy == SecurityPolicy::None || self.security_mode == MessageSecurityMode::None {
+            return Ok(());
+        }
+        let public_key = if let Some(ref remote_cert) = self.remote_cert {
+            remote_cert.public_key()?
+        } else {
+            error!("Security policy {:?} requires a remote certificate", self.security_policy);
+            return Err(BadSecurityChecksFailed);
+        };
+        if !self.security_policy.is_valid_key_type(&public_key) {
+            error!("Remote certificate's key is not the type required by security policy {:?}", self.security_policy);
+            return Err(BadSecurityPolicyRejected);
+        }
+        let bit_length = public_key.bit_length();
+        let (min_key_length, max_key_length) = (self.security_policy.min_asymmetric_key_length(), self.security_policy.max_asymmetric_key_length());
+        if bit_length < min_key_length || bit_length > max_key_length {
+            error!("Remote certificate's key length {} is outside of the range {} to {} of security policy {:?}", bit_length, min_key_length, max_key_length, self.security_policy);
+            return Err(BadSecurityPolicyRejected);
+        }
+        Ok(())
+    }
+
+    /// Revises the token lifetime in milliseconds requested by OpenSecureChannel so it lies
+    /// between `MIN_TOKEN_LIFETIME` and `MAX_TOKEN_LIFETIME`. A request of 0 gets the maximum.
+    pub fn revise_token_lifetime(requested_lifetime: UInt32) -> UInt32 {
+        if requested_lifetime == 0 {
+            MAX_TOKEN_LIFETIME
+        } else if requested_lifetime < MIN_TOKEN_LIFETIME {
+            MIN_TOKEN_LIFETIME
+        } else if requested_lifetime > MAX_TOKEN_LIFETIME {
+            MAX_TOKEN_LIFETIME
+        } else {
+            requested_lifetime
+        }
+    }
+
     /// Part 6
     /// 6.7.5
     /// Deriving keys Once the SecureChannel is established the Messages are signed and encrypted with
@@ -294,21 +464,105 @@ impl SecureChannel {
     /// The Client keys are used to secure Messages sent by the Client. The Server keys
     /// are used to secure Messages sent by the Server.
     ///
-    pub fn derive_keys(&mut self) {
-        self.remote_keys = Some(self.security_policy.make_secure_channel_keys(&self.local_nonce, &self.remote_nonce));
-        self.local_keys = Some(self.security_policy.make_secure_channel_keys(&self.remote_nonce, &self.local_nonce));
+    /// The ECC policies instead derive the keys with HKDF from the secret shared through the
+    /// ephemeral keys, see `SecurityPolicy::make_ecc_secure_channel_keys`. The remote nonce must be
+    /// a public key on the policy's curve or `BadNonceInvalid` is returned.
+    ///
+    pub fn derive_keys(&mut self) -> Result<(), StatusCode> {
+        let (local_keys, remote_keys) = self.make_keys()?;
+        self.set_keys(local_keys, remote_keys);
+        Ok(())
+    }
+
+    fn set_keys(&mut self, local_keys: (Vec<u8>, AesKey, Vec<u8>), remote_keys: (Vec<u8>, AesKey, Vec<u8>)) {
+        self.local_keys = Some(local_keys);
+        self.remote_keys = Some(remote_keys);
         trace!("Remote nonce = {:?}", self.remote_nonce);
         trace!("Local nonce = {:?}", self.local_nonce);
         trace!("Derived remote keys = {:?}", self.remote_keys);
         trace!("Derived local keys = {:?}", self.local_keys);
     }
 
-    /// Test if the token has expired yet
+    /// Makes the local and remote keys from the nonces without changing the keys in use
+    fn make_keys(&self) -> Result<((Vec<u8>, AesKey, Vec<u8>), (Vec<u8>, AesKey, Vec<u8>)), StatusCode> {
+        if self.security_policy.is_ecc() {
+            let shared_secret = if let Some(ref ephemeral_key) = self.ephemeral_key {
+                ephemeral_key.shared_secret(&self.remote_nonce)?
+            } else {
+                error!("Cannot derive keys because there is no ephemeral key");
+                return Err(BadNonceInvalid);
+            };
+            let (client_keys, server_keys) = if self.is_client_role() {
+                self.security_policy.make_ecc_secure_channel_keys(&shared_secret, &self.local_nonce, &self.remote_nonce)
+            } else {
+                self.security_policy.make_ecc_secure_channel_keys(&shared_secret, &self.remote_nonce, &self.local_nonce)
+            };
+            if self.is_client_role() {
+                Ok((client_keys, server_keys))
+            } else {
+                Ok((server_keys, client_keys))
+            }
+        } else {
+            let remote_keys = self.security_policy.make_secure_channel_keys(&self.local_nonce, &self.remote_nonce);
+            let local_keys = self.security_policy.make_secure_channel_keys(&self.remote_nonce, &self.local_nonce);
+            Ok((local_keys, remote_keys))
+        }
+    }
+
+    /// Test if the token has expired yet. A token with a lifetime of 0 never expires.
     pub fn token_has_expired(&self) -> bool {
-        let now: chrono::DateTime<chrono::Utc> = DateTime::now().into();
-        let token_created_at: chrono::DateTime<chrono::Utc> = self.token_created_at.clone().into();
-        let token_expires = token_created_at + chrono::Duration::seconds(self.token_lifetime as i64);
-        if now.ge(&token_expires) { true } else { false }
+        Self::token_lifetime_has_expired(&self.token_created_at, self.token_lifetime)
+    }
+
+    fn token_lifetime_has_expired(created_at: &DateTime, lifetime: UInt32) -> bool {
+        if lifetime == 0 {
+            false
+        } else {
+            let now: chrono::DateTime<chrono::Utc> = DateTime::now().into();
+            let created_at: chrono::DateTime<chrono::Utc> = created_at.clone().into();
+            let expires_at = created_at + chrono::Duration::milliseconds(lifetime as i64);
+            now >= expires_at
+        }
+    }
+
+    /// The previous token, if the messages we send must still be secured with it. Only the server
+    /// does this since the client switches to a renewed token straight away.
+    fn sending_previous_token(&self) -> Option<&PreviousToken> {
+        if self.role == Role::Server {
+            match self.previous_token {
+                Some(ref previous_token) if !previous_token.has_expired() => Some(previous_token),
+                _ => None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The id of the token that the messages we send are secured with
+    fn sending_token_id(&self) -> UInt32 {
+        self.sending_previous_token().map_or(self.token_id, |t| t.token_id)
+    }
+
+    /// Checks the token id of a received symmetric message. The current token is accepted until
+    /// it expires. The previous token is accepted until it expires or a message secured with the
+    /// current token has been verified. Anything else is rejected with `BadSecureChannelTokenUnknown`.
+    fn check_received_token_id(&self, token_id: UInt32) -> Result<(), StatusCode> {
+        if token_id == self.token_id {
+            if self.token_has_expired() {
+                error!("Received a message secured with token {} which has expired", token_id);
+                Err(BadSecureChannelTokenUnknown)
+            } else {
+                Ok(())
+            }
+        } else {
+            match self.previous_token {
+                Some(ref previous_token) if previous_token.token_id == token_id && !previous_token.has_expired() => Ok(()),
+                _ => {
+                    error!("Received a message secured with token {} which is unknown or has expired", token_id);
+                    Err(BadSecureChannelTokenUnknown)
+                }
+            }
+        }
     }
 
     /// Calculates the signature size for a message depending on the supplied security header
@@ -318,7 +572,7 @@ impl SecureChannel {
             SecurityHeader::Asymmetric(ref security_header) => {
                 if !security_header.sender_certificate.is_null() {
                     let x509 = X509::from_byte_string(&security_header.sender_certificate).unwrap();
-                    x509.public_key().unwrap().size()
+                    x509.public_key().unwrap().signature_size()
                 } else {
                     trace!("No certificate / public key was supplied in the asymmetric security header");
                     0
@@ -344,7 +598,10 @@ impl SecureChannel {
             // Signature size in bytes
             let plain_text_block_size = match *security_header {
                 SecurityHeader::Asymmetric(ref security_header) => {
-                    if !security_header.sender_certificate.is_null() {
+                    if self.security_policy.is_ecc() {
+                        // The ECC policies sign but do not encrypt OpenSecureChannel so there is no padding
+                        return 0;
+                    } else if !security_header.sender_certificate.is_null() {
                         // Padding requires we look at the sending key and security policy
                         let padding = self.security_policy.padding();
                         let x509 = X509::from_byte_string(&security_header.sender_certificate).unwrap();
@@ -482,7 +739,10 @@ impl SecureChannel {
             let encrypted_range = chunk_info.sequence_header_offset..data.len();
 
             // Encrypt and sign - open secure channel
-            let encrypted_size = if message_chunk.is_open_secure_channel() {
+            let encrypted_size = if message_chunk.is_open_secure_channel() && self.security_policy.is_ecc() {
+                // Sign only - open secure channel for ECC policies
+                self.asymmetric_sign(self.security_policy, &data, dst)?
+            } else if message_chunk.is_open_secure_channel() {
                 self.asymmetric_sign_and_encrypt(self.security_policy, &data, encrypted_range, dst)?
             } else {
                 // Symmetric encrypt and sign
@@ -530,6 +790,15 @@ impl SecureChannel {
             return Err(BadUnexpectedError);
         }
 
+        // Symmetric messages must be secured with a token that is still valid
+        let token_id = match security_header {
+            SecurityHeader::Symmetric(ref security_header) => {
+                self.check_received_token_id(security_header.token_id)?;
+                security_header.token_id
+            }
+            _ => self.token_id
+        };
+
         // S - Message Header
         // S - Security Header
         // S - Sequence Header - E
@@ -583,11 +852,19 @@ impl SecureChannel {
             let sender_certificate = X509::from_byte_string(&security_header.sender_certificate)?;
 
             let verification_key = sender_certificate.public_key()?;
+            if !security_policy.is_valid_key_type(&verification_key) {
+                error!("Sender certificate's key is not the type or size required by security policy {:?}", security_policy);
+                return Err(BadSecurityPolicyRejected);
+            }
             let receiver_thumbprint = security_header.receiver_certificate_thumbprint;
             trace!("Receiver thumbprint = {:?}", receiver_thumbprint);
 
             let mut decrypted_data = vec![0u8; message_size];
-            let decrypted_size = self.asymmetric_decrypt_and_verify(security_policy, &verification_key, receiver_thumbprint, src, encrypted_range, their_key, &mut decrypted_data)?;
+            let decrypted_size = if security_policy.is_ecc() {
+                self.asymmetric_verify(security_policy, &verification_key, receiver_thumbprint, src, their_key, &mut decrypted_data)?
+            } else {
+                self.asymmetric_decrypt_and_verify(security_policy, &verification_key, receiver_thumbprint, src, encrypted_range, their_key, &mut decrypted_data)?
+            };
 
             Self::update_message_size_and_truncate(decrypted_data, decrypted_size)?
         } else if self.security_policy != SecurityPolicy::None && (self.security_mode == MessageSecurityMode::Sign || self.security_mode == MessageSecurityMode::SignAndEncrypt) {
@@ -598,7 +875,7 @@ impl SecureChannel {
             debug!("Decrypting block with signature info {:?} and encrypt info {:?}", signed_range, encrypted_range);
 
             let mut decrypted_data = vec![0u8; message_size];
-            let decrypted_size = self.symmetric_decrypt_and_verify(src, signed_range, encrypted_range, &mut decrypted_data)?;
+            let decrypted_size = self.symmetric_decrypt_and_verify_with_token(token_id, src, signed_range, encrypted_range, &mut decrypted_data)?;
 
             // Now we need to strip off signature
             Self::update_message_size_and_truncate(decrypted_data, decrypted_size - signature_size)?
@@ -606,6 +883,13 @@ impl SecureChannel {
             src.to_vec()
         };
 
+        // A verified message secured with the current token means the previous token is no
+        // longer needed. A forged or corrupt message must not get rid of it.
+        if token_id == self.token_id && self.previous_token.is_some() && !message_header.message_type.is_open_secure_channel() {
+            debug!("Received the first message secured with token {}, discarding the previous token", token_id);
+            self.previous_token = None;
+        }
+
         Ok(MessageChunk { data })
     }
 
@@ -664,6 +948,60 @@ impl SecureChannel {
         Ok(header_size + encrypted_size)
     }
 
+    /// Use the security policy to asymmetric sign the specified chunk of data without encrypting
+    /// it. The ECC policies do this for the OpenSecureChannel messages.
+    fn asymmetric_sign(&self, security_policy: SecurityPolicy, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let signing_key = self.private_key.as_ref().unwrap();
+        if !security_policy.is_valid_key_type(signing_key) {
+            error!("Private key is not the type or size required by security policy {:?}", security_policy);
+            return Err(BadSecurityPolicyRejected);
+        }
+        let signature_size = signing_key.signature_size();
+
+        let signed_range = 0..(src.len() - signature_size);
+        let signature_range = signed_range.end..src.len();
+        trace!("Signed range = {:?}, Signature range = {:?}, signature size = {}", signed_range, signature_range, signature_size);
+
+        dst[signed_range.clone()].copy_from_slice(&src[signed_range.clone()]);
+        security_policy.asymmetric_sign(signing_key, &src[signed_range], &mut dst[signature_range.clone()])?;
+
+        Self::log_crypto_data("Chunk after signing", &dst[..signature_range.end]);
+
+        Ok(signature_range.end)
+    }
+
+    /// Checks the receiver thumbprint in an asymmetric security header is the thumbprint of our certificate
+    fn check_receiver_thumbprint(&self, receiver_thumbprint: &ByteString) -> Result<(), StatusCode> {
+        let our_cert = self.cert.as_ref().unwrap();
+        let our_thumbprint = our_cert.thumbprint();
+        if &our_thumbprint.value[..] != receiver_thumbprint.as_ref() {
+            error!("Supplied thumbprint does not match application certificate's thumbprint");
+            Err(BadNoValidCertificates)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Verifies the signature of a chunk that was signed but not encrypted and copies the chunk
+    /// without the signature to dst.
+    fn asymmetric_verify(&self, security_policy: SecurityPolicy, verification_key: &PublicKey, receiver_thumbprint: ByteString, src: &[u8], their_key: Option<PrivateKey>, dst: &mut [u8]) -> Result<usize, StatusCode> {
+        self.check_receiver_thumbprint(&receiver_thumbprint)?;
+
+        let signature_size = verification_key.signature_size();
+        if src.len() < signature_size {
+            error!("Chunk of {} bytes is too small to hold a signature of {} bytes", src.len(), signature_size);
+            return Err(BadSecurityChecksFailed);
+        }
+        let signed_range = 0..(src.len() - signature_size);
+        let signature_range = signed_range.end..src.len();
+
+        trace!("Verifying signature range {:?} with signature at {:?}", signed_range, signature_range);
+        security_policy.asymmetric_verify_signature(verification_key, &src[signed_range.clone()], &src[signature_range], their_key)?;
+
+        dst[signed_range.clone()].copy_from_slice(&src[signed_range.clone()]);
+        Ok(signed_range.end)
+    }
+
     fn check_padding_bytes(padding_bytes: &[u8], expected_padding_byte: u8, padding_range_start: usize) -> Result<(), StatusCode> {
         for (i, b) in padding_bytes.iter().enumerate() {
             if *b != expected_padding_byte {
@@ -708,7 +1046,8 @@ impl SecureChannel {
     fn asymmetric_decrypt_and_verify(&self, security_policy: SecurityPolicy, verification_key: &PublicKey, receiver_thumbprint: ByteString, src: &[u8], encrypted_range: Range<usize>, their_key: Option<PrivateKey>, dst: &mut [u8]) -> Result<usize, StatusCode> {
         // Asymmetric encrypt requires the caller supply the security policy
         match security_policy {
-            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => {}
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 |
+            SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss => {}
             _ => {
                 return Err(BadSecurityPolicyRejected);
             }
@@ -723,11 +1062,8 @@ impl SecureChannel {
         // The receiver certificate thumbprint identifies which of our certs was used by the client
         // to encrypt the message. We have to work out from the thumbprint which cert to use
 
-        let our_cert = self.cert.as_ref().unwrap();
-        let our_thumbprint = our_cert.thumbprint();
-        if &our_thumbprint.value[..] != receiver_thumbprint.as_ref() {
-            error!("Supplied thumbprint does not match application certificate's thumbprint");
-            Err(BadNoValidCertificates)
+        if let Err(err) = self.check_receiver_thumbprint(&receiver_thumbprint) {
+            Err(err)
         } else {
             // Copy message, security header
             dst[..encrypted_range.start].copy_from_slice(&src[..encrypted_range.start]);
@@ -806,11 +1142,20 @@ impl SecureChannel {
     }
 
     fn local_keys(&self) -> &(Vec<u8>, AesKey, Vec<u8>) {
-        self.local_keys.as_ref().unwrap()
+        if let Some(previous_token) = self.sending_previous_token() {
+            previous_token.local_keys.as_ref().unwrap()
+        } else {
+            self.local_keys.as_ref().unwrap()
+        }
     }
 
-    fn remote_keys(&self) -> &(Vec<u8>, AesKey, Vec<u8>) {
-        self.remote_keys.as_ref().unwrap()
+    fn remote_keys(&self, token_id: UInt32) -> &(Vec<u8>, AesKey, Vec<u8>) {
+        match self.previous_token {
+            Some(ref previous_token) if token_id != self.token_id && token_id == previous_token.token_id => {
+                previous_token.remote_keys.as_ref().unwrap()
+            }
+            _ => self.remote_keys.as_ref().unwrap()
+        }
     }
 
     fn encryption_keys(&self) -> (&AesKey, &[u8]) {
@@ -822,13 +1167,13 @@ impl SecureChannel {
         &(self.local_keys()).0
     }
 
-    fn decryption_keys(&self) -> (&AesKey, &[u8]) {
-        let keys = self.remote_keys();
+    fn decryption_keys(&self, token_id: UInt32) -> (&AesKey, &[u8]) {
+        let keys = self.remote_keys(token_id);
         (&keys.1, &keys.2)
     }
 
-    fn verification_key(&self) -> &[u8] {
-        &(self.remote_keys()).0
+    fn verification_key(&self, token_id: UInt32) -> &[u8] {
+        &(self.remote_keys(token_id)).0
     }
 
     /// Encode data using security. Destination buffer is expected to be same size as src and expected
@@ -910,6 +1255,12 @@ impl SecureChannel {
     /// S - Padding         - E
     ///     Signature       - E
     pub fn symmetric_decrypt_and_verify(&self, src: &[u8], signed_range: Range<usize>, encrypted_range: Range<usize>, dst: &mut [u8]) -> Result<usize, StatusCode> {
+        self.symmetric_decrypt_and_verify_with_token(self.token_id, src, signed_range, encrypted_range, dst)
+    }
+
+    /// Decrypts and verifies data with the keys of the specified token, which may be the
+    /// previous token after a renew.
+    fn symmetric_decrypt_and_verify_with_token(&self, token_id: UInt32, src: &[u8], signed_range: Range<usize>, encrypted_range: Range<usize>, dst: &mut [u8]) -> Result<usize, StatusCode> {
         match self.security_mode {
             MessageSecurityMode::None => {
                 // Just copy everything from src to dst
@@ -924,7 +1275,7 @@ impl SecureChannel {
                 dst[all].copy_from_slice(&src[all]);
                 // Verify signature
                 trace!("Verifying range from {:?} to signature {}..", signed_range, signed_range.end);
-                let verification_key = self.verification_key();
+                let verification_key = self.verification_key(token_id);
                 self.security_policy.symmetric_verify_signature(verification_key, &dst[signed_range.clone()], &dst[signed_range.end..])?;
 
                 Ok(encrypted_range.end)
@@ -944,7 +1295,7 @@ impl SecureChannel {
 
                 // Decrypt encrypted portion
                 let mut decrypted_tmp = vec![0u8; ciphertext_size + 16]; // tmp includes +16 for blocksize
-                let (key, iv) = self.decryption_keys();
+                let (key, iv) = self.decryption_keys(token_id);
 
                 trace!("Secure decrypt called with encrypted range {:?}", encrypted_range);
                 let decrypted_size = self.security_policy.symmetric_decrypt(key, iv, &src[encrypted_range.clone()], &mut decrypted_tmp[..])?;
@@ -957,7 +1308,7 @@ impl SecureChannel {
                 // Verify signature (after encrypted portion)
                 let signature_range = (encrypted_range.end - self.security_policy.symmetric_signature_size())..encrypted_range.end;
                 trace!("signed range = {:?}, signature range = {:?}", signed_range, signature_range);
-                let verification_key = self.verification_key();
+                let verification_key = self.verification_key(token_id);
                 self.security_policy.symmetric_verify_signature(verification_key, &dst[signed_range.clone()], &dst[signature_range])?;
                 Ok(encrypted_range.end)
             }
@@ -971,7 +1322,9 @@ impl SecureChannel {
     // Panic code which requires a policy
     fn expect_supported_security_policy(&self) {
         match self.security_policy {
-            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => {}
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 |
+            SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 | SecurityPolicy::EccNistP384 => {}
             _ => {
                 panic!("Unsupported security policy");
             }
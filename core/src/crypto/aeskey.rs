@@ -1,14 +1,14 @@
 //! Symmetric encryption / decryption wrapper.
-use std::marker::Send;
 use std::result::Result;
 
-use openssl::symm::{Cipher, Crypter};
-use openssl::symm::Mode;
-
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 
 use crypto::SecurityPolicy;
+use crypto::provider::provider;
+
+/// Size of an AES block, and so also of the initialization vector, in bytes
+const AES_BLOCK_SIZE: usize = 16;
 
 #[derive(Debug)]
 pub struct AesKey {
@@ -16,9 +16,6 @@ pub struct AesKey {
     security_policy: SecurityPolicy,
 }
 
-/// This allows key to be transferred between threads
-unsafe impl Send for AesKey {}
-
 impl AesKey {
     pub fn new(security_policy: SecurityPolicy, value: &[u8]) -> AesKey {
         AesKey { value: value.to_vec(), security_policy }
@@ -28,9 +25,9 @@ impl AesKey {
         &self.value
     }
 
-    fn validate_aes_args(cipher: &Cipher, src: &[u8], iv: &[u8], dst: &mut [u8]) -> Result<(), StatusCode> {
-        if dst.len() < src.len() + cipher.block_size() {
-            error!("Dst buffer is too small {} vs {} + {}", src.len(), dst.len(), cipher.block_size());
+    fn validate_aes_args(&self, src: &[u8], iv: &[u8], dst: &mut [u8]) -> Result<(), StatusCode> {
+        if dst.len() < src.len() + self.block_size() {
+            error!("Dst buffer is too small {} vs {} + {}", src.len(), dst.len(), self.block_size());
             Err(BadUnexpectedError)
         } else if iv.len() != 16 && iv.len() != 32 {
             // ... It would be nice to compare iv size to be exact to the key size here (should be the
@@ -39,20 +36,31 @@ impl AesKey {
             Err(BadUnexpectedError)
         } else if src.len() % 16 != 0 {
             panic!("Block size {} is wrong, check stack", src.len());
+        } else if self.value.len() != self.key_length() {
+            error!("Key is {} bytes but the security policy requires {}", self.value.len(), self.key_length());
+            Err(BadUnexpectedError)
         } else {
             Ok(())
         }
     }
 
-    fn cipher(&self) -> Cipher {
+    pub fn block_size(&self) -> usize {
+        AES_BLOCK_SIZE
+    }
+
+    pub fn iv_length(&self) -> usize {
+        AES_BLOCK_SIZE
+    }
+
+    pub fn key_length(&self) -> usize {
         match self.security_policy {
-            SecurityPolicy::Basic128Rsa15 => {
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::EccNistP256 => {
                 // Aes128_CBC
-                Cipher::aes_128_cbc()
+                16
             }
-            SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => {
+            SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes256Sha256RsaPss | SecurityPolicy::EccNistP384 => {
                 // Aes256_CBC
-                Cipher::aes_256_cbc()
+                32
             }
             _ => {
                 panic!("Unsupported")
@@ -60,55 +68,16 @@ impl AesKey {
         }
     }
 
-    /// Encrypt or decrypt  data according to the mode
-    fn do_cipher(&self, mode: Mode, src: &[u8], iv: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
-        let cipher = self.cipher();
-
-        let _ = Self::validate_aes_args(&cipher, src, iv, dst)?;
-
-        trace!("Encrypting block of size {}", src.len());
-
-        let crypter = Crypter::new(cipher, mode, &self.value, Some(iv));
-        if let Ok(mut crypter) = crypter {
-            crypter.pad(false);
-            let result = crypter.update(src, dst);
-            if let Ok(count) = result {
-                let result = crypter.finalize(&mut dst[count..]);
-                if let Ok(rest) = result {
-                    trace!("do cipher size {}", count + rest);
-                    Ok(count + rest)
-                } else {
-                    error!("Encryption error during finalize {:?}", result.unwrap_err());
-                    Err(BadUnexpectedError)
-                }
-            } else {
-                error!("Encryption error during update {:?}", result.unwrap_err());
-                Err(BadUnexpectedError)
-            }
-        } else {
-            error!("Encryption Error");
-            Err(BadUnexpectedError)
-        }
-    }
-
-    pub fn block_size(&self) -> usize {
-        self.cipher().block_size()
-    }
-
-    pub fn iv_length(&self) -> usize {
-        self.cipher().iv_len().unwrap()
-    }
-
-    pub fn key_length(&self) -> usize {
-        self.cipher().key_len()
-    }
-
     pub fn encrypt(&self, src: &[u8], iv: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
-        self.do_cipher(Mode::Encrypt, src, iv, dst)
+        let _ = self.validate_aes_args(src, iv, dst)?;
+        trace!("Encrypting block of size {}", src.len());
+        provider().aes_cbc_encrypt(&self.value, iv, src, dst)
     }
 
     /// Decrypts data using AES. The initialization vector is the nonce generated for the secure channel
     pub fn decrypt(&self, src: &[u8], iv: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
-        self.do_cipher(Mode::Decrypt, src, iv, dst)
+        let _ = self.validate_aes_args(src, iv, dst)?;
+        trace!("Decrypting block of size {}", src.len());
+        provider().aes_cbc_decrypt(&self.value, iv, src, dst)
     }
 }
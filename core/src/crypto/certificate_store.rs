@@ -1,22 +1,21 @@
 //! The certificate store holds and retrieves private keys and certificates from disk. It is responsible
 //! for checking certificates supplied by the remote end to see if they are valid and trusted or not.
 use std::path::{Path, PathBuf};
-use std::fs::{File, metadata};
+use std::fs::{File, metadata, read_dir, remove_file};
 use std::io::{Write, Read};
+use std::net::IpAddr;
 
-use openssl::x509;
-use openssl::x509::extension::*;
-use openssl::pkey;
-use openssl::rsa::*;
-use openssl::asn1::*;
-use openssl::hash::*;
+use chrono::{Duration, Utc};
 
-use opcua_types::service_types::ApplicationDescription;
+use opcua_types::{UInt32, ByteString};
+use opcua_types::service_types::{ApplicationDescription, TrustListDataType};
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 
-use crypto::x509::{X509, X509Data};
-use crypto::pkey::PrivateKey;
+use crypto::x509::{X509, X509Crl, X509Data};
+use crypto::pkey::{PrivateKey, PublicKey, KeySize, KeyType};
+use crypto::hash::HashAlgorithm;
+use crypto::provider::{provider, AltName, KeyUsage, CertificateParams, CrlParams};
 
 /// The name that the server/client's application instance certificate is expected to be
 const OWN_CERTIFICATE_NAME: &'static str = "cert.der";
@@ -31,6 +30,24 @@ const OWN_PRIVATE_KEY_DIR: &'static str = "private";
 const TRUSTED_CERTS_DIR: &'static str = "trusted";
 /// The directory holding rejected certificates
 const REJECTED_CERTS_DIR: &'static str = "rejected";
+/// The directory holding CA certificates that are not trusted themselves but may issue trusted certificates
+const ISSUER_CERTS_DIR: &'static str = "issuers";
+/// The directory under the trusted and issuers directories holding revocation lists of their CAs
+const CRL_DIR: &'static str = "crl";
+
+/// Trust list mask for the trusted certificates, see OPC UA Part 12 7.8.2.7
+pub const TRUST_LIST_TRUSTED_CERTIFICATES: UInt32 = 1;
+/// Trust list mask for the revocation lists of trusted CAs
+pub const TRUST_LIST_TRUSTED_CRLS: UInt32 = 2;
+/// Trust list mask for the issuer certificates
+pub const TRUST_LIST_ISSUER_CERTIFICATES: UInt32 = 4;
+/// Trust list mask for the revocation lists of issuer CAs
+pub const TRUST_LIST_ISSUER_CRLS: UInt32 = 8;
+/// Trust list mask for every list
+pub const TRUST_LIST_ALL: UInt32 = 15;
+
+/// The longest chain of issuers that will be followed from a certificate to its root CA
+const MAX_CHAIN_LENGTH: usize = 10;
 
 /// The certificate store manages the storage of a server/client's own certificate & private key
 /// and the trust / rejection of certificates from the other end.
@@ -44,6 +61,12 @@ pub struct CertificateStore {
     /// into the trusted folder if this flag is set. Certs in the trusted folder must still pass
     /// validity checks.
     pub trust_unknown_certs: bool,
+    /// The application instance certificate and private key, held so that every new secure
+    /// channel uses the same pair until the store is reloaded.
+    own_cert_and_pkey: Option<(X509, PrivateKey)>,
+    /// The files in the PKI directory that the store loads and the digests of their contents
+    /// when the store was last loaded
+    pki_snapshot: Vec<(PathBuf, Vec<u8>)>,
 }
 
 impl CertificateStore {
@@ -55,12 +78,14 @@ impl CertificateStore {
             pki_path: pki_path.to_path_buf(),
             check_time: true,
             trust_unknown_certs: false,
+            own_cert_and_pkey: None,
+            pki_snapshot: Vec::new(),
         }
     }
 
     /// Sets up the certificate store, creates the path to it, and optionally creates a demo cert
     pub fn new_with_keypair(pki_path: &Path, application_description: Option<ApplicationDescription>) -> (CertificateStore, Option<X509>, Option<PrivateKey>) {
-        let certificate_store = CertificateStore::new(pki_path);
+        let mut certificate_store = CertificateStore::new(pki_path);
         let (cert, pkey) = if certificate_store.ensure_pki_path().is_err() {
             error!("Folder for storing certificates cannot be examined so server has no application instance certificate or private key.");
             (None, None)
@@ -85,9 +110,82 @@ impl CertificateStore {
                 }
             }
         };
+        if let (&Some(ref cert), &Some(ref pkey)) = (&cert, &pkey) {
+            certificate_store.own_cert_and_pkey = Some((cert.clone(), pkey.clone()));
+        }
+        certificate_store.pki_snapshot = certificate_store.pki_snapshot();
         (certificate_store, cert, pkey)
     }
 
+    /// Returns the application instance certificate and private key that the store last loaded,
+    /// reading them from disk if it has not loaded them yet.
+    pub fn own_cert_and_pkey(&self) -> Result<(X509, PrivateKey), String> {
+        if let Some((ref cert, ref pkey)) = self.own_cert_and_pkey {
+            Ok((cert.clone(), pkey.clone()))
+        } else {
+            self.read_own_cert_and_pkey()
+        }
+    }
+
+    /// Tests if anything in the PKI directory that affects the store, i.e. its own cert and
+    /// private key or the trusted and issuer certs and revocation lists, has changed since it was
+    /// last loaded. Rejected certs are ignored.
+    pub fn has_changed(&self) -> bool {
+        self.pki_snapshot() != self.pki_snapshot
+    }
+
+    /// Reloads the application instance certificate and private key from disk so that secure
+    /// channels created from now on use them. Existing secure channels keep the pair they were
+    /// created with. The trusted and issuer certs and revocation lists are read from disk every
+    /// time a cert is validated so changes to them take effect immediately.
+    ///
+    /// The pair that was previously loaded is kept if the new pair cannot be read or the
+    /// private key does not belong to the cert, e.g. because only one of them has been replaced.
+    ///
+    /// # Errors
+    ///
+    /// A string description of any failure
+    ///
+    pub fn reload(&mut self) -> Result<(), String> {
+        let pki_snapshot = self.pki_snapshot();
+        let (cert, pkey) = self.read_own_cert_and_pkey()?;
+        if !cert.matches_private_key(&pkey) {
+            return Err(format!("Private key {} does not belong to cert {}", self.own_private_key_path().display(), self.own_cert_path().display()));
+        }
+        let changed = if let Some((ref own_cert, _)) = self.own_cert_and_pkey {
+            own_cert.thumbprint().as_byte_string() != cert.thumbprint().as_byte_string()
+        } else {
+            true
+        };
+        if changed {
+            info!("Application instance certificate {} has been loaded", cert.thumbprint().as_hex_string());
+        }
+        self.own_cert_and_pkey = Some((cert, pkey));
+        self.pki_snapshot = pki_snapshot;
+        Ok(())
+    }
+
+    /// Lists the files that the store loads, sorted by path, with the SHA1 digest of each file's
+    /// contents. Comparing digests rather than modification times catches a file that is replaced
+    /// within the granularity of the file system's timestamps.
+    fn pki_snapshot(&self) -> Vec<(PathBuf, Vec<u8>)> {
+        let dirs = [self.own_cert_dir(), self.private_key_dir(), self.trusted_certs_dir(), self.trusted_crl_dir(), self.issuer_certs_dir(), self.issuer_crl_dir()];
+        let mut snapshot = dirs.iter()
+            .flat_map(|dir| CertificateStore::files_in_dir(dir))
+            .filter_map(|path| {
+                let mut contents = Vec::new();
+                if File::open(&path).and_then(|mut file| file.read_to_end(&mut contents)).is_ok() {
+                    let digest = provider().hash(HashAlgorithm::Sha1, &contents);
+                    Some((path, digest))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<(PathBuf, Vec<u8>)>>();
+        snapshot.sort();
+        snapshot
+    }
+
     /// Creates a self-signed X509v3 certificate and public/private key from the supplied creation args.
     /// The certificate identifies an instance of the application running on a host as well
     /// as the public key. The PKey holds the corresponding public/private key. Note that if
@@ -100,85 +198,225 @@ impl CertificateStore {
     /// hostnames / ip addresses that the host runs on.
     pub fn create_cert_and_pkey(args: &X509Data) -> Result<(X509, PrivateKey), String> {
         // Create a public / private keypair
-        let pkey = {
-            let rsa = Rsa::generate(args.key_size).unwrap();
-            pkey::PKey::from_rsa(rsa).unwrap()
+        let pkey = CertificateStore::generate_pkey(args.key_type, args.key_size)?;
+
+        // Create an X509 cert (the public part). Issuer and subject shall be the same for self-signed cert
+        let params = CertificateParams {
+            subject_name: CertificateStore::subject_name(args)?,
+            public_key: pkey.public_key_to_der(),
+            issuer_cert: None,
+            serial_number: CertificateStore::random_serial_number(),
+            not_before: Utc::now(),
+            not_after: Utc::now() + Duration::days(args.certificate_duration_days as i64),
+            basic_constraints_ca: None,
+            path_len_constraint: None,
+            // For Application Instance Certificate specifies how cert may be used
+            key_usage: Some((CertificateStore::application_key_usage(args.key_type), false)),
+            extended_key_usage: true,
+            subject_key_identifier: false,
+            authority_key_identifier: false,
+            // Subject alt names - Alt hostnames, ip addresses for application instance cert
+            alt_names: CertificateStore::subject_alternative_name(&args.alt_host_names),
+            hash_algorithm: CertificateStore::signature_digest(&pkey),
         };
 
-        // Create an X509 cert (the public part)
-        let cert = {
-            let mut builder = x509::X509Builder::new().unwrap();
-            // value 2 == version 3 (go figure)
-            let _ = builder.set_version(2);
-            let issuer_name = {
-                let mut name = x509::X509NameBuilder::new().unwrap();
-                // Common name
-                name.append_entry_by_text("CN", &args.common_name).unwrap();
-                // Organization
-                name.append_entry_by_text("O", &args.organization).unwrap();
-                // Organizational Unit
-                name.append_entry_by_text("OU", &args.organizational_unit).unwrap();
-                // Country
-                name.append_entry_by_text("C", &args.country).unwrap();
-                // State
-                name.append_entry_by_text("ST", &args.state).unwrap();
-                name.build()
-            };
-            // Issuer and subject shall be the same for self-signed cert
-            let _ = builder.set_subject_name(&issuer_name);
-            let _ = builder.set_issuer_name(&issuer_name);
+        // Self-sign
+        let cert = provider().create_certificate(&params, pkey.der())
+            .map_err(|_| "Cannot create the cert".to_string())
+            .and_then(|der| CertificateStore::cert_from_der(&der))?;
+        Ok((cert, pkey))
+    }
 
-            // For Application Instance Certificate specifies how cert may be used
-            let key_usage = KeyUsage::new().
-                digital_signature().
-                non_repudiation().
-                key_encipherment().
-                data_encipherment().build().unwrap();
-            let _ = builder.append_extension(key_usage);
-            let extended_key_usage = ExtendedKeyUsage::new().
-                client_auth().
-                server_auth().build().unwrap();
-            let _ = builder.append_extension(extended_key_usage);
-
-            builder.set_not_before(&Asn1Time::days_from_now(0).unwrap()).unwrap();
-            builder.set_not_after(&Asn1Time::days_from_now(args.certificate_duration_days).unwrap()).unwrap();
-            builder.set_pubkey(&pkey).unwrap();
-
-            // Random serial number
-            {
-                use openssl::bn::BigNum;
-                use openssl::bn::MsbOption;
-                let mut serial = BigNum::new().unwrap();
-                serial.rand(128, MsbOption::MAYBE_ZERO, false).unwrap();
-                let serial = serial.to_asn1_integer().unwrap();
-                let _ = builder.set_serial_number(&serial);
-            }
+    /// Generates a private key of the type and size, i.e. an RSA key of the key size in bits or an
+    /// EC key on the nistP256 / nistP384 curve for a key size of 256 / 384
+    fn generate_pkey(key_type: KeyType, key_size: u32) -> Result<PrivateKey, String> {
+        match key_type {
+            KeyType::Rsa => provider().generate_rsa_key(key_size).and_then(|der| PrivateKey::from_der(&der))
+                .map_err(|_| format!("Cannot generate an RSA key of {} bits", key_size)),
+            KeyType::Ecc => PrivateKey::new_ecc(key_size)
+                .map_err(|_| format!("Cannot generate an EC key of {} bits, it must be 256 or 384", key_size)),
+        }
+    }
 
-            // Subject alt names - Alt hostnames, ip addresses for application instance cert
-            if !args.alt_host_names.is_empty() {
-                let subject_alternative_name = {
-                    let mut subject_alternative_name = SubjectAlternativeName::new();
-                    for (i, alt_host_name) in args.alt_host_names.iter().enumerate() {
-                        if i == 0 {
-                            // The first entry is the application uri
-                            subject_alternative_name.uri(alt_host_name);
-                        } else {
-                            // The remainder are alternate DNS entries
-                            subject_alternative_name.dns(alt_host_name);
-                        }
-                    }
-                    subject_alternative_name.build(&builder.x509v3_context(None, None)).unwrap()
-                };
-                let _ = builder.append_extension(subject_alternative_name).unwrap();
+    /// The digest for signing with the key. EC keys on the nistP384 curve use Sha384, everything else Sha256.
+    fn signature_digest(pkey: &PrivateKey) -> HashAlgorithm {
+        if pkey.is_ec() && pkey.bit_length() == 384 {
+            HashAlgorithm::Sha384
+        } else {
+            HashAlgorithm::Sha256
+        }
+    }
+
+    /// The key usage of an application instance cert. An RSA key is used for signing and encryption,
+    /// whereas an EC key is used for signing and key agreement.
+    fn application_key_usage(key_type: KeyType) -> KeyUsage {
+        let mut key_usage = KeyUsage::default();
+        key_usage.digital_signature = true;
+        key_usage.non_repudiation = true;
+        match key_type {
+            KeyType::Rsa => {
+                key_usage.key_encipherment = true;
+                key_usage.data_encipherment = true;
+            }
+            KeyType::Ecc => {
+                key_usage.key_agreement = true;
             }
+        }
+        key_usage
+    }
+
+    /// Makes the subject name of a cert from the creation args
+    fn subject_name(args: &X509Data) -> Result<Vec<u8>, String> {
+        let entries = [
+            // Common name
+            ("CN", args.common_name.as_str()),
+            // Organization
+            ("O", args.organization.as_str()),
+            // Organizational Unit
+            ("OU", args.organizational_unit.as_str()),
+            // Country
+            ("C", args.country.as_str()),
+            // State
+            ("ST", args.state.as_str()),
+        ];
+        provider().encode_name(&entries).map_err(|_| "Cannot make the subject name".to_string())
+    }
+
+    /// Creates a certificate signing request (PKCS #10) in DER form for the private key, with the
+    /// subject and alt host names from the creation args. A CA signs the request to produce an
+    /// application instance certificate for the key.
+    pub fn create_signing_request(args: &X509Data, pkey: &PrivateKey) -> Result<Vec<u8>, String> {
+        let subject_name = CertificateStore::subject_name(args)?;
+        let alt_names = CertificateStore::subject_alternative_name(&args.alt_host_names);
+        provider().create_signing_request(&subject_name, pkey.der(), &alt_names, CertificateStore::signature_digest(pkey))
+            .map_err(|_| "Cannot create the request".to_string())
+    }
 
-            // Self-sign
-            let _ = builder.sign(&pkey, MessageDigest::sha256());
+    /// Creates a self-signed X509v3 CA certificate and public/private key from the supplied creation
+    /// args. The CA may sign application instance certificates and revocation lists, and should be
+    /// put in the trusted or issuers directory of the certificate stores that are to accept the
+    /// certs it signs. Alt host names in the args are ignored.
+    pub fn create_ca_cert_and_pkey(args: &X509Data) -> Result<(X509, PrivateKey), String> {
+        let pkey = CertificateStore::generate_pkey(args.key_type, args.key_size)?;
 
-            builder.build()
+        // A CA may sign certs and revocation lists but nothing else
+        let mut key_usage = KeyUsage::default();
+        key_usage.key_cert_sign = true;
+        key_usage.crl_sign = true;
+
+        let params = CertificateParams {
+            subject_name: CertificateStore::subject_name(args)?,
+            public_key: pkey.public_key_to_der(),
+            issuer_cert: None,
+            serial_number: CertificateStore::random_serial_number(),
+            not_before: Utc::now(),
+            not_after: Utc::now() + Duration::days(args.certificate_duration_days as i64),
+            basic_constraints_ca: Some(true),
+            path_len_constraint: None,
+            key_usage: Some((key_usage, true)),
+            extended_key_usage: false,
+            subject_key_identifier: true,
+            authority_key_identifier: false,
+            alt_names: Vec::new(),
+            hash_algorithm: CertificateStore::signature_digest(&pkey),
+        };
+
+        let cert = provider().create_certificate(&params, pkey.der())
+            .map_err(|_| "Cannot sign the CA cert".to_string())
+            .and_then(|der| CertificateStore::cert_from_der(&der))?;
+        Ok((cert, pkey))
+    }
+
+    /// Signs a certificate signing request in DER form with a CA's cert and private key to produce
+    /// an application instance certificate. The cert has the subject and public key of the request
+    /// and the alt host names supplied, the first of which is the application uri and the remainder
+    /// of which are DNS names or IP addresses.
+    ///
+    /// # Errors
+    ///
+    /// A string description of any failure
+    ///
+    pub fn sign_signing_request(request: &[u8], issuer_cert: &X509, issuer_pkey: &PrivateKey, alt_host_names: &[String], certificate_duration_days: u32) -> Result<X509, String> {
+        let request_info = provider().parse_signing_request(request).map_err(|_| "Cannot read the signing request".to_string())?;
+        let request_pkey = PublicKey::from_der(&request_info.public_key).map_err(|_| "Signing request has no public key".to_string())?;
+        if !provider().verify_signing_request(request) {
+            return Err("Signing request is not signed by its own key".to_string());
+        }
+        if alt_host_names.is_empty() {
+            return Err("Certificate requires an application uri".to_string());
+        }
+
+        // For Application Instance Certificate specifies how cert may be used, see Part 6 Table 23
+        let params = CertificateParams {
+            subject_name: request_info.subject_name,
+            public_key: request_info.public_key,
+            issuer_cert: Some(issuer_cert.der().to_vec()),
+            serial_number: CertificateStore::random_serial_number(),
+            not_before: Utc::now(),
+            not_after: Utc::now() + Duration::days(certificate_duration_days as i64),
+            basic_constraints_ca: Some(false),
+            path_len_constraint: None,
+            key_usage: Some((CertificateStore::application_key_usage(request_pkey.key_type()), true)),
+            extended_key_usage: true,
+            subject_key_identifier: true,
+            authority_key_identifier: true,
+            alt_names: CertificateStore::subject_alternative_name(alt_host_names),
+            hash_algorithm: CertificateStore::signature_digest(issuer_pkey),
+        };
+
+        provider().create_certificate(&params, issuer_pkey.der())
+            .map_err(|_| "Cannot sign the cert".to_string())
+            .and_then(|der| CertificateStore::cert_from_der(&der))
+    }
+
+    /// Creates a revocation list signed by a CA's cert and private key that revokes the supplied
+    /// certs. The list number should be larger than that of any list the CA issued before it.
+    ///
+    /// # Errors
+    ///
+    /// A string description of any failure
+    ///
+    pub fn create_crl(issuer_cert: &X509, issuer_pkey: &PrivateKey, revoked_certs: &[X509], crl_number: u32, next_update_days: u32) -> Result<X509Crl, String> {
+        let params = CrlParams {
+            issuer_cert: issuer_cert.der().to_vec(),
+            last_update: Utc::now(),
+            next_update: Utc::now() + Duration::days(next_update_days as i64),
+            crl_number,
+            revoked_serial_numbers: revoked_certs.iter().map(|cert| cert.info().serial_number.clone()).collect(),
+            hash_algorithm: CertificateStore::signature_digest(issuer_pkey),
         };
+        let crl = provider().create_crl(&params, issuer_pkey.der())
+            .map_err(|_| "Cannot make the revocation list, does the CA cert have a subject key identifier?".to_string())?;
+        X509Crl::from_der(&crl).map_err(|_| "Cannot read the revocation list".to_string())
+    }
 
-        Ok((X509::wrap(cert), PrivateKey::wrap_private_key(pkey)))
+    /// Makes the subject alt names of an application instance certificate. The first alt host name
+    /// is the application uri, the remainder are IP addresses or DNS names.
+    fn subject_alternative_name(alt_host_names: &[String]) -> Vec<AltName> {
+        alt_host_names.iter().enumerate().map(|(i, alt_host_name)| {
+            if i == 0 {
+                AltName::Uri(alt_host_name.clone())
+            } else if let Ok(ip_address) = alt_host_name.parse::<IpAddr>() {
+                match ip_address {
+                    IpAddr::V4(ip_address) => AltName::IpAddress(ip_address.octets().to_vec()),
+                    IpAddr::V6(ip_address) => AltName::IpAddress(ip_address.octets().to_vec()),
+                }
+            } else {
+                AltName::Dns(alt_host_name.clone())
+            }
+        }).collect()
+    }
+
+    /// Makes a random 128-bit serial number for a cert
+    fn random_serial_number() -> Vec<u8> {
+        let mut serial = vec![0u8; 16];
+        provider().random_bytes(&mut serial);
+        serial
+    }
+
+    /// Reads a cert from its DER form
+    fn cert_from_der(der: &[u8]) -> Result<X509, String> {
+        X509::from_der(der).map_err(|_| "Cannot read the cert that was created".to_string())
     }
 
     /// Reads a private key from a path on disk disk
@@ -188,8 +426,8 @@ impl CertificateStore {
                 let mut buffer = Vec::with_capacity(pkey_info.len() as usize);
                 let _ = f.read_to_end(&mut buffer);
                 drop(f);
-                if let Ok(pkey) = pkey::PKey::private_key_from_pem(&buffer) {
-                    return Ok(PrivateKey::wrap_private_key(pkey));
+                if let Ok(pkey) = PrivateKey::from_pem(&buffer) {
+                    return Ok(pkey);
                 }
             }
         }
@@ -217,7 +455,13 @@ impl CertificateStore {
     pub fn create_and_store_application_instance_cert(&self, args: &X509Data, overwrite: bool) -> Result<(X509, PrivateKey), String> {
         // Create the cert and corresponding private key
         let (cert, pkey) = CertificateStore::create_cert_and_pkey(args)?;
+        self.store_own_cert_and_pkey(&cert, &pkey, overwrite)?;
+        Ok((cert, pkey))
+    }
 
+    /// Writes the cert and private key as the store's own application instance certificate and
+    /// private key.
+    pub fn store_own_cert_and_pkey(&self, cert: &X509, pkey: &PrivateKey, overwrite: bool) -> Result<(), String> {
         // Public cert goes under own/
         let public_cert_path = CertificateStore::make_and_ensure_file_path(&self.own_cert_dir(), OWN_CERTIFICATE_NAME)?;
         // Private key goes under private/
@@ -229,9 +473,7 @@ impl CertificateStore {
         // Write the private key
         let pem = pkey.private_key_to_pem().unwrap();
         info!("Writing private key to {}", private_key_path.display());
-        CertificateStore::write_to_file(&pem, &private_key_path, overwrite)?;
-
-        Ok((cert, pkey))
+        CertificateStore::write_to_file(&pem, &private_key_path, overwrite)
     }
 
     /// Validates the cert and if its unknown, writes the value to the rejected folder so it can
@@ -281,10 +523,14 @@ impl CertificateStore {
         }
     }
 
-    /// Validates the certificate according to the strictness set in the CertificateStore itself.
-    /// Validation might include checking the issue time, expiration time, revocation, trust chain
-    /// etc. In the first instance this function will only check if the cert is recognized
-    /// and is already contained in the trusted or rejected folder.
+    /// Validates the certificate according to the strictness set in the CertificateStore itself,
+    /// following the order of checks in OPC UA Part 4 Table 106.
+    ///
+    /// The chain of issuers is built from the CA certs in the trusted and issuers folders up to a
+    /// self-signed root. The cert is trusted if it, or any CA in its chain, is in the trusted folder.
+    /// Every cert in the chain must be within its validity period and, other than the root, must
+    /// not be revoked by a revocation list of its issuer held in either `crl` folder. A CA without
+    /// a revocation list is treated as an unknown revocation status.
     ///
     /// # Errors
     ///
@@ -321,9 +567,22 @@ impl CertificateStore {
             }
             cert_path.push(&cert_file_name);
 
-            // Check if cert is in the trusted folder
-            if !cert_path.exists() {
-                // ... trust checks based on ca could be added here to add cert straight to trust folder
+            // Build the chain from the cert to its root CA. Each link is the cert and whether it
+            // came from the trusted folder.
+            let trusted_certs = CertificateStore::read_certs(&self.trusted_certs_dir());
+            let issuer_certs = CertificateStore::read_certs(&self.issuer_certs_dir());
+            let chain = match CertificateStore::build_chain(cert, &trusted_certs, &issuer_certs) {
+                Ok(chain) => chain,
+                Err(status_code) => {
+                    return status_code;
+                }
+            };
+            let issuer_is_trusted = chain.iter().skip(1).any(|&(_, trusted)| trusted);
+
+            // Check if cert is in the trusted folder, or was issued by a trusted CA
+            if issuer_is_trusted {
+                debug!("Certificate {} is trusted because a CA in its chain is trusted", cert_file_name);
+            } else if !cert_path.exists() {
                 if self.trust_unknown_certs {
                     // Put the unknown cert into the trusted folder
                     warn!("Certificate {} is unknown but policy will store it into the trusted directory", cert_file_name);
@@ -337,7 +596,7 @@ impl CertificateStore {
             }
 
             // Read the cert from the trusted folder to make sure it matches the one supplied
-            if !CertificateStore::ensure_cert_and_file_are_the_same(cert, &cert_path) {
+            if !issuer_is_trusted && !CertificateStore::ensure_cert_and_file_are_the_same(cert, &cert_path) {
                 error!("Certificate in memory does not match the one on disk {} so cert will automatically be treated as untrusted", cert_path.display());
                 return BadUnexpectedError;
             }
@@ -350,6 +609,14 @@ impl CertificateStore {
                 if status_code.is_bad() {
                     return status_code;
                 }
+                for &(ref issuer, _) in chain.iter().skip(1) {
+                    let status_code = issuer.is_time_valid(&now);
+                    if status_code == BadCertificateTimeInvalid {
+                        return BadCertificateIssuerTimeInvalid;
+                    } else if status_code.is_bad() {
+                        return status_code;
+                    }
+                }
             }
 
             // Compare the hostname of the cert against the cert supplied
@@ -368,14 +635,99 @@ impl CertificateStore {
                 }
             }
 
-            // Other tests that we might do with trust lists
-            // ... issuer
-            // ... trust (self-signed, ca etc.)
-            // ... revocation
+            // Check each cert against the revocation lists of its issuer
+            if chain.len() > 1 {
+                let mut crls = CertificateStore::read_crls(&self.trusted_crl_dir());
+                crls.append(&mut CertificateStore::read_crls(&self.issuer_crl_dir()));
+                let status_code = CertificateStore::check_revocation(&chain, &crls);
+                if status_code.is_bad() {
+                    return status_code;
+                }
+            }
         }
         Good
     }
 
+    /// Builds the chain of certs from the cert up to a self-signed root, taking issuers from the
+    /// trusted and issuer certs. Each link is the cert and whether it is trusted. Every issuer must
+    /// be a CA, and the number of intermediate CAs below it must be within its path length
+    /// constraint.
+    ///
+    /// # Errors
+    ///
+    /// `BadCertificateChainIncomplete` if the issuer of a cert in the chain cannot be found.
+    /// `BadCertificateIssuerUseNotAllowed` if a CA has more intermediate CAs below it than its
+    /// path length constraint allows.
+    ///
+    fn build_chain(cert: &X509, trusted_certs: &[X509], issuer_certs: &[X509]) -> Result<Vec<(X509, bool)>, StatusCode> {
+        let mut chain = vec![(cert.clone(), false)];
+        while chain.len() <= MAX_CHAIN_LENGTH {
+            let issuer = {
+                let subject = &chain.last().unwrap().0;
+                if subject.is_self_signed() {
+                    return Ok(chain);
+                }
+                let in_chain = |c: &X509| chain.iter().any(|&(ref link, _)| link.thumbprint().value == c.thumbprint().value);
+                let trusted_issuer = trusted_certs.iter().find(|c| !in_chain(c) && c.is_issuer_of(subject)).map(|c| (c.clone(), true));
+                trusted_issuer.or_else(|| issuer_certs.iter().find(|c| !in_chain(c) && c.is_issuer_of(subject)).map(|c| (c.clone(), false)))
+            };
+            if let Some(issuer) = issuer {
+                // The chain below the issuer is the leaf and the intermediate CAs
+                let intermediates = chain.len() - 1;
+                if let Some(path_len_constraint) = issuer.0.info().path_len_constraint {
+                    if intermediates > path_len_constraint as usize {
+                        error!("Certificate {} allows {} intermediate CAs below it but the chain has {}", CertificateStore::cert_file_name(&issuer.0), path_len_constraint, intermediates);
+                        return Err(BadCertificateIssuerUseNotAllowed);
+                    }
+                }
+                chain.push(issuer);
+            } else {
+                error!("The issuer of certificate {} cannot be found in the trusted or issuers directories", CertificateStore::cert_file_name(&chain.last().unwrap().0));
+                return Err(BadCertificateChainIncomplete);
+            }
+        }
+        error!("Certificate chain is longer than {} certificates", MAX_CHAIN_LENGTH);
+        Err(BadCertificateChainIncomplete)
+    }
+
+    /// Checks every cert in the chain except the root against the revocation lists signed by its
+    /// issuer. A list that is past its next update is treated as missing, since it cannot show a
+    /// revocation made after it was issued.
+    fn check_revocation(chain: &[(X509, bool)], crls: &[X509Crl]) -> StatusCode {
+        let now = Utc::now();
+        for (idx, link) in chain.windows(2).enumerate() {
+            let (ref cert, _) = link[0];
+            let (ref issuer, _) = link[1];
+            let issuer_crls = crls.iter().filter(|crl| crl.is_issued_by(issuer)).collect::<Vec<&X509Crl>>();
+            let current_crls = issuer_crls.iter().filter(|crl| crl.is_current(&now)).collect::<Vec<_>>();
+            if current_crls.is_empty() {
+                if issuer_crls.is_empty() {
+                    error!("There is no revocation list for CA {}", CertificateStore::cert_file_name(issuer));
+                } else {
+                    error!("The revocation lists of CA {} are out of date", CertificateStore::cert_file_name(issuer));
+                }
+                return if idx == 0 { BadCertificateRevocationUnknown } else { BadCertificateIssuerRevocationUnknown };
+            }
+            if issuer_crls.iter().any(|crl| crl.is_revoked(cert)) {
+                error!("Certificate {} has been revoked by its issuer", CertificateStore::cert_file_name(cert));
+                return if idx == 0 { BadCertificateRevoked } else { BadCertificateIssuerRevoked };
+            }
+        }
+        Good
+    }
+
+    /// Makes a file name for a revocation list from the SHA1 digest of its DER form, e.g.
+    /// "[digest].crl"
+    fn crl_file_name(der: &[u8]) -> String {
+        let digest = provider().hash(HashAlgorithm::Sha1, der);
+        let mut file_name = String::with_capacity(digest.len() * 2 + 4);
+        for b in digest.iter() {
+            file_name.push_str(&format!("{:02x}", b));
+        }
+        file_name.push_str(".crl");
+        file_name
+    }
+
     /// Returns a certificate file name from the cert's issuer and thumbprint fields
     pub fn cert_file_name(cert: &X509) -> String {
         let mut file_name = String::with_capacity(128);
@@ -409,13 +761,14 @@ impl CertificateStore {
     ///
     pub fn ensure_pki_path(&self) -> Result<(), String> {
         let mut path = self.pki_path.clone();
-        let subdirs = [OWN_CERTIFICATE_DIR, OWN_PRIVATE_KEY_DIR, TRUSTED_CERTS_DIR, REJECTED_CERTS_DIR];
+        let subdirs = [OWN_CERTIFICATE_DIR, OWN_PRIVATE_KEY_DIR, TRUSTED_CERTS_DIR, REJECTED_CERTS_DIR, ISSUER_CERTS_DIR];
         for subdir in &subdirs {
             path.push(subdir);
             CertificateStore::ensure_dir(&path)?;
             path.pop();
         }
-        Ok(())
+        CertificateStore::ensure_dir(&self.trusted_crl_dir())?;
+        CertificateStore::ensure_dir(&self.issuer_crl_dir())
     }
 
     /// Ensure the directory exists, creating it if necessary
@@ -481,6 +834,27 @@ impl CertificateStore {
         path
     }
 
+    /// Get the path to the issuer certs dir
+    pub fn issuer_certs_dir(&self) -> PathBuf {
+        let mut path = PathBuf::from(&self.pki_path);
+        path.push(ISSUER_CERTS_DIR);
+        path
+    }
+
+    /// Get the path to the revocation lists of trusted CAs
+    pub fn trusted_crl_dir(&self) -> PathBuf {
+        let mut path = self.trusted_certs_dir();
+        path.push(CRL_DIR);
+        path
+    }
+
+    /// Get the path to the revocation lists of issuer CAs
+    pub fn issuer_crl_dir(&self) -> PathBuf {
+        let mut path = self.issuer_certs_dir();
+        path.push(CRL_DIR);
+        path
+    }
+
     /// Write a cert to the rejected directory. If the write succeeds, the function
     /// returns a path to the written file.
     ///
@@ -504,7 +878,7 @@ impl CertificateStore {
     ///
     /// A string description of any failure
     ///
-    fn store_trusted_cert(&self, cert: &X509) -> Result<PathBuf, String> {
+    pub fn store_trusted_cert(&self, cert: &X509) -> Result<PathBuf, String> {
         // Store the cert in the rejected folder where untrusted certs go
         let cert_file_name = CertificateStore::cert_file_name(&cert);
         let mut cert_path = self.trusted_certs_dir();
@@ -513,6 +887,139 @@ impl CertificateStore {
         Ok(cert_path)
     }
 
+    /// Writes a CA cert to the issuers directory. If the write succeeds, the function
+    /// returns a path to the written file.
+    ///
+    /// # Errors
+    ///
+    /// A string description of any failure
+    ///
+    pub fn store_issuer_cert(&self, cert: &X509) -> Result<PathBuf, String> {
+        let cert_file_name = CertificateStore::cert_file_name(&cert);
+        let mut cert_path = self.issuer_certs_dir();
+        cert_path.push(&cert_file_name);
+        CertificateStore::store_cert(cert, &cert_path, true)?;
+        Ok(cert_path)
+    }
+
+    /// Removes the cert with the thumbprint, as a hexadecimal string, from the trusted or issuers
+    /// directory. Returns true if a cert was removed.
+    pub fn remove_cert(&self, thumbprint: &str, trusted: bool) -> bool {
+        let dir = if trusted { self.trusted_certs_dir() } else { self.issuer_certs_dir() };
+        let mut removed = false;
+        for path in CertificateStore::files_in_dir(&dir) {
+            if let Ok(cert) = CertificateStore::read_cert(&path) {
+                if cert.thumbprint().as_hex_string().eq_ignore_ascii_case(thumbprint) {
+                    info!("Removing X509 cert {}", path.display());
+                    if remove_file(&path).is_ok() {
+                        removed = true;
+                    } else {
+                        error!("Could not remove cert file {}", path.display());
+                    }
+                }
+            }
+        }
+        removed
+    }
+
+    /// Returns the certs in the rejected directory
+    pub fn rejected_certs(&self) -> Vec<X509> {
+        CertificateStore::read_certs(&self.rejected_certs_dir())
+    }
+
+    /// Reads the lists of trusted and issuer certs and revocation lists specified by the mask
+    /// into a trust list. The certs and revocation lists are in DER form.
+    pub fn read_trust_list(&self, specified_lists: UInt32) -> TrustListDataType {
+        let read_files = |dir: PathBuf| -> Option<Vec<ByteString>> {
+            Some(CertificateStore::files_in_dir(&dir).iter().filter_map(|path| {
+                let mut data = Vec::new();
+                if let Ok(mut file) = File::open(path) {
+                    if file.read_to_end(&mut data).is_ok() {
+                        return Some(data);
+                    }
+                }
+                warn!("Could not read {}", path.display());
+                None
+            }).map(|data| ByteString::from(&data)).collect())
+        };
+        let specified_lists = specified_lists & TRUST_LIST_ALL;
+        TrustListDataType {
+            specified_lists,
+            trusted_certificates: if specified_lists & TRUST_LIST_TRUSTED_CERTIFICATES != 0 { read_files(self.trusted_certs_dir()) } else { None },
+            trusted_crls: if specified_lists & TRUST_LIST_TRUSTED_CRLS != 0 { read_files(self.trusted_crl_dir()) } else { None },
+            issuer_certificates: if specified_lists & TRUST_LIST_ISSUER_CERTIFICATES != 0 { read_files(self.issuer_certs_dir()) } else { None },
+            issuer_crls: if specified_lists & TRUST_LIST_ISSUER_CRLS != 0 { read_files(self.issuer_crl_dir()) } else { None },
+        }
+    }
+
+    /// Replaces the contents of each list specified in the trust list with the certs and
+    /// revocation lists it holds. Nothing is written unless every entry can be read.
+    ///
+    /// # Errors
+    ///
+    /// `BadCertificateInvalid` if an entry is not a DER cert or revocation list, or
+    /// `BadUnexpectedError` if the store cannot be written
+    ///
+    pub fn update_trust_list(&self, trust_list: &TrustListDataType) -> Result<(), StatusCode> {
+        let specified_lists = trust_list.specified_lists;
+        let parse_certs = |mask: UInt32, certs: &Option<Vec<ByteString>>| -> Result<Option<Vec<X509>>, StatusCode> {
+            if specified_lists & mask == 0 {
+                Ok(None)
+            } else if let Some(ref certs) = *certs {
+                certs.iter().map(|c| X509::from_byte_string(c)).collect::<Result<Vec<X509>, StatusCode>>().map(Some)
+            } else {
+                Ok(Some(Vec::new()))
+            }
+        };
+        let parse_crls = |mask: UInt32, crls: &Option<Vec<ByteString>>| -> Result<Option<Vec<Vec<u8>>>, StatusCode> {
+            if specified_lists & mask == 0 {
+                Ok(None)
+            } else if let Some(ref crls) = *crls {
+                crls.iter().map(|c| {
+                    let der = c.value.clone().unwrap_or(Vec::new());
+                    if X509Crl::from_der_or_pem(&der).is_ok() { Ok(der) } else { Err(BadCertificateInvalid) }
+                }).collect::<Result<Vec<Vec<u8>>, StatusCode>>().map(Some)
+            } else {
+                Ok(Some(Vec::new()))
+            }
+        };
+        let trusted_certificates = parse_certs(TRUST_LIST_TRUSTED_CERTIFICATES, &trust_list.trusted_certificates)?;
+        let trusted_crls = parse_crls(TRUST_LIST_TRUSTED_CRLS, &trust_list.trusted_crls)?;
+        let issuer_certificates = parse_certs(TRUST_LIST_ISSUER_CERTIFICATES, &trust_list.issuer_certificates)?;
+        let issuer_crls = parse_crls(TRUST_LIST_ISSUER_CRLS, &trust_list.issuer_crls)?;
+
+        let write_certs = |dir: PathBuf, certs: Option<Vec<X509>>| -> Result<(), String> {
+            if let Some(certs) = certs {
+                CertificateStore::remove_files_in_dir(&dir)?;
+                for cert in certs {
+                    let mut cert_path = dir.clone();
+                    cert_path.push(CertificateStore::cert_file_name(&cert));
+                    CertificateStore::store_cert(&cert, &cert_path, true)?;
+                }
+            }
+            Ok(())
+        };
+        let write_crls = |dir: PathBuf, crls: Option<Vec<Vec<u8>>>| -> Result<(), String> {
+            if let Some(crls) = crls {
+                CertificateStore::remove_files_in_dir(&dir)?;
+                for crl in crls {
+                    let mut crl_path = dir.clone();
+                    crl_path.push(CertificateStore::crl_file_name(&crl));
+                    CertificateStore::write_to_file(&crl, &crl_path, true)?;
+                }
+            }
+            Ok(())
+        };
+        write_certs(self.trusted_certs_dir(), trusted_certificates)
+            .and_then(|_| write_crls(self.trusted_crl_dir(), trusted_crls))
+            .and_then(|_| write_certs(self.issuer_certs_dir(), issuer_certificates))
+            .and_then(|_| write_crls(self.issuer_crl_dir(), issuer_crls))
+            .map_err(|err| {
+                error!("Trust list could not be updated, error = {}", err);
+                BadUnexpectedError
+            })
+    }
+
     /// Writes a cert to the specified directory
     ///
     /// # Errors
@@ -531,7 +1038,7 @@ impl CertificateStore {
     ///
     /// A string description of any failure
     ///
-    fn read_cert(path: &Path) -> Result<X509, String> {
+    pub fn read_cert(path: &Path) -> Result<X509, String> {
         let file = File::open(path);
         if file.is_err() {
             return Err(format!("Could not open cert file {}", path.display()));
@@ -544,12 +1051,51 @@ impl CertificateStore {
             return Err(format!("Could not read bytes from cert file {}", path.display()));
         }
 
-        let cert = x509::X509::from_der(&cert);
+        let cert = X509::from_der(&cert);
         if cert.is_err() {
             return Err(format!("Could not read cert from cert file {}", path.display()));
         }
 
-        Ok(X509::wrap(cert.unwrap()))
+        Ok(cert.unwrap())
+    }
+
+    /// Reads every cert from the files in a directory, skipping anything that is not a DER cert
+    fn read_certs(dir: &Path) -> Vec<X509> {
+        CertificateStore::files_in_dir(dir).iter().filter_map(|path| CertificateStore::read_cert(path).ok()).collect()
+    }
+
+    /// Reads every revocation list from the files in a directory, skipping anything that is not a
+    /// DER or PEM revocation list
+    fn read_crls(dir: &Path) -> Vec<X509Crl> {
+        CertificateStore::files_in_dir(dir).iter().filter_map(|path| {
+            let mut data = Vec::new();
+            if let Ok(mut file) = File::open(path) {
+                if file.read_to_end(&mut data).is_ok() {
+                    return X509Crl::from_der_or_pem(&data).ok();
+                }
+            }
+            warn!("Could not read revocation list from {}", path.display());
+            None
+        }).collect()
+    }
+
+    /// Removes the files, but not the directories, in a directory
+    fn remove_files_in_dir(dir: &Path) -> Result<(), String> {
+        for path in CertificateStore::files_in_dir(dir) {
+            if remove_file(&path).is_err() {
+                return Err(format!("Could not remove file {}", path.display()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists the files, but not the directories, in a directory
+    fn files_in_dir(dir: &Path) -> Vec<PathBuf> {
+        if let Ok(entries) = read_dir(dir) {
+            entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.is_file()).collect()
+        } else {
+            Vec::new()
+        }
     }
 
     /// Makes a path
@@ -0,0 +1,78 @@
+//! Elliptic curve Diffie-Hellman key agreement for the ECC security policies. Each side of the
+//! secure channel creates an ephemeral key pair and sends the public key as its nonce. The shared
+//! secret is derived from our private key and their public key and the channel keys from that.
+use std;
+use std::fmt::{Debug, Formatter};
+use std::result::Result;
+
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use crypto::SecurityPolicy;
+use crypto::provider::{provider, EcCurve};
+
+/// An ephemeral EC key pair on the curve of an ECC security policy
+pub struct EphemeralKey {
+    security_policy: SecurityPolicy,
+    /// The private key in PKCS #8 DER form
+    value: Vec<u8>,
+}
+
+impl Debug for EphemeralKey {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // This impl will not write out the key, but it exists to keep structs happy
+        // that contain a key as a field
+        write!(f, "[ephemeral key {:?}]", self.security_policy)
+    }
+}
+
+impl EphemeralKey {
+    /// Creates a new key pair on the curve used by the security policy
+    pub fn new(security_policy: SecurityPolicy) -> Result<EphemeralKey, StatusCode> {
+        let curve = Self::curve(security_policy)?;
+        let value = provider().generate_ec_key(curve)?;
+        Ok(EphemeralKey { security_policy, value })
+    }
+
+    /// Returns the public key as the X and Y coordinates concatenated, which is how it is sent in a nonce
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        provider().ec_public_key_bytes(&self.value).unwrap()
+    }
+
+    /// Tests if the bytes are a public key in the form of X and Y coordinates which is a point on
+    /// the curve of the security policy
+    pub fn is_valid_public_key(security_policy: SecurityPolicy, public_key: &[u8]) -> bool {
+        Self::check_public_key(security_policy, public_key).is_ok()
+    }
+
+    /// Computes the secret shared with the other side from our private key and their public key
+    pub fn shared_secret(&self, remote_public_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let curve = Self::check_public_key(self.security_policy, remote_public_key)?;
+        provider().ecdh_shared_secret(&self.value, curve, remote_public_key)
+    }
+
+    fn curve(security_policy: SecurityPolicy) -> Result<EcCurve, StatusCode> {
+        match security_policy {
+            SecurityPolicy::EccNistP256 => Ok(EcCurve::NistP256),
+            SecurityPolicy::EccNistP384 => Ok(EcCurve::NistP384),
+            _ => {
+                error!("Security policy {:?} does not use ephemeral keys", security_policy);
+                Err(BadSecurityPolicyRejected)
+            }
+        }
+    }
+
+    fn check_public_key(security_policy: SecurityPolicy, public_key: &[u8]) -> Result<EcCurve, StatusCode> {
+        if public_key.len() != security_policy.secure_channel_nonce_length() {
+            error!("Public key is {} bytes but the security policy requires {}", public_key.len(), security_policy.secure_channel_nonce_length());
+            return Err(BadNonceInvalid);
+        }
+        let curve = Self::curve(security_policy)?;
+        if provider().ec_is_valid_public_key(curve, public_key) {
+            Ok(curve)
+        } else {
+            error!("Public key is not a point on the curve");
+            Err(BadNonceInvalid)
+        }
+    }
+}
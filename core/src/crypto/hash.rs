@@ -2,67 +2,70 @@
 
 use std::result::Result;
 
-use openssl::pkey;
-use openssl::sign;
-use openssl::hash;
-
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 
-use crypto::{SHA1_SIZE, SHA256_SIZE};
+use crypto::{SHA1_SIZE, SHA256_SIZE, SHA384_SIZE};
+use crypto::provider::provider;
 
-/// Pseudo random `P_SHA` implementation for creating pseudo random range of bytes from an input
-///
-/// https://www.ietf.org/rfc/rfc4346.txt
-/// https://tools.ietf.org/html/rfc5246
-///
-/// P_SHA1(secret, seed) = HMAC_SHA1(secret, A(1) + seed) +
-///                        HMAC_SHA1(secret, A(2) + seed) +
-///                        HMAC_SHA1 (secret, A(3) + seed) + ...
-///
-/// Where A(n) is defined as:
-///   A(0) = seed
-///   A(n) = HMAC_SHA1(secret, A(n-1))
-/// + indicates that the results are appended to previous results.
-pub fn p_sha(message_digest: hash::MessageDigest, secret: &[u8], seed: &[u8], length: usize) -> Vec<u8> {
-    let mut result = Vec::with_capacity(length);
+/// The digest algorithms used for signing, HMAC and key derivation
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+}
 
-    let mut hmac = Vec::with_capacity(seed.len() * 2);
+impl HashAlgorithm {
+    /// The size of a digest in bytes
+    pub fn size(&self) -> usize {
+        match *self {
+            HashAlgorithm::Sha1 => SHA1_SIZE,
+            HashAlgorithm::Sha256 => SHA256_SIZE,
+            HashAlgorithm::Sha384 => SHA384_SIZE,
+        }
+    }
+}
 
-    let mut a_last = Vec::with_capacity(seed.len());
-    a_last.extend_from_slice(seed); // A(0) = seed
+/// Pseudo random `P_SHA` implementation for creating pseudo random range of bytes from an input.
+/// See `CryptoProvider::p_sha`.
+pub fn p_sha(hash_algorithm: HashAlgorithm, secret: &[u8], seed: &[u8], length: usize) -> Vec<u8> {
+    provider().p_sha(hash_algorithm, secret, seed, length)
+}
 
+/// HMAC-based extract-and-expand key derivation function (HKDF) for producing keying material
+/// from a shared secret. Used by the ECC policies to derive the secure channel keys.
+///
+/// https://tools.ietf.org/html/rfc5869
+///
+/// PRK = HMAC(salt, IKM)
+/// T(0) = empty string
+/// T(n) = HMAC(PRK, T(n-1) | info | n)
+/// OKM = first L bytes of T(1) | T(2) | T(3) | ...
+pub fn hkdf(hash_algorithm: HashAlgorithm, salt: &[u8], ikm: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    // Extract
+    let prk = provider().hmac(hash_algorithm, salt, ikm);
+
+    // Expand
+    let mut result = Vec::with_capacity(length);
+    let mut t = Vec::new();
+    let mut counter = 1u8;
     while result.len() < length {
-        // A(n) = HMAC_SHA1(secret, A(n-1))
-        let a_next = hmac_vec(message_digest, secret, &a_last);
-
-        // Append a slice of random data
-        let bytes = {
-            hmac.clear();
-            hmac.extend(&a_next);
-            hmac.extend_from_slice(seed);
-            hmac_vec(message_digest, secret, &hmac)
-        };
-        result.extend(&bytes);
-
-        a_last.clear();
-        a_last.extend(&a_next);
+        let mut input = Vec::with_capacity(t.len() + info.len() + 1);
+        input.extend_from_slice(&t);
+        input.extend_from_slice(info);
+        input.push(counter);
+        t = provider().hmac(hash_algorithm, &prk, &input);
+        result.extend(&t);
+        counter += 1;
     }
 
     result.truncate(length);
     result
 }
 
-fn hmac_vec(digest: hash::MessageDigest, key: &[u8], data: &[u8]) -> Vec<u8> {
-    // Compute a signature
-    let pkey = pkey::PKey::hmac(key).unwrap();
-    let mut signer = sign::Signer::new(digest, &pkey).unwrap();
-    signer.update(data).unwrap();
-    signer.sign_to_vec().unwrap()
-}
-
-fn hmac(digest: hash::MessageDigest, key: &[u8], data: &[u8], signature: &mut [u8]) -> Result<(), StatusCode> {
-    let hmac = hmac_vec(digest, key, data);
+fn hmac(hash_algorithm: HashAlgorithm, key: &[u8], data: &[u8], signature: &mut [u8]) -> Result<(), StatusCode> {
+    let hmac = provider().hmac(hash_algorithm, key, data);
     trace!("hmac length = {}", hmac.len());
     signature.copy_from_slice(&hmac);
     Ok(())
@@ -71,7 +74,7 @@ fn hmac(digest: hash::MessageDigest, key: &[u8], data: &[u8], signature: &mut [u
 pub fn hmac_sha1(key: &[u8], data: &[u8], signature: &mut [u8]) -> Result<(), StatusCode> {
     match signature.len() {
         SHA1_SIZE => {
-            hmac(hash::MessageDigest::sha1(), key, data, signature)
+            hmac(HashAlgorithm::Sha1, key, data, signature)
         }
         _ => {
             error!("Signature buffer length {} is not enough to receive hmac_sha1 signature", signature.len());
@@ -95,7 +98,7 @@ pub fn verify_hmac_sha1(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
 pub fn hmac_sha256(key: &[u8], data: &[u8], signature: &mut [u8]) -> Result<(), StatusCode> {
     match signature.len() {
         SHA256_SIZE => {
-            hmac(hash::MessageDigest::sha256(), key, data, signature)
+            hmac(HashAlgorithm::Sha256, key, data, signature)
         }
         _ => {
             error!("Signature buffer length {} is not enough to receive hmac_sha256 signature", signature.len());
@@ -113,3 +116,25 @@ pub fn verify_hmac_sha256(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
         signature == &tmp_signature[..]
     }
 }
+
+pub fn hmac_sha384(key: &[u8], data: &[u8], signature: &mut [u8]) -> Result<(), StatusCode> {
+    match signature.len() {
+        SHA384_SIZE => {
+            hmac(HashAlgorithm::Sha384, key, data, signature)
+        }
+        _ => {
+            error!("Signature buffer length {} is not enough to receive hmac_sha384 signature", signature.len());
+            Err(BadInvalidArgument)
+        }
+    }
+}
+
+/// Verify that the HMAC for the data block matches the supplied signature
+pub fn verify_hmac_sha384(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    let mut tmp_signature = vec![0u8; SHA384_SIZE];
+    if hmac_sha384(key, data, &mut tmp_signature).is_err() {
+        false
+    } else {
+        signature == &tmp_signature[..]
+    }
+}
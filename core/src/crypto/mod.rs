@@ -9,6 +9,8 @@ pub mod thumbprint;
 pub mod certificate_store;
 pub mod hash;
 pub mod security_policy;
+pub mod ecdh;
+pub mod provider;
 
 pub use self::x509::*;
 pub use self::aeskey::*;
@@ -17,6 +19,8 @@ pub use self::thumbprint::*;
 pub use self::certificate_store::*;
 pub use self::hash::*;
 pub use self::security_policy::*;
+pub use self::ecdh::*;
+pub use self::provider::{CryptoProvider, provider};
 
 use opcua_types::{UAString, ByteString};
 use opcua_types::service_types::SignatureData;
@@ -26,6 +30,8 @@ use opcua_types::status_codes::StatusCode;
 pub const SHA1_SIZE: usize = 20;
 // Size of a SHA256 hash value bytes
 pub const SHA256_SIZE: usize = 32;
+// Size of a SHA384 hash value bytes
+pub const SHA384_SIZE: usize = 48;
 
 /// These are algorithms that are used by various policies or external to this file
 pub mod algorithms {
@@ -44,6 +50,9 @@ pub mod algorithms {
     /// Asymmetric encryption algorithm RSA-OAEP-MGF1P
     pub const ENC_RSA_OAEP_MGF1P: &'static str = "http://www.w3.org/2001/04/xmlenc#rsa-oaep-mgf1p";
 
+    /// Asymmetric encryption algorithm RSA-OAEP with SHA256 as digest and mask generation function
+    pub const ENC_RSA_OAEP_SHA256: &'static str = "http://opcfoundation.org/UA/security/rsa-oaep-sha2-256";
+
     /// SymmetricSignatureAlgorithm – HmacSha1 – (http://www.w3.org/2000/09/xmldsig#hmac-sha1).
     pub const DSIG_HMAC_SHA1: &'static str = "http://www.w3.org/2000/09/xmldsig#hmac-sha1";
 
@@ -56,11 +65,29 @@ pub mod algorithms {
     /// Asymmetric digital signature algorithm using RSA-SHA256
     pub const DSIG_RSA_SHA256: &'static str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
 
+    /// Asymmetric digital signature algorithm using RSA-PSS-SHA256
+    pub const DSIG_RSA_PSS_SHA256: &'static str = "http://opcfoundation.org/UA/security/rsa-pss-sha2-256";
+
+    /// SymmetricSignatureAlgorithm – HmacSha384 – (http://www.w3.org/2001/04/xmldsig-more#hmac-sha384).
+    pub const DSIG_HMAC_SHA384: &'static str = "http://www.w3.org/2001/04/xmldsig-more#hmac-sha384";
+
+    /// Asymmetric digital signature algorithm using ECDSA-SHA256
+    pub const DSIG_ECDSA_SHA256: &'static str = "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256";
+
+    /// Asymmetric digital signature algorithm using ECDSA-SHA384
+    pub const DSIG_ECDSA_SHA384: &'static str = "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha384";
+
     /// Key derivation algorithm P_SHA1
     pub const KEY_P_SHA1: &'static str = "http://docs.oasis-open.org/ws-sx/ws-secureconversation/200512/dk/p_sha1";
 
     /// Key derivation algorithm P_SHA256
     pub const KEY_P_SHA256: &'static str = "http://docs.oasis-open.org/ws-sx/ws-secureconversation/200512/dk/p_sha256";
+
+    /// Key derivation algorithm HKDF with SHA256
+    pub const KEY_HKDF_SHA256: &'static str = "http://opcfoundation.org/UA/security/hkdf-sha256";
+
+    /// Key derivation algorithm HKDF with SHA384
+    pub const KEY_HKDF_SHA384: &'static str = "http://opcfoundation.org/UA/security/hkdf-sha384";
 }
 
 pub fn concat_data_and_nonce(data: &[u8], nonce: &[u8]) -> Vec<u8> {
@@ -78,9 +105,11 @@ pub fn create_signature_data(signing_key: &PrivateKey, security_policy: Security
         let data = concat_data_and_nonce(contained_cert.as_ref(), nonce.as_ref());
         // Sign the bytes and return the algorithm, signature
         match security_policy {
-            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => {
-                let signing_key_size = signing_key.size();
-                let mut signature = vec![0u8; signing_key_size];
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 |
+            SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 | SecurityPolicy::EccNistP384 => {
+                let signature_size = signing_key.signature_size();
+                let mut signature = vec![0u8; signature_size];
                 let _ = security_policy.asymmetric_sign(signing_key, &data, &mut signature)?;
                 (
                     UAString::from(security_policy.asymmetric_signature_algorithm()),
@@ -1,42 +1,53 @@
 //! Asymmetric encryption / decryption, signing / verification wrapper.
 use std;
-use std::marker::Send;
+use std::marker::PhantomData;
 use std::fmt::{Debug, Formatter};
 use std::result::Result;
 
-use openssl::pkey;
-use openssl::rsa;
-use openssl::sign;
-use openssl::hash;
-
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 
-#[derive(Copy, Clone)]
+use crypto::hash::HashAlgorithm;
+use crypto::provider::{provider, EcCurve, KeyInfo};
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum RsaPadding {
     PKCS1,
     OAEP,
+    /// OAEP with SHA256 as the digest and mask generation function
+    OAEP_SHA256,
+    /// PSS, which is only used for signing
+    PSS,
 }
 
-impl Into<rsa::Padding> for RsaPadding {
-    fn into(self) -> rsa::Padding {
-        match self {
-            RsaPadding::PKCS1 => rsa::Padding::PKCS1,
-            RsaPadding::OAEP => rsa::Padding::PKCS1_OAEP,
-        }
-    }
+/// The kind of asymmetric key pair. The RSA security policies use RSA keys, the ECC security
+/// policies use EC keys on the curve of the policy.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum KeyType {
+    Rsa,
+    Ecc,
 }
 
-/// This is a wrapper around an `OpenSSL` asymmetric key pair. Since openssl 0.10, the PKey is either
-/// a public or private key so we have to differentiate that as well.
+/// Marks a `PKey` as a public key
+pub enum Public {}
+
+/// Marks a `PKey` as a private key
+pub enum Private {}
+
+/// This is a wrapper around an asymmetric key that is either a public or a private key. The key is
+/// held in DER form, PKCS #8 for a private key and SubjectPublicKeyInfo for a public key, and the
+/// crypto provider does the work.
 pub struct PKey<T> {
-    value: pkey::PKey<T>,
+    value: Vec<u8>,
+    info: KeyInfo,
+    _key: PhantomData<T>,
 }
 
 /// A public key
-pub type PublicKey = PKey<pkey::Public>;
+pub type PublicKey = PKey<Public>;
 // A private key
-pub type PrivateKey = PKey<pkey::Private>;
+pub type PrivateKey = PKey<Private>;
 
 impl<T> Debug for PKey<T> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
@@ -46,13 +57,59 @@ impl<T> Debug for PKey<T> {
     }
 }
 
-unsafe impl<T> Send for PKey<T> {}
+impl<T> Clone for PKey<T> {
+    fn clone(&self) -> Self {
+        PKey { value: self.value.clone(), info: self.info.clone(), _key: PhantomData }
+    }
+}
+
+impl<T> PKey<T> {
+    /// Returns the key in DER form so the other crypto types can pass it to the provider
+    pub(crate) fn der(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Returns the type of key
+    pub fn key_type(&self) -> KeyType {
+        self.info.key_type
+    }
+
+    /// Returns the public key, or for a private key the public key of the pair, in DER form as a
+    /// SubjectPublicKeyInfo
+    pub fn public_key_to_der(&self) -> Vec<u8> {
+        self.info.public_key.clone()
+    }
+
+    /// Returns the curve of an EC key
+    fn curve(&self) -> Result<EcCurve, StatusCode> {
+        if self.info.key_type == KeyType::Ecc {
+            if let Some(curve) = EcCurve::from_bit_length(self.info.bit_length as u32) {
+                return Ok(curve);
+            }
+        }
+        error!("Key is not an EC key on a supported curve");
+        Err(BadUnexpectedError)
+    }
+}
 
 pub trait KeySize {
     fn bit_length(&self) -> usize;
 
+    /// Tests if the key is an EC key rather than an RSA key
+    fn is_ec(&self) -> bool;
+
     fn size(&self) -> usize { self.bit_length() / 8 }
 
+    /// Size of a signature produced by the key. An ECDSA signature is the r and s values each
+    /// padded to the size of a curve coordinate.
+    fn signature_size(&self) -> usize {
+        if self.is_ec() {
+            self.size() * 2
+        } else {
+            self.size()
+        }
+    }
+
     fn calculate_cipher_text_size(&self, data_size: usize, padding: RsaPadding) -> usize {
         let plain_text_block_size = self.plain_text_block_size(padding);
         let block_count = if data_size % plain_text_block_size == 0 {
@@ -69,10 +126,15 @@ pub trait KeySize {
         // based padding modes, less than RSA_size(rsa) - 41 for RSA_PKCS1_OAEP_PADDING and exactly
         // RSA_size(rsa) for RSA_NO_PADDING.
         //
-        // Note other RSA impls use 11 and 42 so this impl will too.
+        // Note other RSA impls use 11 and 42 so this impl will too. OAEP with SHA256 uses
+        // 2 * hash size + 2 = 66.
         match padding {
             RsaPadding::PKCS1 => self.size() - 11,
             RsaPadding::OAEP => self.size() - 42,
+            RsaPadding::OAEP_SHA256 => self.size() - 66,
+            RsaPadding::PSS => {
+                panic!("PSS padding is only for signing");
+            }
         }
     }
 
@@ -85,27 +147,52 @@ pub trait KeySize {
 impl KeySize for PrivateKey {
     /// Length in bits
     fn bit_length(&self) -> usize {
-        self.value.bits() as usize
+        self.info.bit_length
+    }
+
+    fn is_ec(&self) -> bool {
+        self.info.key_type == KeyType::Ecc
     }
 }
 
 impl PrivateKey {
     pub fn new(bit_length: u32) -> PrivateKey {
-        PKey {
-            value: {
-                let rsa = rsa::Rsa::generate(bit_length).unwrap();
-                pkey::PKey::from_rsa(rsa).unwrap()
-            },
-        }
+        let der = provider().generate_rsa_key(bit_length).unwrap();
+        PrivateKey::from_der(&der).unwrap()
+    }
+
+    /// Creates an EC key on the NIST curve of the specified size, i.e. 256 for nistP256 or 384 for nistP384
+    pub fn new_ecc(bit_length: u32) -> Result<PrivateKey, StatusCode> {
+        let curve = if let Some(curve) = EcCurve::from_bit_length(bit_length) {
+            curve
+        } else {
+            error!("There is no supported curve with a key size of {}", bit_length);
+            return Err(BadInvalidArgument);
+        };
+        let der = provider().generate_ec_key(curve)?;
+        PrivateKey::from_der(&der)
     }
 
-    pub fn wrap_private_key(pkey: pkey::PKey<pkey::Private>) -> PrivateKey {
-        PrivateKey { value: pkey }
+    /// Wraps an `OpenSSL` private key
+    #[cfg(feature = "openssl")]
+    pub fn wrap_private_key(pkey: ::openssl::pkey::PKey<::openssl::pkey::Private>) -> PrivateKey {
+        PrivateKey::from_der(&pkey.private_key_to_pkcs8().unwrap()).unwrap()
+    }
+
+    /// Reads a private key in PKCS #8 DER form
+    pub fn from_der(der: &[u8]) -> Result<PrivateKey, StatusCode> {
+        let info = provider().private_key_info(der)?;
+        Ok(PKey { value: der.to_vec(), info, _key: PhantomData })
+    }
+
+    /// Returns the private key in PKCS #8 DER form
+    pub fn private_key_to_der(&self) -> Vec<u8> {
+        self.value.clone()
     }
 
     pub fn from_pem(pem: &[u8]) -> Result<PrivateKey, ()> {
-        if let Ok(value) = pkey::PKey::private_key_from_pem(pem) {
-            Ok(PKey { value })
+        if let Ok(value) = provider().private_key_from_pem(pem).and_then(|der| PrivateKey::from_der(&der)) {
+            Ok(value)
         } else {
             error!("Cannot produce a private key from the data supplied");
             Err(())
@@ -113,7 +200,7 @@ impl PrivateKey {
     }
 
     pub fn private_key_to_pem(&self) -> Result<Vec<u8>, ()> {
-        if let Ok(pem) = self.value.private_key_to_pem_pkcs8() {
+        if let Ok(pem) = provider().private_key_to_pem(&self.value) {
             Ok(pem)
         } else {
             error!("Cannot turn private key to PEM");
@@ -122,31 +209,60 @@ impl PrivateKey {
     }
 
     /// Creates a message digest from the specified block of data and then signs it to return a signature
-    fn sign(&self, message_digest: hash::MessageDigest, data: &[u8], signature: &mut [u8], padding: RsaPadding) -> Result<usize, StatusCode> {
+    fn sign(&self, hash_algorithm: HashAlgorithm, data: &[u8], signature: &mut [u8], padding: RsaPadding) -> Result<usize, StatusCode> {
         trace!("RSA signing");
-        if let Ok(mut signer) = sign::Signer::new(message_digest, &self.value) {
-            signer.set_rsa_padding(padding.into()).unwrap();
-            if signer.update(data).is_ok() {
-                let result = signer.sign_to_vec();
-                if let Ok(result) = result {
-                    trace!("Signature result, len {} = {:?}, copying to signature len {}", result.len(), result, signature.len());
-                    signature.copy_from_slice(&result);
-                    return Ok(result.len());
-                } else {
-                    debug!("Can't sign data - error = {:?}", result.unwrap_err());
-                }
+        match provider().rsa_sign(&self.value, hash_algorithm, padding, data) {
+            Ok(result) => {
+                trace!("Signature result, len {} = {:?}, copying to signature len {}", result.len(), result, signature.len());
+                signature.copy_from_slice(&result);
+                Ok(result.len())
+            }
+            Err(err) => {
+                debug!("Can't sign data - error = {:?}", err);
+                Err(BadUnexpectedError)
             }
         }
-        Err(BadUnexpectedError)
     }
 
     /// Signs the data using RSA-SHA1
     pub fn sign_hmac_sha1(&self, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
-        self.sign(hash::MessageDigest::sha1(), data, signature, RsaPadding::PKCS1)
+        self.sign(HashAlgorithm::Sha1, data, signature, RsaPadding::PKCS1)
     }
     /// Signs the data using RSA-SHA256
     pub fn sign_hmac_sha256(&self, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
-        self.sign(hash::MessageDigest::sha256(), data, signature, RsaPadding::PKCS1)
+        self.sign(HashAlgorithm::Sha256, data, signature, RsaPadding::PKCS1)
+    }
+    /// Signs the data using RSA-PSS-SHA256
+    pub fn sign_sha256_pss(&self, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
+        self.sign(HashAlgorithm::Sha256, data, signature, RsaPadding::PSS)
+    }
+
+    /// Creates a message digest from the specified block of data and signs it with ECDSA. The
+    /// signature is the r and s values concatenated, each padded to the size of a curve coordinate.
+    fn sign_ecdsa(&self, hash_algorithm: HashAlgorithm, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
+        trace!("ECDSA signing");
+        let signature_size = self.signature_size();
+        if signature.len() < signature_size {
+            error!("Signature buffer length {} is too small for an ECDSA signature of {} bytes", signature.len(), signature_size);
+            return Err(BadInvalidArgument);
+        }
+        let _ = self.curve()?;
+        let result = provider().ecdsa_sign(&self.value, hash_algorithm, data).map_err(|err| {
+            debug!("Can't sign data - error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        signature[..signature_size].copy_from_slice(&result);
+        Ok(signature_size)
+    }
+
+    /// Signs the data using ECDSA-SHA256
+    pub fn sign_ecdsa_sha256(&self, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
+        self.sign_ecdsa(HashAlgorithm::Sha256, data, signature)
+    }
+
+    /// Signs the data using ECDSA-SHA384
+    pub fn sign_ecdsa_sha384(&self, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
+        self.sign_ecdsa(HashAlgorithm::Sha384, data, signature)
     }
 
     /// Decrypts data in src to dst using the specified padding and returning the size of the decrypted
@@ -154,8 +270,6 @@ impl PrivateKey {
     pub fn private_decrypt(&self, src: &[u8], dst: &mut [u8], padding: RsaPadding) -> Result<usize, ()> {
         // decrypt data using our private key
         let cipher_text_block_size = self.cipher_text_block_size();
-        let rsa = self.value.rsa().unwrap();
-        let padding: rsa::Padding = padding.into();
 
         // Decrypt the data
         let mut src_idx = 0;
@@ -163,7 +277,7 @@ impl PrivateKey {
         while src_idx < src.len() {
             let src = &src[src_idx..(src_idx + cipher_text_block_size)];
             let dst = &mut dst[dst_idx..(dst_idx + cipher_text_block_size)];
-            let decrypted_bytes = rsa.private_decrypt(src, dst, padding);
+            let decrypted_bytes = provider().rsa_private_decrypt(&self.value, padding, src, dst);
             if decrypted_bytes.is_err() {
                 error!("Decryption failed for key size {}, src idx {}, dst idx {} error - {:?}", cipher_text_block_size, src_idx, dst_idx, decrypted_bytes.unwrap_err());
                 return Err(());
@@ -178,41 +292,76 @@ impl PrivateKey {
 impl KeySize for PublicKey {
     /// Length in bits
     fn bit_length(&self) -> usize {
-        self.value.bits() as usize
+        self.info.bit_length
+    }
+
+    fn is_ec(&self) -> bool {
+        self.info.key_type == KeyType::Ecc
     }
 }
 
 impl PublicKey {
-    pub fn wrap_public_key(pkey: pkey::PKey<pkey::Public>) -> PublicKey {
-        PublicKey { value: pkey }
+    /// Wraps an `OpenSSL` public key
+    #[cfg(feature = "openssl")]
+    pub fn wrap_public_key(pkey: ::openssl::pkey::PKey<::openssl::pkey::Public>) -> PublicKey {
+        PublicKey::from_der(&pkey.public_key_to_der().unwrap()).unwrap()
+    }
+
+    /// Reads a public key in SubjectPublicKeyInfo DER form
+    pub fn from_der(der: &[u8]) -> Result<PublicKey, StatusCode> {
+        let info = provider().public_key_info(der)?;
+        Ok(PKey { value: der.to_vec(), info, _key: PhantomData })
     }
 
     /// Verifies that the signature matches the hash / signing key of the supplied data
-    fn verify(&self, message_digest: hash::MessageDigest, data: &[u8], signature: &[u8], padding: RsaPadding) -> Result<bool, StatusCode> {
+    fn verify(&self, hash_algorithm: HashAlgorithm, data: &[u8], signature: &[u8], padding: RsaPadding) -> Result<bool, StatusCode> {
         trace!("RSA verifying, against signature {:?}, len {}", signature, signature.len());
-        if let Ok(mut verifier) = sign::Verifier::new(message_digest, &self.value) {
-            verifier.set_rsa_padding(padding.into()).unwrap();
-            if verifier.update(data).is_ok() {
-                let result = verifier.verify(signature);
-                if let Ok(result) = result {
-                    trace!("Key verified = {:?}", result);
-                    return Ok(result);
-                } else {
-                    debug!("Can't verify key - error = {:?}", result.unwrap_err());
-                }
+        match provider().rsa_verify(&self.value, hash_algorithm, padding, data, signature) {
+            Ok(result) => {
+                trace!("Key verified = {:?}", result);
+                Ok(result)
+            }
+            Err(err) => {
+                debug!("Can't verify key - error = {:?}", err);
+                Err(BadUnexpectedError)
             }
         }
-        Err(BadUnexpectedError)
     }
 
     /// Verifies the data using RSA-SHA1
     pub fn verify_hmac_sha1(&self, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
-        self.verify(hash::MessageDigest::sha1(), data, signature, RsaPadding::PKCS1)
+        self.verify(HashAlgorithm::Sha1, data, signature, RsaPadding::PKCS1)
     }
 
     /// Verifies the data using RSA-SHA256
     pub fn verify_hmac_sha256(&self, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
-        self.verify(hash::MessageDigest::sha256(), data, signature, RsaPadding::PKCS1)
+        self.verify(HashAlgorithm::Sha256, data, signature, RsaPadding::PKCS1)
+    }
+
+    /// Verifies the data using RSA-PSS-SHA256
+    pub fn verify_sha256_pss(&self, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        self.verify(HashAlgorithm::Sha256, data, signature, RsaPadding::PSS)
+    }
+
+    /// Verifies an ECDSA signature made up of the r and s values concatenated, each padded to the
+    /// size of a curve coordinate.
+    fn verify_ecdsa(&self, hash_algorithm: HashAlgorithm, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        trace!("ECDSA verifying, against signature {:?}, len {}", signature, signature.len());
+        if signature.len() != self.signature_size() {
+            return Ok(false);
+        }
+        let _ = self.curve()?;
+        provider().ecdsa_verify(&self.value, hash_algorithm, data, signature)
+    }
+
+    /// Verifies the data using ECDSA-SHA256
+    pub fn verify_ecdsa_sha256(&self, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        self.verify_ecdsa(HashAlgorithm::Sha256, data, signature)
+    }
+
+    /// Verifies the data using ECDSA-SHA384
+    pub fn verify_ecdsa_sha384(&self, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        self.verify_ecdsa(HashAlgorithm::Sha384, data, signature)
     }
 
     /// Encrypts data from src to dst using the specified padding and returns the size of encrypted
@@ -221,12 +370,6 @@ impl PublicKey {
         let cipher_text_block_size = self.cipher_text_block_size();
         let plain_text_block_size = self.plain_text_block_size(padding);
 
-        // For reference:
-        //
-        // https://www.openssl.org/docs/man1.0.2/crypto/RSA_public_encrypt.html
-        let rsa = self.value.rsa().unwrap();
-        let padding: rsa::Padding = padding.into();
-
         // Encrypt the data in chunks no larger than the key size less padding
         let mut src_idx = 0;
         let mut dst_idx = 0;
@@ -243,7 +386,7 @@ impl PublicKey {
             dst_idx += {
                 let src = &src[src_idx..(src_idx + bytes_to_encrypt)];
                 let dst = &mut dst[dst_idx..(dst_idx + cipher_text_block_size)];
-                let encrypted_bytes = rsa.public_encrypt(src, dst, padding);
+                let encrypted_bytes = provider().rsa_public_encrypt(&self.value, padding, src, dst);
                 if encrypted_bytes.is_err() {
                     error!("Encryption failed for bytes_to_encrypt {}, key_size {}, src_idx {}, dst_idx {} error - {:?}", bytes_to_encrypt, cipher_text_block_size, src_idx, dst_idx, encrypted_bytes.unwrap_err());
                     return Err(());
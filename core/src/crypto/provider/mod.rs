@@ -0,0 +1,354 @@
+//! Crypto providers. Every cryptographic primitive the stack uses - RSA encryption and signing,
+//! ECDSA and ECDH, AES-CBC, HMAC, P_SHA, random numbers and X509 certificates - goes through the
+//! `CryptoProvider` trait so that the implementation can be chosen when the crate is built.
+//!
+//! * `openssl` feature (default) - the provider is implemented with OpenSSL
+//! * `rust-crypto` feature - the provider is implemented with pure Rust crates, so the crate can be
+//!   built and cross-compiled without OpenSSL or any other C library
+//!
+//! If both features are enabled then OpenSSL is used.
+//!
+//! Keys, certificates and revocation lists are passed to and from the provider in their DER form,
+//! i.e. a private key is PKCS #8, a public key is a SubjectPublicKeyInfo and a name is the DER of
+//! an X509 Name. That way the wrapper types in the crypto module don't depend on the provider.
+
+use chrono::{DateTime, Utc};
+
+use opcua_types::status_codes::StatusCode;
+
+use crypto::hash::HashAlgorithm;
+use crypto::pkey::{KeyType, RsaPadding};
+
+#[cfg(feature = "openssl")]
+pub mod openssl;
+#[cfg(feature = "rust-crypto")]
+pub mod rust_crypto;
+
+#[cfg(not(any(feature = "openssl", feature = "rust-crypto")))]
+compile_error!("A crypto provider must be enabled with either the \"openssl\" or \"rust-crypto\" feature");
+
+/// The curve of an EC key. The ECC security policies use the NIST curves.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EcCurve {
+    NistP256,
+    NistP384,
+}
+
+impl EcCurve {
+    /// Returns the curve for a key size in bits, i.e. 256 for nistP256 or 384 for nistP384
+    pub fn from_bit_length(bit_length: u32) -> Option<EcCurve> {
+        match bit_length {
+            256 => Some(EcCurve::NistP256),
+            384 => Some(EcCurve::NistP384),
+            _ => None
+        }
+    }
+
+    /// The size of a coordinate on the curve in bytes
+    pub fn coordinate_size(&self) -> usize {
+        match *self {
+            EcCurve::NistP256 => 32,
+            EcCurve::NistP384 => 48,
+        }
+    }
+}
+
+/// Information about a public or private key
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyInfo {
+    pub key_type: KeyType,
+    /// The key size in bits
+    pub bit_length: usize,
+    /// The public key in DER form as a SubjectPublicKeyInfo. For a private key it is the public
+    /// key of the pair.
+    pub public_key: Vec<u8>,
+}
+
+/// An entry of the subject alternative names extension of a certificate
+#[derive(Clone, Debug, PartialEq)]
+pub enum AltName {
+    Uri(String),
+    Dns(String),
+    /// An IPv4 or IPv6 address as 4 or 16 octets
+    IpAddress(Vec<u8>),
+    /// Any other kind of name, which is only kept so the position of the other entries is preserved
+    Other,
+}
+
+/// The uses of the key in a certificate
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct KeyUsage {
+    pub digital_signature: bool,
+    pub non_repudiation: bool,
+    pub key_encipherment: bool,
+    pub data_encipherment: bool,
+    pub key_agreement: bool,
+    pub key_cert_sign: bool,
+    pub crl_sign: bool,
+}
+
+/// The fields of a certificate that are used to identify and validate it
+#[derive(Clone, Debug, PartialEq)]
+pub struct CertificateInfo {
+    /// The subject name in DER form
+    pub subject_name: Vec<u8>,
+    /// The issuer name in DER form
+    pub issuer_name: Vec<u8>,
+    /// The common name of the subject, if it has one
+    pub common_name: Option<String>,
+    /// The serial number as a big endian unsigned integer without leading zeros
+    pub serial_number: Vec<u8>,
+    pub not_before: Option<DateTime<Utc>>,
+    pub not_after: Option<DateTime<Utc>>,
+    /// The subject alternative names in the order they appear on the cert, or `None` if the cert
+    /// doesn't have the extension
+    pub alt_names: Option<Vec<AltName>>,
+    /// The public key in DER form as a SubjectPublicKeyInfo
+    pub public_key: Vec<u8>,
+    /// Whether the cert has a basic constraints extension saying it is a CA
+    pub is_ca: bool,
+    /// The maximum number of intermediate CA certs that may follow a CA cert in a chain, or `None`
+    /// if it is unlimited
+    pub path_len_constraint: Option<u32>,
+}
+
+/// The fields of a revocation list that are used to check certificates against it
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrlInfo {
+    /// The issuer name in DER form
+    pub issuer_name: Vec<u8>,
+    /// The serial numbers of the revoked certs as big endian unsigned integers without leading zeros
+    pub revoked_serial_numbers: Vec<Vec<u8>>,
+    /// The time by which the issuer publishes the next list, if the list says
+    pub next_update: Option<DateTime<Utc>>,
+}
+
+/// The fields of a certificate signing request
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningRequestInfo {
+    /// The subject name in DER form
+    pub subject_name: Vec<u8>,
+    /// The public key in DER form as a SubjectPublicKeyInfo
+    pub public_key: Vec<u8>,
+}
+
+/// Describes an X509v3 certificate for a provider to create and sign
+#[derive(Clone, Debug)]
+pub struct CertificateParams {
+    /// The subject name in DER form
+    pub subject_name: Vec<u8>,
+    /// The public key of the subject in DER form as a SubjectPublicKeyInfo
+    pub public_key: Vec<u8>,
+    /// The DER form of the cert of the issuer, or `None` for a self-signed cert
+    pub issuer_cert: Option<Vec<u8>>,
+    /// The serial number as a big endian unsigned integer
+    pub serial_number: Vec<u8>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// A critical basic constraints extension saying whether the cert is a CA or not
+    pub basic_constraints_ca: Option<bool>,
+    /// The path length constraint of the basic constraints extension of a CA cert
+    pub path_len_constraint: Option<u32>,
+    /// The key usage extension and whether it is critical
+    pub key_usage: Option<(KeyUsage, bool)>,
+    /// An extended key usage extension for client and server authentication
+    pub extended_key_usage: bool,
+    /// A subject key identifier extension derived from the public key
+    pub subject_key_identifier: bool,
+    /// An authority key identifier extension taken from the subject key identifier of the issuer
+    /// cert, if it has one
+    pub authority_key_identifier: bool,
+    /// The subject alternative names, if any
+    pub alt_names: Vec<AltName>,
+    /// The digest used with the issuer key to sign the cert
+    pub hash_algorithm: HashAlgorithm,
+}
+
+/// Describes a revocation list for a provider to create and sign
+#[derive(Clone, Debug)]
+pub struct CrlParams {
+    /// The DER form of the cert of the CA issuing the list. The list identifies the CA by the subject
+    /// key identifier of the cert, which it must have.
+    pub issuer_cert: Vec<u8>,
+    pub last_update: DateTime<Utc>,
+    pub next_update: DateTime<Utc>,
+    pub crl_number: u32,
+    /// The serial numbers of the revoked certs as big endian unsigned integers
+    pub revoked_serial_numbers: Vec<Vec<u8>>,
+    /// The digest used with the issuer key to sign the list
+    pub hash_algorithm: HashAlgorithm,
+}
+
+/// A crypto provider implements the cryptographic primitives over keys and certificates in their
+/// DER form. Errors are returned as a status code, leaving the caller to log or translate them.
+pub trait CryptoProvider: Send + Sync {
+    /// The name of the provider for diagnostic purposes
+    fn name(&self) -> &'static str;
+
+    /// Fills the buffer with cryptographically secure random bytes
+    fn random_bytes(&self, bytes: &mut [u8]);
+
+    /// Returns the digest of the data
+    fn hash(&self, hash_algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8>;
+
+    /// Returns the HMAC of the data for the key
+    fn hmac(&self, hash_algorithm: HashAlgorithm, key: &[u8], data: &[u8]) -> Vec<u8>;
+
+    /// Pseudo random `P_SHA` implementation for creating a pseudo random range of bytes from a
+    /// secret and seed. The default implementation builds it from `hmac`.
+    ///
+    /// https://www.ietf.org/rfc/rfc4346.txt
+    /// https://tools.ietf.org/html/rfc5246
+    ///
+    /// P_SHA1(secret, seed) = HMAC_SHA1(secret, A(1) + seed) +
+    ///                        HMAC_SHA1(secret, A(2) + seed) +
+    ///                        HMAC_SHA1 (secret, A(3) + seed) + ...
+    ///
+    /// Where A(n) is defined as:
+    ///   A(0) = seed
+    ///   A(n) = HMAC_SHA1(secret, A(n-1))
+    /// + indicates that the results are appended to previous results.
+    fn p_sha(&self, hash_algorithm: HashAlgorithm, secret: &[u8], seed: &[u8], length: usize) -> Vec<u8> {
+        let mut result = Vec::with_capacity(length);
+
+        let mut hmac = Vec::with_capacity(seed.len() * 2);
+
+        let mut a_last = Vec::with_capacity(seed.len());
+        a_last.extend_from_slice(seed); // A(0) = seed
+
+        while result.len() < length {
+            // A(n) = HMAC_SHA1(secret, A(n-1))
+            let a_next = self.hmac(hash_algorithm, secret, &a_last);
+
+            // Append a slice of random data
+            let bytes = {
+                hmac.clear();
+                hmac.extend(&a_next);
+                hmac.extend_from_slice(seed);
+                self.hmac(hash_algorithm, secret, &hmac)
+            };
+            result.extend(&bytes);
+
+            a_last.clear();
+            a_last.extend(&a_next);
+        }
+
+        result.truncate(length);
+        result
+    }
+
+    /// Encrypts the source, which must be a multiple of the block size, with AES-CBC and no padding.
+    /// The key is 16 bytes for AES-128 or 32 bytes for AES-256. Returns the number of bytes written.
+    fn aes_cbc_encrypt(&self, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode>;
+
+    /// Decrypts the source, which must be a multiple of the block size, with AES-CBC and no padding.
+    /// The key is 16 bytes for AES-128 or 32 bytes for AES-256. Returns the number of bytes written.
+    fn aes_cbc_decrypt(&self, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode>;
+
+    /// Generates an RSA private key of the size in bits, returned in PKCS #8 DER form
+    fn generate_rsa_key(&self, bit_length: u32) -> Result<Vec<u8>, StatusCode>;
+
+    /// Generates an EC private key on the curve, returned in PKCS #8 DER form
+    fn generate_ec_key(&self, curve: EcCurve) -> Result<Vec<u8>, StatusCode>;
+
+    /// Reads a private key in PKCS #8 DER form
+    fn private_key_info(&self, private_key: &[u8]) -> Result<KeyInfo, StatusCode>;
+
+    /// Reads a public key in SubjectPublicKeyInfo DER form
+    fn public_key_info(&self, public_key: &[u8]) -> Result<KeyInfo, StatusCode>;
+
+    /// Reads a private key from PEM, returning it in PKCS #8 DER form
+    fn private_key_from_pem(&self, pem: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Writes a private key in PKCS #8 DER form to PKCS #8 PEM
+    fn private_key_to_pem(&self, private_key: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Encrypts a single block of plain text, which must fit the padding, with an RSA public key.
+    /// Returns the number of bytes written to the destination, which is the size of the key.
+    fn rsa_public_encrypt(&self, public_key: &[u8], padding: RsaPadding, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode>;
+
+    /// Decrypts a single block of cipher text with an RSA private key. Returns the number of bytes
+    /// written to the destination.
+    fn rsa_private_decrypt(&self, private_key: &[u8], padding: RsaPadding, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode>;
+
+    /// Signs the digest of the data with an RSA private key with PKCS #1 v1.5 or PSS padding. PSS
+    /// uses the digest for the mask generation function and a salt the length of the digest.
+    fn rsa_sign(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, padding: RsaPadding, data: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Verifies a signature made by `rsa_sign`
+    fn rsa_verify(&self, public_key: &[u8], hash_algorithm: HashAlgorithm, padding: RsaPadding, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode>;
+
+    /// Signs the digest of the data with an EC private key. The signature is the r and s values
+    /// concatenated, each padded to the size of a curve coordinate.
+    fn ecdsa_sign(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Verifies a signature made by `ecdsa_sign`. A malformed signature is not valid rather than an error.
+    fn ecdsa_verify(&self, public_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode>;
+
+    /// Returns the public key of an EC private key as the X and Y coordinates concatenated
+    fn ec_public_key_bytes(&self, private_key: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Tests if the X and Y coordinates concatenated are a valid public key on the curve
+    fn ec_is_valid_public_key(&self, curve: EcCurve, public_key: &[u8]) -> bool;
+
+    /// Computes the ECDH shared secret of an EC private key and a public key on the same curve given
+    /// as the X and Y coordinates concatenated
+    fn ecdh_shared_secret(&self, private_key: &[u8], curve: EcCurve, public_key: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Reads a certificate in DER form
+    fn parse_certificate(&self, cert: &[u8]) -> Result<CertificateInfo, StatusCode>;
+
+    /// Tests if the certificate is signed by the public key
+    fn verify_certificate_signature(&self, cert: &[u8], public_key: &[u8]) -> bool;
+
+    /// Tests if the issuer cert could have issued the subject cert, i.e. the issuer is a CA
+    /// according to its basic constraints, the subject names the issuer as its issuer, the key
+    /// identifiers agree and the issuer key may sign certificates. The signature is not checked.
+    fn check_issued(&self, issuer_cert: &[u8], subject_cert: &[u8]) -> bool;
+
+    /// Returns a human readable dump of the certificate's fields and extensions
+    fn certificate_to_text(&self, cert: &[u8]) -> Result<String, StatusCode>;
+
+    /// Makes a name in DER form from entries of a short attribute name, i.e. "CN", "O", "OU", "C"
+    /// or "ST", and a value
+    fn encode_name(&self, entries: &[(&str, &str)]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Creates a certificate signed by the private key of the issuer, returned in DER form
+    fn create_certificate(&self, params: &CertificateParams, issuer_private_key: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Creates a certificate signing request (PKCS #10) for the private key with the subject name
+    /// and subject alternative names, returned in DER form
+    fn create_signing_request(&self, subject_name: &[u8], private_key: &[u8], alt_names: &[AltName], hash_algorithm: HashAlgorithm) -> Result<Vec<u8>, StatusCode>;
+
+    /// Reads a certificate signing request in DER form
+    fn parse_signing_request(&self, request: &[u8]) -> Result<SigningRequestInfo, StatusCode>;
+
+    /// Tests if the certificate signing request is signed by its own key
+    fn verify_signing_request(&self, request: &[u8]) -> bool;
+
+    /// Reads a revocation list in DER form
+    fn parse_crl(&self, crl: &[u8]) -> Result<CrlInfo, StatusCode>;
+
+    /// Converts a revocation list in PEM form to DER form
+    fn crl_from_pem(&self, pem: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Tests if the revocation list is signed by the public key
+    fn verify_crl_signature(&self, crl: &[u8], public_key: &[u8]) -> bool;
+
+    /// Creates a revocation list signed by the private key of the issuer, returned in DER form
+    fn create_crl(&self, params: &CrlParams, issuer_private_key: &[u8]) -> Result<Vec<u8>, StatusCode>;
+}
+
+/// Returns the crypto provider the crate was built with
+#[cfg(feature = "openssl")]
+pub fn provider() -> &'static CryptoProvider {
+    static PROVIDER: openssl::OpenSslProvider = openssl::OpenSslProvider;
+    &PROVIDER
+}
+
+/// Returns the crypto provider the crate was built with
+#[cfg(all(feature = "rust-crypto", not(feature = "openssl")))]
+pub fn provider() -> &'static CryptoProvider {
+    static PROVIDER: rust_crypto::RustCryptoProvider = rust_crypto::RustCryptoProvider;
+    &PROVIDER
+}
@@ -0,0 +1,716 @@
+//! Crypto provider implemented with OpenSSL.
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ops::Deref;
+
+use openssl::asn1::{Asn1IntegerRef, Asn1Time, Asn1TimeRef};
+use openssl::bn::{BigNum, BigNumContext};
+use openssl::derive::Deriver;
+use openssl::ec::{EcGroup, EcKey, EcPoint, PointConversionForm};
+use openssl::ecdsa::EcdsaSig;
+use openssl::encrypt::{Decrypter, Encrypter};
+use openssl::hash::{hash, MessageDigest};
+use openssl::nid::Nid;
+use openssl::pkey::{HasPublic, Id, PKey, PKeyRef, Private, Public};
+use openssl::rand::rand_bytes;
+use openssl::rsa::{self, Rsa};
+use openssl::sign::{RsaPssSaltlen, Signer, Verifier};
+use openssl::stack::Stack;
+use openssl::symm::{Cipher, Crypter, Mode};
+use openssl::x509::{self, X509Name, X509VerifyResult};
+use openssl::x509::extension::{AuthorityKeyIdentifier, BasicConstraints, CrlNumber, ExtendedKeyUsage, KeyUsage, SubjectAlternativeName, SubjectKeyIdentifier};
+
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use chrono::{DateTime, Utc};
+
+use crypto::hash::HashAlgorithm;
+use crypto::pkey::{KeyType, RsaPadding};
+use crypto::x509::X509;
+use crypto::provider::{CryptoProvider, EcCurve, KeyInfo, AltName, CertificateInfo, CrlInfo, SigningRequestInfo, CertificateParams, CrlParams};
+
+/// The OpenSSL crypto provider
+pub struct OpenSslProvider;
+
+impl Into<rsa::Padding> for RsaPadding {
+    fn into(self) -> rsa::Padding {
+        match self {
+            RsaPadding::PKCS1 => rsa::Padding::PKCS1,
+            RsaPadding::OAEP | RsaPadding::OAEP_SHA256 => rsa::Padding::PKCS1_OAEP,
+            RsaPadding::PSS => rsa::Padding::PKCS1_PSS,
+        }
+    }
+}
+
+impl RsaPadding {
+    /// The digest used for OAEP and its mask generation function where it is not the SHA1 default
+    fn oaep_md(&self) -> Option<MessageDigest> {
+        match *self {
+            RsaPadding::OAEP_SHA256 => Some(MessageDigest::sha256()),
+            _ => None
+        }
+    }
+}
+
+impl Into<MessageDigest> for HashAlgorithm {
+    fn into(self) -> MessageDigest {
+        match self {
+            HashAlgorithm::Sha1 => MessageDigest::sha1(),
+            HashAlgorithm::Sha256 => MessageDigest::sha256(),
+            HashAlgorithm::Sha384 => MessageDigest::sha384(),
+        }
+    }
+}
+
+impl EcCurve {
+    fn nid(&self) -> Nid {
+        match *self {
+            EcCurve::NistP256 => Nid::X9_62_PRIME256V1,
+            EcCurve::NistP384 => Nid::SECP384R1,
+        }
+    }
+
+    fn group(&self) -> Result<EcGroup, StatusCode> {
+        EcGroup::from_curve_name(self.nid()).map_err(|_| BadUnexpectedError)
+    }
+}
+
+impl OpenSslProvider {
+    fn private_key(private_key: &[u8]) -> Result<PKey<Private>, StatusCode> {
+        PKey::private_key_from_pkcs8(private_key).map_err(|_| {
+            error!("Cannot read the private key");
+            BadUnexpectedError
+        })
+    }
+
+    fn public_key(public_key: &[u8]) -> Result<PKey<Public>, StatusCode> {
+        PKey::public_key_from_der(public_key).map_err(|_| {
+            error!("Cannot read the public key");
+            BadUnexpectedError
+        })
+    }
+
+    fn key_info<T>(pkey: &PKeyRef<T>) -> Result<KeyInfo, StatusCode> where T: HasPublic {
+        let key_type = if pkey.id() == Id::EC { KeyType::Ecc } else { KeyType::Rsa };
+        let public_key = pkey.public_key_to_der().map_err(|_| BadUnexpectedError)?;
+        Ok(KeyInfo { key_type, bit_length: pkey.bits() as usize, public_key })
+    }
+
+    /// Returns the curve of an EC key
+    fn curve<T>(pkey: &PKeyRef<T>) -> Result<EcCurve, StatusCode> where T: HasPublic {
+        if pkey.id() == Id::EC {
+            if let Some(curve) = EcCurve::from_bit_length(pkey.bits()) {
+                return Ok(curve);
+            }
+        }
+        error!("Key is not an EC key on a supported curve");
+        Err(BadUnexpectedError)
+    }
+
+    /// Makes a public key from the X and Y coordinates concatenated
+    fn ec_public_key(curve: EcCurve, public_key: &[u8]) -> Result<PKey<Public>, StatusCode> {
+        if public_key.len() != curve.coordinate_size() * 2 {
+            return Err(BadNonceInvalid);
+        }
+        let group = curve.group()?;
+        let mut ctx = BigNumContext::new().map_err(|_| BadUnexpectedError)?;
+        // Prepend the 0x04 that says the point is uncompressed
+        let mut bytes = Vec::with_capacity(public_key.len() + 1);
+        bytes.push(0x04);
+        bytes.extend_from_slice(public_key);
+        let point = EcPoint::from_bytes(&group, &bytes, &mut ctx).map_err(|_| {
+            error!("Public key is not a point on the curve");
+            BadNonceInvalid
+        })?;
+        let ec_key = EcKey::from_public_key(&group, &point).map_err(|_| BadNonceInvalid)?;
+        ec_key.check_key().map_err(|_| BadNonceInvalid)?;
+        PKey::from_ec_key(ec_key).map_err(|_| BadUnexpectedError)
+    }
+
+    fn aes_cbc(mode: Mode, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let cipher = match key.len() {
+            16 => Cipher::aes_128_cbc(),
+            32 => Cipher::aes_256_cbc(),
+            _ => {
+                error!("AES key length {} is unsupported", key.len());
+                return Err(BadUnexpectedError);
+            }
+        };
+        let mut crypter = Crypter::new(cipher, mode, key, Some(iv)).map_err(|err| {
+            error!("Cannot create a crypter, error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        crypter.pad(false);
+        let count = crypter.update(src, dst).map_err(|err| {
+            error!("Cannot process the data, error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        let rest = crypter.finalize(&mut dst[count..]).map_err(|err| {
+            error!("Cannot finalize the data, error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        Ok(count + rest)
+    }
+
+    /// Parses an ASN1 time via its text form
+    fn asn1_time(time: &Asn1TimeRef) -> Option<DateTime<Utc>> {
+        X509::parse_asn1_date(&time.to_string()).ok()
+    }
+
+    fn asn1_time_from(time: &DateTime<Utc>) -> Result<Asn1Time, StatusCode> {
+        Asn1Time::from_unix(time.timestamp() as _).map_err(|_| BadUnexpectedError)
+    }
+
+    /// Returns the big endian unsigned bytes of a serial number, with at least one byte
+    fn serial_number(serial_number: &Asn1IntegerRef) -> Vec<u8> {
+        let serial_number = serial_number.to_bn().map(|bn| bn.to_vec()).unwrap_or_default();
+        if serial_number.is_empty() { vec![0u8] } else { serial_number }
+    }
+
+    /// Splits the first DER element off the data, returning its tag, contents and the data that
+    /// follows it
+    fn der_element(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+        if data.len() < 2 {
+            return None;
+        }
+        let (len, offset) = if data[1] & 0x80 == 0 {
+            (data[1] as usize, 2)
+        } else {
+            let len_bytes = (data[1] & 0x7f) as usize;
+            if len_bytes == 0 || len_bytes > 4 || data.len() < 2 + len_bytes {
+                return None;
+            }
+            (data[2..2 + len_bytes].iter().fold(0usize, |len, b| (len << 8) | *b as usize), 2 + len_bytes)
+        };
+        if data.len() - offset < len {
+            None
+        } else {
+            Some((data[0], &data[offset..offset + len], &data[offset + len..]))
+        }
+    }
+
+    /// Reads the CA flag and path length constraint of the basic constraints extension of a cert
+    /// in DER form. The OpenSSL bindings don't expose the CA flag, so the extension is found by
+    /// walking the DER of the cert.
+    fn basic_constraints(cert: &[u8]) -> (bool, Option<u32>) {
+        // OID 2.5.29.19
+        const BASIC_CONSTRAINTS_OID: [u8; 3] = [0x55, 0x1d, 0x13];
+        let tbs_certificate = Self::der_element(cert)
+            .and_then(|(_, certificate, _)| Self::der_element(certificate))
+            .map(|(_, tbs_certificate, _)| tbs_certificate);
+        // The extensions are the explicitly tagged [3] field of the TBSCertificate
+        let mut fields = tbs_certificate.unwrap_or(&[]);
+        let mut extensions = &[][..];
+        while let Some((tag, contents, rest)) = Self::der_element(fields) {
+            if tag == 0xa3 {
+                extensions = Self::der_element(contents).map(|(_, extensions, _)| extensions).unwrap_or(&[]);
+                break;
+            }
+            fields = rest;
+        }
+        while let Some((_, extension, rest)) = Self::der_element(extensions) {
+            extensions = rest;
+            match Self::der_element(extension) {
+                Some((0x06, oid, rest)) if oid == &BASIC_CONSTRAINTS_OID[..] => {
+                    // Skip the optional critical flag to get to the value
+                    let value = match Self::der_element(rest) {
+                        Some((0x01, _, rest)) => Self::der_element(rest),
+                        value => value,
+                    };
+                    let mut constraints = match value.and_then(|(_, value, _)| Self::der_element(value)) {
+                        Some((0x30, constraints, _)) => constraints,
+                        _ => return (false, None),
+                    };
+                    let mut is_ca = false;
+                    if let Some((0x01, ca, rest)) = Self::der_element(constraints) {
+                        is_ca = ca.first().map_or(false, |b| *b != 0);
+                        constraints = rest;
+                    }
+                    let path_len_constraint = match Self::der_element(constraints) {
+                        Some((0x02, path_len, _)) if !path_len.is_empty() && path_len.len() <= 4 && path_len[0] & 0x80 == 0 => {
+                            Some(path_len.iter().fold(0u32, |path_len, b| (path_len << 8) | *b as u32))
+                        }
+                        _ => None
+                    };
+                    return (is_ca, path_len_constraint);
+                }
+                _ => {}
+            }
+        }
+        (false, None)
+    }
+
+    fn name(name: &[u8]) -> Result<X509Name, StatusCode> {
+        X509Name::from_der(name).map_err(|_| {
+            error!("Cannot read the name");
+            BadUnexpectedError
+        })
+    }
+
+    fn subject_alternative_name(alt_names: &[AltName]) -> SubjectAlternativeName {
+        let mut subject_alternative_name = SubjectAlternativeName::new();
+        for alt_name in alt_names {
+            match *alt_name {
+                AltName::Uri(ref uri) => {
+                    subject_alternative_name.uri(uri);
+                }
+                AltName::Dns(ref dns) => {
+                    subject_alternative_name.dns(dns);
+                }
+                AltName::IpAddress(ref octets) => {
+                    let ip_address = if octets.len() == 4 {
+                        IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]))
+                    } else if octets.len() == 16 {
+                        let mut v6 = [0u8; 16];
+                        v6.copy_from_slice(octets);
+                        IpAddr::V6(Ipv6Addr::from(v6))
+                    } else {
+                        continue;
+                    };
+                    subject_alternative_name.ip(&ip_address.to_string());
+                }
+                AltName::Other => {}
+            }
+        }
+        subject_alternative_name
+    }
+}
+
+impl CryptoProvider for OpenSslProvider {
+    fn name(&self) -> &'static str {
+        "OpenSSL"
+    }
+
+    fn random_bytes(&self, bytes: &mut [u8]) {
+        rand_bytes(bytes).unwrap();
+    }
+
+    fn hash(&self, hash_algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+        hash(hash_algorithm.into(), data).unwrap().to_vec()
+    }
+
+    fn hmac(&self, hash_algorithm: HashAlgorithm, key: &[u8], data: &[u8]) -> Vec<u8> {
+        // OpenSSL won't make an HMAC key of zero length. A key is padded with zeros to the
+        // digest block size so a single zero byte is the same key.
+        let pkey = if key.is_empty() {
+            PKey::hmac(&[0u8]).unwrap()
+        } else {
+            PKey::hmac(key).unwrap()
+        };
+        let mut signer = Signer::new(hash_algorithm.into(), &pkey).unwrap();
+        signer.update(data).unwrap();
+        signer.sign_to_vec().unwrap()
+    }
+
+    fn aes_cbc_encrypt(&self, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        Self::aes_cbc(Mode::Encrypt, key, iv, src, dst)
+    }
+
+    fn aes_cbc_decrypt(&self, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        Self::aes_cbc(Mode::Decrypt, key, iv, src, dst)
+    }
+
+    fn generate_rsa_key(&self, bit_length: u32) -> Result<Vec<u8>, StatusCode> {
+        Rsa::generate(bit_length)
+            .and_then(PKey::from_rsa)
+            .and_then(|pkey| pkey.private_key_to_pkcs8())
+            .map_err(|_| BadUnexpectedError)
+    }
+
+    fn generate_ec_key(&self, curve: EcCurve) -> Result<Vec<u8>, StatusCode> {
+        let group = curve.group()?;
+        EcKey::generate(&group)
+            .and_then(PKey::from_ec_key)
+            .and_then(|pkey| pkey.private_key_to_pkcs8())
+            .map_err(|_| BadUnexpectedError)
+    }
+
+    fn private_key_info(&self, private_key: &[u8]) -> Result<KeyInfo, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        Self::key_info(&pkey)
+    }
+
+    fn public_key_info(&self, public_key: &[u8]) -> Result<KeyInfo, StatusCode> {
+        let pkey = Self::public_key(public_key)?;
+        Self::key_info(&pkey)
+    }
+
+    fn private_key_from_pem(&self, pem: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        PKey::private_key_from_pem(pem)
+            .and_then(|pkey| pkey.private_key_to_pkcs8())
+            .map_err(|_| BadUnexpectedError)
+    }
+
+    fn private_key_to_pem(&self, private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        Self::private_key(private_key)?.private_key_to_pem_pkcs8().map_err(|_| BadUnexpectedError)
+    }
+
+    fn rsa_public_encrypt(&self, public_key: &[u8], padding: RsaPadding, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let pkey = Self::public_key(public_key)?;
+        // For reference:
+        //
+        // https://www.openssl.org/docs/man1.0.2/crypto/RSA_public_encrypt.html
+        //
+        // The EVP interface is used rather than RSA_public_encrypt because only it can set the OAEP digest
+        let mut encrypter = Encrypter::new(&pkey).map_err(|_| BadUnexpectedError)?;
+        encrypter.set_rsa_padding(padding.into()).map_err(|_| BadUnexpectedError)?;
+        if let Some(md) = padding.oaep_md() {
+            encrypter.set_rsa_oaep_md(md).map_err(|_| BadUnexpectedError)?;
+            encrypter.set_rsa_mgf1_md(md).map_err(|_| BadUnexpectedError)?;
+        }
+        encrypter.encrypt(src, dst).map_err(|err| {
+            debug!("Can't encrypt data - error = {:?}", err);
+            BadUnexpectedError
+        })
+    }
+
+    fn rsa_private_decrypt(&self, private_key: &[u8], padding: RsaPadding, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        let mut decrypter = Decrypter::new(&pkey).map_err(|_| BadUnexpectedError)?;
+        decrypter.set_rsa_padding(padding.into()).map_err(|_| BadUnexpectedError)?;
+        if let Some(md) = padding.oaep_md() {
+            decrypter.set_rsa_oaep_md(md).map_err(|_| BadUnexpectedError)?;
+            decrypter.set_rsa_mgf1_md(md).map_err(|_| BadUnexpectedError)?;
+        }
+        decrypter.decrypt(src, dst).map_err(|err| {
+            debug!("Can't decrypt data - error = {:?}", err);
+            BadUnexpectedError
+        })
+    }
+
+    fn rsa_sign(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, padding: RsaPadding, data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        let message_digest: MessageDigest = hash_algorithm.into();
+        let mut signer = Signer::new(message_digest, &pkey).map_err(|_| BadUnexpectedError)?;
+        signer.set_rsa_padding(padding.into()).map_err(|_| BadUnexpectedError)?;
+        if padding == RsaPadding::PSS {
+            signer.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH).map_err(|_| BadUnexpectedError)?;
+            signer.set_rsa_mgf1_md(message_digest).map_err(|_| BadUnexpectedError)?;
+        }
+        signer.update(data).map_err(|_| BadUnexpectedError)?;
+        signer.sign_to_vec().map_err(|err| {
+            debug!("Can't sign data - error = {:?}", err);
+            BadUnexpectedError
+        })
+    }
+
+    fn rsa_verify(&self, public_key: &[u8], hash_algorithm: HashAlgorithm, padding: RsaPadding, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        let pkey = Self::public_key(public_key)?;
+        let message_digest: MessageDigest = hash_algorithm.into();
+        let mut verifier = Verifier::new(message_digest, &pkey).map_err(|_| BadUnexpectedError)?;
+        verifier.set_rsa_padding(padding.into()).map_err(|_| BadUnexpectedError)?;
+        if padding == RsaPadding::PSS {
+            verifier.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH).map_err(|_| BadUnexpectedError)?;
+            verifier.set_rsa_mgf1_md(message_digest).map_err(|_| BadUnexpectedError)?;
+        }
+        verifier.update(data).map_err(|_| BadUnexpectedError)?;
+        verifier.verify(signature).map_err(|err| {
+            debug!("Can't verify key - error = {:?}", err);
+            BadUnexpectedError
+        })
+    }
+
+    fn ecdsa_sign(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        let coordinate_size = Self::curve(&pkey)?.coordinate_size() as i32;
+        let mut signer = Signer::new(hash_algorithm.into(), &pkey).map_err(|_| BadUnexpectedError)?;
+        signer.update(data).map_err(|_| BadUnexpectedError)?;
+        let der = signer.sign_to_vec().map_err(|err| {
+            debug!("Can't sign data - error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        // OpenSSL signs in DER form, OPC UA wants the r and s values
+        let ecdsa_sig = EcdsaSig::from_der(&der).map_err(|_| BadUnexpectedError)?;
+        let mut signature = ecdsa_sig.r().to_vec_padded(coordinate_size).map_err(|_| BadUnexpectedError)?;
+        signature.extend(ecdsa_sig.s().to_vec_padded(coordinate_size).map_err(|_| BadUnexpectedError)?);
+        Ok(signature)
+    }
+
+    fn ecdsa_verify(&self, public_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        let pkey = Self::public_key(public_key)?;
+        let coordinate_size = Self::curve(&pkey)?.coordinate_size();
+        if signature.len() != coordinate_size * 2 {
+            return Ok(false);
+        }
+        let r = BigNum::from_slice(&signature[..coordinate_size]).map_err(|_| BadUnexpectedError)?;
+        let s = BigNum::from_slice(&signature[coordinate_size..]).map_err(|_| BadUnexpectedError)?;
+        let der = EcdsaSig::from_private_components(r, s)
+            .and_then(|ecdsa_sig| ecdsa_sig.to_der())
+            .map_err(|_| BadUnexpectedError)?;
+        let mut verifier = Verifier::new(hash_algorithm.into(), &pkey).map_err(|_| BadUnexpectedError)?;
+        verifier.update(data).map_err(|_| BadUnexpectedError)?;
+        // A malformed signature is an error in OpenSSL, but it is just an invalid signature here
+        Ok(verifier.verify(&der).unwrap_or(false))
+    }
+
+    fn ec_public_key_bytes(&self, private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        let ec_key = pkey.ec_key().map_err(|_| BadUnexpectedError)?;
+        let mut ctx = BigNumContext::new().map_err(|_| BadUnexpectedError)?;
+        let bytes = ec_key.public_key().to_bytes(ec_key.group(), PointConversionForm::UNCOMPRESSED, &mut ctx)
+            .map_err(|_| BadUnexpectedError)?;
+        // Remove the leading 0x04 which says the point is uncompressed
+        Ok(bytes[1..].to_vec())
+    }
+
+    fn ec_is_valid_public_key(&self, curve: EcCurve, public_key: &[u8]) -> bool {
+        Self::ec_public_key(curve, public_key).is_ok()
+    }
+
+    fn ecdh_shared_secret(&self, private_key: &[u8], curve: EcCurve, public_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        let remote_public_key = Self::ec_public_key(curve, public_key)?;
+        let mut deriver = Deriver::new(&pkey).map_err(|_| BadUnexpectedError)?;
+        deriver.set_peer(&remote_public_key).map_err(|_| BadNonceInvalid)?;
+        deriver.derive_to_vec().map_err(|_| BadUnexpectedError)
+    }
+
+    fn parse_certificate(&self, der: &[u8]) -> Result<CertificateInfo, StatusCode> {
+        let cert = x509::X509::from_der(der).map_err(|_| BadCertificateInvalid)?;
+        let subject_name = cert.subject_name().to_der().map_err(|_| BadCertificateInvalid)?;
+        let issuer_name = cert.issuer_name().to_der().map_err(|_| BadCertificateInvalid)?;
+        let common_name = cert.subject_name().entries_by_nid(Nid::COMMONNAME).next()
+            // Asn1StringRef has to be converted out of Asn1 into UTF-8 and then a String
+            .and_then(|entry| entry.data().as_utf8().ok())
+            .map(|value| value.deref().to_string());
+        let alt_names = cert.subject_alt_names().map(|alt_names| {
+            alt_names.iter().map(|n| {
+                if let Some(uri) = n.uri() {
+                    AltName::Uri(uri.to_string())
+                } else if let Some(dns) = n.dnsname() {
+                    AltName::Dns(dns.to_string())
+                } else if let Some(ip_address) = n.ipaddress() {
+                    AltName::IpAddress(ip_address.to_vec())
+                } else {
+                    AltName::Other
+                }
+            }).collect()
+        });
+        let public_key = cert.public_key()
+            .and_then(|pkey| pkey.public_key_to_der())
+            .map_err(|_| BadCertificateInvalid)?;
+        let (is_ca, path_len_constraint) = Self::basic_constraints(der);
+        Ok(CertificateInfo {
+            subject_name,
+            issuer_name,
+            common_name,
+            serial_number: Self::serial_number(cert.serial_number()),
+            not_before: Self::asn1_time(cert.not_before()),
+            not_after: Self::asn1_time(cert.not_after()),
+            alt_names,
+            public_key,
+            is_ca,
+            path_len_constraint,
+        })
+    }
+
+    fn verify_certificate_signature(&self, cert: &[u8], public_key: &[u8]) -> bool {
+        match (x509::X509::from_der(cert), PKey::public_key_from_der(public_key)) {
+            (Ok(cert), Ok(pkey)) => cert.verify(&pkey).unwrap_or(false),
+            _ => false
+        }
+    }
+
+    fn check_issued(&self, issuer_cert: &[u8], subject_cert: &[u8]) -> bool {
+        // OpenSSL checks the key usage of the issuer but leaves its basic constraints to chain
+        // verification
+        let (is_ca, _) = Self::basic_constraints(issuer_cert);
+        match (x509::X509::from_der(issuer_cert), x509::X509::from_der(subject_cert)) {
+            (Ok(issuer), Ok(subject)) => is_ca && issuer.issued(&subject) == X509VerifyResult::OK,
+            _ => false
+        }
+    }
+
+    fn certificate_to_text(&self, cert: &[u8]) -> Result<String, StatusCode> {
+        x509::X509::from_der(cert)
+            .and_then(|cert| cert.to_text())
+            .map(|text| String::from_utf8_lossy(&text).into_owned())
+            .map_err(|_| BadCertificateInvalid)
+    }
+
+    fn encode_name(&self, entries: &[(&str, &str)]) -> Result<Vec<u8>, StatusCode> {
+        let mut name = x509::X509NameBuilder::new().map_err(|_| BadUnexpectedError)?;
+        for &(field, value) in entries {
+            name.append_entry_by_text(field, value).map_err(|_| BadInvalidArgument)?;
+        }
+        name.build().to_der().map_err(|_| BadUnexpectedError)
+    }
+
+    fn create_certificate(&self, params: &CertificateParams, issuer_private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let issuer_pkey = Self::private_key(issuer_private_key)?;
+        let issuer_cert = if let Some(ref issuer_cert) = params.issuer_cert {
+            Some(x509::X509::from_der(issuer_cert).map_err(|_| BadCertificateInvalid)?)
+        } else {
+            None
+        };
+        let subject_name = Self::name(&params.subject_name)?;
+        let public_key = Self::public_key(&params.public_key)?;
+
+        let mut builder = x509::X509Builder::new().map_err(|_| BadUnexpectedError)?;
+        // value 2 == version 3 (go figure)
+        let _ = builder.set_version(2);
+        let _ = builder.set_subject_name(&subject_name);
+        if let Some(ref issuer_cert) = issuer_cert {
+            let _ = builder.set_issuer_name(issuer_cert.subject_name());
+        } else {
+            // Issuer and subject shall be the same for self-signed cert
+            let _ = builder.set_issuer_name(&subject_name);
+        }
+        let not_before = Self::asn1_time_from(&params.not_before)?;
+        let not_after = Self::asn1_time_from(&params.not_after)?;
+        builder.set_not_before(&not_before).map_err(|_| BadUnexpectedError)?;
+        builder.set_not_after(&not_after).map_err(|_| BadUnexpectedError)?;
+        builder.set_pubkey(&public_key).map_err(|_| BadUnexpectedError)?;
+        let serial_number = BigNum::from_slice(&params.serial_number)
+            .and_then(|serial_number| serial_number.to_asn1_integer())
+            .map_err(|_| BadUnexpectedError)?;
+        let _ = builder.set_serial_number(&serial_number);
+
+        if let Some(ca) = params.basic_constraints_ca {
+            let mut basic_constraints = BasicConstraints::new();
+            basic_constraints.critical();
+            if ca {
+                basic_constraints.ca();
+            }
+            if let Some(path_len_constraint) = params.path_len_constraint {
+                basic_constraints.pathlen(path_len_constraint);
+            }
+            let _ = builder.append_extension(basic_constraints.build().map_err(|_| BadUnexpectedError)?);
+        }
+        if let Some((key_usage_flags, critical)) = params.key_usage {
+            let mut key_usage = KeyUsage::new();
+            if critical { key_usage.critical(); }
+            if key_usage_flags.digital_signature { key_usage.digital_signature(); }
+            if key_usage_flags.non_repudiation { key_usage.non_repudiation(); }
+            if key_usage_flags.key_encipherment { key_usage.key_encipherment(); }
+            if key_usage_flags.data_encipherment { key_usage.data_encipherment(); }
+            if key_usage_flags.key_agreement { key_usage.key_agreement(); }
+            if key_usage_flags.key_cert_sign { key_usage.key_cert_sign(); }
+            if key_usage_flags.crl_sign { key_usage.crl_sign(); }
+            let _ = builder.append_extension(key_usage.build().map_err(|_| BadUnexpectedError)?);
+        }
+        if params.extended_key_usage {
+            let extended_key_usage = ExtendedKeyUsage::new().
+                client_auth().
+                server_auth().build().map_err(|_| BadUnexpectedError)?;
+            let _ = builder.append_extension(extended_key_usage);
+        }
+        if params.subject_key_identifier {
+            let subject_key_identifier = SubjectKeyIdentifier::new()
+                .build(&builder.x509v3_context(issuer_cert.as_ref().map(|c| c.deref()), None))
+                .map_err(|_| BadUnexpectedError)?;
+            let _ = builder.append_extension(subject_key_identifier);
+        }
+        if params.authority_key_identifier {
+            let authority_key_identifier = AuthorityKeyIdentifier::new().keyid(false).issuer(false)
+                .build(&builder.x509v3_context(issuer_cert.as_ref().map(|c| c.deref()), None))
+                .map_err(|_| BadUnexpectedError)?;
+            let _ = builder.append_extension(authority_key_identifier);
+        }
+        if !params.alt_names.is_empty() {
+            let subject_alternative_name = Self::subject_alternative_name(&params.alt_names)
+                .build(&builder.x509v3_context(issuer_cert.as_ref().map(|c| c.deref()), None))
+                .map_err(|_| BadUnexpectedError)?;
+            let _ = builder.append_extension(subject_alternative_name);
+        }
+
+        builder.sign(&issuer_pkey, params.hash_algorithm.into()).map_err(|_| BadUnexpectedError)?;
+        builder.build().to_der().map_err(|_| BadUnexpectedError)
+    }
+
+    fn create_signing_request(&self, subject_name: &[u8], private_key: &[u8], alt_names: &[AltName], hash_algorithm: HashAlgorithm) -> Result<Vec<u8>, StatusCode> {
+        let pkey = Self::private_key(private_key)?;
+        let mut builder = x509::X509ReqBuilder::new().map_err(|_| BadUnexpectedError)?;
+        let _ = builder.set_version(0);
+        let subject_name = Self::name(subject_name)?;
+        builder.set_subject_name(&subject_name).map_err(|_| BadUnexpectedError)?;
+        builder.set_pubkey(&pkey).map_err(|_| BadUnexpectedError)?;
+        if !alt_names.is_empty() {
+            let subject_alternative_name = Self::subject_alternative_name(alt_names)
+                .build(&builder.x509v3_context(None)).map_err(|_| BadUnexpectedError)?;
+            let mut extensions = Stack::new().map_err(|_| BadUnexpectedError)?;
+            extensions.push(subject_alternative_name).map_err(|_| BadUnexpectedError)?;
+            builder.add_extensions(&extensions).map_err(|_| BadUnexpectedError)?;
+        }
+        builder.sign(&pkey, hash_algorithm.into()).map_err(|_| BadUnexpectedError)?;
+        builder.build().to_der().map_err(|_| BadUnexpectedError)
+    }
+
+    fn parse_signing_request(&self, request: &[u8]) -> Result<SigningRequestInfo, StatusCode> {
+        let request = x509::X509Req::from_der(request).map_err(|_| BadInvalidArgument)?;
+        let subject_name = request.subject_name().to_der().map_err(|_| BadInvalidArgument)?;
+        let public_key = request.public_key()
+            .and_then(|pkey| pkey.public_key_to_der())
+            .map_err(|_| BadInvalidArgument)?;
+        Ok(SigningRequestInfo { subject_name, public_key })
+    }
+
+    fn verify_signing_request(&self, request: &[u8]) -> bool {
+        if let Ok(request) = x509::X509Req::from_der(request) {
+            if let Ok(pkey) = request.public_key() {
+                return request.verify(&pkey).unwrap_or(false);
+            }
+        }
+        false
+    }
+
+    fn parse_crl(&self, crl: &[u8]) -> Result<CrlInfo, StatusCode> {
+        let crl = x509::X509Crl::from_der(crl).map_err(|_| BadCertificateInvalid)?;
+        let issuer_name = crl.issuer_name().to_der().map_err(|_| BadCertificateInvalid)?;
+        let revoked_serial_numbers = crl.get_revoked()
+            .map(|revoked| revoked.iter().map(|r| Self::serial_number(r.serial_number())).collect())
+            .unwrap_or_default();
+        let next_update = crl.next_update().and_then(Self::asn1_time);
+        Ok(CrlInfo { issuer_name, revoked_serial_numbers, next_update })
+    }
+
+    fn crl_from_pem(&self, pem: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        x509::X509Crl::from_pem(pem)
+            .and_then(|crl| crl.to_der())
+            .map_err(|_| BadCertificateInvalid)
+    }
+
+    fn verify_crl_signature(&self, crl: &[u8], public_key: &[u8]) -> bool {
+        match (x509::X509Crl::from_der(crl), PKey::public_key_from_der(public_key)) {
+            (Ok(crl), Ok(pkey)) => crl.verify(&pkey).unwrap_or(false),
+            _ => false
+        }
+    }
+
+    fn create_crl(&self, params: &CrlParams, issuer_private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let issuer_pkey = Self::private_key(issuer_private_key)?;
+        let issuer_cert = x509::X509::from_der(&params.issuer_cert).map_err(|_| BadCertificateInvalid)?;
+        let mut builder = x509::X509CrlBuilder::new().map_err(|_| BadUnexpectedError)?;
+        builder.set_issuer_name(issuer_cert.subject_name()).map_err(|_| BadUnexpectedError)?;
+        let last_update = Self::asn1_time_from(&params.last_update)?;
+        let next_update = Self::asn1_time_from(&params.next_update)?;
+        builder.set_last_update(&last_update).map_err(|_| BadUnexpectedError)?;
+        builder.set_next_update(&next_update).map_err(|_| BadUnexpectedError)?;
+        let authority_key_identifier = {
+            let context_builder = x509::X509Builder::new().map_err(|_| BadUnexpectedError)?;
+            AuthorityKeyIdentifier::new().keyid(true).build(&context_builder.x509v3_context(Some(&issuer_cert), None))
+                .map_err(|_| {
+                    error!("CA cert has no subject key identifier");
+                    BadCertificateInvalid
+                })?
+        };
+        builder.append_extension(authority_key_identifier).map_err(|_| BadUnexpectedError)?;
+        let crl_number = BigNum::from_u32(params.crl_number).map_err(|_| BadUnexpectedError)?;
+        builder.append_extension(CrlNumber::new(crl_number).and_then(|n| n.build()).map_err(|_| BadUnexpectedError)?)
+            .map_err(|_| BadUnexpectedError)?;
+        let revocation_date = Self::asn1_time_from(&params.last_update)?;
+        for serial_number in &params.revoked_serial_numbers {
+            let serial_number = BigNum::from_slice(serial_number)
+                .and_then(|serial_number| serial_number.to_asn1_integer())
+                .map_err(|_| BadUnexpectedError)?;
+            let mut revoked = x509::X509RevokedBuilder::new().map_err(|_| BadUnexpectedError)?;
+            revoked.set_serial_number(&serial_number).map_err(|_| BadUnexpectedError)?;
+            revoked.set_revocation_date(&revocation_date).map_err(|_| BadUnexpectedError)?;
+            builder.add_revoked(revoked.build()).map_err(|_| BadUnexpectedError)?;
+        }
+        builder.sign(&issuer_pkey, params.hash_algorithm.into()).map_err(|_| BadUnexpectedError)?;
+        builder.build()
+            .and_then(|crl| crl.to_der())
+            .map_err(|_| BadUnexpectedError)
+    }
+}
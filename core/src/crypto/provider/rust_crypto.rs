@@ -0,0 +1,1021 @@
+//! Crypto provider implemented with pure Rust crates, i.e. the RustCrypto crates for the primitives
+//! and `x509-cert` for certificates, so that the stack can be built without OpenSSL.
+use std::convert::TryFrom;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use aes::{Aes128, Aes256};
+use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use cbc::cipher::block_padding::NoPadding;
+use hmac::{Hmac, Mac};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding, PrivateKeyInfo, SubjectPublicKeyInfoRef};
+use rand_core::{OsRng, RngCore};
+use rsa::{Oaep, Pkcs1v15Encrypt, Pkcs1v15Sign, Pss, RsaPrivateKey, RsaPublicKey};
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::traits::PublicKeyParts;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384};
+use x509_cert::{Certificate, TbsCertificate, Version};
+use x509_cert::attr::{Attribute, AttributeTypeAndValue};
+use x509_cert::crl::{CertificateList, RevokedCert, TbsCertList};
+use x509_cert::der::{Any, Decode, Encode, Reader, SliceReader, Tag, Tagged};
+use x509_cert::der::asn1::{BitString, GeneralizedTime, Ia5String, ObjectIdentifier, OctetString, PrintableStringRef, SetOfVec, Uint, UtcTime, Utf8StringRef};
+use x509_cert::der::flagset::FlagSet;
+use x509_cert::der::oid::AssociatedOid;
+use x509_cert::der::oid::db::DB;
+use x509_cert::der::pem;
+use x509_cert::ext::Extension;
+use x509_cert::ext::pkix::{AuthorityKeyIdentifier, BasicConstraints, CrlNumber, ExtendedKeyUsage, KeyUsage as KeyUsageExt, KeyUsages, SubjectAltName, SubjectKeyIdentifier};
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::name::{Name, RelativeDistinguishedName};
+use x509_cert::request::{CertReq, CertReqInfo, ExtensionReq};
+use x509_cert::serial_number::SerialNumber;
+use x509_cert::spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+use x509_cert::time::{Time, Validity};
+
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use crypto::hash::HashAlgorithm;
+use crypto::pkey::{KeyType, RsaPadding};
+use crypto::provider::{CryptoProvider, EcCurve, KeyInfo, AltName, CertificateInfo, CrlInfo, SigningRequestInfo, CertificateParams, CrlParams};
+
+const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const SHA1_WITH_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.5");
+const SHA256_WITH_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+const SHA384_WITH_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.12");
+const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const ECDSA_WITH_SHA1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.1");
+const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+const ECDSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+const PRIME256V1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const SECP384R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+const COMMON_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.3");
+const COUNTRY_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.6");
+const STATE_OR_PROVINCE_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.8");
+const ORGANIZATION_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.10");
+const ORGANIZATIONAL_UNIT_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.11");
+const ID_KP_SERVER_AUTH: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.3.1");
+const ID_KP_CLIENT_AUTH: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.3.2");
+
+/// Runs the body with `$c` as the crate of the curve, i.e. `p256` or `p384`
+macro_rules! with_curve {
+    ($curve: expr, $c: ident => $body: expr) => {
+        match $curve {
+            EcCurve::NistP256 => {
+                use p256 as $c;
+                $body
+            }
+            EcCurve::NistP384 => {
+                use p384 as $c;
+                $body
+            }
+        }
+    }
+}
+
+/// Runs the body with `$d` as the digest type of the hash algorithm
+macro_rules! with_digest {
+    ($hash_algorithm: expr, $d: ident => $body: expr) => {
+        match $hash_algorithm {
+            HashAlgorithm::Sha1 => {
+                type $d = Sha1;
+                $body
+            }
+            HashAlgorithm::Sha256 => {
+                type $d = Sha256;
+                $body
+            }
+            HashAlgorithm::Sha384 => {
+                type $d = Sha384;
+                $body
+            }
+        }
+    }
+}
+
+/// The pure Rust crypto provider
+pub struct RustCryptoProvider;
+
+/// The private key of either kind
+enum AnyPrivateKey {
+    Rsa(RsaPrivateKey),
+    Ec(EcCurve),
+}
+
+/// Maps any error to a status code, logging what failed
+fn bad<E>(what: &'static str, status_code: StatusCode) -> impl Fn(E) -> StatusCode {
+    move |_| {
+        error!("{}", what);
+        status_code
+    }
+}
+
+/// Strips the leading zeros from a big endian unsigned integer, leaving at least one byte
+fn strip_leading_zeros(value: &[u8]) -> Vec<u8> {
+    let first_non_zero = value.iter().position(|b| *b != 0).unwrap_or(value.len().saturating_sub(1));
+    value[first_non_zero..].to_vec()
+}
+
+impl RustCryptoProvider {
+    /// Reads the curve from the parameters of an EC key algorithm identifier
+    fn curve_from_parameters(parameters: Option<ObjectIdentifier>) -> Result<EcCurve, StatusCode> {
+        match parameters {
+            Some(PRIME256V1) => Ok(EcCurve::NistP256),
+            Some(SECP384R1) => Ok(EcCurve::NistP384),
+            _ => {
+                error!("EC key is not on a supported curve");
+                Err(BadUnexpectedError)
+            }
+        }
+    }
+
+    fn private_key(private_key: &[u8]) -> Result<AnyPrivateKey, StatusCode> {
+        let info = PrivateKeyInfo::try_from(private_key).map_err(bad("Cannot read the private key", BadUnexpectedError))?;
+        if info.algorithm.oid == RSA_ENCRYPTION {
+            let key = RsaPrivateKey::from_pkcs8_der(private_key).map_err(bad("Cannot read the RSA private key", BadUnexpectedError))?;
+            Ok(AnyPrivateKey::Rsa(key))
+        } else if info.algorithm.oid == EC_PUBLIC_KEY {
+            let curve = Self::curve_from_parameters(info.algorithm.parameters_oid().ok())?;
+            Ok(AnyPrivateKey::Ec(curve))
+        } else {
+            error!("Private key algorithm {} is unsupported", info.algorithm.oid);
+            Err(BadUnexpectedError)
+        }
+    }
+
+    fn key_type(private_key: &[u8]) -> Result<KeyType, StatusCode> {
+        match Self::private_key(private_key)? {
+            AnyPrivateKey::Rsa(_) => Ok(KeyType::Rsa),
+            AnyPrivateKey::Ec(_) => Ok(KeyType::Ecc),
+        }
+    }
+
+    fn rsa_private_key(private_key: &[u8]) -> Result<RsaPrivateKey, StatusCode> {
+        RsaPrivateKey::from_pkcs8_der(private_key).map_err(bad("Cannot read the RSA private key", BadUnexpectedError))
+    }
+
+    fn rsa_public_key(public_key: &[u8]) -> Result<RsaPublicKey, StatusCode> {
+        RsaPublicKey::from_public_key_der(public_key).map_err(bad("Cannot read the RSA public key", BadUnexpectedError))
+    }
+
+    /// Returns the curve of an EC private key
+    fn ec_private_key_curve(private_key: &[u8]) -> Result<EcCurve, StatusCode> {
+        match Self::private_key(private_key)? {
+            AnyPrivateKey::Ec(curve) => Ok(curve),
+            AnyPrivateKey::Rsa(_) => {
+                error!("Key is not an EC key");
+                Err(BadUnexpectedError)
+            }
+        }
+    }
+
+    /// Returns the curve of an EC public key
+    fn ec_public_key_curve(public_key: &[u8]) -> Result<EcCurve, StatusCode> {
+        let info = SubjectPublicKeyInfoRef::try_from(public_key).map_err(bad("Cannot read the public key", BadUnexpectedError))?;
+        if info.algorithm.oid == EC_PUBLIC_KEY {
+            Self::curve_from_parameters(info.algorithm.parameters_oid().ok())
+        } else {
+            error!("Key is not an EC key");
+            Err(BadUnexpectedError)
+        }
+    }
+
+    fn rsa_encryption_padding(padding: RsaPadding) -> Result<RsaEncryptionPadding, StatusCode> {
+        match padding {
+            RsaPadding::PKCS1 => Ok(RsaEncryptionPadding::Pkcs1v15(Pkcs1v15Encrypt)),
+            RsaPadding::OAEP => Ok(RsaEncryptionPadding::Oaep(Oaep::new::<Sha1>())),
+            RsaPadding::OAEP_SHA256 => Ok(RsaEncryptionPadding::Oaep(Oaep::new::<Sha256>())),
+            RsaPadding::PSS => {
+                error!("PSS padding is only for signing");
+                Err(BadUnexpectedError)
+            }
+        }
+    }
+
+    fn aes_key_size_error(key: &[u8]) -> StatusCode {
+        error!("AES key length {} is unsupported", key.len());
+        BadUnexpectedError
+    }
+
+    /// Converts a time on a cert to a portable type
+    fn from_time(time: &Time) -> DateTime<Utc> {
+        let duration = time.to_unix_duration();
+        Utc.timestamp(duration.as_secs() as i64, 0)
+    }
+
+    /// Converts a portable time to a time for a cert, using UTCTime up to 2049 as RFC 5280 says
+    fn to_time(time: &DateTime<Utc>) -> Result<Time, StatusCode> {
+        let duration = Duration::from_secs(time.timestamp() as u64);
+        if time.year() < 2050 {
+            UtcTime::from_unix_duration(duration).map(Time::UtcTime)
+        } else {
+            GeneralizedTime::from_unix_duration(duration).map(Time::GeneralTime)
+        }.map_err(bad("Time cannot be encoded", BadUnexpectedError))
+    }
+
+    /// Splits a signed structure, i.e. a cert, CRL or signing request, into the DER of the part
+    /// that is signed, the signature algorithm and the signature
+    fn signed_parts(der: &[u8]) -> Result<(&[u8], AlgorithmIdentifierOwned, Vec<u8>), StatusCode> {
+        let mut reader = SliceReader::new(der).map_err(bad("Cannot read the signed data", BadCertificateInvalid))?;
+        reader.sequence(|reader| {
+            let tbs = reader.tlv_bytes()?;
+            let algorithm: AlgorithmIdentifierOwned = reader.decode()?;
+            let signature: BitString = reader.decode()?;
+            Ok((tbs, algorithm, signature.raw_bytes().to_vec()))
+        }).map_err(bad("Cannot read the signed data", BadCertificateInvalid))
+    }
+
+    /// Verifies the signature over the data with the signature algorithm of a cert, CRL or signing request
+    fn verify_signed(&self, public_key: &[u8], algorithm: &AlgorithmIdentifierOwned, data: &[u8], signature: &[u8]) -> bool {
+        let rsa_hash_algorithm = match algorithm.oid {
+            SHA1_WITH_RSA_ENCRYPTION => Some(HashAlgorithm::Sha1),
+            SHA256_WITH_RSA_ENCRYPTION => Some(HashAlgorithm::Sha256),
+            SHA384_WITH_RSA_ENCRYPTION => Some(HashAlgorithm::Sha384),
+            _ => None
+        };
+        let ecdsa_hash_algorithm = match algorithm.oid {
+            ECDSA_WITH_SHA1 => Some(HashAlgorithm::Sha1),
+            ECDSA_WITH_SHA256 => Some(HashAlgorithm::Sha256),
+            ECDSA_WITH_SHA384 => Some(HashAlgorithm::Sha384),
+            _ => None
+        };
+        if let Some(hash_algorithm) = rsa_hash_algorithm {
+            self.rsa_verify(public_key, hash_algorithm, RsaPadding::PKCS1, data, signature).unwrap_or(false)
+        } else if let Some(hash_algorithm) = ecdsa_hash_algorithm {
+            // The signature is DER encoded, OPC UA wants the r and s values
+            let signature = Self::ec_public_key_curve(public_key).ok().and_then(|curve| {
+                with_curve!(curve, c => c::ecdsa::Signature::from_der(signature).ok().map(|s| s.to_bytes().to_vec()))
+            });
+            if let Some(signature) = signature {
+                self.ecdsa_verify(public_key, hash_algorithm, data, &signature).unwrap_or(false)
+            } else {
+                false
+            }
+        } else {
+            error!("Signature algorithm {} is unsupported", algorithm.oid);
+            false
+        }
+    }
+
+    /// Signs a cert, CRL or signing request with the private key of the issuer, returning the
+    /// signature algorithm and the signature in the form it takes in the signed structure
+    fn sign_signed(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8]) -> Result<(AlgorithmIdentifierOwned, Vec<u8>), StatusCode> {
+        match Self::private_key(private_key)? {
+            AnyPrivateKey::Rsa(_) => {
+                let signature = self.rsa_sign(private_key, hash_algorithm, RsaPadding::PKCS1, data)?;
+                Ok((signature_algorithm(KeyType::Rsa, hash_algorithm), signature))
+            }
+            AnyPrivateKey::Ec(curve) => {
+                // OPC UA signatures are the r and s values but X509 ones are DER encoded
+                let signature = self.ecdsa_sign(private_key, hash_algorithm, data)?;
+                let signature = with_curve!(curve, c => {
+                    c::ecdsa::Signature::from_slice(&signature).map(|s| s.to_der().as_bytes().to_vec())
+                }).map_err(bad("Cannot encode the signature", BadUnexpectedError))?;
+                Ok((signature_algorithm(KeyType::Ecc, hash_algorithm), signature))
+            }
+        }
+    }
+
+    /// Finds the extension with the OID
+    fn extension<'a>(extensions: &'a Option<Vec<Extension>>, oid: ObjectIdentifier) -> Option<&'a Extension> {
+        extensions.as_ref().and_then(|extensions| extensions.iter().find(|e| e.extn_id == oid))
+    }
+
+    /// Decodes the value of the extension of the type, if there is one
+    fn decode_extension<T>(extensions: &Option<Vec<Extension>>) -> Option<T> where T: AssociatedOid + for<'a> Decode<'a> {
+        Self::extension(extensions, T::OID).and_then(|e| T::from_der(e.extn_value.as_bytes()).ok())
+    }
+
+    fn make_extension<T>(value: &T, critical: bool) -> Result<Extension, StatusCode> where T: AssociatedOid + Encode {
+        let extn_value = value.to_der()
+            .and_then(OctetString::new)
+            .map_err(bad("Cannot encode the extension", BadUnexpectedError))?;
+        Ok(Extension { extn_id: T::OID, critical, extn_value })
+    }
+
+    /// The subject key identifier of a public key is the SHA1 hash of its bits
+    fn key_identifier(public_key: &SubjectPublicKeyInfoOwned) -> Vec<u8> {
+        Sha1::digest(public_key.subject_public_key.raw_bytes()).to_vec()
+    }
+
+    fn general_names(alt_names: &[AltName]) -> Result<Vec<GeneralName>, StatusCode> {
+        let mut general_names = Vec::with_capacity(alt_names.len());
+        for alt_name in alt_names {
+            let general_name = match *alt_name {
+                AltName::Uri(ref uri) => Ia5String::new(uri).map(GeneralName::UniformResourceIdentifier),
+                AltName::Dns(ref dns) => Ia5String::new(dns).map(GeneralName::DnsName),
+                AltName::IpAddress(ref octets) => OctetString::new(octets.clone()).map(GeneralName::IpAddress),
+                AltName::Other => continue,
+            }.map_err(bad("Cannot encode the subject alt name", BadInvalidArgument))?;
+            general_names.push(general_name);
+        }
+        Ok(general_names)
+    }
+
+    fn alt_name(general_name: &GeneralName) -> AltName {
+        match *general_name {
+            GeneralName::UniformResourceIdentifier(ref uri) => AltName::Uri(uri.to_string()),
+            GeneralName::DnsName(ref dns) => AltName::Dns(dns.to_string()),
+            GeneralName::IpAddress(ref ip_address) => AltName::IpAddress(ip_address.as_bytes().to_vec()),
+            _ => AltName::Other
+        }
+    }
+
+    /// Decodes a directory string, which may be any of several string types
+    fn directory_string(value: &Any) -> Option<String> {
+        match value.tag() {
+            Tag::Utf8String | Tag::PrintableString | Tag::Ia5String | Tag::TeletexString => {
+                String::from_utf8(value.value().to_vec()).ok()
+            }
+            _ => None
+        }
+    }
+
+    fn common_name(name: &Name) -> Option<String> {
+        name.0.iter()
+            .flat_map(|rdn| rdn.0.iter())
+            .find(|atv| atv.oid == COMMON_NAME)
+            .and_then(|atv| Self::directory_string(&atv.value))
+    }
+
+    fn certificate(cert: &[u8]) -> Result<Certificate, StatusCode> {
+        Certificate::from_der(cert).map_err(bad("Cannot read the cert", BadCertificateInvalid))
+    }
+
+    fn to_der<T>(value: &T) -> Result<Vec<u8>, StatusCode> where T: Encode {
+        value.to_der().map_err(bad("Cannot encode the value", BadUnexpectedError))
+    }
+
+    fn oid_name(oid: &ObjectIdentifier) -> String {
+        DB.by_oid(oid).map(|name| name.to_string()).unwrap_or_else(|| oid.to_string())
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(":")
+    }
+
+    fn ip_address_to_string(octets: &[u8]) -> String {
+        if octets.len() == 4 {
+            IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3])).to_string()
+        } else if octets.len() == 16 {
+            let mut v6 = [0u8; 16];
+            v6.copy_from_slice(octets);
+            IpAddr::V6(Ipv6Addr::from(v6)).to_string()
+        } else {
+            Self::hex(octets)
+        }
+    }
+}
+
+/// The padding scheme for RSA encryption. The `rsa` crate consumes the scheme for every operation.
+enum RsaEncryptionPadding {
+    Pkcs1v15(Pkcs1v15Encrypt),
+    Oaep(Oaep),
+}
+
+impl CryptoProvider for RustCryptoProvider {
+    fn name(&self) -> &'static str {
+        "RustCrypto"
+    }
+
+    fn random_bytes(&self, bytes: &mut [u8]) {
+        OsRng.fill_bytes(bytes);
+    }
+
+    fn hash(&self, hash_algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+        with_digest!(hash_algorithm, D => D::digest(data).to_vec())
+    }
+
+    fn hmac(&self, hash_algorithm: HashAlgorithm, key: &[u8], data: &[u8]) -> Vec<u8> {
+        with_digest!(hash_algorithm, D => {
+            let mut mac = <Hmac<D> as Mac>::new_from_slice(key).unwrap();
+            mac.update(data);
+            mac.finalize().into_bytes().to_vec()
+        })
+    }
+
+    fn aes_cbc_encrypt(&self, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let result = match key.len() {
+            16 => cbc::Encryptor::<Aes128>::new_from_slices(key, iv)
+                .map_err(|_| Self::aes_key_size_error(key))?
+                .encrypt_padded_b2b_mut::<NoPadding>(src, dst).map(|r| r.len()),
+            32 => cbc::Encryptor::<Aes256>::new_from_slices(key, iv)
+                .map_err(|_| Self::aes_key_size_error(key))?
+                .encrypt_padded_b2b_mut::<NoPadding>(src, dst).map(|r| r.len()),
+            _ => return Err(Self::aes_key_size_error(key))
+        };
+        result.map_err(bad("Cannot encrypt the data", BadUnexpectedError))
+    }
+
+    fn aes_cbc_decrypt(&self, key: &[u8], iv: &[u8], src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let result = match key.len() {
+            16 => cbc::Decryptor::<Aes128>::new_from_slices(key, iv)
+                .map_err(|_| Self::aes_key_size_error(key))?
+                .decrypt_padded_b2b_mut::<NoPadding>(src, dst).map(|r| r.len()),
+            32 => cbc::Decryptor::<Aes256>::new_from_slices(key, iv)
+                .map_err(|_| Self::aes_key_size_error(key))?
+                .decrypt_padded_b2b_mut::<NoPadding>(src, dst).map(|r| r.len()),
+            _ => return Err(Self::aes_key_size_error(key))
+        };
+        result.map_err(bad("Cannot decrypt the data", BadUnexpectedError))
+    }
+
+    fn generate_rsa_key(&self, bit_length: u32) -> Result<Vec<u8>, StatusCode> {
+        RsaPrivateKey::new(&mut OsRng, bit_length as usize)
+            .map_err(bad("Cannot generate an RSA key", BadUnexpectedError))?
+            .to_pkcs8_der()
+            .map(|der| der.as_bytes().to_vec())
+            .map_err(bad("Cannot encode the RSA key", BadUnexpectedError))
+    }
+
+    fn generate_ec_key(&self, curve: EcCurve) -> Result<Vec<u8>, StatusCode> {
+        with_curve!(curve, c => c::SecretKey::random(&mut OsRng).to_pkcs8_der())
+            .map(|der| der.as_bytes().to_vec())
+            .map_err(bad("Cannot encode the EC key", BadUnexpectedError))
+    }
+
+    fn private_key_info(&self, private_key: &[u8]) -> Result<KeyInfo, StatusCode> {
+        match Self::private_key(private_key)? {
+            AnyPrivateKey::Rsa(key) => {
+                let public_key = key.to_public_key().to_public_key_der().map_err(bad("Cannot encode the public key", BadUnexpectedError))?;
+                Ok(KeyInfo { key_type: KeyType::Rsa, bit_length: key.n().bits(), public_key: public_key.as_bytes().to_vec() })
+            }
+            AnyPrivateKey::Ec(curve) => {
+                let public_key = with_curve!(curve, c => {
+                    c::SecretKey::from_pkcs8_der(private_key)
+                        .map_err(bad("Cannot read the EC private key", BadUnexpectedError))?
+                        .public_key().to_public_key_der()
+                }).map_err(bad("Cannot encode the public key", BadUnexpectedError))?;
+                Ok(KeyInfo { key_type: KeyType::Ecc, bit_length: curve.coordinate_size() * 8, public_key: public_key.as_bytes().to_vec() })
+            }
+        }
+    }
+
+    fn public_key_info(&self, public_key: &[u8]) -> Result<KeyInfo, StatusCode> {
+        let info = SubjectPublicKeyInfoRef::try_from(public_key).map_err(bad("Cannot read the public key", BadUnexpectedError))?;
+        if info.algorithm.oid == RSA_ENCRYPTION {
+            let key = Self::rsa_public_key(public_key)?;
+            Ok(KeyInfo { key_type: KeyType::Rsa, bit_length: key.n().bits(), public_key: public_key.to_vec() })
+        } else if info.algorithm.oid == EC_PUBLIC_KEY {
+            let curve = Self::curve_from_parameters(info.algorithm.parameters_oid().ok())?;
+            let _ = with_curve!(curve, c => c::PublicKey::from_public_key_der(public_key).map(|_| ()))
+                .map_err(bad("Cannot read the EC public key", BadUnexpectedError))?;
+            Ok(KeyInfo { key_type: KeyType::Ecc, bit_length: curve.coordinate_size() * 8, public_key: public_key.to_vec() })
+        } else {
+            error!("Public key algorithm {} is unsupported", info.algorithm.oid);
+            Err(BadUnexpectedError)
+        }
+    }
+
+    fn private_key_from_pem(&self, pem: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let (label, der) = pem::decode_vec(pem).map_err(bad("Cannot read the PEM", BadUnexpectedError))?;
+        let der = match label {
+            "PRIVATE KEY" => der,
+            "RSA PRIVATE KEY" => {
+                RsaPrivateKey::from_pkcs1_der(&der)
+                    .map_err(bad("Cannot read the RSA private key", BadUnexpectedError))?
+                    .to_pkcs8_der()
+                    .map(|der| der.as_bytes().to_vec())
+                    .map_err(bad("Cannot encode the RSA private key", BadUnexpectedError))?
+            }
+            "EC PRIVATE KEY" => {
+                if let Ok(key) = p256::SecretKey::from_sec1_der(&der) {
+                    key.to_pkcs8_der()
+                } else {
+                    p384::SecretKey::from_sec1_der(&der)
+                        .map_err(bad("Cannot read the EC private key", BadUnexpectedError))?
+                        .to_pkcs8_der()
+                }.map(|der| der.as_bytes().to_vec()).map_err(bad("Cannot encode the EC private key", BadUnexpectedError))?
+            }
+            _ => {
+                error!("PEM label {} is not a private key", label);
+                return Err(BadUnexpectedError);
+            }
+        };
+        // Make sure the key is usable
+        let _ = self.private_key_info(&der)?;
+        Ok(der)
+    }
+
+    fn private_key_to_pem(&self, private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        pem::encode_string("PRIVATE KEY", LineEnding::LF, private_key)
+            .map(|pem| pem.into_bytes())
+            .map_err(bad("Cannot encode the PEM", BadUnexpectedError))
+    }
+
+    fn rsa_public_encrypt(&self, public_key: &[u8], padding: RsaPadding, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let key = Self::rsa_public_key(public_key)?;
+        let padding = Self::rsa_encryption_padding(padding)?;
+        let result = match padding {
+            RsaEncryptionPadding::Pkcs1v15(padding) => key.encrypt(&mut OsRng, padding, src),
+            RsaEncryptionPadding::Oaep(padding) => key.encrypt(&mut OsRng, padding, src),
+        }.map_err(|err| {
+            debug!("Can't encrypt data - error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        if dst.len() < result.len() {
+            error!("Destination buffer is too small for the cipher text");
+            return Err(BadUnexpectedError);
+        }
+        dst[..result.len()].copy_from_slice(&result);
+        Ok(result.len())
+    }
+
+    fn rsa_private_decrypt(&self, private_key: &[u8], padding: RsaPadding, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let key = Self::rsa_private_key(private_key)?;
+        let padding = Self::rsa_encryption_padding(padding)?;
+        let result = match padding {
+            RsaEncryptionPadding::Pkcs1v15(padding) => key.decrypt_blinded(&mut OsRng, padding, src),
+            RsaEncryptionPadding::Oaep(padding) => key.decrypt_blinded(&mut OsRng, padding, src),
+        }.map_err(|err| {
+            debug!("Can't decrypt data - error = {:?}", err);
+            BadUnexpectedError
+        })?;
+        if dst.len() < result.len() {
+            error!("Destination buffer is too small for the plain text");
+            return Err(BadUnexpectedError);
+        }
+        dst[..result.len()].copy_from_slice(&result);
+        Ok(result.len())
+    }
+
+    fn rsa_sign(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, padding: RsaPadding, data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let key = Self::rsa_private_key(private_key)?;
+        let hashed = self.hash(hash_algorithm, data);
+        let result = with_digest!(hash_algorithm, D => {
+            match padding {
+                RsaPadding::PKCS1 => key.sign(Pkcs1v15Sign::new::<D>(), &hashed),
+                RsaPadding::PSS => key.sign_with_rng(&mut OsRng, Pss::new_with_salt::<D>(hash_algorithm.size()), &hashed),
+                _ => {
+                    error!("Padding {:?} is not for signing", padding);
+                    return Err(BadUnexpectedError);
+                }
+            }
+        });
+        result.map_err(|err| {
+            debug!("Can't sign data - error = {:?}", err);
+            BadUnexpectedError
+        })
+    }
+
+    fn rsa_verify(&self, public_key: &[u8], hash_algorithm: HashAlgorithm, padding: RsaPadding, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        let key = Self::rsa_public_key(public_key)?;
+        let hashed = self.hash(hash_algorithm, data);
+        let result = with_digest!(hash_algorithm, D => {
+            match padding {
+                RsaPadding::PKCS1 => key.verify(Pkcs1v15Sign::new::<D>(), &hashed, signature),
+                RsaPadding::PSS => key.verify(Pss::new_with_salt::<D>(hash_algorithm.size()), &hashed, signature),
+                _ => {
+                    error!("Padding {:?} is not for signing", padding);
+                    return Err(BadUnexpectedError);
+                }
+            }
+        });
+        // Any failure, including a signature of the wrong length, is an invalid signature
+        Ok(result.is_ok())
+    }
+
+    fn ecdsa_sign(&self, private_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        use p256::ecdsa::signature::hazmat::PrehashSigner;
+        let curve = Self::ec_private_key_curve(private_key)?;
+        let hashed = self.hash(hash_algorithm, data);
+        with_curve!(curve, c => {
+            let key = c::SecretKey::from_pkcs8_der(private_key).map_err(bad("Cannot read the EC private key", BadUnexpectedError))?;
+            let signing_key = c::ecdsa::SigningKey::from(key);
+            let signature: c::ecdsa::Signature = signing_key.sign_prehash(&hashed).map_err(|err| {
+                debug!("Can't sign data - error = {:?}", err);
+                BadUnexpectedError
+            })?;
+            Ok(signature.to_bytes().to_vec())
+        })
+    }
+
+    fn ecdsa_verify(&self, public_key: &[u8], hash_algorithm: HashAlgorithm, data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
+        use p256::ecdsa::signature::hazmat::PrehashVerifier;
+        let curve = Self::ec_public_key_curve(public_key)?;
+        if signature.len() != curve.coordinate_size() * 2 {
+            return Ok(false);
+        }
+        let hashed = self.hash(hash_algorithm, data);
+        with_curve!(curve, c => {
+            let key = c::PublicKey::from_public_key_der(public_key).map_err(bad("Cannot read the EC public key", BadUnexpectedError))?;
+            let verifying_key = c::ecdsa::VerifyingKey::from(key);
+            // A malformed signature is just an invalid signature
+            if let Ok(signature) = c::ecdsa::Signature::from_slice(signature) {
+                Ok(verifying_key.verify_prehash(&hashed, &signature).is_ok())
+            } else {
+                Ok(false)
+            }
+        })
+    }
+
+    fn ec_public_key_bytes(&self, private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let curve = Self::ec_private_key_curve(private_key)?;
+        with_curve!(curve, c => {
+            let key = c::SecretKey::from_pkcs8_der(private_key).map_err(bad("Cannot read the EC private key", BadUnexpectedError))?;
+            // Remove the leading 0x04 which says the point is uncompressed
+            Ok(key.public_key().to_encoded_point(false).as_bytes()[1..].to_vec())
+        })
+    }
+
+    fn ec_is_valid_public_key(&self, curve: EcCurve, public_key: &[u8]) -> bool {
+        if public_key.len() != curve.coordinate_size() * 2 {
+            return false;
+        }
+        // Prepend the 0x04 that says the point is uncompressed
+        let mut bytes = Vec::with_capacity(public_key.len() + 1);
+        bytes.push(0x04);
+        bytes.extend_from_slice(public_key);
+        with_curve!(curve, c => c::PublicKey::from_sec1_bytes(&bytes).is_ok())
+    }
+
+    fn ecdh_shared_secret(&self, private_key: &[u8], curve: EcCurve, public_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        if Self::ec_private_key_curve(private_key)? != curve || public_key.len() != curve.coordinate_size() * 2 {
+            return Err(BadNonceInvalid);
+        }
+        let mut bytes = Vec::with_capacity(public_key.len() + 1);
+        bytes.push(0x04);
+        bytes.extend_from_slice(public_key);
+        with_curve!(curve, c => {
+            let key = c::SecretKey::from_pkcs8_der(private_key).map_err(bad("Cannot read the EC private key", BadUnexpectedError))?;
+            let remote_public_key = c::PublicKey::from_sec1_bytes(&bytes).map_err(bad("Public key is not a point on the curve", BadNonceInvalid))?;
+            let shared_secret = c::ecdh::diffie_hellman(key.to_nonzero_scalar(), remote_public_key.as_affine());
+            Ok(shared_secret.raw_secret_bytes().to_vec())
+        })
+    }
+
+    fn parse_certificate(&self, cert: &[u8]) -> Result<CertificateInfo, StatusCode> {
+        let cert = Self::certificate(cert)?;
+        let tbs = &cert.tbs_certificate;
+        let alt_names = Self::decode_extension::<SubjectAltName>(&tbs.extensions)
+            .map(|alt_names| alt_names.0.iter().map(Self::alt_name).collect());
+        let basic_constraints = Self::decode_extension::<BasicConstraints>(&tbs.extensions);
+        Ok(CertificateInfo {
+            subject_name: Self::to_der(&tbs.subject)?,
+            issuer_name: Self::to_der(&tbs.issuer)?,
+            common_name: Self::common_name(&tbs.subject),
+            serial_number: strip_leading_zeros(tbs.serial_number.as_bytes()),
+            not_before: Some(Self::from_time(&tbs.validity.not_before)),
+            not_after: Some(Self::from_time(&tbs.validity.not_after)),
+            alt_names,
+            public_key: Self::to_der(&tbs.subject_public_key_info)?,
+            is_ca: basic_constraints.as_ref().map_or(false, |basic_constraints| basic_constraints.ca),
+            path_len_constraint: basic_constraints.and_then(|basic_constraints| basic_constraints.path_len_constraint).map(u32::from),
+        })
+    }
+
+    fn verify_certificate_signature(&self, cert: &[u8], public_key: &[u8]) -> bool {
+        if let Ok((tbs, algorithm, signature)) = Self::signed_parts(cert) {
+            self.verify_signed(public_key, &algorithm, tbs, &signature)
+        } else {
+            false
+        }
+    }
+
+    fn check_issued(&self, issuer_cert: &[u8], subject_cert: &[u8]) -> bool {
+        let (issuer_cert, subject_cert) = match (Self::certificate(issuer_cert), Self::certificate(subject_cert)) {
+            (Ok(issuer_cert), Ok(subject_cert)) => (issuer_cert.tbs_certificate, subject_cert.tbs_certificate),
+            _ => return false
+        };
+        if issuer_cert.subject != subject_cert.issuer {
+            return false;
+        }
+        // Only a CA may issue certs, whatever its key usage says
+        if !Self::decode_extension::<BasicConstraints>(&issuer_cert.extensions).map_or(false, |basic_constraints| basic_constraints.ca) {
+            return false;
+        }
+        // The key identifiers must agree if both certs have them
+        let subject_key_identifier = Self::decode_extension::<SubjectKeyIdentifier>(&issuer_cert.extensions);
+        let authority_key_identifier = Self::decode_extension::<AuthorityKeyIdentifier>(&subject_cert.extensions)
+            .and_then(|authority_key_identifier| authority_key_identifier.key_identifier);
+        if let (Some(subject_key_identifier), Some(authority_key_identifier)) = (subject_key_identifier, authority_key_identifier) {
+            if subject_key_identifier.0 != authority_key_identifier {
+                return false;
+            }
+        }
+        // The issuer key must be allowed to sign certs if its use is restricted
+        if let Some(key_usage) = Self::decode_extension::<KeyUsageExt>(&issuer_cert.extensions) {
+            key_usage.0.contains(KeyUsages::KeyCertSign)
+        } else {
+            true
+        }
+    }
+
+    fn certificate_to_text(&self, cert: &[u8]) -> Result<String, StatusCode> {
+        let cert = Self::certificate(cert)?;
+        let tbs = &cert.tbs_certificate;
+        let mut text = String::new();
+        text.push_str("Certificate:\n    Data:\n");
+        text.push_str(&format!("        Version: {:?}\n", tbs.version));
+        text.push_str(&format!("        Serial Number: {}\n", Self::hex(tbs.serial_number.as_bytes())));
+        text.push_str(&format!("        Signature Algorithm: {}\n", Self::oid_name(&cert.signature_algorithm.oid)));
+        text.push_str(&format!("        Issuer: {}\n", tbs.issuer));
+        text.push_str("        Validity\n");
+        text.push_str(&format!("            Not Before: {}\n", Self::from_time(&tbs.validity.not_before)));
+        text.push_str(&format!("            Not After : {}\n", Self::from_time(&tbs.validity.not_after)));
+        text.push_str(&format!("        Subject: {}\n", tbs.subject));
+        text.push_str("        Subject Public Key Info:\n");
+        text.push_str(&format!("            Public Key Algorithm: {}\n", Self::oid_name(&tbs.subject_public_key_info.algorithm.oid)));
+        if let Ok(info) = Self::to_der(&tbs.subject_public_key_info).and_then(|der| self.public_key_info(&der)) {
+            text.push_str(&format!("                Public-Key: ({} bit)\n", info.bit_length));
+        }
+        if let Some(ref extensions) = tbs.extensions {
+            text.push_str("        X509v3 extensions:\n");
+            for extension in extensions {
+                text.push_str(&format!("            {}:{}\n", Self::oid_name(&extension.extn_id), if extension.critical { " critical" } else { "" }));
+                let value = if extension.extn_id == SubjectAltName::OID {
+                    SubjectAltName::from_der(extension.extn_value.as_bytes()).ok().map(|alt_names| {
+                        alt_names.0.iter().map(|n| match Self::alt_name(n) {
+                            AltName::Uri(uri) => format!("URI:{}", uri),
+                            AltName::Dns(dns) => format!("DNS:{}", dns),
+                            AltName::IpAddress(ip_address) => format!("IP Address:{}", Self::ip_address_to_string(&ip_address)),
+                            AltName::Other => "othername".to_string(),
+                        }).collect::<Vec<_>>().join(", ")
+                    })
+                } else if extension.extn_id == BasicConstraints::OID {
+                    BasicConstraints::from_der(extension.extn_value.as_bytes()).ok().map(|b| format!("CA:{}", if b.ca { "TRUE" } else { "FALSE" }))
+                } else if extension.extn_id == KeyUsageExt::OID {
+                    KeyUsageExt::from_der(extension.extn_value.as_bytes()).ok().map(|k| format!("{:?}", k.0))
+                } else if extension.extn_id == ExtendedKeyUsage::OID {
+                    ExtendedKeyUsage::from_der(extension.extn_value.as_bytes()).ok().map(|e| e.0.iter().map(Self::oid_name).collect::<Vec<_>>().join(", "))
+                } else {
+                    None
+                };
+                let value = value.unwrap_or_else(|| Self::hex(extension.extn_value.as_bytes()));
+                text.push_str(&format!("                {}\n", value));
+            }
+        }
+        text.push_str(&format!("    Signature Algorithm: {}\n", Self::oid_name(&cert.signature_algorithm.oid)));
+        text.push_str(&format!("         {}\n", Self::hex(cert.signature.raw_bytes())));
+        Ok(text)
+    }
+
+    fn encode_name(&self, entries: &[(&str, &str)]) -> Result<Vec<u8>, StatusCode> {
+        let mut rdns = Vec::with_capacity(entries.len());
+        for &(field, value) in entries {
+            let oid = match field {
+                "CN" => COMMON_NAME,
+                "O" => ORGANIZATION_NAME,
+                "OU" => ORGANIZATIONAL_UNIT_NAME,
+                "C" => COUNTRY_NAME,
+                "ST" => STATE_OR_PROVINCE_NAME,
+                _ => {
+                    error!("Name field {} is unsupported", field);
+                    return Err(BadInvalidArgument);
+                }
+            };
+            // The country is a printable string, like OpenSSL makes it
+            let value = if oid == COUNTRY_NAME {
+                PrintableStringRef::new(value).and_then(|value| Any::encode_from(&value))
+            } else {
+                Utf8StringRef::new(value).and_then(|value| Any::encode_from(&value))
+            }.map_err(bad("Cannot encode the name", BadInvalidArgument))?;
+            let rdn = SetOfVec::try_from(vec![AttributeTypeAndValue { oid, value }])
+                .map_err(bad("Cannot encode the name", BadInvalidArgument))?;
+            rdns.push(RelativeDistinguishedName(rdn));
+        }
+        Self::to_der(&Name::from(rdns))
+    }
+
+    fn create_certificate(&self, params: &CertificateParams, issuer_private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let issuer_cert = if let Some(ref issuer_cert) = params.issuer_cert {
+            Some(Self::certificate(issuer_cert)?.tbs_certificate)
+        } else {
+            None
+        };
+        let subject = Name::from_der(&params.subject_name).map_err(bad("Cannot read the name", BadInvalidArgument))?;
+        let issuer = if let Some(ref issuer_cert) = issuer_cert {
+            issuer_cert.subject.clone()
+        } else {
+            // Issuer and subject shall be the same for self-signed cert
+            subject.clone()
+        };
+        let subject_public_key_info = SubjectPublicKeyInfoOwned::from_der(&params.public_key)
+            .map_err(bad("Cannot read the public key", BadInvalidArgument))?;
+        let serial_number = SerialNumber::new(&params.serial_number).map_err(bad("Cannot encode the serial number", BadInvalidArgument))?;
+
+        let mut extensions = Vec::new();
+        if let Some(ca) = params.basic_constraints_ca {
+            extensions.push(Self::make_extension(&BasicConstraints { ca, path_len_constraint: params.path_len_constraint.map(|path_len| path_len.min(255) as u8) }, true)?);
+        }
+        if let Some((usage, critical)) = params.key_usage {
+            let mut flags = FlagSet::<KeyUsages>::default();
+            if usage.digital_signature { flags |= KeyUsages::DigitalSignature; }
+            if usage.non_repudiation { flags |= KeyUsages::NonRepudiation; }
+            if usage.key_encipherment { flags |= KeyUsages::KeyEncipherment; }
+            if usage.data_encipherment { flags |= KeyUsages::DataEncipherment; }
+            if usage.key_agreement { flags |= KeyUsages::KeyAgreement; }
+            if usage.key_cert_sign { flags |= KeyUsages::KeyCertSign; }
+            if usage.crl_sign { flags |= KeyUsages::CRLSign; }
+            extensions.push(Self::make_extension(&KeyUsageExt(flags), critical)?);
+        }
+        if params.extended_key_usage {
+            extensions.push(Self::make_extension(&ExtendedKeyUsage(vec![ID_KP_CLIENT_AUTH, ID_KP_SERVER_AUTH]), false)?);
+        }
+        let key_identifier = Self::key_identifier(&subject_public_key_info);
+        if params.subject_key_identifier {
+            let key_identifier = OctetString::new(key_identifier.clone()).map_err(bad("Cannot encode the key identifier", BadUnexpectedError))?;
+            extensions.push(Self::make_extension(&SubjectKeyIdentifier(key_identifier), false)?);
+        }
+        if params.authority_key_identifier {
+            // The key identifier of the issuer, or of the cert itself if it is self-signed
+            let authority_key_identifier = if let Some(ref issuer_cert) = issuer_cert {
+                Self::decode_extension::<SubjectKeyIdentifier>(&issuer_cert.extensions).map(|k| k.0)
+            } else if params.subject_key_identifier {
+                OctetString::new(key_identifier).ok()
+            } else {
+                None
+            };
+            if let Some(key_identifier) = authority_key_identifier {
+                let authority_key_identifier = AuthorityKeyIdentifier {
+                    key_identifier: Some(key_identifier),
+                    authority_cert_issuer: None,
+                    authority_cert_serial_number: None,
+                };
+                extensions.push(Self::make_extension(&authority_key_identifier, false)?);
+            }
+        }
+        if !params.alt_names.is_empty() {
+            extensions.push(Self::make_extension(&SubjectAltName(Self::general_names(&params.alt_names)?), false)?);
+        }
+
+        // The algorithm is in the signed part so it is worked out before signing
+        let signature_algorithm = signature_algorithm(Self::key_type(issuer_private_key)?, params.hash_algorithm);
+        let tbs_certificate = TbsCertificate {
+            version: Version::V3,
+            serial_number,
+            signature: signature_algorithm.clone(),
+            issuer,
+            validity: Validity {
+                not_before: Self::to_time(&params.not_before)?,
+                not_after: Self::to_time(&params.not_after)?,
+            },
+            subject,
+            subject_public_key_info,
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: if extensions.is_empty() { None } else { Some(extensions) },
+        };
+        let tbs = Self::to_der(&tbs_certificate)?;
+        let (_, signature) = self.sign_signed(issuer_private_key, params.hash_algorithm, &tbs)?;
+        let cert = Certificate {
+            tbs_certificate,
+            signature_algorithm,
+            signature: BitString::from_bytes(&signature).map_err(bad("Cannot encode the signature", BadUnexpectedError))?,
+        };
+        Self::to_der(&cert)
+    }
+
+    fn create_signing_request(&self, subject_name: &[u8], private_key: &[u8], alt_names: &[AltName], hash_algorithm: HashAlgorithm) -> Result<Vec<u8>, StatusCode> {
+        let key_info = self.private_key_info(private_key)?;
+        let subject = Name::from_der(subject_name).map_err(bad("Cannot read the name", BadInvalidArgument))?;
+        let public_key = SubjectPublicKeyInfoOwned::from_der(&key_info.public_key).map_err(bad("Cannot read the public key", BadUnexpectedError))?;
+        let mut attributes = SetOfVec::new();
+        if !alt_names.is_empty() {
+            let extensions = ExtensionReq(vec![Self::make_extension(&SubjectAltName(Self::general_names(alt_names)?), false)?]);
+            let value = Any::encode_from(&extensions).map_err(bad("Cannot encode the extensions", BadUnexpectedError))?;
+            let attribute = Attribute {
+                oid: ExtensionReq::OID,
+                values: SetOfVec::try_from(vec![value]).map_err(bad("Cannot encode the extensions", BadUnexpectedError))?,
+            };
+            attributes.insert(attribute).map_err(bad("Cannot encode the extensions", BadUnexpectedError))?;
+        }
+        let info = CertReqInfo {
+            version: x509_cert::request::Version::V1,
+            subject,
+            public_key,
+            attributes,
+        };
+        let (algorithm, signature) = self.sign_signed(private_key, hash_algorithm, &Self::to_der(&info)?)?;
+        let request = CertReq {
+            info,
+            algorithm,
+            signature: BitString::from_bytes(&signature).map_err(bad("Cannot encode the signature", BadUnexpectedError))?,
+        };
+        Self::to_der(&request)
+    }
+
+    fn parse_signing_request(&self, request: &[u8]) -> Result<SigningRequestInfo, StatusCode> {
+        let request = CertReq::from_der(request).map_err(bad("Cannot read the signing request", BadInvalidArgument))?;
+        Ok(SigningRequestInfo {
+            subject_name: Self::to_der(&request.info.subject)?,
+            public_key: Self::to_der(&request.info.public_key)?,
+        })
+    }
+
+    fn verify_signing_request(&self, request: &[u8]) -> bool {
+        match (CertReq::from_der(request), Self::signed_parts(request)) {
+            (Ok(request), Ok((info, algorithm, signature))) => {
+                if let Ok(public_key) = request.info.public_key.to_der() {
+                    self.verify_signed(&public_key, &algorithm, info, &signature)
+                } else {
+                    false
+                }
+            }
+            _ => false
+        }
+    }
+
+    fn parse_crl(&self, crl: &[u8]) -> Result<CrlInfo, StatusCode> {
+        let crl = CertificateList::from_der(crl).map_err(bad("Cannot read the revocation list", BadCertificateInvalid))?;
+        let revoked_serial_numbers = crl.tbs_cert_list.revoked_certificates
+            .map(|revoked| revoked.iter().map(|r| strip_leading_zeros(r.serial_number.as_bytes())).collect())
+            .unwrap_or_default();
+        Ok(CrlInfo {
+            issuer_name: Self::to_der(&crl.tbs_cert_list.issuer)?,
+            revoked_serial_numbers,
+            next_update: crl.tbs_cert_list.next_update.as_ref().map(Self::from_time),
+        })
+    }
+
+    fn crl_from_pem(&self, pem: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let (label, der) = pem::decode_vec(pem).map_err(bad("Cannot read the PEM", BadCertificateInvalid))?;
+        if label == "X509 CRL" {
+            Ok(der)
+        } else {
+            error!("PEM label {} is not a revocation list", label);
+            Err(BadCertificateInvalid)
+        }
+    }
+
+    fn verify_crl_signature(&self, crl: &[u8], public_key: &[u8]) -> bool {
+        if let Ok((tbs, algorithm, signature)) = Self::signed_parts(crl) {
+            self.verify_signed(public_key, &algorithm, tbs, &signature)
+        } else {
+            false
+        }
+    }
+
+    fn create_crl(&self, params: &CrlParams, issuer_private_key: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let issuer_cert = Self::certificate(&params.issuer_cert)?.tbs_certificate;
+        let key_identifier = if let Some(key_identifier) = Self::decode_extension::<SubjectKeyIdentifier>(&issuer_cert.extensions) {
+            key_identifier.0
+        } else {
+            error!("CA cert has no subject key identifier");
+            return Err(BadCertificateInvalid);
+        };
+        let authority_key_identifier = AuthorityKeyIdentifier {
+            key_identifier: Some(key_identifier),
+            authority_cert_issuer: None,
+            authority_cert_serial_number: None,
+        };
+        let crl_number = Uint::new(&params.crl_number.to_be_bytes()).map_err(bad("Cannot encode the list number", BadUnexpectedError))?;
+        let revocation_date = Self::to_time(&params.last_update)?;
+        let mut revoked_certificates = Vec::with_capacity(params.revoked_serial_numbers.len());
+        for serial_number in &params.revoked_serial_numbers {
+            revoked_certificates.push(RevokedCert {
+                serial_number: SerialNumber::new(serial_number).map_err(bad("Cannot encode the serial number", BadInvalidArgument))?,
+                revocation_date,
+                crl_entry_extensions: None,
+            });
+        }
+
+        let signature_algorithm = signature_algorithm(Self::key_type(issuer_private_key)?, params.hash_algorithm);
+        let tbs_cert_list = TbsCertList {
+            version: Version::V2,
+            signature: signature_algorithm.clone(),
+            issuer: issuer_cert.subject,
+            this_update: Self::to_time(&params.last_update)?,
+            next_update: Some(Self::to_time(&params.next_update)?),
+            revoked_certificates: if revoked_certificates.is_empty() { None } else { Some(revoked_certificates) },
+            crl_extensions: Some(vec![
+                Self::make_extension(&authority_key_identifier, false)?,
+                Self::make_extension(&CrlNumber(crl_number), false)?,
+            ]),
+        };
+        let tbs = Self::to_der(&tbs_cert_list)?;
+        let (_, signature) = self.sign_signed(issuer_private_key, params.hash_algorithm, &tbs)?;
+        let crl = CertificateList {
+            tbs_cert_list,
+            signature_algorithm,
+            signature: BitString::from_bytes(&signature).map_err(bad("Cannot encode the signature", BadUnexpectedError))?,
+        };
+        Self::to_der(&crl)
+    }
+}
+
+/// The signature algorithm for a key type and digest
+fn signature_algorithm(key_type: KeyType, hash_algorithm: HashAlgorithm) -> AlgorithmIdentifierOwned {
+    match key_type {
+        KeyType::Rsa => {
+            let oid = match hash_algorithm {
+                HashAlgorithm::Sha1 => SHA1_WITH_RSA_ENCRYPTION,
+                HashAlgorithm::Sha256 => SHA256_WITH_RSA_ENCRYPTION,
+                HashAlgorithm::Sha384 => SHA384_WITH_RSA_ENCRYPTION,
+            };
+            AlgorithmIdentifierOwned { oid, parameters: Some(Any::null()) }
+        }
+        KeyType::Ecc => {
+            let oid = match hash_algorithm {
+                HashAlgorithm::Sha1 => ECDSA_WITH_SHA1,
+                HashAlgorithm::Sha256 => ECDSA_WITH_SHA256,
+                HashAlgorithm::Sha384 => ECDSA_WITH_SHA384,
+            };
+            AlgorithmIdentifierOwned { oid, parameters: None }
+        }
+    }
+}
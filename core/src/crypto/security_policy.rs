@@ -3,16 +3,15 @@
 use std::fmt;
 use std::str::FromStr;
 
-use openssl::hash as openssl_hash;
-
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 use opcua_types::ByteString;
 
-use crypto::{SHA1_SIZE, SHA256_SIZE};
+use crypto::{SHA1_SIZE, SHA256_SIZE, SHA384_SIZE};
 use crypto::aeskey::AesKey;
 use crypto::pkey::{PrivateKey, PublicKey, RsaPadding, KeySize};
 use crypto::hash;
+use crypto::hash::HashAlgorithm;
 
 /// URI supplied for the None security policy
 pub const SECURITY_POLICY_NONE_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#None";
@@ -22,6 +21,14 @@ pub const SECURITY_POLICY_BASIC_128_RSA_15_URI: &'static str = "http://opcfounda
 pub const SECURITY_POLICY_BASIC_256_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#Basic256";
 /// URI supplied for the `Basic256Sha256` security policy
 pub const SECURITY_POLICY_BASIC_256_SHA_256_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256";
+/// URI supplied for the `Aes128_Sha256_RsaOaep` security policy
+pub const SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#Aes128_Sha256_RsaOaep";
+/// URI supplied for the `Aes256_Sha256_RsaPss` security policy
+pub const SECURITY_POLICY_AES_256_SHA_256_RSA_PSS_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#Aes256_Sha256_RsaPss";
+/// URI supplied for the `ECC_nistP256` security policy
+pub const SECURITY_POLICY_ECC_NIST_P256_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#ECC_nistP256";
+/// URI supplied for the `ECC_nistP384` security policy
+pub const SECURITY_POLICY_ECC_NIST_P384_URI: &'static str = "http://opcfoundation.org/UA/SecurityPolicy#ECC_nistP384";
 
 /// String used as shorthand in config files, debug etc.for `None` security policy
 pub const SECURITY_POLICY_NONE: &'static str = "None";
@@ -31,6 +38,14 @@ pub const SECURITY_POLICY_BASIC_128_RSA_15: &'static str = "Basic128Rsa15";
 pub const SECURITY_POLICY_BASIC_256: &'static str = "Basic256";
 /// String used as shorthand in config files, debug etc.for `Basic256Sha256` security policy
 pub const SECURITY_POLICY_BASIC_256_SHA_256: &'static str = "Basic256Sha256";
+/// String used as shorthand in config files, debug etc.for `Aes128_Sha256_RsaOaep` security policy
+pub const SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP: &'static str = "Aes128-Sha256-RsaOaep";
+/// String used as shorthand in config files, debug etc.for `Aes256_Sha256_RsaPss` security policy
+pub const SECURITY_POLICY_AES_256_SHA_256_RSA_PSS: &'static str = "Aes256-Sha256-RsaPss";
+/// String used as shorthand in config files, debug etc.for `ECC_nistP256` security policy
+pub const SECURITY_POLICY_ECC_NIST_P256: &'static str = "ECC-nistP256";
+/// String used as shorthand in config files, debug etc.for `ECC_nistP384` security policy
+pub const SECURITY_POLICY_ECC_NIST_P384: &'static str = "ECC-nistP384";
 
 // These are constants that govern the different encryption / signing modes for OPC UA. In some
 // cases these algorithm string constants will be passed over the wire and code needs to test the
@@ -75,6 +90,9 @@ pub mod basic128rsa15 {
     /// Symmetric key length - 128 / 16 bytes
     pub const SYMMETRIC_KEY_LENGTH: usize = 128;
 
+    /// SecureChannelNonceLength - 16 bytes
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 16;
+
     /// CertificateSignatureAlgorithm – Sha1
     ///
     /// If a certificate or any certificate in the chain is not signed with a hash that is Sha1 or stronger then the certificate shall be rejected.
@@ -120,6 +138,9 @@ pub mod basic256 {
     /// Symmetric key length - 256 / 32 bytes
     pub const SYMMETRIC_KEY_LENGTH: usize = 256;
 
+    /// SecureChannelNonceLength - 32 bytes
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 32;
+
     /// CertificateSignatureAlgorithm –
     ///
     /// Sha1 [deprecated] or Sha256 [recommended]
@@ -169,6 +190,9 @@ pub mod basic256sha256 {
     /// Symmetric key length - 256 / 32 bytes
     pub const SYMMETRIC_KEY_LENGTH: usize = 256;
 
+    /// SecureChannelNonceLength - 32 bytes
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 32;
+
     /// CertificateSignatureAlgorithm – Sha256
     ///
     /// If a certificate or any certificate in the chain is not signed with a hash that is Sha256 or stronger
@@ -179,6 +203,190 @@ pub mod basic256sha256 {
     pub const CERTIFICATE_SIGNATURE_ALGORITHM: &'static str = "Sha256";
 }
 
+/// Aes128_Sha256_RsaOaep
+///
+/// A suite of algorithms that uses Sha256 for hashing, RSA-OAEP for key wrap and 128-Bit for
+/// symmetric encryption.
+pub mod aes128sha256rsaoaep {
+    use crypto::algorithms::*;
+
+    /// SymmetricSignatureAlgorithm – Hmac_Sha256 – (http://www.w3.org/2000/09/xmldsig#hmac-sha256).
+    pub const SYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_HMAC_SHA256;
+
+    /// SymmetricEncryptionAlgorithm – Aes128_CBC – (http://www.w3.org/2001/04/xmlenc#aes128-cbc).
+    pub const SYMMETRIC_ENCRYPTION_ALGORITHM: &'static str = ENC_AES128_CBC;
+
+    /// AsymmetricSignatureAlgorithm – Rsa_Sha256 – (http://www.w3.org/2001/04/xmldsig-more#rsa-sha256).
+    pub const ASYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_RSA_SHA256;
+
+    /// AsymmetricKeyWrapAlgorithm – KwRsaOaep – (http://www.w3.org/2001/04/xmlenc#rsa-oaep-mgf1p).
+    pub const ASYMMETRIC_KEY_WRAP_ALGORITHM: &'static str = ENC_RSA_OAEP_MGF1P;
+
+    /// AsymmetricEncryptionAlgorithm – Rsa_Oaep_Sha1 – (http://www.w3.org/2001/04/xmlenc#rsa-oaep).
+    pub const ASYMMETRIC_ENCRYPTION_ALGORITHM: &'static str = ENC_RSA_OAEP;
+
+    /// KeyDerivationAlgorithm – PSHA256 – (http://docs.oasis-open.org/ws-sx/ws-secureconversation/200512/dk/p_sha256).
+    pub const KEY_DERIVATION_ALGORITHM: &'static str = KEY_P_SHA256;
+
+    /// DerivedSignatureKeyLength – 256 / 32 bytes.
+    pub const DERIVED_SIGNATURE_KEY_LENGTH: usize = 256;
+
+    /// DerivedEncryptionKeyLength – 128 / 16 bytes.
+    pub const DERIVED_ENCRYPTION_KEY_LENGTH: usize = 128;
+
+    /// MinAsymmetricKeyLength – 2048
+    pub const MIN_ASYMMETRIC_KEY_LENGTH: usize = 2048;
+
+    /// MaxAsymmetricKeyLength – 4096
+    pub const MAX_ASYMMETRIC_KEY_LENGTH: usize = 4096;
+
+    /// Symmetric key length - 128 / 16 bytes
+    pub const SYMMETRIC_KEY_LENGTH: usize = 128;
+
+    /// SecureChannelNonceLength - 32 bytes
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 32;
+
+    /// CertificateSignatureAlgorithm – Sha256
+    ///
+    /// If a certificate or any certificate in the chain is not signed with a hash that is Sha256 or stronger
+    /// then the certificate shall be rejected.
+    pub const CERTIFICATE_SIGNATURE_ALGORITHM: &'static str = "Sha256";
+}
+
+/// Aes256_Sha256_RsaPss
+///
+/// A suite of algorithms that uses Sha256 for hashing, RSA-PSS for signatures, RSA-OAEP-SHA256 for
+/// key wrap and 256-Bit for symmetric encryption.
+pub mod aes256sha256rsapss {
+    use crypto::algorithms::*;
+
+    /// SymmetricSignatureAlgorithm – Hmac_Sha256 – (http://www.w3.org/2000/09/xmldsig#hmac-sha256).
+    pub const SYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_HMAC_SHA256;
+
+    /// SymmetricEncryptionAlgorithm – Aes256_CBC – (http://www.w3.org/2001/04/xmlenc#aes256-cbc).
+    pub const SYMMETRIC_ENCRYPTION_ALGORITHM: &'static str = ENC_AES256_CBC;
+
+    /// AsymmetricSignatureAlgorithm – Rsa_Pss_Sha256 – (http://opcfoundation.org/UA/security/rsa-pss-sha2-256).
+    pub const ASYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_RSA_PSS_SHA256;
+
+    /// AsymmetricKeyWrapAlgorithm – KwRsaOaepSha256 – (http://opcfoundation.org/UA/security/rsa-oaep-sha2-256).
+    pub const ASYMMETRIC_KEY_WRAP_ALGORITHM: &'static str = ENC_RSA_OAEP_SHA256;
+
+    /// AsymmetricEncryptionAlgorithm – Rsa_Oaep_Sha256 – (http://opcfoundation.org/UA/security/rsa-oaep-sha2-256).
+    pub const ASYMMETRIC_ENCRYPTION_ALGORITHM: &'static str = ENC_RSA_OAEP_SHA256;
+
+    /// KeyDerivationAlgorithm – PSHA256 – (http://docs.oasis-open.org/ws-sx/ws-secureconversation/200512/dk/p_sha256).
+    pub const KEY_DERIVATION_ALGORITHM: &'static str = KEY_P_SHA256;
+
+    /// DerivedSignatureKeyLength – 256 / 32 bytes.
+    pub const DERIVED_SIGNATURE_KEY_LENGTH: usize = 256;
+
+    /// DerivedEncryptionKeyLength – 256 / 32 bytes.
+    pub const DERIVED_ENCRYPTION_KEY_LENGTH: usize = 256;
+
+    /// MinAsymmetricKeyLength – 2048
+    pub const MIN_ASYMMETRIC_KEY_LENGTH: usize = 2048;
+
+    /// MaxAsymmetricKeyLength – 4096
+    pub const MAX_ASYMMETRIC_KEY_LENGTH: usize = 4096;
+
+    /// Symmetric key length - 256 / 32 bytes
+    pub const SYMMETRIC_KEY_LENGTH: usize = 256;
+
+    /// SecureChannelNonceLength - 32 bytes
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 32;
+
+    /// CertificateSignatureAlgorithm – Sha256
+    ///
+    /// If a certificate or any certificate in the chain is not signed with a hash that is Sha256 or stronger
+    /// then the certificate shall be rejected.
+    pub const CERTIFICATE_SIGNATURE_ALGORITHM: &'static str = "Sha256";
+}
+
+/// ECC_nistP256
+///
+/// A suite of algorithms that uses ECDH on the NIST P-256 curve for key agreement, ECDSA for
+/// asymmetric signatures and 128-Bit for symmetric encryption. OpenSecureChannel messages are
+/// signed but not encrypted, the nonces carry the ephemeral public keys.
+pub mod ecc_nistp256 {
+    use crypto::algorithms::*;
+
+    /// SymmetricSignatureAlgorithm – Hmac_Sha256 – (http://www.w3.org/2000/09/xmldsig#hmac-sha256).
+    pub const SYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_HMAC_SHA256;
+
+    /// SymmetricEncryptionAlgorithm – Aes128_CBC – (http://www.w3.org/2001/04/xmlenc#aes128-cbc).
+    pub const SYMMETRIC_ENCRYPTION_ALGORITHM: &'static str = ENC_AES128_CBC;
+
+    /// AsymmetricSignatureAlgorithm – Ecdsa_Sha256 – (http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256).
+    pub const ASYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_ECDSA_SHA256;
+
+    /// KeyDerivationAlgorithm – HKDF-SHA256 – (http://opcfoundation.org/UA/security/hkdf-sha256).
+    pub const KEY_DERIVATION_ALGORITHM: &'static str = KEY_HKDF_SHA256;
+
+    /// DerivedSignatureKeyLength – 256 / 32 bytes.
+    pub const DERIVED_SIGNATURE_KEY_LENGTH: usize = 256;
+
+    /// DerivedEncryptionKeyLength – 128 / 16 bytes.
+    pub const DERIVED_ENCRYPTION_KEY_LENGTH: usize = 128;
+
+    /// MinAsymmetricKeyLength – 256
+    pub const MIN_ASYMMETRIC_KEY_LENGTH: usize = 256;
+
+    /// MaxAsymmetricKeyLength – 256
+    pub const MAX_ASYMMETRIC_KEY_LENGTH: usize = 256;
+
+    /// Symmetric key length - 128 / 16 bytes
+    pub const SYMMETRIC_KEY_LENGTH: usize = 128;
+
+    /// SecureChannelNonceLength - 64 bytes, the X and Y coordinates of the ephemeral public key
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 64;
+
+    /// CertificateSignatureAlgorithm – EcdsaSha256
+    pub const CERTIFICATE_SIGNATURE_ALGORITHM: &'static str = "EcdsaSha256";
+}
+
+/// ECC_nistP384
+///
+/// A suite of algorithms that uses ECDH on the NIST P-384 curve for key agreement, ECDSA for
+/// asymmetric signatures and 256-Bit for symmetric encryption. OpenSecureChannel messages are
+/// signed but not encrypted, the nonces carry the ephemeral public keys.
+pub mod ecc_nistp384 {
+    use crypto::algorithms::*;
+
+    /// SymmetricSignatureAlgorithm – Hmac_Sha384 – (http://www.w3.org/2001/04/xmldsig-more#hmac-sha384).
+    pub const SYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_HMAC_SHA384;
+
+    /// SymmetricEncryptionAlgorithm – Aes256_CBC – (http://www.w3.org/2001/04/xmlenc#aes256-cbc).
+    pub const SYMMETRIC_ENCRYPTION_ALGORITHM: &'static str = ENC_AES256_CBC;
+
+    /// AsymmetricSignatureAlgorithm – Ecdsa_Sha384 – (http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha384).
+    pub const ASYMMETRIC_SIGNATURE_ALGORITHM: &'static str = DSIG_ECDSA_SHA384;
+
+    /// KeyDerivationAlgorithm – HKDF-SHA384 – (http://opcfoundation.org/UA/security/hkdf-sha384).
+    pub const KEY_DERIVATION_ALGORITHM: &'static str = KEY_HKDF_SHA384;
+
+    /// DerivedSignatureKeyLength – 384 / 48 bytes.
+    pub const DERIVED_SIGNATURE_KEY_LENGTH: usize = 384;
+
+    /// DerivedEncryptionKeyLength – 256 / 32 bytes.
+    pub const DERIVED_ENCRYPTION_KEY_LENGTH: usize = 256;
+
+    /// MinAsymmetricKeyLength – 384
+    pub const MIN_ASYMMETRIC_KEY_LENGTH: usize = 384;
+
+    /// MaxAsymmetricKeyLength – 384
+    pub const MAX_ASYMMETRIC_KEY_LENGTH: usize = 384;
+
+    /// Symmetric key length - 256 / 32 bytes
+    pub const SYMMETRIC_KEY_LENGTH: usize = 256;
+
+    /// SecureChannelNonceLength - 96 bytes, the X and Y coordinates of the ephemeral public key
+    pub const SECURE_CHANNEL_NONCE_LENGTH: usize = 96;
+
+    /// CertificateSignatureAlgorithm – EcdsaSha384
+    pub const CERTIFICATE_SIGNATURE_ALGORITHM: &'static str = "EcdsaSha384";
+}
+
 /// SecurityPolicy implies what encryption and signing algorithms and their relevant key strengths
 /// are used during an encrypted session.
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -188,6 +396,10 @@ pub enum SecurityPolicy {
     Basic128Rsa15,
     Basic256,
     Basic256Sha256,
+    Aes128Sha256RsaOaep,
+    Aes256Sha256RsaPss,
+    EccNistP256,
+    EccNistP384,
 }
 
 impl fmt::Display for SecurityPolicy {
@@ -205,6 +417,10 @@ impl FromStr for SecurityPolicy {
             SECURITY_POLICY_BASIC_128_RSA_15 | SECURITY_POLICY_BASIC_128_RSA_15_URI => SecurityPolicy::Basic128Rsa15,
             SECURITY_POLICY_BASIC_256 | SECURITY_POLICY_BASIC_256_URI => SecurityPolicy::Basic256,
             SECURITY_POLICY_BASIC_256_SHA_256 | SECURITY_POLICY_BASIC_256_SHA_256_URI => SecurityPolicy::Basic256Sha256,
+            SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP | SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP_URI => SecurityPolicy::Aes128Sha256RsaOaep,
+            SECURITY_POLICY_AES_256_SHA_256_RSA_PSS | SECURITY_POLICY_AES_256_SHA_256_RSA_PSS_URI => SecurityPolicy::Aes256Sha256RsaPss,
+            SECURITY_POLICY_ECC_NIST_P256 | SECURITY_POLICY_ECC_NIST_P256_URI => SecurityPolicy::EccNistP256,
+            SECURITY_POLICY_ECC_NIST_P384 | SECURITY_POLICY_ECC_NIST_P384_URI => SecurityPolicy::EccNistP384,
             _ => {
                 error!("Specified security policy {} is not recognized", s);
                 SecurityPolicy::Unknown
@@ -226,6 +442,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => SECURITY_POLICY_BASIC_128_RSA_15_URI,
             SecurityPolicy::Basic256 => SECURITY_POLICY_BASIC_256_URI,
             SecurityPolicy::Basic256Sha256 => SECURITY_POLICY_BASIC_256_SHA_256_URI,
+            SecurityPolicy::Aes128Sha256RsaOaep => SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP_URI,
+            SecurityPolicy::Aes256Sha256RsaPss => SECURITY_POLICY_AES_256_SHA_256_RSA_PSS_URI,
+            SecurityPolicy::EccNistP256 => SECURITY_POLICY_ECC_NIST_P256_URI,
+            SecurityPolicy::EccNistP384 => SECURITY_POLICY_ECC_NIST_P384_URI,
             _ => {
                 panic!("Shouldn't be turning an unknown policy into a uri");
             }
@@ -238,6 +458,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => SECURITY_POLICY_BASIC_128_RSA_15,
             SecurityPolicy::Basic256 => SECURITY_POLICY_BASIC_256,
             SecurityPolicy::Basic256Sha256 => SECURITY_POLICY_BASIC_256_SHA_256,
+            SecurityPolicy::Aes128Sha256RsaOaep => SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP,
+            SecurityPolicy::Aes256Sha256RsaPss => SECURITY_POLICY_AES_256_SHA_256_RSA_PSS,
+            SecurityPolicy::EccNistP256 => SECURITY_POLICY_ECC_NIST_P256,
+            SecurityPolicy::EccNistP384 => SECURITY_POLICY_ECC_NIST_P384,
             _ => {
                 panic!("Shouldn't be turning an unknown policy into a string");
             }
@@ -249,6 +473,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => basic128rsa15::ASYMMETRIC_SIGNATURE_ALGORITHM,
             SecurityPolicy::Basic256 => basic256::ASYMMETRIC_SIGNATURE_ALGORITHM,
             SecurityPolicy::Basic256Sha256 => basic256sha256::ASYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::ASYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::ASYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::ASYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::ASYMMETRIC_SIGNATURE_ALGORITHM,
             _ => {
                 panic!("Invalid policy");
             }
@@ -260,6 +488,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => basic128rsa15::SYMMETRIC_SIGNATURE_ALGORITHM,
             SecurityPolicy::Basic256 => basic256::SYMMETRIC_SIGNATURE_ALGORITHM,
             SecurityPolicy::Basic256Sha256 => basic256sha256::SYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::SYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::SYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::SYMMETRIC_SIGNATURE_ALGORITHM,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::SYMMETRIC_SIGNATURE_ALGORITHM,
             _ => {
                 panic!("Invalid policy");
             }
@@ -272,6 +504,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => basic128rsa15::SYMMETRIC_KEY_LENGTH,
             SecurityPolicy::Basic256 => basic256::SYMMETRIC_KEY_LENGTH,
             SecurityPolicy::Basic256Sha256 => basic256sha256::SYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::SYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::SYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::SYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::SYMMETRIC_KEY_LENGTH,
             _ => {
                 panic!("Invalid policy");
             }
@@ -282,7 +518,9 @@ impl SecurityPolicy {
     // Plaintext block size in bytes
     pub fn plain_block_size(&self) -> usize {
         match *self {
-            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => 16,
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 |
+            SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 | SecurityPolicy::EccNistP384 => 16,
             _ => {
                 panic!("Invalid policy");
             }
@@ -294,7 +532,9 @@ impl SecurityPolicy {
         match *self {
             SecurityPolicy::None => 0,
             SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 => SHA1_SIZE,
-            SecurityPolicy::Basic256Sha256 => SHA256_SIZE,
+            SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 => SHA256_SIZE,
+            SecurityPolicy::EccNistP384 => SHA384_SIZE,
             _ => {
                 panic!("Invalid policy");
             }
@@ -307,6 +547,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => basic128rsa15::DERIVED_SIGNATURE_KEY_LENGTH,
             SecurityPolicy::Basic256 => basic256::DERIVED_SIGNATURE_KEY_LENGTH,
             SecurityPolicy::Basic256Sha256 => basic256sha256::DERIVED_SIGNATURE_KEY_LENGTH,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::DERIVED_SIGNATURE_KEY_LENGTH,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::DERIVED_SIGNATURE_KEY_LENGTH,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::DERIVED_SIGNATURE_KEY_LENGTH,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::DERIVED_SIGNATURE_KEY_LENGTH,
             _ => {
                 panic!("Invalid policy");
             }
@@ -320,6 +564,10 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => basic128rsa15::MIN_ASYMMETRIC_KEY_LENGTH,
             SecurityPolicy::Basic256 => basic256::MIN_ASYMMETRIC_KEY_LENGTH,
             SecurityPolicy::Basic256Sha256 => basic256sha256::MIN_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::MIN_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::MIN_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::MIN_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::MIN_ASYMMETRIC_KEY_LENGTH,
             _ => {
                 panic!("Invalid policy");
             }
@@ -332,19 +580,65 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 => basic128rsa15::MAX_ASYMMETRIC_KEY_LENGTH,
             SecurityPolicy::Basic256 => basic256::MAX_ASYMMETRIC_KEY_LENGTH,
             SecurityPolicy::Basic256Sha256 => basic256sha256::MAX_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::MAX_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::MAX_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::MAX_ASYMMETRIC_KEY_LENGTH,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::MAX_ASYMMETRIC_KEY_LENGTH,
+            _ => {
+                panic!("Invalid policy");
+            }
+        }
+    }
+
+    /// Returns the length in bytes of the nonces exchanged during OpenSecureChannel
+    pub fn secure_channel_nonce_length(&self) -> usize {
+        match *self {
+            SecurityPolicy::Basic128Rsa15 => basic128rsa15::SECURE_CHANNEL_NONCE_LENGTH,
+            SecurityPolicy::Basic256 => basic256::SECURE_CHANNEL_NONCE_LENGTH,
+            SecurityPolicy::Basic256Sha256 => basic256sha256::SECURE_CHANNEL_NONCE_LENGTH,
+            SecurityPolicy::Aes128Sha256RsaOaep => aes128sha256rsaoaep::SECURE_CHANNEL_NONCE_LENGTH,
+            SecurityPolicy::Aes256Sha256RsaPss => aes256sha256rsapss::SECURE_CHANNEL_NONCE_LENGTH,
+            SecurityPolicy::EccNistP256 => ecc_nistp256::SECURE_CHANNEL_NONCE_LENGTH,
+            SecurityPolicy::EccNistP384 => ecc_nistp384::SECURE_CHANNEL_NONCE_LENGTH,
             _ => {
                 panic!("Invalid policy");
             }
         }
     }
 
+    /// Tests if the policy is one of the ECC policies, which use EC keys for signing, exchange
+    /// ephemeral public keys in the OpenSecureChannel nonces and do not encrypt OpenSecureChannel messages
+    pub fn is_ecc(&self) -> bool {
+        match *self {
+            SecurityPolicy::EccNistP256 | SecurityPolicy::EccNistP384 => true,
+            _ => false
+        }
+    }
+
+    /// Tests if the asymmetric key is the kind required by the policy, i.e. an EC key on the policy's
+    /// curve for the ECC policies, or an RSA key for the others.
+    pub fn is_valid_key_type<K>(&self, key: &K) -> bool where K: KeySize {
+        match *self {
+            SecurityPolicy::None | SecurityPolicy::Unknown => false,
+            _ => if self.is_ecc() {
+                key.is_ec() && key.bit_length() == self.min_asymmetric_key_length()
+            } else {
+                !key.is_ec()
+            }
+        }
+    }
+
     /// Creates a random nonce in a bytestring with a length appropriate for the policy
     pub fn nonce(&self) -> ByteString {
         match *self {
             SecurityPolicy::None => ByteString::null(),
             SecurityPolicy::Basic128Rsa15 |
             SecurityPolicy::Basic256 |
-            SecurityPolicy::Basic256Sha256 => ByteString::random(self.symmetric_key_size()),
+            SecurityPolicy::Basic256Sha256 |
+            SecurityPolicy::Aes128Sha256RsaOaep |
+            SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 |
+            SecurityPolicy::EccNistP384 => ByteString::random(self.secure_channel_nonce_length()),
             _ => {
                 panic!("Can't make a nonce because key size is unknown");
             }
@@ -357,6 +651,10 @@ impl SecurityPolicy {
             SECURITY_POLICY_BASIC_128_RSA_15_URI => SecurityPolicy::Basic128Rsa15,
             SECURITY_POLICY_BASIC_256_URI => SecurityPolicy::Basic256,
             SECURITY_POLICY_BASIC_256_SHA_256_URI => SecurityPolicy::Basic256Sha256,
+            SECURITY_POLICY_AES_128_SHA_256_RSA_OAEP_URI => SecurityPolicy::Aes128Sha256RsaOaep,
+            SECURITY_POLICY_AES_256_SHA_256_RSA_PSS_URI => SecurityPolicy::Aes256Sha256RsaPss,
+            SECURITY_POLICY_ECC_NIST_P256_URI => SecurityPolicy::EccNistP256,
+            SECURITY_POLICY_ECC_NIST_P384_URI => SecurityPolicy::EccNistP384,
             _ => {
                 error!("Specified security policy {} is not recognized", uri);
                 SecurityPolicy::Unknown
@@ -368,17 +666,29 @@ impl SecurityPolicy {
     /// from a secret and seed specified by the parameters.
     fn prf(&self, secret: &[u8], seed: &[u8], length: usize, offset: usize) -> Vec<u8> {
         // P_SHA1 or P_SHA256
-        let message_digest = match *self {
-            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 => openssl_hash::MessageDigest::sha1(),
-            SecurityPolicy::Basic256Sha256 => openssl_hash::MessageDigest::sha256(),
+        let hash_algorithm = match *self {
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 => HashAlgorithm::Sha1,
+            SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss => HashAlgorithm::Sha256,
             _ => {
                 panic!("Invalid policy");
             }
         };
-        let result = hash::p_sha(message_digest, secret, seed, offset + length);
+        let result = hash::p_sha(hash_algorithm, secret, seed, offset + length);
         result[offset..(offset + length)].to_vec()
     }
 
+    /// Returns the length of the symmetric encryption key and the encrypting block size in bytes
+    fn encrypting_key_and_block_size(&self) -> (usize, usize) {
+        match *self {
+            SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::EccNistP256 => (16, 16),
+            SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP384 => (32, 16),
+            _ => {
+                panic!("Invalid policy");
+            }
+        }
+    }
+
     /// Part 6
     /// 6.7.5
     /// Deriving keys Once the SecureChannel is established the Messages are signed and encrypted with
@@ -415,13 +725,7 @@ impl SecurityPolicy {
     pub fn make_secure_channel_keys(&self, secret: &[u8], seed: &[u8]) -> (Vec<u8>, AesKey, Vec<u8>) {
         // Work out the length of stuff
         let signing_key_length = self.derived_signature_key_size();
-        let (encrypting_key_length, encrypting_block_size) = match *self {
-            SecurityPolicy::Basic128Rsa15 => (16, 16),
-            SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => (32, 16),
-            _ => {
-                panic!("Invalid policy");
-            }
-        };
+        let (encrypting_key_length, encrypting_block_size) = self.encrypting_key_and_block_size();
 
         let signing_key = self.prf(secret, seed, signing_key_length, 0);
         let encrypting_key = self.prf(secret, seed, encrypting_key_length, signing_key_length);
@@ -431,6 +735,52 @@ impl SecurityPolicy {
         (signing_key, encrypting_key, iv)
     }
 
+    /// Derives the client and server keys for the ECC policies from the shared secret that both
+    /// sides calculate from their own ephemeral private key and the other side's ephemeral public key.
+    ///
+    /// The keys are created with HKDF using salts that are built from the nonces:
+    ///
+    /// Key | Salt | Info
+    /// ClientKeys | L | "opcua-client" | ClientNonce | ServerNonce | ClientSalt
+    /// ServerKeys | L | "opcua-server" | ServerNonce | ClientNonce | ServerSalt
+    ///
+    /// Where L is the total length of the signing key, encrypting key and initialization vector
+    /// encoded as a little endian UInt16. The output is split into the signing key, encrypting key
+    /// and initialization vector in that order.
+    ///
+    /// Returns the client keys and server keys as a pair.
+    pub fn make_ecc_secure_channel_keys(&self, shared_secret: &[u8], client_nonce: &[u8], server_nonce: &[u8]) -> ((Vec<u8>, AesKey, Vec<u8>), (Vec<u8>, AesKey, Vec<u8>)) {
+        let hash_algorithm = match *self {
+            SecurityPolicy::EccNistP256 => HashAlgorithm::Sha256,
+            SecurityPolicy::EccNistP384 => HashAlgorithm::Sha384,
+            _ => {
+                panic!("Invalid policy");
+            }
+        };
+
+        let signing_key_length = self.derived_signature_key_size();
+        let (encrypting_key_length, encrypting_block_size) = self.encrypting_key_and_block_size();
+        let length = signing_key_length + encrypting_key_length + encrypting_block_size;
+
+        let make_keys = |label: &[u8], first_nonce: &[u8], second_nonce: &[u8]| {
+            let mut salt = Vec::with_capacity(2 + label.len() + first_nonce.len() + second_nonce.len());
+            salt.push((length & 0xff) as u8);
+            salt.push((length >> 8) as u8);
+            salt.extend_from_slice(label);
+            salt.extend_from_slice(first_nonce);
+            salt.extend_from_slice(second_nonce);
+            let keys = hash::hkdf(hash_algorithm, &salt, shared_secret, &salt, length);
+            let signing_key = keys[..signing_key_length].to_vec();
+            let encrypting_key = AesKey::new(*self, &keys[signing_key_length..(signing_key_length + encrypting_key_length)]);
+            let iv = keys[(signing_key_length + encrypting_key_length)..].to_vec();
+            (signing_key, encrypting_key, iv)
+        };
+
+        let client_keys = make_keys(b"opcua-client", client_nonce, server_nonce);
+        let server_keys = make_keys(b"opcua-server", server_nonce, client_nonce);
+        (client_keys, server_keys)
+    }
+
     /// Produce a signature of the data using an asymmetric key. Stores the signature in the supplied
     /// `signature` buffer. Returns the size of the signature within that buffer.
     pub fn asymmetric_sign(&self, signing_key: &PrivateKey, data: &[u8], signature: &mut [u8]) -> Result<usize, StatusCode> {
@@ -438,9 +788,18 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 => {
                 signing_key.sign_hmac_sha1(data, signature)?
             }
-            SecurityPolicy::Basic256Sha256 => {
+            SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep => {
                 signing_key.sign_hmac_sha256(data, signature)?
             }
+            SecurityPolicy::Aes256Sha256RsaPss => {
+                signing_key.sign_sha256_pss(data, signature)?
+            }
+            SecurityPolicy::EccNistP256 => {
+                signing_key.sign_ecdsa_sha256(data, signature)?
+            }
+            SecurityPolicy::EccNistP384 => {
+                signing_key.sign_ecdsa_sha384(data, signature)?
+            }
             _ => {
                 panic!("Invalid policy");
             }
@@ -457,9 +816,18 @@ impl SecurityPolicy {
             SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 => {
                 verification_key.verify_hmac_sha1(data, signature)?
             }
-            SecurityPolicy::Basic256Sha256 => {
+            SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep => {
                 verification_key.verify_hmac_sha256(data, signature)?
             }
+            SecurityPolicy::Aes256Sha256RsaPss => {
+                verification_key.verify_sha256_pss(data, signature)?
+            }
+            SecurityPolicy::EccNistP256 => {
+                verification_key.verify_ecdsa_sha256(data, signature)?
+            }
+            SecurityPolicy::EccNistP384 => {
+                verification_key.verify_ecdsa_sha384(data, signature)?
+            }
             _ => {
                 panic!("Invalid policy");
             }
@@ -472,7 +840,7 @@ impl SecurityPolicy {
             // For debugging / unit testing purposes we might have a their_key to see the source of the error
             if let Some(their_key) = their_private_key {
                 // Calculate the signature using their key, see what we were expecting versus theirs
-                let mut their_signature = vec![0u8; their_key.size()];
+                let mut their_signature = vec![0u8; their_key.signature_size()];
                 self.asymmetric_sign(&their_key, data, &mut their_signature[..])?;
                 trace!("Using their_key, signature should be {:?}", &their_signature);
             }
@@ -481,11 +849,13 @@ impl SecurityPolicy {
         }
     }
 
-    /// Returns the padding algorithm used for this security policy.
+    /// Returns the padding algorithm used for this security policy. The ECC policies do not use
+    /// asymmetric encryption so have no padding.
     pub fn padding(&self) -> RsaPadding {
         match *self {
             SecurityPolicy::Basic128Rsa15 => RsaPadding::PKCS1,
-            SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 => RsaPadding::OAEP,
+            SecurityPolicy::Basic256 | SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep => RsaPadding::OAEP,
+            SecurityPolicy::Aes256Sha256RsaPss => RsaPadding::OAEP_SHA256,
             _ => {
                 panic!("Security policy is not supported, shouldn't have gotten here");
             }
@@ -524,10 +894,15 @@ impl SecurityPolicy {
                 // HMAC SHA-1
                 hash::hmac_sha1(key, data, signature)
             }
-            SecurityPolicy::Basic256Sha256 => {
+            SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 => {
                 // HMAC SHA-256
                 hash::hmac_sha256(key, data, signature)
             }
+            SecurityPolicy::EccNistP384 => {
+                // HMAC SHA-384
+                hash::hmac_sha384(key, data, signature)
+            }
             _ => {
                 panic!("Unsupported policy")
             }
@@ -536,16 +911,21 @@ impl SecurityPolicy {
 
     /// Verify the signature of a data block using the supplied symmetric key.
     pub fn symmetric_verify_signature(&self, key: &[u8], data: &[u8], signature: &[u8]) -> Result<bool, StatusCode> {
-        // Verify the signature using SHA-1 / SHA-256 HMAC
+        // Verify the signature using SHA-1 / SHA-256 / SHA-384 HMAC
         let verified = match *self {
             SecurityPolicy::Basic128Rsa15 | SecurityPolicy::Basic256 => {
                 // HMAC SHA-1
                 hash::verify_hmac_sha1(key, data, signature)
             }
-            SecurityPolicy::Basic256Sha256 => {
+            SecurityPolicy::Basic256Sha256 | SecurityPolicy::Aes128Sha256RsaOaep | SecurityPolicy::Aes256Sha256RsaPss |
+            SecurityPolicy::EccNistP256 => {
                 // HMAC SHA-256
                 hash::verify_hmac_sha256(key, data, signature)
             }
+            SecurityPolicy::EccNistP384 => {
+                // HMAC SHA-384
+                hash::verify_hmac_sha384(key, data, signature)
+            }
             _ => {
                 panic!("Unsupported policy")
             }
@@ -1,13 +1,10 @@
 // X509 certificate wrapper.
 
 use std;
-use std::marker::Send;
 use std::fmt::{Debug, Formatter};
+use std::net::IpAddr;
 use std::result::Result;
 
-use openssl::x509;
-use openssl::nid::Nid;
-
 use chrono::{DateTime, Utc, TimeZone};
 
 use opcua_types::ByteString;
@@ -15,8 +12,10 @@ use opcua_types::service_types::ApplicationDescription;
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 
-use crypto::pkey::PublicKey;
+use crypto::pkey::{PrivateKey, PublicKey, KeyType};
 use crypto::thumbprint::Thumbprint;
+use crypto::hash::HashAlgorithm;
+use crypto::provider::{provider, AltName, CertificateInfo, CrlInfo};
 
 const DEFAULT_KEYSIZE: u32 = 2048;
 const DEFAULT_COUNTRY: &'static str = "IE";
@@ -25,6 +24,9 @@ const DEFAULT_STATE: &'static str = "Dublin";
 #[derive(Debug)]
 /// Used to create an X509 cert (and private key)
 pub struct X509Data {
+    /// The type of key pair. RSA keys are used by the RSA security policies, EC keys by the ECC policies.
+    pub key_type: KeyType,
+    /// The key size in bits. For an EC key this is 256 or 384 for the nistP256 or nistP384 curve.
     pub key_size: u32,
     pub common_name: String,
     pub organization: String,
@@ -41,6 +43,7 @@ impl From<ApplicationDescription> for X509Data {
     fn from(application_description: ApplicationDescription) -> Self {
         let alt_host_names = Self::alt_host_names(application_description.application_uri.as_ref(), false, true);
         X509Data {
+            key_type: KeyType::Rsa,
             key_size: DEFAULT_KEYSIZE,
             common_name: application_description.application_name.to_string(),
             organization: application_description.application_name.to_string(),
@@ -88,6 +91,7 @@ impl X509Data {
     pub fn sample_cert() -> X509Data {
         let alt_host_names = Self::alt_host_names("urn:OPCUADemo", true, true);
         X509Data {
+            key_type: KeyType::Rsa,
             key_size: 2048,
             common_name: "OPC UA Demo Key".to_string(),
             organization: "OPC UA for Rust".to_string(),
@@ -101,10 +105,12 @@ impl X509Data {
 }
 
 
-/// This is a wrapper around the `OpenSSL` `X509` cert
+/// This is a wrapper around an X509 cert. The cert is held in DER form along with the fields the
+/// crypto provider read from it.
 #[derive(Clone)]
 pub struct X509 {
-    value: x509::X509,
+    value: Vec<u8>,
+    info: CertificateInfo,
 }
 
 impl Debug for X509 {
@@ -115,19 +121,26 @@ impl Debug for X509 {
     }
 }
 
-/// This allows certs to be transferred between threads
-unsafe impl Send for X509 {}
+impl X509 {
+    /// Wraps an `OpenSSL` cert
+    #[cfg(feature = "openssl")]
+    pub fn wrap(value: ::openssl::x509::X509) -> X509 {
+        X509::from_der(&value.to_der().unwrap()).unwrap()
+    }
 
-unsafe impl std::marker::Sync for X509 {}
+    /// Returns the DER form of the cert so the other crypto types can pass it to the provider
+    pub(crate) fn der(&self) -> &[u8] {
+        &self.value
+    }
 
-impl X509 {
-    pub fn wrap(value: x509::X509) -> X509 {
-        X509 { value }
+    /// Returns the fields of the cert
+    pub(crate) fn info(&self) -> &CertificateInfo {
+        &self.info
     }
 
     pub fn from_der(der: &[u8]) -> Result<Self, ()> {
-        if let Ok(value) = x509::X509::from_der(der) {
-            Ok(X509 { value })
+        if let Ok(info) = provider().parse_certificate(der) {
+            Ok(X509 { value: der.to_vec(), info })
         } else {
             error!("Cannot produce an x509 cert from the data supplied");
             Err(())
@@ -138,8 +151,8 @@ impl X509 {
         if data.is_null() {
             error!("Can't make certificate from null bytestring");
             Err(BadCertificateInvalid)
-        } else if let Ok(cert) = x509::X509::from_der(&data.value.as_ref().unwrap()) {
-            Ok(X509::wrap(cert))
+        } else if let Ok(cert) = X509::from_der(&data.value.as_ref().unwrap()) {
+            Ok(cert)
         } else {
             error!("Can't make certificate, does bytestring contain .der?");
             Err(BadCertificateInvalid)
@@ -148,13 +161,11 @@ impl X509 {
 
     /// Returns a ByteString representation of the cert which is DER encoded form of X509v3
     pub fn as_byte_string(&self) -> ByteString {
-        let der = self.value.to_der().unwrap();
-        ByteString::from(&der)
+        ByteString::from(&self.value)
     }
 
     pub fn public_key(&self) -> Result<PublicKey, StatusCode> {
-        if let Ok(pkey) = self.value.public_key() {
-            let pkey = PublicKey::wrap_public_key(pkey);
+        if let Ok(pkey) = PublicKey::from_der(&self.info.public_key) {
             Ok(pkey)
         } else {
             error!("Can't obtain public key from certificate");
@@ -162,25 +173,8 @@ impl X509 {
         }
     }
 
-    fn get_subject_entry(&self, nid: Nid) -> Result<String, ()> {
-        let subject_name = self.value.subject_name();
-        let mut entries = subject_name.entries_by_nid(nid);
-        if let Some(entry) = entries.next() {
-            // Asn1StringRef has to be converted out of Asn1 into UTF-8 and then a String
-            if let Ok(value) = entry.data().as_utf8() {
-                use std::ops::Deref;
-                // Value is an OpensslString type here so it has to be converted
-                Ok(value.deref().to_string())
-            } else {
-                Err(())
-            }
-        } else {
-            Err(())
-        }
-    }
-
     pub fn common_name(&self) -> Result<String, ()> {
-        self.get_subject_entry(Nid::COMMONNAME)
+        self.info.common_name.clone().ok_or(())
     }
 
     /// Tests if the certificate is valid for the supplied time using the not before and not
@@ -216,18 +210,21 @@ impl X509 {
         Good
     }
 
-    /// Tests if the supplied hostname matches any of the dns alt subject name entries on the cert
+    /// Tests if the supplied hostname matches any of the dns or ip address alt subject name entries
+    /// on the cert
     pub fn is_hostname_valid(&self, hostname: &str) -> StatusCode {
         trace!("is_hostname_valid against {} on cert", hostname);
-        // Look through alt subject names for a matching dns entry
-        if let Some(ref alt_names) = self.value.subject_alt_names() {
+        let ip_address = hostname.parse::<IpAddr>().ok();
+        // Look through alt subject names for a matching dns or ip address entry
+        if let Some(ref alt_names) = self.info.alt_names {
             // Skip the application uri
             let found = alt_names.iter().skip(1).find(|n| {
-                if let Some(dns) = n.dnsname() {
+                match (*n, ip_address) {
                     // Case insensitive comparison
-                    dns.eq_ignore_ascii_case(hostname)
-                } else {
-                    false
+                    (&AltName::Dns(ref dns), _) => dns.eq_ignore_ascii_case(hostname),
+                    (&AltName::IpAddress(ref cert_ip_address), Some(IpAddr::V4(ip_address))) => cert_ip_address[..] == ip_address.octets()[..],
+                    (&AltName::IpAddress(ref cert_ip_address), Some(IpAddr::V6(ip_address))) => cert_ip_address[..] == ip_address.octets()[..],
+                    _ => false
                 }
             });
             if found.is_some() {
@@ -249,9 +246,9 @@ impl X509 {
         trace!("is_application_uri_valid against {} on cert", application_uri);
         // Expecting the first subject alternative name to be a uri that matches with the supplied
         // application uri
-        if let Some(ref alt_names) = self.value.subject_alt_names() {
+        if let Some(ref alt_names) = self.info.alt_names {
             if alt_names.len() > 0 {
-                if let Some(cert_application_uri) = alt_names[0].uri() {
+                if let AltName::Uri(ref cert_application_uri) = alt_names[0] {
                     if cert_application_uri == application_uri {
                         info!("Certificate application uri {} is good", application_uri);
                         Good
@@ -281,34 +278,61 @@ impl X509 {
     ///
     /// The thumbprint might be used by the server / client for look-up purposes.
     pub fn thumbprint(&self) -> Thumbprint {
-        use openssl::hash::{MessageDigest, hash};
-        let der = self.value.to_der().unwrap();
-        let digest = hash(MessageDigest::sha1(), &der).unwrap();
+        let digest = provider().hash(HashAlgorithm::Sha1, &self.value);
         Thumbprint::new(&digest)
     }
 
+    /// Returns a human readable dump of the cert's fields and extensions
+    pub fn to_text(&self) -> Result<String, ()> {
+        if let Ok(text) = provider().certificate_to_text(&self.value) {
+            Ok(text)
+        } else {
+            error!("Cannot dump the cert as text");
+            Err(())
+        }
+    }
+
     /// Turn the Asn1 values into useful portable types
     pub fn not_before(&self) -> Result<DateTime<Utc>, ()> {
-        let date = self.value.not_before().to_string();
-        Self::parse_asn1_date(&date)
+        self.info.not_before.ok_or(())
     }
 
     /// Turn the Asn1 values into useful portable types
     pub fn not_after(&self) -> Result<DateTime<Utc>, ()> {
-        let date = self.value.not_after().to_string();
-        Self::parse_asn1_date(&date)
+        self.info.not_after.ok_or(())
     }
 
     pub fn to_der(&self) -> Result<Vec<u8>, ()> {
-        if let Ok(der) = self.value.to_der() {
-            Ok(der)
+        Ok(self.value.clone())
+    }
+
+    /// Tests if the public key of the cert belongs to the private key
+    pub fn matches_private_key(&self, pkey: &PrivateKey) -> bool {
+        if let Ok(public_key) = self.public_key() {
+            public_key.public_key_to_der() == pkey.public_key_to_der()
         } else {
-            error!("Cannot turn X509 cert to DER");
-            Err(())
+            false
         }
     }
 
-    fn parse_asn1_date(date: &str) -> Result<DateTime<Utc>, ()> {
+    /// Tests if this cert issued the subject cert, i.e. this cert is a CA, the subject names this
+    /// cert's subject as its issuer and is signed by this cert's key.
+    pub fn is_issuer_of(&self, subject: &X509) -> bool {
+        provider().check_issued(&self.value, &subject.value) &&
+            provider().verify_certificate_signature(&subject.value, &self.info.public_key)
+    }
+
+    /// Tests if the cert is self-signed, i.e. names itself as its issuer and is signed by its own
+    /// key. Application instance certs are self-signed without being allowed to sign other certs,
+    /// so unlike `is_issuer_of` the basic constraints and key usage are not checked.
+    pub fn is_self_signed(&self) -> bool {
+        self.info.subject_name == self.info.issuer_name &&
+            provider().verify_certificate_signature(&self.value, &self.info.public_key)
+    }
+
+    /// Parses an ASN1 time in its text form, e.g. "Feb 21 12:45:30 1999 GMT"
+    #[cfg_attr(not(feature = "openssl"), allow(dead_code))]
+    pub(crate) fn parse_asn1_date(date: &str) -> Result<DateTime<Utc>, ()> {
         // Parse ASN1 time format
         // MMM DD HH:MM:SS YYYY [GMT]
         let date = if date.ends_with(" GMT") {
@@ -327,6 +351,76 @@ impl X509 {
     }
 }
 
+/// This is a wrapper around an X509 certificate revocation list. The list is held in DER form along
+/// with the fields the crypto provider read from it.
+pub struct X509Crl {
+    value: Vec<u8>,
+    info: CrlInfo,
+}
+
+impl Debug for X509Crl {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "[x509crl]")
+    }
+}
+
+impl X509Crl {
+    /// Wraps an `OpenSSL` revocation list
+    #[cfg(feature = "openssl")]
+    pub fn wrap(value: ::openssl::x509::X509Crl) -> X509Crl {
+        X509Crl::from_der(&value.to_der().unwrap()).unwrap()
+    }
+
+    /// Reads a revocation list in DER form
+    pub fn from_der(der: &[u8]) -> Result<Self, ()> {
+        if let Ok(info) = provider().parse_crl(der) {
+            Ok(X509Crl { value: der.to_vec(), info })
+        } else {
+            error!("Cannot produce an x509 crl from the data supplied");
+            Err(())
+        }
+    }
+
+    /// Reads a revocation list in either DER or PEM form
+    pub fn from_der_or_pem(data: &[u8]) -> Result<Self, ()> {
+        if let Ok(info) = provider().parse_crl(data) {
+            Ok(X509Crl { value: data.to_vec(), info })
+        } else if let Ok(der) = provider().crl_from_pem(data) {
+            X509Crl::from_der(&der)
+        } else {
+            error!("Cannot produce an x509 crl from the data supplied");
+            Err(())
+        }
+    }
+
+    /// Tests if the list was issued by the cert, i.e. it names the cert's subject as its issuer
+    /// and is signed by the cert's key.
+    pub fn is_issued_by(&self, issuer: &X509) -> bool {
+        issuer.info.subject_name == self.info.issuer_name &&
+            provider().verify_crl_signature(&self.value, &issuer.info.public_key)
+    }
+
+    pub fn to_der(&self) -> Result<Vec<u8>, ()> {
+        Ok(self.value.clone())
+    }
+
+    /// Returns the time by which the issuer publishes the next list, if the list says
+    pub fn next_update(&self) -> Option<DateTime<Utc>> {
+        self.info.next_update
+    }
+
+    /// Tests if the list is still current, i.e. the time is before its next update. A list that
+    /// does not say when it is next updated stays current.
+    pub fn is_current(&self, now: &DateTime<Utc>) -> bool {
+        self.info.next_update.map_or(true, |next_update| *now < next_update)
+    }
+
+    /// Tests if the list contains the cert's serial number
+    pub fn is_revoked(&self, cert: &X509) -> bool {
+        self.info.revoked_serial_numbers.contains(&cert.info.serial_number)
+    }
+}
+
 #[test]
 fn parse_asn1_date_test() {
     use chrono::{Datelike, Timelike};
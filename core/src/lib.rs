@@ -6,8 +6,30 @@ extern crate log;
 extern crate env_logger;
 extern crate chrono;
 extern crate regex;
-extern crate ring;
+#[cfg(feature = "openssl")]
 extern crate openssl;
+#[cfg(feature = "rust-crypto")]
+extern crate rsa;
+#[cfg(feature = "rust-crypto")]
+extern crate aes;
+#[cfg(feature = "rust-crypto")]
+extern crate cbc;
+#[cfg(feature = "rust-crypto")]
+extern crate hmac;
+#[cfg(feature = "rust-crypto")]
+extern crate sha1;
+#[cfg(feature = "rust-crypto")]
+extern crate sha2;
+#[cfg(feature = "rust-crypto")]
+extern crate p256;
+#[cfg(feature = "rust-crypto")]
+extern crate p384;
+#[cfg(feature = "rust-crypto")]
+extern crate x509_cert;
+#[cfg(feature = "rust-crypto")]
+extern crate pkcs8;
+#[cfg(feature = "rust-crypto")]
+extern crate rand_core;
 #[cfg(test)]
 extern crate tempdir;
 extern crate serde;
@@ -1,30 +1,33 @@
 extern crate rustc_serialize as serialize;
 
-use std::fs::File;
+use std::fs::{File, remove_file};
 use std::io::Write;
+use std::path::Path;
+
+use chrono::{Duration, Utc};
 
 use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
 
-use crypto::{SecurityPolicy, SHA1_SIZE, SHA256_SIZE};
+use crypto::{SecurityPolicy, EphemeralKey, SHA1_SIZE, SHA256_SIZE};
 use crypto::certificate_store::*;
-use crypto::x509::{X509, X509Data};
-use crypto::pkey::{PrivateKey, KeySize, RsaPadding};
+use crypto::x509::{X509, X509Crl, X509Data};
+use crypto::pkey::{PrivateKey, KeySize, KeyType, RsaPadding};
 use crypto::aeskey::AesKey;
+use crypto::hash::HashAlgorithm;
+use crypto::provider::{provider, CertificateParams, CrlParams, KeyUsage};
 
-use tests::{make_certificate_store, make_test_cert_1024, make_test_cert_2048, APPLICATION_URI, APPLICATION_HOSTNAME};
+use tests::{make_certificate_store, make_test_cert_1024, make_test_cert_2048, make_test_cert_ecc_p256, make_test_cert_ecc_p384, APPLICATION_URI, APPLICATION_HOSTNAME};
 
 #[test]
 fn aes_test() {
-    use ring::rand::{SystemRandom, SecureRandom};
-    let rng = SystemRandom::new();
-
     // Create a random 128-bit key
     let mut raw_key = [0u8; 16];
-    let _ = rng.fill(&mut raw_key);
+    provider().random_bytes(&mut raw_key);
 
     // Create a random iv.
     let mut iv = [0u8; 16];
-    let _ = rng.fill(&mut iv);
+    provider().random_bytes(&mut iv);
 
     let aes_key = AesKey::new(SecurityPolicy::Basic128Rsa15, &raw_key);
 
@@ -66,7 +69,7 @@ fn create_cert() {
 fn ensure_pki_path() {
     let (tmp_dir, cert_store) = make_certificate_store();
     let pki = cert_store.pki_path.clone();
-    for dirname in ["rejected", "trusted", "private", "own"].iter() {
+    for dirname in ["rejected", "trusted", "private", "own", "issuers", "trusted/crl", "issuers/crl"].iter() {
         let mut subdir = pki.to_path_buf();
         subdir.push(dirname);
         assert!(subdir.exists());
@@ -77,6 +80,7 @@ fn ensure_pki_path() {
 #[test]
 fn create_own_cert_in_pki() {
     let args = X509Data {
+        key_type: KeyType::Rsa,
         key_size: 2048,
         common_name: "x".to_string(),
         organization: "x.org".to_string(),
@@ -175,16 +179,339 @@ fn test_and_reject_thumbprint_mismatch() {
     drop(tmp_dir);
 }
 
+/// Makes a cert signed by the issuer, or self-signed if there is no issuer. CA certs may sign other
+/// certs and revocation lists.
+fn make_chain_cert(common_name: &str, is_ca: bool, issuer: Option<&(X509, PrivateKey)>) -> (X509, PrivateKey) {
+    make_constrained_chain_cert(common_name, is_ca, None, issuer)
+}
+
+/// Makes a chain cert with a path length constraint. The key of a cert that isn't a CA may still
+/// sign other certs, so only its basic constraints stop it from being an issuer.
+fn make_constrained_chain_cert(common_name: &str, is_ca: bool, path_len_constraint: Option<u32>, issuer: Option<&(X509, PrivateKey)>) -> (X509, PrivateKey) {
+    let pkey = PrivateKey::new(1024);
+    let mut key_usage = KeyUsage::default();
+    key_usage.key_cert_sign = true;
+    key_usage.crl_sign = true;
+    let mut serial_number = vec![0u8; 8];
+    provider().random_bytes(&mut serial_number);
+    let params = CertificateParams {
+        subject_name: provider().encode_name(&[("CN", common_name)]).unwrap(),
+        public_key: pkey.public_key_to_der(),
+        issuer_cert: issuer.map(|&(ref issuer_cert, _)| issuer_cert.to_der().unwrap()),
+        serial_number,
+        not_before: Utc::now(),
+        not_after: Utc::now() + Duration::days(60),
+        basic_constraints_ca: Some(is_ca),
+        path_len_constraint,
+        key_usage: if is_ca { Some((key_usage, false)) } else { None },
+        extended_key_usage: false,
+        subject_key_identifier: is_ca,
+        authority_key_identifier: false,
+        alt_names: Vec::new(),
+        hash_algorithm: HashAlgorithm::Sha256,
+    };
+    let cert = if let Some(&(_, ref issuer_pkey)) = issuer {
+        provider().create_certificate(&params, &issuer_pkey.private_key_to_der()).unwrap()
+    } else {
+        provider().create_certificate(&params, &pkey.private_key_to_der()).unwrap()
+    };
+    (X509::from_der(&cert).unwrap(), pkey)
+}
+
+/// Writes a cert to a directory of the certificate store
+fn write_chain_cert(dir: &Path, cert: &X509) {
+    let mut path = dir.to_path_buf();
+    path.push(CertificateStore::cert_file_name(cert));
+    let mut file = File::create(path).unwrap();
+    assert!(file.write(&cert.to_der().unwrap()).is_ok());
+}
+
+/// Writes a revocation list signed by the issuer, listing the revoked certs, to a directory of the
+/// certificate store
+fn write_crl(dir: &Path, issuer: &(X509, PrivateKey), revoked: &[&X509]) {
+    let crl = CertificateStore::create_crl(&issuer.0, &issuer.1, &revoked.iter().map(|cert| (*cert).clone()).collect::<Vec<_>>(), 1, 30).unwrap();
+    write_crl_file(dir, issuer, &crl);
+}
+
+/// Writes a revocation list signed by the issuer that was due to be replaced a day ago
+fn write_expired_crl(dir: &Path, issuer: &(X509, PrivateKey)) {
+    let params = CrlParams {
+        issuer_cert: issuer.0.to_der().unwrap(),
+        last_update: Utc::now() - Duration::days(31),
+        next_update: Utc::now() - Duration::days(1),
+        crl_number: 1,
+        revoked_serial_numbers: Vec::new(),
+        hash_algorithm: HashAlgorithm::Sha256,
+    };
+    let crl = provider().create_crl(&params, &issuer.1.private_key_to_der()).unwrap();
+    write_crl_file(dir, issuer, &X509Crl::from_der(&crl).unwrap());
+}
+
+fn write_crl_file(dir: &Path, issuer: &(X509, PrivateKey), crl: &X509Crl) {
+    let mut path = dir.to_path_buf();
+    path.push(format!("{}.crl", issuer.0.common_name().unwrap()));
+    let mut file = File::create(path).unwrap();
+    assert!(file.write(&crl.to_der().unwrap()).is_ok());
+}
+
+#[test]
+fn validate_chain_of_trust() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    // A root CA that is trusted, an intermediate CA that is only an issuer, and a leaf that is
+    // unknown to the store.
+    let root = make_chain_cert("root", true, None);
+    let intermediate = make_chain_cert("intermediate", true, Some(&root));
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&intermediate));
+    write_chain_cert(&cert_store.trusted_certs_dir(), &root.0);
+
+    // The intermediate is missing so the chain is incomplete
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateChainIncomplete);
+
+    // The chain is complete but neither CA has a revocation list
+    write_chain_cert(&cert_store.issuer_certs_dir(), &intermediate.0);
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateRevocationUnknown);
+
+    // The leaf is trusted through the root once both CAs have published their revocation lists
+    write_crl(&cert_store.trusted_crl_dir(), &root, &[]);
+    write_crl(&cert_store.issuer_crl_dir(), &intermediate, &[]);
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), Good);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn validate_expired_crl() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    let root = make_chain_cert("root", true, None);
+    let intermediate = make_chain_cert("intermediate", true, Some(&root));
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&intermediate));
+    write_chain_cert(&cert_store.trusted_certs_dir(), &root.0);
+    write_chain_cert(&cert_store.issuer_certs_dir(), &intermediate.0);
+    write_crl(&cert_store.trusted_crl_dir(), &root, &[]);
+
+    // A list past its next update could hide a later revocation, so it counts as missing
+    write_expired_crl(&cert_store.issuer_crl_dir(), &intermediate);
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateRevocationUnknown);
+    write_crl(&cert_store.issuer_crl_dir(), &intermediate, &[]);
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), Good);
+
+    // The same goes for the list of an issuer further up the chain
+    write_expired_crl(&cert_store.trusted_crl_dir(), &root);
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateIssuerRevocationUnknown);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn validate_chain_not_trusted() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    // The whole chain is known but no part of it is trusted
+    let root = make_chain_cert("root", true, None);
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&root));
+    write_chain_cert(&cert_store.issuer_certs_dir(), &root.0);
+    write_crl(&cert_store.issuer_crl_dir(), &root, &[]);
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateUntrusted);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn validate_revoked_cert() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    let root = make_chain_cert("root", true, None);
+    let intermediate = make_chain_cert("intermediate", true, Some(&root));
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&intermediate));
+    write_chain_cert(&cert_store.trusted_certs_dir(), &root.0);
+    write_chain_cert(&cert_store.issuer_certs_dir(), &intermediate.0);
+    write_crl(&cert_store.trusted_crl_dir(), &root, &[]);
+    write_crl(&cert_store.issuer_crl_dir(), &intermediate, &[&leaf]);
+
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateRevoked);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn validate_revoked_issuer() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    let root = make_chain_cert("root", true, None);
+    let intermediate = make_chain_cert("intermediate", true, Some(&root));
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&intermediate));
+    write_chain_cert(&cert_store.trusted_certs_dir(), &root.0);
+    write_chain_cert(&cert_store.issuer_certs_dir(), &intermediate.0);
+    write_crl(&cert_store.trusted_crl_dir(), &root, &[&intermediate.0]);
+    write_crl(&cert_store.issuer_crl_dir(), &intermediate, &[]);
+
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateIssuerRevoked);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn validate_chain_through_non_ca() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    // The intermediate has a key that can sign certs but is not a CA, so it cannot be the issuer of
+    // the leaf
+    let root = make_chain_cert("root", true, None);
+    let intermediate = make_chain_cert("intermediate", false, Some(&root));
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&intermediate));
+    assert!(root.0.is_issuer_of(&intermediate.0));
+    assert!(!intermediate.0.is_issuer_of(&leaf));
+    write_chain_cert(&cert_store.trusted_certs_dir(), &root.0);
+    write_chain_cert(&cert_store.issuer_certs_dir(), &intermediate.0);
+    write_crl(&cert_store.trusted_crl_dir(), &root, &[]);
+
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateChainIncomplete);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn validate_chain_path_length() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+
+    // The root only allows the leaf below it, not an intermediate CA
+    let root = make_constrained_chain_cert("root", true, Some(0), None);
+    let intermediate = make_chain_cert("intermediate", true, Some(&root));
+    let (leaf, _) = make_chain_cert("leaf", false, Some(&intermediate));
+    let (direct_leaf, _) = make_chain_cert("direct leaf", false, Some(&root));
+    write_chain_cert(&cert_store.trusted_certs_dir(), &root.0);
+    write_chain_cert(&cert_store.issuer_certs_dir(), &intermediate.0);
+    write_crl(&cert_store.trusted_crl_dir(), &root, &[]);
+    write_crl(&cert_store.issuer_crl_dir(), &intermediate, &[]);
+
+    assert_eq!(cert_store.validate_application_instance_cert(&leaf, None, None), BadCertificateIssuerUseNotAllowed);
+    assert_eq!(cert_store.validate_application_instance_cert(&direct_leaf, None, None), Good);
+
+    drop(tmp_dir);
+}
+
+/// Makes a CA and an application instance cert signed by it from a signing request
+fn make_ca_signed_cert() -> ((X509, PrivateKey), (X509, PrivateKey)) {
+    let mut ca_args = X509Data::sample_cert();
+    ca_args.common_name = "Test CA".to_string();
+    let (ca_cert, ca_pkey) = CertificateStore::create_ca_cert_and_pkey(&ca_args).unwrap();
+    assert!(ca_cert.is_self_signed());
+
+    let pkey = PrivateKey::new(2048);
+    let request = CertificateStore::create_signing_request(&X509Data::sample_cert(), &pkey).unwrap();
+    let alt_host_names = vec![APPLICATION_URI.to_string(), APPLICATION_HOSTNAME.to_string(), "127.0.0.1".to_string()];
+    let cert = CertificateStore::sign_signing_request(&request, &ca_cert, &ca_pkey, &alt_host_names, 30).unwrap();
+    ((ca_cert, ca_pkey), (cert, pkey))
+}
+
+#[test]
+fn sign_signing_request() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+    let ((ca_cert, _), (cert, pkey)) = make_ca_signed_cert();
+
+    assert!(cert.matches_private_key(&pkey));
+    assert!(ca_cert.is_issuer_of(&cert));
+    assert_eq!(cert.is_application_uri_valid(APPLICATION_URI), Good);
+    assert_eq!(cert.is_hostname_valid(APPLICATION_HOSTNAME), Good);
+    assert_eq!(cert.is_hostname_valid("127.0.0.1"), Good);
+    assert_eq!(cert.is_hostname_valid("127.0.0.2"), BadCertificateHostNameInvalid);
+
+    // The cert is trusted through its CA, but cannot be accepted until the CA issues a revocation list
+    assert!(cert_store.store_trusted_cert(&ca_cert).is_ok());
+    assert_eq!(cert_store.validate_application_instance_cert(&cert, Some(APPLICATION_HOSTNAME), Some(APPLICATION_URI)), BadCertificateRevocationUnknown);
+
+    // A request that has been tampered with is not signed
+    let request = CertificateStore::create_signing_request(&X509Data::sample_cert(), &pkey).unwrap();
+    let mut request_tampered = request.clone();
+    let idx = request_tampered.len() - 10;
+    request_tampered[idx] ^= 0xff;
+    let (ca_cert, ca_pkey) = CertificateStore::create_ca_cert_and_pkey(&X509Data::sample_cert()).unwrap();
+    assert!(CertificateStore::sign_signing_request(&request_tampered, &ca_cert, &ca_pkey, &[APPLICATION_URI.to_string()], 30).is_err());
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn create_crl() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+    let ((ca_cert, ca_pkey), (cert, _)) = make_ca_signed_cert();
+    assert!(cert_store.store_trusted_cert(&ca_cert).is_ok());
+
+    let crl = CertificateStore::create_crl(&ca_cert, &ca_pkey, &[], 1, 30).unwrap();
+    assert!(crl.is_issued_by(&ca_cert));
+    assert!(!crl.is_revoked(&cert));
+    let mut path = cert_store.trusted_crl_dir();
+    path.push("ca.crl");
+    File::create(&path).unwrap().write(&crl.to_der().unwrap()).unwrap();
+    assert_eq!(cert_store.validate_application_instance_cert(&cert, None, None), Good);
+
+    let crl = CertificateStore::create_crl(&ca_cert, &ca_pkey, &[cert.clone()], 2, 30).unwrap();
+    assert!(crl.is_revoked(&cert));
+    File::create(&path).unwrap().write(&crl.to_der().unwrap()).unwrap();
+    assert_eq!(cert_store.validate_application_instance_cert(&cert, None, None), BadCertificateRevoked);
+
+    drop(tmp_dir);
+}
+
+#[test]
+fn reload_own_cert_and_pkey() {
+    let (tmp_dir, cert_store) = make_certificate_store();
+    let (cert, pkey) = make_test_cert_2048();
+    cert_store.store_own_cert_and_pkey(&cert, &pkey, false).unwrap();
+
+    let (mut cert_store, _, _) = CertificateStore::new_with_keypair(&tmp_dir.path(), None);
+    assert!(!cert_store.has_changed());
+
+    // Replace the cert and key on disk. The store carries on with the old pair until it reloads
+    let (new_cert, new_pkey) = make_test_cert_2048();
+    cert_store.store_own_cert_and_pkey(&new_cert, &new_pkey, true).unwrap();
+    assert!(cert_store.has_changed());
+    let (own_cert, _) = cert_store.own_cert_and_pkey().unwrap();
+    assert_eq!(own_cert.thumbprint().as_byte_string(), cert.thumbprint().as_byte_string());
+
+    assert!(cert_store.reload().is_ok());
+    assert!(!cert_store.has_changed());
+    let (own_cert, own_pkey) = cert_store.own_cert_and_pkey().unwrap();
+    assert_eq!(own_cert.thumbprint().as_byte_string(), new_cert.thumbprint().as_byte_string());
+    assert!(own_cert.matches_private_key(&own_pkey));
+
+    // Writing the same pair again is not a change, but replacing a file with other contents of the
+    // same size is, however soon it happens
+    cert_store.store_own_cert_and_pkey(&new_cert, &new_pkey, true).unwrap();
+    assert!(!cert_store.has_changed());
+    let mut path = cert_store.trusted_certs_dir();
+    path.push("same-size.der");
+    File::create(&path).unwrap().write_all(&[1u8; 64]).unwrap();
+    assert!(cert_store.has_changed());
+    assert!(cert_store.reload().is_ok());
+    File::create(&path).unwrap().write_all(&[2u8; 64]).unwrap();
+    assert!(cert_store.has_changed());
+    remove_file(&path).unwrap();
+    assert!(cert_store.reload().is_ok());
+
+    // A key that does not belong to the cert is not loaded
+    cert_store.store_own_cert_and_pkey(&cert, &new_pkey, true).unwrap();
+    assert!(cert_store.reload().is_err());
+    let (own_cert, _) = cert_store.own_cert_and_pkey().unwrap();
+    assert_eq!(own_cert.thumbprint().as_byte_string(), new_cert.thumbprint().as_byte_string());
+
+    drop(tmp_dir);
+}
+
 fn test_asymmetric_encrypt_and_decrypt(cert: &X509, key: &PrivateKey, security_policy: SecurityPolicy, plaintext_size: usize) {
     let mut plaintext = vec![0u8; plaintext_size];
     for i in 0..plaintext_size {
         plaintext[i] = (i % 256) as u8;
     }
-    let mut ciphertext = vec![0u8; plaintext_size + 4096];
-    let mut plaintext2 = vec![0u8; plaintext_size + 4096];
+    let public_key = cert.public_key().unwrap();
+    let ciphertext_size = public_key.calculate_cipher_text_size(plaintext_size, security_policy.padding());
+    let mut ciphertext = vec![0u8; ciphertext_size];
+    let mut plaintext2 = vec![0u8; ciphertext_size];
 
     trace!("Encrypting data of length {}", plaintext_size);
-    let encrypted_size = security_policy.asymmetric_encrypt(&cert.public_key().unwrap(), &plaintext, &mut ciphertext).unwrap();
+    let encrypted_size = security_policy.asymmetric_encrypt(&public_key, &plaintext, &mut ciphertext).unwrap();
     trace!("Encrypted size = {}", encrypted_size);
     trace!("Decrypting cipher text back");
     let decrypted_size = security_policy.asymmetric_decrypt(key, &ciphertext[..encrypted_size], &mut plaintext2).unwrap();
@@ -199,7 +526,7 @@ fn test_asymmetric_encrypt_and_decrypt(cert: &X509, key: &PrivateKey, security_p
 fn asymmetric_encrypt_and_decrypt() {
     let (cert, key) = make_test_cert_2048();
     // Try all security policies, ensure they encrypt / decrypt for various sizes
-    for security_policy in [SecurityPolicy::Basic128Rsa15, SecurityPolicy::Basic256, SecurityPolicy::Basic256Sha256].iter() {
+    for security_policy in [SecurityPolicy::Basic128Rsa15, SecurityPolicy::Basic256, SecurityPolicy::Basic256Sha256, SecurityPolicy::Aes128Sha256RsaOaep, SecurityPolicy::Aes256Sha256RsaPss].iter() {
         for data_size in [0, 1, 127, 128, 129, 255, 256, 257, 13001].iter() {
             test_asymmetric_encrypt_and_decrypt(&cert, &key, *security_policy, *data_size);
         }
@@ -227,6 +554,43 @@ fn calculate_cipher_text_size() {
     assert_eq!(pkey.calculate_cipher_text_size(255, padding), 512);
     assert_eq!(pkey.calculate_cipher_text_size(256, padding), 512);
     assert_eq!(pkey.calculate_cipher_text_size(512, padding), 768);
+
+    // Testing -66 bounds
+    let padding = RsaPadding::OAEP_SHA256;
+    assert_eq!(pkey.calculate_cipher_text_size(1, padding), 256);
+    assert_eq!(pkey.calculate_cipher_text_size(190, padding), 256);
+    assert_eq!(pkey.calculate_cipher_text_size(191, padding), 512);
+    assert_eq!(pkey.calculate_cipher_text_size(380, padding), 512);
+    assert_eq!(pkey.calculate_cipher_text_size(381, padding), 768);
+}
+
+#[test]
+fn asymmetric_sign_and_verify() {
+    let (cert, key) = make_test_cert_2048();
+    let public_key = cert.public_key().unwrap();
+    let data = b"The quick brown fox jumps over the lazy dog";
+    for security_policy in [SecurityPolicy::Basic128Rsa15, SecurityPolicy::Basic256, SecurityPolicy::Basic256Sha256, SecurityPolicy::Aes128Sha256RsaOaep, SecurityPolicy::Aes256Sha256RsaPss].iter() {
+        let mut signature = vec![0u8; key.size()];
+        assert_eq!(security_policy.asymmetric_sign(&key, data, &mut signature).unwrap(), key.size());
+        assert!(security_policy.asymmetric_verify_signature(&public_key, data, &signature, None).is_ok());
+        // Altered data must fail
+        assert_eq!(security_policy.asymmetric_verify_signature(&public_key, &data[1..], &signature, None).unwrap_err(), BadSecurityChecksFailed);
+    }
+
+    // PSS signatures are salted so two signatures of the same data differ, and a PKCS#1 v1.5
+    // signature of the same data is not a valid PSS signature.
+    let mut signature1 = vec![0u8; key.size()];
+    let mut signature2 = vec![0u8; key.size()];
+    let _ = key.sign_sha256_pss(data, &mut signature1).unwrap();
+    let _ = key.sign_sha256_pss(data, &mut signature2).unwrap();
+    assert_ne!(signature1, signature2);
+    assert!(public_key.verify_sha256_pss(data, &signature1).unwrap());
+    assert!(public_key.verify_sha256_pss(data, &signature2).unwrap());
+
+    let mut signature3 = vec![0u8; key.size()];
+    let _ = key.sign_hmac_sha256(data, &mut signature3).unwrap();
+    assert!(SecurityPolicy::Aes256Sha256RsaPss.asymmetric_verify_signature(&public_key, data, &signature3, None).is_err());
+    assert!(SecurityPolicy::Basic256Sha256.asymmetric_verify_signature(&public_key, data, &signature1, None).is_err());
 }
 
 #[test]
@@ -356,6 +720,157 @@ fn generate_nonce() {
     assert_eq!(SecurityPolicy::Basic128Rsa15.nonce().as_ref().len(), 16);
     assert_eq!(SecurityPolicy::Basic256.nonce().as_ref().len(), 32);
     assert_eq!(SecurityPolicy::Basic256Sha256.nonce().as_ref().len(), 32);
+    assert_eq!(SecurityPolicy::Aes128Sha256RsaOaep.nonce().as_ref().len(), 32);
+    assert_eq!(SecurityPolicy::Aes256Sha256RsaPss.nonce().as_ref().len(), 32);
+    assert_eq!(SecurityPolicy::EccNistP256.nonce().as_ref().len(), 64);
+    assert_eq!(SecurityPolicy::EccNistP384.nonce().as_ref().len(), 96);
+}
+
+#[test]
+fn hkdf_sha256() {
+    use tests::crypto::serialize::hex::FromHex;
+
+    // Test case 1 from RFC 5869
+    let ikm = vec![0x0bu8; 22];
+    let salt = (0x00u8..0x0d).collect::<Vec<u8>>();
+    let info = (0xf0u8..0xfa).collect::<Vec<u8>>();
+    let okm = ::crypto::hash::hkdf(HashAlgorithm::Sha256, &salt, &ikm, &info, 42);
+    let expected = "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865".from_hex().unwrap();
+    assert_eq!(okm, expected);
+}
+
+#[test]
+fn create_ecc_cert() {
+    let (cert, key) = make_test_cert_ecc_p256();
+    let public_key = cert.public_key().unwrap();
+    assert!(key.is_ec() && public_key.is_ec());
+    assert_eq!(public_key.bit_length(), 256);
+    assert_eq!(public_key.signature_size(), 64);
+    assert!(SecurityPolicy::EccNistP256.is_valid_key_type(&public_key));
+    assert!(!SecurityPolicy::EccNistP384.is_valid_key_type(&public_key));
+    assert!(!SecurityPolicy::Basic256Sha256.is_valid_key_type(&public_key));
+
+    let (cert, key) = make_test_cert_ecc_p384();
+    let public_key = cert.public_key().unwrap();
+    assert!(key.is_ec() && public_key.is_ec());
+    assert_eq!(public_key.bit_length(), 384);
+    assert_eq!(public_key.signature_size(), 96);
+    assert!(SecurityPolicy::EccNistP384.is_valid_key_type(&public_key));
+    assert!(!SecurityPolicy::EccNistP256.is_valid_key_type(&public_key));
+
+    // RSA keys are only valid for the RSA policies
+    let (cert, _) = make_test_cert_2048();
+    let public_key = cert.public_key().unwrap();
+    assert!(SecurityPolicy::Basic256Sha256.is_valid_key_type(&public_key));
+    assert!(!SecurityPolicy::EccNistP256.is_valid_key_type(&public_key));
+
+    // There are no other curves
+    assert!(PrivateKey::new_ecc(521).is_err());
+}
+
+#[test]
+fn ecdsa_sign_and_verify() {
+    let data = b"The quick brown fox jumps over the lazy dog";
+    for &(security_policy, (ref cert, ref key)) in [(SecurityPolicy::EccNistP256, make_test_cert_ecc_p256()), (SecurityPolicy::EccNistP384, make_test_cert_ecc_p384())].iter() {
+        let public_key = cert.public_key().unwrap();
+        let mut signature = vec![0u8; key.signature_size()];
+        assert_eq!(security_policy.asymmetric_sign(key, data, &mut signature).unwrap(), key.signature_size());
+        assert!(security_policy.asymmetric_verify_signature(&public_key, data, &signature, None).is_ok());
+        // Altered data or signature must fail
+        assert_eq!(security_policy.asymmetric_verify_signature(&public_key, &data[1..], &signature, None).unwrap_err(), BadSecurityChecksFailed);
+        signature[0] ^= 0xff;
+        assert_eq!(security_policy.asymmetric_verify_signature(&public_key, data, &signature, None).unwrap_err(), BadSecurityChecksFailed);
+        // A truncated signature must fail
+        assert!(security_policy.asymmetric_verify_signature(&public_key, data, &signature[1..], None).is_err());
+    }
+}
+
+#[test]
+fn ecdsa_signature_data() {
+    use opcua_types::ByteString;
+    use crypto::{create_signature_data, verify_signature_data};
+
+    // The session signatures of the ECC policies are ECDSA signatures
+    let (cert, key) = make_test_cert_ecc_p384();
+    let (contained_cert, _) = make_test_cert_ecc_p384();
+    let nonce = SecurityPolicy::EccNistP384.nonce();
+    let signature_data = create_signature_data(&key, SecurityPolicy::EccNistP384, &contained_cert.as_byte_string(), &nonce).unwrap();
+    assert_eq!(signature_data.signature.as_ref().len(), 96);
+    assert_eq!(verify_signature_data(&signature_data, SecurityPolicy::EccNistP384, &cert, &contained_cert, &nonce), StatusCode::Good);
+    assert_eq!(verify_signature_data(&signature_data, SecurityPolicy::EccNistP384, &cert, &contained_cert, &ByteString::from(&[1u8, 2, 3])), BadSecurityChecksFailed);
+}
+
+#[test]
+fn ecdh_shared_secret() {
+    for &security_policy in [SecurityPolicy::EccNistP256, SecurityPolicy::EccNistP384].iter() {
+        let key1 = EphemeralKey::new(security_policy).unwrap();
+        let key2 = EphemeralKey::new(security_policy).unwrap();
+        let public_key1 = key1.public_key_bytes();
+        let public_key2 = key2.public_key_bytes();
+        assert_eq!(public_key1.len(), security_policy.secure_channel_nonce_length());
+        assert!(EphemeralKey::is_valid_public_key(security_policy, &public_key1));
+
+        // Both sides compute the same secret
+        let secret1 = key1.shared_secret(&public_key2).unwrap();
+        let secret2 = key2.shared_secret(&public_key1).unwrap();
+        assert_eq!(secret1, secret2);
+
+        // A random nonce is not a point on the curve
+        let random_nonce = security_policy.nonce();
+        assert!(!EphemeralKey::is_valid_public_key(security_policy, random_nonce.as_ref()));
+        assert_eq!(key1.shared_secret(random_nonce.as_ref()).unwrap_err(), BadNonceInvalid);
+
+        // Nor is a key of the wrong length
+        assert_eq!(key1.shared_secret(&public_key2[1..]).unwrap_err(), BadNonceInvalid);
+    }
+    // The key must be from the same curve
+    let key1 = EphemeralKey::new(SecurityPolicy::EccNistP256).unwrap();
+    let key2 = EphemeralKey::new(SecurityPolicy::EccNistP384).unwrap();
+    assert!(key1.shared_secret(&key2.public_key_bytes()).is_err());
+    assert!(EphemeralKey::new(SecurityPolicy::Basic256Sha256).is_err());
+}
+
+#[test]
+fn derive_ecc_keys() {
+    // ECC_nistP256
+    //
+    // a) SigningKeyLength = 32
+    // b) EncryptingKeyLength = 16
+    // c) EncryptingBlockSize = 16
+    //
+    // ECC_nistP384
+    //
+    // a) SigningKeyLength = 48
+    // b) EncryptingKeyLength = 32
+    // c) EncryptingBlockSize = 16
+    for &(security_policy, signing_key_length, encrypting_key_length) in [(SecurityPolicy::EccNistP256, 32, 16), (SecurityPolicy::EccNistP384, 48, 32)].iter() {
+        let client_key = EphemeralKey::new(security_policy).unwrap();
+        let server_key = EphemeralKey::new(security_policy).unwrap();
+        let client_nonce = client_key.public_key_bytes();
+        let server_nonce = server_key.public_key_bytes();
+
+        let shared_secret = client_key.shared_secret(&server_nonce).unwrap();
+        let (client_keys, server_keys) = security_policy.make_ecc_secure_channel_keys(&shared_secret, &client_nonce, &server_nonce);
+        assert_eq!(client_keys.0.len(), signing_key_length);
+        assert_eq!(client_keys.1.value().len(), encrypting_key_length);
+        assert_eq!(client_keys.2.len(), 16);
+        assert_eq!(server_keys.0.len(), signing_key_length);
+        assert_eq!(server_keys.1.value().len(), encrypting_key_length);
+        assert_eq!(server_keys.2.len(), 16);
+
+        // The client and server keys differ
+        assert_ne!(client_keys.0, server_keys.0);
+        assert_ne!(client_keys.1.value(), server_keys.1.value());
+        assert_ne!(client_keys.2, server_keys.2);
+
+        // The server derives the same keys from its side
+        let shared_secret = server_key.shared_secret(&client_nonce).unwrap();
+        let (client_keys2, server_keys2) = security_policy.make_ecc_secure_channel_keys(&shared_secret, &client_nonce, &server_nonce);
+        assert_eq!(client_keys.0, client_keys2.0);
+        assert_eq!(client_keys.1.value(), client_keys2.1.value());
+        assert_eq!(server_keys.0, server_keys2.0);
+        assert_eq!(server_keys.2, server_keys2.2);
+    }
 }
 
 #[test]
@@ -396,6 +911,52 @@ fn derive_keys_from_nonce() {
     assert_eq!(signing_key.len(), 32);
     assert_eq!(encryption_key.value().len(), 32);
     assert_eq!(iv.len(), 16);
+
+    // Create a security policy Aes128_Sha256_RsaOaep policy
+    //
+    // a) SigningKeyLength = 32
+    // b) EncryptingKeyLength = 16
+    // c) EncryptingBlockSize = 16
+    let security_policy = SecurityPolicy::Aes128Sha256RsaOaep;
+    let (signing_key, encryption_key, iv) = security_policy.make_secure_channel_keys(&nonce1, &nonce2);
+    assert_eq!(signing_key.len(), 32);
+    assert_eq!(encryption_key.value().len(), 16);
+    assert_eq!(iv.len(), 16);
+
+    // Create a security policy Aes256_Sha256_RsaPss policy
+    //
+    // a) SigningKeyLength = 32
+    // b) EncryptingKeyLength = 32
+    // c) EncryptingBlockSize = 16
+    let security_policy = SecurityPolicy::Aes256Sha256RsaPss;
+    let (signing_key, encryption_key, iv) = security_policy.make_secure_channel_keys(&nonce1, &nonce2);
+    assert_eq!(signing_key.len(), 32);
+    assert_eq!(encryption_key.value().len(), 32);
+    assert_eq!(iv.len(), 16);
+}
+
+#[test]
+fn derive_keys_from_nonce_sha256_policies() {
+    // The Aes policies derive keys with P_SHA256 like Basic256Sha256 so their keys must be slices of
+    // the same pseudo random sequence for the same nonces.
+    let local_nonce = SecurityPolicy::Basic256Sha256.nonce();
+    let remote_nonce = SecurityPolicy::Basic256Sha256.nonce();
+    let (signing_key, encrypting_key, iv) = SecurityPolicy::Basic256Sha256.make_secure_channel_keys(remote_nonce.as_ref(), local_nonce.as_ref());
+    let mut sequence = signing_key.clone();
+    sequence.extend_from_slice(encrypting_key.value());
+    sequence.extend_from_slice(&iv);
+
+    // Aes256_Sha256_RsaPss has the same lengths as Basic256Sha256
+    let keys = SecurityPolicy::Aes256Sha256RsaPss.make_secure_channel_keys(remote_nonce.as_ref(), local_nonce.as_ref());
+    assert_eq!(keys.0, signing_key);
+    assert_eq!(keys.1.value(), encrypting_key.value());
+    assert_eq!(keys.2, iv);
+
+    // Aes128_Sha256_RsaOaep has a shorter encrypting key, so the iv begins earlier in the sequence
+    let keys = SecurityPolicy::Aes128Sha256RsaOaep.make_secure_channel_keys(remote_nonce.as_ref(), local_nonce.as_ref());
+    assert_eq!(keys.0, signing_key);
+    assert_eq!(keys.1.value(), &sequence[32..48]);
+    assert_eq!(keys.2, &sequence[48..64]);
 }
 
 #[test]
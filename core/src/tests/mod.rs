@@ -8,9 +8,9 @@ use opcua_types::*;
 use opcua_types::status_codes::StatusCode::*;
 use opcua_types::service_types::*;
 
-use comms::secure_channel::SecureChannel;
+use comms::secure_channel::{SecureChannel, Role};
 
-use crypto::pkey::PrivateKey;
+use crypto::pkey::{PrivateKey, KeyType};
 use crypto::x509::{X509, X509Data};
 use crypto::certificate_store::*;
 use crypto::security_policy::SecurityPolicy;
@@ -58,12 +58,37 @@ fn make_secure_channel(security_mode: MessageSecurityMode, security_policy: Secu
     secure_channel.set_security_policy(security_policy);
     secure_channel.set_local_nonce(&local_nonce);
     secure_channel.set_remote_nonce(&remote_nonce);
-    secure_channel.derive_keys();
+    secure_channel.derive_keys().unwrap();
     secure_channel
 }
 
+/// Makes a pair of secure channels for an ECC policy, a client and a server. Each side's nonce is
+/// the public key of its own ephemeral key pair.
+fn make_ecc_secure_channels(security_mode: MessageSecurityMode, security_policy: SecurityPolicy) -> (SecureChannel, SecureChannel) {
+    let make_secure_channel = |role: Role| {
+        let mut secure_channel = SecureChannel::new_no_certificate_store();
+        secure_channel.set_role(role);
+        secure_channel.set_security_mode(security_mode);
+        secure_channel.set_security_policy(security_policy);
+        secure_channel.create_random_nonce();
+        secure_channel
+    };
+    let mut client = make_secure_channel(Role::Client);
+    let mut server = make_secure_channel(Role::Server);
+    let client_nonce = client.local_nonce().to_vec();
+    let server_nonce = server.local_nonce().to_vec();
+    client.set_remote_nonce(&server_nonce);
+    server.set_remote_nonce(&client_nonce);
+    client.derive_keys().unwrap();
+    server.derive_keys().unwrap();
+    (client, server)
+}
+
 /// Makes a pair of secure channels representing local and remote side to test crypto
 fn make_secure_channels(security_mode: MessageSecurityMode, security_policy: SecurityPolicy) -> (SecureChannel, SecureChannel) {
+    if security_policy.is_ecc() {
+        return make_ecc_secure_channels(security_mode, security_policy);
+    }
     let local_nonce = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
     let remote_nonce = vec![16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
 
@@ -84,7 +109,12 @@ const APPLICATION_HOSTNAME: &'static str = "testhost";
 
 
 fn make_test_cert(key_size: u32) -> (X509, PrivateKey) {
+    make_test_cert_with_key_type(KeyType::Rsa, key_size)
+}
+
+fn make_test_cert_with_key_type(key_type: KeyType, key_size: u32) -> (X509, PrivateKey) {
     let args = X509Data {
+        key_type,
         key_size,
         common_name: "x".to_string(),
         organization: "x.org".to_string(),
@@ -104,6 +134,10 @@ fn make_test_cert_2048() -> (X509, PrivateKey) { make_test_cert(2048) }
 
 fn make_test_cert_4096() -> (X509, PrivateKey) { make_test_cert(4096) }
 
+fn make_test_cert_ecc_p256() -> (X509, PrivateKey) { make_test_cert_with_key_type(KeyType::Ecc, 256) }
+
+fn make_test_cert_ecc_p384() -> (X509, PrivateKey) { make_test_cert_with_key_type(KeyType::Ecc, 384) }
+
 fn make_open_secure_channel_response() -> OpenSecureChannelResponse {
     OpenSecureChannelResponse {
         response_header: ResponseHeader {
@@ -156,4 +190,6 @@ mod services;
 mod comms;
 mod authentication;
 mod crypto;
-mod secure_channel;
\ No newline at end of file
+mod secure_channel;
+#[cfg(all(feature = "openssl", feature = "rust-crypto"))]
+mod provider;
\ No newline at end of file
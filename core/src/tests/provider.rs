@@ -0,0 +1,222 @@
+//! Tests that the OpenSSL and pure Rust crypto providers interoperate, i.e. what one of them
+//! encrypts, signs or issues, the other can decrypt, verify or read.
+
+use chrono::{Duration, Utc};
+
+use crypto::hash::HashAlgorithm;
+use crypto::pkey::RsaPadding;
+use crypto::provider::{CryptoProvider, EcCurve, AltName, KeyUsage, CertificateParams, CrlParams};
+use crypto::provider::openssl::OpenSslProvider;
+use crypto::provider::rust_crypto::RustCryptoProvider;
+
+const OPENSSL: &'static CryptoProvider = &OpenSslProvider;
+const RUST_CRYPTO: &'static CryptoProvider = &RustCryptoProvider;
+
+/// Each provider paired with the other one
+fn provider_pairs() -> Vec<(&'static CryptoProvider, &'static CryptoProvider)> {
+    vec![(OPENSSL, RUST_CRYPTO), (RUST_CRYPTO, OPENSSL)]
+}
+
+fn make_ca_params(provider: &CryptoProvider, private_key: &[u8]) -> CertificateParams {
+    let key_info = provider.private_key_info(private_key).unwrap();
+    let now = Utc::now();
+    CertificateParams {
+        subject_name: provider.encode_name(&[("CN", "Test CA"), ("O", "x.org"), ("C", "EN")]).unwrap(),
+        public_key: key_info.public_key,
+        issuer_cert: None,
+        serial_number: vec![0x80, 0x01, 0x02],
+        not_before: now,
+        not_after: now + Duration::days(30),
+        basic_constraints_ca: Some(true),
+        path_len_constraint: Some(1),
+        key_usage: Some((KeyUsage { digital_signature: true, key_cert_sign: true, crl_sign: true, ..Default::default() }, true)),
+        extended_key_usage: false,
+        subject_key_identifier: true,
+        authority_key_identifier: true,
+        alt_names: Vec::new(),
+        hash_algorithm: HashAlgorithm::Sha256,
+    }
+}
+
+fn make_leaf_params(provider: &CryptoProvider, private_key: &[u8], issuer_cert: &[u8]) -> CertificateParams {
+    let key_info = provider.private_key_info(private_key).unwrap();
+    let now = Utc::now();
+    CertificateParams {
+        subject_name: provider.encode_name(&[("CN", "Test leaf"), ("O", "x.org"), ("OU", "x.org ops"), ("C", "EN"), ("ST", "London")]).unwrap(),
+        public_key: key_info.public_key,
+        issuer_cert: Some(issuer_cert.to_vec()),
+        serial_number: vec![0x01, 0x02, 0x03, 0x04],
+        not_before: now,
+        not_after: now + Duration::days(10),
+        basic_constraints_ca: Some(false),
+        path_len_constraint: None,
+        key_usage: Some((KeyUsage { digital_signature: true, non_repudiation: true, key_encipherment: true, data_encipherment: true, ..Default::default() }, false)),
+        extended_key_usage: true,
+        subject_key_identifier: true,
+        authority_key_identifier: true,
+        alt_names: vec![AltName::Uri("urn:testapplication".to_string()), AltName::Dns("testhost".to_string()), AltName::IpAddress(vec![127, 0, 0, 1])],
+        hash_algorithm: HashAlgorithm::Sha256,
+    }
+}
+
+#[test]
+fn digests_are_the_same() {
+    let key = b"secret key";
+    let data = b"The quick brown fox jumps over the lazy dog";
+    for hash_algorithm in &[HashAlgorithm::Sha1, HashAlgorithm::Sha256, HashAlgorithm::Sha384] {
+        let hash_algorithm = *hash_algorithm;
+        assert_eq!(OPENSSL.hash(hash_algorithm, data), RUST_CRYPTO.hash(hash_algorithm, data));
+        assert_eq!(OPENSSL.hmac(hash_algorithm, key, data), RUST_CRYPTO.hmac(hash_algorithm, key, data));
+        assert_eq!(OPENSSL.hmac(hash_algorithm, &[], data), RUST_CRYPTO.hmac(hash_algorithm, &[], data));
+        assert_eq!(OPENSSL.p_sha(hash_algorithm, key, data, 100), RUST_CRYPTO.p_sha(hash_algorithm, key, data, 100));
+    }
+}
+
+#[test]
+fn aes_cbc_interoperates() {
+    let plain_text = b"0123456789012345012345678901234501234567890123450123456789012345";
+    for key_size in &[16, 32] {
+        let mut key = vec![0u8; *key_size];
+        RUST_CRYPTO.random_bytes(&mut key);
+        let mut iv = [0u8; 16];
+        OPENSSL.random_bytes(&mut iv);
+        for &(encryptor, decryptor) in &provider_pairs() {
+            let mut cipher_text = vec![0u8; plain_text.len() + 16];
+            let size = encryptor.aes_cbc_encrypt(&key, &iv, plain_text, &mut cipher_text).unwrap();
+            assert_eq!(size, plain_text.len());
+            let mut decrypted = vec![0u8; plain_text.len() + 16];
+            let size = decryptor.aes_cbc_decrypt(&key, &iv, &cipher_text[..size], &mut decrypted).unwrap();
+            assert_eq!(&decrypted[..size], &plain_text[..]);
+        }
+    }
+}
+
+#[test]
+fn rsa_interoperates() {
+    let data = b"Some data to sign";
+    for &(generator, other) in &provider_pairs() {
+        let private_key = generator.generate_rsa_key(2048).unwrap();
+        let public_key = generator.private_key_info(&private_key).unwrap().public_key;
+        assert_eq!(other.private_key_info(&private_key).unwrap(), generator.private_key_info(&private_key).unwrap());
+        assert_eq!(other.public_key_info(&public_key).unwrap().bit_length, 2048);
+
+        // PEM written by one provider is read by the other
+        let pem = generator.private_key_to_pem(&private_key).unwrap();
+        assert_eq!(other.private_key_from_pem(&pem).unwrap(), private_key);
+
+        for &(encryptor, decryptor) in &provider_pairs() {
+            for padding in &[RsaPadding::PKCS1, RsaPadding::OAEP, RsaPadding::OAEP_SHA256] {
+                let mut cipher_text = vec![0u8; 256];
+                let size = encryptor.rsa_public_encrypt(&public_key, *padding, b"plain text", &mut cipher_text).unwrap();
+                assert_eq!(size, 256);
+                let mut plain_text = vec![0u8; 256];
+                let size = decryptor.rsa_private_decrypt(&private_key, *padding, &cipher_text, &mut plain_text).unwrap();
+                assert_eq!(&plain_text[..size], b"plain text");
+            }
+            for &(hash_algorithm, padding) in &[(HashAlgorithm::Sha1, RsaPadding::PKCS1), (HashAlgorithm::Sha256, RsaPadding::PKCS1), (HashAlgorithm::Sha256, RsaPadding::PSS)] {
+                let signature = encryptor.rsa_sign(&private_key, hash_algorithm, padding, data).unwrap();
+                assert!(decryptor.rsa_verify(&public_key, hash_algorithm, padding, data, &signature).unwrap());
+                assert!(!decryptor.rsa_verify(&public_key, hash_algorithm, padding, b"Other data", &signature).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn ec_interoperates() {
+    let data = b"Some data to sign";
+    for &(curve, hash_algorithm) in &[(EcCurve::NistP256, HashAlgorithm::Sha256), (EcCurve::NistP384, HashAlgorithm::Sha384)] {
+        for &(generator, other) in &provider_pairs() {
+            let private_key = generator.generate_ec_key(curve).unwrap();
+            let public_key = generator.private_key_info(&private_key).unwrap().public_key;
+            assert_eq!(other.private_key_info(&private_key).unwrap(), generator.private_key_info(&private_key).unwrap());
+
+            let pem = generator.private_key_to_pem(&private_key).unwrap();
+            assert_eq!(other.private_key_from_pem(&pem).unwrap(), private_key);
+
+            let signature = generator.ecdsa_sign(&private_key, hash_algorithm, data).unwrap();
+            assert_eq!(signature.len(), curve.coordinate_size() * 2);
+            assert!(other.ecdsa_verify(&public_key, hash_algorithm, data, &signature).unwrap());
+            assert!(!other.ecdsa_verify(&public_key, hash_algorithm, b"Other data", &signature).unwrap());
+
+            // Both sides of an ECDH exchange compute the same secret
+            let point = generator.ec_public_key_bytes(&private_key).unwrap();
+            assert_eq!(other.ec_public_key_bytes(&private_key).unwrap(), point);
+            assert!(other.ec_is_valid_public_key(curve, &point));
+            let other_private_key = other.generate_ec_key(curve).unwrap();
+            let other_point = other.ec_public_key_bytes(&other_private_key).unwrap();
+            assert_eq!(generator.ecdh_shared_secret(&private_key, curve, &other_point).unwrap(),
+                       other.ecdh_shared_secret(&other_private_key, curve, &point).unwrap());
+        }
+    }
+}
+
+#[test]
+fn certificates_interoperate() {
+    for &(issuer, reader) in &provider_pairs() {
+        let ca_key = issuer.generate_rsa_key(2048).unwrap();
+        let ca_cert = issuer.create_certificate(&make_ca_params(issuer, &ca_key), &ca_key).unwrap();
+        let leaf_key = issuer.generate_ec_key(EcCurve::NistP256).unwrap();
+        let leaf_cert = issuer.create_certificate(&make_leaf_params(issuer, &leaf_key, &ca_cert), &ca_key).unwrap();
+
+        // Both providers read the same fields from the certs
+        let ca_info = reader.parse_certificate(&ca_cert).unwrap();
+        let leaf_info = reader.parse_certificate(&leaf_cert).unwrap();
+        assert_eq!(ca_info, issuer.parse_certificate(&ca_cert).unwrap());
+        assert_eq!(leaf_info, issuer.parse_certificate(&leaf_cert).unwrap());
+        assert_eq!(leaf_info.common_name, Some("Test leaf".to_string()));
+        assert_eq!(leaf_info.serial_number, vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(leaf_info.issuer_name, ca_info.subject_name);
+        assert!(ca_info.is_ca);
+        assert_eq!(ca_info.path_len_constraint, Some(1));
+        assert!(!leaf_info.is_ca);
+        assert_eq!(leaf_info.path_len_constraint, None);
+        assert_eq!(leaf_info.alt_names, Some(vec![AltName::Uri("urn:testapplication".to_string()), AltName::Dns("testhost".to_string()), AltName::IpAddress(vec![127, 0, 0, 1])]));
+
+        // Names are encoded the same way so they compare equal
+        assert_eq!(reader.encode_name(&[("CN", "Test CA"), ("O", "x.org"), ("C", "EN")]).unwrap(), ca_info.subject_name);
+
+        // The chain verifies
+        assert!(reader.verify_certificate_signature(&ca_cert, &ca_info.public_key));
+        assert!(reader.verify_certificate_signature(&leaf_cert, &ca_info.public_key));
+        assert!(!reader.verify_certificate_signature(&leaf_cert, &leaf_info.public_key));
+        assert!(reader.check_issued(&ca_cert, &leaf_cert));
+        assert!(!reader.check_issued(&leaf_cert, &ca_cert));
+
+        // A cert whose key may sign certs is still not an issuer unless it is a CA
+        let mut non_ca_params = make_leaf_params(issuer, &leaf_key, &ca_cert);
+        non_ca_params.key_usage = Some((KeyUsage { digital_signature: true, key_cert_sign: true, ..Default::default() }, false));
+        let non_ca_cert = issuer.create_certificate(&non_ca_params, &ca_key).unwrap();
+        let mut sub_leaf_params = make_leaf_params(issuer, &leaf_key, &non_ca_cert);
+        sub_leaf_params.subject_name = issuer.encode_name(&[("CN", "Test sub leaf")]).unwrap();
+        let sub_leaf_cert = issuer.create_certificate(&sub_leaf_params, &leaf_key).unwrap();
+        assert!(reader.verify_certificate_signature(&sub_leaf_cert, &leaf_info.public_key));
+        assert!(!reader.check_issued(&non_ca_cert, &sub_leaf_cert));
+        assert!(reader.certificate_to_text(&leaf_cert).unwrap().contains("testhost"));
+
+        // A signing request made by one provider is verified and signed by the other
+        let subject_name = issuer.encode_name(&[("CN", "Requester")]).unwrap();
+        let request = issuer.create_signing_request(&subject_name, &leaf_key, &[AltName::Uri("urn:requester".to_string())], HashAlgorithm::Sha256).unwrap();
+        assert!(reader.verify_signing_request(&request));
+        let request_info = reader.parse_signing_request(&request).unwrap();
+        assert_eq!(request_info.subject_name, subject_name);
+        assert_eq!(request_info.public_key, leaf_info.public_key);
+
+        // A revocation list made by one provider is verified and read by the other
+        let now = Utc::now();
+        let crl = issuer.create_crl(&CrlParams {
+            issuer_cert: ca_cert.clone(),
+            last_update: now,
+            next_update: now + Duration::days(30),
+            crl_number: 3,
+            revoked_serial_numbers: vec![vec![0x01, 0x02, 0x03, 0x04], vec![0x00, 0x99]],
+            hash_algorithm: HashAlgorithm::Sha256,
+        }, &ca_key).unwrap();
+        assert!(reader.verify_crl_signature(&crl, &ca_info.public_key));
+        assert!(!reader.verify_crl_signature(&crl, &leaf_info.public_key));
+        let crl_info = reader.parse_crl(&crl).unwrap();
+        assert_eq!(crl_info.issuer_name, ca_info.subject_name);
+        assert_eq!(crl_info.revoked_serial_numbers, vec![vec![0x01, 0x02, 0x03, 0x04], vec![0x99]]);
+        assert_eq!(crl_info.next_update.map(|t| t.timestamp()), Some((now + Duration::days(30)).timestamp()));
+    }
+}
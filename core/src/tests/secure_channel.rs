@@ -2,10 +2,16 @@
 //! chunks containing messages
 extern crate rustc_serialize as serialize;
 
+use std::io::Cursor;
+
+use chrono;
+
 use comms::chunker::*;
+use comms::PROTOCOL_VERSION;
 use comms::secure_channel::*;
 
 use crypto::SecurityPolicy;
+use crypto::pkey::KeySize;
 
 use tests::*;
 
@@ -98,6 +104,116 @@ fn asymmetric_sign_and_encrypt_message_chunk_basic256sha256() {
     test_asymmetric_encrypt_decrypt(SupportedMessage::OpenSecureChannelResponse(make_open_secure_channel_response()), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::Basic256Sha256);
 }
 
+#[test]
+fn asymmetric_sign_and_encrypt_message_chunk_aes128sha256rsaoaep() {
+    let _ = Test::setup();
+    error!("asymmetric_sign_and_encrypt_message_chunk_aes128sha256rsaoaep");
+    test_asymmetric_encrypt_decrypt(SupportedMessage::OpenSecureChannelResponse(make_open_secure_channel_response()), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::Aes128Sha256RsaOaep);
+}
+
+#[test]
+fn asymmetric_sign_and_encrypt_message_chunk_aes256sha256rsapss() {
+    let _ = Test::setup();
+    error!("asymmetric_sign_and_encrypt_message_chunk_aes256sha256rsapss");
+    test_asymmetric_encrypt_decrypt(SupportedMessage::OpenSecureChannelResponse(make_open_secure_channel_response()), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::Aes256Sha256RsaPss);
+}
+
+fn test_asymmetric_sign_verify(message: SupportedMessage, security_mode: MessageSecurityMode, security_policy: SecurityPolicy) {
+    // The ECC policies sign the OpenSecureChannel messages with ECDSA but do not encrypt them
+    let ((our_cert, our_key), (their_cert, their_key)) = if security_policy == SecurityPolicy::EccNistP256 {
+        (make_test_cert_ecc_p256(), make_test_cert_ecc_p256())
+    } else {
+        (make_test_cert_ecc_p384(), make_test_cert_ecc_p384())
+    };
+
+    let mut secure_channel = SecureChannel::new_no_certificate_store();
+    secure_channel.set_security_mode(security_mode);
+    secure_channel.set_security_policy(security_policy);
+    secure_channel.set_cert(Some(our_cert));
+    secure_channel.set_remote_cert(Some(their_cert));
+    secure_channel.set_private_key(Some(our_key));
+
+    let mut chunks = Chunker::encode(1, 1, 0, 0, &secure_channel, &message).unwrap();
+    assert_eq!(chunks.len(), 1);
+
+    let chunk = &mut chunks[0];
+
+    let mut signed_data = vec![0u8; chunk.data.len() + 4096];
+    let signed_size = secure_channel.apply_security(&chunk, &mut signed_data[..]).unwrap();
+
+    // The message is in the clear followed by the signature
+    let signature_size = security_policy.secure_channel_nonce_length();
+    assert_eq!(signed_size, chunk.data.len() + signature_size);
+    assert_eq!(&chunk.data[12..], &signed_data[12..chunk.data.len()]);
+
+    // Altering a byte must break the signature
+    let mut tampered_data = signed_data[..signed_size].to_vec();
+    tampered_data[chunk.data.len() - 1] ^= 0xff;
+
+    // Now verify what has been signed by flipping the keys around
+    let tmp = secure_channel.cert();
+    let remote_cert = secure_channel.remote_cert();
+    secure_channel.set_cert(remote_cert);
+    secure_channel.set_remote_cert(tmp);
+    secure_channel.set_private_key(Some(their_key));
+
+    let chunk2 = secure_channel.verify_and_remove_security(&signed_data[..signed_size]).unwrap();
+    assert_eq!(chunk.data.len(), chunk2.data.len());
+    assert_eq!(&chunk.data[12..], &chunk2.data[12..]);
+
+    assert_eq!(secure_channel.verify_and_remove_security(&tampered_data).unwrap_err(), BadSecurityChecksFailed);
+}
+
+#[test]
+fn asymmetric_sign_message_chunk_ecc_nistp256() {
+    let _ = Test::setup();
+    error!("asymmetric_sign_message_chunk_ecc_nistp256");
+    test_asymmetric_sign_verify(SupportedMessage::OpenSecureChannelResponse(make_open_secure_channel_response()), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::EccNistP256);
+}
+
+#[test]
+fn asymmetric_sign_message_chunk_ecc_nistp384() {
+    let _ = Test::setup();
+    error!("asymmetric_sign_message_chunk_ecc_nistp384");
+    test_asymmetric_sign_verify(SupportedMessage::OpenSecureChannelResponse(make_open_secure_channel_response()), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::EccNistP384);
+}
+
+#[test]
+fn asymmetric_ecc_policy_rejects_rsa_key() {
+    let _ = Test::setup();
+    // An RSA cert cannot be used to open a secure channel with an ECC policy
+    let (our_cert, our_key) = make_test_cert_2048();
+    let (their_cert, _) = make_test_cert_2048();
+
+    let mut secure_channel = SecureChannel::new_no_certificate_store();
+    secure_channel.set_security_mode(MessageSecurityMode::Sign);
+    secure_channel.set_security_policy(SecurityPolicy::EccNistP256);
+    secure_channel.set_cert(Some(our_cert));
+    secure_channel.set_remote_cert(Some(their_cert));
+    secure_channel.set_private_key(Some(our_key));
+
+    let message = SupportedMessage::OpenSecureChannelResponse(make_open_secure_channel_response());
+    let chunks = Chunker::encode(1, 1, 0, 0, &secure_channel, &message).unwrap();
+    let mut signed_data = vec![0u8; chunks[0].data.len() + 4096];
+    assert_eq!(secure_channel.apply_security(&chunks[0], &mut signed_data[..]).unwrap_err(), BadSecurityPolicyRejected);
+}
+
+#[test]
+fn ecc_derive_keys_rejects_invalid_nonce() {
+    let _ = Test::setup();
+    let mut secure_channel = SecureChannel::new_no_certificate_store();
+    secure_channel.set_role(Role::Server);
+    secure_channel.set_security_mode(MessageSecurityMode::SignAndEncrypt);
+    secure_channel.set_security_policy(SecurityPolicy::EccNistP256);
+    secure_channel.create_random_nonce();
+    assert_eq!(secure_channel.local_nonce().len(), 64);
+
+    // A random nonce of the right length is not a public key
+    let nonce = SecurityPolicy::EccNistP256.nonce();
+    assert!(secure_channel.set_remote_nonce_from_byte_string(&nonce).is_ok());
+    assert_eq!(secure_channel.derive_keys().unwrap_err(), BadNonceInvalid);
+}
+
 /// Create a message, encode it to a chunk, sign the chunk, verify the signature and decode back to message
 #[test]
 fn symmetric_sign_message_chunk_basic128rsa15() {
@@ -120,6 +236,20 @@ fn symmetric_sign_message_chunk_basic256sha256() {
     test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::Sign, SecurityPolicy::Basic256Sha256);
 }
 
+#[test]
+fn symmetric_sign_message_chunk_aes128sha256rsaoaep() {
+    let _ = Test::setup();
+    error!("symmetric_sign_message_chunk_aes128sha256rsaoaep");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::Sign, SecurityPolicy::Aes128Sha256RsaOaep);
+}
+
+#[test]
+fn symmetric_sign_message_chunk_aes256sha256rsapss() {
+    let _ = Test::setup();
+    error!("symmetric_sign_message_chunk_aes256sha256rsapss");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::Sign, SecurityPolicy::Aes256Sha256RsaPss);
+}
+
 /// Create a message, encode it to a chunk, sign the chunk, encrypt, decrypt, verify the signature and decode back to message
 #[test]
 fn symmetric_sign_and_encrypt_message_chunk_basic128rsa15() {
@@ -143,3 +273,345 @@ fn symmetric_sign_and_encrypt_message_chunk_basic256sha256() {
     error!("symmetric_sign_and_encrypt_message_chunk_basic256sha256");
     test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::Basic256Sha256);
 }
+
+/// Create a message, encode it to a chunk, sign the chunk, encrypt, decrypt, verify the signature and decode back to message
+#[test]
+fn symmetric_sign_and_encrypt_message_chunk_aes128sha256rsaoaep() {
+    let _ = Test::setup();
+    error!("symmetric_sign_and_encrypt_message_chunk_aes128sha256rsaoaep");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::Aes128Sha256RsaOaep);
+}
+
+/// Create a message, encode it to a chunk, sign the chunk, encrypt, decrypt, verify the signature and decode back to message
+#[test]
+fn symmetric_sign_and_encrypt_message_chunk_aes256sha256rsapss() {
+    let _ = Test::setup();
+    error!("symmetric_sign_and_encrypt_message_chunk_aes256sha256rsapss");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::Aes256Sha256RsaPss);
+}
+
+/// Create a message, encode it to a chunk, sign the chunk, verify the signature and decode back to message
+#[test]
+fn symmetric_sign_message_chunk_ecc_nistp256() {
+    let _ = Test::setup();
+    error!("symmetric_sign_message_chunk_ecc_nistp256");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::Sign, SecurityPolicy::EccNistP256);
+}
+
+/// Create a message, encode it to a chunk, sign the chunk, verify the signature and decode back to message
+#[test]
+fn symmetric_sign_message_chunk_ecc_nistp384() {
+    let _ = Test::setup();
+    error!("symmetric_sign_message_chunk_ecc_nistp384");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::Sign, SecurityPolicy::EccNistP384);
+}
+
+/// Create a message, encode it to a chunk, sign the chunk, encrypt, decrypt, verify the signature and decode back to message
+#[test]
+fn symmetric_sign_and_encrypt_message_chunk_ecc_nistp256() {
+    let _ = Test::setup();
+    error!("symmetric_sign_and_encrypt_message_chunk_ecc_nistp256");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::EccNistP256);
+}
+
+/// Create a message, encode it to a chunk, sign the chunk, encrypt, decrypt, verify the signature and decode back to message
+#[test]
+fn symmetric_sign_and_encrypt_message_chunk_ecc_nistp384() {
+    let _ = Test::setup();
+    error!("symmetric_sign_and_encrypt_message_chunk_ecc_nistp384");
+    test_symmetric_encrypt_decrypt(make_sample_message(), MessageSecurityMode::SignAndEncrypt, SecurityPolicy::EccNistP384);
+}
+
+fn make_secure_channel_with_remote_cert(security_policy: SecurityPolicy, remote_cert: Option<X509>) -> SecureChannel {
+    let mut secure_channel = SecureChannel::new_no_certificate_store();
+    secure_channel.set_security_mode(MessageSecurityMode::SignAndEncrypt);
+    secure_channel.set_security_policy(security_policy);
+    secure_channel.set_remote_cert(remote_cert);
+    secure_channel
+}
+
+#[test]
+fn remote_nonce_must_look_random() {
+    let _ = Test::setup();
+    let mut secure_channel = SecureChannel::new_no_certificate_store();
+    secure_channel.set_security_mode(MessageSecurityMode::SignAndEncrypt);
+    secure_channel.set_security_policy(SecurityPolicy::Basic256Sha256);
+
+    // Nonces of the right length without enough distinct bytes are rejected
+    assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::from(&[0u8; 32][..])).unwrap_err(), BadNonceInvalid);
+    assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::from(&[0xffu8; 32][..])).unwrap_err(), BadNonceInvalid);
+    assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::from(b"abababababababababababababababab")).unwrap_err(), BadNonceInvalid);
+    assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::from(b"abcdefgabcdefgabcdefgabcdefgabcd")).unwrap_err(), BadNonceInvalid);
+
+    // A random nonce is good
+    assert!(secure_channel.set_remote_nonce_from_byte_string(&SecurityPolicy::Basic256Sha256.nonce()).is_ok());
+
+    // Our own nonce reflected back is rejected
+    secure_channel.create_random_nonce();
+    let local_nonce = secure_channel.local_nonce_as_byte_string();
+    assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&local_nonce).unwrap_err(), BadNonceInvalid);
+
+    // Nonces are not checked without security
+    secure_channel.set_security_policy(SecurityPolicy::None);
+    secure_channel.set_security_mode(MessageSecurityMode::None);
+    assert!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::from(&[0u8; 32][..])).is_ok());
+    assert!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::null()).is_ok());
+}
+
+#[test]
+fn remote_nonce_length_per_policy() {
+    let _ = Test::setup();
+    for security_policy in &[SecurityPolicy::Basic128Rsa15, SecurityPolicy::Basic256, SecurityPolicy::Basic256Sha256,
+        SecurityPolicy::Aes128Sha256RsaOaep, SecurityPolicy::Aes256Sha256RsaPss, SecurityPolicy::EccNistP256, SecurityPolicy::EccNistP384] {
+        let security_policy = *security_policy;
+        let mut secure_channel = SecureChannel::new_no_certificate_store();
+        secure_channel.set_security_mode(MessageSecurityMode::Sign);
+        secure_channel.set_security_policy(security_policy);
+
+        let nonce_length = security_policy.secure_channel_nonce_length();
+        assert!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::random(nonce_length)).is_ok());
+        assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::random(nonce_length - 1)).unwrap_err(), BadNonceInvalid);
+        assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::random(nonce_length + 1)).unwrap_err(), BadNonceInvalid);
+        assert_eq!(secure_channel.set_remote_nonce_from_byte_string(&ByteString::null()).unwrap_err(), BadNonceInvalid);
+    }
+}
+
+#[test]
+fn remote_cert_key_length_bounds() {
+    let _ = Test::setup();
+    let (cert_1024, _) = make_test_cert_1024();
+    let (cert_2048, _) = make_test_cert_2048();
+    let (cert_4096, _) = make_test_cert_4096();
+
+    // Each RSA policy allows keys between its min and max lengths, i.e. 1024 to 2048 bits for the
+    // older policies and 2048 to 4096 bits for the newer ones
+    for security_policy in &[SecurityPolicy::Basic128Rsa15, SecurityPolicy::Basic256, SecurityPolicy::Basic256Sha256,
+        SecurityPolicy::Aes128Sha256RsaOaep, SecurityPolicy::Aes256Sha256RsaPss] {
+        let security_policy = *security_policy;
+        for cert in &[&cert_1024, &cert_2048, &cert_4096] {
+            let bit_length = cert.public_key().unwrap().bit_length();
+            let result = make_secure_channel_with_remote_cert(security_policy, Some((*cert).clone())).validate_remote_cert_key();
+            if bit_length < security_policy.min_asymmetric_key_length() || bit_length > security_policy.max_asymmetric_key_length() {
+                assert_eq!(result.unwrap_err(), BadSecurityPolicyRejected);
+            } else {
+                assert!(result.is_ok());
+            }
+        }
+    }
+    assert!(make_secure_channel_with_remote_cert(SecurityPolicy::Basic128Rsa15, Some(cert_1024.clone())).validate_remote_cert_key().is_ok());
+    assert_eq!(make_secure_channel_with_remote_cert(SecurityPolicy::Basic128Rsa15, Some(cert_4096.clone())).validate_remote_cert_key().unwrap_err(), BadSecurityPolicyRejected);
+    assert_eq!(make_secure_channel_with_remote_cert(SecurityPolicy::Aes256Sha256RsaPss, Some(cert_1024.clone())).validate_remote_cert_key().unwrap_err(), BadSecurityPolicyRejected);
+    assert!(make_secure_channel_with_remote_cert(SecurityPolicy::Aes256Sha256RsaPss, Some(cert_4096.clone())).validate_remote_cert_key().is_ok());
+
+    // The ECC policies need an EC key on their curve
+    let (cert_p256, _) = make_test_cert_ecc_p256();
+    let (cert_p384, _) = make_test_cert_ecc_p384();
+    assert!(make_secure_channel_with_remote_cert(SecurityPolicy::EccNistP256, Some(cert_p256.clone())).validate_remote_cert_key().is_ok());
+    assert_eq!(make_secure_channel_with_remote_cert(SecurityPolicy::EccNistP256, Some(cert_p384.clone())).validate_remote_cert_key().unwrap_err(), BadSecurityPolicyRejected);
+    assert_eq!(make_secure_channel_with_remote_cert(SecurityPolicy::EccNistP256, Some(cert_2048.clone())).validate_remote_cert_key().unwrap_err(), BadSecurityPolicyRejected);
+    assert!(make_secure_channel_with_remote_cert(SecurityPolicy::EccNistP384, Some(cert_p384)).validate_remote_cert_key().is_ok());
+    assert_eq!(make_secure_channel_with_remote_cert(SecurityPolicy::Basic256Sha256, Some(cert_p256)).validate_remote_cert_key().unwrap_err(), BadSecurityPolicyRejected);
+
+    // A secured channel needs a cert but an unsecured one doesn't
+    assert_eq!(make_secure_channel_with_remote_cert(SecurityPolicy::Basic256Sha256, None).validate_remote_cert_key().unwrap_err(), BadSecurityChecksFailed);
+    let mut secure_channel = make_secure_channel_with_remote_cert(SecurityPolicy::None, None);
+    secure_channel.set_security_mode(MessageSecurityMode::None);
+    assert!(secure_channel.validate_remote_cert_key().is_ok());
+}
+
+#[test]
+fn protocol_version() {
+    let _ = Test::setup();
+    assert!(SecureChannel::validate_protocol_version(0, 0).is_ok());
+    // The request must match the HELLO
+    assert_eq!(SecureChannel::validate_protocol_version(0, 1).unwrap_err(), BadProtocolVersionUnsupported);
+    assert_eq!(SecureChannel::validate_protocol_version(1, 0).unwrap_err(), BadProtocolVersionUnsupported);
+    // A newer version than ours is unsupported
+    assert_eq!(SecureChannel::validate_protocol_version(PROTOCOL_VERSION + 1, PROTOCOL_VERSION + 1).unwrap_err(), BadProtocolVersionUnsupported);
+}
+
+#[test]
+fn token_lifetime_is_clamped() {
+    assert_eq!(SecureChannel::revise_token_lifetime(0), MAX_TOKEN_LIFETIME);
+    assert_eq!(SecureChannel::revise_token_lifetime(1), MIN_TOKEN_LIFETIME);
+    assert_eq!(SecureChannel::revise_token_lifetime(MIN_TOKEN_LIFETIME - 1), MIN_TOKEN_LIFETIME);
+    assert_eq!(SecureChannel::revise_token_lifetime(MIN_TOKEN_LIFETIME), MIN_TOKEN_LIFETIME);
+    assert_eq!(SecureChannel::revise_token_lifetime(60000), 60000);
+    assert_eq!(SecureChannel::revise_token_lifetime(MAX_TOKEN_LIFETIME), MAX_TOKEN_LIFETIME);
+    assert_eq!(SecureChannel::revise_token_lifetime(MAX_TOKEN_LIFETIME + 1), MAX_TOKEN_LIFETIME);
+    assert_eq!(SecureChannel::revise_token_lifetime(UInt32::max_value()), MAX_TOKEN_LIFETIME);
+}
+
+/// Makes a client and a server secure channel sharing keys derived from the supplied nonces
+/// and secured with the supplied token
+fn make_client_and_server_secure_channels(security_policy: SecurityPolicy, client_nonce: &[u8], server_nonce: &[u8], channel_token: ChannelSecurityToken) -> (SecureChannel, SecureChannel) {
+    let make_secure_channel = |role: Role, local_nonce: &[u8], remote_nonce: &[u8]| {
+        let mut secure_channel = SecureChannel::new_no_certificate_store();
+        secure_channel.set_role(role);
+        secure_channel.set_security_mode(MessageSecurityMode::SignAndEncrypt);
+        secure_channel.set_security_policy(security_policy);
+        secure_channel.set_security_token(channel_token.clone());
+        secure_channel.set_local_nonce(local_nonce);
+        secure_channel.set_remote_nonce(remote_nonce);
+        secure_channel.derive_keys().unwrap();
+        secure_channel
+    };
+    (make_secure_channel(Role::Client, client_nonce, server_nonce), make_secure_channel(Role::Server, server_nonce, client_nonce))
+}
+
+fn make_channel_security_token(token_id: UInt32, created_at: DateTime, revised_lifetime: UInt32) -> ChannelSecurityToken {
+    ChannelSecurityToken {
+        channel_id: 1,
+        token_id,
+        created_at,
+        revised_lifetime,
+    }
+}
+
+/// Renews the token of a secure channel with keys derived from new nonces
+fn renew_secure_channel(secure_channel: &mut SecureChannel, local_nonce: &[u8], remote_nonce: &[u8], channel_token: ChannelSecurityToken) {
+    secure_channel.set_local_nonce(local_nonce);
+    secure_channel.set_remote_nonce(remote_nonce);
+    secure_channel.renew_security_token(channel_token).unwrap();
+}
+
+/// Encodes a message into a single chunk with security applied by the secure channel
+fn secure_message(secure_channel: &SecureChannel) -> Vec<u8> {
+    let chunks = Chunker::encode(1, 1, 0, 0, secure_channel, &make_sample_message()).unwrap();
+    assert_eq!(chunks.len(), 1);
+    let mut data = vec![0u8; chunks[0].data.len() + 4096];
+    let size = secure_channel.apply_security(&chunks[0], &mut data[..]).unwrap();
+    data.truncate(size);
+    data
+}
+
+fn token_id_of(data: &[u8]) -> UInt32 {
+    // Message header is 12 bytes, followed by the token id
+    let mut stream = Cursor::new(&data[12..16]);
+    UInt32::decode(&mut stream).unwrap()
+}
+
+#[test]
+fn renewed_token_overlaps_previous_token() {
+    let _ = Test::setup();
+    let security_policy = SecurityPolicy::Basic256Sha256;
+    let client_nonce = security_policy.nonce();
+    let server_nonce = security_policy.nonce();
+    let (mut client, mut server) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(1, DateTime::now(), 60000));
+
+    // A request sent with the first token that is still in flight during the renew
+    let old_request = secure_message(&client);
+    assert_eq!(token_id_of(&old_request), 1);
+
+    // Renew on both sides with new nonces
+    let client_nonce = security_policy.nonce();
+    let server_nonce = security_policy.nonce();
+    let channel_token = make_channel_security_token(2, DateTime::now(), 60000);
+    renew_secure_channel(&mut server, server_nonce.as_ref(), client_nonce.as_ref(), channel_token.clone());
+    renew_secure_channel(&mut client, client_nonce.as_ref(), server_nonce.as_ref(), channel_token);
+    assert!(server.has_previous_token());
+    assert!(client.has_previous_token());
+
+    // The server still accepts the old request and carries on responding with the previous token
+    assert!(server.verify_and_remove_security(&old_request).is_ok());
+    let response = secure_message(&server);
+    assert_eq!(token_id_of(&response), 1);
+    assert!(client.verify_and_remove_security(&response).is_ok());
+
+    // The client switches to the new token straight away
+    let request = secure_message(&client);
+    assert_eq!(token_id_of(&request), 2);
+    assert!(server.verify_and_remove_security(&request).is_ok());
+
+    // After the first message with the new token, the server uses it and drops the previous one
+    assert!(!server.has_previous_token());
+    let response = secure_message(&server);
+    assert_eq!(token_id_of(&response), 2);
+    assert!(client.verify_and_remove_security(&response).is_ok());
+    assert!(!client.has_previous_token());
+
+    // The previous token is no longer accepted
+    assert_eq!(server.verify_and_remove_security(&old_request).unwrap_err(), BadSecureChannelTokenUnknown);
+}
+
+#[test]
+fn unknown_or_expired_token_is_rejected() {
+    let _ = Test::setup();
+    let security_policy = SecurityPolicy::Basic256Sha256;
+    let client_nonce = security_policy.nonce();
+    let server_nonce = security_policy.nonce();
+
+    // A token the server does not know
+    let (client, _) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(5, DateTime::now(), 60000));
+    let (_, mut server) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(6, DateTime::now(), 60000));
+    let request = secure_message(&client);
+    assert_eq!(server.verify_and_remove_security(&request).unwrap_err(), BadSecureChannelTokenUnknown);
+
+    // A token whose lifetime has run out
+    let created_at = DateTime::from(chrono::Utc::now() - chrono::Duration::milliseconds(20000));
+    let (client, mut server) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(1, created_at.clone(), 10000));
+    assert!(server.token_has_expired());
+    let request = secure_message(&client);
+    assert_eq!(server.verify_and_remove_security(&request).unwrap_err(), BadSecureChannelTokenUnknown);
+
+    // A previous token whose lifetime has run out is not accepted after a renew
+    let (mut client, mut server) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(1, created_at, 10000));
+    let old_request = secure_message(&client);
+    let channel_token = make_channel_security_token(2, DateTime::now(), 60000);
+    renew_secure_channel(&mut server, server_nonce.as_ref(), client_nonce.as_ref(), channel_token.clone());
+    renew_secure_channel(&mut client, client_nonce.as_ref(), server_nonce.as_ref(), channel_token);
+    assert!(!server.has_previous_token());
+    assert_eq!(server.verify_and_remove_security(&old_request).unwrap_err(), BadSecureChannelTokenUnknown);
+    assert_eq!(token_id_of(&secure_message(&server)), 2);
+    assert!(server.verify_and_remove_security(&secure_message(&client)).is_ok());
+}
+
+#[test]
+fn forged_message_keeps_previous_token() {
+    let _ = Test::setup();
+    let security_policy = SecurityPolicy::Basic256Sha256;
+    let client_nonce = security_policy.nonce();
+    let server_nonce = security_policy.nonce();
+    let (mut client, mut server) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(1, DateTime::now(), 60000));
+    let old_request = secure_message(&client);
+
+    let client_nonce = security_policy.nonce();
+    let server_nonce = security_policy.nonce();
+    let channel_token = make_channel_security_token(2, DateTime::now(), 60000);
+    renew_secure_channel(&mut server, server_nonce.as_ref(), client_nonce.as_ref(), channel_token.clone());
+    renew_secure_channel(&mut client, client_nonce.as_ref(), server_nonce.as_ref(), channel_token);
+
+    // A message that claims the new token but fails verification does not retire the previous token
+    let mut forged_request = secure_message(&client);
+    assert_eq!(token_id_of(&forged_request), 2);
+    let last = forged_request.len() - 1;
+    forged_request[last] ^= 0xff;
+    assert!(server.verify_and_remove_security(&forged_request).is_err());
+    assert!(server.has_previous_token());
+    assert!(server.verify_and_remove_security(&old_request).is_ok());
+    assert_eq!(token_id_of(&secure_message(&server)), 1);
+
+    // A genuine message with the new token does
+    assert!(server.verify_and_remove_security(&secure_message(&client)).is_ok());
+    assert!(!server.has_previous_token());
+}
+
+#[test]
+fn failed_renew_keeps_current_token() {
+    let _ = Test::setup();
+    let security_policy = SecurityPolicy::Basic256Sha256;
+    let client_nonce = security_policy.nonce();
+    let server_nonce = security_policy.nonce();
+    let (client, mut server) = make_client_and_server_secure_channels(security_policy, client_nonce.as_ref(), server_nonce.as_ref(), make_channel_security_token(1, DateTime::now(), 60000));
+
+    // ECC keys cannot be derived without an ephemeral key, so the renew fails
+    server.set_security_policy(SecurityPolicy::EccNistP256);
+    assert_eq!(server.renew_security_token(make_channel_security_token(2, DateTime::now(), 60000)).unwrap_err(), BadNonceInvalid);
+    server.set_security_policy(security_policy);
+
+    // The server carries on with the token and keys it had
+    assert_eq!(server.token_id(), 1);
+    assert!(!server.has_previous_token());
+    assert!(server.verify_and_remove_security(&secure_message(&client)).is_ok());
+    assert_eq!(token_id_of(&secure_message(&server)), 1);
+}
@@ -25,6 +25,10 @@ const ENDPOINT_ID_BASIC256_SIGN_ENCRYPT: &'static str = "sample_basic256_signenc
 const ENDPOINT_ID_BASIC256_SIGN: &'static str = "sample_basic256_sign";
 const ENDPOINT_ID_BASIC256SHA256_SIGN_ENCRYPT: &'static str = "sample_basic256sha256_signencrypt";
 const ENDPOINT_ID_BASIC256SHA256_SIGN: &'static str = "sample_basic256sha256_sign";
+const ENDPOINT_ID_AES128SHA256RSAOAEP_SIGN_ENCRYPT: &'static str = "sample_aes128sha256rsaoaep_signencrypt";
+const ENDPOINT_ID_AES128SHA256RSAOAEP_SIGN: &'static str = "sample_aes128sha256rsaoaep_sign";
+const ENDPOINT_ID_AES256SHA256RSAPSS_SIGN_ENCRYPT: &'static str = "sample_aes256sha256rsapss_signencrypt";
+const ENDPOINT_ID_AES256SHA256RSAPSS_SIGN: &'static str = "sample_aes256sha256rsapss_sign";
 
 #[test]
 fn hello_timeout() {
@@ -84,6 +88,30 @@ fn connect_basic256sha256_sign_and_encrypt() {
     connect_with(next_port_offset(), ENDPOINT_ID_BASIC256SHA256_SIGN_ENCRYPT);
 }
 
+#[test]
+fn connect_aes128sha256rsaoaep_sign() {
+    // Connect a session with Aes128_Sha256_RsaOaep and Sign
+    connect_with(next_port_offset(), ENDPOINT_ID_AES128SHA256RSAOAEP_SIGN);
+}
+
+#[test]
+fn connect_aes128sha256rsaoaep_sign_and_encrypt() {
+    // Connect a session with Aes128_Sha256_RsaOaep and SignAndEncrypt
+    connect_with(next_port_offset(), ENDPOINT_ID_AES128SHA256RSAOAEP_SIGN_ENCRYPT);
+}
+
+#[test]
+fn connect_aes256sha256rsapss_sign() {
+    // Connect a session with Aes256_Sha256_RsaPss and Sign
+    connect_with(next_port_offset(), ENDPOINT_ID_AES256SHA256RSAPSS_SIGN);
+}
+
+#[test]
+fn connect_aes256sha256rsapss_sign_and_encrypt() {
+    // Connect a session with Aes256_Sha256_RsaPss and SignAndEncrypt
+    connect_with(next_port_offset(), ENDPOINT_ID_AES256SHA256RSAPSS_SIGN_ENCRYPT);
+}
+
 static NEXT_PORT_OFFSET: AtomicUsize = ATOMIC_USIZE_INIT;
 
 fn next_port_offset() -> u16 {
@@ -123,6 +151,10 @@ fn new_server(port_offset: u16) -> Server {
         ("basic256_sign_encrypt", endpoint_path, SecurityPolicy::Basic256, MessageSecurityMode::SignAndEncrypt, &user_token_ids),
         ("basic256sha256_sign", endpoint_path, SecurityPolicy::Basic256Sha256, MessageSecurityMode::Sign, &user_token_ids),
         ("basic256sha256_sign_encrypt", endpoint_path, SecurityPolicy::Basic256Sha256, MessageSecurityMode::SignAndEncrypt, &user_token_ids),
+        ("aes128sha256rsaoaep_sign", endpoint_path, SecurityPolicy::Aes128Sha256RsaOaep, MessageSecurityMode::Sign, &user_token_ids),
+        ("aes128sha256rsaoaep_sign_encrypt", endpoint_path, SecurityPolicy::Aes128Sha256RsaOaep, MessageSecurityMode::SignAndEncrypt, &user_token_ids),
+        ("aes256sha256rsapss_sign", endpoint_path, SecurityPolicy::Aes256Sha256RsaPss, MessageSecurityMode::Sign, &user_token_ids),
+        ("aes256sha256rsapss_sign_encrypt", endpoint_path, SecurityPolicy::Aes256Sha256RsaPss, MessageSecurityMode::SignAndEncrypt, &user_token_ids),
     ].iter().map(|v| {
         (v.0.to_string(), ServerEndpoint::from((v.1, v.2, v.3, &v.4[..])))
     }).collect::<BTreeMap<_, _>>();
@@ -163,6 +195,10 @@ fn new_client(port_offset: u16) -> Client {
         (ENDPOINT_ID_BASIC256_SIGN, SecurityPolicy::Basic256, MessageSecurityMode::Sign, anonymous_id),
         (ENDPOINT_ID_BASIC256SHA256_SIGN_ENCRYPT, SecurityPolicy::Basic256Sha256, MessageSecurityMode::SignAndEncrypt, anonymous_id),
         (ENDPOINT_ID_BASIC256SHA256_SIGN, SecurityPolicy::Basic256Sha256, MessageSecurityMode::Sign, anonymous_id),
+        (ENDPOINT_ID_AES128SHA256RSAOAEP_SIGN_ENCRYPT, SecurityPolicy::Aes128Sha256RsaOaep, MessageSecurityMode::SignAndEncrypt, anonymous_id),
+        (ENDPOINT_ID_AES128SHA256RSAOAEP_SIGN, SecurityPolicy::Aes128Sha256RsaOaep, MessageSecurityMode::Sign, anonymous_id),
+        (ENDPOINT_ID_AES256SHA256RSAPSS_SIGN_ENCRYPT, SecurityPolicy::Aes256Sha256RsaPss, MessageSecurityMode::SignAndEncrypt, anonymous_id),
+        (ENDPOINT_ID_AES256SHA256RSAPSS_SIGN, SecurityPolicy::Aes256Sha256RsaPss, MessageSecurityMode::Sign, anonymous_id),
     ].iter().map(|v| {
         (v.0.to_string(), ClientEndpoint {
             url: endpoint_url(port_offset),
@@ -11,6 +11,7 @@ extern crate opcua_types;
 extern crate rand;
 
 use opcua_server::http;
+use opcua_server::http::api::UserTokenAuthenticator;
 use opcua_server::prelude::*;
 use rand::Rng;
 use std::path::PathBuf;
@@ -30,8 +31,9 @@ fn main() {
     // Add dynamically changing scalar values
     add_dynamic_scalar_variables(&mut server);
 
-    // Start the http server, used for metrics
-    http::run_http_server("127.0.0.1:8585", server.server_state.clone(), server.connections.clone(), server.server_metrics.clone());
+    // Start the http server, used for metrics and the JSON api. The api may be used by any user in
+    // the server's user tokens.
+    http::run_http_server("127.0.0.1:8585", &server, Arc::new(UserTokenAuthenticator::new()));
 
     // Run the server. This does not ordinarily exit so you must Ctrl+C to terminate
     Server::run(Arc::new(RwLock::new(server)));
@@ -0,0 +1,155 @@
+//! Benchmarks of browsing and reading a large address space. Run with
+//!
+//! ```text
+//! cargo bench -p opcua-server
+//! ```
+//!
+//! The memory used by the nodes of an even larger address space is printed before the benchmarks
+//! are run.
+
+#[macro_use]
+extern crate bencher;
+extern crate opcua_types;
+extern crate opcua_server;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bencher::{Bencher, TestOpts};
+
+use opcua_types::*;
+use opcua_types::node_ids::{ObjectTypeId, ReferenceTypeId};
+use opcua_types::service_types::BrowseDirection;
+
+use opcua_server::prelude::*;
+
+/// The number of folders under the objects folder
+const FOLDER_COUNT: usize = 100;
+/// The number of folders in the address space whose memory is measured, about 500,000 nodes
+const MEMORY_FOLDER_COUNT: usize = 500;
+/// The number of variables in each folder
+const VARIABLES_PER_FOLDER: usize = 1000;
+
+/// The number of bytes allocated and not yet freed
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// An allocator that keeps count of the allocated bytes, to measure the memory of nodes
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Makes an address space of folders of variables, returning it with the ids of the folders and
+/// the variables
+fn make_address_space() -> (AddressSpace, Vec<NodeId>, Vec<NodeId>) {
+    let mut address_space = AddressSpace::new();
+    let (folder_ids, variable_ids) = add_folders(&mut address_space, FOLDER_COUNT);
+    (address_space, folder_ids, variable_ids)
+}
+
+/// Adds folders of variables to the address space, returning the ids of the folders and the
+/// variables
+fn add_folders(address_space: &mut AddressSpace, folder_count: usize) -> (Vec<NodeId>, Vec<NodeId>) {
+    let ns = address_space.register_namespace("urn:bench");
+    let mut folder_ids = Vec::with_capacity(folder_count);
+    let mut variable_ids = Vec::with_capacity(folder_count * VARIABLES_PER_FOLDER);
+    for f in 0..folder_count {
+        let folder_id = NodeId::new_string(ns, &format!("Folder{}", f));
+        let name = format!("Folder{}", f);
+        let _ = address_space.add_folder_with_id(&folder_id, (ns, name.as_ref()), &name, &AddressSpace::objects_folder_id());
+        for v in 0..VARIABLES_PER_FOLDER {
+            let name = format!("Folder{}.Variable{}", f, v);
+            let variable_id = NodeId::new_string(ns, &name);
+            address_space.insert(Variable::new(&variable_id, (ns, name.as_ref()), &name, "", v as Int32), Some(&[
+                (&folder_id, ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+            ]));
+            variable_ids.push(variable_id);
+        }
+        folder_ids.push(folder_id);
+    }
+    (folder_ids, variable_ids)
+}
+
+/// Prints the memory used by the nodes and references of folders of variables, not counting the
+/// standard nodes of the address space
+fn print_memory_use() {
+    let mut address_space = AddressSpace::new();
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    // The returned ids are dropped so only the address space is measured
+    let _ = add_folders(&mut address_space, MEMORY_FOLDER_COUNT);
+    let bytes = ALLOCATED.load(Ordering::Relaxed) - before;
+    let node_count = MEMORY_FOLDER_COUNT * (VARIABLES_PER_FOLDER + 1);
+    println!("memory use of {} nodes: {} bytes, {} bytes per node", node_count, bytes, bytes / node_count);
+}
+
+/// Browses the variables of every folder, touching each target node as the Browse service does
+fn browse_folders(b: &mut Bencher) {
+    let (address_space, folder_ids, _) = make_address_space();
+    b.iter(|| {
+        let mut count = 0;
+        for folder_id in &folder_ids {
+            for (_, node_id) in address_space.references_from(folder_id, Some((ReferenceTypeId::HierarchicalReferences, true))) {
+                if let Some(node) = address_space.find_node(node_id) {
+                    count += node.as_node().node_class() as usize;
+                }
+            }
+        }
+        count
+    });
+}
+
+/// Browses the forward and inverse references of each folder through the API that copies them
+fn browse_folders_by_direction(b: &mut Bencher) {
+    let (address_space, folder_ids, _) = make_address_space();
+    b.iter(|| {
+        folder_ids.iter().map(|folder_id| {
+            address_space.find_references_by_direction(folder_id, BrowseDirection::Both, None).0.len()
+        }).sum::<usize>()
+    });
+}
+
+/// Finds the type definition of every folder, a filtered lookup that each Browse result makes
+fn find_type_definitions(b: &mut Bencher) {
+    let (address_space, folder_ids, _) = make_address_space();
+    let folder_type_id: NodeId = ObjectTypeId::FolderType.into();
+    b.iter(|| {
+        folder_ids.iter().filter(|folder_id| {
+            address_space.has_reference(folder_id, ReferenceTypeId::HasTypeDefinition, &folder_type_id)
+        }).count()
+    });
+}
+
+/// Reads the value of every variable
+fn read_values(b: &mut Bencher) {
+    let (address_space, _, variable_ids) = make_address_space();
+    b.iter(|| {
+        variable_ids.iter().filter(|variable_id| {
+            address_space.find_node(variable_id).and_then(|node| node.as_node().find_attribute(AttributeId::Value)).is_some()
+        }).count()
+    });
+}
+
+benchmark_group!(benches, browse_folders, browse_folders_by_direction, find_type_definitions, read_values);
+
+fn main() {
+    print_memory_use();
+
+    // As benchmark_main!, which cannot run anything before the benchmarks
+    let mut test_opts = TestOpts::default();
+    if let Some(arg) = std::env::args().skip(1).find(|arg| *arg != "--bench") {
+        test_opts.filter = Some(arg);
+    }
+    bencher::run_tests_console(&test_opts, benches()).unwrap();
+}
@@ -5,7 +5,7 @@ use chrono::Utc;
 
 use opcua_types::*;
 use opcua_types::node_ids::*;
-use opcua_types::service_types::{BrowseDirection, RelativePath, RelativePathElement, ServerDiagnosticsSummaryDataType};
+use opcua_types::service_types::{Argument, BrowseDirection, RelativePath, RelativePathElement, ServerDiagnosticsSummaryDataType};
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 use opcua_types::service_types::{CallMethodRequest, CallMethodResult};
@@ -15,6 +15,12 @@ use address_space::node::{Node, NodeType, HasNodeId};
 use address_space::object::Object;
 use address_space::variable::Variable;
 use address_space::method_impls;
+use address_space::method_arguments::{MethodInputs, MethodOutputs};
+use address_space::value_constraints::{self, ValueConstraints};
+use address_space::node_store::{NodeStore, References};
+use address_space::data_change::{DataChangeIndex, DataChangeQueue, DataChangeSignal};
+use address_space::sampler::Sampler;
+use address_space::server_configuration::CertificateManager;
 
 use state::ServerState;
 use session::Session;
@@ -102,16 +108,23 @@ struct MethodKey {
 /// The address space holds references between nodes. It is populated with some standard nodes
 /// and any that the server implementation chooses to add for itself.
 pub struct AddressSpace {
-    /// A map of all the nodes that are part of the address space
-    node_map: HashMap<NodeId, NodeType>,
-    /// A map of references between nodes
-    references: HashMap<NodeId, Vec<Reference>>,
-    /// A map of inverse references between nodes
-    inverse_references: HashMap<NodeId, Vec<Reference>>,
+    /// The nodes that are part of the address space and the references between them
+    node_store: NodeStore,
     /// This is the last time that nodes or references to nodes were added or removed from the address space.
     last_modified: DateTimeUtc,
     /// Method handlers
     method_handlers: HashMap<MethodKey, MethodCallback>,
+    /// The declarations that instantiated methods were copied from, which handle calls to the
+    /// methods when they have no handler of their own
+    method_declarations: HashMap<NodeId, NodeId>,
+    /// The namespace table. The index of a uri is the namespace index of nodes in that namespace.
+    namespaces: Vec<String>,
+    /// The monitored items that are told when the attributes of nodes are set
+    data_change_index: Arc<DataChangeIndex>,
+    /// Samples values for the monitored items of every session
+    sampler: Arc<Sampler>,
+    /// Manages certificates through the ServerConfiguration object, if it has been added
+    certificate_manager: Option<Arc<Mutex<CertificateManager>>>,
 }
 
 impl AddressSpace {
@@ -120,11 +133,14 @@ impl AddressSpace {
     pub fn new() -> AddressSpace {
         // Construct the Root folder and the top level nodes
         let mut address_space = AddressSpace {
-            node_map: HashMap::new(),
-            references: HashMap::new(),
-            inverse_references: HashMap::new(),
+            node_store: NodeStore::new(),
             last_modified: Utc::now(),
             method_handlers: HashMap::new(),
+            method_declarations: HashMap::new(),
+            namespaces: vec![constants::OPC_UA_NAMESPACE_URI.to_string()],
+            data_change_index: Arc::new(DataChangeIndex::new()),
+            sampler: Arc::new(Sampler::new()),
+            certificate_manager: None,
         };
         address_space.add_default_nodes();
         address_space
@@ -142,10 +158,9 @@ impl AddressSpace {
         // Server variables
         {
             let server_state = trace_read_lock_unwrap!(server_state);
-            if let Some(ref mut v) = self.find_variable(Server_NamespaceArray) {
-                v.set_value_direct(&DateTime::now(), Variant::from_string_array(&server_state.namespaces));
-                v.set_array_dimensions(&[server_state.namespaces.len() as UInt32]);
-            }
+            // The internal namespace is index 1 and the application's namespace is index 2
+            self.register_namespace(constants::INTERNAL_NAMESPACE_URI);
+            self.register_namespace(server_state.application_uri.as_ref());
             if let Some(ref mut v) = self.find_variable(Server_ServerArray) {
                 v.set_value_direct(&DateTime::now(), Variant::from_string_array(&server_state.servers));
                 v.set_array_dimensions(&[server_state.servers.len() as UInt32]);
@@ -228,6 +243,16 @@ impl AddressSpace {
         });
 
         // Server_ServerDiagnostics_SamplingIntervalDiagnosticsArray
+        let sampler = self.sampler.clone();
+        if let Some(ref mut v) = self.find_variable(Server_ServerDiagnostics_SamplingIntervalDiagnosticsArray) {
+            let getter = AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+                let diagnostics = sampler.sampling_interval_diagnostics().into_iter().map(|diagnostics| {
+                    Variant::from(ExtensionObject::from_encodable(ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary, diagnostics))
+                }).collect::<Vec<Variant>>();
+                Ok(Some(DataValue::new(Variant::Array(diagnostics))))
+            });
+            v.set_value_getter(Arc::new(Mutex::new(getter)));
+        }
         // Server_ServerDiagnostics_SubscriptionDiagnosticsArray
         // Server_ServerDiagnostics_EnabledFlag
 
@@ -275,7 +300,7 @@ impl AddressSpace {
         }
 
         // Server method handlers
-        self.register_method_handler(ObjectId::Server, MethodId::Server_GetMonitoredItems, Box::new(method_impls::handle_get_monitored_items));
+        self.register_typed_method_handler(ObjectId::Server, MethodId::Server_GetMonitoredItems, method_impls::handle_get_monitored_items);
     }
 
     /// Updates the server diagnostics data with new values
@@ -294,6 +319,42 @@ impl AddressSpace {
         self.set_variable_value(Server_ServerDiagnostics_ServerDiagnosticsSummary_RejectedRequestsCount, sds.rejected_requests_count as UInt32);
     }
 
+    /// Returns the namespace table of the address space
+    pub fn namespaces(&self) -> &[String] {
+        &self.namespaces
+    }
+
+    /// Finds the index of a namespace uri in the namespace table
+    pub fn namespace_index(&self, namespace_uri: &str) -> Option<UInt16> {
+        self.namespaces.iter().position(|ns| ns == namespace_uri).map(|idx| idx as UInt16)
+    }
+
+    /// Finds the uri of a namespace index in the namespace table
+    pub fn namespace_uri(&self, namespace_index: UInt16) -> Option<&str> {
+        self.namespaces.get(namespace_index as usize).map(|ns| ns.as_ref())
+    }
+
+    /// Finds the index of the namespace uri, adding it to the end of the namespace table if it
+    /// is not there already. The server's NamespaceArray variable is updated with the new table.
+    pub fn register_namespace(&mut self, namespace_uri: &str) -> UInt16 {
+        if let Some(idx) = self.namespace_index(namespace_uri) {
+            idx
+        } else {
+            self.namespaces.push(namespace_uri.to_string());
+            self.update_namespace_array();
+            (self.namespaces.len() - 1) as UInt16
+        }
+    }
+
+    fn update_namespace_array(&mut self) {
+        use opcua_types::node_ids::VariableId::Server_NamespaceArray;
+        let namespaces = self.namespaces.clone();
+        if let Some(ref mut v) = self.find_variable(Server_NamespaceArray) {
+            v.set_value_direct(&DateTime::now(), Variant::from_string_array(&namespaces));
+            v.set_array_dimensions(&[namespaces.len() as UInt32]);
+        }
+    }
+
     /// Returns the node id for the root folder
     pub fn root_folder_id() -> NodeId {
         ObjectId::RootFolder.into()
@@ -338,12 +399,12 @@ impl AddressSpace {
     /// The tuple of references is the node id, reference type id and a bool which is false for
     /// a forward reference and indicating inverse
     pub fn insert<T>(&mut self, node: T, references: Option<&[(&NodeId, ReferenceTypeId, ReferenceDirection)]>) where T: Into<NodeType> {
-        let node_type = node.into();
+        let mut node_type = node.into();
         let node_id = node_type.node_id();
-        if self.node_exists(&node_id) {
+        node_type.as_mut_node().set_data_change_index(self.data_change_index.clone());
+        if !self.node_store.insert(node_type) {
             panic!("This node {:?} already exists", node_id);
         }
-        self.node_map.insert(node_id.clone(), node_type);
 
         // If references are supplied, add them now
         if let Some(references) = references {
@@ -364,16 +425,13 @@ impl AddressSpace {
     pub fn add_default_nodes(&mut self) {
         debug!("populating address space");
 
-        // Reserve space in the maps. The default node set contains just under 2000 values for
-        // nodes, references and inverse references.
-        self.node_map.reserve(2000);
-        self.references.reserve(2000);
-        self.inverse_references.reserve(2000);
+        // Reserve space in the store. The default node set contains just under 2000 nodes.
+        self.node_store.reserve(2000);
 
         // Run the generated code that will populate the address space with the default nodes
         super::generated::populate_address_space(self);
-        debug!("finished populating address space, number of nodes = {}, number of references = {}, number of reverse references = {}",
-               self.node_map.len(), self.references.len(), self.inverse_references.len());
+        debug!("finished populating address space, number of nodes = {}, number of references = {}",
+               self.node_store.node_count(), self.node_store.reference_count());
     }
 
     // Inserts a bunch of references between two nodes into the address space
@@ -383,8 +441,7 @@ impl AddressSpace {
             if node_id_from == node_id_to {
                 panic!("Node id from == node id to {:?}", node_id_from);
             }
-            AddressSpace::add_reference(&mut self.references, node_id_from, Reference::new(reference_type_id, node_id_to));
-            AddressSpace::add_reference(&mut self.inverse_references, node_id_to, Reference::new(reference_type_id, node_id_from));
+            self.node_store.add_reference(node_id_from, node_id_to, reference_type_id);
         });
         self.update_last_modified();
     }
@@ -394,6 +451,23 @@ impl AddressSpace {
         self.insert_references(&[(node_id_from, node_id_to, reference_type_id)]);
     }
 
+    /// Inserts a reference of a type that is a subtype of a standard reference type. The
+    /// reference is found by the standard type, and `reference_type()` and Browse return its own
+    /// type.
+    pub fn insert_subtype_reference(&mut self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId, reference_type: &NodeId) {
+        if node_id_from == node_id_to {
+            panic!("Node id from == node id to {:?}", node_id_from);
+        }
+        self.node_store.add_subtype_reference(node_id_from, node_id_to, reference_type_id, reference_type);
+        self.update_last_modified();
+    }
+
+    /// Returns the type of a reference between two nodes that is found by the standard reference
+    /// type, which differs from it if the reference was inserted with a subtype
+    pub fn reference_type(&self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId) -> NodeId {
+        self.node_store.reference_type(node_id_from, node_id_to, reference_type_id)
+    }
+
     pub fn set_object_type(&mut self, node_id: &NodeId, object_type: ObjectTypeId) {
         self.insert_reference(node_id, &object_type.into(), ReferenceTypeId::HasTypeDefinition);
     }
@@ -423,15 +497,20 @@ impl AddressSpace {
     }
 
     pub fn find_node(&self, node_id: &NodeId) -> Option<&NodeType> {
-        self.node_map.get(node_id)
+        self.node_store.get(node_id)
     }
 
     pub fn find_node_mut(&mut self, node_id: &NodeId) -> Option<&mut NodeType> {
-        self.node_map.get_mut(node_id)
+        self.node_store.get_mut(node_id)
+    }
+
+    /// Finds the nodes whose node ids are in the namespace
+    pub fn find_nodes_in_namespace(&self, namespace: UInt16) -> Vec<&NodeType> {
+        self.node_store.nodes().filter(|node| node.node_id().namespace == namespace).collect()
     }
 
     pub fn node_exists(&self, node_id: &NodeId) -> bool {
-        self.node_map.contains_key(node_id)
+        self.node_store.contains(node_id)
     }
 
     pub fn find_nodes_relative_path(&self, node_id: &NodeId, relative_path: &RelativePath) -> Result<Vec<NodeId>, StatusCode> {
@@ -496,7 +575,7 @@ impl AddressSpace {
 
     /// Adds a node as a child (organized by) another node. The type id says what kind of node the object
     /// should be, e.g. folder node or something else.
-    pub fn add_organized_node(&mut self, node_id: &NodeId, browse_name: &str, display_name: &str, parent_node_id: &NodeId, node_type_id: ObjectTypeId) -> Result<NodeId, ()> {
+    pub fn add_organized_node<R>(&mut self, node_id: &NodeId, browse_name: R, display_name: &str, parent_node_id: &NodeId, node_type_id: ObjectTypeId) -> Result<NodeId, ()> where R: Into<QualifiedName> {
         if self.node_exists(&node_id) {
             panic!("Node {:?} already exists", node_id);
         } else {
@@ -510,12 +589,13 @@ impl AddressSpace {
     }
 
     /// Adds a folder with a specified id
-    pub fn add_folder_with_id(&mut self, node_id: &NodeId, browse_name: &str, display_name: &str, parent_node_id: &NodeId) -> Result<NodeId, ()> {
+    pub fn add_folder_with_id<R>(&mut self, node_id: &NodeId, browse_name: R, display_name: &str, parent_node_id: &NodeId) -> Result<NodeId, ()> where R: Into<QualifiedName> {
         self.add_organized_node(node_id, browse_name, display_name, parent_node_id, ObjectTypeId::FolderType)
     }
 
-    /// Adds a folder using a generated node id
-    pub fn add_folder(&mut self, browse_name: &str, display_name: &str, parent_node_id: &NodeId) -> Result<NodeId, ()> {
+    /// Adds a folder using a generated node id. The browse name may be a `&str` in namespace 0 or
+    /// a `QualifiedName` in another namespace.
+    pub fn add_folder<R>(&mut self, browse_name: R, display_name: &str, parent_node_id: &NodeId) -> Result<NodeId, ()> where R: Into<QualifiedName> {
         self.add_folder_with_id(&NodeId::next_numeric(), browse_name, display_name, parent_node_id)
     }
 
@@ -532,7 +612,7 @@ impl AddressSpace {
     /// Adds a single variable under the parent node
     pub fn add_variable(&mut self, variable: Variable, parent_node_id: &NodeId) -> Result<NodeId, ()> {
         let node_id = variable.node_id();
-        if !self.node_map.contains_key(&node_id) {
+        if !self.node_exists(&node_id) {
             self.insert(NodeType::Variable(variable), Some(&[
                 (&parent_node_id, ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
             ]));
@@ -542,20 +622,6 @@ impl AddressSpace {
         }
     }
 
-    /// Adds a reference between one node and a target
-    fn add_reference(reference_map: &mut HashMap<NodeId, Vec<Reference>>, node_id: &NodeId, reference: Reference) {
-        if reference_map.contains_key(node_id) {
-            let references = reference_map.get_mut(node_id).unwrap();
-            references.push(reference);
-        } else {
-            // Some nodes will have more than one reference, so save some reallocs by reserving
-            // space for some more.
-            let mut references = Vec::with_capacity(8);
-            references.push(reference);
-            reference_map.insert(node_id.clone(), references);
-        }
-    }
-
     /// Find and return a variable with the specified node id or return None if it cannot be
     /// found or is not a variable
     pub fn find_variable<N>(&mut self, node_id: N) -> Option<&mut Variable> where N: Into<NodeId> {
@@ -582,12 +648,49 @@ impl AddressSpace {
         }
     }
 
-    /// Registers a method callback on the specified object id and method id
+    /// Returns a queue that receives the value of the attribute of the node each time it is set,
+    /// e.g. through `set_variable_value()` or `Variable::set_value()`, until the queue is dropped.
+    /// Values that come from a getter are not pushed to the queue. The signal, if there is one, is
+    /// raised with each change.
+    pub fn watch_data_changes(&self, node_id: &NodeId, attribute_id: AttributeId, signal: Option<Arc<DataChangeSignal>>) -> DataChangeQueue {
+        self.data_change_index.watch(node_id, attribute_id, signal)
+    }
+
+    /// Returns the sampler that shares the sampling of values between monitored items
+    pub fn sampler(&self) -> &Sampler {
+        &self.sampler
+    }
+
+    /// Returns the manager of the certificates of the ServerConfiguration object, if it has been
+    /// added
+    pub fn certificate_manager(&self) -> Option<Arc<Mutex<CertificateManager>>> {
+        self.certificate_manager.clone()
+    }
+
+    pub(crate) fn set_certificate_manager(&mut self, certificate_manager: Arc<Mutex<CertificateManager>>) {
+        self.certificate_manager = Some(certificate_manager);
+    }
+
+    /// Releases what the address space holds for a session that has closed or been terminated,
+    /// i.e. the trust list file handles it left open
+    pub fn session_closed(&self, authentication_token: &NodeId) {
+        if let Some(ref certificate_manager) = self.certificate_manager {
+            let mut certificate_manager = trace_lock_unwrap!(certificate_manager);
+            certificate_manager.close_session_files(authentication_token);
+        }
+    }
+
+    /// Registers a method callback on the specified object id and method id. A null object id
+    /// registers the callback for the method on any object that has it, which is used when there is
+    /// no callback for the specific object.
+    ///
+    /// The input arguments of a call are checked against the method's InputArguments property
+    /// before the callback is called.
     pub fn register_method_handler<N1, N2>(&mut self, object_id: N1, method_id: N2, handler: MethodCallback) where N1: Into<NodeId>, N2: Into<NodeId> {
         // Check the object id and method id actually exist as things in the address space
         let object_id = object_id.into();
         let method_id = method_id.into();
-        if !is_object!(self, &object_id) || !is_method!(self, &method_id) {
+        if (!object_id.is_null() && !is_object!(self, &object_id)) || !is_method!(self, &method_id) {
             panic!("Invalid id {:?} / {:?} supplied to method handler", object_id, method_id)
         }
         let key = MethodKey { object_id, method_id };
@@ -596,30 +699,35 @@ impl AddressSpace {
         }
     }
 
+    /// Registers a method callback that takes its input arguments as a tuple of Rust types and
+    /// returns its output arguments as a tuple of types convertible to `Variant`, e.g. a handler
+    /// taking `(UInt32,)` and returning `(Vec<UInt32>, Vec<UInt32>)`. Arguments that cannot be
+    /// converted fail the call with `BadInvalidArgument`. See `register_method_handler` for the
+    /// meaning of a null object id.
+    pub fn register_typed_method_handler<N1, N2, I, O, F>(&mut self, object_id: N1, method_id: N2, handler: F)
+        where N1: Into<NodeId>, N2: Into<NodeId>, I: MethodInputs, O: MethodOutputs,
+              F: Fn(&AddressSpace, &ServerState, &Session, I) -> Result<O, StatusCode> + Send + Sync + 'static {
+        self.register_method_handler(object_id, method_id, Box::new(move |address_space: &AddressSpace, server_state: &ServerState, session: &Session, request: &CallMethodRequest| {
+            let input_arguments = request.input_arguments.as_ref().map_or(&[][..], |a| &a[..]);
+            let inputs = I::from_arguments(input_arguments)?;
+            let outputs = handler(address_space, server_state, session, inputs)?;
+            Ok(CallMethodResult {
+                status_code: Good,
+                input_argument_results: if input_arguments.is_empty() { None } else { Some(vec![Good; input_arguments.len()]) },
+                input_argument_diagnostic_infos: None,
+                output_arguments: Some(outputs.into_arguments()),
+            })
+        }));
+    }
+
     /// This finds the type definition (if any corresponding to the input object)
     fn get_type_id(&self, node_id: &NodeId) -> Option<NodeId> {
-        if let Some(references) = self.references.get(&node_id) {
-            if let Some(reference) = references.iter().find(|r| {
-                r.reference_type_id == ReferenceTypeId::HasTypeDefinition
-            }) {
-                Some(reference.node_id.clone())
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.references_from(node_id, Some((ReferenceTypeId::HasTypeDefinition, false))).next().map(|(_, node_id)| node_id.clone())
     }
 
     /// Test if a reference relationship exists between one node and another node
-    fn has_reference(&self, from_node_id: &NodeId, reference_type: ReferenceTypeId, to_node_id: &NodeId) -> bool {
-        if let Some(references) = self.references.get(&from_node_id) {
-            references.iter().find(|r| {
-                r.reference_type_id == reference_type && r.node_id == *to_node_id
-            }).is_some()
-        } else {
-            false
-        }
+    pub fn has_reference(&self, from_node_id: &NodeId, reference_type: ReferenceTypeId, to_node_id: &NodeId) -> bool {
+        self.references_from(from_node_id, Some((reference_type, false))).any(|(_, node_id)| node_id == to_node_id)
     }
 
     /// Tests if a method exists on a specific object. This will be true if the method id is
@@ -636,10 +744,151 @@ impl AddressSpace {
         }
     }
 
+    /// Tests if a data type is the same as, or a subtype of, another data type. The same applies
+    /// to other types such as reference types or object types.
+    pub fn is_subtype_of(&self, type_id: &NodeId, supertype_id: &NodeId) -> bool {
+        let mut type_id = type_id.clone();
+        // The depth limit guards against a cycle of subtypes
+        for _ in 0..32 {
+            if type_id == *supertype_id {
+                return true;
+            }
+            type_id = if let Some((_, supertype_id)) = self.references_to(&type_id, Some((ReferenceTypeId::HasSubtype, false))).next() {
+                supertype_id.clone()
+            } else {
+                return false;
+            };
+        }
+        false
+    }
+
+    /// Finds the constraints that the DataType, ValueRank and ArrayDimensions attributes of a
+    /// variable place on its value, or `None` if the node is not a variable.
+    pub fn value_constraints(&self, node_id: &NodeId) -> Option<ValueConstraints> {
+        if let Some(&NodeType::Variable(ref variable)) = self.find_node(node_id) {
+            let data_type = find_attribute_value_optional!(variable, DataType, NodeId)
+                .map(|data_type| *data_type)
+                .unwrap_or(DataTypeId::BaseDataType.into());
+            let array_dimensions = find_attribute_value_optional!(variable, ArrayDimensions, Array)
+                .map(|dimensions| dimensions.iter().filter_map(|d| if let Variant::UInt32(d) = *d { Some(d) } else { None }).collect::<Vec<UInt32>>());
+            Some(ValueConstraints::new(self, &data_type, variable.value_rank(), array_dimensions.as_ref().map(|d| &d[..])))
+        } else {
+            None
+        }
+    }
+
+    /// Finds the arguments in the InputArguments property of a method, or of the declaration
+    /// the method was instantiated from, or `None` if neither has such a property or it cannot be
+    /// read.
+    fn method_input_arguments(&self, method_id: &NodeId) -> Option<Vec<Argument>> {
+        let value = self.input_arguments_value(method_id).or_else(|| {
+            self.method_declarations.get(method_id).and_then(|declaration_id| self.input_arguments_value(declaration_id))
+        })?;
+        match value {
+            Variant::Array(values) => {
+                values.iter().map(|v| {
+                    if let Variant::ExtensionObject(ref extension_object) = *v {
+                        extension_object.decode_inner::<Argument>().ok()
+                    } else {
+                        None
+                    }
+                }).collect()
+            }
+            Variant::Empty => Some(Vec::new()),
+            _ => None
+        }
+    }
+
+    fn input_arguments_value(&self, method_id: &NodeId) -> Option<Variant> {
+        let references = self.find_references_from(method_id, Some((ReferenceTypeId::HasProperty, false)))?;
+        references.iter().filter_map(|r| {
+            if let Some(&NodeType::Variable(ref variable)) = self.find_node(&r.node_id) {
+                if variable.browse_name().name.as_ref() == "InputArguments" {
+                    return variable.value().value;
+                }
+            }
+            None
+        }).next()
+    }
+
+    /// Tests if a value is of the data type. Values of a built-in type also match data types that
+    /// are derived from it, e.g. a Double is a Duration, and Int32 values match enumerations.
+    fn value_is_data_type(&self, value: &Variant, data_type: &NodeId) -> bool {
+        let base_data_type: NodeId = DataTypeId::BaseDataType.into();
+        if *data_type == base_data_type {
+            return true;
+        }
+        match *value {
+            Variant::Empty => false,
+            Variant::ExtensionObject(ref extension_object) => {
+                // The data type of a structure is the source of its encoding's HasEncoding reference
+                let value_type = self.find_references_to(&extension_object.node_id, Some((ReferenceTypeId::HasEncoding, false)))
+                    .and_then(|r| r.first().map(|r| r.node_id.clone()))
+                    .unwrap_or(DataTypeId::Structure.into());
+                self.is_subtype_of(&value_type, data_type)
+            }
+            _ => {
+                if let Some(value_type) = value.data_type() {
+                    let value_type: NodeId = value_type.into();
+                    self.is_subtype_of(&value_type, data_type) || self.is_subtype_of(data_type, &value_type) ||
+                        (value_type == DataTypeId::Int32.into() && self.is_subtype_of(data_type, &DataTypeId::Enumeration.into()))
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Tests if an input argument value has the data type, value rank and array dimensions of the
+    /// argument. A null value matches any argument, leaving it to the handler to decide if it is
+    /// acceptable.
+    fn value_matches_argument(&self, value: &Variant, argument: &Argument) -> bool {
+        if let Variant::Empty = *value {
+            return true;
+        }
+        let (dimensions, values) = match *value {
+            Variant::Array(ref values) => (vec![values.len() as UInt32], &values[..]),
+            Variant::MultiDimensionArray(ref mda) => (mda.dimensions.iter().map(|d| *d as UInt32).collect(), &mda.values[..]),
+            _ => (Vec::new(), ::std::slice::from_ref(value))
+        };
+        if !value_constraints::value_rank_matches(argument.value_rank, dimensions.len()) {
+            return false;
+        }
+        // A dimension of 0 means the length of that dimension is not fixed
+        if let Some(ref array_dimensions) = argument.array_dimensions {
+            if array_dimensions.len() == dimensions.len() && array_dimensions.iter().zip(dimensions.iter()).any(|(expected, actual)| *expected != 0 && expected != actual) {
+                return false;
+            }
+        }
+        values.iter().all(|v| self.value_is_data_type(v, &argument.data_type))
+    }
+
+    /// Validates the input arguments of a call against the InputArguments property of the method.
+    /// The wrong number of arguments is an error, otherwise the result of each argument is
+    /// returned. Methods without the property are not checked.
+    fn validate_input_arguments(&self, method_id: &NodeId, input_arguments: Option<&Vec<Variant>>) -> Result<Option<Vec<StatusCode>>, StatusCode> {
+        if let Some(arguments) = self.method_input_arguments(method_id) {
+            let input_arguments = input_arguments.map_or(&[][..], |a| &a[..]);
+            if input_arguments.len() < arguments.len() {
+                Err(BadArgumentsMissing)
+            } else if input_arguments.len() > arguments.len() {
+                Err(BadTooManyArguments)
+            } else {
+                Ok(Some(input_arguments.iter().zip(arguments.iter()).map(|(value, argument)| {
+                    if self.value_matches_argument(value, argument) { Good } else { BadTypeMismatch }
+                }).collect()))
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Calls a method node with the supplied request and expecting a result.
     ///
     /// Calls require a registered handler to handle the method. If there is no handler, or if
     /// the request refers to a non existent object / method, the function will return an error.
+    /// Input arguments that do not match the method's InputArguments property fail the call with
+    /// `BadInvalidArgument` and the result of each argument.
     pub fn call_method(&self, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
         let (object_id, method_id) = (&request.object_id, &request.method_id);
 
@@ -656,145 +905,97 @@ impl AddressSpace {
         } else {
             // TODO check security - session / user may not have permission to call methods
 
-            // Find the handler for this method call
-            let key = MethodKey {
-                object_id: object_id.clone(),
-                method_id: method_id.clone(),
-            };
-            if let Some(handler) = self.method_handlers.get(&key) {
+            // Check the input arguments
+            if let Some(input_argument_results) = self.validate_input_arguments(method_id, request.input_arguments.as_ref())? {
+                if input_argument_results.iter().any(|r| r.is_bad()) {
+                    error!("Method call to {:?} on {:?} has invalid arguments {:?}", method_id, object_id, input_argument_results);
+                    return Ok(CallMethodResult {
+                        status_code: BadInvalidArgument,
+                        input_argument_results: Some(input_argument_results),
+                        input_argument_diagnostic_infos: None,
+                        output_arguments: None,
+                    });
+                }
+            }
+
+            // Find the handler for this method call, either on this object or on any object, and
+            // then on the declaration of an instantiated method
+            let handler = self.find_method_handler(object_id, method_id).or_else(|| {
+                self.method_declarations.get(method_id).and_then(|declaration_id| self.find_method_handler(object_id, declaration_id))
+            });
+            if let Some(handler) = handler {
                 // Call the handler
                 trace!("Method call to {:?} on {:?} being handled by a registered handler", method_id, object_id);
                 handler(self, server_state, session, request)
             } else {
-                // TODO we could do a secondary search on a (NodeId::null(), method_id) here
-                // so that method handler is reusable for multiple objects
                 error!("Method call to {:?} on {:?} has no handler, treating as invalid", method_id, object_id);
                 Err(BadMethodInvalid)
             }
         }
     }
 
-    fn reference_type_matches(&self, r1: ReferenceTypeId, r2: ReferenceTypeId, include_subtypes: bool) -> bool {
-        if r1 == r2 {
-            true
-        } else if include_subtypes {
-            // THIS IS AN UGLY HACK. The subtype code should really walk down the hierarchy of
-            // types in the address space to figure this out
-            match r1 {
-                ReferenceTypeId::HierarchicalReferences => {
-                    match r2 {
-                        ReferenceTypeId::HierarchicalReferences | ReferenceTypeId::HasChild |
-                        ReferenceTypeId::HasSubtype | ReferenceTypeId::Organizes |
-                        ReferenceTypeId::Aggregates | ReferenceTypeId::HasProperty |
-                        ReferenceTypeId::HasComponent | ReferenceTypeId::HasOrderedComponent |
-                        ReferenceTypeId::HasEventSource | ReferenceTypeId::HasNotifier => {
-                            true
-                        }
-                        _ => false
-                    }
-                }
-                ReferenceTypeId::HasChild => {
-                    match r2 {
-                        ReferenceTypeId::Aggregates | ReferenceTypeId::HasComponent |
-                        ReferenceTypeId::HasHistoricalConfiguration | ReferenceTypeId::HasProperty |
-                        ReferenceTypeId::HasOrderedComponent | ReferenceTypeId::HasSubtype => {
-                            true
-                        }
-                        _ => false
-                    }
-                }
-                ReferenceTypeId::Aggregates => {
-                    match r2 {
-                        ReferenceTypeId::HasComponent | ReferenceTypeId::HasHistoricalConfiguration |
-                        ReferenceTypeId::HasProperty | ReferenceTypeId::HasOrderedComponent => {
-                            true
-                        }
-                        _ => false
-                    }
-                }
-                ReferenceTypeId::HasComponent => {
-                    r2 == ReferenceTypeId::HasOrderedComponent
-                }
-                ReferenceTypeId::HasEventSource => {
-                    r2 == ReferenceTypeId::HasNotifier
-                }
-                _ => {
-                    // TODO somehow work out subtypes, e.g. working back along inverse references
-                    false
-                }
-            }
-        } else {
-            false
-        }
+    fn find_method_handler(&self, object_id: &NodeId, method_id: &NodeId) -> Option<&MethodCallback> {
+        let key = MethodKey {
+            object_id: object_id.clone(),
+            method_id: method_id.clone(),
+        };
+        let any_object_key = MethodKey {
+            object_id: NodeId::null(),
+            method_id: method_id.clone(),
+        };
+        self.method_handlers.get(&key).or_else(|| self.method_handlers.get(&any_object_key))
     }
 
-    fn filter_references_by_type(&self, references: &Vec<Reference>, reference_filter: Option<(ReferenceTypeId, bool)>) -> Vec<Reference> {
-        if reference_filter.is_none() {
-            references.clone()
-        } else {
-            // Filter by type
-            let (reference_type_id, include_subtypes) = reference_filter.unwrap();
-            let mut result = Vec::new();
-            for reference in references {
-                if self.reference_type_matches(reference_type_id, reference.reference_type_id, include_subtypes) {
-                    result.push(reference.clone());
-                }
-            }
-            result
-        }
+    /// Records the declaration that an instantiated method was copied from
+    pub(crate) fn add_method_declaration(&mut self, method_id: &NodeId, declaration_id: &NodeId) {
+        self.method_declarations.insert(method_id.clone(), declaration_id.clone());
     }
 
-    /// Find and filter references that refer to the specified node.
-    fn find_references(&self, reference_map: &HashMap<NodeId, Vec<Reference>>, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> Option<Vec<Reference>> {
-        if let Some(ref node_references) = reference_map.get(node_id) {
-            let result = self.filter_references_by_type(node_references, reference_filter);
-            if result.is_empty() {
-                None
-            } else {
-                Some(result)
-            }
-        } else {
+    /// Iterates over the forward references from the specified node without copying them
+    pub fn references_from(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> References {
+        self.node_store.references_from(node_id, reference_filter)
+    }
+
+    /// Iterates over the inverse references, i.e. those that point to the specified node, without
+    /// copying them
+    pub fn references_to(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> References {
+        self.node_store.references_to(node_id, reference_filter)
+    }
+
+    /// Copies references into a `Vec`, or `None` if there are none
+    fn collect_references(references: References) -> Option<Vec<Reference>> {
+        let result = references.map(|(reference_type_id, node_id)| Reference::new(reference_type_id, node_id)).collect::<Vec<Reference>>();
+        if result.is_empty() {
             None
+        } else {
+            Some(result)
         }
     }
 
     /// Finds forward references from the specified node
     pub fn find_references_from(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> Option<Vec<Reference>> {
-        self.find_references(&self.references, node_id, reference_filter)
+        Self::collect_references(self.references_from(node_id, reference_filter))
     }
 
     /// Finds inverse references, it those that point to the specified node
     pub fn find_references_to(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> Option<Vec<Reference>> {
-        self.find_references(&self.inverse_references, node_id, reference_filter)
+        Self::collect_references(self.references_to(node_id, reference_filter))
     }
 
     /// Finds references for optionally forwards, inverse or both and return the references. The usize
     /// represents the index in the collection where the inverse references start (if applicable)
     pub fn find_references_by_direction(&self, node_id: &NodeId, browse_direction: BrowseDirection, reference_filter: Option<(ReferenceTypeId, bool)>) -> (Vec<Reference>, usize) {
-        let mut references = Vec::new();
-        let inverse_ref_idx: usize;
-        match browse_direction {
-            BrowseDirection::Forward => {
-                if let Some(mut forward_references) = self.find_references_from(node_id, reference_filter) {
-                    references.append(&mut forward_references);
-                }
-                inverse_ref_idx = references.len();
-            }
-            BrowseDirection::Inverse => {
-                inverse_ref_idx = 0;
-                if let Some(mut inverse_references) = self.find_references_to(node_id, reference_filter) {
-                    references.append(&mut inverse_references);
-                }
-            }
-            BrowseDirection::Both => {
-                if let Some(mut forward_references) = self.find_references_from(node_id, reference_filter) {
-                    references.append(&mut forward_references);
-                }
-                inverse_ref_idx = references.len();
-                if let Some(mut inverse_references) = self.find_references_to(node_id, reference_filter) {
-                    references.append(&mut inverse_references);
-                }
-            }
+        let forward = if browse_direction != BrowseDirection::Inverse { Some(self.references_from(node_id, reference_filter)) } else { None };
+        let inverse = if browse_direction != BrowseDirection::Forward { Some(self.references_to(node_id, reference_filter)) } else { None };
+        // Unfiltered references are counted exactly, so they are copied without reallocating
+        let capacity = forward.as_ref().map_or(0, |r| r.size_hint().0) + inverse.as_ref().map_or(0, |r| r.size_hint().0);
+        let mut references = Vec::with_capacity(capacity);
+        if let Some(forward) = forward {
+            references.extend(forward.map(|(reference_type_id, node_id)| Reference::new(reference_type_id, node_id)));
+        }
+        let inverse_ref_idx = references.len();
+        if let Some(inverse) = inverse {
+            references.extend(inverse.map(|(reference_type_id, node_id)| Reference::new(reference_type_id, node_id)));
         }
         (references, inverse_ref_idx)
     }
@@ -10,6 +10,7 @@ use opcua_types::service_types::*;
 
 use address_space::{AttributeGetter, AttributeSetter};
 use address_space::node::Node;
+use address_space::data_change::DataChangeIndex;
 
 // This should match size of AttributeId
 const NUM_ATTRIBUTES: usize = 22;
@@ -54,6 +55,8 @@ pub struct Base {
     attribute_getters: HashMap<AttributeId, Arc<Mutex<AttributeGetter + Send>>>,
     /// Attribute setters - if None, handled by Base
     attribute_setters: HashMap<AttributeId, Arc<Mutex<AttributeSetter + Send>>>,
+    /// Monitored items that are told when an attribute is set, once the node is in an address space
+    data_change_index: Option<Arc<DataChangeIndex>>,
 }
 
 impl Debug for Base {
@@ -138,7 +141,7 @@ impl Node for Base {
                 false
             }
             AttributeId::BrowseName => {
-                is_valid_value_type!(value, String)
+                is_valid_value_type!(value, QualifiedName)
             }
             AttributeId::DisplayName | AttributeId::Description | AttributeId::InverseName => {
                 is_valid_value_type!(value, LocalizedText)
@@ -192,21 +195,35 @@ impl Node for Base {
                 let mut setter = setter.lock().unwrap();
                 setter.set(self.node_id(), attribute_id, value)?;
             } else {
+                // Values from a getter are polled, others are pushed to the items that watch them
+                if let Some(ref data_change_index) = self.data_change_index {
+                    if !self.attribute_getters.contains_key(&attribute_id) {
+                        data_change_index.notify(&self.node_id(), attribute_id, &value);
+                    }
+                }
                 self.attributes[attribute_idx] = Some(value);
             }
             Ok(())
         }
     }
+
+    fn has_attribute_getter(&self, attribute_id: AttributeId) -> bool {
+        self.attribute_getters.contains_key(&attribute_id)
+    }
+
+    fn set_data_change_index(&mut self, data_change_index: Arc<DataChangeIndex>) {
+        self.data_change_index = Some(data_change_index);
+    }
 }
 
 impl Base {
-    pub fn new(node_class: NodeClass, node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, mut attributes: Vec<(AttributeId, Variant)>) -> Base {
+    pub fn new<R>(node_class: NodeClass, node_id: &NodeId, browse_name: R, display_name: &str, description: &str, mut attributes: Vec<(AttributeId, Variant)>) -> Base where R: Into<QualifiedName> {
         // Mandatory attributes
         let mut attributes_to_add = vec![
             (AttributeId::NodeClass, Variant::Int32(node_class as Int32)),
             (AttributeId::NodeId, Variant::new(node_id.clone())),
             (AttributeId::DisplayName, Variant::new(LocalizedText::new("", display_name))),
-            (AttributeId::BrowseName, Variant::new(QualifiedName::new(0, browse_name))),
+            (AttributeId::BrowseName, Variant::new(browse_name.into())),
             (AttributeId::Description, Variant::new(LocalizedText::new("", description))),
             (AttributeId::WriteMask, Variant::UInt32(0)),
             (AttributeId::UserWriteMask, Variant::UInt32(0)),
@@ -232,10 +249,15 @@ impl Base {
             attributes,
             attribute_getters: HashMap::new(),
             attribute_setters: HashMap::new(),
+            data_change_index: None,
         }
     }
 
     pub fn set_attribute_getter(&mut self, attribute_id: AttributeId, getter: Arc<Mutex<AttributeGetter + Send>>) {
+        // Items that watch the attribute poll the getter from now on
+        if let Some(ref data_change_index) = self.data_change_index {
+            data_change_index.set_polled(&self.node_id(), attribute_id);
+        }
         self.attribute_getters.insert(attribute_id, getter);
     }
 
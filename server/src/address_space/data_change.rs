@@ -0,0 +1,236 @@
+//! The index of the monitored items that watch the attributes of nodes.
+//!
+//! When the value of an attribute is set on a node, the node pushes the new value to the queue of
+//! each monitored item that watches the attribute, so items do not have to poll the address space
+//! to find out if something changed. A queue is held weakly by the index and is unregistered when
+//! its monitored item drops it. A queue may have a `DataChangeSignal`, which the index raises as it
+//! pushes a change, so the subscriptions of a session are woken to sample the change instead of
+//! waiting for their timer.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::Utc;
+use futures::{Async, Poll, Stream};
+use futures::task::AtomicTask;
+
+use opcua_types::{NodeId, AttributeId, DataValue};
+
+use constants;
+use DateTimeUtc;
+
+/// A value of an attribute and the time that it was set
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataChange {
+    pub value: DataValue,
+    pub time: DateTimeUtc,
+}
+
+/// Raised when a change is pushed to any of the queues that have the signal, and wakes the task
+/// that waits on its `DataChanges` stream
+pub struct DataChangeSignal {
+    changed: AtomicBool,
+    task: AtomicTask,
+}
+
+impl fmt::Debug for DataChangeSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DataChangeSignal {{ changed: {:?} }}", self.changed.load(Ordering::SeqCst))
+    }
+}
+
+impl PartialEq for DataChangeSignal {
+    fn eq(&self, other: &DataChangeSignal) -> bool {
+        self as *const DataChangeSignal == other as *const DataChangeSignal
+    }
+}
+
+impl DataChangeSignal {
+    pub fn new() -> DataChangeSignal {
+        DataChangeSignal {
+            changed: AtomicBool::new(false),
+            task: AtomicTask::new(),
+        }
+    }
+
+    fn raise(&self) {
+        self.changed.store(true, Ordering::SeqCst);
+        self.task.notify();
+    }
+
+    /// Tests if the signal was raised since the last call, and lowers it
+    pub fn take(&self) -> bool {
+        self.changed.swap(false, Ordering::SeqCst)
+    }
+
+    /// Returns a stream that yields each time the signal is raised. Raises that happen before
+    /// the stream is polled are yielded once.
+    pub fn changes(signal: &Arc<DataChangeSignal>) -> DataChanges {
+        DataChanges {
+            signal: signal.clone(),
+        }
+    }
+}
+
+/// A stream that yields when its `DataChangeSignal` is raised. It never ends.
+pub struct DataChanges {
+    signal: Arc<DataChangeSignal>,
+}
+
+impl Stream for DataChanges {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Option<()>, ()> {
+        // Register before testing, so a raise in between is not missed
+        self.signal.task.register();
+        if self.signal.take() {
+            Ok(Async::Ready(Some(())))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+/// The changes to an attribute that a monitored item has yet to sample, oldest first
+#[derive(Debug, Clone)]
+pub struct DataChangeQueue {
+    changes: Arc<Mutex<VecDeque<DataChange>>>,
+    /// Set when the attribute gets a getter, after which its values are polled
+    polled: Arc<AtomicBool>,
+}
+
+impl PartialEq for DataChangeQueue {
+    fn eq(&self, other: &DataChangeQueue) -> bool {
+        Arc::ptr_eq(&self.changes, &other.changes)
+    }
+}
+
+impl DataChangeQueue {
+    fn new() -> DataChangeQueue {
+        DataChangeQueue {
+            changes: Arc::new(Mutex::new(VecDeque::new())),
+            polled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Tests if the queue has no changes
+    pub fn is_empty(&self) -> bool {
+        let changes = trace_lock_unwrap!(self.changes);
+        changes.is_empty()
+    }
+
+    /// Tests if the attribute has got a getter since the queue was made, so changes are no
+    /// longer pushed to it and the value must be polled instead
+    pub fn is_polled(&self) -> bool {
+        self.polled.load(Ordering::SeqCst)
+    }
+
+    /// Takes the changes from the queue
+    pub fn take(&self) -> Vec<DataChange> {
+        let mut changes = trace_lock_unwrap!(self.changes);
+        changes.drain(..).collect()
+    }
+
+    /// Discards the changes in the queue
+    pub fn clear(&self) {
+        let mut changes = trace_lock_unwrap!(self.changes);
+        changes.clear();
+    }
+}
+
+/// A queue that watches an attribute, and the signal that it raises
+struct Watcher {
+    changes: Weak<Mutex<VecDeque<DataChange>>>,
+    polled: Arc<AtomicBool>,
+    signal: Option<Arc<DataChangeSignal>>,
+}
+
+/// Maps an attribute of a node to the queues of the monitored items that watch it
+pub struct DataChangeIndex {
+    watchers: Mutex<HashMap<(NodeId, AttributeId), Vec<Watcher>>>,
+}
+
+impl DataChangeIndex {
+    pub fn new() -> DataChangeIndex {
+        DataChangeIndex {
+            watchers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns a queue that receives the changes to the attribute of the node, until the queue
+    /// and its clones are dropped. The signal, if there is one, is raised with each change.
+    pub fn watch(&self, node_id: &NodeId, attribute_id: AttributeId, signal: Option<Arc<DataChangeSignal>>) -> DataChangeQueue {
+        let queue = DataChangeQueue::new();
+        let mut watchers = trace_lock_unwrap!(self.watchers);
+        watchers.entry((node_id.clone(), attribute_id)).or_insert_with(Vec::new).push(Watcher {
+            changes: Arc::downgrade(&queue.changes),
+            polled: queue.polled.clone(),
+            signal,
+        });
+        queue
+    }
+
+    /// Tells the queues that watch the attribute of the node that it has a getter, which means
+    /// its values are polled from now on, and unregisters them
+    pub fn set_polled(&self, node_id: &NodeId, attribute_id: AttributeId) {
+        let mut watchers = trace_lock_unwrap!(self.watchers);
+        if let Some(queues) = watchers.remove(&(node_id.clone(), attribute_id)) {
+            queues.iter().for_each(|queue| {
+                queue.polled.store(true, Ordering::SeqCst);
+                if let Some(ref signal) = queue.signal {
+                    signal.raise();
+                }
+            });
+        }
+    }
+
+    /// Pushes the value of an attribute to the queues that watch it and removes the queues that
+    /// have been dropped. A queue that is full loses its oldest change.
+    pub fn notify(&self, node_id: &NodeId, attribute_id: AttributeId, value: &DataValue) {
+        let mut watchers = trace_lock_unwrap!(self.watchers);
+        if watchers.is_empty() {
+            return;
+        }
+        let key = (node_id.clone(), attribute_id);
+        let is_unwatched = if let Some(queues) = watchers.get_mut(&key) {
+            let change = DataChange {
+                value: value.clone(),
+                time: Utc::now(),
+            };
+            queues.retain(|queue| {
+                if let Some(changes) = queue.changes.upgrade() {
+                    {
+                        let mut changes = trace_lock_unwrap!(changes);
+                        if changes.len() >= constants::MAX_PENDING_DATA_CHANGES {
+                            let _ = changes.pop_front();
+                        }
+                        changes.push_back(change.clone());
+                    }
+                    if let Some(ref signal) = queue.signal {
+                        signal.raise();
+                    }
+                    true
+                } else {
+                    false
+                }
+            });
+            queues.is_empty()
+        } else {
+            false
+        };
+        if is_unwatched {
+            watchers.remove(&key);
+        }
+    }
+
+    /// Returns the number of queues that watch the attribute of the node
+    pub fn watcher_count(&self, node_id: &NodeId, attribute_id: AttributeId) -> usize {
+        let watchers = trace_lock_unwrap!(self.watchers);
+        watchers.get(&(node_id.clone(), attribute_id)).map_or(0, |queues| {
+            queues.iter().filter(|queue| queue.changes.upgrade().is_some()).count()
+        })
+    }
+}
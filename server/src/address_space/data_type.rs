@@ -1,20 +1,62 @@
 use address_space::base::Base;
 use address_space::node::Node;
 
+/// A field of a structure, or a value of an enumeration, in a data type definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTypeField {
+    /// The name of the field
+    pub name: String,
+    /// The data type of the field
+    pub data_type: NodeId,
+    /// The value rank of the field, -1 for a scalar
+    pub value_rank: Int32,
+    /// The value of the field if the definition is of an enumeration
+    pub value: Option<Int32>,
+    /// Description of the field
+    pub description: Option<LocalizedText>,
+}
+
+/// Describes the fields of a structure or the values of an enumeration, as it appears in the
+/// `<Definition>` of a nodeset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTypeDefinition {
+    /// The name of the type
+    pub name: QualifiedName,
+    /// True if the structure is a union of its fields
+    pub is_union: Boolean,
+    /// The fields in the order they are encoded
+    pub fields: Vec<DataTypeField>,
+}
+
 #[derive(Debug)]
 pub struct DataType {
     base: Base,
+    definition: Option<DataTypeDefinition>,
 }
 
 node_impl!(DataType);
 
 impl DataType {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, is_abstract: Boolean) -> DataType {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, is_abstract: Boolean) -> DataType where R: Into<QualifiedName> {
         let attributes = vec![
             (AttributeId::IsAbstract, Variant::Boolean(is_abstract)),
         ];
         DataType {
             base: Base::new(NodeClass::DataType, node_id, browse_name, display_name, description, attributes),
+            definition: None,
         }
     }
+
+    pub fn is_abstract(&self) -> Boolean {
+        find_attribute_value_mandatory!(&self.base, IsAbstract, Boolean)
+    }
+
+    /// Returns the definition of the fields of the type, if it has one
+    pub fn definition(&self) -> Option<&DataTypeDefinition> {
+        self.definition.as_ref()
+    }
+
+    pub fn set_definition(&mut self, definition: DataTypeDefinition) {
+        self.definition = Some(definition);
+    }
 }
@@ -0,0 +1,349 @@
+//! Instantiation of ObjectTypes and VariableTypes.
+//!
+//! The children of a type that have a HasModellingRule reference are its InstanceDeclarations
+//! (Part 3, 6.4). Instantiating the type creates a node for each Mandatory declaration, and for
+//! each Optional declaration that is asked for, with a new node id and the attributes and
+//! references of the declaration. Declarations are inherited from supertypes, and a subtype
+//! overrides a declaration of its supertype by declaring a child with the same browse name.
+//! Object and variable declarations have children of their own, which come from the declaration
+//! and from its type definition, so instantiation descends through them in the same way.
+
+use std::collections::HashSet;
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ObjectId, ObjectTypeId, ReferenceTypeId, VariableTypeId};
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use address_space::address_space::{AddressSpace, Reference, ReferenceDirection};
+use address_space::node::{Node, NodeType};
+use address_space::types::{Method, Object, Variable};
+
+/// The attributes copied from an InstanceDeclaration to the node made from it. Attributes that
+/// the node class does not have are not copied.
+const COPIED_ATTRIBUTES: [AttributeId; 16] = [
+    AttributeId::BrowseName,
+    AttributeId::DisplayName,
+    AttributeId::Description,
+    AttributeId::WriteMask,
+    AttributeId::UserWriteMask,
+    AttributeId::EventNotifier,
+    AttributeId::Value,
+    AttributeId::DataType,
+    AttributeId::ValueRank,
+    AttributeId::ArrayDimensions,
+    AttributeId::AccessLevel,
+    AttributeId::UserAccessLevel,
+    AttributeId::MinimumSamplingInterval,
+    AttributeId::Historizing,
+    AttributeId::Executable,
+    AttributeId::UserExecutable,
+];
+
+/// The limit to how deeply declarations are nested, which guards against a type that contains
+/// an instance of itself
+const MAX_INSTANTIATION_DEPTH: usize = 16;
+
+/// A node of the instance, made from a declaration
+struct PlannedNode {
+    /// The InstanceDeclaration that the node is made from
+    declaration_id: NodeId,
+    /// The id of the new node, or the declaration's id when the declaration is shared
+    node_id: NodeId,
+    /// The node that references the new node
+    parent_id: NodeId,
+    /// The reference from the parent, the same type as the declaration's reference
+    reference_type_id: ReferenceTypeId,
+    /// True for a MandatoryShared declaration, which the instance references instead of copying
+    shared: bool,
+}
+
+impl AddressSpace {
+    /// Creates an instance of an ObjectType or VariableType under the parent node and returns its
+    /// node id. The instance has a node for each Mandatory InstanceDeclaration of the type and its
+    /// supertypes, and for the declarations of those in turn. Optional declarations are left out.
+    /// The new nodes have numeric ids in the parent's namespace, or in the browse name's when the
+    /// parent is in namespace 0.
+    pub fn instantiate<R>(&mut self, type_id: &NodeId, parent_id: &NodeId, browse_name: R) -> Result<NodeId, StatusCode> where R: Into<QualifiedName> {
+        self.instantiate_with_optionals(type_id, parent_id, browse_name, &[])
+    }
+
+    /// Creates an instance of an ObjectType or VariableType, as `instantiate()` does, which also
+    /// has the Optional declarations named in `optionals`. An optional is named by the path of
+    /// browse names from the instance, separated by '/', e.g. "Motor/Speed" for the optional
+    /// Speed of the instance's Motor.
+    ///
+    /// The type must be a concrete ObjectType or VariableType, the parent must exist and must not
+    /// have another child with the browse name, and every optional must name an Optional
+    /// declaration. Nothing is added to the address space when the instance cannot be made.
+    pub fn instantiate_with_optionals<R>(&mut self, type_id: &NodeId, parent_id: &NodeId, browse_name: R, optionals: &[&str]) -> Result<NodeId, StatusCode> where R: Into<QualifiedName> {
+        let browse_name = browse_name.into();
+        let is_variable_type = match self.find_node(type_id) {
+            Some(&NodeType::ObjectType(ref object_type)) if !object_type.is_abstract() => false,
+            Some(&NodeType::VariableType(ref variable_type)) if !variable_type.is_abstract() => true,
+            _ => {
+                error!("Cannot instantiate {:?}, it is not a concrete object type or variable type", type_id);
+                return Err(BadTypeDefinitionInvalid);
+            }
+        };
+        if !self.node_exists(parent_id) {
+            error!("Cannot instantiate {:?} under {:?}, the parent does not exist", type_id, parent_id);
+            return Err(BadParentNodeIdInvalid);
+        }
+        if browse_name.name.is_null() || browse_name.name.as_ref().is_empty() {
+            return Err(BadBrowseNameInvalid);
+        }
+        if self.has_child_named(parent_id, &browse_name) {
+            error!("Cannot instantiate {:?} under {:?}, the parent already has a child named {:?}", type_id, parent_id, browse_name);
+            return Err(BadBrowseNameDuplicated);
+        }
+
+        // Work out every node of the instance before adding any of them
+        let namespace = Self::instance_namespace(parent_id, &browse_name);
+        let node_id = self.new_instance_node_id(namespace);
+        let mut planned_nodes = Vec::new();
+        let mut selected = HashSet::new();
+        let sources = self.type_hierarchy(type_id);
+        self.plan_children(&node_id, namespace, &sources, "", optionals, 0, &mut planned_nodes, &mut selected)?;
+        if let Some(optional) = optionals.iter().find(|optional| !selected.contains(**optional)) {
+            error!("Cannot instantiate {:?}, the optional {} is not an optional declaration of the type", type_id, optional);
+            return Err(BadInvalidArgument);
+        }
+
+        // The instance itself
+        let reference_type_id = self.reference_type_to_instance(parent_id, is_variable_type, type_id);
+        if is_variable_type {
+            let mut variable = Variable::new_data_value(&node_id, browse_name.clone(), browse_name.name.as_ref(), "", DataTypeId::BaseDataType, DataValue::null());
+            if let Some(variable_type) = self.find_node(type_id) {
+                copy_attributes(variable_type.as_node(), &mut variable, &[AttributeId::Value, AttributeId::DataType, AttributeId::ValueRank, AttributeId::ArrayDimensions]);
+            }
+            self.insert(variable, None);
+        } else {
+            self.insert(Object::new(&node_id, browse_name.clone(), browse_name.name.as_ref(), ""), None);
+        }
+        self.insert_references(&[
+            (parent_id, &node_id, reference_type_id),
+            (&node_id, type_id, ReferenceTypeId::HasTypeDefinition),
+        ]);
+
+        // Its children, which are planned in order so that a parent is added before its children
+        for planned_node in planned_nodes {
+            if !planned_node.shared {
+                self.insert_declaration_copy(&planned_node);
+            }
+            self.insert_reference(&planned_node.parent_id, &planned_node.node_id, planned_node.reference_type_id);
+        }
+
+        Ok(node_id)
+    }
+
+    /// Plans the nodes made from the declarations of the sources, i.e. the type hierarchy of the
+    /// instance, or a declaration followed by its type hierarchy.
+    fn plan_children(&self, parent_id: &NodeId, namespace: UInt16, sources: &[NodeId], path: &str, optionals: &[&str], depth: usize, planned_nodes: &mut Vec<PlannedNode>, selected: &mut HashSet<String>) -> Result<(), StatusCode> {
+        if depth > MAX_INSTANTIATION_DEPTH {
+            error!("Cannot instantiate, the declarations under {} are nested too deeply", path);
+            return Err(BadTypeDefinitionInvalid);
+        }
+        for declaration in self.instance_declarations(sources) {
+            let declaration_id = declaration.node_id;
+            // Only objects, variables and methods can be declarations
+            let browse_name = match self.find_node(&declaration_id) {
+                Some(&NodeType::Object(ref node)) => node.browse_name(),
+                Some(&NodeType::Variable(ref node)) => node.browse_name(),
+                Some(&NodeType::Method(ref node)) => node.browse_name(),
+                _ => {
+                    error!("Cannot instantiate, the declaration {:?} under {} is not an object, variable or method", declaration_id, path);
+                    return Err(BadTypeDefinitionInvalid);
+                }
+            };
+            let child_path = if path.is_empty() {
+                browse_name.name.as_ref().to_string()
+            } else {
+                format!("{}/{}", path, browse_name.name.as_ref())
+            };
+
+            let modelling_rule = self.modelling_rule(&declaration_id);
+            let shared = if modelling_rule == Some(ObjectId::ModellingRule_Mandatory.into()) {
+                false
+            } else if modelling_rule == Some(ObjectId::ModellingRule_MandatoryShared.into()) {
+                true
+            } else if modelling_rule == Some(ObjectId::ModellingRule_Optional.into()) {
+                if !optionals.contains(&child_path.as_ref()) {
+                    continue;
+                }
+                selected.insert(child_path.clone());
+                false
+            } else {
+                // Placeholders and ExposesItsArray describe nodes that the server adds as it
+                // needs them
+                continue;
+            };
+
+            if shared {
+                planned_nodes.push(PlannedNode {
+                    node_id: declaration_id.clone(),
+                    declaration_id,
+                    parent_id: parent_id.clone(),
+                    reference_type_id: declaration.reference_type_id,
+                    shared,
+                });
+            } else {
+                let node_id = self.new_instance_node_id(namespace);
+                let mut sources = vec![declaration_id.clone()];
+                if let Some(type_id) = self.type_definition(&declaration_id) {
+                    sources.extend(self.type_hierarchy(&type_id));
+                }
+                planned_nodes.push(PlannedNode {
+                    declaration_id,
+                    node_id: node_id.clone(),
+                    parent_id: parent_id.clone(),
+                    reference_type_id: declaration.reference_type_id,
+                    shared,
+                });
+                self.plan_children(&node_id, namespace, &sources, &child_path, optionals, depth + 1, planned_nodes, selected)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The namespace of the nodes of an instance, which is the parent's, or the browse name's
+    /// when the parent is in namespace 0, or else namespace 1
+    fn instance_namespace(parent_id: &NodeId, browse_name: &QualifiedName) -> UInt16 {
+        if parent_id.namespace != 0 {
+            parent_id.namespace
+        } else if browse_name.namespace_index != 0 {
+            browse_name.namespace_index
+        } else {
+            1
+        }
+    }
+
+    /// Makes a numeric node id in the namespace for a node of an instance, skipping any id that
+    /// is already taken, e.g. by a node that was imported or added with an id of its own
+    fn new_instance_node_id(&self, namespace: UInt16) -> NodeId {
+        loop {
+            let node_id = NodeId {
+                namespace,
+                identifier: NodeId::next_numeric().identifier,
+            };
+            if !self.node_exists(&node_id) {
+                return node_id;
+            }
+        }
+    }
+
+    /// Adds the node of an instance that is a copy of its declaration, together with the
+    /// declaration's HasTypeDefinition and HasModellingRule references. The node class of the
+    /// declaration has been checked when the node was planned.
+    fn insert_declaration_copy(&mut self, planned_node: &PlannedNode) {
+        let node_id = &planned_node.node_id;
+        let declaration_id = &planned_node.declaration_id;
+        let node = match self.find_node(declaration_id) {
+            Some(&NodeType::Object(ref declaration)) => {
+                let mut object = Object::new(node_id, "", "", "");
+                copy_attributes(declaration, &mut object, &COPIED_ATTRIBUTES);
+                NodeType::Object(object)
+            }
+            Some(&NodeType::Variable(ref declaration)) => {
+                let mut variable = Variable::new_data_value(node_id, "", "", "", DataTypeId::BaseDataType, DataValue::null());
+                copy_attributes(declaration, &mut variable, &COPIED_ATTRIBUTES);
+                NodeType::Variable(variable)
+            }
+            Some(&NodeType::Method(ref declaration)) => {
+                let mut method = Method::new(node_id, "", "", "", false, false, false);
+                copy_attributes(declaration, &mut method, &COPIED_ATTRIBUTES);
+                NodeType::Method(method)
+            }
+            _ => {
+                unreachable!("Declaration {:?} is not an object, variable or method", declaration_id);
+            }
+        };
+        let is_method = if let NodeType::Method(_) = node { true } else { false };
+        let references = self.references_from(declaration_id, None)
+            .filter(|&(reference_type_id, _)| reference_type_id == ReferenceTypeId::HasTypeDefinition || reference_type_id == ReferenceTypeId::HasModellingRule)
+            .map(|(reference_type_id, node_id)| Reference::new(reference_type_id, node_id))
+            .collect::<Vec<Reference>>();
+        let references = references.iter()
+            .map(|r| (&r.node_id, r.reference_type_id, ReferenceDirection::Forward))
+            .collect::<Vec<(&NodeId, ReferenceTypeId, ReferenceDirection)>>();
+        self.insert(node, Some(&references));
+        if is_method {
+            self.add_method_declaration(node_id, declaration_id);
+        }
+    }
+
+    /// Finds the declarations of the sources. The first declaration of a browse name is the one
+    /// that counts, so a source overrides the declarations of the sources after it.
+    fn instance_declarations(&self, sources: &[NodeId]) -> Vec<Reference> {
+        let mut browse_names = HashSet::new();
+        let mut declarations = Vec::new();
+        for source in sources {
+            for (reference_type_id, node_id) in self.references_from(source, Some((ReferenceTypeId::Aggregates, true))) {
+                if let Some(node) = self.find_node(node_id) {
+                    if self.modelling_rule(node_id).is_some() {
+                        let browse_name = node.as_node().browse_name();
+                        if browse_names.insert((browse_name.namespace_index, browse_name.name.as_ref().to_string())) {
+                            declarations.push(Reference::new(reference_type_id, node_id));
+                        }
+                    }
+                }
+            }
+        }
+        declarations
+    }
+
+    /// Returns the type followed by its supertypes
+    fn type_hierarchy(&self, type_id: &NodeId) -> Vec<NodeId> {
+        let mut type_ids = vec![type_id.clone()];
+        let mut type_id = type_id.clone();
+        // The depth limit guards against a cycle of subtypes
+        for _ in 0..32 {
+            type_id = if let Some((_, supertype_id)) = self.references_to(&type_id, Some((ReferenceTypeId::HasSubtype, false))).next() {
+                supertype_id.clone()
+            } else {
+                break;
+            };
+            type_ids.push(type_id.clone());
+        }
+        type_ids
+    }
+
+    fn modelling_rule(&self, node_id: &NodeId) -> Option<NodeId> {
+        self.references_from(node_id, Some((ReferenceTypeId::HasModellingRule, false))).next().map(|(_, node_id)| node_id.clone())
+    }
+
+    fn type_definition(&self, node_id: &NodeId) -> Option<NodeId> {
+        self.references_from(node_id, Some((ReferenceTypeId::HasTypeDefinition, false))).next().map(|(_, node_id)| node_id.clone())
+    }
+
+    fn has_child_named(&self, parent_id: &NodeId, browse_name: &QualifiedName) -> bool {
+        self.references_from(parent_id, Some((ReferenceTypeId::HierarchicalReferences, true)))
+            .filter_map(|(_, node_id)| self.find_node(node_id))
+            .any(|node| node.as_node().browse_name() == *browse_name)
+    }
+
+    /// Works out how the parent references a new instance. Folders organize their instances,
+    /// objects and variables have them as components, or properties if the instance is a
+    /// property.
+    fn reference_type_to_instance(&self, parent_id: &NodeId, is_variable_type: bool, type_id: &NodeId) -> ReferenceTypeId {
+        let parent_is_folder = self.type_definition(parent_id).map_or(false, |parent_type_id| {
+            self.is_subtype_of(&parent_type_id, &ObjectTypeId::FolderType.into())
+        });
+        let is_property = is_variable_type && self.is_subtype_of(type_id, &VariableTypeId::PropertyType.into());
+        match self.find_node(parent_id) {
+            Some(&NodeType::Object(_)) if parent_is_folder => ReferenceTypeId::Organizes,
+            Some(&NodeType::Object(_)) | Some(&NodeType::ObjectType(_)) | Some(&NodeType::Variable(_)) | Some(&NodeType::VariableType(_)) => {
+                if is_property { ReferenceTypeId::HasProperty } else { ReferenceTypeId::HasComponent }
+            }
+            _ => ReferenceTypeId::Organizes,
+        }
+    }
+}
+
+fn copy_attributes(from: &Node, to: &mut Node, attribute_ids: &[AttributeId]) {
+    for attribute_id in attribute_ids {
+        if let Some(value) = from.find_attribute(*attribute_id) {
+            let _ = to.set_attribute(*attribute_id, value);
+        }
+    }
+}
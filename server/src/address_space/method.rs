@@ -9,7 +9,7 @@ pub struct Method {
 node_impl!(Method);
 
 impl Method {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, is_abstract: Boolean, executable: Boolean, user_executable: Boolean) -> Method {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, is_abstract: Boolean, executable: Boolean, user_executable: Boolean) -> Method where R: Into<QualifiedName> {
         // Mandatory
         let attributes = vec![
             (AttributeId::IsAbstract, Variant::Boolean(is_abstract)),
@@ -0,0 +1,145 @@
+//! Conversions between the input / output arguments of a method call and Rust types. These are
+//! used by handlers registered with `AddressSpace::register_typed_method_handler` which take a
+//! tuple of inputs and return a tuple of outputs instead of a raw `CallMethodRequest`.
+
+use opcua_types::*;
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+/// A type that a single input argument can be converted into
+pub trait FromArgument: Sized {
+    /// Converts the argument, or returns `None` if it holds a different type
+    fn from_argument(value: &Variant) -> Option<Self>;
+}
+
+macro_rules! from_argument_impl {
+    ( $t: ty, $variant_type: ident ) => {
+        impl FromArgument for $t {
+            fn from_argument(value: &Variant) -> Option<Self> {
+                if let Variant::$variant_type(ref value) = *value {
+                    Some(value.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+macro_rules! from_argument_boxed_impl {
+    ( $t: ty, $variant_type: ident ) => {
+        impl FromArgument for $t {
+            fn from_argument(value: &Variant) -> Option<Self> {
+                if let Variant::$variant_type(ref value) = *value {
+                    Some(value.as_ref().clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+from_argument_impl!(Boolean, Boolean);
+from_argument_impl!(SByte, SByte);
+from_argument_impl!(Byte, Byte);
+from_argument_impl!(Int16, Int16);
+from_argument_impl!(UInt16, UInt16);
+from_argument_impl!(Int32, Int32);
+from_argument_impl!(UInt32, UInt32);
+from_argument_impl!(Int64, Int64);
+from_argument_impl!(UInt64, UInt64);
+from_argument_impl!(Float, Float);
+from_argument_impl!(Double, Double);
+from_argument_impl!(UAString, String);
+from_argument_impl!(DateTime, DateTime);
+from_argument_impl!(Guid, Guid);
+from_argument_impl!(StatusCode, StatusCode);
+from_argument_impl!(ByteString, ByteString);
+from_argument_boxed_impl!(QualifiedName, QualifiedName);
+from_argument_boxed_impl!(LocalizedText, LocalizedText);
+from_argument_boxed_impl!(NodeId, NodeId);
+from_argument_boxed_impl!(ExpandedNodeId, ExpandedNodeId);
+from_argument_boxed_impl!(ExtensionObject, ExtensionObject);
+
+impl FromArgument for String {
+    fn from_argument(value: &Variant) -> Option<Self> {
+        if let Variant::String(ref value) = *value {
+            value.value.clone()
+        } else {
+            None
+        }
+    }
+}
+
+/// Any argument is accepted as is
+impl FromArgument for Variant {
+    fn from_argument(value: &Variant) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+/// A single dimension array argument whose elements are all convertible
+impl<T> FromArgument for Vec<T> where T: FromArgument {
+    fn from_argument(value: &Variant) -> Option<Self> {
+        if let Variant::Array(ref values) = *value {
+            values.iter().map(T::from_argument).collect()
+        } else {
+            None
+        }
+    }
+}
+
+/// The input arguments of a method as a tuple. The number of arguments must match the size of
+/// the tuple.
+pub trait MethodInputs: Sized {
+    fn from_arguments(arguments: &[Variant]) -> Result<Self, StatusCode>;
+}
+
+/// The output arguments of a method as a tuple
+pub trait MethodOutputs {
+    fn into_arguments(self) -> Vec<Variant>;
+}
+
+impl MethodInputs for () {
+    fn from_arguments(arguments: &[Variant]) -> Result<Self, StatusCode> {
+        if arguments.is_empty() { Ok(()) } else { Err(BadTooManyArguments) }
+    }
+}
+
+impl MethodOutputs for () {
+    fn into_arguments(self) -> Vec<Variant> {
+        Vec::new()
+    }
+}
+
+macro_rules! method_arguments_impl {
+    ( $count: expr, $( $t: ident : $idx: tt ),+ ) => {
+        impl<$( $t ),+> MethodInputs for ($( $t, )+) where $( $t: FromArgument ),+ {
+            fn from_arguments(arguments: &[Variant]) -> Result<Self, StatusCode> {
+                if arguments.len() < $count {
+                    Err(BadArgumentsMissing)
+                } else if arguments.len() > $count {
+                    Err(BadTooManyArguments)
+                } else {
+                    Ok(($( $t::from_argument(&arguments[$idx]).ok_or(BadInvalidArgument)?, )+))
+                }
+            }
+        }
+
+        impl<$( $t ),+> MethodOutputs for ($( $t, )+) where $( $t: Into<Variant> ),+ {
+            fn into_arguments(self) -> Vec<Variant> {
+                vec![$( self.$idx.into() ),+]
+            }
+        }
+    }
+}
+
+method_arguments_impl!(1, A: 0);
+method_arguments_impl!(2, A: 0, B: 1);
+method_arguments_impl!(3, A: 0, B: 1, C: 2);
+method_arguments_impl!(4, A: 0, B: 1, C: 2, D: 3);
+method_arguments_impl!(5, A: 0, B: 1, C: 2, D: 3, E: 4);
+method_arguments_impl!(6, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+method_arguments_impl!(7, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+method_arguments_impl!(8, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
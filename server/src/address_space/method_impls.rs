@@ -1,7 +1,6 @@
 use opcua_types::*;
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
-use opcua_types::service_types::{CallMethodRequest, CallMethodResult};
 
 use address_space::address_space::AddressSpace;
 use state::ServerState;
@@ -9,41 +8,16 @@ use session::Session;
 
 /// This is the handler for the GetMonitoredItems method call. It's called via a CallRequest on
 /// the Method service.
-pub fn handle_get_monitored_items(_: &AddressSpace, _: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+///
+/// Input is the subscriptionId and output is the serverHandles and clientHandles of the monitored
+/// items in the subscription.
+pub fn handle_get_monitored_items(_: &AddressSpace, _: &ServerState, session: &Session, (subscription_id, ): (UInt32, )) -> Result<(Vec<UInt32>, Vec<UInt32>), StatusCode> {
     debug!("Method handler for GetMonitoredItems");
-    // Expect arguments:
-    //   subscriptionId: UInt32
-    if let Some(ref input_arguments) = request.input_arguments {
-        match input_arguments.len() {
-            1 => {
-                let arg1 = input_arguments.get(0).unwrap();
-                if let Variant::UInt32(subscription_id) = arg1 {
-                    if let Some(subscription) = session.subscriptions.subscriptions().get(&subscription_id) {
-                        // Response
-                        //   serverHandles: Vec<UInt32>
-                        //   clientHandles: Vec<UInt32>
-                        let (server_handles, client_handles) = subscription.get_handles();
-                        Ok(CallMethodResult {
-                            status_code: Good,
-                            input_argument_results: Some(vec![Good]),
-                            input_argument_diagnostic_infos: None,
-                            output_arguments: Some(vec![server_handles.into(), client_handles.into()]),
-                        })
-                    } else {
-                        // Subscription id does not exist
-                        // Note we could check other sessions for a matching id and return BadUserAccessDenied in that case
-                        Err(BadSubscriptionIdInvalid)
-                    }
-                } else {
-                    // Argument is not the right type
-                    Err(BadInvalidArgument)
-                }
-            }
-            0 => Err(BadArgumentsMissing),
-            _ => Err(BadTooManyArguments),
-        }
+    if let Some(subscription) = session.subscriptions.subscriptions().get(&subscription_id) {
+        Ok(subscription.get_handles())
     } else {
-        // Args are missing
-        Err(BadArgumentsMissing)
+        // Subscription id does not exist
+        // Note we could check other sessions for a matching id and return BadUserAccessDenied in that case
+        Err(BadSubscriptionIdInvalid)
     }
 }
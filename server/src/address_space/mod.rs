@@ -31,7 +31,8 @@ impl<F> AttrFnGetter<F> where F: FnMut(NodeId, AttributeId) -> Result<Option<Dat
 
 // An attribute setter. Sets the value on the specified attribute
 pub trait AttributeSetter {
-    /// Sets the attribute on the specified node
+    /// Sets the attribute on the specified node. Values written by clients have been checked
+    /// against the variable's `ValueConstraints` and converted to its data type before this is called.
     fn set(&mut self, node_id: NodeId, attribute_id: AttributeId, data_value: DataValue) -> Result<(), StatusCode>;
 }
 
@@ -71,6 +72,8 @@ macro_rules! node_impl {
             fn set_user_write_mask(&mut self, write_mask: UInt32) { self.base.set_user_write_mask(write_mask) }
             fn find_attribute(&self, attribute_id: AttributeId) -> Option<DataValue> { self.base.find_attribute(attribute_id) }
             fn set_attribute(&mut self, attribute_id: AttributeId, value: DataValue) -> Result<(), StatusCode> { self.base.set_attribute(attribute_id, value) }
+            fn has_attribute_getter(&self, attribute_id: AttributeId) -> bool { self.base.has_attribute_getter(attribute_id) }
+            fn set_data_change_index(&mut self, data_change_index: ::std::sync::Arc<::address_space::data_change::DataChangeIndex>) { self.base.set_data_change_index(data_change_index) }
         }
 
         impl Into<NodeType> for $node_struct {
@@ -124,12 +127,22 @@ pub mod base;
 pub mod object;
 pub mod variable;
 pub mod method;
+pub mod method_arguments;
 pub mod node;
 pub mod reference_type;
 pub mod object_type;
 pub mod variable_type;
 pub mod data_type;
+pub mod data_change;
 pub mod view;
+pub mod instantiate;
+pub mod node_store;
+pub mod sampler;
+pub mod nodeset;
+pub mod server_configuration;
+pub mod type_dictionary;
+pub mod value_constraints;
+pub mod value_source;
 
 mod method_impls;
 
@@ -157,13 +170,18 @@ pub mod user_access_level {
 pub mod types {
     pub use super::{AttrFnGetter, AttrFnSetter};
     pub use super::address_space::{AddressSpace, ReferenceDirection};
-    pub use super::data_type::DataType;
+    pub use super::data_type::{DataType, DataTypeDefinition, DataTypeField};
     pub use super::object::Object;
     pub use super::variable::Variable;
     pub use super::method::Method;
+    pub use super::method_arguments::{FromArgument, MethodInputs, MethodOutputs};
     pub use super::reference_type::ReferenceType;
     pub use super::object_type::ObjectType;
     pub use super::variable_type::VariableType;
     pub use super::view::View;
     pub use super::node::{Node, NodeType};
+    pub use super::node_store::References;
+    pub use super::nodeset::NodeSetError;
+    pub use super::value_constraints::ValueConstraints;
+    pub use super::value_source::{AsyncValueSource, AsyncFnValueSource, ValueFuture};
 }
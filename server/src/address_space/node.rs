@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use opcua_types::{UInt32, NodeId, QualifiedName, LocalizedText, AttributeId, DataValue};
 use opcua_types::service_types::NodeClass;
 use opcua_types::status_codes::StatusCode;
 
+use address_space::data_change::DataChangeIndex;
 use address_space::types::{Object, ObjectType, ReferenceType, Variable, VariableType, View, DataType, Method};
 
 #[derive(Debug)]
@@ -68,4 +71,10 @@ pub trait Node {
     fn set_user_write_mask(&mut self, write_mask: UInt32);
     fn find_attribute(&self, attribute_id: AttributeId) -> Option<DataValue>;
     fn set_attribute(&mut self, attribute_id: AttributeId, value: DataValue) -> Result<(), StatusCode>;
+    /// Tests if the value of the attribute comes from a getter, in which case changes to it are
+    /// found by polling
+    fn has_attribute_getter(&self, attribute_id: AttributeId) -> bool;
+    /// Sets the index of monitored items that are told when the value of an attribute is set.
+    /// The address space sets it when the node is inserted.
+    fn set_data_change_index(&mut self, data_change_index: Arc<DataChangeIndex>);
 }
@@ -0,0 +1,421 @@
+//! The storage of nodes and the references between them.
+//!
+//! Every node id is interned once into a table and given a `NodeIndex`. The table is the only
+//! copy of the id, it is found from a hash of the id rather than from a map keyed by a second
+//! copy of it. Nodes live in an arena at their index, next to their forward and inverse
+//! references. References hold the index of the other node and are kept sorted by reference
+//! type, so the references of one type are found with a search and are iterated in place instead
+//! of being cloned into a new `Vec`. The target of a reference does not need to be a node, its id
+//! is interned all the same.
+//!
+//! A reference whose type is not a standard reference type is stored as its standard supertype,
+//! and its own type is kept to one side so that it can be reported.
+
+use std::cmp::{self, Ordering};
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+use std::slice;
+
+use opcua_types::NodeId;
+use opcua_types::node_ids::ReferenceTypeId;
+
+use address_space::node::{HasNodeId, NodeType};
+
+/// The index of an interned node id in the store
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeIndex(u32);
+
+/// A reference to or from a node, held by the node
+type StoredReference = (ReferenceTypeId, NodeIndex);
+
+/// Hashes the hash of a node id, which is a hash already, to itself
+#[derive(Default)]
+struct HashHasher(u64);
+
+impl Hasher for HashHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, _: &[u8]) {
+        unreachable!("Only the hash of a node id is hashed");
+    }
+
+    fn write_u64(&mut self, hash: u64) {
+        self.0 = hash;
+    }
+}
+
+/// A map keyed by the hash of a node id
+type HashMapByHash<V> = HashMap<u64, V, BuildHasherDefault<HashHasher>>;
+
+/// The arena entry of an interned node id, which has a node unless the id is only the target of
+/// references
+struct NodeEntry {
+    node: Option<NodeType>,
+    forward: Vec<StoredReference>,
+    inverse: Vec<StoredReference>,
+}
+
+/// Holds the nodes and references of an address space
+pub struct NodeStore {
+    /// The interned node ids, by index
+    node_ids: Vec<NodeId>,
+    /// The index of the first interned node id with each hash
+    indexes: HashMapByHash<NodeIndex>,
+    /// The indexes of any other node ids with the same hash, which are rare
+    colliding_indexes: HashMapByHash<Vec<NodeIndex>>,
+    /// Hashes the node ids
+    hash_state: RandomState,
+    /// The nodes and their references, by index
+    entries: Vec<NodeEntry>,
+    node_count: usize,
+    reference_count: usize,
+    /// The reference types of references whose type is a subtype of a standard one, by the nodes
+    /// they join and the standard type they are stored as
+    reference_subtypes: HashMap<(NodeIndex, NodeIndex, u32), NodeIndex>,
+}
+
+impl NodeStore {
+    pub fn new() -> NodeStore {
+        NodeStore {
+            node_ids: Vec::new(),
+            indexes: HashMapByHash::default(),
+            colliding_indexes: HashMapByHash::default(),
+            hash_state: RandomState::new(),
+            entries: Vec::new(),
+            node_count: 0,
+            reference_count: 0,
+            reference_subtypes: HashMap::new(),
+        }
+    }
+
+    /// Reserves space for a number of additional node ids
+    pub fn reserve(&mut self, additional: usize) {
+        self.node_ids.reserve(additional);
+        self.indexes.reserve(additional);
+        self.entries.reserve(additional);
+    }
+
+    /// Returns the number of nodes
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Returns the number of references between nodes, counting each once
+    pub fn reference_count(&self) -> usize {
+        self.reference_count
+    }
+
+    /// Returns the index of the node id, interning it if it is new
+    pub fn intern(&mut self, node_id: &NodeId) -> NodeIndex {
+        let hash = self.hash(node_id);
+        if let Some(index) = self.find_index(hash, node_id) {
+            return index;
+        }
+        let index = NodeIndex(self.node_ids.len() as u32);
+        self.node_ids.push(node_id.clone());
+        if self.indexes.contains_key(&hash) {
+            self.colliding_indexes.entry(hash).or_insert_with(Vec::new).push(index);
+        } else {
+            self.indexes.insert(hash, index);
+        }
+        self.entries.push(NodeEntry {
+            node: None,
+            forward: Vec::new(),
+            inverse: Vec::new(),
+        });
+        index
+    }
+
+    /// Returns the index of the node id if it has been interned
+    pub fn index_of(&self, node_id: &NodeId) -> Option<NodeIndex> {
+        self.find_index(self.hash(node_id), node_id)
+    }
+
+    fn find_index(&self, hash: u64, node_id: &NodeId) -> Option<NodeIndex> {
+        match self.indexes.get(&hash) {
+            Some(&index) if self.node_ids[index.0 as usize] == *node_id => Some(index),
+            Some(_) => self.colliding_indexes.get(&hash)
+                .and_then(|indexes| indexes.iter().find(|index| self.node_ids[index.0 as usize] == *node_id).cloned()),
+            None => None
+        }
+    }
+
+    fn hash(&self, node_id: &NodeId) -> u64 {
+        let mut hasher = self.hash_state.build_hasher();
+        node_id.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the node id of an index
+    pub fn node_id(&self, index: NodeIndex) -> &NodeId {
+        &self.node_ids[index.0 as usize]
+    }
+
+    /// Inserts a node. Returns false and leaves the store unchanged if a node of the same id
+    /// exists.
+    pub fn insert(&mut self, node: NodeType) -> bool {
+        let index = self.intern(&node.node_id());
+        let entry = &mut self.entries[index.0 as usize];
+        if entry.node.is_some() {
+            false
+        } else {
+            entry.node = Some(node);
+            self.node_count += 1;
+            true
+        }
+    }
+
+    pub fn contains(&self, node_id: &NodeId) -> bool {
+        self.get(node_id).is_some()
+    }
+
+    pub fn get(&self, node_id: &NodeId) -> Option<&NodeType> {
+        self.index_of(node_id).and_then(|index| self.get_at(index))
+    }
+
+    pub fn get_mut(&mut self, node_id: &NodeId) -> Option<&mut NodeType> {
+        if let Some(index) = self.index_of(node_id) {
+            self.entries[index.0 as usize].node.as_mut()
+        } else {
+            None
+        }
+    }
+
+    pub fn get_at(&self, index: NodeIndex) -> Option<&NodeType> {
+        self.entries[index.0 as usize].node.as_ref()
+    }
+
+    /// Iterates over every node
+    pub fn nodes(&self) -> Nodes {
+        Nodes {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Adds a reference from one node to another
+    pub fn add_reference(&mut self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId) {
+        let from = self.intern(node_id_from);
+        let to = self.intern(node_id_to);
+        insert_sorted(&mut self.entries[from.0 as usize].forward, (reference_type_id, to));
+        insert_sorted(&mut self.entries[to.0 as usize].inverse, (reference_type_id, from));
+        self.reference_count += 1;
+    }
+
+    /// Adds a reference from one node to another whose type is a subtype of a standard reference
+    /// type. It is stored as the standard type, which filters and lookups match it by.
+    pub fn add_subtype_reference(&mut self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId, reference_type: &NodeId) {
+        self.add_reference(node_id_from, node_id_to, reference_type_id);
+        let from = self.intern(node_id_from);
+        let to = self.intern(node_id_to);
+        let reference_type = self.intern(reference_type);
+        self.reference_subtypes.insert((from, to, reference_type_id as u32), reference_type);
+    }
+
+    /// Returns the type of the reference from one node to another that is stored as the standard
+    /// reference type, which is the standard type unless the reference has a subtype of it
+    pub fn reference_type(&self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId) -> NodeId {
+        if !self.reference_subtypes.is_empty() {
+            if let (Some(from), Some(to)) = (self.index_of(node_id_from), self.index_of(node_id_to)) {
+                if let Some(reference_type) = self.reference_subtypes.get(&(from, to, reference_type_id as u32)) {
+                    return self.node_id(*reference_type).clone();
+                }
+            }
+        }
+        reference_type_id.into()
+    }
+
+    /// Iterates over the forward references from the node, optionally filtered by a reference
+    /// type and whether its subtypes are included
+    pub fn references_from(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> References {
+        self.references(node_id, reference_filter, |entry| &entry.forward)
+    }
+
+    /// Iterates over the inverse references of the node, i.e. the references to it
+    pub fn references_to(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> References {
+        self.references(node_id, reference_filter, |entry| &entry.inverse)
+    }
+
+    fn references<F>(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>, f: F) -> References where F: Fn(&NodeEntry) -> &Vec<StoredReference> {
+        let references: &[StoredReference] = if let Some(index) = self.index_of(node_id) {
+            let references = f(&self.entries[index.0 as usize]);
+            match reference_filter {
+                // References of a single type are a run of the sorted references. The iterator
+                // stops at the end of the run so it is not searched for here.
+                Some((reference_type_id, false)) => &references[type_start(references, reference_type_id)..],
+                _ => references
+            }
+        } else {
+            &[]
+        };
+        References {
+            node_ids: &self.node_ids,
+            references: references.iter(),
+            reference_filter,
+        }
+    }
+}
+
+/// An iterator over the nodes of a store
+pub struct Nodes<'a> {
+    entries: slice::Iter<'a, NodeEntry>,
+}
+
+impl<'a> Iterator for Nodes<'a> {
+    type Item = &'a NodeType;
+
+    fn next(&mut self) -> Option<&'a NodeType> {
+        while let Some(entry) = self.entries.next() {
+            if let Some(ref node) = entry.node {
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the references of a node, which yields the reference type and the node id
+/// at the other end of each reference
+pub struct References<'a> {
+    node_ids: &'a [NodeId],
+    references: slice::Iter<'a, StoredReference>,
+    reference_filter: Option<(ReferenceTypeId, bool)>,
+}
+
+impl<'a> Iterator for References<'a> {
+    type Item = (ReferenceTypeId, &'a NodeId);
+
+    fn next(&mut self) -> Option<(ReferenceTypeId, &'a NodeId)> {
+        while let Some(&(reference_type_id, index)) = self.references.next() {
+            if let Some((filter_type_id, false)) = self.reference_filter {
+                if reference_type_id != filter_type_id {
+                    self.references = [].iter();
+                    return None;
+                }
+            }
+            let matches = if let Some((filter_type_id, include_subtypes)) = self.reference_filter {
+                reference_type_matches(filter_type_id, reference_type_id, include_subtypes)
+            } else {
+                true
+            };
+            if matches {
+                return Some((reference_type_id, &self.node_ids[index.0 as usize]));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Without a filter every reference is yielded, so a collection can be sized exactly
+        let remaining = self.references.len();
+        if self.reference_filter.is_none() {
+            (remaining, Some(remaining))
+        } else {
+            (0, Some(remaining))
+        }
+    }
+}
+
+/// Inserts a reference after the others of the same type, keeping references in the order they
+/// were added within a type
+fn insert_sorted(references: &mut Vec<StoredReference>, reference: StoredReference) {
+    let (_, end) = type_range(references, reference.0);
+    references.insert(end, reference);
+}
+
+/// The key that references are sorted by. The hierarchical types that a node may have thousands
+/// of references of sort after the other types, which a node has few of, so that a lookup of a
+/// type definition or modelling rule finds it at the front.
+fn type_order(reference_type_id: ReferenceTypeId) -> u32 {
+    let many = match reference_type_id {
+        ReferenceTypeId::Organizes | ReferenceTypeId::HasComponent | ReferenceTypeId::HasProperty |
+        ReferenceTypeId::HasOrderedComponent | ReferenceTypeId::HasNotifier | ReferenceTypeId::HasEventSource => 1 << 31,
+        _ => 0
+    };
+    many | reference_type_id as u32
+}
+
+/// Finds the start of the run of references of the type, or where it would be
+fn type_start(references: &[StoredReference], reference_type_id: ReferenceTypeId) -> usize {
+    // Gallop from the front in growing steps to the step holding the start of the run, so a
+    // run near the front is found without touching the rest of the references
+    let key = type_order(reference_type_id);
+    let mut start = 0;
+    let mut step = 1;
+    while start + step <= references.len() && type_order(references[start + step - 1].0) < key {
+        start += step;
+        step *= 2;
+    }
+    let end = cmp::min(start + step, references.len());
+    // The comparisons never return Equal, so the search ends at the boundary of the run
+    match references[start..end].binary_search_by(|r| if type_order(r.0) < key { Ordering::Less } else { Ordering::Greater }) {
+        Ok(idx) | Err(idx) => start + idx
+    }
+}
+
+/// Finds the start and end of the run of references of the type
+fn type_range(references: &[StoredReference], reference_type_id: ReferenceTypeId) -> (usize, usize) {
+    let start = type_start(references, reference_type_id);
+    let end = match references[start..].binary_search_by(|r| if r.0 == reference_type_id { Ordering::Less } else { Ordering::Greater }) {
+        Ok(idx) | Err(idx) => start + idx
+    };
+    (start, end)
+}
+
+/// Tests if a reference type matches the type of a filter, or is a subtype of it when subtypes
+/// are included
+pub fn reference_type_matches(r1: ReferenceTypeId, r2: ReferenceTypeId, include_subtypes: bool) -> bool {
+    if r1 == r2 {
+        true
+    } else if include_subtypes {
+        // THIS IS AN UGLY HACK. The subtype code should really walk down the hierarchy of
+        // types in the address space to figure this out
+        match r1 {
+            ReferenceTypeId::HierarchicalReferences => {
+                match r2 {
+                    ReferenceTypeId::HierarchicalReferences | ReferenceTypeId::HasChild |
+                    ReferenceTypeId::HasSubtype | ReferenceTypeId::Organizes |
+                    ReferenceTypeId::Aggregates | ReferenceTypeId::HasProperty |
+                    ReferenceTypeId::HasComponent | ReferenceTypeId::HasOrderedComponent |
+                    ReferenceTypeId::HasEventSource | ReferenceTypeId::HasNotifier => {
+                        true
+                    }
+                    _ => false
+                }
+            }
+            ReferenceTypeId::HasChild => {
+                match r2 {
+                    ReferenceTypeId::Aggregates | ReferenceTypeId::HasComponent |
+                    ReferenceTypeId::HasHistoricalConfiguration | ReferenceTypeId::HasProperty |
+                    ReferenceTypeId::HasOrderedComponent | ReferenceTypeId::HasSubtype => {
+                        true
+                    }
+                    _ => false
+                }
+            }
+            ReferenceTypeId::Aggregates => {
+                match r2 {
+                    ReferenceTypeId::HasComponent | ReferenceTypeId::HasHistoricalConfiguration |
+                    ReferenceTypeId::HasProperty | ReferenceTypeId::HasOrderedComponent => {
+                        true
+                    }
+                    _ => false
+                }
+            }
+            ReferenceTypeId::HasComponent => {
+                r2 == ReferenceTypeId::HasOrderedComponent
+            }
+            ReferenceTypeId::HasEventSource => {
+                r2 == ReferenceTypeId::HasNotifier
+            }
+            _ => {
+                // TODO somehow work out subtypes, e.g. working back along inverse references
+                false
+            }
+        }
+    } else {
+        false
+    }
+}
@@ -0,0 +1,730 @@
+//! Imports nodes into and exports nodes from the address space as XML documents conforming to
+//! `UANodeSet.xsd`, i.e. the NodeSet2 files that companion specifications and modelling tools
+//! publish information models in.
+//!
+//! Namespace indices in the document refer to its `<NamespaceUris>` and are remapped onto the
+//! namespace table of the address space, registering any uri it does not already hold.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ReferenceTypeId};
+
+use address_space::address_space::AddressSpace;
+use address_space::data_type::{DataTypeDefinition, DataTypeField};
+use address_space::node::{Node, NodeType, HasNodeId};
+use address_space::types::{DataType, Method, Object, ObjectType, ReferenceType, Variable, VariableType, View};
+
+/// Describes why a nodeset could not be imported or exported
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeSetError {
+    /// The node id, as written in the document, of the node the error was found on
+    pub node_id: Option<String>,
+    /// What was wrong
+    pub message: String,
+}
+
+impl fmt::Display for NodeSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref node_id) = self.node_id {
+            write!(f, "Node {}: {}", node_id, self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl NodeSetError {
+    fn new(message: String) -> NodeSetError {
+        NodeSetError { node_id: None, message }
+    }
+}
+
+/// A node read from the document together with its references
+struct ImportedNode {
+    node: NodeType,
+    /// The node id as written in the document, for reporting errors
+    document_node_id: String,
+    /// Reference type, target node and true for a forward reference
+    references: Vec<(NodeId, NodeId, bool)>,
+}
+
+impl AddressSpace {
+    /// Imports the nodes of a `UANodeSet` XML document. The document is read and checked in its
+    /// entirety before anything is added so an import that fails leaves the address space as it was.
+    ///
+    /// References whose type is defined by the nodeset are recorded as the standard reference type
+    /// the type is a subtype of.
+    pub fn import_nodeset<R>(&mut self, reader: R) -> Result<(), NodeSetError> where R: Read {
+        let document = XmlNode::parse(reader).map_err(|err| {
+            NodeSetError::new(format!("Document is not well formed XML, {}", err))
+        })?;
+        if document.name != "UANodeSet" {
+            return Err(NodeSetError::new(format!("Document element is {}, expected UANodeSet", document.name)));
+        }
+
+        let importer = NodeSetImporter::new(self, &document)?;
+        let nodes = document.children.iter()
+            .filter(|e| e.name.starts_with("UA"))
+            .map(|e| importer.import_node(e).map_err(|message| NodeSetError {
+                node_id: e.attributes.get("NodeId").cloned(),
+                message,
+            }))
+            .collect::<Result<Vec<ImportedNode>, NodeSetError>>()?;
+        if let Some(e) = document.children.iter().find(|e| !e.name.starts_with("UA") && !NON_NODE_ELEMENTS.contains(&e.name.as_str())) {
+            return Err(NodeSetError::new(format!("Element {} is not part of a nodeset", e.name)));
+        }
+
+        // Check the nodes can be added and resolve their references before changing anything
+        let mut node_ids = HashSet::with_capacity(nodes.len());
+        for n in &nodes {
+            let node_id = n.node.node_id();
+            if self.node_exists(&node_id) || !node_ids.insert(node_id.clone()) {
+                return Err(NodeSetError {
+                    node_id: Some(n.document_node_id.clone()),
+                    message: "Node already exists".to_string(),
+                });
+            }
+        }
+        let references = importer.resolve_references(self, &nodes)?;
+
+        importer.new_namespaces.iter().for_each(|ns| { self.register_namespace(ns); });
+        nodes.into_iter().for_each(|n| self.insert(n.node, None));
+        references.iter().for_each(|&(ref from, ref to, reference_type_id, ref reference_type)| {
+            if reference_type.as_reference_type_id().is_ok() {
+                self.insert_reference(from, to, reference_type_id);
+            } else {
+                self.insert_subtype_reference(from, to, reference_type_id, reference_type);
+            }
+        });
+        Ok(())
+    }
+
+    /// Exports the nodes of the namespaces, their attributes, references and current values as a
+    /// `UANodeSet` XML document that `import_nodeset()` can read back. Nodes and references are
+    /// written in a stable order so documents from different runs can be compared.
+    ///
+    /// The whole namespace table is written to `<NamespaceUris>` so namespace indices in the
+    /// document are those of the address space.
+    pub fn export_nodeset<W>(&self, namespaces: &[UInt16], mut writer: W) -> Result<(), NodeSetError> where W: Write {
+        let mut nodes = namespaces.iter().flat_map(|ns| self.find_nodes_in_namespace(*ns)).collect::<Vec<&NodeType>>();
+        nodes.sort_by_key(|n| node_id_sort_key(&n.node_id()));
+        let exported = nodes.iter().map(|n| n.node_id()).collect::<HashSet<NodeId>>();
+
+        let mut exporter = NodeSetExporter {
+            address_space: self,
+            aliases: BTreeMap::new(),
+        };
+        let elements = nodes.iter().map(|n| exporter.export_node(n, &exported)).collect::<Vec<XmlNode>>();
+
+        let mut document = XmlNode::new("UANodeSet");
+        document.attributes.insert("xmlns".to_string(), UANODESET_NAMESPACE.to_string());
+        document.attributes.insert("xmlns:uax".to_string(), TYPES_NAMESPACE.to_string());
+        if self.namespaces().len() > 1 {
+            let mut uris = XmlNode::new("NamespaceUris");
+            self.namespaces()[1..].iter().for_each(|ns| uris.children.push(text_element("Uri", ns)));
+            document.children.push(uris);
+        }
+        if !exporter.aliases.is_empty() {
+            let mut aliases = XmlNode::new("Aliases");
+            exporter.aliases.iter().for_each(|(alias, node_id)| {
+                let mut e = text_element("Alias", node_id);
+                e.attributes.insert("Alias".to_string(), alias.clone());
+                aliases.children.push(e);
+            });
+            document.children.push(aliases);
+        }
+        document.children.extend(elements);
+
+        writer.write_all(b"<?xml version=\"1.0\" encoding=\"utf-8\"?>\n")
+            .and_then(|_| write_element(&mut writer, &document, 0))
+            .map_err(|err| NodeSetError::new(format!("Cannot write the document, {}", err)))
+    }
+}
+
+/// The namespace of the elements in `UANodeSet.xsd`
+pub const UANODESET_NAMESPACE: &'static str = "http://opcfoundation.org/UA/2011/03/UANodeSet.xsd";
+
+/// Top level elements that are not nodes and are either handled up front or of no interest
+const NON_NODE_ELEMENTS: [&'static str; 5] = ["NamespaceUris", "ServerUris", "Models", "Aliases", "Extensions"];
+
+struct NodeSetImporter {
+    /// Server namespace index of each namespace index in the document
+    namespace_map: Vec<UInt16>,
+    /// Namespace uris of the document that the address space does not have yet
+    new_namespaces: Vec<String>,
+    aliases: HashMap<String, String>,
+}
+
+impl NodeSetImporter {
+    fn new(address_space: &AddressSpace, document: &XmlNode) -> Result<NodeSetImporter, NodeSetError> {
+        let mut importer = NodeSetImporter {
+            namespace_map: vec![0],
+            new_namespaces: Vec::new(),
+            aliases: HashMap::new(),
+        };
+        if let Some(uris) = document.get_child("NamespaceUris") {
+            for uri in uris.children.iter().map(|e| read_xml_text(e)) {
+                let idx = if let Some(idx) = address_space.namespaces().iter().position(|ns| ns == uri) {
+                    idx
+                } else if let Some(idx) = importer.new_namespaces.iter().position(|ns| ns == uri) {
+                    address_space.namespaces().len() + idx
+                } else {
+                    importer.new_namespaces.push(uri.to_string());
+                    address_space.namespaces().len() + importer.new_namespaces.len() - 1
+                };
+                if idx > UInt16::max_value() as usize {
+                    return Err(NodeSetError::new(format!("Namespace {} does not fit in the namespace table", uri)));
+                }
+                importer.namespace_map.push(idx as UInt16);
+            }
+        }
+        if let Some(aliases) = document.get_child("Aliases") {
+            for alias in &aliases.children {
+                if let Some(name) = alias.attributes.get("Alias") {
+                    importer.aliases.insert(name.clone(), read_xml_text(alias).to_string());
+                } else {
+                    return Err(NodeSetError::new("Alias is missing its Alias attribute".to_string()));
+                }
+            }
+        }
+        Ok(importer)
+    }
+
+    fn import_node(&self, e: &XmlNode) -> Result<ImportedNode, String> {
+        let document_node_id = self.attribute(e, "NodeId")?;
+        let node_id = self.node_id(document_node_id)?;
+        let browse_name = self.qualified_name(self.attribute(e, "BrowseName")?)?;
+        let display_name = self.localized_text_field(e, "DisplayName")
+            .unwrap_or_else(|| LocalizedText::new("", browse_name.name.as_ref()));
+        let name = browse_name.name.as_ref().to_string();
+        let is_abstract = self.parse_attribute(e, "IsAbstract", false)?;
+
+        let mut node: NodeType = match e.name.as_ref() {
+            "UAObject" => {
+                let mut node = Object::new(&node_id, &name, &name, "");
+                set_attribute(&mut node, AttributeId::EventNotifier, Variant::Byte(self.parse_attribute(e, "EventNotifier", 0)?))?;
+                node.into()
+            }
+            "UAVariable" => {
+                let value = self.value(e)?;
+                let mut node = Variable::new_data_value(&node_id, &name, &name, "", DataTypeId::BaseDataType, DataValue::new(value));
+                self.set_variable_attributes(&mut node, e)?;
+                set_attribute(&mut node, AttributeId::AccessLevel, Variant::Byte(self.parse_attribute(e, "AccessLevel", 1)?))?;
+                set_attribute(&mut node, AttributeId::UserAccessLevel, Variant::Byte(self.parse_attribute(e, "UserAccessLevel", 1)?))?;
+                set_attribute(&mut node, AttributeId::Historizing, Variant::Boolean(self.parse_attribute(e, "Historizing", false)?))?;
+                if e.attributes.contains_key("MinimumSamplingInterval") {
+                    set_attribute(&mut node, AttributeId::MinimumSamplingInterval, Variant::Double(self.parse_attribute(e, "MinimumSamplingInterval", 0f64)?))?;
+                }
+                node.into()
+            }
+            "UAMethod" => {
+                let executable = self.parse_attribute(e, "Executable", true)?;
+                let user_executable = self.parse_attribute(e, "UserExecutable", true)?;
+                Method::new(&node_id, &name, &name, "", false, executable, user_executable).into()
+            }
+            "UAObjectType" => {
+                ObjectType::new(&node_id, &name, &name, "", is_abstract).into()
+            }
+            "UAVariableType" => {
+                let value = self.value(e)?;
+                let mut node = VariableType::new(&node_id, &name, &name, "", is_abstract, -1);
+                if value != Variant::Empty {
+                    set_attribute(&mut node, AttributeId::Value, value)?;
+                }
+                self.set_variable_attributes(&mut node, e)?;
+                node.into()
+            }
+            "UADataType" => {
+                let mut node = DataType::new(&node_id, &name, &name, "", is_abstract);
+                if let Some(definition) = e.get_child("Definition") {
+                    node.set_definition(self.definition(definition)?);
+                }
+                node.into()
+            }
+            "UAReferenceType" => {
+                let inverse_name = self.localized_text_field(e, "InverseName");
+                let symmetric = self.parse_attribute(e, "Symmetric", false)?;
+                ReferenceType::new(&node_id, &name, &name, "", inverse_name, symmetric, is_abstract).into()
+            }
+            "UAView" => {
+                let event_notifier: Byte = self.parse_attribute(e, "EventNotifier", 0)?;
+                let contains_no_loops = self.parse_attribute(e, "ContainsNoLoops", false)?;
+                View::new(&node_id, &name, &name, "", event_notifier != 0, contains_no_loops).into()
+            }
+            name => {
+                return Err(format!("Element {} is not a node class", name));
+            }
+        };
+
+        {
+            let node = node.as_mut_node();
+            set_attribute(node, AttributeId::BrowseName, Variant::new(browse_name))?;
+            set_attribute(node, AttributeId::DisplayName, Variant::new(display_name))?;
+            if let Some(description) = self.localized_text_field(e, "Description") {
+                set_attribute(node, AttributeId::Description, Variant::new(description))?;
+            }
+            set_attribute(node, AttributeId::WriteMask, Variant::UInt32(self.parse_attribute(e, "WriteMask", 0)?))?;
+            set_attribute(node, AttributeId::UserWriteMask, Variant::UInt32(self.parse_attribute(e, "UserWriteMask", 0)?))?;
+        }
+
+        let mut references = Vec::new();
+        if let Some(e) = e.get_child("References") {
+            for r in &e.children {
+                let reference_type = self.node_id(self.attribute(r, "ReferenceType")?)?;
+                let target = self.node_id(read_xml_text(r))?;
+                if target == node_id {
+                    return Err("Node has a reference to itself".to_string());
+                }
+                let is_forward = self.parse_attribute(r, "IsForward", true)?;
+                references.push((reference_type, target, is_forward));
+            }
+        }
+
+        Ok(ImportedNode { node, document_node_id: document_node_id.to_string(), references })
+    }
+
+    /// Sets the attributes variables and variable types have in common
+    fn set_variable_attributes(&self, node: &mut Node, e: &XmlNode) -> Result<(), String> {
+        let data_type = if let Some(data_type) = e.attributes.get("DataType") {
+            self.node_id(data_type)?
+        } else {
+            DataTypeId::BaseDataType.into()
+        };
+        set_attribute(node, AttributeId::DataType, Variant::new(data_type))?;
+        set_attribute(node, AttributeId::ValueRank, Variant::Int32(self.parse_attribute(e, "ValueRank", -1)?))?;
+        if let Some(dimensions) = e.attributes.get("ArrayDimensions") {
+            let dimensions = dimensions.split(',')
+                .map(|d| d.trim().parse::<UInt32>().map_err(|_| format!("ArrayDimensions {} is not a list of dimensions", dimensions)))
+                .collect::<Result<Vec<UInt32>, String>>()?;
+            set_attribute(node, AttributeId::ArrayDimensions, Variant::from_u32_array(&dimensions))?;
+        }
+        Ok(())
+    }
+
+    fn definition(&self, e: &XmlNode) -> Result<DataTypeDefinition, String> {
+        let name = self.qualified_name(self.attribute(e, "Name")?)?;
+        let is_union = self.parse_attribute(e, "IsUnion", false)?;
+        let fields = e.children.iter().filter(|f| f.name == "Field").map(|f| {
+            let data_type = if let Some(data_type) = f.attributes.get("DataType") {
+                self.node_id(data_type)?
+            } else {
+                DataTypeId::BaseDataType.into()
+            };
+            let value = if f.attributes.contains_key("Value") {
+                Some(self.parse_attribute(f, "Value", 0)?)
+            } else {
+                None
+            };
+            Ok(DataTypeField {
+                name: self.attribute(f, "Name")?.to_string(),
+                data_type,
+                value_rank: self.parse_attribute(f, "ValueRank", -1)?,
+                value,
+                description: self.localized_text_field(f, "Description"),
+            })
+        }).collect::<Result<Vec<DataTypeField>, String>>()?;
+        Ok(DataTypeDefinition { name, is_union, fields })
+    }
+
+    /// Reads the `<Value>` of a variable or variable type
+    fn value(&self, e: &XmlNode) -> Result<Variant, String> {
+        if let Some(value) = e.get_child("Value") {
+            let value = Variant::decode_nodeset_xml(value).map_err(|err| format!("Value cannot be decoded, {:?}", err))?;
+            self.remap_variant(value)
+        } else {
+            Ok(Variant::Empty)
+        }
+    }
+
+    /// Moves node ids and qualified names inside a value into the server's namespaces
+    fn remap_variant(&self, value: Variant) -> Result<Variant, String> {
+        Ok(match value {
+            Variant::NodeId(node_id) => Variant::new(self.remap_node_id(*node_id)?),
+            Variant::ExpandedNodeId(mut node_id) => {
+                if node_id.namespace_uri.is_null() {
+                    node_id.node_id = self.remap_node_id(node_id.node_id.clone())?;
+                }
+                Variant::ExpandedNodeId(node_id)
+            }
+            Variant::QualifiedName(mut name) => {
+                name.namespace_index = self.remap_namespace(name.namespace_index)?;
+                Variant::QualifiedName(name)
+            }
+            Variant::Array(values) => {
+                Variant::Array(values.into_iter().map(|v| self.remap_variant(v)).collect::<Result<Vec<Variant>, String>>()?)
+            }
+            value => value
+        })
+    }
+
+    fn remap_namespace(&self, namespace: UInt16) -> Result<UInt16, String> {
+        if let Some(namespace) = self.namespace_map.get(namespace as usize) {
+            Ok(*namespace)
+        } else {
+            Err(format!("Namespace index {} is not in the NamespaceUris of the document", namespace))
+        }
+    }
+
+    fn remap_node_id(&self, mut node_id: NodeId) -> Result<NodeId, String> {
+        node_id.namespace = self.remap_namespace(node_id.namespace)?;
+        Ok(node_id)
+    }
+
+    /// Parses a node id, which may be an alias
+    fn node_id(&self, value: &str) -> Result<NodeId, String> {
+        let value = value.trim();
+        let node_id = self.aliases.get(value).map_or(value, |v| v.as_str());
+        let node_id = NodeId::from_str(node_id).map_err(|_| format!("{} is not a node id or alias", value))?;
+        self.remap_node_id(node_id)
+    }
+
+    /// Parses a qualified name in the form `<namespace index>:<name>`, where the index is optional
+    fn qualified_name(&self, value: &str) -> Result<QualifiedName, String> {
+        if let Some(idx) = value.find(':') {
+            if let Ok(namespace) = value[..idx].parse::<UInt16>() {
+                return Ok(QualifiedName::new(self.remap_namespace(namespace)?, &value[idx + 1..]));
+            }
+        }
+        Ok(QualifiedName::new(0, value))
+    }
+
+    /// Reads an element holding a localized text, taking the first of any translations
+    fn localized_text_field(&self, e: &XmlNode, name: &str) -> Option<LocalizedText> {
+        e.get_child(name).map(|e| {
+            let locale = e.attributes.get("Locale").map_or("", |l| l.as_str());
+            LocalizedText::new(locale, read_xml_text(e))
+        })
+    }
+
+    fn attribute<'a>(&self, e: &'a XmlNode, name: &str) -> Result<&'a str, String> {
+        e.attributes.get(name).map(|v| v.as_str())
+            .ok_or_else(|| format!("{} is missing its {} attribute", e.name, name))
+    }
+
+    fn parse_attribute<T>(&self, e: &XmlNode, name: &str, default: T) -> Result<T, String> where T: FromStr {
+        if let Some(value) = e.attributes.get(name) {
+            value.trim().parse::<T>().map_err(|_| format!("{} attribute {} has an invalid value {}", e.name, name, value))
+        } else {
+            Ok(default)
+        }
+    }
+
+    /// Turns the references of the nodes into forward references between nodes, with the standard
+    /// reference type that each is stored as and its own reference type. A reference that appears
+    /// on both of the nodes it joins is only returned once.
+    fn resolve_references(&self, address_space: &AddressSpace, nodes: &[ImportedNode]) -> Result<Vec<(NodeId, NodeId, ReferenceTypeId, NodeId)>, NodeSetError> {
+        // Supertypes of reference types in the document
+        let has_subtype: NodeId = ReferenceTypeId::HasSubtype.into();
+        let mut supertypes = HashMap::new();
+        nodes.iter().for_each(|n| {
+            let node_id = n.node.node_id();
+            n.references.iter().filter(|r| r.0 == has_subtype).for_each(|&(_, ref target, is_forward)| {
+                if is_forward {
+                    supertypes.insert(target.clone(), node_id.clone());
+                } else {
+                    supertypes.insert(node_id.clone(), target.clone());
+                }
+            });
+        });
+
+        let mut result = Vec::new();
+        let mut seen = HashSet::new();
+        for n in nodes {
+            let node_id = n.node.node_id();
+            for &(ref reference_type, ref target, is_forward) in &n.references {
+                let reference_type_id = Self::reference_type_id(address_space, &supertypes, reference_type).map_err(|message| NodeSetError {
+                    node_id: Some(n.document_node_id.clone()),
+                    message,
+                })?;
+                let (from, to) = if is_forward { (&node_id, target) } else { (target, &node_id) };
+                if seen.insert((from.clone(), to.clone(), reference_type_id as UInt32)) && !address_space.has_reference(from, reference_type_id, to) {
+                    result.push((from.clone(), to.clone(), reference_type_id, reference_type.clone()));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Finds the standard reference type that is, or is a supertype of, the reference type. The
+    /// reference is stored as this type and keeps its own type as well.
+    fn reference_type_id(address_space: &AddressSpace, supertypes: &HashMap<NodeId, NodeId>, reference_type: &NodeId) -> Result<ReferenceTypeId, String> {
+        let mut node_id = reference_type.clone();
+        // The depth limit guards against a cycle of subtypes
+        for _ in 0..32 {
+            if let Ok(reference_type_id) = node_id.as_reference_type_id() {
+                return Ok(reference_type_id);
+            }
+            node_id = if let Some(supertype) = supertypes.get(&node_id) {
+                supertype.clone()
+            } else if let Some(supertype) = address_space.find_references_to(&node_id, Some((ReferenceTypeId::HasSubtype, false))).and_then(|r| r.first().cloned()) {
+                supertype.node_id
+            } else {
+                break;
+            };
+        }
+        Err(format!("Reference type {:?} is not a known reference type", reference_type))
+    }
+}
+
+fn set_attribute(node: &mut Node, attribute_id: AttributeId, value: Variant) -> Result<(), String> {
+    node.set_attribute(attribute_id, DataValue::new(value))
+        .map_err(|err| format!("Attribute {:?} cannot be set, {:?}", attribute_id, err))
+}
+
+/// Attributes of nodes that are written as XML attributes, with the default that is omitted
+const XML_ATTRIBUTES: [(AttributeId, &'static str, &'static str); 13] = [
+    (AttributeId::IsAbstract, "IsAbstract", "false"),
+    (AttributeId::Symmetric, "Symmetric", "false"),
+    (AttributeId::EventNotifier, "EventNotifier", "0"),
+    (AttributeId::ContainsNoLoops, "ContainsNoLoops", "false"),
+    (AttributeId::ValueRank, "ValueRank", "-1"),
+    (AttributeId::AccessLevel, "AccessLevel", "1"),
+    (AttributeId::UserAccessLevel, "UserAccessLevel", "1"),
+    (AttributeId::MinimumSamplingInterval, "MinimumSamplingInterval", "0"),
+    (AttributeId::Historizing, "Historizing", "false"),
+    (AttributeId::Executable, "Executable", "true"),
+    (AttributeId::UserExecutable, "UserExecutable", "true"),
+    (AttributeId::WriteMask, "WriteMask", "0"),
+    (AttributeId::UserWriteMask, "UserWriteMask", "0"),
+];
+
+struct NodeSetExporter<'a> {
+    address_space: &'a AddressSpace,
+    /// Aliases used by the document and the node ids they stand for
+    aliases: BTreeMap<String, String>,
+}
+
+impl<'a> NodeSetExporter<'a> {
+    fn export_node(&mut self, node_type: &NodeType, exported: &HashSet<NodeId>) -> XmlNode {
+        let node = node_type.as_node();
+        let node_id = node.node_id();
+        let mut e = XmlNode::new(match *node_type {
+            NodeType::Object(_) => "UAObject",
+            NodeType::ObjectType(_) => "UAObjectType",
+            NodeType::ReferenceType(_) => "UAReferenceType",
+            NodeType::Variable(_) => "UAVariable",
+            NodeType::VariableType(_) => "UAVariableType",
+            NodeType::View(_) => "UAView",
+            NodeType::DataType(_) => "UADataType",
+            NodeType::Method(_) => "UAMethod",
+        });
+        e.attributes.insert("NodeId".to_string(), node_id.to_string());
+        e.attributes.insert("BrowseName".to_string(), qualified_name_text(&node.browse_name()));
+        if let Some(Variant::NodeId(data_type)) = attribute_value(node, AttributeId::DataType) {
+            if *data_type != DataTypeId::BaseDataType.into() {
+                let data_type = self.node_id_or_alias(&data_type);
+                e.attributes.insert("DataType".to_string(), data_type);
+            }
+        }
+        if let Some(Variant::Array(dimensions)) = attribute_value(node, AttributeId::ArrayDimensions) {
+            let dimensions = dimensions.iter().map(variant_text).collect::<Vec<String>>();
+            e.attributes.insert("ArrayDimensions".to_string(), dimensions.join(","));
+        }
+        for &(attribute_id, name, default) in XML_ATTRIBUTES.iter() {
+            if let Some(value) = attribute_value(node, attribute_id) {
+                let value = match value {
+                    // Views hold their event notifier as a boolean
+                    Variant::Boolean(value) if attribute_id == AttributeId::EventNotifier => Variant::Byte(value as Byte),
+                    value => value
+                };
+                let value = variant_text(&value);
+                if value != default {
+                    e.attributes.insert(name.to_string(), value);
+                }
+            }
+        }
+
+        e.children.push(localized_text_element("DisplayName", &node.display_name()));
+        if let Some(description) = node.description() {
+            if !description.text.is_null() && !description.text.as_ref().is_empty() {
+                e.children.push(localized_text_element("Description", &description));
+            }
+        }
+        let references = self.references(&node_id, exported);
+        if !references.is_empty() {
+            let mut references_element = XmlNode::new("References");
+            references_element.children = references;
+            e.children.push(references_element);
+        }
+
+        match *node_type {
+            NodeType::Variable(_) | NodeType::VariableType(_) => {
+                if let Some(value) = attribute_value(node, AttributeId::Value) {
+                    if value != Variant::Empty {
+                        let mut value_element = XmlNode::new("Value");
+                        value.encode_nodeset_xml(&mut value_element);
+                        value_element.children.iter_mut().for_each(set_types_prefix);
+                        e.children.push(value_element);
+                    }
+                }
+            }
+            NodeType::DataType(ref data_type) => {
+                if let Some(definition) = data_type.definition() {
+                    let definition = self.definition(definition);
+                    e.children.push(definition);
+                }
+            }
+            NodeType::ReferenceType(ref reference_type) => {
+                if let Some(inverse_name) = reference_type.inverse_name() {
+                    e.children.push(localized_text_element("InverseName", &inverse_name));
+                }
+            }
+            _ => {}
+        }
+        e
+    }
+
+    /// Writes the forward references of the node and the inverse references from nodes that are
+    /// not exported, which would otherwise be lost.
+    fn references(&mut self, node_id: &NodeId, exported: &HashSet<NodeId>) -> Vec<XmlNode> {
+        let mut references = Vec::new();
+        let address_space = self.address_space;
+        if let Some(forward) = address_space.find_references_from(node_id, None) {
+            forward.into_iter().for_each(|r| {
+                let reference_type = address_space.reference_type(node_id, &r.node_id, r.reference_type_id);
+                references.push((r.reference_type_id, reference_type, r.node_id, true));
+            });
+        }
+        if let Some(inverse) = address_space.find_references_to(node_id, None) {
+            inverse.into_iter().filter(|r| !exported.contains(&r.node_id)).for_each(|r| {
+                let reference_type = address_space.reference_type(&r.node_id, node_id, r.reference_type_id);
+                references.push((r.reference_type_id, reference_type, r.node_id, false));
+            });
+        }
+        references.sort_by_key(|&(reference_type_id, ref reference_type, ref target, is_forward)| (reference_type_id as UInt32, node_id_sort_key(reference_type), !is_forward, node_id_sort_key(target)));
+        references.into_iter().map(|(_, reference_type, target, is_forward)| {
+            let mut e = text_element("Reference", &target.to_string());
+            let reference_type = self.node_id_or_alias(&reference_type);
+            e.attributes.insert("ReferenceType".to_string(), reference_type);
+            if !is_forward {
+                e.attributes.insert("IsForward".to_string(), "false".to_string());
+            }
+            e
+        }).collect()
+    }
+
+    fn definition(&mut self, definition: &DataTypeDefinition) -> XmlNode {
+        let mut e = XmlNode::new("Definition");
+        e.attributes.insert("Name".to_string(), qualified_name_text(&definition.name));
+        if definition.is_union {
+            e.attributes.insert("IsUnion".to_string(), "true".to_string());
+        }
+        e.children = definition.fields.iter().map(|f| {
+            let mut field = XmlNode::new("Field");
+            field.attributes.insert("Name".to_string(), f.name.clone());
+            if f.data_type != DataTypeId::BaseDataType.into() {
+                field.attributes.insert("DataType".to_string(), self.node_id_or_alias(&f.data_type));
+            }
+            if f.value_rank != -1 {
+                field.attributes.insert("ValueRank".to_string(), f.value_rank.to_string());
+            }
+            if let Some(value) = f.value {
+                field.attributes.insert("Value".to_string(), value.to_string());
+            }
+            if let Some(ref description) = f.description {
+                field.children.push(localized_text_element("Description", description));
+            }
+            field
+        }).collect();
+        e
+    }
+
+    /// Standard nodes are written using their browse name as an alias
+    fn node_id_or_alias(&mut self, node_id: &NodeId) -> String {
+        if node_id.namespace == 0 {
+            if let Some(node) = self.address_space.find_node(node_id) {
+                let alias = node.as_node().browse_name().name.as_ref().to_string();
+                self.aliases.insert(alias.clone(), node_id.to_string());
+                return alias;
+            }
+        }
+        node_id.to_string()
+    }
+}
+
+/// Orders node ids by namespace and then numerically before other kinds of identifier
+fn node_id_sort_key(node_id: &NodeId) -> (UInt16, UInt32, String) {
+    match node_id.identifier {
+        Identifier::Numeric(id) => (node_id.namespace, id, String::new()),
+        _ => (node_id.namespace, UInt32::max_value(), node_id.to_string())
+    }
+}
+
+fn attribute_value(node: &Node, attribute_id: AttributeId) -> Option<Variant> {
+    node.find_attribute(attribute_id).and_then(|v| v.value)
+}
+
+fn variant_text(value: &Variant) -> String {
+    match *value {
+        Variant::Boolean(value) => value.to_string(),
+        Variant::Byte(value) => value.to_string(),
+        Variant::Int32(value) => value.to_string(),
+        Variant::UInt32(value) => value.to_string(),
+        Variant::Double(value) => value.to_string(),
+        ref value => format!("{:?}", value)
+    }
+}
+
+/// Writes a qualified name in the form `<namespace index>:<name>`, leaving out namespace 0
+fn qualified_name_text(name: &QualifiedName) -> String {
+    if name.namespace_index == 0 {
+        name.name.as_ref().to_string()
+    } else {
+        format!("{}:{}", name.namespace_index, name.name.as_ref())
+    }
+}
+
+fn text_element(name: &str, text: &str) -> XmlNode {
+    let mut e = XmlNode::new(name);
+    e.text = Some(text.to_string());
+    e
+}
+
+fn localized_text_element(name: &str, value: &LocalizedText) -> XmlNode {
+    let mut e = text_element(name, if value.text.is_null() { "" } else { value.text.as_ref() });
+    if !value.locale.is_null() && !value.locale.as_ref().is_empty() {
+        e.attributes.insert("Locale".to_string(), value.locale.as_ref().to_string());
+    }
+    e
+}
+
+/// Puts the elements of a value into the types namespace
+fn set_types_prefix(e: &mut XmlNode) {
+    e.prefix = Some("uax".to_string());
+    e.children.iter_mut().for_each(set_types_prefix);
+}
+
+/// Writes an element indented by its depth. Attributes are written in a fixed order, with the
+/// namespace declarations, node id, browse name and reference type first, because the order of
+/// the attribute map is not stable.
+pub(crate) fn write_element<W>(writer: &mut W, e: &XmlNode, depth: usize) -> ::std::io::Result<()> where W: Write {
+    const FIRST_ATTRIBUTES: [&'static str; 5] = ["xmlns", "xmlns:uax", "NodeId", "BrowseName", "ReferenceType"];
+    let indent = "  ".repeat(depth);
+    let name = if let Some(ref prefix) = e.prefix { format!("{}:{}", prefix, e.name) } else { e.name.clone() };
+    let mut attributes = e.attributes.iter().collect::<Vec<(&String, &String)>>();
+    attributes.sort_by_key(|&(name, _)| (FIRST_ATTRIBUTES.iter().position(|n| n == name).unwrap_or(FIRST_ATTRIBUTES.len()), name.clone()));
+
+    write!(writer, "{}<{}", indent, name)?;
+    for (name, value) in attributes {
+        write!(writer, " {}=\"{}\"", name, escape_xml(value).replace('"', "&quot;"))?;
+    }
+    if !e.children.is_empty() {
+        writeln!(writer, ">")?;
+        for child in &e.children {
+            write_element(writer, child, depth + 1)?;
+        }
+        writeln!(writer, "{}</{}>", indent, name)
+    } else if let Some(ref text) = e.text {
+        writeln!(writer, ">{}</{}>", escape_xml(text), name)
+    } else {
+        writeln!(writer, " />")
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
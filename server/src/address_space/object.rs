@@ -9,7 +9,7 @@ pub struct Object {
 node_impl!(Object);
 
 impl Object {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str) -> Object {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str) -> Object where R: Into<QualifiedName> {
         // Mandatory
         let attributes = vec![
             (AttributeId::EventNotifier, Variant::Byte(0))
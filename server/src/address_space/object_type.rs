@@ -9,7 +9,7 @@ pub struct ObjectType {
 node_impl!(ObjectType);
 
 impl ObjectType {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, is_abstract: Boolean) -> ObjectType {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, is_abstract: Boolean) -> ObjectType where R: Into<QualifiedName> {
         // Mandatory
         let attributes = vec![
             (AttributeId::IsAbstract, Variant::Boolean(is_abstract)),
@@ -9,7 +9,7 @@ pub struct ReferenceType {
 node_impl!(ReferenceType);
 
 impl ReferenceType {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, inverse_name: Option<LocalizedText>, symmetric: Boolean, is_abstract: Boolean) -> ReferenceType {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, inverse_name: Option<LocalizedText>, symmetric: Boolean, is_abstract: Boolean) -> ReferenceType where R: Into<QualifiedName> {
         // Mandatory
         let mut attributes = vec![
             (AttributeId::Symmetric, Variant::Boolean(symmetric)),
@@ -0,0 +1,266 @@
+//! Sampling of attribute values that is shared between monitored items.
+//!
+//! Monitored items that sample the same attribute of the same node, with the same index range and
+//! sampling interval, are in one sampling group regardless of their session. The group samples
+//! the source once per interval and hands the sample to every member, which compares it against
+//! its own filter and queues it. So a value with an expensive getter that many clients monitor is
+//! only read once per interval.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use time;
+
+use opcua_types::{Double, UInt32, NodeId, AttributeId, DataValue, UAString};
+use opcua_types::service_types::{ReadValueId, SamplingIntervalDiagnosticsDataType};
+
+use constants;
+use DateTimeUtc;
+
+/// Identifies what the members of a group sample. The sampling interval is in microseconds, and
+/// is negative for the interval of each item's subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SamplingKey {
+    node_id: NodeId,
+    attribute_id: AttributeId,
+    index_range: UAString,
+    sampling_interval: i64,
+}
+
+/// The number of monitored items in groups of a sampling interval
+#[derive(Debug, Clone, Default)]
+struct IntervalCount {
+    item_count: UInt32,
+    max_item_count: UInt32,
+    disabled_item_count: UInt32,
+}
+
+/// Counts that are shared between the sampler and its groups
+struct SamplerStats {
+    /// Counts of items by sampling interval, in microseconds
+    interval_counts: Mutex<BTreeMap<i64, IntervalCount>>,
+    /// The number of times that the source of a group was sampled
+    sampling_count: AtomicUsize,
+    /// The number of times that a member was given the sample of its group instead
+    shared_sampling_count: AtomicUsize,
+}
+
+impl SamplerStats {
+    fn join(&self, sampling_interval: i64) {
+        let mut interval_counts = trace_lock_unwrap!(self.interval_counts);
+        let interval_count = interval_counts.entry(sampling_interval).or_insert_with(IntervalCount::default);
+        interval_count.item_count += 1;
+        if interval_count.item_count > interval_count.max_item_count {
+            interval_count.max_item_count = interval_count.item_count;
+        }
+    }
+
+    fn leave(&self, sampling_interval: i64, disabled: bool) {
+        let mut interval_counts = trace_lock_unwrap!(self.interval_counts);
+        if let Some(interval_count) = interval_counts.get_mut(&sampling_interval) {
+            interval_count.item_count -= 1;
+            if disabled {
+                interval_count.disabled_item_count -= 1;
+            }
+        }
+    }
+
+    fn set_disabled(&self, sampling_interval: i64, disabled: bool) {
+        let mut interval_counts = trace_lock_unwrap!(self.interval_counts);
+        if let Some(interval_count) = interval_counts.get_mut(&sampling_interval) {
+            if disabled {
+                interval_count.disabled_item_count += 1;
+            } else {
+                interval_count.disabled_item_count -= 1;
+            }
+        }
+    }
+}
+
+/// A group of monitored items that sample the same thing, and its last sample
+struct SamplingGroup {
+    sampling_interval: i64,
+    /// The time and value of the last sample
+    last_sample: Mutex<Option<(DateTimeUtc, DataValue)>>,
+    stats: Arc<SamplerStats>,
+}
+
+/// The membership of a monitored item in a sampling group, which it leaves when this is dropped
+pub struct SamplingGroupMember {
+    group: Arc<SamplingGroup>,
+    sampling_interval: Double,
+    disabled: bool,
+}
+
+impl fmt::Debug for SamplingGroupMember {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SamplingGroupMember {{ sampling_interval: {}, disabled: {} }}", self.sampling_interval, self.disabled)
+    }
+}
+
+impl PartialEq for SamplingGroupMember {
+    fn eq(&self, other: &SamplingGroupMember) -> bool {
+        Arc::ptr_eq(&self.group, &other.group) && self.disabled == other.disabled
+    }
+}
+
+impl Clone for SamplingGroupMember {
+    fn clone(&self) -> SamplingGroupMember {
+        self.group.stats.join(self.group.sampling_interval);
+        if self.disabled {
+            self.group.stats.set_disabled(self.group.sampling_interval, true);
+        }
+        SamplingGroupMember {
+            group: self.group.clone(),
+            sampling_interval: self.sampling_interval,
+            disabled: self.disabled,
+        }
+    }
+}
+
+impl Drop for SamplingGroupMember {
+    fn drop(&mut self) {
+        self.group.stats.leave(self.group.sampling_interval, self.disabled);
+    }
+}
+
+impl SamplingGroupMember {
+    /// Returns the sampling interval that the member joined with
+    pub fn sampling_interval(&self) -> Double {
+        self.sampling_interval
+    }
+
+    /// Records whether the member's monitored item is disabled, for diagnostics
+    pub fn set_disabled(&mut self, disabled: bool) {
+        if self.disabled != disabled {
+            self.disabled = disabled;
+            self.group.stats.set_disabled(self.group.sampling_interval, disabled);
+        }
+    }
+
+    /// Returns a sample of the group's source at the time. If another member sampled the source
+    /// within the sampling interval, its sample is returned, otherwise `source` is called to take
+    /// a new one. Members that sample at the same time wait for the one sample.
+    pub fn sample<F>(&self, now: &DateTimeUtc, source: F) -> Option<DataValue> where F: FnOnce() -> Option<DataValue> {
+        let group = &self.group;
+        let mut last_sample = trace_lock_unwrap!(group.last_sample);
+        if let Some((ref sample_time, ref value)) = *last_sample {
+            if (*now).signed_duration_since(*sample_time) < Self::sample_lifetime(group.sampling_interval) {
+                group.stats.shared_sampling_count.fetch_add(1, Ordering::Relaxed);
+                return Some(value.clone());
+            }
+        }
+        let value = source();
+        group.stats.sampling_count.fetch_add(1, Ordering::Relaxed);
+        *last_sample = value.as_ref().map(|value| (*now, value.clone()));
+        value
+    }
+
+    /// The time that a sample is given to other members for. For the interval of a
+    /// subscription, it is shorter than the subscription timer so each tick of a timer samples
+    /// again.
+    fn sample_lifetime(sampling_interval: i64) -> time::Duration {
+        if sampling_interval < 0 {
+            time::Duration::milliseconds(constants::SUBSCRIPTION_TIMER_RATE_MS / 2)
+        } else {
+            time::Duration::microseconds(sampling_interval)
+        }
+    }
+}
+
+/// Groups the monitored items of every session that sample the same attribute at the same
+/// interval, so that each group samples its source once.
+pub struct Sampler {
+    groups: Mutex<HashMap<SamplingKey, Weak<SamplingGroup>>>,
+    stats: Arc<SamplerStats>,
+}
+
+impl Sampler {
+    pub fn new() -> Sampler {
+        Sampler {
+            groups: Mutex::new(HashMap::new()),
+            stats: Arc::new(SamplerStats {
+                interval_counts: Mutex::new(BTreeMap::new()),
+                sampling_count: AtomicUsize::new(0),
+                shared_sampling_count: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Joins the sampling group of the item to monitor at the sampling interval, which is
+    /// created if it does not exist. A negative interval is the interval of the subscription.
+    pub fn join(&self, item_to_monitor: &ReadValueId, attribute_id: AttributeId, sampling_interval: Double) -> SamplingGroupMember {
+        let interval = Self::interval_micros(sampling_interval);
+        let key = SamplingKey {
+            node_id: item_to_monitor.node_id.clone(),
+            attribute_id,
+            index_range: item_to_monitor.index_range.clone(),
+            sampling_interval: interval,
+        };
+        let mut groups = trace_lock_unwrap!(self.groups);
+        let group = if let Some(group) = groups.get(&key).and_then(|group| group.upgrade()) {
+            group
+        } else {
+            // Remove the groups that have no members while making a new one
+            groups.retain(|_, group| group.upgrade().is_some());
+            let group = Arc::new(SamplingGroup {
+                sampling_interval: interval,
+                last_sample: Mutex::new(None),
+                stats: self.stats.clone(),
+            });
+            groups.insert(key, Arc::downgrade(&group));
+            group
+        };
+        self.stats.join(interval);
+        SamplingGroupMember {
+            group,
+            sampling_interval,
+            disabled: false,
+        }
+    }
+
+    /// Returns the number of sampling groups that have members
+    pub fn group_count(&self) -> usize {
+        let groups = trace_lock_unwrap!(self.groups);
+        groups.values().filter(|group| group.upgrade().is_some()).count()
+    }
+
+    /// Returns the number of times that the source of a group has been sampled
+    pub fn sampling_count(&self) -> usize {
+        self.stats.sampling_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of times that a monitored item was given the sample of its group
+    /// rather than sampling the source itself
+    pub fn shared_sampling_count(&self) -> usize {
+        self.stats.shared_sampling_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the diagnostics of each sampling interval that monitored items use, ordered by
+    /// interval
+    pub fn sampling_interval_diagnostics(&self) -> Vec<SamplingIntervalDiagnosticsDataType> {
+        Self::diagnostics_of(&self.stats)
+    }
+
+    fn diagnostics_of(stats: &SamplerStats) -> Vec<SamplingIntervalDiagnosticsDataType> {
+        let interval_counts = trace_lock_unwrap!(stats.interval_counts);
+        interval_counts.iter().filter(|&(_, count)| count.item_count > 0).map(|(sampling_interval, count)| {
+            SamplingIntervalDiagnosticsDataType {
+                sampling_interval: if *sampling_interval < 0 { -1f64 } else { *sampling_interval as Double / 1000f64 },
+                monitored_item_count: count.item_count,
+                max_monitored_item_count: count.max_item_count,
+                disabled_monitored_item_count: count.disabled_item_count,
+            }
+        }).collect()
+    }
+
+    fn interval_micros(sampling_interval: Double) -> i64 {
+        if sampling_interval < 0f64 {
+            -1
+        } else {
+            (sampling_interval * 1000f64) as i64
+        }
+    }
+}
@@ -0,0 +1,873 @@
+//! Implements the push model of certificate management described in OPC UA Part 12 7.7 on the
+//! ServerConfiguration object. A security administrator can have the server create a certificate
+//! signing request, supply it with a new application instance certificate, fetch the certs it
+//! rejected, and read or replace its trust list through the TrustList file object.
+//!
+//! Every method requires an encrypted session whose user is one of the configured security
+//! administrators.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, MethodId, ObjectId, ObjectTypeId, ReferenceTypeId, VariableId};
+use opcua_types::service_types::{CallMethodRequest, CallMethodResult, TrustListDataType};
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use opcua_core::config::Config;
+use opcua_core::crypto::{CertificateStore, KeySize, PrivateKey, X509, X509Data, TRUST_LIST_ALL};
+
+use address_space::AttrFnGetter;
+use address_space::address_space::{AddressSpace, ReferenceDirection};
+use address_space::node::{Node, NodeType};
+use address_space::types::{Method, Object, Variable};
+use session::Session;
+use state::ServerState;
+
+/// File open mode to read from the file, see Part 5 C.2.1
+const FILE_MODE_READ: Byte = 1;
+/// File open mode to write to the file
+const FILE_MODE_WRITE: Byte = 2;
+/// File open mode to erase the existing contents of the file
+const FILE_MODE_ERASE_EXISTING: Byte = 4;
+
+/// The only format of private key that UpdateCertificate accepts
+const PRIVATE_KEY_FORMAT_PEM: &'static str = "PEM";
+
+/// The minimum length of the nonce CreateSigningRequest requires to regenerate the private key
+const MIN_SIGNING_REQUEST_NONCE_LENGTH: usize = 32;
+
+/// Key size of a regenerated private key if the server has no key to take it from
+const DEFAULT_KEY_SIZE: u32 = 2048;
+
+/// The largest trust list that may be written to the trust list file, in bytes
+const MAX_TRUST_LIST_SIZE: usize = 4 * 1024 * 1024;
+
+/// An open trust list file handle that is not used for this long is closed, so a client that
+/// goes away without closing it cannot hold the trust list open
+const TRUST_LIST_FILE_TIMEOUT_MS: u64 = 60_000;
+
+/// A certificate and private key supplied by UpdateCertificate that is waiting for ApplyChanges
+struct PendingCertificate {
+    cert: X509,
+    pkey: PrivateKey,
+    issuer_certs: Vec<X509>,
+}
+
+/// An open handle on the trust list file. A handle open for reading holds the encoded trust list
+/// as it was when opened, a handle open for writing accumulates the new trust list. The handle
+/// belongs to the session with the authentication token.
+struct TrustListFile {
+    authentication_token: NodeId,
+    writing: bool,
+    data: Vec<u8>,
+    position: usize,
+    last_used: Instant,
+}
+
+/// Holds the state of certificate management between method calls
+pub struct CertificateManager {
+    certificate_store: Arc<RwLock<CertificateStore>>,
+    /// The private key generated by CreateSigningRequest, waiting for its cert
+    signing_request_key: Option<PrivateKey>,
+    /// The cert waiting to be applied
+    pending_certificate: Option<PendingCertificate>,
+    /// Open handles on the trust list file
+    trust_list_files: BTreeMap<UInt32, TrustListFile>,
+    last_file_handle: UInt32,
+    /// How long an open handle may go unused before it is closed
+    file_timeout: Duration,
+    /// The time the trust list was last changed through the TrustList object
+    last_update_time: DateTime,
+    /// Set when ApplyChanges has stored a new certificate that the server has yet to reload
+    reload_requested: bool,
+}
+
+impl CertificateManager {
+    pub fn new(certificate_store: Arc<RwLock<CertificateStore>>) -> CertificateManager {
+        CertificateManager {
+            certificate_store,
+            signing_request_key: None,
+            pending_certificate: None,
+            trust_list_files: BTreeMap::new(),
+            last_file_handle: 0,
+            file_timeout: Duration::from_millis(TRUST_LIST_FILE_TIMEOUT_MS),
+            last_update_time: DateTime::now(),
+            reload_requested: false,
+        }
+    }
+
+    /// Sets how long an open trust list file handle may go unused before it is closed
+    pub fn set_file_timeout(&mut self, file_timeout: Duration) {
+        self.file_timeout = file_timeout;
+    }
+
+    /// Tests if ApplyChanges has stored a new certificate for the server to reload, and clears
+    /// the request
+    pub fn take_reload_request(&mut self) -> bool {
+        let reload_requested = self.reload_requested;
+        self.reload_requested = false;
+        reload_requested
+    }
+
+    /// Closes the trust list file handles of a session that has closed or been terminated,
+    /// discarding anything written to them
+    pub fn close_session_files(&mut self, authentication_token: &NodeId) {
+        let file_handles = self.trust_list_files.iter()
+            .filter(|&(_, f)| f.authentication_token == *authentication_token)
+            .map(|(file_handle, _)| *file_handle)
+            .collect::<Vec<UInt32>>();
+        for file_handle in file_handles {
+            info!("Closing trust list file handle {} of a session that has ended", file_handle);
+            self.trust_list_files.remove(&file_handle);
+        }
+    }
+
+    /// Closes the trust list file handles that have not been used within the timeout
+    fn close_idle_files(&mut self) {
+        let file_timeout = self.file_timeout;
+        let file_handles = self.trust_list_files.iter()
+            .filter(|&(_, f)| f.last_used.elapsed() >= file_timeout)
+            .map(|(file_handle, _)| *file_handle)
+            .collect::<Vec<UInt32>>();
+        for file_handle in file_handles {
+            warn!("Closing trust list file handle {} that has not been used for {:?}", file_handle, file_timeout);
+            self.trust_list_files.remove(&file_handle);
+        }
+    }
+
+    /// The number of open trust list file handles
+    fn open_count(&mut self) -> usize {
+        self.close_idle_files();
+        self.trust_list_files.len()
+    }
+
+    /// Tests if a handle is open for writing to the trust list
+    fn is_trust_list_writing(&mut self) -> bool {
+        self.close_idle_files();
+        self.trust_list_files.values().any(|f| f.writing)
+    }
+
+    /// Finds the open trust list file of the session
+    fn trust_list_file(&mut self, session: &Session, file_handle: UInt32) -> Result<&mut TrustListFile, StatusCode> {
+        self.close_idle_files();
+        let is_session_file = self.trust_list_files.get(&file_handle).map_or(false, |f| f.authentication_token == session.authentication_token);
+        if is_session_file {
+            let file = self.trust_list_files.get_mut(&file_handle).unwrap();
+            file.last_used = Instant::now();
+            Ok(file)
+        } else {
+            error!("Trust list file handle {} is not open on this session", file_handle);
+            Err(BadInvalidArgument)
+        }
+    }
+
+    /// Opens the trust list file for reading the lists in the mask, or for writing
+    fn open_trust_list(&mut self, session: &Session, writing: bool, specified_lists: UInt32) -> Result<UInt32, StatusCode> {
+        if self.is_trust_list_writing() || (writing && !self.trust_list_files.is_empty()) {
+            error!("Trust list cannot be opened while it is open for writing, or opened for writing while it is open");
+            return Err(BadInvalidState);
+        }
+        let data = if writing {
+            Vec::new()
+        } else {
+            let certificate_store = trace_read_lock_unwrap!(self.certificate_store);
+            encode_trust_list(&certificate_store.read_trust_list(specified_lists))?
+        };
+        self.last_file_handle += 1;
+        self.trust_list_files.insert(self.last_file_handle, TrustListFile {
+            authentication_token: session.authentication_token.clone(),
+            writing,
+            data,
+            position: 0,
+            last_used: Instant::now(),
+        });
+        Ok(self.last_file_handle)
+    }
+}
+
+/// Encodes a trust list as the contents of the trust list file
+fn encode_trust_list(trust_list: &TrustListDataType) -> Result<Vec<u8>, StatusCode> {
+    let mut stream = Cursor::new(Vec::with_capacity(trust_list.byte_len()));
+    trust_list.encode(&mut stream)?;
+    Ok(stream.into_inner())
+}
+
+/// Certificate management is restricted to security administrators on encrypted sessions
+fn check_security_admin(server_state: &ServerState, session: &Session) -> Result<(), StatusCode> {
+    if session.secure_channel.security_mode() != MessageSecurityMode::SignAndEncrypt {
+        error!("Certificate management requires an encrypted session");
+        Err(BadSecurityModeInsufficient)
+    } else if !server_state.is_security_admin(&session.user_identity) {
+        error!("Certificate management requires a security administrator");
+        Err(BadUserAccessDenied)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the input arguments of the request, which must be exactly the expected number
+fn input_arguments(request: &CallMethodRequest, expected: usize) -> Result<&[Variant], StatusCode> {
+    let input_arguments: &[Variant] = if let Some(ref input_arguments) = request.input_arguments { input_arguments } else { &[] };
+    if input_arguments.len() < expected {
+        Err(BadArgumentsMissing)
+    } else if input_arguments.len() > expected {
+        Err(BadTooManyArguments)
+    } else {
+        Ok(input_arguments)
+    }
+}
+
+fn node_id_argument(value: &Variant) -> Result<NodeId, StatusCode> {
+    match *value {
+        Variant::NodeId(ref node_id) => Ok(*node_id.clone()),
+        Variant::Empty => Ok(NodeId::null()),
+        _ => Err(BadInvalidArgument)
+    }
+}
+
+fn byte_string_argument(value: &Variant) -> Result<ByteString, StatusCode> {
+    match *value {
+        Variant::ByteString(ref value) => Ok(value.clone()),
+        Variant::Empty => Ok(ByteString::null()),
+        _ => Err(BadInvalidArgument)
+    }
+}
+
+fn byte_strings_argument(value: &Variant) -> Result<Vec<ByteString>, StatusCode> {
+    match *value {
+        Variant::Array(ref values) => values.iter().map(byte_string_argument).collect(),
+        Variant::Empty => Ok(Vec::new()),
+        _ => Err(BadInvalidArgument)
+    }
+}
+
+fn string_argument(value: &Variant) -> Result<UAString, StatusCode> {
+    match *value {
+        Variant::String(ref value) => Ok(value.clone()),
+        Variant::Empty => Ok(UAString::null()),
+        _ => Err(BadInvalidArgument)
+    }
+}
+
+fn boolean_argument(value: &Variant) -> Result<Boolean, StatusCode> {
+    if let Variant::Boolean(value) = *value { Ok(value) } else { Err(BadInvalidArgument) }
+}
+
+fn byte_argument(value: &Variant) -> Result<Byte, StatusCode> {
+    if let Variant::Byte(value) = *value { Ok(value) } else { Err(BadInvalidArgument) }
+}
+
+fn int32_argument(value: &Variant) -> Result<Int32, StatusCode> {
+    if let Variant::Int32(value) = *value { Ok(value) } else { Err(BadInvalidArgument) }
+}
+
+fn uint32_argument(value: &Variant) -> Result<UInt32, StatusCode> {
+    if let Variant::UInt32(value) = *value { Ok(value) } else { Err(BadInvalidArgument) }
+}
+
+fn uint64_argument(value: &Variant) -> Result<UInt64, StatusCode> {
+    if let Variant::UInt64(value) = *value { Ok(value) } else { Err(BadInvalidArgument) }
+}
+
+/// Makes the result of a successful call
+fn call_result(input_argument_count: usize, output_arguments: Vec<Variant>) -> CallMethodResult {
+    CallMethodResult {
+        status_code: Good,
+        input_argument_results: if input_argument_count > 0 { Some(vec![Good; input_argument_count]) } else { None },
+        input_argument_diagnostic_infos: None,
+        output_arguments: if output_arguments.is_empty() { None } else { Some(output_arguments) },
+    }
+}
+
+/// The server has a single certificate group, the default application group
+fn check_certificate_group(certificate_group_id: &NodeId) -> Result<(), StatusCode> {
+    if certificate_group_id.is_null() || *certificate_group_id == ObjectId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup.into() {
+        Ok(())
+    } else {
+        error!("Certificate group {:?} is not supported", certificate_group_id);
+        Err(BadInvalidArgument)
+    }
+}
+
+/// The server only has RSA application instance certificates
+fn check_certificate_type(certificate_type_id: &NodeId) -> Result<(), StatusCode> {
+    let supported = [ObjectTypeId::ApplicationCertificateType, ObjectTypeId::RsaMinApplicationCertificateType, ObjectTypeId::RsaSha256ApplicationCertificateType];
+    if certificate_type_id.is_null() || supported.iter().any(|t| *certificate_type_id == (*t).into()) {
+        Ok(())
+    } else {
+        error!("Certificate type {:?} is not supported", certificate_type_id);
+        Err(BadNotSupported)
+    }
+}
+
+/// Overrides the subject of the creation args with the entries of a subject name such as
+/// "CN=Server, O=Company, C=DE". Entries may be separated by commas or slashes.
+fn apply_subject_name(args: &mut X509Data, subject_name: &str) -> Result<(), StatusCode> {
+    for entry in subject_name.split(|c| c == ',' || c == '/').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        let mut parts = entry.splitn(2, '=');
+        let (key, value) = match (parts.next(), parts.next()) {
+            (Some(key), Some(value)) => (key.trim().to_uppercase(), value.trim().to_string()),
+            _ => {
+                error!("Subject name entry \"{}\" is invalid", entry);
+                return Err(BadInvalidArgument);
+            }
+        };
+        match key.as_ref() {
+            "CN" => args.common_name = value,
+            "O" => args.organization = value,
+            "OU" => args.organizational_unit = value,
+            "C" => args.country = value,
+            "ST" => args.state = value,
+            _ => {
+                error!("Subject name entry \"{}\" is not supported", entry);
+                return Err(BadInvalidArgument);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a new application instance certificate can replace the server's. Its key must be
+/// of the same type as the server's key and at least as long, so the server's endpoints remain
+/// usable, and the cert must be valid now and name the server's application uri.
+fn validate_new_certificate(server_state: &ServerState, cert: &X509, pkey: &PrivateKey) -> Result<(), StatusCode> {
+    if let Some(ref server_pkey) = server_state.server_pkey {
+        if pkey.key_type() != server_pkey.key_type() || pkey.bit_length() < server_pkey.bit_length() {
+            error!("Certificate key of {} bits does not suit the server, whose key is of {} bits", pkey.bit_length(), server_pkey.bit_length());
+            return Err(BadCertificateInvalid);
+        }
+    }
+    let status_code = cert.is_time_valid(&Utc::now());
+    if status_code.is_bad() {
+        error!("Certificate is not valid now");
+        return Err(status_code);
+    }
+    let application_uri = {
+        let config = trace_read_lock_unwrap!(server_state.config);
+        config.application_uri.clone()
+    };
+    let status_code = cert.is_application_uri_valid(&application_uri);
+    if status_code.is_bad() {
+        error!("Certificate is not for the server's application uri {}", application_uri);
+        return Err(status_code);
+    }
+    Ok(())
+}
+
+/// Handles UpdateCertificate, which supplies a new application instance certificate. The private
+/// key is either supplied in PEM form or is the key of the last signing request or the server's
+/// current key. The cert is applied by ApplyChanges.
+fn update_certificate(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 6)?;
+    check_certificate_group(&node_id_argument(&input_arguments[0])?)?;
+    check_certificate_type(&node_id_argument(&input_arguments[1])?)?;
+    let cert = X509::from_byte_string(&byte_string_argument(&input_arguments[2])?)?;
+    let issuer_certs = byte_strings_argument(&input_arguments[3])?.iter()
+        .map(X509::from_byte_string)
+        .collect::<Result<Vec<X509>, StatusCode>>()?;
+    let private_key_format = string_argument(&input_arguments[4])?;
+    let private_key = byte_string_argument(&input_arguments[5])?;
+
+    let mut manager = trace_lock_unwrap!(manager);
+    let supplied_pkey = if private_key.is_null() || private_key.as_ref().is_empty() {
+        None
+    } else if private_key_format.as_ref() == PRIVATE_KEY_FORMAT_PEM {
+        let pkey = PrivateKey::from_pem(private_key.as_ref()).map_err(|_| BadSecurityChecksFailed)?;
+        if !cert.matches_private_key(&pkey) {
+            error!("Certificate does not match the supplied private key");
+            return Err(BadSecurityChecksFailed);
+        }
+        Some(pkey)
+    } else {
+        error!("Private key format {} is not supported", private_key_format);
+        return Err(BadNotSupported);
+    };
+
+    // Without a supplied key, the cert must be for the key of the last signing request or the
+    // server's current key
+    let is_signing_request_cert = supplied_pkey.is_none() && manager.signing_request_key.as_ref().map_or(false, |pkey| cert.matches_private_key(pkey));
+    let is_server_cert = supplied_pkey.is_none() && !is_signing_request_cert && server_state.server_pkey.as_ref().map_or(false, |pkey| cert.matches_private_key(pkey));
+    {
+        let pkey = if is_signing_request_cert {
+            manager.signing_request_key.as_ref()
+        } else if is_server_cert {
+            server_state.server_pkey.as_ref()
+        } else {
+            supplied_pkey.as_ref()
+        };
+        if let Some(pkey) = pkey {
+            validate_new_certificate(server_state, &cert, pkey)?;
+        } else {
+            error!("Certificate does not match the private key of the signing request or of the server");
+            return Err(BadSecurityChecksFailed);
+        }
+    }
+
+    let pkey = if is_signing_request_cert {
+        manager.signing_request_key.take().unwrap()
+    } else if is_server_cert {
+        let pem = server_state.server_pkey.as_ref().unwrap().private_key_to_pem().map_err(|_| BadUnexpectedError)?;
+        PrivateKey::from_pem(&pem).map_err(|_| BadUnexpectedError)?
+    } else {
+        supplied_pkey.unwrap()
+    };
+    manager.pending_certificate = Some(PendingCertificate { cert, pkey, issuer_certs });
+    Ok(call_result(input_arguments.len(), vec![Variant::Boolean(true)]))
+}
+
+/// Handles CreateSigningRequest, which makes a signing request for the server's key or for a
+/// newly generated key that is kept until the signed cert is supplied to UpdateCertificate.
+fn create_signing_request(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 5)?;
+    check_certificate_group(&node_id_argument(&input_arguments[0])?)?;
+    check_certificate_type(&node_id_argument(&input_arguments[1])?)?;
+    let subject_name = string_argument(&input_arguments[2])?;
+    let regenerate_private_key = boolean_argument(&input_arguments[3])?;
+    let nonce = byte_string_argument(&input_arguments[4])?;
+
+    let mut args = {
+        let config = trace_read_lock_unwrap!(server_state.config);
+        X509Data::from(config.application_description())
+    };
+    if !subject_name.is_null() {
+        apply_subject_name(&mut args, subject_name.as_ref())?;
+    }
+
+    let mut manager = trace_lock_unwrap!(manager);
+    let certificate_request = if regenerate_private_key {
+        if nonce.is_null() || nonce.as_ref().len() < MIN_SIGNING_REQUEST_NONCE_LENGTH {
+            error!("Regenerating the private key requires a nonce of at least {} bytes", MIN_SIGNING_REQUEST_NONCE_LENGTH);
+            return Err(BadInvalidArgument);
+        }
+        let key_size = server_state.server_pkey.as_ref().map_or(DEFAULT_KEY_SIZE, |pkey| pkey.bit_length() as u32);
+        let pkey = PrivateKey::new(key_size);
+        let certificate_request = CertificateStore::create_signing_request(&args, &pkey);
+        manager.signing_request_key = Some(pkey);
+        certificate_request
+    } else if let Some(ref pkey) = server_state.server_pkey {
+        CertificateStore::create_signing_request(&args, pkey)
+    } else {
+        error!("Server has no private key to make a signing request for");
+        return Err(BadInvalidState);
+    };
+    let certificate_request = certificate_request.map_err(|err| {
+        error!("Signing request could not be created, error = {}", err);
+        BadUnexpectedError
+    })?;
+    Ok(call_result(input_arguments.len(), vec![ByteString::from(&certificate_request).into()]))
+}
+
+/// Handles ApplyChanges, which writes the cert supplied to UpdateCertificate to the certificate
+/// store as the server's own certificate and private key, along with its issuers. The call holds
+/// the server state that the certificate is replaced in, so the handler asks for the server to
+/// reload its certificates, which it does as soon as the call completes. New secure channels use
+/// the new certificate from then on.
+fn apply_changes(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 0)?;
+    let mut manager = trace_lock_unwrap!(manager);
+    if manager.is_trust_list_writing() {
+        error!("Changes cannot be applied while the trust list is open for writing");
+        return Err(BadInvalidState);
+    }
+    if let Some(pending_certificate) = manager.pending_certificate.take() {
+        let result: Result<(), String> = {
+            let certificate_store = trace_read_lock_unwrap!(manager.certificate_store);
+            certificate_store.store_own_cert_and_pkey(&pending_certificate.cert, &pending_certificate.pkey, true)
+                .and_then(|_| {
+                    pending_certificate.issuer_certs.iter().map(|cert| certificate_store.store_issuer_cert(cert).map(|_| ())).collect()
+                })
+        };
+        if let Err(err) = result {
+            error!("Certificate could not be applied, error = {}", err);
+            return Err(BadUnexpectedError);
+        }
+        info!("Application instance certificate has been updated");
+        manager.reload_requested = true;
+    }
+    Ok(call_result(input_arguments.len(), Vec::new()))
+}
+
+/// Handles GetRejectedList, which returns the certs in the rejected directory
+fn get_rejected_list(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 0)?;
+    let manager = trace_lock_unwrap!(manager);
+    let certificate_store = trace_read_lock_unwrap!(manager.certificate_store);
+    let certificates: Vec<Variant> = certificate_store.rejected_certs().iter().map(|cert| cert.as_byte_string().into()).collect();
+    Ok(call_result(input_arguments.len(), vec![certificates.into()]))
+}
+
+/// Handles Open on the trust list. The trust list may be opened for reading, or for writing with
+/// its existing contents erased.
+fn trust_list_open(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 1)?;
+    let mode = byte_argument(&input_arguments[0])?;
+    let writing = if mode == FILE_MODE_READ {
+        false
+    } else if mode == FILE_MODE_WRITE | FILE_MODE_ERASE_EXISTING {
+        true
+    } else {
+        error!("Trust list cannot be opened with mode {}", mode);
+        return Err(BadInvalidArgument);
+    };
+    let mut manager = trace_lock_unwrap!(manager);
+    let file_handle = manager.open_trust_list(session, writing, TRUST_LIST_ALL)?;
+    Ok(call_result(input_arguments.len(), vec![file_handle.into()]))
+}
+
+/// Handles OpenWithMasks on the trust list, which opens the lists in the mask for reading
+fn trust_list_open_with_masks(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 1)?;
+    let masks = uint32_argument(&input_arguments[0])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    let file_handle = manager.open_trust_list(session, false, masks)?;
+    Ok(call_result(input_arguments.len(), vec![file_handle.into()]))
+}
+
+/// Handles Close on the trust list, discarding anything written to the handle
+fn trust_list_close(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 1)?;
+    let file_handle = uint32_argument(&input_arguments[0])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    manager.trust_list_file(session, file_handle)?;
+    manager.trust_list_files.remove(&file_handle);
+    Ok(call_result(input_arguments.len(), Vec::new()))
+}
+
+/// Handles Read on the trust list, returning up to the requested number of bytes from the position
+fn trust_list_read(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 2)?;
+    let file_handle = uint32_argument(&input_arguments[0])?;
+    let length = int32_argument(&input_arguments[1])?;
+    if length < 0 {
+        return Err(BadInvalidArgument);
+    }
+    let mut manager = trace_lock_unwrap!(manager);
+    let file = manager.trust_list_file(session, file_handle)?;
+    if file.writing {
+        error!("Trust list file handle {} is not open for reading", file_handle);
+        return Err(BadInvalidState);
+    }
+    let start = file.position;
+    let end = (start + length as usize).min(file.data.len());
+    file.position = end;
+    Ok(call_result(input_arguments.len(), vec![ByteString::from(&file.data[start..end]).into()]))
+}
+
+/// Handles Write on the trust list, writing the bytes at the position
+fn trust_list_write(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 2)?;
+    let file_handle = uint32_argument(&input_arguments[0])?;
+    let data = byte_string_argument(&input_arguments[1])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    let file = manager.trust_list_file(session, file_handle)?;
+    if !file.writing {
+        error!("Trust list file handle {} is not open for writing", file_handle);
+        return Err(BadInvalidState);
+    }
+    if let Some(ref data) = data.value {
+        let end = file.position + data.len();
+        if end > MAX_TRUST_LIST_SIZE {
+            error!("Trust list file cannot be larger than {} bytes", MAX_TRUST_LIST_SIZE);
+            return Err(BadEncodingLimitsExceeded);
+        }
+        if end > file.data.len() {
+            file.data.resize(end, 0);
+        }
+        file.data[file.position..end].copy_from_slice(data);
+        file.position = end;
+    }
+    Ok(call_result(input_arguments.len(), Vec::new()))
+}
+
+/// Handles GetPosition on the trust list
+fn trust_list_get_position(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 1)?;
+    let file_handle = uint32_argument(&input_arguments[0])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    let file = manager.trust_list_file(session, file_handle)?;
+    Ok(call_result(input_arguments.len(), vec![Variant::UInt64(file.position as UInt64)]))
+}
+
+/// Handles SetPosition on the trust list. A position past the end moves to the end.
+fn trust_list_set_position(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 2)?;
+    let file_handle = uint32_argument(&input_arguments[0])?;
+    let position = uint64_argument(&input_arguments[1])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    let file = manager.trust_list_file(session, file_handle)?;
+    file.position = (position as usize).min(file.data.len());
+    Ok(call_result(input_arguments.len(), Vec::new()))
+}
+
+/// Handles CloseAndUpdate on the trust list, which replaces the lists in the written trust list
+fn trust_list_close_and_update(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 1)?;
+    let file_handle = uint32_argument(&input_arguments[0])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    if !manager.trust_list_file(session, file_handle)?.writing {
+        error!("Trust list file handle {} is not open for writing", file_handle);
+        return Err(BadInvalidState);
+    }
+    let file = manager.trust_list_files.remove(&file_handle).unwrap();
+    let trust_list = TrustListDataType::decode(&mut Cursor::new(&file.data)).map_err(|_| {
+        error!("Trust list file does not hold a trust list");
+        BadInvalidArgument
+    })?;
+    {
+        let certificate_store = trace_read_lock_unwrap!(manager.certificate_store);
+        certificate_store.update_trust_list(&trust_list)?;
+    }
+    manager.last_update_time = DateTime::now();
+    // The trust list is used as soon as it is written so there are no changes to apply
+    Ok(call_result(input_arguments.len(), vec![Variant::Boolean(false)]))
+}
+
+/// Handles AddCertificate on the trust list, which adds a cert to the trusted or issuers list
+fn trust_list_add_certificate(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 2)?;
+    let cert = X509::from_byte_string(&byte_string_argument(&input_arguments[0])?)?;
+    let is_trusted_certificate = boolean_argument(&input_arguments[1])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    if manager.is_trust_list_writing() {
+        error!("Certificate cannot be added while the trust list is open for writing");
+        return Err(BadInvalidState);
+    }
+    {
+        let certificate_store = trace_read_lock_unwrap!(manager.certificate_store);
+        let result = if is_trusted_certificate { certificate_store.store_trusted_cert(&cert) } else { certificate_store.store_issuer_cert(&cert) };
+        if let Err(err) = result {
+            error!("Certificate could not be added to the trust list, error = {}", err);
+            return Err(BadUnexpectedError);
+        }
+    }
+    manager.last_update_time = DateTime::now();
+    Ok(call_result(input_arguments.len(), Vec::new()))
+}
+
+/// Handles RemoveCertificate on the trust list, which removes the cert with the thumbprint from
+/// the trusted or issuers list
+fn trust_list_remove_certificate(manager: &Mutex<CertificateManager>, server_state: &ServerState, session: &Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    check_security_admin(server_state, session)?;
+    let input_arguments = input_arguments(request, 2)?;
+    let thumbprint = string_argument(&input_arguments[0])?;
+    let is_trusted_certificate = boolean_argument(&input_arguments[1])?;
+    let mut manager = trace_lock_unwrap!(manager);
+    if manager.is_trust_list_writing() {
+        error!("Certificate cannot be removed while the trust list is open for writing");
+        return Err(BadInvalidState);
+    }
+    let removed = {
+        let certificate_store = trace_read_lock_unwrap!(manager.certificate_store);
+        !thumbprint.is_null() && certificate_store.remove_cert(thumbprint.as_ref(), is_trusted_certificate)
+    };
+    if !removed {
+        error!("Trust list has no certificate with thumbprint {}", thumbprint);
+        return Err(BadInvalidArgument);
+    }
+    manager.last_update_time = DateTime::now();
+    Ok(call_result(input_arguments.len(), Vec::new()))
+}
+
+type CertificateManagerHandler = fn(&Mutex<CertificateManager>, &ServerState, &Session, &CallMethodRequest) -> Result<CallMethodResult, StatusCode>;
+
+impl AddressSpace {
+    /// Adds the ServerConfiguration object, with its default application certificate group and
+    /// trust list, to the Server object. The methods of the object manage the certificates held
+    /// in the certificate store.
+    pub fn add_server_configuration(&mut self, certificate_store: Arc<RwLock<CertificateStore>>) {
+        let manager = Arc::new(Mutex::new(CertificateManager::new(certificate_store)));
+        self.set_certificate_manager(manager.clone());
+
+        let server_configuration_id: NodeId = ObjectId::ServerConfiguration.into();
+        let certificate_groups_id: NodeId = ObjectId::ServerConfiguration_CertificateGroups.into();
+        let default_application_group_id: NodeId = ObjectId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup.into();
+        let trust_list_id: NodeId = ObjectId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList.into();
+
+        self.insert(Object::new(&server_configuration_id, "ServerConfiguration", "ServerConfiguration", ""), Some(&[
+            (&ObjectId::Server.into(), ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+            (&ObjectTypeId::ServerConfigurationType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        self.insert(Object::new(&certificate_groups_id, "CertificateGroups", "CertificateGroups", ""), Some(&[
+            (&server_configuration_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+            (&ObjectTypeId::CertificateGroupFolderType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        self.insert(Object::new(&default_application_group_id, "DefaultApplicationGroup", "DefaultApplicationGroup", ""), Some(&[
+            (&certificate_groups_id, ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+            (&ObjectTypeId::CertificateGroupType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        self.insert(Object::new(&trust_list_id, "TrustList", "TrustList", ""), Some(&[
+            (&default_application_group_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+            (&ObjectTypeId::TrustListType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+
+        // Properties
+        self.add_property(&server_configuration_id, Variable::new_array(&VariableId::ServerConfiguration_ServerCapabilities.into(), "ServerCapabilities", "ServerCapabilities", "",
+                                                                        DataTypeId::String, DataValue::new(Variant::Array(Vec::new())), &[0]));
+        self.add_property(&server_configuration_id, Variable::new_array(&VariableId::ServerConfiguration_SupportedPrivateKeyFormats.into(), "SupportedPrivateKeyFormats", "SupportedPrivateKeyFormats", "",
+                                                                        DataTypeId::String, DataValue::new(Variant::Array(vec![UAString::from(PRIVATE_KEY_FORMAT_PEM).into()])), &[1]));
+        self.add_property(&server_configuration_id, Variable::new(&VariableId::ServerConfiguration_MaxTrustListSize.into(), "MaxTrustListSize", "MaxTrustListSize", "", MAX_TRUST_LIST_SIZE as UInt32));
+        self.add_property(&server_configuration_id, Variable::new(&VariableId::ServerConfiguration_MulticastDnsEnabled.into(), "MulticastDnsEnabled", "MulticastDnsEnabled", "", false));
+        let certificate_type_id: NodeId = ObjectTypeId::RsaSha256ApplicationCertificateType.into();
+        self.add_property(&default_application_group_id, Variable::new_array(&VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_CertificateTypes.into(), "CertificateTypes", "CertificateTypes", "",
+                                                                             DataTypeId::NodeId, DataValue::new(Variant::Array(vec![certificate_type_id.into()])), &[1]));
+        self.add_property(&trust_list_id, Variable::new(&VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Writable.into(), "Writable", "Writable", "", true));
+        self.add_property(&trust_list_id, Variable::new(&VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_UserWritable.into(), "UserWritable", "UserWritable", "", true));
+        {
+            let mut size = Variable::new(&VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Size.into(), "Size", "Size", "", 0 as UInt64);
+            let manager = manager.clone();
+            size.set_value_getter(Arc::new(Mutex::new(AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+                let manager = trace_lock_unwrap!(manager);
+                let certificate_store = trace_read_lock_unwrap!(manager.certificate_store);
+                let size = certificate_store.read_trust_list(TRUST_LIST_ALL).byte_len() as UInt64;
+                Ok(Some(DataValue::new(size)))
+            }))));
+            self.add_property(&trust_list_id, size);
+        }
+        {
+            let mut open_count = Variable::new(&VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_OpenCount.into(), "OpenCount", "OpenCount", "", 0 as UInt16);
+            let manager = manager.clone();
+            open_count.set_value_getter(Arc::new(Mutex::new(AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+                let mut manager = trace_lock_unwrap!(manager);
+                Ok(Some(DataValue::new(manager.open_count() as UInt16)))
+            }))));
+            self.add_property(&trust_list_id, open_count);
+        }
+        {
+            let mut last_update_time = Variable::new(&VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_LastUpdateTime.into(), "LastUpdateTime", "LastUpdateTime", "", DateTime::now());
+            let manager = manager.clone();
+            last_update_time.set_value_getter(Arc::new(Mutex::new(AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+                let manager = trace_lock_unwrap!(manager);
+                Ok(Some(DataValue::new(manager.last_update_time.clone())))
+            }))));
+            self.add_property(&trust_list_id, last_update_time);
+        }
+
+        // Methods
+        let server_configuration_methods: [(MethodId, &str, Option<VariableId>, Option<VariableId>, CertificateManagerHandler); 4] = [
+            (MethodId::ServerConfiguration_UpdateCertificate, "UpdateCertificate",
+             Some(VariableId::ServerConfiguration_UpdateCertificate_InputArguments), Some(VariableId::ServerConfiguration_UpdateCertificate_OutputArguments), update_certificate),
+            (MethodId::ServerConfiguration_CreateSigningRequest, "CreateSigningRequest",
+             Some(VariableId::ServerConfiguration_CreateSigningRequest_InputArguments), Some(VariableId::ServerConfiguration_CreateSigningRequest_OutputArguments), create_signing_request),
+            (MethodId::ServerConfiguration_ApplyChanges, "ApplyChanges", None, None, apply_changes),
+            (MethodId::ServerConfiguration_GetRejectedList, "GetRejectedList",
+             None, Some(VariableId::ServerConfiguration_GetRejectedList_OutputArguments), get_rejected_list),
+        ];
+        for &(method_id, browse_name, input_arguments_id, output_arguments_id, handler) in server_configuration_methods.iter() {
+            self.add_server_configuration_method(&server_configuration_id, ObjectTypeId::ServerConfigurationType, method_id, browse_name, input_arguments_id, output_arguments_id, manager.clone(), handler);
+        }
+
+        let trust_list_methods: [(MethodId, &str, Option<VariableId>, Option<VariableId>, CertificateManagerHandler); 10] = [
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open, "Open",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open_InputArguments),
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open_OutputArguments), trust_list_open),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Close, "Close",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Close_InputArguments), None, trust_list_close),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Read, "Read",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Read_InputArguments),
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Read_OutputArguments), trust_list_read),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Write, "Write",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Write_InputArguments), None, trust_list_write),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_GetPosition, "GetPosition",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_GetPosition_InputArguments),
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_GetPosition_OutputArguments), trust_list_get_position),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_SetPosition, "SetPosition",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_SetPosition_InputArguments), None, trust_list_set_position),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_OpenWithMasks, "OpenWithMasks",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_OpenWithMasks_InputArguments),
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_OpenWithMasks_OutputArguments), trust_list_open_with_masks),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_CloseAndUpdate, "CloseAndUpdate",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_CloseAndUpdate_InputArguments),
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_CloseAndUpdate_OutputArguments), trust_list_close_and_update),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_AddCertificate, "AddCertificate",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_AddCertificate_InputArguments), None, trust_list_add_certificate),
+            (MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_RemoveCertificate, "RemoveCertificate",
+             Some(VariableId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_RemoveCertificate_InputArguments), None, trust_list_remove_certificate),
+        ];
+        for &(method_id, browse_name, input_arguments_id, output_arguments_id, handler) in trust_list_methods.iter() {
+            self.add_server_configuration_method(&trust_list_id, ObjectTypeId::TrustListType, method_id, browse_name, input_arguments_id, output_arguments_id, manager.clone(), handler);
+        }
+    }
+
+    /// Adds a property to a node
+    fn add_property(&mut self, parent_node_id: &NodeId, variable: Variable) {
+        let node_id = variable.node_id();
+        self.insert(variable, Some(&[
+            (parent_node_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+        ]));
+        self.set_variable_as_property_type(&node_id);
+    }
+
+    /// Adds a method to the object, copying its arguments from the method of the same name on the
+    /// object's type or a supertype, and registers the handler for it.
+    fn add_server_configuration_method(&mut self, object_id: &NodeId, object_type_id: ObjectTypeId, method_id: MethodId, browse_name: &str,
+                                       input_arguments_id: Option<VariableId>, output_arguments_id: Option<VariableId>,
+                                       manager: Arc<Mutex<CertificateManager>>, handler: CertificateManagerHandler) {
+        let method_node_id: NodeId = method_id.into();
+        self.insert(Method::new(&method_node_id, browse_name, browse_name, "", false, true, true), Some(&[
+            (object_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        ]));
+        let type_method_id = self.find_type_member(&object_type_id.into(), browse_name);
+        for &(arguments_id, arguments_name) in [(input_arguments_id, "InputArguments"), (output_arguments_id, "OutputArguments")].iter() {
+            if let Some(arguments_id) = arguments_id {
+                let value = type_method_id.as_ref()
+                    .and_then(|type_method_id| self.find_type_member(type_method_id, arguments_name))
+                    .and_then(|type_arguments_id| {
+                        if let Some(&NodeType::Variable(ref type_arguments)) = self.find_node(&type_arguments_id) {
+                            Some(type_arguments.value())
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or(DataValue::new(Variant::Array(Vec::new())));
+                let arguments = Variable::new_data_value(&arguments_id.into(), arguments_name, arguments_name, "", DataTypeId::Argument, value);
+                self.add_property(&method_node_id, arguments);
+            }
+        }
+        self.register_method_handler(object_id.clone(), method_node_id, Box::new(move |_: &AddressSpace, server_state: &ServerState, session: &Session, request: &CallMethodRequest| {
+            handler(&manager, server_state, session, request)
+        }));
+    }
+
+    /// Finds the child of a node with the browse name, looking through the supertypes of the node
+    /// if it is a type.
+    fn find_type_member(&self, node_id: &NodeId, browse_name: &str) -> Option<NodeId> {
+        let mut node_id = node_id.clone();
+        loop {
+            let member = self.find_references_from(&node_id, Some((ReferenceTypeId::HierarchicalReferences, true)))
+                .and_then(|references| {
+                    references.iter().find(|r| {
+                        self.find_node(&r.node_id).map_or(false, |n| n.as_node().browse_name().name.as_ref() == browse_name)
+                    }).map(|r| r.node_id.clone())
+                });
+            if member.is_some() {
+                return member;
+            }
+            let supertype = self.find_references_to(&node_id, Some((ReferenceTypeId::HasSubtype, false)))
+                .and_then(|references| references.first().map(|r| r.node_id.clone()));
+            if let Some(supertype) = supertype {
+                node_id = supertype;
+            } else {
+                return None;
+            }
+        }
+    }
+}
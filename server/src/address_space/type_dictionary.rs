@@ -0,0 +1,335 @@
+//! Custom structured and enumerated data types, and the binary type dictionary that describes
+//! them to clients.
+//!
+//! A structured type is a DataType node derived from Structure with a "Default Binary" encoding
+//! node. Values of the type are `ExtensionObject`s whose node id is the encoding node. Clients
+//! find how to decode them by following the encoding's HasDescription reference to a
+//! DataTypeDescription variable, whose value names the type in the DataTypeDictionary variable of
+//! its namespace. The dictionary holds an OPC Binary schema (Part 3 Annex C) of every custom type
+//! in the namespace and sits under the OPC Binary type system, like the dictionary of namespace 0.
+//!
+//! An enumerated type is a DataType node derived from Enumeration with an EnumStrings or
+//! EnumValues property. Values of the type are written as Int32.
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ObjectId, ObjectTypeId, ReferenceTypeId, VariableTypeId};
+use opcua_types::service_types::EnumValueType;
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use address_space::address_space::{AddressSpace, ReferenceDirection};
+use address_space::data_type::{DataType, DataTypeDefinition};
+use address_space::node::{Node, NodeType};
+use address_space::nodeset;
+use address_space::types::{Object, Variable};
+
+/// The OPC Binary names of the built-in types
+const BUILT_IN_TYPE_NAMES: [(DataTypeId, &'static str); 29] = [
+    (DataTypeId::Boolean, "opc:Boolean"),
+    (DataTypeId::SByte, "opc:SByte"),
+    (DataTypeId::Byte, "opc:Byte"),
+    (DataTypeId::Int16, "opc:Int16"),
+    (DataTypeId::UInt16, "opc:UInt16"),
+    (DataTypeId::Int32, "opc:Int32"),
+    (DataTypeId::UInt32, "opc:UInt32"),
+    (DataTypeId::Int64, "opc:Int64"),
+    (DataTypeId::UInt64, "opc:UInt64"),
+    (DataTypeId::Float, "opc:Float"),
+    (DataTypeId::Double, "opc:Double"),
+    (DataTypeId::String, "opc:String"),
+    (DataTypeId::DateTime, "opc:DateTime"),
+    (DataTypeId::Guid, "opc:Guid"),
+    (DataTypeId::ByteString, "opc:ByteString"),
+    (DataTypeId::XmlElement, "ua:XmlElement"),
+    (DataTypeId::NodeId, "ua:NodeId"),
+    (DataTypeId::ExpandedNodeId, "ua:ExpandedNodeId"),
+    (DataTypeId::StatusCode, "ua:StatusCode"),
+    (DataTypeId::QualifiedName, "ua:QualifiedName"),
+    (DataTypeId::LocalizedText, "ua:LocalizedText"),
+    (DataTypeId::Structure, "ua:ExtensionObject"),
+    (DataTypeId::DataValue, "ua:DataValue"),
+    (DataTypeId::BaseDataType, "ua:Variant"),
+    (DataTypeId::DiagnosticInfo, "ua:DiagnosticInfo"),
+    (DataTypeId::Number, "ua:Variant"),
+    (DataTypeId::Integer, "ua:Variant"),
+    (DataTypeId::UInteger, "ua:Variant"),
+    (DataTypeId::Enumeration, "opc:Int32"),
+];
+
+/// The node id of the DataTypeDictionary variable of a namespace
+pub fn type_dictionary_id(namespace: UInt16) -> NodeId {
+    NodeId::new(namespace, UAString::from("TypeDictionary"))
+}
+
+fn type_dictionary_child_id(namespace: UInt16, name: &str) -> NodeId {
+    NodeId::new(namespace, UAString::from(format!("TypeDictionary.{}", name)))
+}
+
+impl AddressSpace {
+    /// Adds a structured data type with the fields of the definition, derived from Structure.
+    /// The type's "Default Binary" encoding node is given the binary encoding id, which is the
+    /// node id of `ExtensionObject` values of the type, e.g. those made with
+    /// `ExtensionObject::from_encodable()`. The type is added to the binary type dictionary of
+    /// its namespace so clients can decode its values.
+    ///
+    /// The namespace of the node id must be registered and the data type of each field must be
+    /// in the address space.
+    pub fn add_structure_data_type<R>(&mut self, node_id: &NodeId, browse_name: R, binary_encoding_id: &NodeId, definition: DataTypeDefinition) -> Result<(), StatusCode> where R: Into<QualifiedName> {
+        let browse_name = browse_name.into();
+        let namespace = node_id.namespace;
+        self.check_new_data_type(node_id, &definition)?;
+        if self.node_exists(binary_encoding_id) {
+            error!("Encoding node {:?} already exists", binary_encoding_id);
+            return Err(BadNodeIdExists);
+        }
+        if let Some(field) = definition.fields.iter().find(|f| !self.node_exists(&f.data_type)) {
+            error!("Field {} of data type {:?} has an unknown data type {:?}", field.name, node_id, field.data_type);
+            return Err(BadDataTypeIdUnknown);
+        }
+
+        let name = browse_name.name.as_ref().to_string();
+        self.add_data_type_node(node_id, browse_name, DataTypeId::Structure, definition);
+
+        // The description of the type in the dictionary and the encoding that refers to it
+        self.ensure_type_dictionary(namespace);
+        let description_id = type_dictionary_child_id(namespace, &name);
+        let dictionary_id = type_dictionary_id(namespace);
+        self.insert(Variable::new(&description_id, (namespace, name.as_ref()), &name, "", UAString::from(name.as_ref())), Some(&[
+            (&dictionary_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+            (&VariableTypeId::DataTypeDescriptionType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        self.insert(Object::new(binary_encoding_id, "Default Binary", "Default Binary", ""), Some(&[
+            (node_id, ReferenceTypeId::HasEncoding, ReferenceDirection::Inverse),
+            (&description_id, ReferenceTypeId::HasDescription, ReferenceDirection::Forward),
+            (&ObjectTypeId::DataTypeEncodingType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        self.update_type_dictionary(namespace)
+    }
+
+    /// Adds an enumerated data type with the values of the definition, derived from Enumeration.
+    /// Fields without a value are numbered by their position. The names are exposed through an
+    /// EnumStrings property when the values are 0, 1, 2... and otherwise through an EnumValues
+    /// property. The type is added to the binary type dictionary of its namespace.
+    ///
+    /// The namespace of the node id must be registered.
+    pub fn add_enumeration_data_type<R>(&mut self, node_id: &NodeId, browse_name: R, definition: DataTypeDefinition) -> Result<(), StatusCode> where R: Into<QualifiedName> {
+        let browse_name = browse_name.into();
+        let namespace = node_id.namespace;
+        self.check_new_data_type(node_id, &definition)?;
+
+        let mut definition = definition;
+        definition.fields.iter_mut().enumerate().for_each(|(i, f)| {
+            if f.value.is_none() {
+                f.value = Some(i as Int32);
+            }
+            f.data_type = DataTypeId::Int32.into();
+            f.value_rank = -1;
+        });
+
+        // Names of sequential values from zero go in EnumStrings, anything else in EnumValues
+        let is_sequential = definition.fields.iter().enumerate().all(|(i, f)| f.value == Some(i as Int32));
+        let (property_name, property_data_type, property_value) = if is_sequential {
+            let values = definition.fields.iter().map(|f| Variant::from(LocalizedText::new("", &f.name))).collect::<Vec<Variant>>();
+            ("EnumStrings", DataTypeId::LocalizedText, values)
+        } else {
+            let values = definition.fields.iter().map(|f| {
+                Variant::from(ExtensionObject::from_encodable(ObjectId::EnumValueType_Encoding_DefaultBinary, EnumValueType {
+                    value: f.value.unwrap() as Int64,
+                    display_name: LocalizedText::new("", &f.name),
+                    description: f.description.clone().unwrap_or_else(|| LocalizedText::new("", "")),
+                }))
+            }).collect::<Vec<Variant>>();
+            ("EnumValues", DataTypeId::EnumValueType, values)
+        };
+        let property_count = property_value.len() as UInt32;
+
+        let name = browse_name.name.as_ref().to_string();
+        self.add_data_type_node(node_id, browse_name, DataTypeId::Enumeration, definition);
+
+        let property_id = NodeId::new(namespace, UAString::from(format!("{}.{}", name, property_name)));
+        self.insert(Variable::new_array(&property_id, property_name, property_name, "", property_data_type, DataValue::new(property_value), &[property_count]), Some(&[
+            (node_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+            (&VariableTypeId::PropertyType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+
+        self.ensure_type_dictionary(namespace);
+        self.update_type_dictionary(namespace)
+    }
+
+    /// Returns the OPC Binary schema in the type dictionary of a namespace, if it has one
+    pub fn type_dictionary(&self, namespace: UInt16) -> Option<String> {
+        if let Some(&NodeType::Variable(ref variable)) = self.find_node(&type_dictionary_id(namespace)) {
+            if let Some(Variant::ByteString(dictionary)) = variable.value().value {
+                return dictionary.value.and_then(|v| String::from_utf8(v).ok());
+            }
+        }
+        None
+    }
+
+    fn check_new_data_type(&self, node_id: &NodeId, definition: &DataTypeDefinition) -> Result<(), StatusCode> {
+        if node_id.namespace == 0 || self.namespace_uri(node_id.namespace).is_none() {
+            error!("Data type {:?} must be in a registered namespace other than 0", node_id);
+            Err(BadNodeIdInvalid)
+        } else if self.node_exists(node_id) {
+            error!("Data type {:?} already exists", node_id);
+            Err(BadNodeIdExists)
+        } else if definition.fields.is_empty() {
+            error!("Data type {:?} has no fields", node_id);
+            Err(BadInvalidArgument)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn add_data_type_node(&mut self, node_id: &NodeId, browse_name: QualifiedName, supertype_id: DataTypeId, mut definition: DataTypeDefinition) {
+        if definition.name.is_null() {
+            definition.name = browse_name.clone();
+        }
+        let name = browse_name.name.as_ref().to_string();
+        let mut data_type = DataType::new(node_id, browse_name, &name, "", false);
+        data_type.set_definition(definition);
+        self.insert(data_type, Some(&[
+            (&supertype_id.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+        ]));
+    }
+
+    /// Adds the DataTypeDictionary variable of a namespace if it does not exist yet
+    fn ensure_type_dictionary(&mut self, namespace: UInt16) {
+        let dictionary_id = type_dictionary_id(namespace);
+        if self.node_exists(&dictionary_id) {
+            return;
+        }
+        let namespace_uri = self.namespace_uri(namespace).unwrap_or("").to_string();
+        self.insert(Variable::new_data_value(&dictionary_id, (namespace, "TypeDictionary"), "TypeDictionary", "", DataTypeId::ByteString, DataValue::new(ByteString::null())), Some(&[
+            (&ObjectId::OPCBinarySchema_TypeSystem.into(), ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+            (&VariableTypeId::DataTypeDictionaryType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        let namespace_uri_id = type_dictionary_child_id(namespace, "NamespaceUri");
+        self.insert(Variable::new(&namespace_uri_id, "NamespaceUri", "NamespaceUri", "", UAString::from(namespace_uri)), Some(&[
+            (&dictionary_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+            (&VariableTypeId::PropertyType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+    }
+
+    /// Writes the OPC Binary schema of the structured and enumerated types of a namespace into
+    /// its DataTypeDictionary variable
+    fn update_type_dictionary(&mut self, namespace: UInt16) -> Result<(), StatusCode> {
+        let dictionary = self.make_type_dictionary(namespace)?;
+        let mut buffer = b"<?xml version=\"1.0\" encoding=\"utf-8\"?>\n".to_vec();
+        nodeset::write_element(&mut buffer, &dictionary, 0).map_err(|_| BadEncodingError)?;
+        let now = DateTime::now();
+        if let Some(&mut NodeType::Variable(ref mut variable)) = self.find_node_mut(&type_dictionary_id(namespace)) {
+            variable.set_value_direct(&now, ByteString::from(buffer));
+        }
+        Ok(())
+    }
+
+    fn make_type_dictionary(&self, namespace: UInt16) -> Result<XmlNode, StatusCode> {
+        let namespace_uri = self.namespace_uri(namespace).unwrap_or("").to_string();
+        let mut dictionary = XmlNode::new("TypeDictionary");
+        dictionary.prefix = Some("opc".to_string());
+        {
+            let attributes = &mut dictionary.attributes;
+            attributes.insert("xmlns:opc".to_string(), BINARY_SCHEMA_NAMESPACE.to_string());
+            attributes.insert("xmlns:ua".to_string(), UA_TYPES_NAMESPACE.to_string());
+            attributes.insert("xmlns:tns".to_string(), namespace_uri.clone());
+            attributes.insert("DefaultByteOrder".to_string(), "LittleEndian".to_string());
+            attributes.insert("TargetNamespace".to_string(), namespace_uri);
+        }
+        let mut import = binary_schema_element("Import");
+        import.attributes.insert("Namespace".to_string(), UA_TYPES_NAMESPACE.to_string());
+        dictionary.children.push(import);
+
+        // Types are written in the order of their names so the dictionary is stable
+        let mut data_types = self.find_nodes_in_namespace(namespace).into_iter().filter_map(|node| {
+            if let NodeType::DataType(ref data_type) = *node {
+                data_type.definition().map(|definition| (data_type.node_id(), data_type.browse_name(), definition))
+            } else {
+                None
+            }
+        }).collect::<Vec<_>>();
+        data_types.sort_by(|a, b| a.1.name.as_ref().cmp(b.1.name.as_ref()));
+
+        for (node_id, browse_name, definition) in data_types {
+            let name = browse_name.name.as_ref().to_string();
+            if self.is_subtype_of(&node_id, &DataTypeId::Enumeration.into()) {
+                let mut e = binary_schema_element("EnumeratedType");
+                e.attributes.insert("Name".to_string(), name);
+                e.attributes.insert("LengthInBits".to_string(), "32".to_string());
+                definition.fields.iter().enumerate().for_each(|(i, f)| {
+                    let mut v = binary_schema_element("EnumeratedValue");
+                    v.attributes.insert("Name".to_string(), f.name.clone());
+                    v.attributes.insert("Value".to_string(), f.value.unwrap_or(i as Int32).to_string());
+                    e.children.push(v);
+                });
+                dictionary.children.push(e);
+            } else if self.is_subtype_of(&node_id, &DataTypeId::Structure.into()) {
+                let mut e = binary_schema_element("StructuredType");
+                e.attributes.insert("Name".to_string(), name);
+                e.attributes.insert("BaseType".to_string(), "ua:ExtensionObject".to_string());
+                // A union is preceded by a switch field holding the 1 based index of the field
+                // that is encoded
+                if definition.is_union {
+                    let mut switch = binary_schema_element("Field");
+                    switch.attributes.insert("Name".to_string(), "SwitchField".to_string());
+                    switch.attributes.insert("TypeName".to_string(), "opc:UInt32".to_string());
+                    e.children.push(switch);
+                }
+                for (i, field) in definition.fields.iter().enumerate() {
+                    let type_name = self.binary_schema_type_name(&field.data_type, namespace).ok_or_else(|| {
+                        error!("Field {} of data type {:?} has no type in the binary schema", field.name, node_id);
+                        BadDataTypeIdUnknown
+                    })?;
+                    // Arrays are preceded by their length
+                    if field.value_rank >= 0 {
+                        let mut length = binary_schema_element("Field");
+                        length.attributes.insert("Name".to_string(), format!("NoOf{}", field.name));
+                        length.attributes.insert("TypeName".to_string(), "opc:Int32".to_string());
+                        e.children.push(length);
+                    }
+                    let mut f = binary_schema_element("Field");
+                    f.attributes.insert("Name".to_string(), field.name.clone());
+                    f.attributes.insert("TypeName".to_string(), type_name);
+                    if field.value_rank >= 0 {
+                        f.attributes.insert("LengthField".to_string(), format!("NoOf{}", field.name));
+                    }
+                    if definition.is_union {
+                        f.attributes.insert("SwitchField".to_string(), "SwitchField".to_string());
+                        f.attributes.insert("SwitchValue".to_string(), (i + 1).to_string());
+                    }
+                    e.children.push(f);
+                }
+                dictionary.children.push(e);
+            }
+        }
+        Ok(dictionary)
+    }
+
+    /// Finds the name of a data type in the binary schema of a namespace. Structures and
+    /// enumerations are named after their browse name when they are in namespace 0 or the
+    /// dictionary's namespace, other types by the built-in type they derive from.
+    fn binary_schema_type_name(&self, data_type: &NodeId, namespace: UInt16) -> Option<String> {
+        let is_structure_or_enumeration = *data_type != DataTypeId::Structure.into() && *data_type != DataTypeId::Enumeration.into() &&
+            (self.is_subtype_of(data_type, &DataTypeId::Structure.into()) || self.is_subtype_of(data_type, &DataTypeId::Enumeration.into()));
+        if is_structure_or_enumeration && (data_type.namespace == 0 || data_type.namespace == namespace) {
+            let prefix = if data_type.namespace == 0 { "ua" } else { "tns" };
+            let node = self.find_node(data_type)?;
+            return Some(format!("{}:{}", prefix, node.as_node().browse_name().name.as_ref()));
+        }
+        let mut type_id = data_type.clone();
+        // The depth limit guards against a cycle of subtypes
+        for _ in 0..32 {
+            if let Some(&(_, name)) = BUILT_IN_TYPE_NAMES.iter().find(|&&(id, _)| { let id: NodeId = id.into(); id == type_id }) {
+                return Some(name.to_string());
+            }
+            type_id = self.references_to(&type_id, Some((ReferenceTypeId::HasSubtype, false))).next()?.1.clone();
+        }
+        None
+    }
+}
+
+fn binary_schema_element(name: &str) -> XmlNode {
+    let mut e = XmlNode::new(name);
+    e.prefix = Some("opc".to_string());
+    e
+}
@@ -0,0 +1,230 @@
+//! Checks that values written to a variable agree with its DataType, ValueRank and
+//! ArrayDimensions attributes.
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ReferenceTypeId};
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use address_space::address_space::AddressSpace;
+
+/// The kind of value a data type accepts, found from the data type or the nearest of its
+/// supertypes that is a built-in or abstract type of namespace 0.
+#[derive(Debug, Clone, PartialEq)]
+enum ValueType {
+    /// BaseDataType, or a type whose values cannot be checked
+    Any,
+    /// Any numeric value
+    Number,
+    /// Any signed integer
+    Integer,
+    /// Any unsigned integer
+    UInteger,
+    /// A built-in type, or a type derived from it, e.g. a Duration is a Double
+    BuiltIn(VariantTypeId),
+    /// A structure encoded with one of the encodings, or any structure if there are none
+    Structure(Vec<NodeId>),
+}
+
+/// Tests if an array with the number of dimensions is acceptable for the value rank, where
+/// scalars have no dimensions.
+pub fn value_rank_matches(value_rank: Int32, dimensions: usize) -> bool {
+    match value_rank {
+        // ScalarOrOneDimension
+        -3 => dimensions <= 1,
+        // Any
+        -2 => true,
+        // Scalar
+        -1 => dimensions == 0,
+        // OneOrMoreDimensions
+        0 => dimensions > 0,
+        value_rank => value_rank > 0 && dimensions == value_rank as usize,
+    }
+}
+
+/// The constraints that the DataType, ValueRank and ArrayDimensions of a variable place on its
+/// value.
+///
+/// Values written through the attribute service are checked before they are set on the node,
+/// so an `AttributeSetter` receives values that already satisfy them. Setters and other code
+/// that obtain values from elsewhere can create the constraints from the address space and
+/// apply the same checks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueConstraints {
+    value_type: ValueType,
+    value_rank: Int32,
+    array_dimensions: Option<Vec<UInt32>>,
+}
+
+impl ValueConstraints {
+    /// Creates the constraints of a data type, value rank and array dimensions. The data type is
+    /// resolved through the address space to the built-in or abstract type it derives from.
+    pub fn new(address_space: &AddressSpace, data_type: &NodeId, value_rank: Int32, array_dimensions: Option<&[UInt32]>) -> ValueConstraints {
+        ValueConstraints {
+            value_type: Self::value_type(address_space, data_type),
+            value_rank,
+            array_dimensions: array_dimensions.map(|d| d.to_vec()),
+        }
+    }
+
+    fn value_type(address_space: &AddressSpace, data_type: &NodeId) -> ValueType {
+        let value_types = [
+            (DataTypeId::Boolean, ValueType::BuiltIn(VariantTypeId::Boolean)),
+            (DataTypeId::SByte, ValueType::BuiltIn(VariantTypeId::SByte)),
+            (DataTypeId::Byte, ValueType::BuiltIn(VariantTypeId::Byte)),
+            (DataTypeId::Int16, ValueType::BuiltIn(VariantTypeId::Int16)),
+            (DataTypeId::UInt16, ValueType::BuiltIn(VariantTypeId::UInt16)),
+            (DataTypeId::Int32, ValueType::BuiltIn(VariantTypeId::Int32)),
+            (DataTypeId::UInt32, ValueType::BuiltIn(VariantTypeId::UInt32)),
+            (DataTypeId::Int64, ValueType::BuiltIn(VariantTypeId::Int64)),
+            (DataTypeId::UInt64, ValueType::BuiltIn(VariantTypeId::UInt64)),
+            (DataTypeId::Float, ValueType::BuiltIn(VariantTypeId::Float)),
+            (DataTypeId::Double, ValueType::BuiltIn(VariantTypeId::Double)),
+            (DataTypeId::String, ValueType::BuiltIn(VariantTypeId::String)),
+            (DataTypeId::DateTime, ValueType::BuiltIn(VariantTypeId::DateTime)),
+            (DataTypeId::Guid, ValueType::BuiltIn(VariantTypeId::Guid)),
+            (DataTypeId::ByteString, ValueType::BuiltIn(VariantTypeId::ByteString)),
+            (DataTypeId::XmlElement, ValueType::BuiltIn(VariantTypeId::XmlElement)),
+            (DataTypeId::NodeId, ValueType::BuiltIn(VariantTypeId::NodeId)),
+            (DataTypeId::ExpandedNodeId, ValueType::BuiltIn(VariantTypeId::ExpandedNodeId)),
+            (DataTypeId::StatusCode, ValueType::BuiltIn(VariantTypeId::StatusCode)),
+            (DataTypeId::QualifiedName, ValueType::BuiltIn(VariantTypeId::QualifiedName)),
+            (DataTypeId::LocalizedText, ValueType::BuiltIn(VariantTypeId::LocalizedText)),
+            (DataTypeId::DataValue, ValueType::BuiltIn(VariantTypeId::DataValue)),
+            (DataTypeId::Number, ValueType::Number),
+            (DataTypeId::Integer, ValueType::Integer),
+            (DataTypeId::UInteger, ValueType::UInteger),
+            // Enumerations are written as their Int32 value
+            (DataTypeId::Enumeration, ValueType::BuiltIn(VariantTypeId::Int32)),
+            (DataTypeId::BaseDataType, ValueType::Any),
+        ];
+
+        let structure_id: NodeId = DataTypeId::Structure.into();
+        let mut type_id = data_type.clone();
+        // The depth limit guards against a cycle of subtypes
+        for _ in 0..32 {
+            if type_id == structure_id {
+                return if *data_type == structure_id {
+                    ValueType::Structure(Vec::new())
+                } else {
+                    ValueType::Structure(Self::structure_encodings(address_space, data_type))
+                };
+            }
+            if let Some(&(_, ref value_type)) = value_types.iter().find(|&&(id, _)| { let id: NodeId = id.into(); id == type_id }) {
+                return value_type.clone();
+            }
+            type_id = if let Some((_, supertype_id)) = address_space.references_to(&type_id, Some((ReferenceTypeId::HasSubtype, false))).next() {
+                supertype_id.clone()
+            } else {
+                break;
+            };
+        }
+        ValueType::Any
+    }
+
+    /// Finds the encodings of a structure and the structures derived from it
+    fn structure_encodings(address_space: &AddressSpace, data_type: &NodeId) -> Vec<NodeId> {
+        let mut encodings = Vec::new();
+        let mut data_types = vec![data_type.clone()];
+        let mut visited = 0;
+        while let Some(data_type) = data_types.pop() {
+            visited += 1;
+            if visited > 1000 {
+                break;
+            }
+            encodings.extend(address_space.references_from(&data_type, Some((ReferenceTypeId::HasEncoding, false))).map(|(_, node_id)| node_id.clone()));
+            data_types.extend(address_space.references_from(&data_type, Some((ReferenceTypeId::HasSubtype, false))).map(|(_, node_id)| node_id.clone()));
+        }
+        encodings
+    }
+
+    /// Checks a value against the constraints, returning the value converted to the data type
+    /// where Part 4 allows an implicit conversion, e.g. an Int32 written to a Byte variable.
+    ///
+    /// A null value is accepted. A value of the wrong type or value rank is `BadTypeMismatch`.
+    /// Integers outside the range of the data type and arrays longer than the array dimensions
+    /// are `BadOutOfRange`.
+    pub fn check(&self, value: &Variant) -> Result<Variant, StatusCode> {
+        match *value {
+            Variant::Empty => Ok(Variant::Empty),
+            Variant::Array(ref values) => {
+                self.check_shape(&[values.len()])?;
+                Ok(Variant::Array(self.check_values(values)?))
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                let dimensions = mda.dimensions.iter().map(|d| *d as usize).collect::<Vec<usize>>();
+                self.check_shape(&dimensions)?;
+                Ok(Variant::new_multi_dimension_array(self.check_values(&mda.values)?, mda.dimensions.clone()))
+            }
+            _ => {
+                self.check_shape(&[])?;
+                self.check_scalar(value)
+            }
+        }
+    }
+
+    /// Checks the value of a data value, returning the data value with the converted value
+    pub fn check_data_value(&self, mut data_value: DataValue) -> Result<DataValue, StatusCode> {
+        if let Some(value) = data_value.value.take() {
+            data_value.value = Some(self.check(&value)?);
+        }
+        Ok(data_value)
+    }
+
+    fn check_shape(&self, dimensions: &[usize]) -> Result<(), StatusCode> {
+        if !value_rank_matches(self.value_rank, dimensions.len()) {
+            error!("Value with {} dimensions does not match value rank {}", dimensions.len(), self.value_rank);
+            return Err(BadTypeMismatch);
+        }
+        // Array dimensions are the maximum length of each dimension, or 0 if it is not limited
+        if let Some(ref array_dimensions) = self.array_dimensions {
+            if array_dimensions.len() == dimensions.len() && array_dimensions.iter().zip(dimensions.iter()).any(|(max, actual)| *max != 0 && *actual > *max as usize) {
+                error!("Value with dimensions {:?} exceeds array dimensions {:?}", dimensions, array_dimensions);
+                return Err(BadOutOfRange);
+            }
+        }
+        Ok(())
+    }
+
+    fn check_values(&self, values: &[Variant]) -> Result<Vec<Variant>, StatusCode> {
+        values.iter().map(|v| {
+            if v.is_array() {
+                // Nested arrays are not allowed
+                Err(BadTypeMismatch)
+            } else {
+                self.check_scalar(v)
+            }
+        }).collect()
+    }
+
+    fn check_scalar(&self, value: &Variant) -> Result<Variant, StatusCode> {
+        let type_matches = match (&self.value_type, value) {
+            (_, &Variant::Empty) | (&ValueType::Any, _) => true,
+            (&ValueType::Number, value) => value.is_numeric(),
+            (&ValueType::Integer, value) => match value.type_id() {
+                VariantTypeId::SByte | VariantTypeId::Int16 | VariantTypeId::Int32 | VariantTypeId::Int64 => true,
+                _ => false
+            },
+            (&ValueType::UInteger, value) => match value.type_id() {
+                VariantTypeId::Byte | VariantTypeId::UInt16 | VariantTypeId::UInt32 | VariantTypeId::UInt64 => true,
+                _ => false
+            },
+            (&ValueType::Structure(ref encodings), &Variant::ExtensionObject(ref extension_object)) => {
+                encodings.is_empty() || encodings.contains(&extension_object.node_id)
+            }
+            (&ValueType::Structure(_), _) => false,
+            (&ValueType::BuiltIn(variant_type), value) => {
+                return value.convert(variant_type).map_err(|err| {
+                    error!("Value {:?} cannot be converted to {:?}, {:?}", value, variant_type, err);
+                    err
+                });
+            }
+        };
+        if type_matches {
+            Ok(value.clone())
+        } else {
+            error!("Value {:?} does not match the data type {:?}", value, self.value_type);
+            Err(BadTypeMismatch)
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Values of variables that are read asynchronously from a slow source, such as a device on a
+//! fieldbus.
+//!
+//! A variable with a value source holds the last value that was read from it, and getting the
+//! value never waits for the source. Instead, a Read refreshes the value before it locks the
+//! address space when the value is older than the request's `max_age`, and monitored items start
+//! a refresh in the background when the value is older than their sampling interval. When a
+//! refresh fails or does not complete within the timeout, the last value is reported with the
+//! status `UncertainLastUsableValue`, or the error, e.g. `BadTimeout`, if there is no value yet.
+
+use std::fmt;
+use std::cmp;
+use std::sync::{Arc, Mutex, Condvar};
+use std::time::{Duration as StdDuration, Instant};
+
+use futures::Future;
+use futures_cpupool::{CpuPool, Builder as CpuPoolBuilder};
+use tokio_timer;
+
+use opcua_types::{NodeId, AttributeId, DataValue, DateTime, Duration};
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use address_space::AttributeGetter;
+
+/// The future of a value that is read from a source, or the reason that the read failed
+pub type ValueFuture = Box<Future<Item = DataValue, Error = StatusCode> + Send>;
+
+/// A source of the value of a variable that takes time to read
+pub trait AsyncValueSource {
+    /// Starts a read of the value of the node. The future is polled on a pool of threads that
+    /// is shared by all value sources and dropped if it does not complete within the timeout, so
+    /// it should not block. A source that can only be read by blocking should do so on a thread
+    /// of its own, e.g. with a `CpuPool`, and return a future of the result.
+    fn read(&mut self, node_id: &NodeId) -> ValueFuture;
+}
+
+/// An implementation of value source that can be easily constructed from a mutable function
+pub struct AsyncFnValueSource<F> where F: FnMut(&NodeId) -> ValueFuture + Send {
+    source: F
+}
+
+impl<F> AsyncValueSource for AsyncFnValueSource<F> where F: FnMut(&NodeId) -> ValueFuture + Send {
+    fn read(&mut self, node_id: &NodeId) -> ValueFuture {
+        (self.source)(node_id)
+    }
+}
+
+impl<F> AsyncFnValueSource<F> where F: FnMut(&NodeId) -> ValueFuture + Send {
+    pub fn new(source: F) -> AsyncFnValueSource<F> { AsyncFnValueSource { source } }
+}
+
+/// The longest timeout that a refresh is abandoned after
+const MAX_TIMEOUT_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// The pool that refreshes are polled on and the timer of their deadlines, which are shared by
+/// all value sources
+struct RefreshExecutor {
+    pool: CpuPool,
+    timer: tokio_timer::Timer,
+}
+
+lazy_static! {
+    static ref REFRESH_EXECUTOR: RefreshExecutor = RefreshExecutor {
+        pool: CpuPoolBuilder::new().name_prefix("opcua-value-source-").create(),
+        timer: tokio_timer::wheel()
+            .tick_duration(StdDuration::from_millis(10))
+            .max_timeout(StdDuration::from_millis(MAX_TIMEOUT_MS))
+            .build(),
+    };
+}
+
+/// A read of the source that is in progress
+struct Refresh {
+    generation: u64,
+    started: Instant,
+}
+
+struct ValueState {
+    /// The last value that was read
+    value: Option<DataValue>,
+    /// The time that the last value was read
+    read_time: Option<Instant>,
+    /// The refresh that is in progress
+    refresh: Option<Refresh>,
+    /// Increments for each refresh, so the result of an abandoned refresh is ignored
+    generation: u64,
+    /// The error of the last refresh, if it failed
+    error: Option<StatusCode>,
+}
+
+/// The value of a variable that is read from an `AsyncValueSource`. It is the variable's value
+/// getter, which returns the last value read, and it refreshes the value on request.
+#[derive(Clone)]
+pub struct AsyncValue {
+    node_id: NodeId,
+    source: Arc<Mutex<AsyncValueSource + Send>>,
+    timeout: StdDuration,
+    state: Arc<(Mutex<ValueState>, Condvar)>,
+}
+
+impl fmt::Debug for AsyncValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AsyncValue {{ node_id: {:?}, timeout: {:?} }}", self.node_id, self.timeout)
+    }
+}
+
+impl AttributeGetter for AsyncValue {
+    fn get(&mut self, _: NodeId, _: AttributeId) -> Result<Option<DataValue>, StatusCode> {
+        Ok(Some(self.value()))
+    }
+}
+
+impl AsyncValue {
+    /// Creates the value of a node that is read from the source, where a read that takes longer
+    /// than the timeout in milliseconds is abandoned
+    pub fn new(node_id: &NodeId, source: Arc<Mutex<AsyncValueSource + Send>>, timeout: Duration) -> AsyncValue {
+        AsyncValue {
+            node_id: node_id.clone(),
+            source,
+            timeout: Self::std_duration(timeout),
+            state: Arc::new((Mutex::new(ValueState {
+                value: None,
+                read_time: None,
+                refresh: None,
+                generation: 0,
+                error: None,
+            }), Condvar::new())),
+        }
+    }
+
+    /// Returns the last value that was read. If the last refresh failed or has timed out, the
+    /// value has the status `UncertainLastUsableValue`, or if there is no value, it is only the
+    /// error.
+    pub fn value(&self) -> DataValue {
+        let state = trace_lock_unwrap!(self.state.0);
+        let timed_out = state.refresh.as_ref().map_or(false, |refresh| refresh.started.elapsed() >= self.timeout);
+        let error = if timed_out { Some(BadTimeout) } else { state.error };
+        match (state.value.as_ref(), error) {
+            (Some(value), None) => value.clone(),
+            (Some(value), Some(_)) => {
+                let mut value = value.clone();
+                value.status = Some(UncertainLastUsableValue);
+                value
+            }
+            (None, Some(error)) => Self::status_value(error),
+            (None, None) => Self::status_value(BadWaitingForInitialData),
+        }
+    }
+
+    /// Starts a refresh of the value in the background if it is older than the max age in
+    /// milliseconds, unless a refresh is already in progress
+    pub fn refresh(&self, max_age: Duration) {
+        let generation = {
+            let mut state = trace_lock_unwrap!(self.state.0);
+            let is_fresh = state.read_time.map_or(false, |read_time| read_time.elapsed() <= Self::std_duration(max_age));
+            let is_refreshing = state.refresh.as_ref().map_or(false, |refresh| refresh.started.elapsed() < self.timeout);
+            if is_fresh || is_refreshing {
+                return;
+            }
+            // A refresh that has timed out is abandoned, and the value stays uncertain until one
+            // succeeds
+            if state.refresh.is_some() {
+                state.error = Some(BadTimeout);
+            }
+            state.generation += 1;
+            state.refresh = Some(Refresh {
+                generation: state.generation,
+                started: Instant::now(),
+            });
+            state.generation
+        };
+
+        let future = {
+            let mut source = trace_lock_unwrap!(self.source);
+            source.read(&self.node_id)
+        };
+        // The read is dropped when the deadline passes, so a source that hangs does not hold on
+        // to anything after it is abandoned
+        let deadline = REFRESH_EXECUTOR.timer.sleep(cmp::min(self.timeout, StdDuration::from_millis(MAX_TIMEOUT_MS)))
+            .then(|_| Err::<DataValue, StatusCode>(BadTimeout));
+        let state = self.state.clone();
+        let refresh = future.select(deadline)
+            .then(move |result| {
+                let result = result.map(|(value, _)| value).map_err(|(status_code, _)| status_code);
+                Self::complete_refresh(&state, generation, result);
+                Ok::<(), ()>(())
+            });
+        REFRESH_EXECUTOR.pool.spawn(refresh).forget();
+    }
+
+    /// Refreshes the values that are older than the max age in milliseconds in parallel and waits
+    /// until each refresh completes or times out
+    pub fn refresh_all(values: &[AsyncValue], max_age: Duration) {
+        values.iter().for_each(|value| value.refresh(max_age));
+        values.iter().for_each(|value| value.wait());
+    }
+
+    /// Waits for the refresh in progress until it completes or times out
+    fn wait(&self) {
+        let (ref lock, ref condvar) = *self.state;
+        let mut state = trace_lock_unwrap!(lock);
+        loop {
+            let remaining = if let Some(ref refresh) = state.refresh {
+                let elapsed = refresh.started.elapsed();
+                if elapsed >= self.timeout {
+                    break;
+                }
+                self.timeout - elapsed
+            } else {
+                break;
+            };
+            state = condvar.wait_timeout(state, remaining).unwrap().0;
+        }
+    }
+
+    fn complete_refresh(state: &Arc<(Mutex<ValueState>, Condvar)>, generation: u64, result: Result<DataValue, StatusCode>) {
+        let (ref lock, ref condvar) = **state;
+        let mut state = trace_lock_unwrap!(lock);
+        let is_current = state.refresh.as_ref().map_or(false, |refresh| refresh.generation == generation);
+        if !is_current {
+            trace!("Ignoring the result of abandoned refresh {}", generation);
+            return;
+        }
+        state.refresh = None;
+        match result {
+            Ok(mut value) => {
+                if value.server_timestamp.is_none() {
+                    value.server_timestamp = Some(DateTime::now());
+                    value.server_picoseconds = Some(0);
+                }
+                state.value = Some(value);
+                state.read_time = Some(Instant::now());
+                state.error = None;
+            }
+            Err(status_code) => {
+                warn!("Refresh of a value from its source failed, {:?}", status_code);
+                state.error = Some(status_code);
+            }
+        }
+        condvar.notify_all();
+    }
+
+    fn status_value(status_code: StatusCode) -> DataValue {
+        let now = DateTime::now();
+        DataValue {
+            value: None,
+            status: Some(status_code),
+            source_timestamp: None,
+            source_picoseconds: None,
+            server_timestamp: Some(now),
+            server_picoseconds: Some(0),
+        }
+    }
+
+    fn std_duration(duration: Duration) -> StdDuration {
+        if duration <= 0f64 {
+            StdDuration::from_millis(0)
+        } else {
+            StdDuration::from_millis(duration as u64)
+        }
+    }
+}
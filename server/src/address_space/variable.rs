@@ -1,23 +1,24 @@
 use std::sync::{Arc, Mutex};
 use std::convert::Into;
 
-use opcua_types::node_ids::DataTypeId;
-
 use address_space::base::Base;
 use address_space::node::Node;
 use address_space::{AttributeGetter, AttributeSetter};
 use address_space::access_level;
 use address_space::user_access_level;
+use address_space::value_source::{AsyncValue, AsyncValueSource};
 
 #[derive(Debug)]
 pub struct Variable {
     base: Base,
+    /// The source that the value is read from, if it is read asynchronously
+    value_source: Option<AsyncValue>,
 }
 
 node_impl!(Variable);
 
 impl Variable {
-    pub fn new<V>(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, value: V) -> Variable where V: Into<Variant> {
+    pub fn new<R, V>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, value: V) -> Variable where R: Into<QualifiedName>, V: Into<Variant> {
         let value = DataValue::new(value);
         let data_type = value.value.as_ref().unwrap().data_type();
         if let Some(data_type) = data_type {
@@ -27,18 +28,19 @@ impl Variable {
         }
     }
 
-    pub fn new_with_data_type<V>(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, data_type: DataTypeId, value: V) -> Variable where V: Into<Variant> {
+    pub fn new_with_data_type<R, N, V>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, data_type: N, value: V) -> Variable where R: Into<QualifiedName>, N: Into<NodeId>, V: Into<Variant> {
         Variable::new_data_value(node_id, browse_name, display_name, description, data_type, DataValue::new(value))
     }
 
-    pub fn new_array(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, data_type: DataTypeId, value: DataValue, dimensions: &[UInt32]) -> Variable {
+    pub fn new_array<R, N>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, data_type: N, value: DataValue, dimensions: &[UInt32]) -> Variable where R: Into<QualifiedName>, N: Into<NodeId> {
         let mut variable = Variable::new_data_value(node_id, browse_name, display_name, description, data_type, value);
         variable.set_array_dimensions(dimensions);
         variable
     }
 
-    /// Constructs a new variable with the specified id, name, type and value
-    pub fn new_data_value(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, data_type: DataTypeId, value: DataValue) -> Variable {
+    /// Constructs a new variable with the specified id, name, type and value. The data type is
+    /// a `DataTypeId` for a standard type or the node id of a custom data type.
+    pub fn new_data_value<R, N>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, data_type: N, value: DataValue) -> Variable where R: Into<QualifiedName>, N: Into<NodeId> {
         // Mandatory
         let historizing = false;
         let access_level = access_level::CURRENT_READ;
@@ -47,7 +49,7 @@ impl Variable {
         let attributes = vec![
             (AttributeId::UserAccessLevel, Variant::Byte(user_access_level)),
             (AttributeId::AccessLevel, Variant::Byte(access_level)),
-            (AttributeId::DataType, Variant::new::<NodeId>(data_type.into())),
+            (AttributeId::DataType, Variant::from(data_type.into())),
             (AttributeId::ValueRank, Variant::Int32(value_rank)),
             (AttributeId::Historizing, Variant::Boolean(historizing))
         ];
@@ -59,6 +61,7 @@ impl Variable {
 
         let mut result = Variable {
             base: Base::new(NodeClass::Variable, node_id, browse_name, display_name, description, attributes),
+            value_source: None,
         };
         let _ = result.base.set_attribute(AttributeId::Value, value);
         result
@@ -81,6 +84,21 @@ impl Variable {
         self.base.set_attribute_setter(AttributeId::Value, setter);
     }
 
+    /// Sets a source that the value is read from asynchronously, e.g. a device that is slow to
+    /// respond, where a read that takes longer than the timeout in milliseconds is abandoned. The
+    /// value is the last one read from the source, which Read requests refresh according to their
+    /// `max_age` and monitored items refresh at their sampling interval.
+    pub fn set_value_source(&mut self, source: Arc<Mutex<AsyncValueSource + Send>>, timeout: Duration) {
+        let value_source = AsyncValue::new(&self.node_id(), source, timeout);
+        self.set_value_getter(Arc::new(Mutex::new(value_source.clone())));
+        self.value_source = Some(value_source);
+    }
+
+    /// Returns the asynchronous source of the value, if it has one
+    pub fn value_source(&self) -> Option<&AsyncValue> {
+        self.value_source.as_ref()
+    }
+
     /// Sets the array dimensions information
     ///
     /// Specifies the length of each dimension for an array value. 
@@ -9,7 +9,7 @@ pub struct VariableType {
 node_impl!(VariableType);
 
 impl VariableType {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, is_abstract: bool, value_rank: Int32) -> VariableType {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, is_abstract: bool, value_rank: Int32) -> VariableType where R: Into<QualifiedName> {
         // Mandatory
         let attributes = vec![
             (AttributeId::IsAbstract, Variant::Boolean(is_abstract)),
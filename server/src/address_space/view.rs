@@ -11,7 +11,7 @@ node_impl!(View);
 // NodeClass::View
 
 impl View {
-    pub fn new(node_id: &NodeId, browse_name: &str, display_name: &str, description: &str, event_notifier: Boolean, contains_no_loops: Boolean) -> View {
+    pub fn new<R>(node_id: &NodeId, browse_name: R, display_name: &str, description: &str, event_notifier: Boolean, contains_no_loops: Boolean) -> View where R: Into<QualifiedName> {
         // Mandatory
         let attributes = vec![
             (AttributeId::EventNotifier, Variant::Boolean(event_notifier)),
@@ -75,9 +75,8 @@ impl SecureChannelService {
         };
 
         // Must compare protocol version to the one from HELLO
-        if request.client_protocol_version != client_protocol_version {
-            error!("Client sent a different protocol version than it did in the HELLO - {} vs {}", request.client_protocol_version, client_protocol_version);
-            return Ok(ServiceFault::new_supported_message(&request.request_header, BadProtocolVersionUnsupported));
+        if let Err(err) = SecureChannel::validate_protocol_version(client_protocol_version, request.client_protocol_version) {
+            return Ok(ServiceFault::new_supported_message(&request.request_header, err));
         }
 
         // Test the request type
@@ -110,9 +109,7 @@ impl SecureChannelService {
         // Check the requested security mode
         debug!("Message security mode == {:?}", request.security_mode);
         match request.security_mode {
-            MessageSecurityMode::None | MessageSecurityMode::Sign | MessageSecurityMode::SignAndEncrypt => {
-                // TODO validate NONCE
-            }
+            MessageSecurityMode::None | MessageSecurityMode::Sign | MessageSecurityMode::SignAndEncrypt => {}
             _ => {
                 error!("Security mode is invalid");
                 return Ok(ServiceFault::new_supported_message(&request.request_header, BadSecurityModeRejected));
@@ -125,10 +122,14 @@ impl SecureChannelService {
         // Create a new secure channel info
         let security_mode = request.security_mode;
         secure_channel.set_security_mode(security_mode);
-        secure_channel.set_token_id(self.secure_channel_state.create_token_id());
-        secure_channel.set_secure_channel_id(self.secure_channel_state.create_secure_channel_id());
         secure_channel.set_remote_cert_from_byte_string(&security_header.sender_certificate)?;
 
+        // The client's key must suit the security policy
+        if let Err(err) = secure_channel.validate_remote_cert_key() {
+            return Ok(ServiceFault::new_supported_message(&request.request_header, err));
+        }
+
+        // The nonce must be the length of the security policy and look random
         let nonce_result = secure_channel.set_remote_nonce_from_byte_string(&request.client_nonce);
         if nonce_result.is_ok() {
             secure_channel.create_random_nonce();
@@ -137,20 +138,46 @@ impl SecureChannelService {
             return Ok(ServiceFault::new_supported_message(&request.request_header, nonce_result.unwrap_err()));
         }
 
+        // An issue creates the secure channel id while a renew keeps it. A renewed token keeps the
+        // previous token's keys valid until the client starts using the new one, and a renew whose
+        // keys cannot be derived leaves the current token in place.
         let security_policy = secure_channel.security_policy();
-        if security_policy != SecurityPolicy::None && (security_mode == MessageSecurityMode::Sign || security_mode == MessageSecurityMode::SignAndEncrypt) {
-            secure_channel.derive_keys();
-        }
+        let security_token = match request.request_type {
+            SecurityTokenRequestType::Renew => {
+                let security_token = ChannelSecurityToken {
+                    channel_id: secure_channel.secure_channel_id(),
+                    token_id: self.secure_channel_state.create_token_id(),
+                    created_at: DateTime::now(),
+                    revised_lifetime: SecureChannel::revise_token_lifetime(request.requested_lifetime),
+                };
+                if let Err(err) = secure_channel.renew_security_token(security_token.clone()) {
+                    error!("Was unable to derive keys from the nonces, error = {:?}", err);
+                    return Ok(ServiceFault::new_supported_message(&request.request_header, err));
+                }
+                security_token
+            }
+            _ => {
+                let security_token = ChannelSecurityToken {
+                    channel_id: self.secure_channel_state.create_secure_channel_id(),
+                    token_id: self.secure_channel_state.create_token_id(),
+                    created_at: DateTime::now(),
+                    revised_lifetime: SecureChannel::revise_token_lifetime(request.requested_lifetime),
+                };
+                secure_channel.set_security_token(security_token.clone());
+                if security_policy != SecurityPolicy::None && (security_mode == MessageSecurityMode::Sign || security_mode == MessageSecurityMode::SignAndEncrypt) {
+                    if let Err(err) = secure_channel.derive_keys() {
+                        error!("Was unable to derive keys from the nonces, error = {:?}", err);
+                        return Ok(ServiceFault::new_supported_message(&request.request_header, err));
+                    }
+                }
+                security_token
+            }
+        };
 
         let response = OpenSecureChannelResponse {
             response_header: ResponseHeader::new_good(&request.request_header),
-            server_protocol_version: 0,
-            security_token: ChannelSecurityToken {
-                channel_id: secure_channel.secure_channel_id(),
-                token_id: secure_channel.token_id(),
-                created_at: DateTime::now(),
-                revised_lifetime: request.requested_lifetime,
-            },
+            server_protocol_version: PROTOCOL_VERSION,
+            security_token,
             server_nonce: secure_channel.local_nonce_as_byte_string(),
         };
         Ok(response.into())
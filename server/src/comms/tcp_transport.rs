@@ -15,7 +15,8 @@ use chrono;
 use chrono::Utc;
 use futures::{Stream, Future};
 use futures::future::{self, loop_fn, Loop};
-use futures::sync::mpsc;
+use futures::sync::mpsc::{self, UnboundedSender};
+use futures_cpupool::CpuPool;
 use tokio;
 use tokio::net::TcpStream;
 use tokio_io::AsyncRead;
@@ -24,6 +25,7 @@ use tokio_io::io::{ReadHalf, WriteHalf};
 use tokio_timer;
 
 use address_space::types::AddressSpace;
+use address_space::data_change::DataChangeSignal;
 use comms::secure_channel_service::SecureChannelService;
 use comms::transport::*;
 use constants;
@@ -68,8 +70,13 @@ pub struct TcpTransport {
     client_address: Option<SocketAddr>,
     /// Secure channel handler
     secure_channel_service: SecureChannelService,
-    /// Message handler
-    message_handler: MessageHandler,
+    /// Message handler, which is shared with the worker pool
+    message_handler: Arc<MessageHandler>,
+    /// The pool of threads that execute service requests
+    worker_pool: CpuPool,
+    /// Sends the responses of service requests from the worker pool to the responses task. It
+    /// is set while the connection is running.
+    response_tx: Option<UnboundedSender<ServiceResponse>>,
     /// Client protocol version set during HELLO
     client_protocol_version: UInt32,
     /// Last encoded sequence number
@@ -138,6 +145,14 @@ impl SocketWriter {
     }
 }
 
+/// The outcome of a service request that was executed on the worker pool
+enum ServiceResponse {
+    /// A response to send for the request id
+    Response(UInt32, SupportedMessage),
+    /// The request failed in a way that terminates the connection
+    Error(StatusCode),
+}
+
 struct ConnectionState {
     /// The associated connection
     pub connection: Arc<RwLock<TcpTransport>>,
@@ -179,9 +194,17 @@ impl Transport for TcpTransport {
     // Terminates the connection and the session
     fn terminate_session(&mut self, status_code: StatusCode) {
         self.transport_state = TransportState::Finished;
+        // Dropping the sender ends the responses task once pending requests have completed and
+        // their responses have been sent
+        self.response_tx = None;
         self.set_session_status(status_code);
-        let mut session = trace_write_lock_unwrap!(self.session);
-        session.set_terminated();
+        let authentication_token = {
+            let mut session = trace_write_lock_unwrap!(self.session);
+            session.set_terminated();
+            session.authentication_token.clone()
+        };
+        let address_space = trace_read_lock_unwrap!(self.address_space);
+        address_space.session_closed(&authentication_token);
     }
 
     /// Test if the connection is terminated
@@ -195,7 +218,7 @@ impl Transport for TcpTransport {
 }
 
 impl TcpTransport {
-    pub fn new(server_state: Arc<RwLock<ServerState>>, session: Arc<RwLock<Session>>, address_space: Arc<RwLock<AddressSpace>>, message_handler: MessageHandler) -> TcpTransport {
+    pub fn new(server_state: Arc<RwLock<ServerState>>, session: Arc<RwLock<Session>>, address_space: Arc<RwLock<AddressSpace>>, message_handler: MessageHandler, worker_pool: CpuPool) -> TcpTransport {
         let secure_channel_service = SecureChannelService::new();
         TcpTransport {
             server_state,
@@ -204,7 +227,9 @@ impl TcpTransport {
             address_space,
             transport_state: TransportState::New,
             client_address: None,
-            message_handler,
+            message_handler: Arc::new(message_handler),
+            worker_pool,
+            response_tx: None,
             secure_channel_service,
             client_protocol_version: 0,
             last_sent_sequence_number: 0,
@@ -249,6 +274,8 @@ impl TcpTransport {
         Self::spawn_hello_timeout_task(&connection_state);
         // Spawn the subscription processing task
         Self::spawn_subscriptions_task(&connection_state);
+        // Spawn the task that sends the responses of service requests
+        Self::spawn_responses_task(&connection_state);
 
         // 1. Read bytes
         // 2. Store bytes in a buffer
@@ -509,18 +536,36 @@ impl TcpTransport {
             let interval_duration = chrono::Duration::milliseconds(constants::SUBSCRIPTION_TIMER_RATE_MS).to_std().unwrap();
             debug!("interval for subscription timer is {:?}", interval_duration);
 
+            // Changes that are pushed to monitored items wake the task to sample them as they
+            // happen. The timer polls the values that have getters and publishes.
+            let data_change_signal = {
+                let connection = trace_read_lock_unwrap!(state.connection);
+                let session = trace_read_lock_unwrap!(connection.session);
+                session.subscriptions.data_change_signal()
+            };
+            let data_changes = DataChangeSignal::changes(&data_change_signal).map(|_| TickReason::DataChanged);
+
             // Creates a repeating interval future that checks subscriptions.
             let monitor_task = timer
                 .interval(interval_duration)
+                .map(|_| TickReason::TickTimerFired)
+                .map_err(|_| ())
+                .select(data_changes)
                 .take_while(move |_| {
                     connection_finished_test!(connection_for_take_while)
                 })
-                .for_each(move |_| {
+                .for_each(move |tick_reason| {
                     let connection = trace_read_lock_unwrap!(state.connection);
                     let mut session = trace_write_lock_unwrap!(connection.session);
 
                     let now = Utc::now();
 
+                    if tick_reason == TickReason::DataChanged {
+                        let address_space = trace_read_lock_unwrap!(connection.address_space);
+                        session.subscriptions.sample_data_changes(&now, &address_space);
+                        return Ok(());
+                    }
+
                     // Request queue might contain stale publish requests
                     session.expire_stale_publish_requests(&now);
 
@@ -573,10 +618,11 @@ impl TcpTransport {
                     match subscription_event {
                         SubscriptionEvent::PublishResponses(publish_responses) => {
                             trace!("Got {} PublishResponse messages to send", publish_responses.len());
+                            // The connection is locked before the writer, as it is everywhere else
+                            let mut connection = trace_write_lock_unwrap!(state.connection);
                             let mut writer = trace_lock_unwrap!(state.writer);
                             for publish_response in publish_responses {
                                 trace!("<-- Sending a Publish Response{}, {:?}", publish_response.request_id, &publish_response.response);
-                                let mut connection = trace_write_lock_unwrap!(state.connection);
                                 let _ = connection.send_response(publish_response.request_id, &publish_response.response, &mut writer.buffer);
                                 let _ = writer.write();
                             }
@@ -587,6 +633,53 @@ impl TcpTransport {
         }
     }
 
+    /// Start the task that sends the responses of service requests as the worker pool completes
+    /// them
+    fn spawn_responses_task(connection_state: &ConnectionState) {
+        let (response_tx, response_rx) = mpsc::unbounded::<ServiceResponse>();
+        {
+            let mut connection = trace_write_lock_unwrap!(connection_state.connection);
+            connection.response_tx = Some(response_tx);
+        }
+
+        let connection = connection_state.connection.clone();
+        let writer = connection_state.writer.clone();
+        tokio::spawn(response_rx.for_each(move |service_response| {
+            let mut connection = trace_write_lock_unwrap!(connection);
+            if !connection.sends_pending_responses() {
+                return Ok(());
+            }
+            let mut writer = trace_lock_unwrap!(writer);
+            let result = match service_response {
+                ServiceResponse::Response(request_id, response) => {
+                    trace!("<-- Sending a response to request {}", request_id);
+                    connection.send_response(request_id, &response, &mut writer.buffer)
+                }
+                ServiceResponse::Error(status_code) => Err(status_code)
+            };
+            if let Err(status_code) = result {
+                warn!("Sending session terminating error {:?}", status_code);
+                writer.encode_error_message(status_code);
+                if connection.is_finished() {
+                    // No more responses are sent after the error
+                    connection.set_session_status(status_code);
+                } else {
+                    connection.terminate_session(status_code);
+                }
+            }
+            let _ = writer.write();
+            Ok(())
+        }));
+    }
+
+    /// Test if the responses of requests that are still executing should be sent. A session that
+    /// closes normally, e.g. on a CloseSession request or a ServiceFault that terminates it, sends
+    /// them before the socket closes. A session that ended with an error has sent the error as
+    /// its last message.
+    fn sends_pending_responses(&self) -> bool {
+        !self.is_finished() || self.session_status == Good || self.session_status == BadConnectionClosed
+    }
+
     /// Test if the connection should abort
     pub fn is_server_abort(&self) -> bool {
         let server_state = trace_read_lock_unwrap!(self.server_state);
@@ -594,7 +687,7 @@ impl TcpTransport {
     }
 
     fn process_hello<W: Write>(&mut self, hello: HelloMessage, out_stream: &mut W) -> std::result::Result<(), StatusCode> {
-        let server_protocol_version = 0;
+        let server_protocol_version = PROTOCOL_VERSION;
 
         trace!("Server received HELLO {:?}", hello);
         if !hello.is_endpoint_url_valid() {
@@ -673,6 +766,23 @@ impl TcpTransport {
                 self.secure_channel_service.close_secure_channel(&message)?
             }
             MessageChunkType::Message => {
+                if let Some(ref response_tx) = self.response_tx {
+                    // Services execute on the worker pool so that a slow request does not hold up
+                    // the network or other requests. The responses task sends the response.
+                    let message_handler = self.message_handler.clone();
+                    let response_tx = response_tx.clone();
+                    self.worker_pool.spawn_fn(move || {
+                        let service_response = match message_handler.handle_message(request_id, message) {
+                            Ok(Some(response)) => ServiceResponse::Response(request_id, response),
+                            Ok(None) => return Ok(()),
+                            Err(status_code) => ServiceResponse::Error(status_code),
+                        };
+                        let _ = response_tx.unbounded_send(service_response);
+                        Ok::<(), ()>(())
+                    }).forget();
+                    return Ok(());
+                }
+                // The connection is not running, so the message is handled here
                 let response = self.message_handler.handle_message(request_id, message)?;
                 if response.is_none() {
                     // No response for the message at this time
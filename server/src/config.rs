@@ -98,6 +98,10 @@ impl ServerEndpoint {
             SecurityPolicy::Basic128Rsa15 => 2,
             SecurityPolicy::Basic256 => 3,
             SecurityPolicy::Basic256Sha256 => 4,
+            SecurityPolicy::Aes128Sha256RsaOaep => 5,
+            SecurityPolicy::Aes256Sha256RsaPss => 6,
+            SecurityPolicy::EccNistP256 => 7,
+            SecurityPolicy::EccNistP384 => 8,
             _ => 0
         }
     }
@@ -130,6 +134,38 @@ impl ServerEndpoint {
         Self::new(path, SecurityPolicy::Basic256Sha256, MessageSecurityMode::SignAndEncrypt, user_token_ids)
     }
 
+    pub fn new_aes128_sha256_rsaoaep_sign<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::Aes128Sha256RsaOaep, MessageSecurityMode::Sign, user_token_ids)
+    }
+
+    pub fn new_aes128_sha256_rsaoaep_sign_encrypt<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::Aes128Sha256RsaOaep, MessageSecurityMode::SignAndEncrypt, user_token_ids)
+    }
+
+    pub fn new_aes256_sha256_rsapss_sign<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::Aes256Sha256RsaPss, MessageSecurityMode::Sign, user_token_ids)
+    }
+
+    pub fn new_aes256_sha256_rsapss_sign_encrypt<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::Aes256Sha256RsaPss, MessageSecurityMode::SignAndEncrypt, user_token_ids)
+    }
+
+    pub fn new_ecc_nistp256_sign<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::EccNistP256, MessageSecurityMode::Sign, user_token_ids)
+    }
+
+    pub fn new_ecc_nistp256_sign_encrypt<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::EccNistP256, MessageSecurityMode::SignAndEncrypt, user_token_ids)
+    }
+
+    pub fn new_ecc_nistp384_sign<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::EccNistP384, MessageSecurityMode::Sign, user_token_ids)
+    }
+
+    pub fn new_ecc_nistp384_sign_encrypt<T>(path: T, user_token_ids: &[String]) -> Self where T: Into<String> {
+        Self::new(path, SecurityPolicy::EccNistP384, MessageSecurityMode::SignAndEncrypt, user_token_ids)
+    }
+
     pub fn is_valid(&self, id: &str, user_tokens: &BTreeMap<String, ServerUserToken>) -> bool {
         let mut valid = true;
 
@@ -149,7 +185,7 @@ impl ServerEndpoint {
         let security_policy = SecurityPolicy::from_str(&self.security_policy).unwrap();
         let security_mode = MessageSecurityMode::from(self.security_mode.as_ref());
         if security_policy == SecurityPolicy::Unknown {
-            error!("Endpoint {} is invalid. Security policy \"{}\" is invalid. Valid values are None, Basic128Rsa15, Basic256, Basic256Sha256", id, self.security_policy);
+            error!("Endpoint {} is invalid. Security policy \"{}\" is invalid. Valid values are None, Basic128Rsa15, Basic256, Basic256Sha256, Aes128-Sha256-RsaOaep, Aes256-Sha256-RsaPss, ECC-nistP256, ECC-nistP384", id, self.security_policy);
             valid = false;
         } else if security_mode == MessageSecurityMode::Invalid {
             error!("Endpoint {} is invalid. Security mode \"{}\" is invalid. Valid values are None, Sign, SignAndEncrypt", id, self.security_mode);
@@ -200,6 +236,11 @@ pub struct ServerConfig {
     /// Autocreates public / private keypair if they don't exist. For testing/samples only
     /// since you do not have control of the values
     pub create_sample_keypair: bool,
+    /// Interval in millis at which the pki folder is checked for a new application instance
+    /// certificate, private key or trust list which are then loaded without a restart. 0 means
+    /// the folder is not watched.
+    #[serde(default = "ServerConfig::default_pki_watch_interval_ms")]
+    pub pki_watch_interval_ms: u32,
     /// Url to a discovery server - adding this string causes the server to assume you wish to
     /// register the server with a discovery server.
     pub discovery_server_url: Option<String>,
@@ -207,12 +248,20 @@ pub struct ServerConfig {
     pub tcp_config: TcpConfig,
     /// User tokens
     pub user_tokens: BTreeMap<String, ServerUserToken>,
+    /// User tokens whose users are security administrators, who may update the server's
+    /// certificate and trust list through the ServerConfiguration object
+    #[serde(default)]
+    pub security_admin_user_token_ids: BTreeSet<String>,
     /// discovery endpoint url which may or may not be the same as the service endpoints below.
     pub discovery_url: String,
     /// Endpoints supported by the server
     pub endpoints: BTreeMap<String, ServerEndpoint>,
     /// Maximum number of subscriptions in a session
     pub max_subscriptions: u32,
+    /// Number of threads in the pool that executes service requests off the network thread.
+    /// 0 means one thread per CPU.
+    #[serde(default)]
+    pub worker_threads: u32,
     /// Max array length in elements
     pub max_array_length: u32,
     /// Max string length in characters
@@ -238,6 +287,12 @@ impl Config for ServerConfig {
                 valid = false;
             }
         }
+        for id in &self.security_admin_user_token_ids {
+            if !self.user_tokens.contains_key(id) {
+                error!("Server configuration is invalid. Security admin user token {} does not exist", id);
+                valid = false;
+            }
+        }
         if self.max_array_length == 0 {
             error!("Server configuration is invalid.  Max array length is invalid");
             valid = false;
@@ -278,6 +333,7 @@ impl ServerConfig {
             product_uri,
             pki_dir,
             create_sample_keypair: false,
+            pki_watch_interval_ms: constants::DEFAULT_PKI_WATCH_INTERVAL_MS,
             discovery_server_url,
             tcp_config: TcpConfig {
                 host,
@@ -285,15 +341,21 @@ impl ServerConfig {
                 hello_timeout: constants::DEFAULT_HELLO_TIMEOUT_SECONDS,
             },
             user_tokens,
+            security_admin_user_token_ids: BTreeSet::new(),
             discovery_url,
             endpoints,
             max_array_length: opcua_types_constants::MAX_ARRAY_LENGTH,
             max_string_length: opcua_types_constants::MAX_STRING_LENGTH,
             max_byte_string_length: opcua_types_constants::MAX_BYTE_STRING_LENGTH,
             max_subscriptions: constants::DEFAULT_MAX_SUBSCRIPTIONS,
+            worker_threads: constants::DEFAULT_WORKER_THREADS,
         }
     }
 
+    fn default_pki_watch_interval_ms() -> u32 {
+        constants::DEFAULT_PKI_WATCH_INTERVAL_MS
+    }
+
     /// Create a server configuration that runs a server with no security and anonymous access enabled
     pub fn new_anonymous<T>(application_name: T) -> Self where T: Into<String> {
         let user_tokens = BTreeMap::new();
@@ -335,6 +397,10 @@ impl ServerConfig {
         config.add_endpoint("basic256_sign_encrypt", ServerEndpoint::new_basic256_sign_encrypt(path, &user_token_ids));
         config.add_endpoint("basic256sha256_sign", ServerEndpoint::new_basic256sha256_sign(path, &user_token_ids));
         config.add_endpoint("basic256sha256_sign_encrypt", ServerEndpoint::new_basic256sha256_sign_encrypt(path, &user_token_ids));
+        config.add_endpoint("aes128-sha256-rsaoaep_sign", ServerEndpoint::new_aes128_sha256_rsaoaep_sign(path, &user_token_ids));
+        config.add_endpoint("aes128-sha256-rsaoaep_sign_encrypt", ServerEndpoint::new_aes128_sha256_rsaoaep_sign_encrypt(path, &user_token_ids));
+        config.add_endpoint("aes256-sha256-rsapss_sign", ServerEndpoint::new_aes256_sha256_rsapss_sign(path, &user_token_ids));
+        config.add_endpoint("aes256-sha256-rsapss_sign_encrypt", ServerEndpoint::new_aes256_sha256_rsapss_sign_encrypt(path, &user_token_ids));
         config.add_endpoint("no_access", ServerEndpoint::new_none("/noaccess", &[]));
         config
     }
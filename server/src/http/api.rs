@@ -0,0 +1,658 @@
+//! The api module is a JSON gateway onto the server's address space. It allows web clients to
+//! browse nodes, read and write values and call methods over plain HTTP, and to receive value
+//! changes as server-sent events. Requests are serviced by the same services that handle
+//! OPC UA requests, so the same rules apply, e.g. access levels on variables. Each user calls
+//! those services on a session of its own which carries the user's identity, so methods see who
+//! is calling them.
+//!
+//! The following routes are supported:
+//!
+//! * `GET /api/browse/{nodeId}` - the forward hierarchical references of the node
+//! * `GET /api/nodes/{nodeId}/value` - the value of the node
+//! * `PUT /api/nodes/{nodeId}/value` - writes the value in the body, e.g. `{"value": {"Int32": 5}}`
+//! * `POST /api/call` - calls the method in the body, e.g. `{"object_id": "i=2253", "method_id": "i=11492", "input_arguments": [{"UInt32": 1}]}`
+//! * `GET /api/events?node_id={nodeId}&interval={ms}` - an event stream of value changes
+//!
+//! Node ids are in their string form, e.g. `ns=2;s=v1`, and must be percent-encoded in paths.
+
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time;
+
+use futures;
+use futures::{Future, Sink, Stream};
+use hyper;
+use hyper::{Body, Chunk, Method};
+use hyper::StatusCode as HttpStatusCode;
+use hyper::header::{Authorization, Basic, ContentType};
+use hyper::mime;
+use hyper::server::{Request, Response};
+use serde;
+use serde_json;
+
+use opcua_core::crypto::{CertificateStore, SecurityPolicy};
+use opcua_types::*;
+use opcua_types::node_ids::ObjectId;
+use opcua_types::node_ids::ReferenceTypeId;
+use opcua_types::service_types::*;
+use opcua_types::status_codes::StatusCode;
+use opcua_types::status_codes::StatusCode::*;
+
+use address_space::address_space::AddressSpace;
+use config::ANONYMOUS_USER_TOKEN_ID;
+use server::Server;
+use services::attribute::AttributeService;
+use services::method::MethodService;
+use services::view::ViewService;
+use session::Session;
+use state::{ServerState, TOKEN_POLICY_ANONYMOUS, TOKEN_POLICY_USER_PASS_PLAINTEXT};
+
+/// Turns a service response into the expected message, or the status code of its fault
+macro_rules! service_response {
+    ($response: expr, $i: ident) => {
+        match $response {
+            SupportedMessage::$i(response) => Ok(response),
+            SupportedMessage::ServiceFault(fault) => Err(fault.response_header.service_result),
+            _ => Err(BadUnexpectedError),
+        }
+    }
+}
+
+/// The default interval between polls of values for the event stream
+const DEFAULT_EVENT_INTERVAL_MS: u64 = 1000;
+/// The smallest interval between polls of values that a client may request for the event stream
+const MIN_EVENT_INTERVAL_MS: u64 = 100;
+/// The longest time an event stream goes without sending anything. A comment is sent when no
+/// value changes so a client that went away is noticed by the failed write.
+const EVENT_KEEP_ALIVE_MS: u64 = 15000;
+/// The maximum number of event streams that may be open at once
+pub const MAX_EVENT_STREAMS: usize = 16;
+
+/// An authenticator decides whether a request to the api is allowed. The credentials are those
+/// from the basic `Authorization` header of the request, if there was one. An allowed request
+/// runs on a session whose user identity is a user name token made from the credentials, or an
+/// anonymous token if there were none.
+pub trait ApiAuthenticator: Send + Sync {
+    fn authenticate(&self, server_state: &ServerState, credentials: Option<&Basic>) -> StatusCode;
+}
+
+/// The default authenticator checks credentials against the user tokens of the server's
+/// configuration.
+pub struct UserTokenAuthenticator {
+    /// The user token ids allowed to use the api. Empty means any user token may be used.
+    user_token_ids: BTreeSet<String>,
+}
+
+impl ApiAuthenticator for UserTokenAuthenticator {
+    fn authenticate(&self, server_state: &ServerState, credentials: Option<&Basic>) -> StatusCode {
+        if let Some(credentials) = credentials {
+            let pass = if let Some(ref pass) = credentials.password { pass.as_ref() } else { "" };
+            let user_token_ids = if self.user_token_ids.is_empty() { None } else { Some(&self.user_token_ids) };
+            server_state.authenticate_user_pass(&credentials.username, pass, user_token_ids)
+        } else if self.user_token_ids.contains(ANONYMOUS_USER_TOKEN_ID) {
+            Good
+        } else {
+            debug!("Api request supplied no credentials and anonymous access is not allowed");
+            BadIdentityTokenRejected
+        }
+    }
+}
+
+impl UserTokenAuthenticator {
+    /// Creates an authenticator that allows any user in the server's user tokens. Anonymous
+    /// requests are rejected.
+    pub fn new() -> UserTokenAuthenticator {
+        UserTokenAuthenticator {
+            user_token_ids: BTreeSet::new(),
+        }
+    }
+
+    /// Creates an authenticator that only allows the specified user token ids. Include
+    /// `ANONYMOUS_USER_TOKEN_ID` to allow requests without credentials.
+    pub fn new_with_user_token_ids(user_token_ids: &[&str]) -> UserTokenAuthenticator {
+        UserTokenAuthenticator {
+            user_token_ids: user_token_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+/// A reference returned by browse
+#[derive(Serialize)]
+pub struct ApiReference {
+    pub node_id: String,
+    pub reference_type_id: String,
+    pub is_forward: bool,
+    pub node_class: String,
+    pub browse_name: QualifiedName,
+    pub display_name: LocalizedText,
+    pub type_definition: String,
+}
+
+impl<'a> From<&'a ReferenceDescription> for ApiReference {
+    fn from(r: &'a ReferenceDescription) -> Self {
+        ApiReference {
+            node_id: r.node_id.node_id.to_string(),
+            reference_type_id: r.reference_type_id.to_string(),
+            is_forward: r.is_forward,
+            node_class: format!("{:?}", r.node_class),
+            browse_name: r.browse_name.clone(),
+            display_name: r.display_name.clone(),
+            type_definition: r.type_definition.node_id.to_string(),
+        }
+    }
+}
+
+/// A value returned by read, or sent as an event
+#[derive(Serialize, PartialEq)]
+pub struct ApiDataValue {
+    pub value: Option<Variant>,
+    pub status: String,
+    pub source_timestamp: Option<String>,
+    pub server_timestamp: Option<String>,
+}
+
+impl From<DataValue> for ApiDataValue {
+    fn from(v: DataValue) -> Self {
+        ApiDataValue {
+            value: v.value,
+            status: v.status.unwrap_or(Good).name().to_string(),
+            source_timestamp: v.source_timestamp.map(|t| t.date_time.to_rfc3339()),
+            server_timestamp: v.server_timestamp.map(|t| t.date_time.to_rfc3339()),
+        }
+    }
+}
+
+/// The body of a value write
+#[derive(Deserialize)]
+pub struct ApiWriteValue {
+    pub value: Variant,
+}
+
+/// The body of a method call
+#[derive(Deserialize)]
+pub struct ApiCallRequest {
+    pub object_id: String,
+    pub method_id: String,
+    pub input_arguments: Option<Vec<Variant>>,
+}
+
+/// The result of a method call
+#[derive(Serialize)]
+pub struct ApiCallResult {
+    pub status: String,
+    pub input_argument_results: Option<Vec<String>>,
+    pub output_arguments: Option<Vec<Variant>>,
+}
+
+/// A status, returned on its own or as an error in place of a result
+#[derive(Serialize)]
+struct ApiStatus {
+    status: String,
+    description: String,
+}
+
+impl From<StatusCode> for ApiStatus {
+    fn from(status_code: StatusCode) -> Self {
+        ApiStatus {
+            status: status_code.name().to_string(),
+            description: status_code.description().to_string(),
+        }
+    }
+}
+
+/// A value change sent through the event stream
+#[derive(Serialize)]
+struct ApiValueEvent<'a> {
+    node_id: String,
+    value: &'a ApiDataValue,
+}
+
+/// The sessions of the api, one for each set of credentials that has been allowed
+type ApiSessions = HashMap<Option<(String, String)>, Arc<RwLock<Session>>>;
+
+/// The api handles requests under `/api`. Services are called on the session of the request's
+/// credentials, which is created the first time those credentials are allowed.
+#[derive(Clone)]
+pub struct Api {
+    server_state: Arc<RwLock<ServerState>>,
+    certificate_store: Arc<RwLock<CertificateStore>>,
+    /// The session that services are called on. This is the anonymous session except in the api
+    /// handling a request.
+    session: Arc<RwLock<Session>>,
+    sessions: Arc<Mutex<ApiSessions>>,
+    address_space: Arc<RwLock<AddressSpace>>,
+    authenticator: Arc<ApiAuthenticator>,
+    /// The number of event streams that are open
+    event_streams: Arc<AtomicUsize>,
+}
+
+/// Holds one of the open event streams, and frees it when dropped
+struct EventStreamSlot {
+    event_streams: Arc<AtomicUsize>,
+}
+
+impl Drop for EventStreamSlot {
+    fn drop(&mut self) {
+        self.event_streams.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Api {
+    pub fn new(server: &Server, authenticator: Arc<ApiAuthenticator>) -> Api {
+        let session = Self::new_session(&server.server_state, &server.certificate_store, None);
+        let mut sessions = HashMap::new();
+        sessions.insert(None, session.clone());
+        Api {
+            server_state: server.server_state.clone(),
+            certificate_store: server.certificate_store.clone(),
+            session,
+            sessions: Arc::new(Mutex::new(sessions)),
+            address_space: server.address_space.clone(),
+            authenticator,
+            event_streams: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the api that calls services on the session of the credentials, creating the
+    /// session if the credentials have not been seen before.
+    pub fn with_credentials(&self, credentials: Option<&Basic>) -> Api {
+        let key = credentials.map(|c| (c.username.clone(), c.password.clone().unwrap_or_default()));
+        let session = {
+            let mut sessions = trace_lock_unwrap!(self.sessions);
+            if let Some(session) = sessions.get(&key) {
+                session.clone()
+            } else {
+                let session = Self::new_session(&self.server_state, &self.certificate_store, key.as_ref());
+                sessions.insert(key, session.clone());
+                session
+            }
+        };
+        Api {
+            session,
+            ..self.clone()
+        }
+    }
+
+    /// Creates an activated session whose user identity is that of the credentials
+    fn new_session(server_state: &Arc<RwLock<ServerState>>, certificate_store: &Arc<RwLock<CertificateStore>>, credentials: Option<&(String, String)>) -> Arc<RwLock<Session>> {
+        let mut session = Session::new_with_certificate_store(server_state, certificate_store.clone());
+        session.security_policy_uri = SecurityPolicy::None.to_uri().to_string();
+        session.user_identity = Some(if let Some(&(ref user, ref pass)) = credentials {
+            ExtensionObject::from_encodable(ObjectId::UserNameIdentityToken_Encoding_DefaultBinary, UserNameIdentityToken {
+                policy_id: UAString::from(TOKEN_POLICY_USER_PASS_PLAINTEXT),
+                user_name: UAString::from(user.as_ref()),
+                password: ByteString::from(pass.as_bytes()),
+                encryption_algorithm: UAString::null(),
+            })
+        } else {
+            ExtensionObject::from_encodable(ObjectId::AnonymousIdentityToken_Encoding_DefaultBinary, AnonymousIdentityToken {
+                policy_id: UAString::from(TOKEN_POLICY_ANONYMOUS),
+            })
+        });
+        session.activated = true;
+        Arc::new(RwLock::new(session))
+    }
+
+    /// Handles a request whose path begins with `/api/`
+    pub fn handle(&self, req: Request) -> Box<Future<Item=Response, Error=hyper::Error>> {
+        let api = {
+            let credentials = req.headers().get::<Authorization<Basic>>().map(|a| &a.0);
+            let status_code = {
+                let server_state = trace_read_lock_unwrap!(self.server_state);
+                self.authenticator.authenticate(&server_state, credentials)
+            };
+            if status_code.is_bad() {
+                let mut response = error_response(status_code);
+                response.headers_mut().set_raw("WWW-Authenticate", "Basic realm=\"OPC UA\"");
+                return Box::new(futures::future::ok(response));
+            }
+            self.with_credentials(credentials)
+        };
+
+        let method = req.method().clone();
+        let segments = req.path().trim_matches('/').split('/').map(|s| percent_decode(s)).collect::<Vec<String>>();
+        let segments = segments.iter().map(|s| s.as_ref()).collect::<Vec<&str>>();
+        let response = match (&method, &segments[..]) {
+            (&Method::Get, &["api", "browse", node_id]) => {
+                json_result(parse_node_id(node_id).and_then(|node_id| api.browse(&node_id)))
+            }
+            (&Method::Get, &["api", "nodes", node_id, "value"]) => {
+                json_result(parse_node_id(node_id).and_then(|node_id| api.read_value(&node_id)))
+            }
+            (&Method::Put, &["api", "nodes", node_id, "value"]) => {
+                let node_id = parse_node_id(node_id);
+                return Box::new(req.body().concat2().map(move |body| {
+                    json_result(node_id.and_then(|node_id| {
+                        let write = parse_body::<ApiWriteValue>(&body)?;
+                        api.write_value(&node_id, write.value)
+                    }).map(ApiStatus::from))
+                }));
+            }
+            (&Method::Post, &["api", "call"]) => {
+                return Box::new(req.body().concat2().map(move |body| {
+                    json_result(parse_body::<ApiCallRequest>(&body).and_then(|call| api.call(call)))
+                }));
+            }
+            (&Method::Get, &["api", "events"]) => {
+                let mut node_ids = Vec::new();
+                let mut interval = DEFAULT_EVENT_INTERVAL_MS;
+                let mut result = Ok(());
+                for (key, value) in parse_query(req.query().unwrap_or("")) {
+                    match key.as_ref() {
+                        "node_id" => {
+                            match parse_node_id(&value) {
+                                Ok(node_id) => node_ids.push(node_id),
+                                Err(err) => result = Err(err)
+                            }
+                        }
+                        "interval" => {
+                            match value.parse::<u64>() {
+                                Ok(value) => interval = if value < MIN_EVENT_INTERVAL_MS { MIN_EVENT_INTERVAL_MS } else { value },
+                                Err(_) => result = Err(BadInvalidArgument)
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                if let Err(err) = result {
+                    error_response(err)
+                } else if node_ids.is_empty() {
+                    error_response(BadNothingToDo)
+                } else {
+                    api.events(node_ids, interval)
+                }
+            }
+            _ => {
+                Response::new().with_status(HttpStatusCode::NotFound)
+            }
+        };
+        Box::new(futures::future::ok(response))
+    }
+
+    /// Browses the forward hierarchical references from the node
+    pub fn browse(&self, node_id: &NodeId) -> Result<Vec<ApiReference>, StatusCode> {
+        let request = BrowseRequest {
+            request_header: make_request_header(),
+            view: ViewDescription {
+                view_id: NodeId::null(),
+                timestamp: DateTime::now(),
+                view_version: 0,
+            },
+            requested_max_references_per_node: 0,
+            nodes_to_browse: Some(vec![BrowseDescription {
+                node_id: node_id.clone(),
+                browse_direction: BrowseDirection::Forward,
+                reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
+                include_subtypes: true,
+                node_class_mask: 0,
+                result_mask: 0xff,
+            }]),
+        };
+        let response = {
+            let mut session = trace_write_lock_unwrap!(self.session);
+            let address_space = trace_read_lock_unwrap!(self.address_space);
+            ViewService::new().browse(&mut session, &address_space, request)?
+        };
+        let response = service_response!(response, BrowseResponse)?;
+        let result = first_result(response.results)?;
+        if result.status_code.is_bad() {
+            Err(result.status_code)
+        } else if let Some(references) = result.references {
+            Ok(references.iter().map(|r| r.into()).collect())
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Reads the value of the node
+    pub fn read_value(&self, node_id: &NodeId) -> Result<ApiDataValue, StatusCode> {
+        let value = self.read_values(&[node_id.clone()])?.remove(0);
+        if let Some(status) = value.status {
+            if status.is_bad() {
+                return Err(status);
+            }
+        }
+        Ok(value.into())
+    }
+
+    /// Writes the value of the node, returning the status code of the write
+    pub fn write_value(&self, node_id: &NodeId, value: Variant) -> Result<StatusCode, StatusCode> {
+        let request = WriteRequest {
+            request_header: make_request_header(),
+            nodes_to_write: Some(vec![WriteValue {
+                node_id: node_id.clone(),
+                attribute_id: AttributeId::Value as UInt32,
+                index_range: UAString::null(),
+                value: DataValue::new(value),
+            }]),
+        };
+        let response = {
+            let mut address_space = trace_write_lock_unwrap!(self.address_space);
+            AttributeService::new().write(&mut address_space, request)?
+        };
+        let response = service_response!(response, WriteResponse)?;
+        let status_code = first_result(response.results)?;
+        if status_code.is_bad() { Err(status_code) } else { Ok(status_code) }
+    }
+
+    /// Calls a method
+    pub fn call(&self, call: ApiCallRequest) -> Result<ApiCallResult, StatusCode> {
+        let request = CallRequest {
+            request_header: make_request_header(),
+            methods_to_call: Some(vec![CallMethodRequest {
+                object_id: parse_node_id(&call.object_id)?,
+                method_id: parse_node_id(&call.method_id)?,
+                input_arguments: call.input_arguments,
+            }]),
+        };
+        let response = {
+            let server_state = trace_read_lock_unwrap!(self.server_state);
+            let session = trace_read_lock_unwrap!(self.session);
+            let address_space = trace_read_lock_unwrap!(self.address_space);
+            MethodService::new().call(&address_space, &server_state, &session, request)?
+        };
+        let response = service_response!(response, CallResponse)?;
+        let result = first_result(response.results)?;
+        if result.status_code.is_bad() {
+            Err(result.status_code)
+        } else {
+            Ok(ApiCallResult {
+                status: result.status_code.name().to_string(),
+                input_argument_results: result.input_argument_results.map(|r| r.iter().map(|s| s.name().to_string()).collect()),
+                output_arguments: result.output_arguments,
+            })
+        }
+    }
+
+    /// Reads the values of nodes. There is a value for every node, with a bad status for
+    /// those that could not be read.
+    fn read_values(&self, node_ids: &[NodeId]) -> Result<Vec<DataValue>, StatusCode> {
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Both,
+            nodes_to_read: Some(node_ids.iter().map(|node_id| ReadValueId {
+                node_id: node_id.clone(),
+                attribute_id: AttributeId::Value as UInt32,
+                index_range: UAString::null(),
+                data_encoding: QualifiedName::null(),
+            }).collect()),
+        };
+        let response = {
+            let address_space = trace_read_lock_unwrap!(self.address_space);
+            AttributeService::new().read(&address_space, request)?
+        };
+        let response = service_response!(response, ReadResponse)?;
+        response.results.ok_or(BadUnexpectedError)
+    }
+
+    /// Creates an event stream that sends the values of the nodes, and then sends each value
+    /// again whenever it changes. The values are polled on a thread which ends when the client
+    /// goes away or the server aborts. A keep-alive is sent when nothing changes so that a client
+    /// that went away is noticed. At most `MAX_EVENT_STREAMS` are open at once.
+    pub fn events(&self, node_ids: Vec<NodeId>, interval: u64) -> Response {
+        if self.event_streams.fetch_add(1, Ordering::SeqCst) >= MAX_EVENT_STREAMS {
+            self.event_streams.fetch_sub(1, Ordering::SeqCst);
+            error!("Event stream rejected, there are already {} streams open", MAX_EVENT_STREAMS);
+            return error_response(BadTooManyOperations);
+        }
+        let slot = EventStreamSlot {
+            event_streams: self.event_streams.clone(),
+        };
+        let (mut sender, body) = Body::pair();
+        let api = self.clone();
+        thread::spawn(move || {
+            let _slot = slot;
+            let keep_alive = time::Duration::from_millis(EVENT_KEEP_ALIVE_MS);
+            let mut last_send = time::Instant::now();
+            let mut last_values: Vec<Option<ApiDataValue>> = node_ids.iter().map(|_| None).collect();
+            loop {
+                {
+                    let server_state = trace_read_lock_unwrap!(api.server_state);
+                    if server_state.abort {
+                        break;
+                    }
+                }
+                let values = match api.read_values(&node_ids) {
+                    Ok(values) => values,
+                    Err(err) => {
+                        error!("Event stream cannot read values, error {:?}", err);
+                        break;
+                    }
+                };
+                let mut events = String::new();
+                for (i, value) in values.into_iter().enumerate() {
+                    let value = ApiDataValue::from(value);
+                    // Only the value and status are compared since timestamps change on every read
+                    let changed = if let Some(ref last_value) = last_values[i] {
+                        last_value.value != value.value || last_value.status != value.status
+                    } else {
+                        true
+                    };
+                    if changed {
+                        let event = ApiValueEvent {
+                            node_id: node_ids[i].to_string(),
+                            value: &value,
+                        };
+                        events.push_str(&format!("event: value\ndata: {}\n\n", serde_json::to_string(&event).unwrap()));
+                        last_values[i] = Some(value);
+                    }
+                }
+                if events.is_empty() && last_send.elapsed() >= keep_alive {
+                    events.push_str(": keep-alive\n\n");
+                }
+                if !events.is_empty() {
+                    last_send = time::Instant::now();
+                    sender = match sender.send(Ok(Chunk::from(events))).wait() {
+                        Ok(sender) => sender,
+                        Err(_) => {
+                            // Client has gone away
+                            break;
+                        }
+                    };
+                }
+                thread::sleep(time::Duration::from_millis(interval));
+            }
+            debug!("Event stream has ended");
+        });
+        Response::new()
+            .with_header(ContentType(mime::TEXT_EVENT_STREAM))
+            .with_body(body)
+    }
+}
+
+fn make_request_header() -> RequestHeader {
+    RequestHeader::new(&NodeId::null(), &DateTime::now(), 0)
+}
+
+fn first_result<T>(results: Option<Vec<T>>) -> Result<T, StatusCode> {
+    if let Some(mut results) = results {
+        if !results.is_empty() {
+            return Ok(results.remove(0));
+        }
+    }
+    Err(BadUnexpectedError)
+}
+
+fn parse_node_id(node_id: &str) -> Result<NodeId, StatusCode> {
+    NodeId::from_str(node_id).map_err(|_| BadNodeIdInvalid)
+}
+
+fn parse_body<'a, T>(body: &'a [u8]) -> Result<T, StatusCode> where T: serde::Deserialize<'a> {
+    serde_json::from_slice(body).map_err(|err| {
+        error!("Api request body is invalid, {}", err);
+        BadDecodingError
+    })
+}
+
+/// Splits a query string into its decoded name value pairs
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+        let mut pair = pair.splitn(2, '=');
+        let key = percent_decode(&pair.next().unwrap().replace('+', " "));
+        let value = percent_decode(&pair.next().unwrap_or("").replace('+', " "));
+        (key, value)
+    }).collect()
+}
+
+/// Decodes percent-encoded characters in a path segment or query component. Malformed sequences
+/// are left as they are.
+pub fn percent_decode(value: &str) -> String {
+    fn hex_value(b: u8) -> Option<u8> {
+        match b {
+            b'0'...b'9' => Some(b - b'0'),
+            b'a'...b'f' => Some(b - b'a' + 10),
+            b'A'...b'F' => Some(b - b'A' + 10),
+            _ => None
+        }
+    }
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                decoded.push(hi << 4 | lo);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Maps a status code onto the closest http status
+pub fn http_status(status_code: StatusCode) -> HttpStatusCode {
+    match status_code {
+        BadNodeIdUnknown | BadMethodInvalid => HttpStatusCode::NotFound,
+        BadIdentityTokenInvalid | BadIdentityTokenRejected => HttpStatusCode::Unauthorized,
+        BadUserAccessDenied | BadNotReadable | BadNotWritable => HttpStatusCode::Forbidden,
+        BadInternalError | BadUnexpectedError | BadOutOfMemory => HttpStatusCode::InternalServerError,
+        BadTooManyOperations => HttpStatusCode::ServiceUnavailable,
+        _ => HttpStatusCode::BadRequest,
+    }
+}
+
+fn error_response(status_code: StatusCode) -> Response {
+    let error = ApiStatus::from(status_code);
+    Response::new()
+        .with_status(http_status(status_code))
+        .with_header(ContentType::json())
+        .with_body(serde_json::to_string_pretty(&error).unwrap())
+}
+
+fn json_result<T>(result: Result<T, StatusCode>) -> Response where T: serde::Serialize {
+    match result {
+        Ok(result) => {
+            Response::new()
+                .with_header(ContentType::json())
+                .with_body(serde_json::to_string_pretty(&result).unwrap())
+        }
+        Err(status_code) => error_response(status_code)
+    }
+}
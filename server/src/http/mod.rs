@@ -5,26 +5,32 @@ use hyper::{Method, StatusCode};
 use hyper::header::ContentType;
 use hyper::server::{Http, NewService, Request, Response, Service};
 use serde_json;
-use server::Connections;
+use server::{Connections, Server};
 use metrics::ServerMetrics;
 use state::ServerState;
 use std::io;
 use std::sync::{Arc, RwLock};
 use std::thread;
 
+pub mod api;
+
+use self::api::{Api, ApiAuthenticator};
+
 /// This is our metrics service, the thing called to handle requests coming from hyper
 struct MetricsService {
     server_state: Arc<RwLock<ServerState>>,
     connections: Arc<RwLock<Connections>>,
     server_metrics: Arc<RwLock<ServerMetrics>>,
+    api: Api,
 }
 
 impl MetricsService {
-    fn new(server_state: Arc<RwLock<ServerState>>, connections: Arc<RwLock<Connections>>, server_metrics: Arc<RwLock<ServerMetrics>>) -> MetricsService {
+    fn new(server_state: Arc<RwLock<ServerState>>, connections: Arc<RwLock<Connections>>, server_metrics: Arc<RwLock<ServerMetrics>>, api: Api) -> MetricsService {
         MetricsService {
             server_state,
             connections,
             server_metrics,
+            api,
         }
     }
 }
@@ -39,6 +45,9 @@ impl Service for MetricsService {
     type Future = Box<Future<Item=Self::Response, Error=Self::Error>>;
 
     fn call(&self, req: Request) -> Self::Future {
+        if req.path().starts_with("/api/") {
+            return self.api.handle(req);
+        }
         let mut response = Response::new();
         match (req.method(), req.path()) {
             (&Method::Get, "/") => {
@@ -76,6 +85,7 @@ struct MetricsServiceFactory {
     server_state: Arc<RwLock<ServerState>>,
     connections: Arc<RwLock<Connections>>,
     server_metrics: Arc<RwLock<ServerMetrics>>,
+    api: Api,
 }
 
 impl NewService for MetricsServiceFactory {
@@ -85,20 +95,22 @@ impl NewService for MetricsServiceFactory {
     type Instance = MetricsService;
 
     fn new_service(&self) -> io::Result<Self::Instance> {
-        Ok(MetricsService::new(self.server_state.clone(), self.connections.clone(), self.server_metrics.clone()))
+        Ok(MetricsService::new(self.server_state.clone(), self.connections.clone(), self.server_metrics.clone(), self.api.clone()))
     }
 }
 
-/// Runs an http server on the specified binding address, serving out the supplied server metrics
-pub fn run_http_server(address: &str, server_state: Arc<RwLock<ServerState>>, connections: Arc<RwLock<Connections>>, server_metrics: Arc<RwLock<ServerMetrics>>) -> thread::JoinHandle<()> {
+/// Runs an http server on the specified binding address, serving out the server metrics and
+/// a JSON api onto the address space. Api requests are checked by the supplied authenticator.
+pub fn run_http_server(address: &str, server: &Server, authenticator: Arc<ApiAuthenticator>) -> thread::JoinHandle<()> {
     let address = address.parse().unwrap();
+    let metrics_factory = MetricsServiceFactory {
+        server_state: server.server_state.clone(),
+        connections: server.connections.clone(),
+        server_metrics: server.server_metrics.clone(),
+        api: Api::new(server, authenticator),
+    };
     thread::spawn(move || {
         // info!("HTTP server is running on {} to provide OPC UA server metrics", address);
-        let metrics_factory = MetricsServiceFactory {
-            server_state,
-            connections,
-            server_metrics,
-        };
         let http_server = Http::new().bind(&address, metrics_factory).unwrap();
         http_server.run().unwrap();
     })
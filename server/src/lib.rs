@@ -3,11 +3,13 @@
 
 extern crate chrono;
 extern crate futures;
+extern crate futures_cpupool;
 #[cfg(feature = "http")]
 extern crate hyper;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
-extern crate ring;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -61,12 +63,20 @@ pub mod constants {
 
     /// The default hello timeout period in seconds
     pub const DEFAULT_HELLO_TIMEOUT_SECONDS: u32 = 120;
+    /// Default interval in millis to check the pki folder for a new certificate or trust list
+    pub const DEFAULT_PKI_WATCH_INTERVAL_MS: u32 = 5000;
     /// Default OPC UA server port for this implementation
     pub const DEFAULT_RUST_OPC_UA_SERVER_PORT: u16 = 4855;
     /// Default maximum number of subscriptions in a session
     pub const DEFAULT_MAX_SUBSCRIPTIONS: u32 = 100;
+    /// Default number of worker threads that execute service requests, 0 for one per CPU
+    pub const DEFAULT_WORKER_THREADS: u32 = 0;
     /// Default, well known address for TCP discovery server
     pub const DEFAULT_DISCOVERY_SERVER_URL: &str = "opc.tcp://localhost:4840/UADiscovery";
+    /// The uri of the OPC UA namespace, always index 0 in the namespace table
+    pub const OPC_UA_NAMESPACE_URI: &str = "http://opcfoundation.org/UA/";
+    /// The uri of the server's internal namespace, index 1 in the namespace table
+    pub const INTERNAL_NAMESPACE_URI: &str = "urn:OPCUA-Rust-Internal";
 
     // Internally controlled values
 
@@ -80,11 +90,15 @@ pub mod constants {
     pub const MIN_DATA_CHANGE_QUEUE_SIZE: usize = 1;
     /// Maximum data change queue allowed by clients on monitored items
     pub const MAX_DATA_CHANGE_QUEUE_SIZE: usize = 10;
+    /// Maximum number of changes to a value that are held for a monitored item between ticks of
+    /// its subscription. The oldest are discarded after that.
+    pub const MAX_PENDING_DATA_CHANGES: usize = 100;
     /// The default size of preallocated vecs of monitored items per subscription
     pub const DEFAULT_MONITORED_ITEM_CAPACITY: usize = 100;
     /// Sampling interval in MS used internally to poll subscriptions. The more finegrained this is
-    /// the more often subscriptions will be checked to see if their subscription interval has elapsed
-    /// therefore the value should be < min sampling interval
+    /// the more often subscriptions will be checked to see if their subscription interval has elapsed.
+    /// Values that are set on nodes are pushed to monitored items and sampled at any interval, but
+    /// values that come from a getter are polled at no more than this rate.
     pub const SUBSCRIPTION_TIMER_RATE_MS: i64 = 100;
     /// Interval to check for HELLO timeout in millis. This can be fairly coarse because it's not
     /// something that requires huge accuracy.
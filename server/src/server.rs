@@ -11,6 +11,7 @@ use chrono;
 use futures::{Future, Stream};
 use futures::future;
 use futures::sync::mpsc::{unbounded, UnboundedSender};
+use futures_cpupool::{Builder as CpuPoolBuilder, CpuPool};
 use tokio;
 use tokio::net::{TcpListener, TcpStream};
 use tokio_timer;
@@ -50,6 +51,8 @@ pub struct Server {
     pub address_space: Arc<RwLock<AddressSpace>>,
     /// List of open connections
     pub connections: Arc<RwLock<Connections>>,
+    /// The pool of threads that execute service requests
+    worker_pool: CpuPool,
 }
 
 impl Server {
@@ -63,11 +66,17 @@ impl Server {
         let application_name = config.application_name.clone();
         let application_uri = UAString::from(config.application_uri.as_ref());
         let product_uri = UAString::from(config.product_uri.as_ref());
-        let namespaces = vec!["http://opcfoundation.org/UA/".to_string(), "urn:OPCUA-Rust-Internal".to_string(), config.application_uri.clone()];
         let start_time = DateTime::now();
         let servers = vec![config.application_uri.clone()];
         let base_endpoint = format!("opc.tcp://{}:{}", config.tcp_config.host, config.tcp_config.port);
         let max_subscriptions = config.max_subscriptions as usize;
+        let worker_pool = {
+            let mut builder = CpuPoolBuilder::new();
+            if config.worker_threads > 0 {
+                builder.pool_size(config.worker_threads as usize);
+            }
+            builder.name_prefix("opcua-worker-").create()
+        };
         let diagnostics = Arc::new(RwLock::new(ServerDiagnostics::new()));
         // TODO max string, byte string and array lengths
 
@@ -86,7 +95,6 @@ impl Server {
                 locale: UAString::null(),
                 text: UAString::from(application_name),
             },
-            namespaces,
             servers,
             base_endpoint,
             state: ServerStateType::Shutdown,
@@ -117,6 +125,12 @@ impl Server {
         // Cert store
         let certificate_store = Arc::new(RwLock::new(certificate_store));
 
+        // Certificate management through the ServerConfiguration object
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            address_space.add_server_configuration(certificate_store.clone());
+        }
+
         let server = Server {
             pending_polling_actions: Vec::new(),
             server_state,
@@ -124,6 +138,7 @@ impl Server {
             address_space,
             certificate_store,
             connections: Arc::new(RwLock::new(Vec::new())),
+            worker_pool,
         };
 
         let mut server_metrics = trace_write_lock_unwrap!(server_metrics);
@@ -200,13 +215,13 @@ impl Server {
             server.log_endpoint_info();
         }
 
-        // Get the address and discovery url
-        let (sock_addr, discovery_server_url) = {
+        // Get the address, discovery url and pki watch interval
+        let (sock_addr, discovery_server_url, pki_watch_interval_ms) = {
             let server = trace_read_lock_unwrap!(server);
             let sock_addr = server.get_socket_address();
             let server_state = trace_read_lock_unwrap!(server.server_state);
             let config = trace_read_lock_unwrap!(server_state.config);
-            (sock_addr, config.discovery_server_url.clone())
+            (sock_addr, config.discovery_server_url.clone(), config.pki_watch_interval_ms)
         };
 
         if sock_addr.is_none() {
@@ -238,6 +253,8 @@ impl Server {
 
                     // Start a timer that registers the server with a discovery server
                     server.start_discovery_server_registration_timer(discovery_server_url);
+                    // Start a timer that reloads the certificate store when the pki folder changes
+                    server.start_pki_watch_timer(pki_watch_interval_ms);
                     // Start any pending polling action timers
                     server.start_pending_polling_actions();
                 }
@@ -325,6 +342,67 @@ impl Server {
         }
     }
 
+    /// Start a timer that checks the pki folder on an interval and reloads the certificate store
+    /// if anything in it has changed
+    fn start_pki_watch_timer(&self, pki_watch_interval_ms: u32) {
+        if pki_watch_interval_ms > 0 {
+            let certificate_store = self.certificate_store.clone();
+            let server_state = self.server_state.clone();
+            let interval_timer = tokio_timer::Timer::default()
+                .interval(chrono::Duration::milliseconds(pki_watch_interval_ms as i64).to_std().unwrap())
+                .for_each(move |_| {
+                    let has_changed = {
+                        let certificate_store = trace_read_lock_unwrap!(certificate_store);
+                        certificate_store.has_changed()
+                    };
+                    if has_changed {
+                        info!("The pki folder has changed so the certificate store will be reloaded");
+                        if let Err(err) = Self::reload_certificate_store(&certificate_store, &server_state) {
+                            error!("Certificate store could not be reloaded, error = {}", err);
+                        }
+                    }
+                    Ok(())
+                });
+            tokio::spawn(interval_timer.map_err(|_| ()));
+        } else {
+            info!("Server does not watch the pki folder, so certificates are only reloaded on request");
+        }
+    }
+
+    /// Reloads the server's application instance certificate, private key and trust lists from
+    /// the pki folder without a restart. Secure channels opened from now on use the new
+    /// certificate and key while existing secure channels carry on with the ones they opened with.
+    ///
+    /// # Errors
+    ///
+    /// A string description of any failure, in which case the server keeps its current
+    /// certificate and key.
+    ///
+    pub fn reload_certificates(&self) -> Result<(), String> {
+        Self::reload_certificate_store(&self.certificate_store, &self.server_state)
+    }
+
+    pub(crate) fn reload_certificate_store(certificate_store: &Arc<RwLock<CertificateStore>>, server_state: &Arc<RwLock<ServerState>>) -> Result<(), String> {
+        let (server_certificate, server_pkey) = {
+            let mut certificate_store = trace_write_lock_unwrap!(certificate_store);
+            certificate_store.reload()?;
+            certificate_store.own_cert_and_pkey()?
+        };
+        let mut server_state = trace_write_lock_unwrap!(server_state);
+        server_state.server_certificate = Some(server_certificate);
+        server_state.server_pkey = Some(server_pkey);
+        Ok(())
+    }
+
+    /// Registers a namespace uri in the address space's namespace table and returns its index,
+    /// which is the namespace index of node ids and browse names in that namespace. Registering a
+    /// uri that is already in the table returns its existing index. The server's NamespaceArray
+    /// variable reflects the table while the server is running.
+    pub fn register_namespace(&self, namespace_uri: &str) -> UInt16 {
+        let mut address_space = trace_write_lock_unwrap!(self.address_space);
+        address_space.register_namespace(namespace_uri)
+    }
+
     /// Creates a polling action that happens continuously on an interval while the server
     /// is running.
     pub fn add_polling_action<F>(&mut self, interval_ms: u32, action: F)
@@ -361,7 +439,7 @@ impl Server {
         };
         let address_space = self.address_space.clone();
         let message_handler = MessageHandler::new(self.certificate_store.clone(), self.server_state.clone(), session.clone(), address_space.clone());
-        TcpTransport::new(self.server_state.clone(), session, address_space, message_handler)
+        TcpTransport::new(self.server_state.clone(), session, address_space, message_handler, self.worker_pool.clone())
     }
 
     /// Handles the incoming request
@@ -9,6 +9,7 @@ use services::Service;
 use address_space::access_level;
 use address_space::address_space::AddressSpace;
 use address_space::node::NodeType;
+use address_space::value_source::AsyncValue;
 
 pub struct AttributeService {}
 
@@ -56,6 +57,22 @@ impl AttributeService {
         Ok(response.into())
     }
 
+    /// Returns the asynchronous sources of the values to read, which are refreshed according to
+    /// the request's `max_age` before the address space is locked for the read
+    pub fn value_sources(&self, address_space: &AddressSpace, request: &ReadRequest) -> Vec<AsyncValue> {
+        if let Some(ref nodes_to_read) = request.nodes_to_read {
+            nodes_to_read.iter().filter(|node_to_read| node_to_read.attribute_id == AttributeId::Value as UInt32).filter_map(|node_to_read| {
+                if let Some(&NodeType::Variable(ref variable)) = address_space.find_node(&node_to_read.node_id) {
+                    variable.value_source().cloned()
+                } else {
+                    None
+                }
+            }).collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     fn read_node_value(address_space: &AddressSpace, node_to_read: &ReadValueId, timestamps_to_return: TimestampsToReturn) -> DataValue {
         let mut result_value = DataValue {
             value: None,
@@ -150,6 +167,12 @@ impl AttributeService {
     }
 
     fn write_node_value(address_space: &mut AddressSpace, node_to_write: &WriteValue) -> StatusCode {
+        // Values written to variables must match the variable's data type, value rank and array dimensions
+        let value_constraints = if node_to_write.attribute_id == AttributeId::Value as UInt32 {
+            address_space.value_constraints(&node_to_write.node_id)
+        } else {
+            None
+        };
         if let Some(node) = address_space.find_node_mut(&node_to_write.node_id) {
             if let Ok(attribute_id) = AttributeId::from_u32(node_to_write.attribute_id) {
                 let is_writable = Self::is_writable(&node, attribute_id);
@@ -159,8 +182,19 @@ impl AttributeService {
                     // Index ranges are not supported
                     BadWriteNotSupported
                 } else {
+                    let value = if let Some(value_constraints) = value_constraints {
+                        match value_constraints.check_data_value(node_to_write.value.clone()) {
+                            Ok(value) => value,
+                            Err(err) => {
+                                warn!("Value written to node id {:?} is invalid, {:?}", node_to_write.node_id, err);
+                                return err;
+                            }
+                        }
+                    } else {
+                        node_to_write.value.clone()
+                    };
                     let node = node.as_mut_node();
-                    let result = node.set_attribute(attribute_id, node_to_write.value.clone());
+                    let result = node.set_attribute(attribute_id, value);
                     if result.is_err() {
                         result.unwrap_err()
                     } else {
@@ -1,9 +1,11 @@
 use address_space::address_space::AddressSpace;
+use address_space::value_source::AsyncValue;
 use opcua_core::crypto::CertificateStore;
 use opcua_types::*;
 use opcua_types::service_types::*;
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
+use server::Server;
 use state::ServerState;
 use services::attribute::AttributeService;
 use services::discovery::DiscoveryService;
@@ -62,11 +64,16 @@ impl MessageHandler {
     ///
     /// The request header should contain the session authentication token issued during a
     /// CreateSession or the request is invalid. An invalid token can cause the session to close.
-    fn validate_request(&self, session: &mut Session, request_header: &RequestHeader) -> Result<(), SupportedMessage> {
+    fn validate_request(&self, request_header: &RequestHeader) -> Result<(), SupportedMessage> {
         // TODO if session's token is null, it might be possible to retrieve session state from a
         // previously closed session and reassociate it if the authentication token is recognized
-        if session.authentication_token != request_header.authentication_token {
+        let is_valid = {
+            let session = trace_read_lock_unwrap!(self.session);
+            session.authentication_token == request_header.authentication_token
+        };
+        if !is_valid {
             // Session should terminate
+            let mut session = trace_write_lock_unwrap!(self.session);
             session.terminate_session = true;
             Err(ServiceFault::new_supported_message(request_header, BadIdentityTokenRejected))
         } else {
@@ -74,139 +81,143 @@ impl MessageHandler {
         }
     }
 
-    pub fn handle_message(&mut self, request_id: UInt32, message: SupportedMessage) -> Result<Option<SupportedMessage>, StatusCode> {
-        // Note address space has to be locked before server_state because of deadlock in address_space.rs
-        // or other vars tied to state that will happen the other way around.
-        let mut server_state = trace_write_lock_unwrap!(self.server_state);
-        let mut session = trace_write_lock_unwrap!(self.session);
-
-        // This MUST be last of the lockable items because server impls may set timers on this but not
-        // state / session.
-        let mut address_space = trace_write_lock_unwrap!(self.address_space);
+    /// Handles a message and returns the response to it, if there is one yet.
+    ///
+    /// Each kind of request locks only the state it uses, and services that only read the
+    /// address space, such as Read, Browse, TranslateBrowsePathsToNodeIds, Call and the sampling
+    /// of Publish, hold a read lock on it. So the requests of different sessions proceed in
+    /// parallel. A slow `AttributeGetter` holds up writes to the address space, and the requests
+    /// that queue behind a waiting write. Locks are always taken in the order server state,
+    /// session, address space.
+    pub fn handle_message(&self, request_id: UInt32, message: SupportedMessage) -> Result<Option<SupportedMessage>, StatusCode> {
+        // Requests other than these must be validated against the session
+        match message {
+            SupportedMessage::GetEndpointsRequest(_) |
+            SupportedMessage::CreateSessionRequest(_) |
+            SupportedMessage::CloseSessionRequest(_) => {}
+            _ => {
+                if let Some(request_header) = Self::request_header(&message) {
+                    if let Err(response) = self.validate_request(request_header) {
+                        return Ok(Some(response));
+                    }
+                }
+            }
+        }
 
         let response = match message {
             SupportedMessage::GetEndpointsRequest(request) => {
+                let server_state = trace_read_lock_unwrap!(self.server_state);
                 Some(self.discovery_service.get_endpoints(&server_state, request)?)
             }
             SupportedMessage::CreateSessionRequest(request) => {
                 let certificate_store = trace_read_lock_unwrap!(self.certificate_store);
+                let mut server_state = trace_write_lock_unwrap!(self.server_state);
+                let mut session = trace_write_lock_unwrap!(self.session);
                 Some(self.session_service.create_session(&certificate_store, &mut server_state, &mut session, request)?)
             }
             SupportedMessage::CloseSessionRequest(request) => {
+                let mut session = trace_write_lock_unwrap!(self.session);
+                {
+                    let address_space = trace_read_lock_unwrap!(self.address_space);
+                    address_space.session_closed(&session.authentication_token);
+                }
                 Some(self.session_service.close_session(&mut session, request)?)
             }
-            // ALL THE REQUESTS BELOW MUST BE VALIDATED AGAINST THE SESSION
             SupportedMessage::ActivateSessionRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.session_service.activate_session(&mut server_state, &mut session, request)?)
-                }
+                let mut server_state = trace_write_lock_unwrap!(self.server_state);
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.session_service.activate_session(&mut server_state, &mut session, request)?)
             }
             SupportedMessage::CreateSubscriptionRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.subscription_service.create_subscription(&mut server_state, &mut session, request)?)
-                }
+                let mut server_state = trace_write_lock_unwrap!(self.server_state);
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.subscription_service.create_subscription(&mut server_state, &mut session, request)?)
             }
             SupportedMessage::ModifySubscriptionRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.subscription_service.modify_subscription(&mut server_state, &mut session, request)?)
-                }
+                let mut server_state = trace_write_lock_unwrap!(self.server_state);
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.subscription_service.modify_subscription(&mut server_state, &mut session, request)?)
             }
             SupportedMessage::DeleteSubscriptionsRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.subscription_service.delete_subscriptions(&mut session, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.subscription_service.delete_subscriptions(&mut session, request)?)
             }
             SupportedMessage::SetPublishingModeRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.subscription_service.set_publishing_mode(&mut session, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.subscription_service.set_publishing_mode(&mut session, request)?)
             }
             SupportedMessage::PublishRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    self.subscription_service.publish(&mut session, request_id, &address_space, request)?
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                let address_space = trace_read_lock_unwrap!(self.address_space);
+                self.subscription_service.publish(&mut session, request_id, &address_space, request)?
             }
             SupportedMessage::RepublishRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.subscription_service.republish(&mut session, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.subscription_service.republish(&mut session, request)?)
             }
             SupportedMessage::BrowseRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.view_service.browse(&mut session, &address_space, request)?)
-                }
+                // The session holds the continuation points of the browse
+                let mut session = trace_write_lock_unwrap!(self.session);
+                let address_space = trace_read_lock_unwrap!(self.address_space);
+                Some(self.view_service.browse(&mut session, &address_space, request)?)
             }
             SupportedMessage::BrowseNextRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.view_service.browse_next(&mut session, &address_space, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                let address_space = trace_read_lock_unwrap!(self.address_space);
+                Some(self.view_service.browse_next(&mut session, &address_space, request)?)
             }
             SupportedMessage::TranslateBrowsePathsToNodeIdsRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.view_service.translate_browse_paths_to_node_ids(&address_space, request)?)
-                }
+                let address_space = trace_read_lock_unwrap!(self.address_space);
+                Some(self.view_service.translate_browse_paths_to_node_ids(&address_space, request)?)
             }
             SupportedMessage::ReadRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.attribute_service.read(&address_space, request)?)
+                // Values from slow sources are refreshed without holding the address space lock
+                let value_sources = {
+                    let address_space = trace_read_lock_unwrap!(self.address_space);
+                    self.attribute_service.value_sources(&address_space, &request)
+                };
+                if !value_sources.is_empty() && request.max_age >= 0f64 {
+                    AsyncValue::refresh_all(&value_sources, request.max_age);
                 }
+                let address_space = trace_read_lock_unwrap!(self.address_space);
+                Some(self.attribute_service.read(&address_space, request)?)
             }
             SupportedMessage::WriteRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.attribute_service.write(&mut address_space, request)?)
-                }
+                let mut address_space = trace_write_lock_unwrap!(self.address_space);
+                Some(self.attribute_service.write(&mut address_space, request)?)
             }
             SupportedMessage::CreateMonitoredItemsRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.monitored_item_service.create_monitored_items(&mut session, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.monitored_item_service.create_monitored_items(&mut session, request)?)
             }
             SupportedMessage::ModifyMonitoredItemsRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.monitored_item_service.modify_monitored_items(&mut session, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.monitored_item_service.modify_monitored_items(&mut session, request)?)
             }
             SupportedMessage::DeleteMonitoredItemsRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.monitored_item_service.delete_monitored_items(&mut session, request)?)
-                }
+                let mut session = trace_write_lock_unwrap!(self.session);
+                Some(self.monitored_item_service.delete_monitored_items(&mut session, request)?)
             }
             SupportedMessage::CallRequest(request) => {
-                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
-                    Some(response)
-                } else {
-                    Some(self.method_service.call(&address_space, &server_state, &session, request)?)
-                }
+                let (response, reload_certificates) = {
+                    let server_state = trace_read_lock_unwrap!(self.server_state);
+                    let session = trace_read_lock_unwrap!(self.session);
+                    let address_space = trace_read_lock_unwrap!(self.address_space);
+                    let response = self.method_service.call(&address_space, &server_state, &session, request)?;
+                    let reload_certificates = address_space.certificate_manager().map_or(false, |certificate_manager| {
+                        let mut certificate_manager = trace_lock_unwrap!(certificate_manager);
+                        certificate_manager.take_reload_request()
+                    });
+                    (response, reload_certificates)
+                };
+                // ApplyChanges stores a new certificate, which replaces the one in the server state
+                // once the call no longer holds it
+                if reload_certificates {
+                    if let Err(err) = Server::reload_certificate_store(&self.certificate_store, &self.server_state) {
+                        error!("Certificate store could not be reloaded after applying changes, error = {}", err);
+                    }
+                }
+                Some(response)
             }
             _ => {
                 debug!("Message handler does not handle this kind of message {:?}", message);
@@ -215,4 +226,27 @@ impl MessageHandler {
         };
         Ok(response)
     }
+
+    /// Returns the request header of the requests that are validated against the session
+    fn request_header(message: &SupportedMessage) -> Option<&RequestHeader> {
+        match *message {
+            SupportedMessage::ActivateSessionRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::CreateSubscriptionRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::ModifySubscriptionRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::DeleteSubscriptionsRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::SetPublishingModeRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::PublishRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::RepublishRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::BrowseRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::BrowseNextRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::TranslateBrowsePathsToNodeIdsRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::ReadRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::WriteRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::CreateMonitoredItemsRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::ModifyMonitoredItemsRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::DeleteMonitoredItemsRequest(ref request) => Some(&request.request_header),
+            SupportedMessage::CallRequest(ref request) => Some(&request.request_header),
+            _ => None
+        }
+    }
 }
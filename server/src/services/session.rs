@@ -69,8 +69,9 @@ impl SessionService {
             let session_timeout = constants::SESSION_TIMEOUT;
             let max_request_message_size = constants::MAX_REQUEST_MESSAGE_SIZE;
 
-            // Calculate a signature (assuming there is a pkey)
-            let server_signature = if let Some(ref pkey) = server_state.server_pkey {
+            // Calculate a signature (assuming there is a pkey). The secure channel's cert and key
+            // are used since the server's may have been reloaded since the channel was created.
+            let server_signature = if let Some(ref pkey) = session.secure_channel.private_key() {
                 crypto::create_signature_data(pkey, security_policy, &request.client_certificate, &request.client_nonce)?
             } else {
                 SignatureData::null()
@@ -78,7 +79,11 @@ impl SessionService {
 
             // Crypto
             let server_nonce = security_policy.nonce();
-            let server_certificate = server_state.server_certificate_as_byte_string();
+            let server_certificate = if let Some(ref server_certificate) = session.secure_channel.cert() {
+                server_certificate.as_byte_string()
+            } else {
+                ByteString::null()
+            };
             let server_endpoints = Some(endpoints);
 
             session.session_id = session_id.clone();
@@ -122,7 +127,7 @@ impl SessionService {
         } else if security_policy != SecurityPolicy::None {
             // Crypto see 5.6.3.1 verify the caller is the same caller as create_session by validating
             // signature supplied by the client during the create.
-            Self::verify_client_signature(server_state, session, &request.client_signature)
+            Self::verify_client_signature(session, &request.client_signature)
         } else {
             // No cert checks for no security
             Good
@@ -136,6 +141,7 @@ impl SessionService {
         let response = if service_result.is_good() {
             session.activated = true;
             session.session_nonce = server_nonce;
+            session.user_identity = Some(request.user_identity_token.clone());
             let diagnostic_infos = None;
             ActivateSessionResponse {
                 response_header: ResponseHeader::new_good(&request.request_header),
@@ -161,9 +167,9 @@ impl SessionService {
 
     /// Verifies that the supplied client signature was produced by the session's client certificate
     /// from the server's certificate and nonce.
-    fn verify_client_signature(server_state: &ServerState, session: &Session, client_signature: &SignatureData) -> StatusCode {
+    fn verify_client_signature(session: &Session, client_signature: &SignatureData) -> StatusCode {
         if let Some(ref client_certificate) = session.client_certificate {
-            if let Some(ref server_certificate) = server_state.server_certificate {
+            if let Some(ref server_certificate) = session.secure_channel.cert() {
                 let security_policy = session.secure_channel.security_policy();
                 crypto::verify_signature_data(client_signature, security_policy, client_certificate, server_certificate, &session.session_nonce)
             } else {
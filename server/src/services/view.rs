@@ -110,7 +110,7 @@ impl ViewService {
                     }
                 } else {
                     let result = result.unwrap();
-                    let targets = if !result.is_empty() {
+                    if !result.is_empty() {
                         use std::u32;
                         let targets = result.iter().map(|node_id| {
                             BrowsePathTarget {
@@ -118,13 +118,17 @@ impl ViewService {
                                 remaining_path_index: u32::MAX as UInt32,
                             }
                         }).collect();
-                        Some(targets)
+                        BrowsePathResult {
+                            status_code: Good,
+                            targets: Some(targets),
+                        }
                     } else {
-                        None
-                    };
-                    BrowsePathResult {
-                        status_code: Good,
-                        targets,
+                        // Browse names are compared with their namespace so a name in the wrong
+                        // namespace does not match
+                        BrowsePathResult {
+                            status_code: BadNoMatch,
+                            targets: None,
+                        }
                     }
                 }
             }
@@ -171,24 +175,28 @@ impl ViewService {
             }
         };
 
-        // Fetch the references to / from the given node to browse
-
-        let (references, inverse_ref_idx) = address_space.find_references_by_direction(&node_to_browse.node_id, node_to_browse.browse_direction, reference_type_id);
+        // Iterate the references to / from the given node to browse
+        let forward_references = match node_to_browse.browse_direction {
+            BrowseDirection::Inverse => None,
+            _ => Some(address_space.references_from(&node_to_browse.node_id, reference_type_id)),
+        };
+        let inverse_references = match node_to_browse.browse_direction {
+            BrowseDirection::Forward => None,
+            _ => Some(address_space.references_to(&node_to_browse.node_id, reference_type_id)),
+        };
+        let references = forward_references.into_iter().flat_map(|r| r.map(|(reference_type_id, node_id)| (reference_type_id, node_id, true)))
+            .chain(inverse_references.into_iter().flat_map(|r| r.map(|(reference_type_id, node_id)| (reference_type_id, node_id, false))));
 
         let result_mask = node_to_browse.result_mask;
         let node_class_mask = node_to_browse.node_class_mask;
 
         // Construct descriptions for each reference
         let mut reference_descriptions: Vec<ReferenceDescription> = Vec::with_capacity(max_references_per_node);
-        for (idx, reference) in references.iter().enumerate() {
-            if idx < starting_index {
-                continue;
-            }
-            let target_node_id = reference.node_id.clone();
+        for (reference_type_id, target_node_id, is_forward) in references.skip(starting_index) {
             if target_node_id.is_null() {
                 continue;
             }
-            let target_node = address_space.find_node(&target_node_id);
+            let target_node = address_space.find_node(target_node_id);
             if target_node.is_none() {
                 continue;
             }
@@ -203,12 +211,16 @@ impl ViewService {
 
             // Prepare the values to put into the struct according to the result mask
             let reference_type_id = if result_mask & RESULT_MASK_REFERENCE_TYPE != 0 {
-                reference.reference_type_id.into()
+                if is_forward {
+                    address_space.reference_type(&node_to_browse.node_id, target_node_id, reference_type_id)
+                } else {
+                    address_space.reference_type(target_node_id, &node_to_browse.node_id, reference_type_id)
+                }
             } else {
                 NodeId::null()
             };
             let is_forward = if result_mask & RESULT_MASK_IS_FORWARD != 0 {
-                idx < inverse_ref_idx
+                is_forward
             } else {
                 true
             };
@@ -234,9 +246,9 @@ impl ViewService {
                 // shall be returned.
                 match target_node_class {
                     NodeClass::Object | NodeClass::Variable => {
-                        let type_defs = address_space.find_references_from(&target_node.node_id(), Some((ReferenceTypeId::HasTypeDefinition, false)));
-                        if let Some(type_defs) = type_defs {
-                            ExpandedNodeId::new(type_defs[0].node_id.clone())
+                        let type_def = address_space.references_from(target_node_id, Some((ReferenceTypeId::HasTypeDefinition, false))).next();
+                        if let Some((_, type_def)) = type_def {
+                            ExpandedNodeId::new(type_def.clone())
                         } else {
                             ExpandedNodeId::null()
                         }
@@ -250,7 +262,7 @@ impl ViewService {
             };
 
             let reference_description = ReferenceDescription {
-                node_id: ExpandedNodeId::new(target_node_id),
+                node_id: ExpandedNodeId::new(target_node_id.clone()),
                 reference_type_id,
                 is_forward,
                 node_class: target_node_class,
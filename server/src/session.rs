@@ -4,7 +4,7 @@ use std::sync::{Arc, RwLock};
 use chrono;
 
 use opcua_core::comms::secure_channel::{Role, SecureChannel};
-use opcua_core::crypto::X509;
+use opcua_core::crypto::{CertificateStore, X509};
 use opcua_types::*;
 use opcua_types::service_types::PublishRequest;
 use opcua_types::status_codes::StatusCode;
@@ -14,6 +14,7 @@ use continuation_point::BrowseContinuationPoint;
 use diagnostics::ServerDiagnostics;
 use DateTimeUtc;
 use server::Server;
+use state::ServerState;
 use subscriptions::subscription::TickReason;
 use subscriptions::subscriptions::Subscriptions;
 
@@ -110,11 +111,16 @@ impl Session {
     }
 
     pub fn new(server: &Server) -> Session {
+        Self::new_with_certificate_store(&server.server_state, server.certificate_store.clone())
+    }
+
+    /// Creates a session for the server state, without needing the server itself
+    pub fn new_with_certificate_store(server_state: &Arc<RwLock<ServerState>>, certificate_store: Arc<RwLock<CertificateStore>>) -> Session {
         let max_publish_requests = MAX_DEFAULT_PUBLISH_REQUEST_QUEUE_SIZE;
         let max_browse_continuation_points = super::constants::MAX_BROWSE_CONTINUATION_POINTS;
 
         let diagnostics = {
-            let server_state = trace_read_lock_unwrap!(server.server_state);
+            let server_state = trace_read_lock_unwrap!(server_state);
             server_state.diagnostics.clone()
         };
 
@@ -128,7 +134,7 @@ impl Session {
             client_certificate: None,
             security_policy_uri: String::new(),
             authentication_token: NodeId::null(),
-            secure_channel: SecureChannel::new(server.certificate_store.clone(), Role::Server),
+            secure_channel: SecureChannel::new(certificate_store, Role::Server),
             session_nonce: ByteString::null(),
             session_timeout: 0f64,
             user_identity: None,
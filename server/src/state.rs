@@ -1,6 +1,7 @@
 //! The server module defines types related to the server, it's current running state
 //! and end point information.
 
+use std::collections::BTreeSet;
 use std::sync::{Arc, RwLock};
 
 use opcua_core::prelude::*;
@@ -14,8 +15,8 @@ use opcua_types::status_codes::StatusCode::*;
 use config::{ServerConfig, ServerEndpoint};
 use diagnostics::ServerDiagnostics;
 
-const TOKEN_POLICY_ANONYMOUS: &'static str = "anonymous";
-const TOKEN_POLICY_USER_PASS_PLAINTEXT: &'static str = "userpass_plaintext";
+pub(crate) const TOKEN_POLICY_ANONYMOUS: &'static str = "anonymous";
+pub(crate) const TOKEN_POLICY_USER_PASS_PLAINTEXT: &'static str = "userpass_plaintext";
 
 /// Server state is any state associated with the server as a whole that individual sessions might
 /// be interested in. That includes configuration info etc.
@@ -32,15 +33,14 @@ pub struct ServerState {
     pub state: ServerStateType,
     /// The time the server started
     pub start_time: DateTime,
-    /// The list of namespaces
-    pub namespaces: Vec<String>,
     /// The list of servers (by urn)
     pub servers: Vec<String>,
     /// Server configuration
     pub config: Arc<RwLock<ServerConfig>>,
-    /// Server public certificate read from config location or null if there is none
+    /// Server public certificate read from config location or null if there is none. It is
+    /// replaced when the server reloads its certificate store.
     pub server_certificate: Option<X509>,
-    /// Server private key
+    /// Server private key, replaced along with the certificate
     pub server_pkey: Option<PrivateKey>,
     /// The next subscription id - subscriptions are shared across the whole server. Initial value
     /// is a random u32.
@@ -271,6 +271,55 @@ impl ServerState {
         }
     }
 
+    /// Authenticates a user name and plaintext password against the configured user tokens,
+    /// optionally restricted to the supplied user token ids. This is for callers that do not
+    /// go through an endpoint, e.g. the http api.
+    pub fn authenticate_user_pass(&self, user: &str, pass: &str, user_token_ids: Option<&BTreeSet<String>>) -> StatusCode {
+        let config = trace_read_lock_unwrap!(self.config);
+        let server_user_token = config.user_tokens.iter().find(|&(id, token)| {
+            token.user == user && user_token_ids.map_or(true, |ids| ids.contains(id))
+        });
+        if let Some((_, server_user_token)) = server_user_token {
+            let server_password = if let Some(ref pass) = server_user_token.pass { pass.as_ref() } else { "" };
+            if server_password == pass {
+                Good
+            } else {
+                error!("Cannot authenticate \"{}\", password is invalid", user);
+                BadIdentityTokenRejected
+            }
+        } else {
+            error!("Cannot authenticate \"{}\", user not found", user);
+            BadIdentityTokenRejected
+        }
+    }
+
+    /// Tests if the user identity token, as supplied to ActivateSession, identifies a security
+    /// administrator. The token must be a user name and password matching one of the configured
+    /// security admin user tokens.
+    pub fn is_security_admin(&self, user_identity_token: &Option<ExtensionObject>) -> bool {
+        let token = if let Some(ref user_identity_token) = *user_identity_token {
+            if user_identity_token.node_id != ObjectId::UserNameIdentityToken_Encoding_DefaultBinary.into() {
+                return false;
+            }
+            if let Ok(token) = user_identity_token.decode_inner::<UserNameIdentityToken>() {
+                token
+            } else {
+                return false;
+            }
+        } else {
+            return false;
+        };
+        let config = trace_read_lock_unwrap!(self.config);
+        config.security_admin_user_token_ids.iter().any(|id| {
+            if let Some(server_user_token) = config.user_tokens.get(id) {
+                let server_password = if let Some(ref pass) = server_user_token.pass { pass.as_bytes() } else { b"" };
+                token.authenticate(&server_user_token.user, server_password).is_ok()
+            } else {
+                false
+            }
+        })
+    }
+
     /// Authenticates an anonymous token, i.e. does the endpoint support anonymous access or not
     fn authenticate_anonymous_token(endpoint: &ServerEndpoint) -> StatusCode {
         if endpoint.supports_anonymous() {
@@ -1,5 +1,6 @@
 use std::result::Result;
 use std::collections::VecDeque;
+use std::sync::Arc;
 
 use chrono;
 use time;
@@ -14,6 +15,9 @@ use constants;
 
 use DateTimeUtc;
 use address_space::address_space::AddressSpace;
+use address_space::data_change::{DataChange, DataChangeQueue, DataChangeSignal};
+use address_space::sampler::SamplingGroupMember;
+use address_space::node::NodeType;
 use subscriptions::subscription::TickReason;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -53,6 +57,14 @@ pub struct MonitoredItem {
     timestamps_to_return: TimestampsToReturn,
     last_sample_time: DateTimeUtc,
     last_data_value: Option<DataValue>,
+    /// The changes that are pushed to the item, once it watches the value
+    data_changes: Option<DataChangeQueue>,
+    /// Raised when a change is pushed to the item
+    data_change_signal: Option<Arc<DataChangeSignal>>,
+    /// A change that is held back until the sampling interval elapses
+    held_change: Option<DataChange>,
+    /// The group of items that share samples of the same value
+    sampling_group: Option<SamplingGroupMember>,
 }
 
 impl MonitoredItem {
@@ -71,6 +83,10 @@ impl MonitoredItem {
             timestamps_to_return,
             last_sample_time: chrono::Utc::now(),
             last_data_value: None,
+            data_changes: None,
+            data_change_signal: None,
+            held_change: None,
+            sampling_group: None,
             queue_size,
             notification_queue: VecDeque::with_capacity(queue_size),
             queue_overflow: false,
@@ -88,112 +104,233 @@ impl MonitoredItem {
         Ok(())
     }
 
+    /// Sets the signal that is raised when a change is pushed to the item. It applies to the
+    /// changes the item watches from its next tick.
+    pub fn set_data_change_signal(&mut self, data_change_signal: Option<Arc<DataChangeSignal>>) {
+        self.data_change_signal = data_change_signal;
+        self.data_changes = None;
+    }
+
+    /// Tests if changes are pushed to the item rather than polled
+    pub fn is_watching_data_changes(&self) -> bool {
+        self.data_changes.is_some()
+    }
+
     /// Called repeatedly on the monitored item.
     ///
-    /// If the monitored item has a negative interval and subscription interval has elapsed,
-    /// the value is tested immediately. Otherwise, the monitored items sampling interval is enforced
-    /// the subscriptions and controls the rate.
+    /// Values that are set on the node are pushed to the item as they change and are sampled at
+    /// the item's sampling interval, according to when they were set. An item that has nothing
+    /// pushed to it since its last tick returns straight away. Values that come from a getter are
+    /// polled instead, which happens when the item's sampling interval has elapsed or, for a
+    /// negative interval, when the subscription's timer fires. The first sample is always polled.
+    /// Items that poll the same getter at the same interval share its samples through the
+    /// address space's `Sampler`.
     ///
     /// Function returns true if a notification message was added to the queue
     pub fn tick(&mut self, address_space: &AddressSpace, now: &DateTimeUtc, reason: TickReason) -> bool {
-        let check_value = if self.sampling_interval > 0f64 {
-            // Compare sample interval
-            let sampling_interval = time::Duration::milliseconds(self.sampling_interval as i64);
-            let elapsed = (*now).signed_duration_since(self.last_sample_time);
-            elapsed >= sampling_interval
-        } else if self.sampling_interval == 0f64 {
-            // Fastest possible rate, i.e. tick quantum
-            true
-        } else if self.sampling_interval < 0f64 {
-            // If the subscription interval elapsed, then this monitored item is evaluated
-            reason == TickReason::TickTimerFired
-        } else {
-            // Always check on the first tick
-            self.last_data_value.is_none()
+        let attribute_id = match AttributeId::from_u32(self.item_to_monitor.attribute_id) {
+            Ok(attribute_id) => attribute_id,
+            Err(_) => {
+                trace!("Item has no attribute_id {} so it hasn't changed, node {:?}", self.item_to_monitor.attribute_id, self.item_to_monitor.node_id);
+                return false;
+            }
         };
 
-        // Test the value (or don't)
-        if !check_value {
-            return false;
+        // The sampling group depends on the sampling interval, which can be modified
+        let is_in_group = self.sampling_group.as_ref().map_or(false, |sampling_group| sampling_group.sampling_interval() == self.sampling_interval);
+        if !is_in_group {
+            self.sampling_group = Some(address_space.sampler().join(&self.item_to_monitor, attribute_id, self.sampling_interval));
         }
 
         // Test if monitoring
-        if self.monitoring_mode == MonitoringMode::Disabled {
+        let is_disabled = self.monitoring_mode == MonitoringMode::Disabled;
+        if let Some(ref mut sampling_group) = self.sampling_group {
+            sampling_group.set_disabled(is_disabled);
+        }
+        if is_disabled {
+            // Changes while disabled are not reported
+            if let Some(ref data_changes) = self.data_changes {
+                data_changes.clear();
+            }
+            self.held_change = None;
+            return false;
+        }
+
+        // Only polled items have anything to do without a pushed change
+        let is_idle = match self.data_changes {
+            Some(ref data_changes) => self.last_data_value.is_some() && self.held_change.is_none() && data_changes.is_empty() && !data_changes.is_polled(),
+            None => false
+        };
+        if is_idle {
+            return false;
+        }
+
+        let node_type = if let Some(node_type) = address_space.find_node(&self.item_to_monitor.node_id) {
+            node_type
+        } else {
+            trace!("Can't find item to monitor, node {:?}", self.item_to_monitor.node_id);
             return false;
+        };
+        let node = node_type.as_node();
+
+        let has_getter = node.has_attribute_getter(attribute_id);
+        if has_getter {
+            // The attribute may have got its getter after the item started to watch it
+            self.data_changes = None;
+        } else if self.data_changes.is_none() {
+            // Watch before the first sample so no change after it is missed
+            self.data_changes = Some(address_space.watch_data_changes(&self.item_to_monitor.node_id, attribute_id, self.data_change_signal.clone()));
+        } else if self.last_data_value.is_some() {
+            return self.sample_data_changes(now, reason);
         }
 
+        if !self.is_sample_due(now, reason) {
+            return false;
+        }
         self.last_sample_time = *now;
 
-        if let Some(node) = address_space.find_node(&self.item_to_monitor.node_id) {
-            let node = node.as_node();
-            let attribute_id = AttributeId::from_u32(self.item_to_monitor.attribute_id);
-            if attribute_id.is_err() {
-                trace!("Item has no attribute_id {:?} so it hasn't changed, node {:?}", attribute_id, self.item_to_monitor.node_id);
-                return false;
-            }
-            let attribute_id = attribute_id.unwrap();
-            let data_value = node.find_attribute(attribute_id);
-            if let Some(mut data_value) = data_value {
-                // Test for data change
-                let data_change = if self.last_data_value.is_none() {
-                    // There is no previous check so yes it changed
-                    trace!("No last data value so item has changed, node {:?}", self.item_to_monitor.node_id);
-                    true
-                } else {
-                    match self.filter {
-                        FilterType::None => {
-                            data_value.value != self.last_data_value.as_ref().unwrap().value
-                        }
-                        FilterType::DataChangeFilter(ref filter) => {
-                            // Use filter to compare values
-                            !filter.compare(&data_value, self.last_data_value.as_ref().unwrap(), None)
+        // The current value supersedes any changes that were pushed before it
+        if let Some(ref data_changes) = self.data_changes {
+            data_changes.clear();
+        }
+        let data_value = match self.sampling_group {
+            Some(ref sampling_group) if has_getter => {
+                let max_age = self.refresh_max_age();
+                sampling_group.sample(now, || {
+                    // A value from a slow source is refreshed in the background, so this samples
+                    // the last value that was read
+                    if let &NodeType::Variable(ref variable) = node_type {
+                        if let (AttributeId::Value, Some(value_source)) = (attribute_id, variable.value_source()) {
+                            value_source.refresh(max_age);
                         }
                     }
-                };
-                if data_change {
-                    trace!("Data change on item -, node {:?}, data_value = {:?}", self.item_to_monitor.node_id, data_value);
-
-                    // Store current data value to compare against on the next tick
-                    self.last_data_value = Some(data_value.clone());
-
-                    // Strip out timestamps that subscriber is not interested in
-                    match self.timestamps_to_return {
-                        TimestampsToReturn::Neither => {
-                            data_value.source_timestamp = None;
-                            data_value.source_picoseconds = None;
-                            data_value.server_timestamp = None;
-                            data_value.server_picoseconds = None
-                        }
-                        TimestampsToReturn::Server => {
-                            data_value.source_timestamp = None;
-                            data_value.source_picoseconds = None;
-                        }
-                        TimestampsToReturn::Source => {
-                            data_value.server_timestamp = None;
-                            data_value.server_picoseconds = None
-                        }
-                        _ => {}
+                    node.find_attribute(attribute_id)
+                })
+            }
+            _ => node.find_attribute(attribute_id)
+        };
+        if let Some(data_value) = data_value {
+            self.sample(data_value)
+        } else {
+            false
+        }
+    }
+
+    /// Tests if the sampling interval has elapsed between the last sample and the time. A
+    /// negative interval is the subscription's, which elapses when its timer fires.
+    fn is_sample_due(&self, time: &DateTimeUtc, reason: TickReason) -> bool {
+        if self.sampling_interval < 0f64 {
+            reason == TickReason::TickTimerFired
+        } else {
+            (*time).signed_duration_since(self.last_sample_time) >= self.sampling_duration()
+        }
+    }
+
+    /// The age in milliseconds at which a value from a slow source is refreshed for the item
+    fn refresh_max_age(&self) -> Duration {
+        if self.sampling_interval < 0f64 {
+            constants::SUBSCRIPTION_TIMER_RATE_MS as Duration
+        } else {
+            self.sampling_interval
+        }
+    }
+
+    fn sampling_duration(&self) -> time::Duration {
+        time::Duration::microseconds((self.sampling_interval * 1000f64) as i64)
+    }
+
+    /// Samples the changes that were pushed to the item since the last tick. A change that is set
+    /// before the sampling interval has elapsed is held back and replaced by any later change in
+    /// the same interval, so at most one value is sampled per interval.
+    fn sample_data_changes(&mut self, now: &DateTimeUtc, reason: TickReason) -> bool {
+        let data_changes = if let Some(ref data_changes) = self.data_changes {
+            data_changes.take()
+        } else {
+            Vec::new()
+        };
+        let mut data_change = false;
+        for change in data_changes {
+            if self.sampling_interval >= 0f64 {
+                if let Some(held_change) = self.held_change.take() {
+                    // The held change is the value when the interval elapsed
+                    let sample_time = self.last_sample_time + self.sampling_duration();
+                    if change.time >= sample_time {
+                        self.last_sample_time = sample_time;
+                        data_change |= self.sample(held_change.value);
                     }
+                }
+                if self.is_sample_due(&change.time, reason) {
+                    self.last_sample_time = change.time;
+                    data_change |= self.sample(change.value);
+                    continue;
+                }
+            }
+            self.held_change = Some(change);
+        }
+        if self.held_change.is_some() && self.is_sample_due(now, reason) {
+            let held_change = self.held_change.take().unwrap();
+            self.last_sample_time = *now;
+            data_change |= self.sample(held_change.value);
+        }
+        data_change
+    }
+
+    /// Compares a sample of the value to the last one and enqueues a notification if it changed,
+    /// returning true if it did
+    fn sample(&mut self, mut data_value: DataValue) -> bool {
+        // Test for data change
+        let data_change = if self.last_data_value.is_none() {
+            // There is no previous check so yes it changed
+            trace!("No last data value so item has changed, node {:?}", self.item_to_monitor.node_id);
+            true
+        } else {
+            match self.filter {
+                FilterType::None => {
+                    data_value.value != self.last_data_value.as_ref().unwrap().value
+                }
+                FilterType::DataChangeFilter(ref filter) => {
+                    // Use filter to compare values
+                    !filter.compare(&data_value, self.last_data_value.as_ref().unwrap(), None)
+                }
+            }
+        };
+        if data_change {
+            trace!("Data change on item -, node {:?}, data_value = {:?}", self.item_to_monitor.node_id, data_value);
 
-                    // Enqueue notification message
-                    let client_handle = self.client_handle;
-                    self.enqueue_notification_message(MonitoredItemNotification {
-                        client_handle,
-                        value: data_value,
-                    });
+            // Store current data value to compare against on the next tick
+            self.last_data_value = Some(data_value.clone());
 
-                    trace!("Monitored item state = {:?}", self);
-                } else {
-                    trace!("No data change on item, node {:?}", self.item_to_monitor.node_id);
+            // Strip out timestamps that subscriber is not interested in
+            match self.timestamps_to_return {
+                TimestampsToReturn::Neither => {
+                    data_value.source_timestamp = None;
+                    data_value.source_picoseconds = None;
+                    data_value.server_timestamp = None;
+                    data_value.server_picoseconds = None
                 }
-                data_change
-            } else {
-                false
+                TimestampsToReturn::Server => {
+                    data_value.source_timestamp = None;
+                    data_value.source_picoseconds = None;
+                }
+                TimestampsToReturn::Source => {
+                    data_value.server_timestamp = None;
+                    data_value.server_picoseconds = None
+                }
+                _ => {}
             }
+
+            // Enqueue notification message
+            let client_handle = self.client_handle;
+            self.enqueue_notification_message(MonitoredItemNotification {
+                client_handle,
+                value: data_value,
+            });
+
+            trace!("Monitored item state = {:?}", self);
         } else {
-            trace!("Can't find item to monitor, node {:?}", self.item_to_monitor.node_id);
-            false
+            trace!("No data change on item, node {:?}", self.item_to_monitor.node_id);
         }
+        data_change
     }
 
     /// Enqueues a notification message for the monitored item
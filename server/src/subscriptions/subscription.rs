@@ -13,6 +13,7 @@ use constants;
 use DateTimeUtc;
 use subscriptions::monitored_item::MonitoredItem;
 use address_space::address_space::AddressSpace;
+use address_space::data_change::DataChangeSignal;
 use diagnostics::ServerDiagnostics;
 
 /// The state of the subscription
@@ -59,6 +60,8 @@ impl UpdateStateResult {
 pub enum TickReason {
     ReceivedPublishRequest,
     TickTimerFired,
+    /// A change was pushed to a monitored item
+    DataChanged,
 }
 
 #[derive(Debug, Clone)]
@@ -96,6 +99,8 @@ pub struct Subscription {
     last_monitored_item_id: UInt32,
     // The time that the subscription interval last fired
     last_timer_expired_time: DateTimeUtc,
+    /// Raised when a change is pushed to one of the monitored items
+    data_change_signal: Option<Arc<DataChangeSignal>>,
     /// Server diagnostics to track creation / destruction / modification of the subscription
     diagnostics: Arc<RwLock<ServerDiagnostics>>,
 }
@@ -125,6 +130,7 @@ impl Subscription {
             // Counters for new items
             last_monitored_item_id: 0,
             last_timer_expired_time: chrono::Utc::now(),
+            data_change_signal: None,
             diagnostics,
         };
         {
@@ -134,6 +140,12 @@ impl Subscription {
         subscription
     }
 
+    /// Sets the signal that is raised when a change is pushed to one of the monitored items
+    pub fn set_data_change_signal(&mut self, data_change_signal: Option<Arc<DataChangeSignal>>) {
+        self.monitored_items.values_mut().for_each(|monitored_item| monitored_item.set_data_change_signal(data_change_signal.clone()));
+        self.data_change_signal = data_change_signal;
+    }
+
     /// Creates monitored items on the specified subscription, returning the creation results
     pub fn create_monitored_items(&mut self, timestamps_to_return: TimestampsToReturn, items_to_create: &[MonitoredItemCreateRequest]) -> Vec<MonitoredItemCreateResult> {
         let mut results = Vec::with_capacity(items_to_create.len());
@@ -144,7 +156,8 @@ impl Subscription {
             let monitored_item_id = self.last_monitored_item_id;
             // Create a monitored item, if possible
             let monitored_item = MonitoredItem::new(monitored_item_id, timestamps_to_return, item_to_create);
-            let result = if let Ok(monitored_item) = monitored_item {
+            let result = if let Ok(mut monitored_item) = monitored_item {
+                monitored_item.set_data_change_signal(self.data_change_signal.clone());
                 // Return the status
                 let result = MonitoredItemCreateResult {
                     status_code: Good,
@@ -233,7 +246,7 @@ impl Subscription {
     pub fn tick(&mut self, address_space: &AddressSpace, tick_reason: TickReason, publishing_req_queued: bool, now: &DateTimeUtc) -> Option<NotificationMessage> {
         // Check if the publishing interval has elapsed. Only checks on the tick timer.
         let publishing_interval_elapsed = match tick_reason {
-            TickReason::ReceivedPublishRequest => false,
+            TickReason::ReceivedPublishRequest | TickReason::DataChanged => false,
             TickReason::TickTimerFired => if self.state == SubscriptionState::Creating {
                 true
             } else if self.publishing_interval <= 0f64 {
@@ -306,17 +319,26 @@ impl Subscription {
         result
     }
 
+    /// Samples the changes that were pushed to the monitored items. Their notifications are queued
+    /// on the items until the subscription's next tick.
+    pub fn sample_data_changes(&mut self, address_space: &AddressSpace, now: &DateTimeUtc) {
+        self.monitored_items.values_mut()
+            .filter(|monitored_item| monitored_item.is_watching_data_changes())
+            .for_each(|monitored_item| {
+                let _ = monitored_item.tick(address_space, now, TickReason::DataChanged);
+            });
+    }
+
     /// Iterate through the monitored items belonging to the subscription, calling tick on each in turn.
     /// The function returns true if any of the monitored items due to the subscription interval
     /// elapsing, or their own interval elapsing.
     fn tick_monitored_items(&mut self, address_space: &AddressSpace, now: &DateTimeUtc, tick_reason: TickReason) -> (Option<NotificationMessage>, bool) {
         let mut monitored_item_notifications = Vec::new();
         for (_, monitored_item) in &mut self.monitored_items {
-            if monitored_item.tick(address_space, now, tick_reason) {
-                // Take the monitored item's first notification
-                if let Some(mut notification_messages) = monitored_item.remove_all_notification_messages() {
-                    monitored_item_notifications.append(&mut notification_messages);
-                }
+            let _ = monitored_item.tick(address_space, now, tick_reason);
+            // Take the item's notifications, including those sampled since the last tick
+            if let Some(mut notification_messages) = monitored_item.remove_all_notification_messages() {
+                monitored_item_notifications.append(&mut notification_messages);
             }
         }
         if !monitored_item_notifications.is_empty() {
@@ -5,6 +5,8 @@ use opcua_types::service_types::{NotificationMessage, PublishRequest, PublishRes
 use opcua_types::status_codes::StatusCode;
 use opcua_types::status_codes::StatusCode::*;
 use std::collections::{BTreeMap, VecDeque};
+use std::sync::Arc;
+use address_space::data_change::DataChangeSignal;
 use subscriptions::{PublishRequestEntry, PublishResponseEntry};
 use subscriptions::subscription::{Subscription, SubscriptionState, TickReason};
 use time;
@@ -67,6 +69,8 @@ pub struct Subscriptions {
     /// This value increments as each notification is added to the transmission queue and the value
     /// is stored in the notification. Sequence numbers wrap.
     sequence_number: SequenceNumber,
+    /// Raised when a change is pushed to a monitored item of any of the subscriptions
+    data_change_signal: Arc<DataChangeSignal>,
 }
 
 impl Subscriptions {
@@ -81,9 +85,16 @@ impl Subscriptions {
             max_retransmission_queue: max_publish_requests * 2,
             transmission_queue: VecDeque::new(),
             retransmission_queue: BTreeMap::new(),
+            data_change_signal: Arc::new(DataChangeSignal::new()),
         }
     }
 
+    /// Returns the signal that is raised when a change is pushed to a monitored item, which
+    /// wakes the session to sample the changes
+    pub fn data_change_signal(&self) -> Arc<DataChangeSignal> {
+        self.data_change_signal.clone()
+    }
+
     #[cfg(test)]
     pub fn retransmission_queue(&mut self) -> &mut BTreeMap<UInt32, (UInt32, NotificationMessage)> {
         &mut self.retransmission_queue
@@ -142,7 +153,8 @@ impl Subscriptions {
         self.subscriptions.contains_key(&subscription_id)
     }
 
-    pub fn insert(&mut self, subscription_id: UInt32, subscription: Subscription) {
+    pub fn insert(&mut self, subscription_id: UInt32, mut subscription: Subscription) {
+        subscription.set_data_change_signal(Some(self.data_change_signal.clone()));
         self.subscriptions.insert(subscription_id, subscription);
     }
 
@@ -154,6 +166,12 @@ impl Subscriptions {
         self.subscriptions.get_mut(&subscription_id)
     }
 
+    /// Samples the changes that were pushed to the monitored items of the subscriptions, so they
+    /// are taken as they happen rather than when the timer next fires
+    pub fn sample_data_changes(&mut self, now: &DateTimeUtc, address_space: &AddressSpace) {
+        self.subscriptions.values_mut().for_each(|subscription| subscription.sample_data_changes(address_space, now));
+    }
+
     /// The tick causes the subscription manager to iterate through individual subscriptions calling tick
     /// on each in order of priority. In each case this could generate data change notifications. Data change
     /// notifications will be attached to the next available publish response and queued for sending
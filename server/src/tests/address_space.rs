@@ -1,6 +1,7 @@
 use prelude::*;
 
 use tests::*;
+use constants::OPC_UA_NAMESPACE_URI;
 
 #[test]
 fn address_space() {
@@ -175,4 +176,102 @@ fn find_references_to() {
     assert!(references.is_some());
     let references = references.unwrap();
     assert_eq!(references.len(), 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn iterate_references() {
+    let mut address_space = AddressSpace::new();
+    let parent_id = NodeId::new_string(1, "Parent");
+    address_space.insert(Object::new(&parent_id, "Parent", "Parent", ""), None);
+
+    // References of mixed types, one of them to a node that does not exist
+    let child_ids = (0..6).map(|i| NodeId::new(1, 100 + i)).collect::<Vec<NodeId>>();
+    for (i, child_id) in child_ids.iter().enumerate() {
+        if i != 5 {
+            address_space.insert(Object::new(child_id, "Child", "Child", ""), None);
+        }
+    }
+    address_space.insert_references(&[
+        (&parent_id, &child_ids[0], ReferenceTypeId::HasProperty),
+        (&parent_id, &child_ids[1], ReferenceTypeId::HasComponent),
+        (&parent_id, &child_ids[2], ReferenceTypeId::HasProperty),
+        (&parent_id, &child_ids[3], ReferenceTypeId::Organizes),
+        (&parent_id, &child_ids[4], ReferenceTypeId::HasOrderedComponent),
+        (&parent_id, &child_ids[5], ReferenceTypeId::HasComponent),
+    ]);
+
+    // References of a type come in the order they were added
+    let targets = |filter| address_space.references_from(&parent_id, filter).map(|(_, node_id)| node_id.clone()).collect::<Vec<NodeId>>();
+    assert_eq!(targets(Some((ReferenceTypeId::HasProperty, false))), vec![child_ids[0].clone(), child_ids[2].clone()]);
+    assert_eq!(targets(Some((ReferenceTypeId::HasComponent, false))), vec![child_ids[1].clone(), child_ids[5].clone()]);
+    assert_eq!(targets(Some((ReferenceTypeId::HasComponent, true))).len(), 3);
+    assert_eq!(targets(Some((ReferenceTypeId::Aggregates, true))).len(), 5);
+    assert_eq!(targets(Some((ReferenceTypeId::HasSubtype, false))), vec![]);
+    assert_eq!(targets(None).len(), 6);
+
+    // Inverse references
+    let sources = address_space.references_to(&child_ids[5], None).collect::<Vec<(ReferenceTypeId, &NodeId)>>();
+    assert_eq!(sources, vec![(ReferenceTypeId::HasComponent, &parent_id)]);
+    assert!(address_space.has_reference(&parent_id, ReferenceTypeId::HasOrderedComponent, &child_ids[4]));
+    assert!(!address_space.has_reference(&parent_id, ReferenceTypeId::HasComponent, &child_ids[4]));
+
+    // Ids that are only the target of references are not nodes, and unknown ids have no references
+    assert!(!address_space.node_exists(&child_ids[5]));
+    assert_eq!(address_space.references_from(&NodeId::new(1, 999), None).count(), 0);
+    assert!(address_space.find_references_from(&NodeId::new(1, 999), None).is_none());
+}
+
+#[test]
+fn iterate_many_references() {
+    let mut address_space = AddressSpace::new();
+    let parent_id = NodeId::new_string(1, "Parent");
+    address_space.insert(Object::new(&parent_id, "Parent", "Parent", ""), None);
+
+    // Types that a node has few references of are found among many references of other types,
+    // whichever order they were added in
+    let child_ids = (0..1000).map(|i| NodeId::new(1, 100 + i)).collect::<Vec<NodeId>>();
+    for (i, child_id) in child_ids.iter().enumerate() {
+        let reference_type_id = if i % 2 == 0 { ReferenceTypeId::Organizes } else { ReferenceTypeId::HasComponent };
+        address_space.insert_references(&[(&parent_id, child_id, reference_type_id)]);
+    }
+    let type_id: NodeId = ObjectTypeId::BaseObjectType.into();
+    let modelling_rule_id: NodeId = ObjectId::ModellingRule_Mandatory.into();
+    address_space.insert_references(&[
+        (&parent_id, &modelling_rule_id, ReferenceTypeId::HasModellingRule),
+        (&parent_id, &type_id, ReferenceTypeId::HasTypeDefinition),
+    ]);
+    assert!(address_space.has_reference(&parent_id, ReferenceTypeId::HasTypeDefinition, &type_id));
+    assert!(address_space.has_reference(&parent_id, ReferenceTypeId::HasModellingRule, &modelling_rule_id));
+    assert!(address_space.has_reference(&parent_id, ReferenceTypeId::HasComponent, &child_ids[999]));
+    assert!(!address_space.has_reference(&parent_id, ReferenceTypeId::HasProperty, &child_ids[0]));
+    assert_eq!(address_space.references_from(&parent_id, Some((ReferenceTypeId::Organizes, false))).count(), 500);
+    assert_eq!(address_space.references_from(&parent_id, Some((ReferenceTypeId::HierarchicalReferences, true))).count(), 1000);
+
+    // Unfiltered references are counted exactly
+    assert_eq!(address_space.references_from(&parent_id, None).size_hint(), (1002, Some(1002)));
+    let (references, inverse_ref_idx) = address_space.find_references_by_direction(&parent_id, BrowseDirection::Both, None);
+    assert_eq!(references.len(), 1002);
+    assert_eq!(inverse_ref_idx, 1002);
+}
+
+#[test]
+fn register_namespace() {
+    let mut address_space = AddressSpace::new();
+    assert_eq!(address_space.namespace_index(OPC_UA_NAMESPACE_URI), Some(0));
+    assert_eq!(address_space.namespace_index("urn:rust-opcua:test:a"), None);
+
+    // Registering is idempotent and the namespace array follows the table
+    let ns = address_space.register_namespace("urn:rust-opcua:test:a");
+    assert_eq!(ns, 1);
+    assert_eq!(address_space.register_namespace("urn:rust-opcua:test:a"), 1);
+    assert_eq!(address_space.register_namespace("urn:rust-opcua:test:b"), 2);
+    assert_eq!(address_space.namespace_uri(2), Some("urn:rust-opcua:test:b"));
+    assert_eq!(address_space.namespace_uri(3), None);
+
+    let namespace_array = address_space.find_variable(VariableId::Server_NamespaceArray).unwrap().value().value.unwrap();
+    assert_eq!(namespace_array, Variant::from_string_array(&[OPC_UA_NAMESPACE_URI.to_string(), "urn:rust-opcua:test:a".to_string(), "urn:rust-opcua:test:b".to_string()]));
+
+    // Browse names are in the namespace they are created with
+    let folder_id = address_space.add_folder((ns, "Folder"), "Folder", &AddressSpace::objects_folder_id()).unwrap();
+    assert_eq!(address_space.find_node(&folder_id).unwrap().as_node().browse_name(), QualifiedName::new(ns, "Folder"));
+}
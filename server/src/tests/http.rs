@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use hyper;
+use hyper::header::Basic;
+
+use opcua_types::node_ids::MethodId;
+use opcua_types::status_codes::StatusCode;
+
+use address_space::access_level;
+use config::{ServerUserToken, ANONYMOUS_USER_TOKEN_ID};
+use http::api::*;
+use server::Server;
+
+use super::*;
+
+fn make_server() -> Server {
+    let mut config = ServerConfig::new_anonymous("foo");
+    config.user_tokens.insert("sample_user".to_string(), ServerUserToken {
+        user: "sample".to_string(),
+        pass: Some("sample1".to_string()),
+    });
+    let server = Server::new(config);
+    {
+        let mut address_space = server.address_space.write().unwrap();
+        add_sample_vars_to_address_space(&mut address_space);
+    }
+    server
+}
+
+fn make_api(server: &Server) -> Api {
+    Api::new(server, Arc::new(UserTokenAuthenticator::new()))
+}
+
+fn basic(username: &str, password: &str) -> Basic {
+    Basic {
+        username: username.to_string(),
+        password: Some(password.to_string()),
+    }
+}
+
+#[test]
+fn api_authenticate() {
+    let server = make_server();
+    let server_state = server.server_state.read().unwrap();
+
+    // Any user token
+    let authenticator = UserTokenAuthenticator::new();
+    assert_eq!(authenticator.authenticate(&server_state, Some(&basic("sample", "sample1"))), StatusCode::Good);
+    assert_eq!(authenticator.authenticate(&server_state, Some(&basic("sample", "x"))), StatusCode::BadIdentityTokenRejected);
+    assert_eq!(authenticator.authenticate(&server_state, Some(&basic("nobody", "sample1"))), StatusCode::BadIdentityTokenRejected);
+    assert_eq!(authenticator.authenticate(&server_state, None), StatusCode::BadIdentityTokenRejected);
+
+    // Restricted to anonymous
+    let authenticator = UserTokenAuthenticator::new_with_user_token_ids(&[ANONYMOUS_USER_TOKEN_ID]);
+    assert_eq!(authenticator.authenticate(&server_state, None), StatusCode::Good);
+    assert_eq!(authenticator.authenticate(&server_state, Some(&basic("sample", "sample1"))), StatusCode::BadIdentityTokenRejected);
+}
+
+#[test]
+fn api_browse() {
+    let server = make_server();
+    let api = make_api(&server);
+
+    let references = api.browse(&AddressSpace::objects_folder_id()).unwrap();
+    assert!(references.iter().any(|r| r.browse_name.name.as_ref() == "Sample"));
+
+    assert_eq!(api.browse(&NodeId::new_string(1, "nothing")).err().unwrap(), StatusCode::BadNodeIdUnknown);
+}
+
+#[test]
+fn api_read_write_value() {
+    let server = make_server();
+    let api = make_api(&server);
+    let node_id = NodeId::new_string(1, "v1");
+
+    let value = api.read_value(&node_id).unwrap();
+    assert_eq!(value.value, Some(Variant::Int32(30)));
+    assert_eq!(value.status, "Good");
+
+    // Variables are not writable by default
+    assert_eq!(api.write_value(&node_id, Variant::Int32(40)).err().unwrap(), StatusCode::BadNotWritable);
+
+    {
+        let mut address_space = server.address_space.write().unwrap();
+        let node = address_space.find_node_mut(&node_id).unwrap();
+        let _ = node.as_mut_node().set_attribute(AttributeId::AccessLevel, DataValue::new((access_level::CURRENT_READ | access_level::CURRENT_WRITE) as Byte)).unwrap();
+    }
+    assert_eq!(api.write_value(&node_id, Variant::Int32(40)).unwrap(), StatusCode::Good);
+    assert_eq!(api.read_value(&node_id).unwrap().value, Some(Variant::Int32(40)));
+
+    assert_eq!(api.read_value(&NodeId::new_string(1, "nothing")).err().unwrap(), StatusCode::BadNodeIdUnknown);
+}
+
+#[test]
+fn api_call() {
+    let server = make_server();
+    let api = make_api(&server);
+
+    // Call GetMonitoredItems on the server object with a subscription that does not exist
+    let result = api.call(ApiCallRequest {
+        object_id: "i=2253".to_string(),
+        method_id: "i=11492".to_string(),
+        input_arguments: Some(vec![Variant::UInt32(1)]),
+    });
+    assert_eq!(result.err().unwrap(), StatusCode::BadSubscriptionIdInvalid);
+
+    let result = api.call(ApiCallRequest {
+        object_id: "i=2253".to_string(),
+        method_id: "x".to_string(),
+        input_arguments: None,
+    });
+    assert_eq!(result.err().unwrap(), StatusCode::BadNodeIdInvalid);
+}
+
+#[test]
+fn api_call_as_user() {
+    let mut config = ServerConfig::new_anonymous("foo");
+    config.user_tokens.insert("sample_user".to_string(), ServerUserToken {
+        user: "sample".to_string(),
+        pass: Some("sample1".to_string()),
+    });
+    config.user_tokens.insert("other_user".to_string(), ServerUserToken {
+        user: "other".to_string(),
+        pass: Some("other1".to_string()),
+    });
+    config.security_admin_user_token_ids.insert("sample_user".to_string());
+    let server = Server::new(config);
+    {
+        // The method succeeds only for a security admin
+        let mut address_space = server.address_space.write().unwrap();
+        address_space.register_method_handler(ObjectId::Server, MethodId::Server_GetMonitoredItems, Box::new(|_, server_state, session, _| {
+            if server_state.is_security_admin(&session.user_identity) {
+                Ok(CallMethodResult {
+                    status_code: StatusCode::Good,
+                    input_argument_results: None,
+                    input_argument_diagnostic_infos: None,
+                    output_arguments: None,
+                })
+            } else {
+                Err(StatusCode::BadUserAccessDenied)
+            }
+        }));
+    }
+    let api = make_api(&server);
+    let call = || ApiCallRequest {
+        object_id: "i=2253".to_string(),
+        method_id: "i=11492".to_string(),
+        input_arguments: Some(vec![Variant::UInt32(1)]),
+    };
+
+    // The admin's identity is on the session the call runs on
+    let admin_api = api.with_credentials(Some(&basic("sample", "sample1")));
+    assert_eq!(admin_api.call(call()).unwrap().status, "Good");
+    assert_eq!(api.with_credentials(Some(&basic("sample", "sample1"))).call(call()).unwrap().status, "Good");
+
+    // Other users and anonymous requests are not admins
+    assert_eq!(api.with_credentials(Some(&basic("other", "other1"))).call(call()).err().unwrap(), StatusCode::BadUserAccessDenied);
+    assert_eq!(api.with_credentials(None).call(call()).err().unwrap(), StatusCode::BadUserAccessDenied);
+    assert_eq!(api.call(call()).err().unwrap(), StatusCode::BadUserAccessDenied);
+}
+
+#[test]
+fn api_percent_decode() {
+    assert_eq!(percent_decode("ns%3D1%3Bs%3Dv1"), "ns=1;s=v1");
+    assert_eq!(percent_decode("i=2253"), "i=2253");
+    assert_eq!(percent_decode("100%"), "100%");
+    assert_eq!(percent_decode("%zz"), "%zz");
+    assert_eq!(percent_decode("%C3%A9"), "\u{e9}");
+}
+
+#[test]
+fn api_http_status() {
+    assert_eq!(http_status(StatusCode::BadNodeIdUnknown), hyper::StatusCode::NotFound);
+    assert_eq!(http_status(StatusCode::BadNotWritable), hyper::StatusCode::Forbidden);
+    assert_eq!(http_status(StatusCode::BadIdentityTokenRejected), hyper::StatusCode::Unauthorized);
+    assert_eq!(http_status(StatusCode::BadTypeMismatch), hyper::StatusCode::BadRequest);
+    assert_eq!(http_status(StatusCode::BadTooManyOperations), hyper::StatusCode::ServiceUnavailable);
+}
+
+#[test]
+fn api_events_limit() {
+    use std::thread;
+    use std::time::Duration;
+
+    let server = make_server();
+    let api = make_api(&server);
+    let node_id = NodeId::new_string(1, "v1");
+
+    // Streams over the limit are refused
+    let mut streams = (0..MAX_EVENT_STREAMS).map(|_| api.events(vec![node_id.clone()], 100)).collect::<Vec<_>>();
+    assert!(streams.iter().all(|stream| stream.status() == hyper::StatusCode::Ok));
+    assert_eq!(api.events(vec![node_id.clone()], 100).status(), hyper::StatusCode::ServiceUnavailable);
+
+    // A stream whose client has gone away frees its place
+    streams.pop();
+    let mut status = hyper::StatusCode::ServiceUnavailable;
+    for _ in 0..50 {
+        thread::sleep(Duration::from_millis(100));
+        let stream = api.events(vec![node_id.clone()], 100);
+        status = stream.status();
+        if status == hyper::StatusCode::Ok {
+            break;
+        }
+    }
+    assert_eq!(status, hyper::StatusCode::Ok);
+}
@@ -0,0 +1,292 @@
+use prelude::*;
+
+use opcua_types::node_ids::VariableTypeId;
+
+use tests::*;
+
+fn motor_type_id() -> NodeId { NodeId::new_string(1, "MotorType") }
+
+fn fast_motor_type_id() -> NodeId { NodeId::new_string(1, "FastMotorType") }
+
+/// Adds a declaration under the parent with the modelling rule, or no rule
+fn add_declaration<T>(address_space: &mut AddressSpace, node: T, parent_id: &NodeId, reference_type_id: ReferenceTypeId, type_id: Option<NodeId>, modelling_rule: Option<ObjectId>) where T: Into<NodeType> {
+    let node = node.into();
+    let node_id = node.as_node().node_id();
+    address_space.insert(node, Some(&[
+        (parent_id, reference_type_id, ReferenceDirection::Inverse),
+    ]));
+    if let Some(type_id) = type_id {
+        address_space.insert_reference(&node_id, &type_id, ReferenceTypeId::HasTypeDefinition);
+    }
+    if let Some(modelling_rule) = modelling_rule {
+        address_space.insert_reference(&node_id, &modelling_rule.into(), ReferenceTypeId::HasModellingRule);
+    }
+}
+
+fn add_variable_declaration(address_space: &mut AddressSpace, id: &str, name: &str, parent_id: &NodeId, value: Double, is_property: bool, modelling_rule: Option<ObjectId>) {
+    let variable = Variable::new_data_value(&NodeId::new_string(1, id), name, name, "", DataTypeId::Double, DataValue::new(value));
+    let (reference_type_id, type_id) = if is_property {
+        (ReferenceTypeId::HasProperty, VariableTypeId::PropertyType)
+    } else {
+        (ReferenceTypeId::HasComponent, VariableTypeId::BaseDataVariableType)
+    };
+    add_declaration(address_space, variable, parent_id, reference_type_id, Some(type_id.into()), modelling_rule);
+}
+
+/// Adds a MotorType with
+///
+/// * Speed - a mandatory component variable
+/// * Serial - a mandatory property
+/// * Temperature - an optional component variable
+/// * Housing - a mandatory object with a mandatory Colour property and an optional Weight property
+/// * Start - a mandatory method
+/// * Units - a shared property
+/// * Spare - an optional placeholder, and Note, a property without a modelling rule
+///
+/// And a FastMotorType subtype, which overrides Speed and adds a mandatory Boost.
+fn add_motor_types(address_space: &mut AddressSpace) {
+    let motor_type_id = motor_type_id();
+    address_space.insert(ObjectType::new(&motor_type_id, "MotorType", "MotorType", "", false), Some(&[
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+
+    add_variable_declaration(address_space, "MotorType.Speed", "Speed", &motor_type_id, 10f64, false, Some(ObjectId::ModellingRule_Mandatory));
+    add_variable_declaration(address_space, "MotorType.Serial", "Serial", &motor_type_id, 1234f64, true, Some(ObjectId::ModellingRule_Mandatory));
+    add_variable_declaration(address_space, "MotorType.Temperature", "Temperature", &motor_type_id, 20f64, false, Some(ObjectId::ModellingRule_Optional));
+    add_variable_declaration(address_space, "MotorType.Units", "Units", &motor_type_id, 1f64, true, Some(ObjectId::ModellingRule_MandatoryShared));
+    add_variable_declaration(address_space, "MotorType.Spare", "Spare", &motor_type_id, 0f64, false, Some(ObjectId::ModellingRule_OptionalPlaceholder));
+    add_variable_declaration(address_space, "MotorType.Note", "Note", &motor_type_id, 0f64, true, None);
+
+    let housing_id = NodeId::new_string(1, "MotorType.Housing");
+    add_declaration(address_space, Object::new(&housing_id, "Housing", "Housing", "The housing"), &motor_type_id, ReferenceTypeId::HasComponent,
+                    Some(ObjectTypeId::BaseObjectType.into()), Some(ObjectId::ModellingRule_Mandatory));
+    add_variable_declaration(address_space, "MotorType.Housing.Colour", "Colour", &housing_id, 3f64, true, Some(ObjectId::ModellingRule_Mandatory));
+    add_variable_declaration(address_space, "MotorType.Housing.Weight", "Weight", &housing_id, 50f64, true, Some(ObjectId::ModellingRule_Optional));
+
+    let start_id = NodeId::new_string(1, "MotorType.Start");
+    add_declaration(address_space, Method::new(&start_id, "Start", "Start", "", false, true, true), &motor_type_id, ReferenceTypeId::HasComponent,
+                    None, Some(ObjectId::ModellingRule_Mandatory));
+
+    let fast_motor_type_id = fast_motor_type_id();
+    address_space.insert(ObjectType::new(&fast_motor_type_id, "FastMotorType", "FastMotorType", "", false), Some(&[
+        (&motor_type_id, ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    add_variable_declaration(address_space, "FastMotorType.Speed", "Speed", &fast_motor_type_id, 100f64, false, Some(ObjectId::ModellingRule_Mandatory));
+    add_variable_declaration(address_space, "FastMotorType.Boost", "Boost", &fast_motor_type_id, 2f64, false, Some(ObjectId::ModellingRule_Mandatory));
+}
+
+/// Finds the child of a node with the browse name, and the reference to it
+fn find_child(address_space: &AddressSpace, node_id: &NodeId, name: &str) -> Option<(ReferenceTypeId, NodeId)> {
+    address_space.find_references_from(node_id, Some((ReferenceTypeId::HierarchicalReferences, true))).unwrap_or(Vec::new())
+        .into_iter()
+        .find(|r| address_space.find_node(&r.node_id).unwrap().as_node().browse_name().name.as_ref() == name)
+        .map(|r| (r.reference_type_id, r.node_id))
+}
+
+fn child_value(address_space: &AddressSpace, node_id: &NodeId, name: &str) -> Variant {
+    let (_, child_id) = find_child(address_space, node_id, name).unwrap();
+    address_space.find_node(&child_id).unwrap().as_node().find_attribute(AttributeId::Value).unwrap().value.unwrap()
+}
+
+fn type_definition(address_space: &AddressSpace, node_id: &NodeId) -> Option<NodeId> {
+    address_space.find_references_from(node_id, Some((ReferenceTypeId::HasTypeDefinition, false))).and_then(|r| r.first().map(|r| r.node_id.clone()))
+}
+
+#[test]
+fn instantiate_object_type() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+
+    let motor_id = address_space.instantiate(&motor_type_id(), &AddressSpace::objects_folder_id(), "Motor1").unwrap();
+    let motor = address_space.find_node(&motor_id).unwrap().as_node();
+    assert_eq!(motor.node_class(), NodeClass::Object);
+    assert_eq!(motor.browse_name(), QualifiedName::from("Motor1"));
+    assert_eq!(type_definition(&address_space, &motor_id), Some(motor_type_id()));
+    assert!(address_space.has_reference(&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, &motor_id));
+
+    // Mandatory children are copies with new ids, their references and values
+    let (reference_type_id, speed_id) = find_child(&address_space, &motor_id, "Speed").unwrap();
+    assert_eq!(reference_type_id, ReferenceTypeId::HasComponent);
+    assert_ne!(speed_id, NodeId::new_string(1, "MotorType.Speed"));
+    assert_eq!(type_definition(&address_space, &speed_id), Some(VariableTypeId::BaseDataVariableType.into()));
+    assert!(address_space.has_reference(&speed_id, ReferenceTypeId::HasModellingRule, &ObjectId::ModellingRule_Mandatory.into()));
+    assert_eq!(child_value(&address_space, &motor_id, "Speed"), Variant::Double(10f64));
+    let speed = address_space.find_node(&speed_id).unwrap().as_node();
+    let double_id: NodeId = DataTypeId::Double.into();
+    assert_eq!(speed.find_attribute(AttributeId::DataType).unwrap().value, Some(Variant::from(double_id)));
+
+    let (reference_type_id, serial_id) = find_child(&address_space, &motor_id, "Serial").unwrap();
+    assert_eq!(reference_type_id, ReferenceTypeId::HasProperty);
+    assert_eq!(type_definition(&address_space, &serial_id), Some(VariableTypeId::PropertyType.into()));
+
+    // Nested objects have their own mandatory children
+    let (_, housing_id) = find_child(&address_space, &motor_id, "Housing").unwrap();
+    let housing = address_space.find_node(&housing_id).unwrap().as_node();
+    assert_eq!(housing.node_class(), NodeClass::Object);
+    assert_eq!(housing.description().unwrap().text, UAString::from("The housing"));
+    assert_eq!(child_value(&address_space, &housing_id, "Colour"), Variant::Double(3f64));
+    assert!(find_child(&address_space, &housing_id, "Weight").is_none());
+
+    // Methods are copied
+    let (_, start_id) = find_child(&address_space, &motor_id, "Start").unwrap();
+    assert_eq!(address_space.find_node(&start_id).unwrap().as_node().node_class(), NodeClass::Method);
+    assert_ne!(start_id, NodeId::new_string(1, "MotorType.Start"));
+
+    // Shared declarations are referenced, not copied
+    let (_, units_id) = find_child(&address_space, &motor_id, "Units").unwrap();
+    assert_eq!(units_id, NodeId::new_string(1, "MotorType.Units"));
+
+    // Optionals, placeholders and children without a modelling rule are left out
+    assert!(find_child(&address_space, &motor_id, "Temperature").is_none());
+    assert!(find_child(&address_space, &motor_id, "Spare").is_none());
+    assert!(find_child(&address_space, &motor_id, "Note").is_none());
+
+    // A second instance has its own nodes
+    let motor2_id = address_space.instantiate(&motor_type_id(), &AddressSpace::objects_folder_id(), "Motor2").unwrap();
+    let (_, speed2_id) = find_child(&address_space, &motor2_id, "Speed").unwrap();
+    assert_ne!(speed_id, speed2_id);
+}
+
+#[test]
+fn instantiate_with_optionals() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+
+    let motor_id = address_space.instantiate_with_optionals(&motor_type_id(), &AddressSpace::objects_folder_id(), "Motor1", &["Temperature", "Housing/Weight"]).unwrap();
+    assert_eq!(child_value(&address_space, &motor_id, "Temperature"), Variant::Double(20f64));
+    assert!(address_space.has_reference(&find_child(&address_space, &motor_id, "Temperature").unwrap().1, ReferenceTypeId::HasModellingRule, &ObjectId::ModellingRule_Optional.into()));
+    let (_, housing_id) = find_child(&address_space, &motor_id, "Housing").unwrap();
+    assert_eq!(child_value(&address_space, &housing_id, "Weight"), Variant::Double(50f64));
+
+    // An optional that is not in the type fails without adding anything
+    let nodes = address_space.find_nodes_in_namespace(1).len();
+    assert_eq!(address_space.instantiate_with_optionals(&motor_type_id(), &AddressSpace::objects_folder_id(), "Motor2", &["Weight"]), Err(BadInvalidArgument));
+    assert_eq!(address_space.instantiate_with_optionals(&motor_type_id(), &AddressSpace::objects_folder_id(), "Motor2", &["Speed"]), Err(BadInvalidArgument));
+    assert_eq!(address_space.find_nodes_in_namespace(1).len(), nodes);
+}
+
+#[test]
+fn instantiate_subtype() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+
+    // The subtype's Speed overrides the supertype's, and the rest is inherited
+    let motor_id = address_space.instantiate(&fast_motor_type_id(), &AddressSpace::objects_folder_id(), "FastMotor").unwrap();
+    assert_eq!(type_definition(&address_space, &motor_id), Some(fast_motor_type_id()));
+    assert_eq!(child_value(&address_space, &motor_id, "Speed"), Variant::Double(100f64));
+    assert_eq!(child_value(&address_space, &motor_id, "Boost"), Variant::Double(2f64));
+    assert_eq!(child_value(&address_space, &motor_id, "Serial"), Variant::Double(1234f64));
+    assert!(find_child(&address_space, &motor_id, "Housing").is_some());
+    let speeds = address_space.find_references_from(&motor_id, Some((ReferenceTypeId::HasComponent, false))).unwrap().iter()
+        .filter(|r| address_space.find_node(&r.node_id).unwrap().as_node().browse_name().name.as_ref() == "Speed")
+        .count();
+    assert_eq!(speeds, 1);
+}
+
+#[test]
+fn instantiate_under_object() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+
+    // Instances under an object that is not a folder are components of it
+    let machine_id = NodeId::new_string(1, "Machine");
+    address_space.insert(Object::new(&machine_id, "Machine", "Machine", ""), Some(&[
+        (&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+    ]));
+    let motor_id = address_space.instantiate(&motor_type_id(), &machine_id, (1, "Motor")).unwrap();
+    assert!(address_space.has_reference(&machine_id, ReferenceTypeId::HasComponent, &motor_id));
+    assert_eq!(address_space.find_node(&motor_id).unwrap().as_node().browse_name(), QualifiedName::new(1, "Motor"));
+
+    // Variable types make variables with the type's value, and properties are properties
+    let property_id = address_space.instantiate(&VariableTypeId::PropertyType.into(), &machine_id, "Rating").unwrap();
+    assert_eq!(address_space.find_node(&property_id).unwrap().as_node().node_class(), NodeClass::Variable);
+    assert!(address_space.has_reference(&machine_id, ReferenceTypeId::HasProperty, &property_id));
+}
+
+#[test]
+fn instantiate_errors() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+    let objects_folder_id = AddressSpace::objects_folder_id();
+
+    // Abstract types and nodes that are not types
+    assert_eq!(address_space.instantiate(&ObjectTypeId::BaseEventType.into(), &objects_folder_id, "Event"), Err(BadTypeDefinitionInvalid));
+    assert_eq!(address_space.instantiate(&NodeId::new_string(1, "MotorType.Speed"), &objects_folder_id, "Speed"), Err(BadTypeDefinitionInvalid));
+    assert_eq!(address_space.instantiate(&NodeId::new_string(1, "NoType"), &objects_folder_id, "Motor"), Err(BadTypeDefinitionInvalid));
+
+    // Parent does not exist
+    assert_eq!(address_space.instantiate(&motor_type_id(), &NodeId::new_string(1, "NoParent"), "Motor"), Err(BadParentNodeIdInvalid));
+
+    // Browse names
+    assert_eq!(address_space.instantiate(&motor_type_id(), &objects_folder_id, ""), Err(BadBrowseNameInvalid));
+    assert!(address_space.instantiate(&motor_type_id(), &objects_folder_id, "Motor").is_ok());
+    assert_eq!(address_space.instantiate(&motor_type_id(), &objects_folder_id, "Motor"), Err(BadBrowseNameDuplicated));
+}
+
+#[test]
+fn instantiate_invalid_declaration() {
+    let mut address_space = AddressSpace::new();
+    let objects_folder_id = AddressSpace::objects_folder_id();
+
+    // A type whose mandatory child is a data type is not a valid type definition
+    let bad_type_id = NodeId::new_string(1, "BadType");
+    address_space.insert(ObjectType::new(&bad_type_id, "BadType", "BadType", "", false), Some(&[
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    add_declaration(&mut address_space, DataType::new(&NodeId::new_string(1, "BadType.Kind"), "Kind", "Kind", "", false), &bad_type_id, ReferenceTypeId::HasComponent,
+                    None, Some(ObjectId::ModellingRule_Mandatory));
+    assert_eq!(address_space.instantiate(&bad_type_id, &objects_folder_id, "Bad"), Err(BadTypeDefinitionInvalid));
+
+    // Nothing was added
+    assert!(find_child(&address_space, &objects_folder_id, "Bad").is_none());
+}
+
+#[test]
+fn instantiate_skips_used_node_ids() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+    let objects_folder_id = AddressSpace::objects_folder_id();
+
+    // Take the ids that instantiation would use next, as an imported nodeset could
+    let next_id = match NodeId::next_numeric().identifier {
+        Identifier::Numeric(id) => id,
+        _ => panic!("Expected a numeric id"),
+    };
+    for id in next_id + 1..next_id + 100 {
+        let node_id = NodeId::new(1, id);
+        let _ = address_space.add_folder_with_id(&node_id, "Taken", "Taken", &objects_folder_id);
+    }
+    let nodes = address_space.find_nodes_in_namespace(1).len();
+
+    // The instance gets ids that are free, and its nodes are all added
+    let motor_id = address_space.instantiate(&motor_type_id(), &objects_folder_id, "Motor").unwrap();
+    assert_eq!(address_space.find_node(&motor_id).unwrap().as_node().browse_name(), QualifiedName::from("Motor"));
+    let (_, speed_id) = find_child(&address_space, &motor_id, "Speed").unwrap();
+    assert_eq!(address_space.find_node(&speed_id).unwrap().as_node().browse_name(), QualifiedName::from("Speed"));
+    // Motor, Speed, Serial, Housing, Colour and Start
+    assert_eq!(address_space.find_nodes_in_namespace(1).len(), nodes + 6);
+}
+
+#[test]
+fn instantiate_namespace() {
+    let mut address_space = AddressSpace::new();
+    add_motor_types(&mut address_space);
+    let objects_folder_id = AddressSpace::objects_folder_id();
+
+    // The nodes of an instance are in the parent's namespace
+    let machine_id = NodeId::new_string(2, "Machine");
+    let _ = address_space.add_folder_with_id(&machine_id, "Machine", "Machine", &objects_folder_id);
+    let motor_id = address_space.instantiate(&motor_type_id(), &machine_id, "Motor").unwrap();
+    assert_eq!(motor_id.namespace, 2);
+    let (_, housing_id) = find_child(&address_space, &motor_id, "Housing").unwrap();
+    assert_eq!(housing_id.namespace, 2);
+    let (_, colour_id) = find_child(&address_space, &housing_id, "Colour").unwrap();
+    assert_eq!(colour_id.namespace, 2);
+
+    // Or the browse name's when the parent is in namespace 0
+    let motor_id = address_space.instantiate(&motor_type_id(), &objects_folder_id, QualifiedName::new(3, "Motor")).unwrap();
+    assert_eq!(motor_id.namespace, 3);
+    let (_, speed_id) = find_child(&address_space, &motor_id, "Speed").unwrap();
+    assert_eq!(speed_id.namespace, 3);
+}
@@ -22,8 +22,13 @@ use subscriptions::*;
 use config::ServerConfig;
 
 mod address_space;
+mod nodeset;
+mod instantiate;
+mod type_dictionary;
 mod services;
 mod subscriptions;
+#[cfg(feature = "http")]
+mod http;
 
 fn make_test_file(filename: &str) -> PathBuf {
     let mut path = std::env::temp_dir();
@@ -74,6 +79,22 @@ pub fn server_config_save() {
     }
 }
 
+#[test]
+pub fn server_config_load_without_optional_fields() {
+    // A config saved before the optional fields existed still loads, with their defaults
+    let path = make_test_file("server_config_optional.yaml");
+    let config = ServerConfig::new_anonymous("foo");
+    assert!(config.save(&path).is_ok());
+    let optional_fields = ["security_admin_user_token_ids:", "pki_watch_interval_ms:", "worker_threads:"];
+    let yaml = std::fs::read_to_string(&path).unwrap().lines()
+        .filter(|line| !optional_fields.iter().any(|field| line.starts_with(field)))
+        .collect::<Vec<&str>>()
+        .join("\n");
+    std::fs::write(&path, yaml).unwrap();
+    let loaded: ServerConfig = ServerConfig::load(&path).unwrap();
+    assert_eq!(loaded, config);
+}
+
 #[test]
 pub fn server_config_invalid() {
     // Remove the endpoint
@@ -88,6 +109,55 @@ pub fn server_config_invalid() {
     assert_eq!(config.is_valid(), false);
 }
 
+#[test]
+pub fn server_config_aes_endpoints() {
+    use config::ServerEndpoint;
+    use config::ANONYMOUS_USER_TOKEN_ID;
+
+    let mut config = ServerConfig::new_anonymous("foo");
+    let user_token_ids = vec![ANONYMOUS_USER_TOKEN_ID.to_string()];
+    config.add_endpoint("aes128_sign", ServerEndpoint::new_aes128_sha256_rsaoaep_sign("/", &user_token_ids));
+    config.add_endpoint("aes128_sign_encrypt", ServerEndpoint::new_aes128_sha256_rsaoaep_sign_encrypt("/", &user_token_ids));
+    config.add_endpoint("aes256_sign", ServerEndpoint::new_aes256_sha256_rsapss_sign("/", &user_token_ids));
+    config.add_endpoint("aes256_sign_encrypt", ServerEndpoint::new_aes256_sha256_rsapss_sign_encrypt("/", &user_token_ids));
+    assert!(config.is_valid());
+
+    let endpoint = &config.endpoints["aes128_sign_encrypt"];
+    assert_eq!(endpoint.security_policy(), SecurityPolicy::Aes128Sha256RsaOaep);
+    assert_eq!(endpoint.message_security_mode(), MessageSecurityMode::SignAndEncrypt);
+    let endpoint = &config.endpoints["aes256_sign"];
+    assert_eq!(endpoint.security_policy(), SecurityPolicy::Aes256Sha256RsaPss);
+    assert_eq!(endpoint.message_security_mode(), MessageSecurityMode::Sign);
+
+    // The newer policies rank above the older ones
+    assert!(ServerEndpoint::security_level(SecurityPolicy::Aes128Sha256RsaOaep) > ServerEndpoint::security_level(SecurityPolicy::Basic256Sha256));
+    assert!(ServerEndpoint::security_level(SecurityPolicy::Aes256Sha256RsaPss) > ServerEndpoint::security_level(SecurityPolicy::Aes128Sha256RsaOaep));
+}
+
+#[test]
+pub fn server_config_ecc_endpoints() {
+    use config::ServerEndpoint;
+    use config::ANONYMOUS_USER_TOKEN_ID;
+
+    let mut config = ServerConfig::new_anonymous("foo");
+    let user_token_ids = vec![ANONYMOUS_USER_TOKEN_ID.to_string()];
+    config.add_endpoint("ecc_nistp256_sign", ServerEndpoint::new_ecc_nistp256_sign("/", &user_token_ids));
+    config.add_endpoint("ecc_nistp256_sign_encrypt", ServerEndpoint::new_ecc_nistp256_sign_encrypt("/", &user_token_ids));
+    config.add_endpoint("ecc_nistp384_sign", ServerEndpoint::new_ecc_nistp384_sign("/", &user_token_ids));
+    config.add_endpoint("ecc_nistp384_sign_encrypt", ServerEndpoint::new_ecc_nistp384_sign_encrypt("/", &user_token_ids));
+    assert!(config.is_valid());
+
+    let endpoint = &config.endpoints["ecc_nistp256_sign"];
+    assert_eq!(endpoint.security_policy(), SecurityPolicy::EccNistP256);
+    assert_eq!(endpoint.message_security_mode(), MessageSecurityMode::Sign);
+    let endpoint = &config.endpoints["ecc_nistp384_sign_encrypt"];
+    assert_eq!(endpoint.security_policy(), SecurityPolicy::EccNistP384);
+    assert_eq!(endpoint.message_security_mode(), MessageSecurityMode::SignAndEncrypt);
+
+    assert!(ServerEndpoint::security_level(SecurityPolicy::EccNistP256) > ServerEndpoint::security_level(SecurityPolicy::Aes256Sha256RsaPss));
+    assert!(ServerEndpoint::security_level(SecurityPolicy::EccNistP384) > ServerEndpoint::security_level(SecurityPolicy::EccNistP256));
+}
+
 #[test]
 pub fn expired_publish_requests() {
     let now = chrono::Utc::now();
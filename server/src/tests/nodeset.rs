@@ -0,0 +1,317 @@
+use prelude::*;
+
+use tests::*;
+
+const SAMPLE_NODESET: &'static str = r#"<?xml version="1.0" encoding="utf-8"?>
+<UANodeSet xmlns="http://opcfoundation.org/UA/2011/03/UANodeSet.xsd" xmlns:uax="http://opcfoundation.org/UA/2008/02/Types.xsd">
+  <NamespaceUris>
+    <Uri>urn:rust-opcua:test:machines</Uri>
+  </NamespaceUris>
+  <Aliases>
+    <Alias Alias="Double">i=11</Alias>
+    <Alias Alias="Int32">i=6</Alias>
+    <Alias Alias="Organizes">i=35</Alias>
+    <Alias Alias="HasComponent">i=47</Alias>
+    <Alias Alias="HasProperty">i=46</Alias>
+    <Alias Alias="HasSubtype">i=45</Alias>
+    <Alias Alias="HasTypeDefinition">i=40</Alias>
+  </Aliases>
+  <UAReferenceType NodeId="ns=1;i=4001" BrowseName="1:Feeds">
+    <DisplayName>Feeds</DisplayName>
+    <InverseName>FedBy</InverseName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=32</Reference>
+    </References>
+  </UAReferenceType>
+  <UADataType NodeId="ns=1;i=3001" BrowseName="1:MachineState">
+    <DisplayName>MachineState</DisplayName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=29</Reference>
+    </References>
+    <Definition Name="1:MachineState">
+      <Field Name="Stopped" Value="0" />
+      <Field Name="Running" Value="1">
+        <Description>Machine is producing</Description>
+      </Field>
+    </Definition>
+  </UADataType>
+  <UAObjectType NodeId="ns=1;i=1001" BrowseName="1:MachineType" IsAbstract="true">
+    <DisplayName>MachineType</DisplayName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=58</Reference>
+    </References>
+  </UAObjectType>
+  <UAObject NodeId="ns=1;i=5001" BrowseName="1:Press" EventNotifier="1">
+    <DisplayName Locale="en">Press</DisplayName>
+    <Description>A hydraulic press</Description>
+    <References>
+      <Reference ReferenceType="Organizes" IsForward="false">i=85</Reference>
+      <Reference ReferenceType="HasTypeDefinition">ns=1;i=1001</Reference>
+      <Reference ReferenceType="HasComponent">ns=1;i=6001</Reference>
+      <Reference ReferenceType="ns=1;i=4001">ns=1;s=Conveyor</Reference>
+    </References>
+  </UAObject>
+  <UAVariable NodeId="ns=1;i=6001" BrowseName="1:Pressure" DataType="Double" AccessLevel="3" MinimumSamplingInterval="500">
+    <DisplayName>Pressure</DisplayName>
+    <References>
+      <Reference ReferenceType="HasComponent" IsForward="false">ns=1;i=5001</Reference>
+      <Reference ReferenceType="HasTypeDefinition">i=63</Reference>
+    </References>
+    <Value>
+      <uax:Double>12.5</uax:Double>
+    </Value>
+  </UAVariable>
+  <UAVariable NodeId="ns=1;i=6002" BrowseName="1:Limits" DataType="Int32" ValueRank="1" ArrayDimensions="3">
+    <DisplayName>Limits</DisplayName>
+    <References>
+      <Reference ReferenceType="HasProperty" IsForward="false">ns=1;i=5001</Reference>
+    </References>
+    <Value>
+      <uax:ListOfInt32>
+        <uax:Int32>1</uax:Int32>
+        <uax:Int32>2</uax:Int32>
+        <uax:Int32>3</uax:Int32>
+      </uax:ListOfInt32>
+    </Value>
+  </UAVariable>
+  <UAObject NodeId="ns=1;s=Conveyor" BrowseName="1:Conveyor">
+    <DisplayName>Conveyor</DisplayName>
+    <References>
+      <Reference ReferenceType="Organizes" IsForward="false">i=85</Reference>
+    </References>
+  </UAObject>
+  <UAMethod NodeId="ns=1;i=7001" BrowseName="1:Start" UserExecutable="false">
+    <DisplayName>Start</DisplayName>
+    <References>
+      <Reference ReferenceType="HasComponent" IsForward="false">ns=1;i=5001</Reference>
+    </References>
+  </UAMethod>
+  <UAView NodeId="ns=1;i=8001" BrowseName="1:Machines" ContainsNoLoops="true">
+    <DisplayName>Machines</DisplayName>
+  </UAView>
+</UANodeSet>"#;
+
+fn import_sample() -> AddressSpace {
+    let mut address_space = AddressSpace::new();
+    address_space.import_nodeset(SAMPLE_NODESET.as_bytes()).unwrap();
+    address_space
+}
+
+#[test]
+fn import_nodeset_namespaces() {
+    let address_space = import_sample();
+    // The document's namespace 1 is appended to the namespace table
+    assert_eq!(address_space.namespaces().len(), 2);
+    assert_eq!(address_space.namespaces()[1], "urn:rust-opcua:test:machines");
+
+    // Imported into an address space that already holds the namespace elsewhere, the index moves
+    let mut address_space = AddressSpace::new();
+    address_space.register_namespace("urn:rust-opcua:test:other");
+    address_space.import_nodeset(SAMPLE_NODESET.as_bytes()).unwrap();
+    assert!(address_space.node_exists(&NodeId::new(2, 5001)));
+    assert!(!address_space.node_exists(&NodeId::new(1, 5001)));
+    let node = address_space.find_node(&NodeId::new(2, 5001)).unwrap().as_node();
+    assert_eq!(node.browse_name(), QualifiedName::new(2, "Press"));
+}
+
+#[test]
+fn import_nodeset_node_classes() {
+    let address_space = import_sample();
+
+    let node = address_space.find_node(&NodeId::new(1, 5001)).unwrap().as_node();
+    assert_eq!(node.node_class(), NodeClass::Object);
+    assert_eq!(node.browse_name(), QualifiedName::new(1, "Press"));
+    assert_eq!(node.display_name(), LocalizedText::new("en", "Press"));
+    assert_eq!(node.description(), Some(LocalizedText::new("", "A hydraulic press")));
+
+    let classes = [
+        (NodeId::new(1, 1001), NodeClass::ObjectType),
+        (NodeId::new(1, 3001), NodeClass::DataType),
+        (NodeId::new(1, 4001), NodeClass::ReferenceType),
+        (NodeId::new(1, 6001), NodeClass::Variable),
+        (NodeId::new(1, 7001), NodeClass::Method),
+        (NodeId::new(1, 8001), NodeClass::View),
+    ];
+    for &(ref node_id, node_class) in classes.iter() {
+        assert_eq!(address_space.find_node(node_id).unwrap().as_node().node_class(), node_class);
+    }
+
+    if let &NodeType::Method(ref method) = address_space.find_node(&NodeId::new(1, 7001)).unwrap() {
+        assert!(method.executable());
+        assert!(!method.user_executable());
+    } else {
+        panic!();
+    }
+    if let &NodeType::ObjectType(ref object_type) = address_space.find_node(&NodeId::new(1, 1001)).unwrap() {
+        assert!(object_type.is_abstract());
+    } else {
+        panic!();
+    }
+    if let &NodeType::ReferenceType(ref reference_type) = address_space.find_node(&NodeId::new(1, 4001)).unwrap() {
+        assert_eq!(reference_type.inverse_name(), Some(LocalizedText::new("", "FedBy")));
+    } else {
+        panic!();
+    }
+}
+
+#[test]
+fn import_nodeset_values() {
+    let mut address_space = import_sample();
+
+    let v = address_space.find_variable(NodeId::new(1, 6001)).unwrap();
+    assert_eq!(v.value().value, Some(Variant::Double(12.5)));
+    assert_eq!(v.access_level(), 3);
+    assert_eq!(v.value_rank(), -1);
+    assert_eq!(v.find_attribute(AttributeId::DataType).unwrap().value, Some(Variant::new::<NodeId>(DataTypeId::Double.into())));
+    assert_eq!(v.find_attribute(AttributeId::MinimumSamplingInterval).unwrap().value, Some(Variant::Double(500f64)));
+
+    let v = address_space.find_variable(NodeId::new(1, 6002)).unwrap();
+    assert_eq!(v.value().value, Some(Variant::Array(vec![Variant::Int32(1), Variant::Int32(2), Variant::Int32(3)])));
+    assert_eq!(v.value_rank(), 1);
+    assert_eq!(v.find_attribute(AttributeId::ArrayDimensions).unwrap().value, Some(Variant::from_u32_array(&[3])));
+}
+
+#[test]
+fn import_nodeset_references() {
+    let address_space = import_sample();
+    let press = NodeId::new(1, 5001);
+
+    assert!(address_space.has_reference(&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, &press));
+    assert!(address_space.has_reference(&press, ReferenceTypeId::HasTypeDefinition, &NodeId::new(1, 1001)));
+    assert!(address_space.has_reference(&press, ReferenceTypeId::HasProperty, &NodeId::new(1, 6002)));
+    // The custom reference type is found by its standard supertype and keeps its own type
+    let conveyor = NodeId::new_string(1, "Conveyor");
+    assert!(address_space.has_reference(&press, ReferenceTypeId::NonHierarchicalReferences, &conveyor));
+    assert_eq!(address_space.reference_type(&press, &conveyor, ReferenceTypeId::NonHierarchicalReferences), NodeId::new(1, 4001));
+    assert_eq!(address_space.reference_type(&press, &NodeId::new(1, 6001), ReferenceTypeId::HasComponent), ReferenceTypeId::HasComponent.into());
+
+    // The reference written on both the object and the variable is only added once
+    let references = address_space.find_references_from(&press, Some((ReferenceTypeId::HasComponent, false))).unwrap();
+    assert_eq!(references.iter().filter(|r| r.node_id == NodeId::new(1, 6001)).count(), 1);
+}
+
+#[test]
+fn import_nodeset_data_type_definition() {
+    let address_space = import_sample();
+    if let &NodeType::DataType(ref data_type) = address_space.find_node(&NodeId::new(1, 3001)).unwrap() {
+        let definition = data_type.definition().unwrap();
+        assert_eq!(definition.name, QualifiedName::new(1, "MachineState"));
+        assert!(!definition.is_union);
+        assert_eq!(definition.fields.len(), 2);
+        let field = &definition.fields[1];
+        assert_eq!(field.name, "Running");
+        assert_eq!(field.value, Some(1));
+        assert_eq!(field.value_rank, -1);
+        assert_eq!(field.data_type, DataTypeId::BaseDataType.into());
+        assert_eq!(field.description, Some(LocalizedText::new("", "Machine is producing")));
+    } else {
+        panic!();
+    }
+}
+
+#[test]
+fn import_nodeset_errors() {
+    let mut address_space = AddressSpace::new();
+
+    let err = address_space.import_nodeset("<UANodeSet>".as_bytes()).unwrap_err();
+    assert!(err.node_id.is_none());
+
+    let err = address_space.import_nodeset("<Foo />".as_bytes()).unwrap_err();
+    assert_eq!(err.message, "Document element is Foo, expected UANodeSet");
+
+    // Namespace index not declared by the document
+    let err = address_space.import_nodeset(r#"<UANodeSet><UAObject NodeId="ns=2;i=1" BrowseName="X"/></UANodeSet>"#.as_bytes()).unwrap_err();
+    assert_eq!(err.node_id, Some("ns=2;i=1".to_string()));
+    assert_eq!(err.message, "Namespace index 2 is not in the NamespaceUris of the document");
+
+    let err = address_space.import_nodeset(r#"<UANodeSet><UAVariable NodeId="i=90001" BrowseName="X" AccessLevel="high"/></UANodeSet>"#.as_bytes()).unwrap_err();
+    assert_eq!(err.message, "UAVariable attribute AccessLevel has an invalid value high");
+
+    let err = address_space.import_nodeset(r#"<UANodeSet><UAObject NodeId="i=90001" BrowseName="X"><References><Reference ReferenceType="Bogus">i=85</Reference></References></UAObject></UANodeSet>"#.as_bytes()).unwrap_err();
+    assert_eq!(err.message, "Bogus is not a node id or alias");
+
+    // Existing nodes are not replaced
+    let err = address_space.import_nodeset(r#"<UANodeSet><UAObject NodeId="i=85" BrowseName="Objects"/></UANodeSet>"#.as_bytes()).unwrap_err();
+    assert_eq!(err.message, "Node already exists");
+    assert_eq!(err.node_id, Some("i=85".to_string()));
+
+    // A failed import leaves nothing behind
+    let err = address_space.import_nodeset(r#"<UANodeSet><NamespaceUris><Uri>urn:x</Uri></NamespaceUris>
+        <UAObject NodeId="ns=1;i=1" BrowseName="1:X"/>
+        <UAObject NodeId="ns=1;i=2" BrowseName="1:Y"><References><Reference ReferenceType="ns=1;i=3">ns=1;i=1</Reference></References></UAObject>
+        </UANodeSet>"#.as_bytes()).unwrap_err();
+    assert_eq!(err.node_id, Some("ns=1;i=2".to_string()));
+    assert!(!address_space.node_exists(&NodeId::new(1, 1)));
+    assert_eq!(address_space.namespaces().len(), 1);
+}
+
+fn export(address_space: &AddressSpace, namespaces: &[UInt16]) -> String {
+    let mut buffer = Vec::new();
+    address_space.export_nodeset(namespaces, &mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn export_nodeset_document() {
+    let mut address_space = import_sample();
+    address_space.set_variable_value(NodeId::new(1, 6001), 99.5f64);
+    let xml = export(&address_space, &[1]);
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<UANodeSet xmlns=\"http://opcfoundation.org/UA/2011/03/UANodeSet.xsd\" xmlns:uax=\"http://opcfoundation.org/UA/2008/02/Types.xsd\">"));
+    assert!(xml.contains("<Uri>urn:rust-opcua:test:machines</Uri>"));
+    // Standard types and reference types are written as aliases
+    assert!(xml.contains("<Alias Alias=\"Double\">i=11</Alias>"));
+    assert!(xml.contains("<Alias Alias=\"HasComponent\">i=47</Alias>"));
+    assert!(xml.contains("<UAVariable NodeId=\"ns=1;i=6001\" BrowseName=\"1:Pressure\" AccessLevel=\"3\" DataType=\"Double\" MinimumSamplingInterval=\"500\">"));
+    // Current values are written
+    assert!(xml.contains("<uax:Double>99.5</uax:Double>"));
+    // The inverse reference from the objects folder is kept, the one from the press to its
+    // variable is only written on the press
+    assert!(xml.contains("<Reference ReferenceType=\"Organizes\" IsForward=\"false\">i=85</Reference>"));
+    // The custom reference type is written rather than its supertype
+    assert!(xml.contains("<Reference ReferenceType=\"ns=1;i=4001\">ns=1;s=Conveyor</Reference>"));
+    assert_eq!(xml.matches(">ns=1;i=6001</Reference>").count(), 1);
+    assert!(!xml.contains(">ns=1;i=5001</Reference>"));
+
+    // Nothing in other namespaces
+    assert!(!xml.contains("NodeId=\"i="));
+
+    // The output is the same every time
+    assert_eq!(xml, export(&address_space, &[1]));
+}
+
+#[test]
+fn export_nodeset_round_trip() {
+    let address_space = import_sample();
+    let xml = export(&address_space, &[1]);
+
+    let mut imported = AddressSpace::new();
+    imported.import_nodeset(xml.as_bytes()).unwrap();
+    assert_eq!(xml, export(&imported, &[1]));
+
+    let attributes = [
+        AttributeId::NodeClass, AttributeId::BrowseName, AttributeId::DisplayName, AttributeId::Description,
+        AttributeId::IsAbstract, AttributeId::InverseName, AttributeId::EventNotifier, AttributeId::Value,
+        AttributeId::DataType, AttributeId::ValueRank, AttributeId::ArrayDimensions, AttributeId::AccessLevel,
+        AttributeId::MinimumSamplingInterval, AttributeId::Executable, AttributeId::UserExecutable, AttributeId::ContainsNoLoops
+    ];
+    for node in address_space.find_nodes_in_namespace(1) {
+        let node = node.as_node();
+        let other = imported.find_node(&node.node_id()).unwrap().as_node();
+        for attribute_id in attributes.iter() {
+            let value = node.find_attribute(*attribute_id).map(|v| v.value);
+            let other_value = other.find_attribute(*attribute_id).map(|v| v.value);
+            assert_eq!(value, other_value, "{:?} of {:?}", attribute_id, node.node_id());
+        }
+    }
+
+    if let &NodeType::DataType(ref data_type) = imported.find_node(&NodeId::new(1, 3001)).unwrap() {
+        assert_eq!(data_type.definition().unwrap().fields.len(), 2);
+    } else {
+        panic!();
+    }
+    let press = NodeId::new(1, 5001);
+    assert!(imported.has_reference(&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, &press));
+    assert!(imported.has_reference(&press, ReferenceTypeId::HasComponent, &NodeId::new(1, 6001)));
+    assert!(imported.has_reference(&press, ReferenceTypeId::NonHierarchicalReferences, &NodeId::new_string(1, "Conveyor")));
+}
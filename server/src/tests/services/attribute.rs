@@ -1,6 +1,8 @@
 use super::*;
 use services::attribute::AttributeService;
+use services::message_handler::MessageHandler;
 use address_space::access_level;
+use super::value_source::{TestValueSource, TestValueSourceMode};
 use opcua_types::write_mask;
 
 fn read_value(node_id: &NodeId, attribute_id: AttributeId) -> ReadValueId {
@@ -187,3 +189,218 @@ fn write_test() {
     // distinguish between write and user write
     // test max_age
 }
+
+#[test]
+fn write_checks_value_type() {
+    let st = ServiceTest::new();
+    let ats = AttributeService::new();
+
+    let double_id = NodeId::new_string(1, "double");
+    let byte_id = NodeId::new_string(1, "byte");
+    let number_id = NodeId::new_string(1, "number");
+    let duration_id = NodeId::new_string(1, "duration");
+    let array_id = NodeId::new_string(1, "array");
+    {
+        let mut address_space = st.server.address_space.write().unwrap();
+        let variables = vec![
+            Variable::new(&double_id, "double", "double", "", 1f64),
+            Variable::new(&byte_id, "byte", "byte", "", 1u8),
+            Variable::new_with_data_type(&number_id, "number", "number", "", DataTypeId::Number, 1i32),
+            Variable::new_with_data_type(&duration_id, "duration", "duration", "", DataTypeId::Duration, 1f64),
+            Variable::new_array(&array_id, "array", "array", "", DataTypeId::Int32, DataValue::new(vec![Variant::from(1i32)]), &[3]),
+        ];
+        let _ = address_space.add_variables(variables, &AddressSpace::objects_folder_id());
+        for node_id in &[&double_id, &byte_id, &number_id, &duration_id, &array_id] {
+            let node = address_space.find_node_mut(node_id).unwrap();
+            let _ = node.as_mut_node().set_attribute(AttributeId::AccessLevel, DataValue::new(access_level::CURRENT_WRITE as Byte)).unwrap();
+        }
+    }
+
+    let int32_array = |len: usize| Variant::from((0..len).map(|i| Variant::from(i as Int32)).collect::<Vec<Variant>>());
+    let writes = vec![
+        // Implicit conversions to the data type
+        (&double_id, Variant::from(5i32), Good),
+        (&byte_id, Variant::from(200i32), Good),
+        (&duration_id, Variant::from(2.5f32), Good),
+        // Abstract data type
+        (&number_id, Variant::from(1.5f32), Good),
+        (&number_id, Variant::from("x"), BadTypeMismatch),
+        // Wrong type or out of range
+        (&double_id, Variant::from("x"), BadTypeMismatch),
+        (&byte_id, Variant::from(300i32), BadOutOfRange),
+        (&byte_id, Variant::from(1.5f64), BadTypeMismatch),
+        // Value rank and array dimensions
+        (&double_id, Variant::from(vec![Variant::from(1f64)]), BadTypeMismatch),
+        (&array_id, int32_array(3), Good),
+        (&array_id, int32_array(4), BadOutOfRange),
+        (&array_id, Variant::from(1i32), BadTypeMismatch),
+        // Null is accepted for any variable
+        (&number_id, Variant::Empty, Good),
+    ];
+
+    let mut address_space = st.server.address_space.write().unwrap();
+    for (node_id, value, expected) in writes {
+        let request = WriteRequest {
+            request_header: make_request_header(),
+            nodes_to_write: Some(vec![write_value(node_id, AttributeId::Value, DataValue::new(value.clone()))]),
+        };
+        let response: WriteResponse = supported_message_as!(ats.write(&mut address_space, request).unwrap(), WriteResponse);
+        assert_eq!(response.results.unwrap()[0], expected, "writing {:?} to {:?}", value, node_id);
+    }
+
+    // The written values were converted to the data type of each variable
+    let value_of = |node_id: &NodeId| address_space.find_node(node_id).unwrap().as_node().find_attribute(AttributeId::Value).unwrap().value.unwrap();
+    assert_eq!(value_of(&double_id), Variant::from(5f64));
+    assert_eq!(value_of(&byte_id), Variant::from(200u8));
+    assert_eq!(value_of(&duration_id), Variant::from(2.5f64));
+    assert_eq!(value_of(&array_id), int32_array(3));
+}
+
+#[test]
+fn read_while_getter_blocks() {
+    use std::sync::{Mutex, mpsc};
+    use std::thread;
+    use std::time::Duration as StdDuration;
+    use address_space::AttrFnGetter;
+
+    // A read that blocks in a getter does not hold up a read from another session
+    let st = ServiceTest::new();
+    let (entered_tx, entered_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let node_ids = {
+        let mut address_space = st.get_address_space();
+        let (_, node_ids) = add_many_vars_to_address_space(&mut address_space, 2);
+        let getter = AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+            let _ = entered_tx.send(());
+            let _ = release_rx.recv();
+            Ok(Some(DataValue::new(100 as Int32)))
+        });
+        address_space.find_variable(node_ids[0].clone()).unwrap().set_value_getter(Arc::new(Mutex::new(getter)));
+        node_ids
+    };
+
+    // Start the slow read and wait for it to enter the getter
+    let slow_handler = new_message_handler(&st);
+    let slow_node_id = node_ids[0].clone();
+    let slow_read = thread::spawn(move || read_with_max_age(&slow_handler, &slow_node_id, 0f64));
+    entered_rx.recv_timeout(StdDuration::from_secs(5)).unwrap();
+
+    // Read the other variable while the getter is blocked
+    let (done_tx, done_rx) = mpsc::channel();
+    let fast_handler = new_message_handler(&st);
+    let fast_node_id = node_ids[1].clone();
+    thread::spawn(move || {
+        let _ = done_tx.send(read_with_max_age(&fast_handler, &fast_node_id, 0f64));
+    });
+    let value = done_rx.recv_timeout(StdDuration::from_secs(5)).expect("read was blocked by the getter of another read");
+    assert_eq!(value.value, Some(Variant::Int32(1)));
+
+    // Release the getter so the slow read completes
+    release_tx.send(()).unwrap();
+    let value = slow_read.join().unwrap();
+    assert_eq!(value.value, Some(Variant::Int32(100)));
+}
+
+fn read_with_max_age(message_handler: &MessageHandler, node_id: &NodeId, max_age: Duration) -> DataValue {
+    let mut request_header = make_request_header();
+    request_header.authentication_token = NodeId::null();
+    let request = SupportedMessage::ReadRequest(ReadRequest {
+        request_header,
+        max_age,
+        timestamps_to_return: TimestampsToReturn::Both,
+        nodes_to_read: Some(vec![read_value(node_id, AttributeId::Value)]),
+    });
+    let response: ReadResponse = supported_message_as!(message_handler.handle_message(1, request).unwrap().unwrap(), ReadResponse);
+    response.results.unwrap().remove(0)
+}
+
+fn new_message_handler(st: &ServiceTest) -> MessageHandler {
+    let session = st.server.new_transport().session();
+    MessageHandler::new(st.server.certificate_store.clone(), st.server_state.clone(), session, st.address_space.clone())
+}
+
+#[test]
+fn read_value_source_max_age() {
+    let st = ServiceTest::new();
+    let node_id = NodeId::new(1, "slow".to_string());
+    let source = TestValueSource::new(TestValueSourceMode::Count);
+    source.add_variable(&mut st.get_address_space(), &node_id, 100f64);
+    let message_handler = new_message_handler(&st);
+
+    // The value is read from the source, and then from the cache while it is younger than max_age
+    let value = read_with_max_age(&message_handler, &node_id, 0f64);
+    assert_eq!(value.value, Some(Variant::UInt32(1)));
+    assert_eq!(value.status, Some(Good));
+    let value = read_with_max_age(&message_handler, &node_id, 60000f64);
+    assert_eq!(value.value, Some(Variant::UInt32(1)));
+    assert_eq!(source.reads(), 1);
+    let value = read_with_max_age(&message_handler, &node_id, 0f64);
+    assert_eq!(value.value, Some(Variant::UInt32(2)));
+    assert_eq!(source.reads(), 2);
+
+    // A read that times out or fails returns the last value as uncertain
+    source.set_mode(TestValueSourceMode::Hang);
+    let value = read_with_max_age(&message_handler, &node_id, 0f64);
+    assert_eq!(value.value, Some(Variant::UInt32(2)));
+    assert_eq!(value.status, Some(UncertainLastUsableValue));
+    source.set_mode(TestValueSourceMode::Fail);
+    let value = read_with_max_age(&message_handler, &node_id, 0f64);
+    assert_eq!(value.value, Some(Variant::UInt32(2)));
+    assert_eq!(value.status, Some(UncertainLastUsableValue));
+    assert_eq!(source.reads(), 4);
+
+    // The abandoned read completing does not replace the value
+    source.release(100);
+    source.set_mode(TestValueSourceMode::Count);
+    let value = read_with_max_age(&message_handler, &node_id, 0f64);
+    assert_eq!(value.value, Some(Variant::UInt32(5)));
+    assert_eq!(value.status, Some(Good));
+}
+
+#[test]
+fn read_value_source_timeout() {
+    // A source that never returns a value times out with no value
+    let st = ServiceTest::new();
+    let node_id = NodeId::new(1, "slow".to_string());
+    let source = TestValueSource::new(TestValueSourceMode::Hang);
+    source.add_variable(&mut st.get_address_space(), &node_id, 50f64);
+    let message_handler = new_message_handler(&st);
+    let value = read_with_max_age(&message_handler, &node_id, 0f64);
+    assert_eq!(value.value, None);
+    assert_eq!(value.status, Some(BadTimeout));
+}
+
+#[test]
+fn read_value_source_does_not_lock() {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration as StdDuration;
+
+    // The address space is not locked while a read waits for a source
+    let st = ServiceTest::new();
+    let node_id = NodeId::new(1, "slow".to_string());
+    let source = TestValueSource::new(TestValueSourceMode::Hang);
+    source.add_variable(&mut st.get_address_space(), &node_id, 10000f64);
+
+    let message_handler = new_message_handler(&st);
+    let (done_tx, done_rx) = mpsc::channel();
+    let read_node_id = node_id.clone();
+    thread::spawn(move || {
+        let _ = done_tx.send(read_with_max_age(&message_handler, &read_node_id, 0f64));
+    });
+    for _ in 0..500 {
+        if source.reads() > 0 {
+            break;
+        }
+        thread::sleep(StdDuration::from_millis(10));
+    }
+    assert_eq!(source.reads(), 1);
+    {
+        let _address_space = st.address_space.try_write().expect("address space is locked by a read waiting for its source");
+    }
+
+    source.release(7);
+    let value = done_rx.recv_timeout(StdDuration::from_secs(5)).unwrap();
+    assert_eq!(value.value, Some(Variant::UInt32(7)));
+    assert_eq!(value.status, Some(Good));
+}
@@ -30,6 +30,25 @@ fn get_endpoints() {
         assert!(!endpoints.is_empty());
 
         debug!("Endpoints = {:#?}", endpoints);
+    }
+
+    // The newer security policies are advertised with their uris
+    {
+        let st = ServiceTest::new_with_config(ServerConfig::new_sample());
+        let (mut server_state, _) = st.get_server_state_and_session();
+        let request = GetEndpointsRequest {
+            request_header: make_request_header(),
+            endpoint_url: UAString::from("opc.tcp://localhost:4855/"),
+            locale_ids: None,
+            profile_uris: None,
+        };
+        let result = ds.get_endpoints(&mut server_state, request);
+        let result = supported_message_as!(result.unwrap(), GetEndpointsResponse);
+        let endpoints = result.endpoints.unwrap();
+        for security_policy in [SecurityPolicy::Aes128Sha256RsaOaep, SecurityPolicy::Aes256Sha256RsaPss].iter() {
+            let uri = security_policy.to_uri();
+            assert_eq!(endpoints.iter().filter(|e| e.security_policy_uri.as_ref() == uri).count(), 2);
+        }
 
 //        panic!("error");
     }
@@ -1,6 +1,6 @@
 use opcua_core;
 use opcua_types::status_codes::StatusCode::*;
-use opcua_types::service_types::{CallRequest, CallResponse, CallMethodRequest, CallMethodResult};
+use opcua_types::service_types::{Argument, CallRequest, CallResponse, CallMethodRequest, CallMethodResult};
 use opcua_types::node_ids::{ObjectId, MethodId};
 
 use super::*;
@@ -164,3 +164,144 @@ fn call_getmonitoreditems() {
         }
     }
 }
+
+/// Adds a method with input arguments to two folders and returns the ids of the folders and the
+/// method
+fn add_scale_method(address_space: &mut AddressSpace) -> (NodeId, NodeId, NodeId) {
+    let folder1_id = address_space.add_folder("Folder1", "Folder1", &AddressSpace::objects_folder_id()).unwrap();
+    let folder2_id = address_space.add_folder("Folder2", "Folder2", &AddressSpace::objects_folder_id()).unwrap();
+
+    let method_id = NodeId::new(1, UAString::from("Scale"));
+    address_space.insert(Method::new(&method_id, "Scale", "Scale", "", false, true, true), Some(&[
+        (&folder1_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        (&folder2_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+    ]));
+
+    let arguments = vec![
+        ("Values", DataTypeId::Double, 1, Some(vec![2])),
+        ("Factor", DataTypeId::Duration, -1, None),
+        ("Name", DataTypeId::String, -1, None),
+    ].into_iter().map(|(name, data_type, value_rank, array_dimensions)| {
+        Variant::from(ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, Argument {
+            name: UAString::from(name),
+            data_type: data_type.into(),
+            value_rank,
+            array_dimensions,
+            description: LocalizedText::new("", ""),
+        }))
+    }).collect::<Vec<Variant>>();
+    let arguments_id = NodeId::new(1, UAString::from("Scale_InputArguments"));
+    address_space.insert(Variable::new_data_value(&arguments_id, "InputArguments", "InputArguments", "", DataTypeId::Argument, DataValue::new(arguments)), Some(&[
+        (&method_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+    ]));
+
+    // The handler is for the method on any object
+    address_space.register_typed_method_handler(NodeId::null(), method_id.clone(), |_: &AddressSpace, _: &ServerState, _: &Session, (values, factor, name): (Vec<Double>, Double, String)| {
+        let values: Vec<Variant> = values.iter().map(|v| Variant::from(v * factor)).collect();
+        Ok((values, name))
+    });
+
+    (folder1_id, folder2_id, method_id)
+}
+
+#[test]
+fn call_validates_input_arguments() {
+    opcua_core::init_logging();
+
+    let st = ServiceTest::new();
+    let s = MethodService::new();
+    let (server_state, session) = st.get_server_state_and_session();
+    let mut address_space = st.server.address_space.write().unwrap();
+    let (folder1_id, folder2_id, method_id) = add_scale_method(&mut address_space);
+
+    let values = Variant::from(vec![Variant::from(1f64), Variant::from(2f64)]);
+    let call = |object_id: &NodeId, args: Vec<Variant>| {
+        let request = new_call_method_request(object_id.clone(), method_id.clone(), Some(args));
+        call_single(&s, &address_space, &server_state, &session, request).unwrap()
+    };
+
+    // The handler is called on either object, and a Double is good for a Duration
+    for object_id in &[&folder1_id, &folder2_id] {
+        let response = call(object_id, vec![values.clone(), 3f64.into(), "x".into()]);
+        assert_eq!(response.status_code, Good);
+        assert_eq!(response.input_argument_results, Some(vec![Good, Good, Good]));
+        assert_eq!(response.output_arguments, Some(vec![Variant::from(vec![Variant::from(3f64), Variant::from(6f64)]), "x".into()]));
+    }
+
+    // The method does not exist on the objects folder
+    let response = call(&AddressSpace::objects_folder_id(), vec![values.clone(), 3f64.into(), "x".into()]);
+    assert_eq!(response.status_code, BadMethodInvalid);
+
+    // Wrong number of arguments
+    let response = call(&folder1_id, vec![values.clone(), 3f64.into()]);
+    assert_eq!(response.status_code, BadArgumentsMissing);
+    let response = call(&folder1_id, vec![values.clone(), 3f64.into(), "x".into(), "y".into()]);
+    assert_eq!(response.status_code, BadTooManyArguments);
+
+    // Wrong data types
+    let response = call(&folder1_id, vec![values.clone(), 3f32.into(), "x".into()]);
+    assert_eq!(response.status_code, BadInvalidArgument);
+    assert_eq!(response.input_argument_results, Some(vec![Good, BadTypeMismatch, Good]));
+    let response = call(&folder1_id, vec![Variant::from(vec![Variant::from(1i32), Variant::from(2i32)]), 3f64.into(), 1i32.into()]);
+    assert_eq!(response.input_argument_results, Some(vec![BadTypeMismatch, Good, BadTypeMismatch]));
+
+    // Wrong value rank or array dimensions
+    let response = call(&folder1_id, vec![1f64.into(), Variant::from(vec![Variant::from(3f64)]), "x".into()]);
+    assert_eq!(response.input_argument_results, Some(vec![BadTypeMismatch, BadTypeMismatch, Good]));
+    let response = call(&folder1_id, vec![Variant::from(vec![Variant::from(1f64)]), 3f64.into(), "x".into()]);
+    assert_eq!(response.input_argument_results, Some(vec![BadTypeMismatch, Good, Good]));
+}
+
+#[test]
+fn call_instantiated_method() {
+    opcua_core::init_logging();
+
+    let st = ServiceTest::new();
+    let s = MethodService::new();
+    let (server_state, session) = st.get_server_state_and_session();
+    let mut address_space = st.server.address_space.write().unwrap();
+
+    // A type with a Double method that takes and returns a Double
+    let type_id = NodeId::new_string(1, "DoublerType");
+    address_space.insert(ObjectType::new(&type_id, "DoublerType", "DoublerType", "", false), Some(&[
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    let method_id = NodeId::new_string(1, "DoublerType.Double");
+    address_space.insert(Method::new(&method_id, "Double", "Double", "", false, true, true), Some(&[
+        (&type_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        (&ObjectId::ModellingRule_Mandatory.into(), ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+    let arguments = vec![Variant::from(ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, Argument {
+        name: UAString::from("Value"),
+        data_type: DataTypeId::Double.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::new("", ""),
+    }))];
+    let arguments_id = NodeId::new_string(1, "DoublerType.Double.InputArguments");
+    address_space.insert(Variable::new_data_value(&arguments_id, "InputArguments", "InputArguments", "", DataTypeId::Argument, DataValue::new(arguments)), Some(&[
+        (&method_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+    ]));
+
+    // The handler is registered for the declaration and handles the method of every instance
+    address_space.register_typed_method_handler(NodeId::null(), method_id.clone(), |_: &AddressSpace, _: &ServerState, _: &Session, (value, ): (Double, )| {
+        Ok((value * 2f64, ))
+    });
+
+    let doubler_id = address_space.instantiate(&type_id, &AddressSpace::objects_folder_id(), "Doubler").unwrap();
+    let instance_method_id = address_space.find_references_from(&doubler_id, Some((ReferenceTypeId::HasComponent, false))).unwrap()
+        .first().unwrap().node_id.clone();
+    assert_ne!(instance_method_id, method_id);
+
+    let call = |args: Vec<Variant>| {
+        let request = new_call_method_request(doubler_id.clone(), instance_method_id.clone(), Some(args));
+        call_single(&s, &address_space, &server_state, &session, request).unwrap()
+    };
+    let response = call(vec![4f64.into()]);
+    assert_eq!(response.status_code, Good);
+    assert_eq!(response.output_arguments, Some(vec![Variant::from(8f64)]));
+
+    // The arguments are checked against the declaration's InputArguments
+    let response = call(vec!["x".into()]);
+    assert_eq!(response.status_code, BadInvalidArgument);
+}
@@ -15,7 +15,11 @@ struct ServiceTest {
 
 impl ServiceTest {
     pub fn new() -> ServiceTest {
-        let server = Server::new(ServerConfig::new_anonymous("foo"));
+        Self::new_with_config(ServerConfig::new_anonymous("foo"))
+    }
+
+    pub fn new_with_config(config: ServerConfig) -> ServiceTest {
+        let server = Server::new(config);
         let tcp_transport = server.new_transport();
         let server_state = server.server_state.clone();
         let address_space = server.address_space.clone();
@@ -72,4 +76,6 @@ pub mod session;
 pub mod monitored_item;
 pub mod subscription;
 pub mod view;
-pub mod method;
\ No newline at end of file
+pub mod method;
+pub mod server_configuration;
+pub mod value_source;
@@ -1,7 +1,9 @@
 use chrono;
 
 use prelude::*;
+use constants;
 use super::*;
+use super::value_source::{TestValueSource, TestValueSourceMode};
 
 fn test_var_node_id() -> NodeId {
     NodeId::new(1, 1)
@@ -247,3 +249,289 @@ fn monitored_item_queue_discard_oldest() {
         assert_first_notification_is_i32(&mut monitored_item, 10);
     }
 }
+
+fn set_test_var_value(address_space: &mut AddressSpace, value: UInt32) {
+    assert!(address_space.set_variable_value(test_var_node_id(), value));
+}
+
+fn notification_values(monitored_item: &mut MonitoredItem) -> Vec<Variant> {
+    monitored_item.remove_all_notification_messages().unwrap_or(Vec::new()).into_iter().map(|n| n.value.value.unwrap()).collect()
+}
+
+#[test]
+fn monitored_item_pushed_changes() {
+    let mut address_space = make_address_space();
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(-1f64, 5)).unwrap();
+
+    // The first sample is read from the node
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(0)]);
+
+    // Changes are held until the subscription's timer fires, which samples the latest
+    set_test_var_value(&mut address_space, 1);
+    set_test_var_value(&mut address_space, 2);
+    assert!(!monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::ReceivedPublishRequest));
+    set_test_var_value(&mut address_space, 3);
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(3)]);
+
+    // Nothing was set so nothing changed
+    assert!(!monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+
+    // Changes made through the node are pushed too
+    if let Some(variable) = address_space.find_variable(test_var_node_id()) {
+        variable.set_value(DataValue::new(4 as UInt32));
+    }
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(4)]);
+
+    // Changes while disabled are not reported
+    monitored_item.monitoring_mode = MonitoringMode::Disabled;
+    set_test_var_value(&mut address_space, 5);
+    assert!(!monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    monitored_item.monitoring_mode = MonitoringMode::Reporting;
+    assert!(!monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+}
+
+#[test]
+fn monitored_item_sampling_interval() {
+    use std::thread;
+    use std::time::Duration as StdDuration;
+
+    // Changes are sampled according to when they were set, at an interval shorter than the
+    // subscription timer's
+    let mut address_space = make_address_space();
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(50f64, 10)).unwrap();
+    thread::sleep(StdDuration::from_millis(60));
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+
+    thread::sleep(StdDuration::from_millis(100));
+    set_test_var_value(&mut address_space, 1);
+    thread::sleep(StdDuration::from_millis(100));
+    set_test_var_value(&mut address_space, 2);
+    // These are within the interval of the last, so only the latest is sampled once it elapses
+    set_test_var_value(&mut address_space, 3);
+    set_test_var_value(&mut address_space, 4);
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    let later = chrono::Utc::now() + time::Duration::seconds(1);
+    monitored_item.tick(&address_space, &later, TickReason::TickTimerFired);
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(0), Variant::UInt32(1), Variant::UInt32(2), Variant::UInt32(4)]);
+}
+
+#[test]
+fn monitored_item_polls_getter() {
+    use std::sync::{Arc, Mutex};
+    use address_space::AttrFnGetter;
+
+    // Values from a getter are polled since they are not pushed
+    let mut address_space = make_address_space();
+    let mut counter = 0;
+    let getter = AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+        counter += 1;
+        Ok(Some(DataValue::new(counter as UInt32)))
+    });
+    address_space.find_variable(test_var_node_id()).unwrap().set_value_getter(Arc::new(Mutex::new(getter)));
+
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(-1f64, 5)).unwrap();
+    let now = chrono::Utc::now();
+    let later = now + time::Duration::milliseconds(constants::SUBSCRIPTION_TIMER_RATE_MS);
+    assert!(monitored_item.tick(&address_space, &now, TickReason::TickTimerFired));
+    assert!(monitored_item.tick(&address_space, &later, TickReason::TickTimerFired));
+    assert!(!monitored_item.tick(&address_space, &later, TickReason::ReceivedPublishRequest));
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(1), Variant::UInt32(2)]);
+}
+
+#[test]
+fn data_change_index() {
+    use address_space::data_change::DataChangeIndex;
+
+    let index = DataChangeIndex::new();
+    let node_id = test_var_node_id();
+    let queue = index.watch(&node_id, AttributeId::Value, None);
+    assert_eq!(index.watcher_count(&node_id, AttributeId::Value), 1);
+
+    // Only the watched attribute is pushed to the queue
+    index.notify(&node_id, AttributeId::Value, &DataValue::new(1 as UInt32));
+    index.notify(&node_id, AttributeId::DisplayName, &DataValue::new(LocalizedText::new("", "x")));
+    index.notify(&NodeId::new(1, 2), AttributeId::Value, &DataValue::new(2 as UInt32));
+    let changes = queue.take();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].value.value, Some(Variant::UInt32(1)));
+    assert!(queue.take().is_empty());
+
+    // A full queue loses its oldest change
+    for i in 0..(constants::MAX_PENDING_DATA_CHANGES + 1) {
+        index.notify(&node_id, AttributeId::Value, &DataValue::new(i as UInt32));
+    }
+    let changes = queue.take();
+    assert_eq!(changes.len(), constants::MAX_PENDING_DATA_CHANGES);
+    assert_eq!(changes[0].value.value, Some(Variant::UInt32(1)));
+
+    // Dropping the queue unregisters it
+    drop(queue);
+    assert_eq!(index.watcher_count(&node_id, AttributeId::Value), 0);
+    index.notify(&node_id, AttributeId::Value, &DataValue::new(3 as UInt32));
+}
+
+#[test]
+fn monitored_item_data_change_signal() {
+    use std::sync::{Arc, Mutex};
+    use address_space::AttrFnGetter;
+    use address_space::data_change::DataChangeSignal;
+
+    let mut address_space = make_address_space();
+    let signal = Arc::new(DataChangeSignal::new());
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(-1f64, 5)).unwrap();
+    monitored_item.set_data_change_signal(Some(signal.clone()));
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    assert!(monitored_item.is_watching_data_changes());
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(0)]);
+    assert!(!signal.take());
+
+    // A change raises the signal once, and is sampled when the item's interval elapses
+    set_test_var_value(&mut address_space, 1);
+    assert!(signal.take());
+    assert!(!signal.take());
+    assert!(!monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::DataChanged));
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(1)]);
+
+    // A getter that is set after the item started to watch is polled from then on
+    let getter = AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+        Ok(Some(DataValue::new(10 as UInt32)))
+    });
+    address_space.find_variable(test_var_node_id()).unwrap().set_value_getter(Arc::new(Mutex::new(getter)));
+    assert!(signal.take());
+    assert!(monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired));
+    assert!(!monitored_item.is_watching_data_changes());
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(10)]);
+}
+
+#[test]
+fn monitored_items_share_samples() {
+    use std::sync::{Arc, Mutex};
+    use address_space::AttrFnGetter;
+
+    // Items that poll the same getter at the same interval share one sample
+    let mut address_space = make_address_space();
+    let calls = Arc::new(Mutex::new(0));
+    {
+        let calls = calls.clone();
+        let getter = AttrFnGetter::new(move |_: NodeId, _: AttributeId| -> Result<Option<DataValue>, StatusCode> {
+            let mut calls = calls.lock().unwrap();
+            *calls += 1;
+            Ok(Some(DataValue::new(*calls as UInt32)))
+        });
+        address_space.find_variable(test_var_node_id()).unwrap().set_value_getter(Arc::new(Mutex::new(getter)));
+    }
+
+    let mut monitored_items: Vec<MonitoredItem> = (1..4).map(|i| MonitoredItem::new(i, TimestampsToReturn::Both, &make_create_request(-1f64, 5)).unwrap()).collect();
+    let now = chrono::Utc::now();
+    for monitored_item in monitored_items.iter_mut() {
+        assert!(monitored_item.tick(&address_space, &now, TickReason::TickTimerFired));
+        assert_eq!(notification_values(monitored_item), vec![Variant::UInt32(1)]);
+    }
+    assert_eq!(*calls.lock().unwrap(), 1);
+    assert_eq!(address_space.sampler().sampling_count(), 1);
+    assert_eq!(address_space.sampler().shared_sampling_count(), 2);
+
+    // The next tick of the subscription timer samples again
+    let later = now + time::Duration::milliseconds(constants::SUBSCRIPTION_TIMER_RATE_MS);
+    for monitored_item in monitored_items.iter_mut() {
+        assert!(monitored_item.tick(&address_space, &later, TickReason::TickTimerFired));
+    }
+    assert_eq!(*calls.lock().unwrap(), 2);
+
+    // An item with another interval is in another group
+    let mut other_item = MonitoredItem::new(4, TimestampsToReturn::Both, &make_create_request(500f64, 5)).unwrap();
+    other_item.tick(&address_space, &later, TickReason::TickTimerFired);
+    monitored_items[0].monitoring_mode = MonitoringMode::Disabled;
+    monitored_items[0].tick(&address_space, &later, TickReason::TickTimerFired);
+    assert_eq!(address_space.sampler().group_count(), 2);
+
+    let diagnostics = address_space.sampler().sampling_interval_diagnostics();
+    assert_eq!(diagnostics, vec![
+        SamplingIntervalDiagnosticsDataType {
+            sampling_interval: -1f64,
+            monitored_item_count: 3,
+            max_monitored_item_count: 3,
+            disabled_monitored_item_count: 1,
+        },
+        SamplingIntervalDiagnosticsDataType {
+            sampling_interval: 500f64,
+            monitored_item_count: 1,
+            max_monitored_item_count: 1,
+            disabled_monitored_item_count: 0,
+        },
+    ]);
+
+    // Items leave their groups when they are removed
+    monitored_items.truncate(1);
+    drop(other_item);
+    assert_eq!(address_space.sampler().group_count(), 1);
+    assert_eq!(address_space.sampler().sampling_interval_diagnostics()[0].monitored_item_count, 1);
+    monitored_items.clear();
+    assert_eq!(address_space.sampler().group_count(), 0);
+    assert!(address_space.sampler().sampling_interval_diagnostics().is_empty());
+}
+
+#[test]
+fn sampling_interval_diagnostics_array() {
+    let st = ServiceTest::new();
+    let mut address_space = st.get_address_space();
+    let _ = address_space.add_variable(Variable::new(&test_var_node_id(), "test", "test", "", 0 as UInt32), &AddressSpace::objects_folder_id());
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(100f64, 5)).unwrap();
+    monitored_item.tick(&address_space, &chrono::Utc::now(), TickReason::TickTimerFired);
+
+    let value = address_space.find_node(&VariableId::Server_ServerDiagnostics_SamplingIntervalDiagnosticsArray.into()).unwrap().as_node().find_attribute(AttributeId::Value).unwrap().value.unwrap();
+    if let Variant::Array(values) = value {
+        assert_eq!(values.len(), 1);
+        if let Variant::ExtensionObject(ref extension_object) = values[0] {
+            let diagnostics = extension_object.decode_inner::<SamplingIntervalDiagnosticsDataType>().unwrap();
+            assert_eq!(diagnostics.sampling_interval, 100f64);
+            assert_eq!(diagnostics.monitored_item_count, 1);
+        } else {
+            panic!("Expected an extension object, got {:?}", values[0]);
+        }
+    } else {
+        panic!("Expected an array, got {:?}", value);
+    }
+}
+
+#[test]
+fn monitored_item_refreshes_value_source() {
+    use std::thread;
+    use std::time::Duration as StdDuration;
+
+    // A value source is refreshed in the background when the value is older than the interval,
+    // and the sample is whatever value the source last returned
+    let mut address_space = AddressSpace::new();
+    let source = TestValueSource::new(TestValueSourceMode::Hang);
+    source.add_variable(&mut address_space, &test_var_node_id(), 100f64);
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(-1f64, 5)).unwrap();
+    let sample_status = |monitored_item: &mut MonitoredItem| {
+        monitored_item.remove_all_notification_messages().unwrap().into_iter().map(|n| n.value.status).collect::<Vec<_>>()
+    };
+    let now = chrono::Utc::now();
+    let tick_time = |tick: i64| now + time::Duration::milliseconds(tick * constants::SUBSCRIPTION_TIMER_RATE_MS);
+
+    assert!(monitored_item.tick(&address_space, &tick_time(0), TickReason::TickTimerFired));
+    assert_eq!(sample_status(&mut monitored_item), vec![Some(BadWaitingForInitialData)]);
+    assert_eq!(source.reads(), 1);
+
+    // The value that the refresh returns is sampled on the next tick
+    source.release(7);
+    thread::sleep(StdDuration::from_millis(20));
+    assert!(monitored_item.tick(&address_space, &tick_time(1), TickReason::TickTimerFired));
+    assert_eq!(notification_values(&mut monitored_item), vec![Variant::UInt32(7)]);
+    assert_eq!(source.reads(), 1);
+
+    // Once the value is older than the interval a refresh starts, and it never completes, so the
+    // value becomes uncertain when it times out
+    thread::sleep(StdDuration::from_millis(150));
+    assert!(!monitored_item.tick(&address_space, &tick_time(2), TickReason::TickTimerFired));
+    assert_eq!(source.reads(), 2);
+    thread::sleep(StdDuration::from_millis(150));
+    assert!(monitored_item.tick(&address_space, &tick_time(3), TickReason::TickTimerFired));
+    assert_eq!(sample_status(&mut monitored_item), vec![Some(UncertainLastUsableValue)]);
+}
@@ -0,0 +1,455 @@
+use std::fs;
+use std::io::Cursor;
+use std::time::Duration;
+
+use opcua_types::service_types::{CallRequest, CallResponse, CallMethodRequest, CallMethodResult, CloseSessionRequest, TrustListDataType};
+use opcua_types::node_ids::{MethodId, ObjectId};
+
+use super::*;
+
+use config::ServerUserToken;
+use services::message_handler::MessageHandler;
+use services::method::MethodService;
+
+const ADMIN_USER: &'static str = "admin";
+const ADMIN_PASS: &'static str = "admin1";
+
+fn server_configuration_id() -> NodeId { ObjectId::ServerConfiguration.into() }
+
+fn trust_list_id() -> NodeId { ObjectId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList.into() }
+
+/// Makes a server with its own pki dir and an admin who may manage certificates
+fn make_service_test(name: &str) -> ServiceTest {
+    let pki_dir = make_test_file(&format!("pki-server-configuration-{}", name));
+    let _ = fs::remove_dir_all(&pki_dir);
+
+    let mut config = ServerConfig::new_anonymous("foo");
+    config.pki_dir = pki_dir;
+    config.create_sample_keypair = true;
+    config.user_tokens.insert(ADMIN_USER.to_string(), ServerUserToken::new_user_pass(ADMIN_USER, ADMIN_PASS));
+    config.security_admin_user_token_ids.insert(ADMIN_USER.to_string());
+    let st = ServiceTest::new_with_config(config);
+    {
+        let mut session = st.session.write().unwrap();
+        session.authentication_token = NodeId::new(0, ByteString::random(32));
+        session.secure_channel.set_security_mode(MessageSecurityMode::SignAndEncrypt);
+        session.user_identity = Some(make_user_name_identity_token(ADMIN_USER, ADMIN_PASS.as_bytes()));
+    }
+    st
+}
+
+fn make_user_name_identity_token(user: &str, pass: &[u8]) -> ExtensionObject {
+    let token = UserNameIdentityToken {
+        policy_id: UAString::from(SecurityPolicy::None.to_uri()),
+        user_name: UAString::from(user),
+        password: ByteString::from(pass),
+        encryption_algorithm: UAString::null(),
+    };
+    ExtensionObject::from_encodable(ObjectId::UserNameIdentityToken_Encoding_DefaultBinary, token)
+}
+
+fn call(st: &ServiceTest, object_id: NodeId, method_id: MethodId, input_arguments: Vec<Variant>) -> CallMethodResult {
+    let s = MethodService::new();
+    let (server_state, session) = st.get_server_state_and_session();
+    let address_space = st.get_address_space();
+    let response = s.call(&address_space, &server_state, &session, CallRequest {
+        request_header: RequestHeader::new(&NodeId::null(), &DateTime::now(), 1),
+        methods_to_call: Some(vec![CallMethodRequest {
+            object_id,
+            method_id: method_id.into(),
+            input_arguments: if input_arguments.is_empty() { None } else { Some(input_arguments) },
+        }]),
+    }).unwrap();
+    let response: CallResponse = supported_message_as!(response, CallResponse);
+    response.results.unwrap().remove(0)
+}
+
+/// Calls a method the way a client does, so the server acts on what the call asks of it once the
+/// call completes
+fn call_through_message_handler(st: &ServiceTest, object_id: NodeId, method_id: MethodId) -> CallMethodResult {
+    let authentication_token = st.session.read().unwrap().authentication_token.clone();
+    let message_handler = MessageHandler::new(st.server.certificate_store.clone(), st.server_state.clone(), st.session.clone(), st.address_space.clone());
+    let response = message_handler.handle_message(1, CallRequest {
+        request_header: RequestHeader::new(&authentication_token, &DateTime::now(), 1),
+        methods_to_call: Some(vec![CallMethodRequest {
+            object_id,
+            method_id: method_id.into(),
+            input_arguments: None,
+        }]),
+    }.into()).unwrap();
+    let response: CallResponse = supported_message_as!(response.unwrap(), CallResponse);
+    response.results.unwrap().remove(0)
+}
+
+fn output_argument(result: &CallMethodResult, idx: usize) -> Variant {
+    assert_eq!(result.status_code, Good);
+    result.output_arguments.as_ref().unwrap()[idx].clone()
+}
+
+fn open_trust_list_for_read(st: &ServiceTest) -> UInt32 {
+    let result = call(st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open, vec![Variant::Byte(1)]);
+    if let Variant::UInt32(file_handle) = output_argument(&result, 0) { file_handle } else { panic!() }
+}
+
+/// Reads the whole trust list through the TrustList object
+fn read_trust_list(st: &ServiceTest) -> TrustListDataType {
+    let file_handle = open_trust_list_for_read(st);
+    let mut data = Vec::new();
+    loop {
+        let result = call(st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Read, vec![file_handle.into(), Variant::Int32(100)]);
+        if let Variant::ByteString(chunk) = output_argument(&result, 0) {
+            let chunk = chunk.value.unwrap();
+            if chunk.is_empty() {
+                break;
+            }
+            data.extend(chunk);
+        } else {
+            panic!();
+        }
+    }
+    let result = call(st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Close, vec![file_handle.into()]);
+    assert_eq!(result.status_code, Good);
+    TrustListDataType::decode(&mut Cursor::new(data)).unwrap()
+}
+
+fn open_trust_list_for_write(st: &ServiceTest) -> UInt32 {
+    let result = call(st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open, vec![Variant::Byte(6)]);
+    if let Variant::UInt32(file_handle) = output_argument(&result, 0) { file_handle } else { panic!() }
+}
+
+fn make_cert_and_pkey(common_name: &str) -> (X509, PrivateKey) {
+    let mut args = X509Data::sample_cert();
+    args.common_name = common_name.to_string();
+    CertificateStore::create_cert_and_pkey(&args).unwrap()
+}
+
+/// Makes a cert and key that may replace the server's own
+fn make_server_cert_and_pkey(st: &ServiceTest, common_name: &str) -> (X509, PrivateKey) {
+    let mut args = {
+        let server_state = st.server_state.read().unwrap();
+        let config = server_state.config.read().unwrap();
+        X509Data::from(config.application_description())
+    };
+    args.common_name = common_name.to_string();
+    CertificateStore::create_cert_and_pkey(&args).unwrap()
+}
+
+#[test]
+fn server_configuration_access() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("access");
+
+    // The admin on an encrypted session is allowed
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_GetRejectedList, vec![]);
+    assert_eq!(result.status_code, Good);
+
+    // Anybody else is not
+    {
+        let mut session = st.session.write().unwrap();
+        session.user_identity = Some(make_user_name_identity_token(ADMIN_USER, b"wrong"));
+    }
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_GetRejectedList, vec![]);
+    assert_eq!(result.status_code, BadUserAccessDenied);
+
+    // Nor is the admin on a session that is not encrypted
+    {
+        let mut session = st.session.write().unwrap();
+        session.user_identity = Some(make_user_name_identity_token(ADMIN_USER, ADMIN_PASS.as_bytes()));
+        session.secure_channel.set_security_mode(MessageSecurityMode::Sign);
+    }
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_GetRejectedList, vec![]);
+    assert_eq!(result.status_code, BadSecurityModeInsufficient);
+}
+
+#[test]
+fn server_configuration_create_signing_request() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("csr");
+
+    // Request for the existing key
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_CreateSigningRequest, vec![
+        Variant::Empty, Variant::Empty, UAString::from("CN=NewName, O=Company").into(), Variant::Boolean(false), Variant::Empty
+    ]);
+    if let Variant::ByteString(csr) = output_argument(&result, 0) {
+        assert!(!csr.value.unwrap().is_empty());
+    } else {
+        panic!();
+    }
+
+    // Regenerating the key requires a nonce
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_CreateSigningRequest, vec![
+        Variant::Empty, Variant::Empty, Variant::Empty, Variant::Boolean(true), ByteString::from(&[1u8; 8]).into()
+    ]);
+    assert_eq!(result.status_code, BadInvalidArgument);
+
+    // Bad subject name
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_CreateSigningRequest, vec![
+        Variant::Empty, Variant::Empty, UAString::from("XX=NewName").into(), Variant::Boolean(false), Variant::Empty
+    ]);
+    assert_eq!(result.status_code, BadInvalidArgument);
+
+    // Unsupported certificate type
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_CreateSigningRequest, vec![
+        Variant::Empty, NodeId::new(0, 99999).into(), Variant::Empty, Variant::Boolean(false), Variant::Empty
+    ]);
+    assert_eq!(result.status_code, BadNotSupported);
+}
+
+#[test]
+fn server_configuration_get_rejected_list() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("rejected");
+    let (cert, _) = make_cert_and_pkey("rejected");
+    {
+        let certificate_store = st.server.certificate_store.read().unwrap();
+        certificate_store.store_rejected_cert(&cert).unwrap();
+    }
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_GetRejectedList, vec![]);
+    if let Variant::Array(certificates) = output_argument(&result, 0) {
+        assert_eq!(certificates, vec![Variant::from(cert.as_byte_string())]);
+    } else {
+        panic!();
+    }
+}
+
+#[test]
+fn server_configuration_update_certificate() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("update");
+    let (cert, pkey) = make_server_cert_and_pkey(&st, "updated");
+    let (_, other_pkey) = make_cert_and_pkey("other");
+
+    // A key that does not belong to the cert
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_UpdateCertificate, vec![
+        Variant::Empty, Variant::Empty, cert.as_byte_string().into(), Variant::Empty,
+        UAString::from("PEM").into(), ByteString::from(&other_pkey.private_key_to_pem().unwrap()).into(),
+    ]);
+    assert_eq!(result.status_code, BadSecurityChecksFailed);
+
+    // Unsupported key format
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_UpdateCertificate, vec![
+        Variant::Empty, Variant::Empty, cert.as_byte_string().into(), Variant::Empty,
+        UAString::from("PFX").into(), ByteString::from(&pkey.private_key_to_pem().unwrap()).into(),
+    ]);
+    assert_eq!(result.status_code, BadNotSupported);
+
+    // A cert that is not for the server's application uri
+    let (other_cert, other_pkey) = make_cert_and_pkey("other");
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_UpdateCertificate, vec![
+        Variant::Empty, Variant::Empty, other_cert.as_byte_string().into(), Variant::Empty,
+        UAString::from("PEM").into(), ByteString::from(&other_pkey.private_key_to_pem().unwrap()).into(),
+    ]);
+    assert_eq!(result.status_code, BadCertificateUriInvalid);
+
+    // A cert with a shorter key than the server's
+    let (short_cert, short_pkey) = {
+        let mut args = {
+            let server_state = st.server_state.read().unwrap();
+            let config = server_state.config.read().unwrap();
+            X509Data::from(config.application_description())
+        };
+        args.key_size = 1024;
+        CertificateStore::create_cert_and_pkey(&args).unwrap()
+    };
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_UpdateCertificate, vec![
+        Variant::Empty, Variant::Empty, short_cert.as_byte_string().into(), Variant::Empty,
+        UAString::from("PEM").into(), ByteString::from(&short_pkey.private_key_to_pem().unwrap()).into(),
+    ]);
+    assert_eq!(result.status_code, BadCertificateInvalid);
+
+    // The right key
+    let result = call(&st, server_configuration_id(), MethodId::ServerConfiguration_UpdateCertificate, vec![
+        Variant::Empty, Variant::Empty, cert.as_byte_string().into(), Variant::Empty,
+        UAString::from("PEM").into(), ByteString::from(&pkey.private_key_to_pem().unwrap()).into(),
+    ]);
+    assert_eq!(output_argument(&result, 0), Variant::Boolean(true));
+
+    // Nothing changes until the changes are applied
+    {
+        let certificate_store = st.server.certificate_store.read().unwrap();
+        let (own_cert, _) = certificate_store.read_own_cert_and_pkey().unwrap();
+        assert_ne!(own_cert.thumbprint().as_hex_string(), cert.thumbprint().as_hex_string());
+    }
+    let old_cert = st.session.read().unwrap().secure_channel.cert().unwrap();
+    let result = call_through_message_handler(&st, server_configuration_id(), MethodId::ServerConfiguration_ApplyChanges);
+    assert_eq!(result.status_code, Good);
+    {
+        let certificate_store = st.server.certificate_store.read().unwrap();
+        let (own_cert, _) = certificate_store.read_own_cert_and_pkey().unwrap();
+        assert_eq!(own_cert.thumbprint().as_hex_string(), cert.thumbprint().as_hex_string());
+    }
+
+    // Applying the changes swaps the cert in for new secure channels, without waiting for the pki
+    // folder to be watched, while the existing one keeps the old cert
+    {
+        let server_state = st.server_state.read().unwrap();
+        assert_eq!(server_state.server_certificate.as_ref().unwrap().thumbprint().as_hex_string(), cert.thumbprint().as_hex_string());
+    }
+    let session = st.session.read().unwrap();
+    assert_eq!(session.secure_channel.cert().unwrap().thumbprint().as_hex_string(), old_cert.thumbprint().as_hex_string());
+    let new_session = st.server.new_transport().session();
+    let new_session = new_session.read().unwrap();
+    assert_eq!(new_session.secure_channel.cert().unwrap().thumbprint().as_hex_string(), cert.thumbprint().as_hex_string());
+
+    // Reloading on request keeps the applied cert
+    assert!(st.server.reload_certificates().is_ok());
+    let server_state = st.server_state.read().unwrap();
+    assert_eq!(server_state.server_certificate.as_ref().unwrap().thumbprint().as_hex_string(), cert.thumbprint().as_hex_string());
+}
+
+#[test]
+fn server_configuration_add_remove_certificate() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("add-remove");
+    let (trusted_cert, _) = make_cert_and_pkey("trusted");
+    let (issuer_cert, _) = make_cert_and_pkey("issuer");
+
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_AddCertificate, vec![
+        trusted_cert.as_byte_string().into(), Variant::Boolean(true)
+    ]);
+    assert_eq!(result.status_code, Good);
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_AddCertificate, vec![
+        issuer_cert.as_byte_string().into(), Variant::Boolean(false)
+    ]);
+    assert_eq!(result.status_code, Good);
+
+    let trust_list = read_trust_list(&st);
+    assert_eq!(trust_list.trusted_certificates.unwrap(), vec![trusted_cert.as_byte_string()]);
+    assert_eq!(trust_list.issuer_certificates.unwrap(), vec![issuer_cert.as_byte_string()]);
+
+    // Removing from the wrong list fails
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_RemoveCertificate, vec![
+        UAString::from(trusted_cert.thumbprint().as_hex_string()).into(), Variant::Boolean(false)
+    ]);
+    assert_eq!(result.status_code, BadInvalidArgument);
+
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_RemoveCertificate, vec![
+        UAString::from(trusted_cert.thumbprint().as_hex_string()).into(), Variant::Boolean(true)
+    ]);
+    assert_eq!(result.status_code, Good);
+
+    let trust_list = read_trust_list(&st);
+    assert!(trust_list.trusted_certificates.map_or(true, |c| c.is_empty()));
+}
+
+#[test]
+fn server_configuration_write_trust_list() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("write");
+    let (old_cert, _) = make_cert_and_pkey("old");
+    let (new_cert, _) = make_cert_and_pkey("new");
+    {
+        let certificate_store = st.server.certificate_store.read().unwrap();
+        certificate_store.store_trusted_cert(&old_cert).unwrap();
+    }
+
+    // Open for writing with the existing contents erased
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open, vec![Variant::Byte(6)]);
+    let file_handle = if let Variant::UInt32(file_handle) = output_argument(&result, 0) { file_handle } else { panic!() };
+
+    // The trust list cannot be read or changed while it is being written
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open, vec![Variant::Byte(1)]);
+    assert_eq!(result.status_code, BadInvalidState);
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_AddCertificate, vec![
+        new_cert.as_byte_string().into(), Variant::Boolean(true)
+    ]);
+    assert_eq!(result.status_code, BadInvalidState);
+
+    // Write the new trust list in two chunks
+    let trust_list = TrustListDataType {
+        specified_lists: TRUST_LIST_TRUSTED_CERTIFICATES,
+        trusted_certificates: Some(vec![new_cert.as_byte_string()]),
+        trusted_crls: None,
+        issuer_certificates: None,
+        issuer_crls: None,
+    };
+    let mut stream = Cursor::new(Vec::new());
+    trust_list.encode(&mut stream).unwrap();
+    let data = stream.into_inner();
+    let (first, second) = data.split_at(data.len() / 2);
+    for chunk in [first, second].iter() {
+        let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Write, vec![
+            file_handle.into(), ByteString::from(*chunk).into()
+        ]);
+        assert_eq!(result.status_code, Good);
+    }
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_CloseAndUpdate, vec![file_handle.into()]);
+    assert_eq!(output_argument(&result, 0), Variant::Boolean(false));
+
+    // The new trust list replaces the old one
+    let trust_list = read_trust_list(&st);
+    assert_eq!(trust_list.trusted_certificates.unwrap(), vec![new_cert.as_byte_string()]);
+
+    // The handle is closed
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Close, vec![file_handle.into()]);
+    assert_eq!(result.status_code, BadInvalidArgument);
+}
+
+#[test]
+fn server_configuration_write_trust_list_limit() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("write-limit");
+    let file_handle = open_trust_list_for_write(&st);
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Write, vec![
+        file_handle.into(), ByteString::from(&vec![0u8; 4 * 1024 * 1024 + 1]).into()
+    ]);
+    assert_eq!(result.status_code, BadEncodingLimitsExceeded);
+}
+
+#[test]
+fn server_configuration_trust_list_closed_with_session() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("close-session");
+    let file_handle = open_trust_list_for_write(&st);
+
+    // Closing the session closes the handles it left open, so the trust list can be opened again
+    let authentication_token = st.session.read().unwrap().authentication_token.clone();
+    let message_handler = MessageHandler::new(st.server.certificate_store.clone(), st.server_state.clone(), st.session.clone(), st.address_space.clone());
+    let response = message_handler.handle_message(1, CloseSessionRequest {
+        request_header: RequestHeader::new(&authentication_token, &DateTime::now(), 1),
+        delete_subscriptions: true,
+    }.into()).unwrap();
+    assert!(response.is_some());
+
+    // The admin on a new session can open the trust list but not use the old handle
+    {
+        let mut session = st.session.write().unwrap();
+        session.authentication_token = NodeId::new(0, ByteString::random(32));
+        session.user_identity = Some(make_user_name_identity_token(ADMIN_USER, ADMIN_PASS.as_bytes()));
+    }
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Close, vec![file_handle.into()]);
+    assert_eq!(result.status_code, BadInvalidArgument);
+    open_trust_list_for_write(&st);
+}
+
+#[test]
+fn server_configuration_trust_list_idle_handle() {
+    opcua_core::init_logging();
+
+    let st = make_service_test("idle");
+    let file_handle = open_trust_list_for_write(&st);
+
+    // The trust list cannot be opened again while the handle is in use
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Open, vec![Variant::Byte(6)]);
+    assert_eq!(result.status_code, BadInvalidState);
+
+    // Once the handle has not been used within the timeout it is closed
+    {
+        let address_space = st.get_address_space();
+        let certificate_manager = address_space.certificate_manager().unwrap();
+        certificate_manager.lock().unwrap().set_file_timeout(Duration::from_millis(0));
+    }
+    let result = call(&st, trust_list_id(), MethodId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList_Write, vec![
+        file_handle.into(), ByteString::from(&[1u8, 2, 3]).into()
+    ]);
+    assert_eq!(result.status_code, BadInvalidArgument);
+    open_trust_list_for_write(&st);
+}
@@ -0,0 +1,113 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration as StdDuration;
+
+use futures::{future, Future};
+use futures::sync::oneshot;
+
+use prelude::*;
+use address_space::value_source::AsyncValue;
+use super::*;
+
+/// How the `TestValueSource` responds to reads
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TestValueSourceMode {
+    /// Returns the number of the read
+    Count,
+    /// Does not complete until the read is released
+    Hang,
+    /// Fails to read
+    Fail,
+}
+
+struct TestValueSourceState {
+    mode: TestValueSourceMode,
+    reads: UInt32,
+    hung_reads: Vec<oneshot::Sender<DataValue>>,
+}
+
+/// A value source that stands in for a slow device
+#[derive(Clone)]
+pub struct TestValueSource {
+    state: Arc<Mutex<TestValueSourceState>>,
+}
+
+impl AsyncValueSource for TestValueSource {
+    fn read(&mut self, _: &NodeId) -> ValueFuture {
+        let mut state = self.state.lock().unwrap();
+        state.reads += 1;
+        match state.mode {
+            TestValueSourceMode::Count => Box::new(future::ok(DataValue::new(state.reads))),
+            TestValueSourceMode::Hang => {
+                let (tx, rx) = oneshot::channel();
+                state.hung_reads.push(tx);
+                Box::new(rx.map_err(|_| BadCommunicationError))
+            }
+            TestValueSourceMode::Fail => Box::new(future::err(BadCommunicationError)),
+        }
+    }
+}
+
+impl TestValueSource {
+    pub fn new(mode: TestValueSourceMode) -> TestValueSource {
+        TestValueSource {
+            state: Arc::new(Mutex::new(TestValueSourceState {
+                mode,
+                reads: 0,
+                hung_reads: Vec::new(),
+            }))
+        }
+    }
+
+    pub fn set_mode(&self, mode: TestValueSourceMode) {
+        self.state.lock().unwrap().mode = mode;
+    }
+
+    pub fn reads(&self) -> UInt32 {
+        self.state.lock().unwrap().reads
+    }
+
+    /// Completes the hung reads with the value
+    pub fn release(&self, value: UInt32) {
+        let hung_reads = self.state.lock().unwrap().hung_reads.drain(..).collect::<Vec<_>>();
+        hung_reads.into_iter().for_each(|tx| { let _ = tx.send(DataValue::new(value)); });
+    }
+
+    /// Returns the number of hung reads that were dropped before they completed
+    pub fn dropped_reads(&self) -> usize {
+        self.state.lock().unwrap().hung_reads.iter().filter(|tx| tx.is_canceled()).count()
+    }
+
+    /// Adds a variable to the address space whose value is read from this source
+    pub fn add_variable(&self, address_space: &mut AddressSpace, node_id: &NodeId, timeout: Duration) {
+        let mut variable = Variable::new(node_id, "slow", "slow", "", 0 as UInt32);
+        variable.set_value_source(Arc::new(Mutex::new(self.clone())), timeout);
+        let _ = address_space.add_variable(variable, &AddressSpace::objects_folder_id());
+    }
+}
+
+#[test]
+fn value_source_drops_timed_out_read() {
+    // A read that does not complete within the timeout is dropped, so the source can tell that
+    // nobody is waiting for it
+    let mut address_space = AddressSpace::new();
+    let node_id = NodeId::new(1, "slow".to_string());
+    let source = TestValueSource::new(TestValueSourceMode::Hang);
+    source.add_variable(&mut address_space, &node_id, 50f64);
+    let value = address_space.find_variable(node_id).unwrap().value_source().unwrap().clone();
+
+    AsyncValue::refresh_all(&[value.clone()], 0f64);
+    assert_eq!(value.value().status, Some(BadTimeout));
+    for _ in 0..100 {
+        if source.dropped_reads() > 0 {
+            break;
+        }
+        thread::sleep(StdDuration::from_millis(10));
+    }
+    assert_eq!(source.dropped_reads(), 1);
+
+    // The next refresh reads the source again
+    source.set_mode(TestValueSourceMode::Count);
+    AsyncValue::refresh_all(&[value.clone()], 0f64);
+    assert_eq!(value.value().value, Some(Variant::UInt32(2)));
+}
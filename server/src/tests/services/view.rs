@@ -258,3 +258,66 @@ fn translate_browse_paths_to_node_ids() {
         */
     }
 }
+
+#[test]
+fn translate_browse_paths_across_namespaces() {
+    let st = ServiceTest::new();
+
+    // Nodes in two namespaces with the same browse name
+    let (machine_id, speed_a_id, speed_b_id, ns_b) = {
+        let mut address_space = st.server.address_space.write().unwrap();
+        let ns_a = address_space.register_namespace("urn:rust-opcua:test:a");
+        let ns_b = address_space.register_namespace("urn:rust-opcua:test:b");
+        let machine_id = address_space.add_folder(QualifiedName::new(ns_a, "Machine"), "Machine", &AddressSpace::objects_folder_id()).unwrap();
+        let speed_a_id = NodeId::new_string(ns_a, "Speed");
+        let speed_b_id = NodeId::new_string(ns_b, "Speed");
+        let _ = address_space.add_variables(vec![
+            Variable::new(&speed_a_id, (ns_a, "Speed"), "Speed", "", 1f64),
+            Variable::new(&speed_b_id, (ns_b, "Speed"), "Speed", "", 2f64),
+        ], &machine_id);
+        (machine_id, speed_a_id, speed_b_id, ns_b)
+    };
+
+    let browse_path = |names: &[QualifiedName]| {
+        BrowsePath {
+            starting_node: AddressSpace::objects_folder_id(),
+            relative_path: RelativePath {
+                elements: Some(names.iter().map(|name| RelativePathElement {
+                    reference_type_id: ReferenceTypeId::Organizes.into(),
+                    is_inverse: false,
+                    include_subtypes: true,
+                    target_name: name.clone(),
+                }).collect()),
+            },
+        }
+    };
+    let machine_name = {
+        let address_space = st.server.address_space.read().unwrap();
+        address_space.find_node(&machine_id).unwrap().as_node().browse_name()
+    };
+    let request = TranslateBrowsePathsToNodeIdsRequest {
+        request_header: make_request_header(),
+        browse_paths: Some(vec![
+            browse_path(&[machine_name.clone()]),
+            browse_path(&[machine_name.clone(), QualifiedName::new(ns_b, "Speed")]),
+            // The names are in namespaces other than 0
+            browse_path(&[QualifiedName::new(0, "Machine")]),
+            browse_path(&[machine_name.clone(), QualifiedName::new(0, "Speed")]),
+        ]),
+    };
+
+    let vs = ViewService::new();
+    let address_space = st.server.address_space.read().unwrap();
+    let result = vs.translate_browse_paths_to_node_ids(&address_space, request).unwrap();
+    let result: TranslateBrowsePathsToNodeIdsResponse = supported_message_as!(result, TranslateBrowsePathsToNodeIdsResponse);
+    let results = result.results.unwrap();
+
+    let target_ids = |result: &BrowsePathResult| result.targets.as_ref().unwrap().iter().map(|t| t.target_id.node_id.clone()).collect::<Vec<NodeId>>();
+    assert_eq!(results[0].status_code, Good);
+    assert_eq!(target_ids(&results[0]), vec![machine_id.clone()]);
+    assert_eq!(results[1].status_code, Good);
+    assert_eq!(target_ids(&results[1]), vec![speed_b_id.clone()]);
+    assert_ne!(speed_a_id, speed_b_id);
+    assert_eq!(results[2].status_code, BadNoMatch);
+    assert_eq!(results[3].status_code, BadNoMatch);
+}
@@ -0,0 +1,251 @@
+use prelude::*;
+
+use opcua_types::extension_object::ExtensionObjectEncoding;
+use opcua_types::service_types;
+
+use address_space::type_dictionary::type_dictionary_id;
+use tests::*;
+
+fn field(name: &str, data_type: NodeId, value_rank: Int32) -> DataTypeField {
+    DataTypeField {
+        name: name.to_string(),
+        data_type,
+        value_rank,
+        value: None,
+        description: None,
+    }
+}
+
+fn enum_value(name: &str, value: Option<Int32>) -> DataTypeField {
+    DataTypeField {
+        name: name.to_string(),
+        data_type: DataTypeId::Int32.into(),
+        value_rank: -1,
+        value,
+        description: None,
+    }
+}
+
+fn definition(fields: Vec<DataTypeField>) -> DataTypeDefinition {
+    DataTypeDefinition {
+        name: QualifiedName::null(),
+        is_union: false,
+        fields,
+    }
+}
+
+/// Adds a Mode enumeration and a Reading structure with a field of every kind to a new namespace
+fn add_custom_types(address_space: &mut AddressSpace) -> (UInt16, NodeId, NodeId, NodeId) {
+    let ns = address_space.register_namespace("urn:rust-opcua:test:types");
+    let mode_id = NodeId::new(ns, 3001);
+    let reading_id = NodeId::new(ns, 3002);
+    let reading_encoding_id = NodeId::new(ns, 5001);
+    address_space.add_enumeration_data_type(&mode_id, (ns, "Mode"), definition(vec![
+        enum_value("Off", None), enum_value("On", None),
+    ])).unwrap();
+    address_space.add_structure_data_type(&reading_id, (ns, "Reading"), &reading_encoding_id, definition(vec![
+        field("Value", DataTypeId::Duration.into(), -1),
+        field("Name", DataTypeId::String.into(), -1),
+        field("Mode", mode_id.clone(), -1),
+        field("Samples", DataTypeId::Int32.into(), 1),
+        field("Argument", DataTypeId::Argument.into(), -1),
+    ])).unwrap();
+    (ns, mode_id, reading_id, reading_encoding_id)
+}
+
+fn extension_object(node_id: &NodeId) -> Variant {
+    Variant::from(ExtensionObject {
+        node_id: node_id.clone(),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![0u8; 8])),
+    })
+}
+
+#[test]
+fn add_structure_data_type() {
+    let mut address_space = AddressSpace::new();
+    let (ns, _, reading_id, reading_encoding_id) = add_custom_types(&mut address_space);
+
+    // The type is a structure with a binary encoding that describes it in the dictionary
+    assert!(address_space.is_subtype_of(&reading_id, &DataTypeId::Structure.into()));
+    assert_eq!(address_space.find_node(&reading_id).unwrap().as_node().browse_name(), QualifiedName::new(ns, "Reading"));
+    let encodings = address_space.find_references_from(&reading_id, Some((ReferenceTypeId::HasEncoding, false))).unwrap();
+    assert_eq!(encodings[0].node_id, reading_encoding_id);
+    let encoding = address_space.find_node(&reading_encoding_id).unwrap().as_node();
+    assert_eq!(encoding.browse_name(), QualifiedName::new(0, "Default Binary"));
+
+    let descriptions = address_space.find_references_from(&reading_encoding_id, Some((ReferenceTypeId::HasDescription, false))).unwrap();
+    let description_id = descriptions[0].node_id.clone();
+    if let Some(&NodeType::Variable(ref description)) = address_space.find_node(&description_id) {
+        assert_eq!(description.value().value, Some(Variant::from("Reading")));
+    } else {
+        panic!("Description is not a variable");
+    }
+
+    // The description is a component of the namespace's dictionary in the OPC Binary type system
+    let dictionary_id = type_dictionary_id(ns);
+    assert!(address_space.has_reference(&dictionary_id, ReferenceTypeId::HasComponent, &description_id));
+    assert!(address_space.has_reference(&ObjectId::OPCBinarySchema_TypeSystem.into(), ReferenceTypeId::HasComponent, &dictionary_id));
+    let namespace_uri = address_space.find_references_from(&dictionary_id, Some((ReferenceTypeId::HasProperty, false))).unwrap();
+    if let Some(&NodeType::Variable(ref namespace_uri)) = address_space.find_node(&namespace_uri[0].node_id) {
+        assert_eq!(namespace_uri.value().value, Some(Variant::from("urn:rust-opcua:test:types")));
+    } else {
+        panic!("NamespaceUri is not a variable");
+    }
+}
+
+#[test]
+fn type_dictionary_describes_types() {
+    let mut address_space = AddressSpace::new();
+    let (ns, _, _, _) = add_custom_types(&mut address_space);
+
+    let dictionary = parse_xml(&address_space.type_dictionary(ns).unwrap()).unwrap();
+    assert_eq!(dictionary.name, "TypeDictionary");
+    assert_eq!(dictionary.attributes.get("TargetNamespace").unwrap(), "urn:rust-opcua:test:types");
+
+    let mode = dictionary.children.iter().find(|e| e.name == "EnumeratedType").unwrap();
+    assert_eq!(mode.attributes.get("Name").unwrap(), "Mode");
+    let values = mode.children.iter().map(|v| (v.attributes["Name"].clone(), v.attributes["Value"].clone())).collect::<Vec<_>>();
+    assert_eq!(values, vec![("Off".to_string(), "0".to_string()), ("On".to_string(), "1".to_string())]);
+
+    // Derived types are written as their built-in type and arrays are preceded by their length
+    let reading = dictionary.children.iter().find(|e| e.name == "StructuredType").unwrap();
+    assert_eq!(reading.attributes.get("Name").unwrap(), "Reading");
+    let fields = reading.children.iter().map(|f| (f.attributes["Name"].clone(), f.attributes["TypeName"].clone(), f.attributes.get("LengthField").cloned())).collect::<Vec<_>>();
+    assert_eq!(fields, vec![
+        ("Value".to_string(), "opc:Double".to_string(), None),
+        ("Name".to_string(), "opc:String".to_string(), None),
+        ("Mode".to_string(), "tns:Mode".to_string(), None),
+        ("NoOfSamples".to_string(), "opc:Int32".to_string(), None),
+        ("Samples".to_string(), "opc:Int32".to_string(), Some("NoOfSamples".to_string())),
+        ("Argument".to_string(), "ua:Argument".to_string(), None),
+    ]);
+}
+
+#[test]
+fn add_enumeration_data_type() {
+    let mut address_space = AddressSpace::new();
+    let (ns, mode_id, _, _) = add_custom_types(&mut address_space);
+    assert!(address_space.is_subtype_of(&mode_id, &DataTypeId::Enumeration.into()));
+
+    let property_value = |address_space: &AddressSpace, node_id: &NodeId| {
+        let properties = address_space.find_references_from(node_id, Some((ReferenceTypeId::HasProperty, false))).unwrap();
+        if let Some(&NodeType::Variable(ref property)) = address_space.find_node(&properties[0].node_id) {
+            (property.browse_name(), property.value().value.unwrap())
+        } else {
+            panic!("Property is not a variable");
+        }
+    };
+
+    // Sequential values are named by EnumStrings
+    let (name, value) = property_value(&address_space, &mode_id);
+    assert_eq!(name, QualifiedName::new(0, "EnumStrings"));
+    assert_eq!(value, Variant::from(vec![Variant::from(LocalizedText::new("", "Off")), Variant::from(LocalizedText::new("", "On"))]));
+
+    // Other values are named by EnumValues
+    let level_id = NodeId::new(ns, 3003);
+    address_space.add_enumeration_data_type(&level_id, (ns, "Level"), definition(vec![
+        enum_value("Low", Some(10)), enum_value("High", Some(20)),
+    ])).unwrap();
+    let (name, value) = property_value(&address_space, &level_id);
+    assert_eq!(name, QualifiedName::new(0, "EnumValues"));
+    if let Variant::Array(values) = value {
+        let values = values.iter().map(|v| {
+            if let Variant::ExtensionObject(ref v) = *v { v.decode_inner::<EnumValueType>().unwrap() } else { panic!() }
+        }).map(|v| (v.value, v.display_name.text.as_ref().to_string())).collect::<Vec<_>>();
+        assert_eq!(values, vec![(10, "Low".to_string()), (20, "High".to_string())]);
+    } else {
+        panic!("EnumValues is not an array");
+    }
+}
+
+#[test]
+fn add_data_type_errors() {
+    let mut address_space = AddressSpace::new();
+    let (ns, mode_id, _, _) = add_custom_types(&mut address_space);
+
+    // Unregistered namespace, existing node, unknown field type and no fields
+    let fields = || definition(vec![field("Value", DataTypeId::Double.into(), -1)]);
+    assert_eq!(address_space.add_structure_data_type(&NodeId::new(ns + 1, 1), "X", &NodeId::new(ns + 1, 2), fields()), Err(BadNodeIdInvalid));
+    assert_eq!(address_space.add_structure_data_type(&mode_id, "X", &NodeId::new(ns, 2), fields()), Err(BadNodeIdExists));
+    assert_eq!(address_space.add_structure_data_type(&NodeId::new(ns, 1), "X", &NodeId::new(ns, 2), definition(vec![field("Value", NodeId::new(ns, 999), -1)])), Err(BadDataTypeIdUnknown));
+    assert_eq!(address_space.add_enumeration_data_type(&NodeId::new(ns, 1), "X", definition(vec![])), Err(BadInvalidArgument));
+}
+
+#[test]
+fn custom_data_type_values() {
+    let mut address_space = AddressSpace::new();
+    let (ns, mode_id, reading_id, reading_encoding_id) = add_custom_types(&mut address_space);
+
+    // Variables of the custom types hold extension objects of the encoding, or Int32 values
+    let reading_var_id = NodeId::new_string(ns, "Reading1");
+    let mode_var_id = NodeId::new_string(ns, "Mode1");
+    let _ = address_space.add_variables(vec![
+        Variable::new_with_data_type(&reading_var_id, (ns, "Reading1"), "Reading1", "", reading_id.clone(), extension_object(&reading_encoding_id)),
+        Variable::new_with_data_type(&mode_var_id, (ns, "Mode1"), "Mode1", "", mode_id.clone(), 1i32),
+    ], &AddressSpace::objects_folder_id());
+
+    let reading = address_space.value_constraints(&reading_var_id).unwrap();
+    assert!(reading.check(&extension_object(&reading_encoding_id)).is_ok());
+    assert_eq!(reading.check(&extension_object(&ObjectId::Argument_Encoding_DefaultBinary.into())), Err(BadTypeMismatch));
+    assert_eq!(reading.check(&Variant::from(1i32)), Err(BadTypeMismatch));
+
+    let mode = address_space.value_constraints(&mode_var_id).unwrap();
+    assert_eq!(mode.check(&Variant::from(0u8)), Ok(Variant::from(0i32)));
+    assert_eq!(mode.check(&Variant::from("On")), Err(BadTypeMismatch));
+}
+
+#[test]
+fn decode_custom_data_type_values() {
+    let mut address_space = AddressSpace::new();
+    let (ns, _, _, reading_encoding_id) = add_custom_types(&mut address_space);
+
+    // Build a registry the way a client does, from the dictionary and the encodings that refer
+    // to its descriptions. The dictionary of namespace 0 describes the Argument field.
+    let mut registry = DataTypeRegistry::new();
+    let dictionary = TypeDictionary::parse(&address_space.type_dictionary(ns).unwrap()).unwrap();
+    let descriptions = address_space.find_references_from(&type_dictionary_id(ns), Some((ReferenceTypeId::HasComponent, false))).unwrap();
+    for description in descriptions {
+        let type_name = if let Some(&NodeType::Variable(ref variable)) = address_space.find_node(&description.node_id) {
+            if let Some(Variant::String(type_name)) = variable.value().value {
+                TypeName::new(&dictionary.target_namespace, type_name.as_ref())
+            } else {
+                panic!("Description has no type name");
+            }
+        } else {
+            panic!("Description is not a variable");
+        };
+        for encoding in address_space.find_references_to(&description.node_id, Some((ReferenceTypeId::HasDescription, false))).unwrap() {
+            registry.add_encoding(encoding.node_id, type_name.clone());
+        }
+    }
+    registry.add_dictionary(dictionary);
+    registry.add_dictionary(TypeDictionary::parse(include_str!("../../../schemas/1.0.3/Opc.Ua.Types.bsd.xml")).unwrap());
+    registry.add_encoding(ObjectId::Argument_Encoding_DefaultBinary, TypeName::new(UA_TYPES_NAMESPACE, "Argument"));
+
+    let reading_type = TypeName::new("urn:rust-opcua:test:types", "Reading");
+    assert_eq!(registry.type_name(&reading_encoding_id), Some(&reading_type));
+
+    let argument = ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, service_types::Argument {
+        name: UAString::from("Level"),
+        data_type: DataTypeId::Double.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::new("", "The level"),
+    });
+    let mut reading = DynamicStructure::new(reading_type);
+    reading.set("Value", 1.5f64);
+    reading.set("Name", "Tank");
+    reading.set("Mode", 1i32);
+    reading.set("Samples", vec![Variant::from(1i32), Variant::from(2i32)]);
+    reading.set("Argument", argument.clone());
+
+    let value = registry.encode(&reading).unwrap();
+    assert_eq!(value.node_id, reading_encoding_id);
+    let decoded = registry.decode(&value).unwrap();
+    assert_eq!(decoded, reading);
+    if let Some(&Variant::ExtensionObject(ref decoded_argument)) = decoded.get("Argument") {
+        assert_eq!(decoded_argument.decode_inner::<service_types::Argument>().unwrap().name, UAString::from("Level"));
+    } else {
+        panic!("Argument is not an extension object");
+    }
+}
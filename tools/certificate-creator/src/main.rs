@@ -3,170 +3,485 @@ extern crate clap;
 
 extern crate opcua_core;
 
-use std::path::PathBuf;
+use std::fs::{File, create_dir_all};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
 use opcua_core::crypto::*;
 
 fn main() {
     // opcua_core::init_logging();
-    if let Ok((args, overwrite, path)) = parse_x509_args() {
-        println!("Creating certificate...");
-
-        println!("  Key size = {}", args.key_size);
-        println!("  CN (common name) = \"{}\"", args.common_name);
-        println!("  O (organization) = \"{}\"", args.organization);
-        println!("  OU (organizational unit) = \"{}\"", args.organizational_unit);
-        println!("  C (country) = \"{}\"", args.country);
-        println!("  ST (state) = \"{}\"", args.state);
-        println!("  Duration = {} days", args.certificate_duration_days);
-        for i in args.alt_host_names.iter().enumerate() {
-            if i.0 == 0 {
-                println!("  Application URI = \"{}\"", i.1);
-            } else {
-                println!("  DNS = \"{}\"", i.1);
-            }
+    let matches = parse_args();
+    let result = match matches.subcommand() {
+        ("ca", Some(matches)) => create_ca(matches),
+        ("csr", Some(matches)) => create_csr(matches),
+        ("sign", Some(matches)) => sign_csr(matches),
+        ("crl", Some(matches)) => create_crl(matches),
+        ("show", Some(matches)) => show_cert(matches),
+        ("verify", Some(matches)) => verify_cert(matches),
+        _ => create_self_signed_cert(&matches),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+/// Creates a self-signed application instance cert and private key in the pki dir
+fn create_self_signed_cert(matches: &ArgMatches) -> Result<(), String> {
+    let args = x509_data(matches, true)?;
+    let overwrite = matches.is_present("overwrite");
+    let path = PathBuf::from(matches.value_of("pkipath").unwrap());
+
+    println!("Creating certificate...");
+    print_x509_data(&args);
+
+    let cert_store = CertificateStore::new(&path);
+    if let Err(_) = cert_store.create_and_store_application_instance_cert(&args, overwrite) {
+        Err("Certificate creation failed, check above for errors".to_string())
+    } else {
+        println!("Certificate and private key have been written to {} and {}",
+                 cert_store.own_cert_path().to_string_lossy(), cert_store.own_private_key_path().to_string_lossy());
+        Ok(())
+    }
+}
+
+/// Creates a CA cert and private key that can sign application instance certs and revocation lists
+fn create_ca(matches: &ArgMatches) -> Result<(), String> {
+    let args = x509_data(matches, false)?;
+    let overwrite = matches.is_present("overwrite");
+    let cert_path = Path::new(matches.value_of("cert").unwrap());
+    let key_path = Path::new(matches.value_of("key").unwrap());
+
+    println!("Creating CA certificate...");
+    print_x509_data(&args);
+
+    let (cert, pkey) = CertificateStore::create_ca_cert_and_pkey(&args)?;
+    write_file(cert_path, &cert.to_der().unwrap(), overwrite)?;
+    write_file(key_path, &pkey.private_key_to_pem().unwrap(), overwrite)?;
+    println!("CA certificate and private key have been written to {} and {}", cert_path.display(), key_path.display());
+    println!("Put the CA certificate in the trusted/ or issuers/ folder of the applications that should accept the certificates it signs.");
+    Ok(())
+}
+
+/// Creates a certificate signing request for an existing private key
+fn create_csr(matches: &ArgMatches) -> Result<(), String> {
+    let mut args = x509_data(matches, true)?;
+    let overwrite = matches.is_present("overwrite");
+    let key_path = Path::new(matches.value_of("key").unwrap());
+    let out_path = Path::new(matches.value_of("out").unwrap());
+
+    let pkey = CertificateStore::read_pkey(key_path)?;
+    args.key_type = if pkey.is_ec() { KeyType::Ecc } else { KeyType::Rsa };
+    args.key_size = pkey.bit_length() as u32;
+
+    println!("Creating certificate signing request...");
+    print_x509_data(&args);
+
+    let request = CertificateStore::create_signing_request(&args, &pkey)?;
+    write_file(out_path, &request, overwrite)?;
+    println!("Certificate signing request has been written to {}", out_path.display());
+    Ok(())
+}
+
+/// Signs a certificate signing request with a CA to produce an application instance cert
+fn sign_csr(matches: &ArgMatches) -> Result<(), String> {
+    let overwrite = matches.is_present("overwrite");
+    let csr_path = Path::new(matches.value_of("csr").unwrap());
+    let out_path = Path::new(matches.value_of("out").unwrap());
+    let certificate_duration_days = duration(matches)?;
+    let alt_host_names = alt_host_names(matches)?;
+    let (ca_cert, ca_pkey) = read_ca(matches)?;
+
+    println!("Signing certificate signing request...");
+    println!("  Duration = {} days", certificate_duration_days);
+    print_alt_host_names(&alt_host_names);
+
+    let request = read_file(csr_path)?;
+    let cert = CertificateStore::sign_signing_request(&request, &ca_cert, &ca_pkey, &alt_host_names, certificate_duration_days)?;
+    write_file(out_path, &cert.to_der().unwrap(), overwrite)?;
+    println!("Certificate has been written to {}", out_path.display());
+    Ok(())
+}
+
+/// Creates a revocation list signed by a CA that revokes the supplied certs
+fn create_crl(matches: &ArgMatches) -> Result<(), String> {
+    let overwrite = matches.is_present("overwrite");
+    let out_path = Path::new(matches.value_of("out").unwrap());
+    let crl_number = value_t!(matches, "number", u32).map_err(|err| err.to_string())?;
+    let next_update_days = value_t!(matches, "next-update", u32).map_err(|err| err.to_string())?;
+    let (ca_cert, ca_pkey) = read_ca(matches)?;
+
+    let mut revoked_certs = Vec::new();
+    if let Some(paths) = matches.values_of("revoke") {
+        for path in paths {
+            let cert = CertificateStore::read_cert(Path::new(path))?;
+            println!("Revoking {}", describe_cert(&cert));
+            revoked_certs.push(cert);
         }
+    }
 
-        let cert_store = CertificateStore::new(&path);
-        if let Err(_) = cert_store.create_and_store_application_instance_cert(&args, overwrite) {
-            eprintln!("Certificate creation failed, check above for errors");
+    let crl = CertificateStore::create_crl(&ca_cert, &ca_pkey, &revoked_certs, crl_number, next_update_days)?;
+    write_file(out_path, &crl.to_der().unwrap(), overwrite)?;
+    println!("Revocation list number {} has been written to {}", crl_number, out_path.display());
+    println!("Put the revocation list in the crl/ folder next to where the CA certificate is trusted.");
+    Ok(())
+}
+
+/// Prints the contents of a cert
+fn show_cert(matches: &ArgMatches) -> Result<(), String> {
+    let cert = CertificateStore::read_cert(Path::new(matches.value_of("cert").unwrap()))?;
+    println!("Thumbprint: {}", cert.thumbprint().as_hex_string());
+    println!("{}", cert.to_text().map_err(|_| "Cannot print the certificate".to_string())?);
+    Ok(())
+}
+
+/// Validates a cert against a certificate store in the same way as the server and client do
+fn verify_cert(matches: &ArgMatches) -> Result<(), String> {
+    let cert = CertificateStore::read_cert(Path::new(matches.value_of("cert").unwrap()))?;
+    let cert_store = CertificateStore::new(Path::new(matches.value_of("pkipath").unwrap()));
+    cert_store.ensure_pki_path()?;
+    let hostname = matches.value_of("hostname");
+    let application_uri = matches.value_of("uri");
+
+    println!("Verifying {}", describe_cert(&cert));
+    let status_code = cert_store.validate_application_instance_cert(&cert, hostname, application_uri);
+    if status_code.is_good() {
+        println!("Certificate is valid and trusted");
+        Ok(())
+    } else {
+        Err(format!("Certificate is not accepted - {:?}", status_code))
+    }
+}
+
+fn describe_cert(cert: &X509) -> String {
+    format!("\"{}\" [{}]", cert.common_name().unwrap_or_default(), cert.thumbprint().as_hex_string())
+}
+
+fn read_ca(matches: &ArgMatches) -> Result<(X509, PrivateKey), String> {
+    let ca_cert = CertificateStore::read_cert(Path::new(matches.value_of("ca-cert").unwrap()))?;
+    let ca_pkey = CertificateStore::read_pkey(Path::new(matches.value_of("ca-key").unwrap()))?;
+    if !ca_cert.matches_private_key(&ca_pkey) {
+        return Err("The CA private key does not belong to the CA certificate".to_string());
+    }
+    Ok((ca_cert, ca_pkey))
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, String> {
+    let mut data = Vec::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_end(&mut data))
+        .map_err(|_| format!("Could not read file {}", path.display()))?;
+    Ok(data)
+}
+
+fn write_file(path: &Path, data: &[u8], overwrite: bool) -> Result<(), String> {
+    if !overwrite && path.exists() {
+        return Err(format!("File {} already exists and will not be overwritten. Use --overwrite to disable this safeguard.", path.display()));
+    }
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            create_dir_all(parent).map_err(|_| format!("Cannot make directories for {}", parent.display()))?;
+        }
+    }
+    File::create(path)
+        .and_then(|mut file| file.write_all(data))
+        .map_err(|_| format!("Could not write file {}", path.display()))
+}
+
+fn print_x509_data(args: &X509Data) {
+    println!("  Key type = {:?}", args.key_type);
+    println!("  Key size = {}", args.key_size);
+    println!("  CN (common name) = \"{}\"", args.common_name);
+    println!("  O (organization) = \"{}\"", args.organization);
+    println!("  OU (organizational unit) = \"{}\"", args.organizational_unit);
+    println!("  C (country) = \"{}\"", args.country);
+    println!("  ST (state) = \"{}\"", args.state);
+    println!("  Duration = {} days", args.certificate_duration_days);
+    print_alt_host_names(&args.alt_host_names);
+}
+
+fn print_alt_host_names(alt_host_names: &[String]) {
+    for i in alt_host_names.iter().enumerate() {
+        if i.0 == 0 {
+            println!("  Application URI = \"{}\"", i.1);
         } else {
-            println!("Certificate and private key have been written to {} and {}",
-                     cert_store.own_cert_path().to_string_lossy(), cert_store.own_private_key_path().to_string_lossy());
+            println!("  DNS / IP = \"{}\"", i.1);
         }
+    }
+}
+
+fn duration(matches: &ArgMatches) -> Result<u32, String> {
+    let certificate_duration_days = value_t!(matches, "duration", u32).map_err(|err| err.to_string())?;
+    if certificate_duration_days == 0 {
+        Err("Duration is zero days!?".to_string())
     } else {
-        eprintln!("Invalid arguments, check above for errors");
+        Ok(certificate_duration_days)
     }
 }
 
-fn parse_x509_args() -> Result<(X509Data, bool, PathBuf), ()> {
-    use clap::*;
-    let matches = App::new("OPC UA Certificate Creator")
-        .author("Adam Lock <locka99@gmail.com>")
-        .about(
-            r#"OPC UA for Rust Certificate Creator.
+fn alt_host_names(matches: &ArgMatches) -> Result<Vec<String>, String> {
+    let application_uri = matches.value_of("uri").unwrap().to_string();
+    let add_localhost = value_t!(matches, "add-localhost-name", bool).map_err(|err| err.to_string())?;
+    let add_computer_name = value_t!(matches, "add-computer-name", bool).map_err(|err| err.to_string())?;
 
-This will creates a self-signed key (private/private.pem) and X509 certificate (own/cert.der) for
-use with OPC UA clients and servers. Use the flags to control what the certificate contains. For
-convenience some values will be prefilled from defaults, but for production purposes all defaults
-should be overridden.
+    // Create alt host names for application uri, localhost and computer name if required
+    let mut alt_host_names = X509Data::alt_host_names(&application_uri, add_localhost, add_computer_name);
 
-Files will be created under the specified under the specified --pkipath value."#)
-        .arg(Arg::with_name("keysize")
-            .long("keysize")
-            .help("Sets the key size(strength)")
-            .default_value("2048")
-            .takes_value(true)
-            .possible_values(&["2048", "4096"])
-            .required(false))
-        .arg(Arg::with_name("pkipath")
-            .long("pkipath")
-            .help("Path to the OPC UA for Rust pki/ directory")
-            .default_value(".")
-            .value_name("path")
-            .takes_value(true)
-            .required(false))
-        .arg(Arg::with_name("duration")
-            .long("duration")
-            .help("The duration in days of this certificate before it expires")
-            .value_name("days")
-            .default_value("365")
-            .takes_value(true)
-            .required(false))
-        .arg(Arg::with_name("overwrite")
-            .long("overwrite")
-            .help("Overwrites existing files"))
-        .arg(Arg::with_name("uri")
+    // Add the host names that were supplied by argument
+    if let Some(hostnames) = matches.values_of("hostnames") {
+        for h in hostnames {
+            alt_host_names.push(h.to_string());
+        }
+    }
+    if alt_host_names.len() == 1 {
+        Err("No alt host names were supplied or could be inferred. Certificate is useless without at least one DNS entry.".to_string())
+    } else {
+        Ok(alt_host_names)
+    }
+}
+
+fn x509_data(matches: &ArgMatches, with_alt_host_names: bool) -> Result<X509Data, String> {
+    let key_type = if matches.value_of("keytype") == Some("ecc") { KeyType::Ecc } else { KeyType::Rsa };
+    let key_size = if key_type == KeyType::Ecc && matches.occurrences_of("keysize") == 0 {
+        // The default key size is for RSA, so use the smaller curve unless told otherwise
+        256
+    } else if matches.is_present("keysize") {
+        value_t!(matches, "keysize", u32).map_err(|err| err.to_string())?
+    } else {
+        0
+    };
+    match (key_type, key_size) {
+        (KeyType::Rsa, 256) | (KeyType::Rsa, 384) => {
+            return Err(format!("Key size {} is for ECC keys, RSA keys must be 2048 or 4096", key_size));
+        }
+        (KeyType::Ecc, 2048) | (KeyType::Ecc, 4096) => {
+            return Err(format!("Key size {} is for RSA keys, ECC keys must be 256 or 384", key_size));
+        }
+        _ => {}
+    }
+    let certificate_duration_days = if matches.is_present("duration") { duration(matches)? } else { 0 };
+    let alt_host_names = if with_alt_host_names { alt_host_names(matches)? } else { Vec::new() };
+    Ok(X509Data {
+        key_type,
+        key_size,
+        common_name: matches.value_of("CN").unwrap().to_string(),
+        organization: matches.value_of("O").unwrap().to_string(),
+        organizational_unit: matches.value_of("OU").unwrap().to_string(),
+        country: matches.value_of("C").unwrap().to_string(),
+        state: matches.value_of("ST").unwrap().to_string(),
+        alt_host_names,
+        certificate_duration_days,
+    })
+}
+
+fn keysize_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("keysize")
+        .long("keysize")
+        .help("Sets the key size(strength). RSA keys are 2048 or 4096, ECC keys are 256 (nistP256) or 384 (nistP384)")
+        .default_value("2048")
+        .takes_value(true)
+        .possible_values(&["256", "384", "2048", "4096"])
+        .required(false)
+}
+
+fn keytype_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("keytype")
+        .long("keytype")
+        .help("Sets the key type, RSA for the RSA security policies or ECC for the ECC policies")
+        .default_value("rsa")
+        .takes_value(true)
+        .possible_values(&["rsa", "ecc"])
+        .required(false)
+}
+
+fn duration_arg<'a, 'b>(help: &'a str, default_value: &'a str) -> Arg<'a, 'b> {
+    Arg::with_name("duration")
+        .long("duration")
+        .help(help)
+        .value_name("days")
+        .default_value(default_value)
+        .takes_value(true)
+        .required(false)
+}
+
+fn overwrite_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("overwrite")
+        .long("overwrite")
+        .help("Overwrites existing files")
+}
+
+fn file_arg<'a, 'b>(name: &'a str, help: &'a str) -> Arg<'a, 'b> {
+    Arg::with_name(name)
+        .long(name)
+        .help(help)
+        .value_name("file")
+        .takes_value(true)
+}
+
+fn ca_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    vec![
+        file_arg("ca-cert", "The CA certificate (DER)").required(true),
+        file_arg("ca-key", "The CA private key (PEM)").required(true),
+    ]
+}
+
+fn alt_host_name_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    vec![
+        Arg::with_name("uri")
             .long("application-uri")
             .help("The application's uri used by OPC UA for authentication purposes.")
             .default_value("urn:OPCUAForRust")
-            .takes_value(true))
-        .arg(Arg::with_name("hostnames")
+            .takes_value(true),
+        Arg::with_name("hostnames")
             .long("hostnames")
-            .help("Explicitly add the specified DNS names to the cert.")
+            .help("Explicitly add the specified DNS names or IP addresses to the cert.")
             .takes_value(true)
             .value_names(&["dns1", "dns2"])
             .multiple(true)
-            .required(false))
-        .arg(Arg::with_name("add-computer-name")
+            .required(false),
+        Arg::with_name("add-computer-name")
             .long("add-computer-name")
             .help("Add this computer's name (inferred from COMPUTERNAME / NAME environment variables) to the DNS names.")
             .value_name("flag")
             .takes_value(true)
-            .default_value("true"))
-        .arg(Arg::with_name("add-localhost-name")
+            .default_value("true"),
+        Arg::with_name("add-localhost-name")
             .long("add-localhost-name")
             .help("Add localhost, 127.0.0.1, ::1 to the DNS names.")
             .value_name("flag")
             .takes_value(true)
-            .default_value("false"))
-        .arg(Arg::with_name("CN")
+            .default_value("false"),
+    ]
+}
+
+fn subject_args<'a, 'b>(default_common_name: &'a str) -> Vec<Arg<'a, 'b>> {
+    vec![
+        Arg::with_name("CN")
             .long("CN")
             .help("Specifies the Common Name for the cert")
-            .default_value("OPC UA Demo Key")
-            .takes_value(true))
-        .arg(Arg::with_name("O")
+            .default_value(default_common_name)
+            .takes_value(true),
+        Arg::with_name("O")
             .long("O")
             .help("Specifies the Organization for the cert")
             .default_value("OPC UA for Rust")
-            .takes_value(true))
-        .arg(Arg::with_name("OU")
+            .takes_value(true),
+        Arg::with_name("OU")
             .long("OU")
             .help("Specifies the Organization Unit for the cert")
             .default_value("Certificate Creator")
-            .takes_value(true))
-        .arg(Arg::with_name("C")
+            .takes_value(true),
+        Arg::with_name("C")
             .long("C")
             .help("Specifies the Country for the cert")
             .default_value("IE")
-            .takes_value(true))
-        .arg(Arg::with_name("ST")
+            .takes_value(true),
+        Arg::with_name("ST")
             .long("ST")
             .help("Specifies the State for the cert")
             .default_value("Dublin")
-            .takes_value(true))
-        .get_matches();
-
-    let pki_path = matches.value_of("pkipath").unwrap().to_string();
-    let key_size = value_t_or_exit!(matches, "keysize", u32);
-    let overwrite = matches.is_present("overwrite");
-    let certificate_duration_days = value_t_or_exit!(matches, "duration", u32);
+            .takes_value(true),
+    ]
+}
 
-    let common_name = matches.value_of("CN").unwrap().to_string();
-    let organization = matches.value_of("O").unwrap().to_string();
-    let organizational_unit = matches.value_of("OU").unwrap().to_string();
-    let country = matches.value_of("C").unwrap().to_string();
-    let state = matches.value_of("ST").unwrap().to_string();
-    let application_uri = matches.value_of("uri").unwrap().to_string();
-    let add_localhost = value_t_or_exit!(matches, "add-localhost-name", bool);
-    let add_computer_name = value_t_or_exit!(matches, "add-computer-name", bool);
+fn parse_args() -> ArgMatches<'static> {
+    App::new("OPC UA Certificate Creator")
+        .author("Adam Lock <locka99@gmail.com>")
+        .about(
+            r#"OPC UA for Rust Certificate Creator.
 
-    if certificate_duration_days == 0 {
-        eprintln!("Duration is zero days!?");
-        return Err(());
-    }
+This will creates a self-signed key (private/private.pem) and X509 certificate (own/cert.der) for
+use with OPC UA clients and servers. Use the flags to control what the certificate contains. For
+convenience some values will be prefilled from defaults, but for production purposes all defaults
+should be overridden.
 
-    // Create alt host names for application uri, localhost and computer name if required
-    let mut alt_host_names = X509Data::alt_host_names(&application_uri, add_localhost, add_computer_name);
+Files will be created under the specified under the specified --pkipath value.
 
-    // Add the host names that were supplied by argument
-    if let Some(hostnames) = matches.values_of("hostnames") {
-        for h in hostnames {
-            alt_host_names.push(h.to_string());
-        }
-    }
-    if alt_host_names.len() == 1 {
-        eprintln!("No alt host names were supplied or could be inferred. Certificate is useless without at least one DNS entry.");
-        return Err(());
-    }
-
-    Ok((X509Data {
-        key_size,
-        common_name,
-        organization,
-        organizational_unit,
-        country,
-        state,
-        alt_host_names,
-        certificate_duration_days,
-    }, overwrite, PathBuf::from(&pki_path)))
-}
\ No newline at end of file
+The subcommands manage certificates signed by a local CA instead. Create the CA with "ca", create
+a signing request for an application's private key with "csr", sign it with "sign" and revoke
+certificates with "crl". Use "show" and "verify" to inspect a certificate and check it against a
+pki/ directory."#)
+        .arg(keytype_arg())
+        .arg(keysize_arg())
+        .arg(Arg::with_name("pkipath")
+            .long("pkipath")
+            .help("Path to the OPC UA for Rust pki/ directory")
+            .default_value(".")
+            .value_name("path")
+            .takes_value(true)
+            .required(false))
+        .arg(duration_arg("The duration in days of this certificate before it expires", "365"))
+        .arg(overwrite_arg())
+        .args(&alt_host_name_args())
+        .args(&subject_args("OPC UA Demo Key"))
+        .subcommand(SubCommand::with_name("ca")
+            .about("Creates a CA certificate and private key")
+            .arg(keytype_arg())
+            .arg(keysize_arg())
+            .arg(duration_arg("The duration in days of the CA certificate before it expires", "3650"))
+            .arg(overwrite_arg())
+            .arg(file_arg("cert", "Where to write the CA certificate (DER)").default_value("ca/cacert.der"))
+            .arg(file_arg("key", "Where to write the CA private key (PEM)").default_value("ca/private/cakey.pem"))
+            .args(&subject_args("OPC UA Demo CA")))
+        .subcommand(SubCommand::with_name("csr")
+            .about("Creates a certificate signing request for an existing private key")
+            .arg(overwrite_arg())
+            .arg(file_arg("key", "The application's private key (PEM), e.g. pki/private/private.pem").required(true))
+            .arg(file_arg("out", "Where to write the signing request (DER)").default_value("cert.csr"))
+            .args(&alt_host_name_args())
+            .args(&subject_args("OPC UA Demo Key")))
+        .subcommand(SubCommand::with_name("sign")
+            .about("Signs a certificate signing request with a CA, producing an application instance certificate")
+            .arg(duration_arg("The duration in days of the certificate before it expires", "365"))
+            .arg(overwrite_arg())
+            .arg(file_arg("csr", "The signing request (DER)").required(true))
+            .arg(file_arg("out", "Where to write the certificate (DER), e.g. pki/own/cert.der").default_value("cert.der"))
+            .args(&ca_args())
+            .args(&alt_host_name_args()))
+        .subcommand(SubCommand::with_name("crl")
+            .about("Creates a revocation list signed by a CA")
+            .arg(overwrite_arg())
+            .arg(Arg::with_name("revoke")
+                .long("revoke")
+                .help("The certificates (DER) to revoke")
+                .value_name("file")
+                .takes_value(true)
+                .multiple(true))
+            .arg(Arg::with_name("number")
+                .long("number")
+                .help("The revocation list number, which must be larger than the CA's previous list")
+                .default_value("1")
+                .takes_value(true))
+            .arg(Arg::with_name("next-update")
+                .long("next-update")
+                .help("The number of days until the next revocation list is due")
+                .value_name("days")
+                .default_value("30")
+                .takes_value(true))
+            .arg(file_arg("out", "Where to write the revocation list (DER)").default_value("ca.crl"))
+            .args(&ca_args()))
+        .subcommand(SubCommand::with_name("show")
+            .about("Prints the contents of a certificate")
+            .arg(file_arg("cert", "The certificate (DER)").required(true)))
+        .subcommand(SubCommand::with_name("verify")
+            .about("Checks a certificate against a pki/ directory the same way that a server or client does")
+            .arg(file_arg("cert", "The certificate (DER)").required(true))
+            .arg(Arg::with_name("pkipath")
+                .long("pkipath")
+                .help("Path to the OPC UA for Rust pki/ directory")
+                .default_value(".")
+                .value_name("path")
+                .takes_value(true))
+            .arg(Arg::with_name("hostname")
+                .long("hostname")
+                .help("Checks the certificate is valid for this host name")
+                .takes_value(true))
+            .arg(Arg::with_name("uri")
+                .long("application-uri")
+                .help("Checks the certificate is valid for this application uri")
+                .takes_value(true)))
+        .get_matches()
+}
@@ -19,7 +19,11 @@ use string::UAString;
 var node_ids = {};
 
 function interested_in_node(node) {
-    return (!node.name.endsWith("_DefaultXml") && !node.name.startsWith("OpcUa_XmlSchema_"));
+    // The xml encoding ids of types are needed to read extension objects with xml bodies
+    if (node.name.endsWith("_DefaultXml") && !node.name.endsWith("_Encoding_DefaultXml")) {
+        return false;
+    }
+    return !node.name.startsWith("OpcUa_XmlSchema_");
 }
 
 fs.createReadStream(status_code_csv)
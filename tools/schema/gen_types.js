@@ -7,7 +7,8 @@ var settings = require("./settings");
 var types_xml = `${settings.schema_dir}/Opc.Ua.Types.bsd.xml`;
 
 /// This code parses the OPC UA Binary types definitions and creates a generated .rs type.
-/// Fields are converted to snake case as they are written. Code for serializing the struct is also generated
+/// Fields are converted to snake case as they are written. Code for serializing the struct is also generated,
+/// for the binary encoding and for the JSON and XML encodings which use the original field names.
 
 /// Any handwritten types are stripped from the output
 
@@ -58,6 +59,14 @@ function massageTypeName(name) {
     return name;
 }
 
+function xmlTypeName(name) {
+    // Arrays of chars are strings in the XML schema
+    if (name === "CharArray") {
+        return "String";
+    }
+    return name;
+}
+
 function convertFieldName(name) {
     // Convert field name to snake case
     return _.snakeCase(name);
@@ -81,6 +90,7 @@ fs.readFile(types_xml, function (err, data) {
                 _.each(structured_type_element["opc:Field"], function (field) {
                     // Convert field name to snake case
                     var field_name = convertFieldName(field["$"]["Name"]);
+                    var json_name = field["$"]["Name"];
 
                     // Strip namespace off the type
                     var type = massageTypeName(field["$"]["TypeName"].split(":")[1]);
@@ -89,9 +99,11 @@ fs.readFile(types_xml, function (err, data) {
                     if (_.has(field["$"], "LengthField")) {
                         fields_to_add.push({
                             name: field_name,
+                            json_name: json_name,
                             type: `Option<Vec<${type}>>`,
                             contained_type: type,
                             inner_type: type,
+                            xml_type: xmlTypeName(field["$"]["TypeName"].split(":")[1]),
                             is_array: true
                         });
                         fields_to_hide.push(convertFieldName(field["$"]["LengthField"]));
@@ -99,6 +111,7 @@ fs.readFile(types_xml, function (err, data) {
                     else {
                         fields_to_add.push({
                             name: field_name,
+                            json_name: json_name,
                             type: type,
                             contained_type: type
                         })
@@ -130,6 +143,7 @@ function generate_types(data) {
         generate_structured_type_file(data.structured_types, structured_type);
     });
     generate_types_mod(data.structured_types);
+    generate_extension_objects(data.structured_types);
 }
 
 function generate_types_mod(structured_types) {
@@ -146,6 +160,9 @@ mod impls;
 pub use self::enums::*;
 pub use self::impls::*;
 
+// Generated dispatch of extension object bodies to their types
+pub mod extension_objects;
+
 // All of the remaining are generated by script
 
 `;
@@ -166,8 +183,88 @@ pub use self::impls::*;
     settings.write_to_file(file_path, contents);
 }
 
+function is_extension_object_type(structured_type) {
+    return _.has(structured_type, "base_type") && structured_type.base_type === "ua:ExtensionObject";
+}
+
+function generate_extension_objects(structured_types) {
+    var file_name = "extension_objects.rs";
+    var file_path = `${settings.rs_types_dir}/${file_name}`;
+
+    var extension_object_types = _.filter(structured_types, is_extension_object_type);
+
+    var contents = `// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use encoding::*;
+use json::*;
+use xml::*;
+use byte_string::ByteString;
+use node_ids::{DataTypeId, ObjectId};
+use service_types::*;
+
+/// Encodes the binary body of an extension object as JSON. Returns the data type id of the type
+/// with the body, or None if the object id is not the binary encoding of a known type.
+pub fn encode_json_body(object_id: ObjectId, body: &ByteString, reversible: bool) -> Option<(DataTypeId, EncodingResult<JsonValue>)> {
+    match object_id {
+`;
+    _.each(extension_object_types, function (structured_type) {
+        contents += `        ObjectId::${structured_type.name}_Encoding_DefaultBinary => Some((DataTypeId::${structured_type.name}, binary_body_to_json::<${structured_type.name}>(body, reversible))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+
+/// Decodes the JSON body of an extension object into a binary body. Returns the binary encoding id
+/// of the type with the body, or None if the data type id is not a known type.
+pub fn decode_json_body(data_type_id: DataTypeId, body: &JsonValue) -> Option<(ObjectId, EncodingResult<ByteString>)> {
+    match data_type_id {
+`;
+    _.each(extension_object_types, function (structured_type) {
+        contents += `        DataTypeId::${structured_type.name} => Some((ObjectId::${structured_type.name}_Encoding_DefaultBinary, json_body_to_binary::<${structured_type.name}>(body))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+
+/// Encodes the binary body of an extension object as the XML element of its type. Returns the
+/// XML encoding id of the type with the element, or None if the object id is not the binary
+/// encoding of a known type.
+pub fn encode_xml_body(object_id: ObjectId, body: &ByteString) -> Option<(ObjectId, EncodingResult<XmlNode>)> {
+    match object_id {
+`;
+    _.each(extension_object_types, function (structured_type) {
+        contents += `        ObjectId::${structured_type.name}_Encoding_DefaultBinary => Some((ObjectId::${structured_type.name}_Encoding_DefaultXml, binary_body_to_xml::<${structured_type.name}>(body, "${structured_type.name}"))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+
+/// Decodes the XML element of an extension object body into a binary body. Returns the binary
+/// encoding id of the type with the body, or None if the object id is not an encoding of a
+/// known type.
+pub fn decode_xml_body(object_id: ObjectId, body: &XmlNode) -> Option<(ObjectId, EncodingResult<ByteString>)> {
+    match object_id {
+`;
+    _.each(extension_object_types, function (structured_type) {
+        contents += `        ObjectId::${structured_type.name}_Encoding_DefaultXml | ObjectId::${structured_type.name}_Encoding_DefaultBinary => Some((ObjectId::${structured_type.name}_Encoding_DefaultBinary, xml_body_to_binary::<${structured_type.name}>(body))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+`;
+
+    settings.write_to_file(file_path, contents);
+}
+
 function generate_type_imports(structured_types, fields_to_add, fields_to_hide, has_message_info) {
     var imports = `use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 `;
@@ -230,7 +327,7 @@ function generate_structured_type_file(structured_types, structured_type) {
     var file_name = _.snakeCase(structured_type.name) + ".rs";
     var file_path = `${settings.rs_types_dir}/${file_name}`;
 
-    var has_message_info = _.has(structured_type, "base_type") && structured_type.base_type === "ua:ExtensionObject";
+    var has_message_info = is_extension_object_type(structured_type);
 
     console.log("Creating structured type file - " + file_path);
 
@@ -346,6 +443,92 @@ pub struct ${structured_type.name} {
     contents += `        })
     }
 }
+
+impl JsonEncoder<${structured_type.name}> for ${structured_type.name} {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+`;
+
+    if (structured_type.fields_to_add.length > 0) {
+        contents += `        let mut map = JsonMap::new();\n`;
+
+        _.each(structured_type.fields_to_add, function (field) {
+            if (!_.includes(structured_type.fields_to_hide, field.name)) {
+                if (_.has(field, 'is_array')) {
+                    contents += `        write_json_array_field(&mut map, "${field.json_name}", &self.${field.name}, reversible);\n`;
+                }
+                else {
+                    contents += `        write_json_field(&mut map, "${field.json_name}", &self.${field.name}, reversible);\n`;
+                }
+            }
+        });
+
+        contents += `        JsonValue::Object(map)\n`;
+    }
+    else {
+        contents += `        JsonValue::Object(JsonMap::new())\n`;
+    }
+
+    contents += `    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(${structured_type.name} {
+`;
+
+    _.each(structured_type.fields_to_add, function (field) {
+        if (!_.includes(structured_type.fields_to_hide, field.name)) {
+            if (_.has(field, 'is_array')) {
+                contents += `            ${field.name}: read_json_array_field(value, "${field.json_name}")?,\n`;
+            }
+            else {
+                contents += `            ${field.name}: read_json_field(value, "${field.json_name}")?,\n`;
+            }
+        }
+    });
+
+    contents += `        })
+    }
+}
+
+impl XmlEncoder<${structured_type.name}> for ${structured_type.name} {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+`;
+
+    _.each(structured_type.fields_to_add, function (field) {
+        if (!_.includes(structured_type.fields_to_hide, field.name)) {
+            if (_.has(field, 'is_array')) {
+                contents += `        write_xml_array_field(node, "${field.json_name}", "${field.xml_type}", &self.${field.name});\n`;
+            }
+            else {
+                contents += `        write_xml_field(node, "${field.json_name}", &self.${field.name});\n`;
+            }
+        }
+    });
+
+    contents += `    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(${structured_type.name} {
+`;
+
+    _.each(structured_type.fields_to_add, function (field) {
+        if (!_.includes(structured_type.fields_to_hide, field.name)) {
+            if (_.has(field, 'is_array')) {
+                contents += `            ${field.name}: read_xml_array_field(node, "${field.json_name}")?,\n`;
+            }
+            else {
+                contents += `            ${field.name}: read_xml_field(node, "${field.json_name}")?,\n`;
+            }
+        }
+    });
+
+    contents += `        })
+    }
+}
 `;
 
     settings.write_to_file(file_path, contents);
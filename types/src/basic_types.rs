@@ -2,6 +2,8 @@ use std::io::{Read, Write};
 use std::fmt;
 
 use encoding::*;
+use json::*;
+use xml::*;
 use string::*;
 use status_codes::StatusCode;
 
@@ -250,6 +252,46 @@ impl BinaryEncoder<QualifiedName> for QualifiedName {
     }
 }
 
+/// QualifiedName encoded as an object with a Name and a Uri holding the namespace index
+/// (omitted for namespace 0)
+impl JsonEncoder<QualifiedName> for QualifiedName {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            let mut map = JsonMap::new();
+            write_json_field(&mut map, "Name", &self.name, reversible);
+            if self.namespace_index != 0 {
+                write_json_field(&mut map, "Uri", &self.namespace_index, reversible);
+            }
+            JsonValue::Object(map)
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QualifiedName {
+            namespace_index: read_json_field(value, "Uri")?,
+            name: read_json_field(value, "Name")?,
+        })
+    }
+}
+
+
+impl XmlEncoder<QualifiedName> for QualifiedName {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NamespaceIndex", &self.namespace_index);
+        write_xml_field(node, "Name", &self.name);
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QualifiedName {
+            namespace_index: read_xml_field(node, "NamespaceIndex")?,
+            name: read_xml_field(node, "Name")?,
+        })
+    }
+}
+
 impl QualifiedName {
     pub fn new(namespace_index: UInt16, name: &str) -> QualifiedName {
         QualifiedName {
@@ -270,6 +312,34 @@ impl QualifiedName {
     }
 }
 
+/// A name in namespace 0
+impl<'a> From<&'a str> for QualifiedName {
+    fn from(value: &'a str) -> Self {
+        QualifiedName::new(0, value)
+    }
+}
+
+/// A name in namespace 0
+impl<'a> From<&'a String> for QualifiedName {
+    fn from(value: &'a String) -> Self {
+        QualifiedName::new(0, value)
+    }
+}
+
+/// A name in namespace 0
+impl From<String> for QualifiedName {
+    fn from(value: String) -> Self {
+        QualifiedName::new(0, &value)
+    }
+}
+
+/// A name in the namespace of the index
+impl<'a> From<(UInt16, &'a str)> for QualifiedName {
+    fn from(value: (UInt16, &'a str)) -> Self {
+        QualifiedName::new(value.0, value.1)
+    }
+}
+
 /// Human readable text with an optional locale identifier
 /// Data type ID 21
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -340,6 +410,50 @@ impl BinaryEncoder<LocalizedText> for LocalizedText {
     }
 }
 
+/// LocalizedText encoded as an object with a Locale and Text, or just the text when the
+/// encoding is non-reversible
+impl JsonEncoder<LocalizedText> for LocalizedText {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        if !reversible {
+            self.text.encode_json(reversible)
+        } else if self.locale.is_null() && self.text.is_null() {
+            JsonValue::Null
+        } else {
+            let mut map = JsonMap::new();
+            write_json_field(&mut map, "Locale", &self.locale, reversible);
+            write_json_field(&mut map, "Text", &self.text, reversible);
+            JsonValue::Object(map)
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(LocalizedText {
+            locale: read_json_field(value, "Locale")?,
+            text: read_json_field(value, "Text")?,
+        })
+    }
+}
+
+
+impl XmlEncoder<LocalizedText> for LocalizedText {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        if !self.locale.is_null() {
+            write_xml_field(node, "Locale", &self.locale);
+        }
+        if !self.text.is_null() {
+            write_xml_field(node, "Text", &self.text);
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(LocalizedText {
+            locale: read_xml_field(node, "Locale")?,
+            text: read_xml_field(node, "Text")?,
+        })
+    }
+}
+
 impl LocalizedText {
     pub fn new(locale: &str, text: &str) -> LocalizedText {
         LocalizedText {
@@ -509,6 +623,64 @@ impl BinaryEncoder<DiagnosticInfo> for DiagnosticInfo {
     }
 }
 
+impl JsonEncoder<DiagnosticInfo> for DiagnosticInfo {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_optional_field(&mut map, "SymbolicId", &self.symbolic_id, reversible);
+        write_json_optional_field(&mut map, "NamespaceUri", &self.namespace_uri, reversible);
+        write_json_optional_field(&mut map, "Locale", &self.locale, reversible);
+        write_json_optional_field(&mut map, "LocalizedText", &self.localized_text, reversible);
+        write_json_optional_field(&mut map, "AdditionalInfo", &self.additional_info, reversible);
+        write_json_optional_field(&mut map, "InnerStatusCode", &self.inner_status_code, reversible);
+        if let Some(ref inner_diagnostic_info) = self.inner_diagnostic_info {
+            write_json_field(&mut map, "InnerDiagnosticInfo", inner_diagnostic_info.as_ref(), reversible);
+        }
+        if map.is_empty() { JsonValue::Null } else { JsonValue::Object(map) }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        let inner_diagnostic_info: Option<DiagnosticInfo> = read_json_optional_field(value, "InnerDiagnosticInfo")?;
+        Ok(DiagnosticInfo {
+            symbolic_id: read_json_optional_field(value, "SymbolicId")?,
+            namespace_uri: read_json_optional_field(value, "NamespaceUri")?,
+            locale: read_json_optional_field(value, "Locale")?,
+            localized_text: read_json_optional_field(value, "LocalizedText")?,
+            additional_info: read_json_optional_field(value, "AdditionalInfo")?,
+            inner_status_code: read_json_optional_field(value, "InnerStatusCode")?,
+            inner_diagnostic_info: inner_diagnostic_info.map(|v| Box::new(v)),
+        })
+    }
+}
+
+
+impl XmlEncoder<DiagnosticInfo> for DiagnosticInfo {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_optional_field(node, "SymbolicId", &self.symbolic_id);
+        write_xml_optional_field(node, "NamespaceUri", &self.namespace_uri);
+        write_xml_optional_field(node, "Locale", &self.locale);
+        write_xml_optional_field(node, "LocalizedText", &self.localized_text);
+        write_xml_optional_field(node, "AdditionalInfo", &self.additional_info);
+        write_xml_optional_field(node, "InnerStatusCode", &self.inner_status_code);
+        if let Some(ref inner_diagnostic_info) = self.inner_diagnostic_info {
+            write_xml_field(node, "InnerDiagnosticInfo", inner_diagnostic_info.as_ref());
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let inner_diagnostic_info: Option<DiagnosticInfo> = read_xml_optional_field(node, "InnerDiagnosticInfo")?;
+        Ok(DiagnosticInfo {
+            symbolic_id: read_xml_optional_field(node, "SymbolicId")?,
+            namespace_uri: read_xml_optional_field(node, "NamespaceUri")?,
+            locale: read_xml_optional_field(node, "Locale")?,
+            localized_text: read_xml_optional_field(node, "LocalizedText")?,
+            additional_info: read_xml_optional_field(node, "AdditionalInfo")?,
+            inner_status_code: read_xml_optional_field(node, "InnerStatusCode")?,
+            inner_diagnostic_info: inner_diagnostic_info.map(|v| Box::new(v)),
+        })
+    }
+}
+
 impl DiagnosticInfo {
     pub fn new() -> DiagnosticInfo {
         DiagnosticInfo {
@@ -3,6 +3,8 @@ use std::io::{Read, Write};
 use base64;
 
 use encoding::{write_i32, BinaryEncoder, EncodingResult, process_encode_io_result, process_decode_io_result};
+use json::{JsonEncoder, JsonValue, read_json_string};
+use xml::{XmlEncoder, XmlNode, read_xml_text};
 use basic_types::Int32;
 use constants;
 use status_codes::StatusCode::{BadDecodingError, BadEncodingLimitsExceeded};
@@ -62,6 +64,49 @@ impl BinaryEncoder<ByteString> for ByteString {
     }
 }
 
+impl JsonEncoder<ByteString> for ByteString {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            JsonValue::from(self.as_base64())
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if let Some(value) = read_json_string(value)? {
+            ByteString::from_base64(value).ok_or_else(|| {
+                error!("ByteString is not valid base64");
+                BadDecodingError
+            })
+        } else {
+            Ok(ByteString::null())
+        }
+    }
+}
+
+
+impl XmlEncoder<ByteString> for ByteString {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        if !self.is_null() {
+            node.text = Some(self.as_base64());
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        // Base64 text may be broken over several lines
+        let text: String = read_xml_text(node).split_whitespace().collect();
+        if text.is_empty() {
+            Ok(ByteString::null())
+        } else {
+            ByteString::from_base64(&text).ok_or_else(|| {
+                error!("ByteString is not valid base64");
+                BadDecodingError
+            })
+        }
+    }
+}
+
 impl<'a, T> From<&'a T> for ByteString where T: AsRef<[u8]> + ?Sized {
     fn from(value: &'a T) -> Self {
         Self::from(value.as_ref().to_vec())
@@ -125,10 +170,9 @@ impl ByteString {
     /// Create a byte string with a number of random characters. Can be used to create a nonce or
     /// a similar reason.
     pub fn random(number_of_bytes: usize) -> ByteString {
-        use ring::rand::{SystemRandom, SecureRandom};
-        let rng = SystemRandom::new();
+        use rand_core::{OsRng, RngCore};
         let mut bytes = vec![0u8; number_of_bytes];
-        let _ = rng.fill(&mut bytes);
+        OsRng.fill_bytes(&mut bytes);
         ByteString::from(bytes)
     }
 }
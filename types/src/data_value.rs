@@ -1,6 +1,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 use basic_types::*;
 use date_time::*;
 use variant::Variant;
@@ -148,6 +150,54 @@ impl BinaryEncoder<DataValue> for DataValue {
     }
 }
 
+impl JsonEncoder<DataValue> for DataValue {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_optional_field(&mut map, "Value", &self.value, reversible);
+        write_json_optional_field(&mut map, "Status", &self.status, reversible);
+        write_json_optional_field(&mut map, "SourceTimestamp", &self.source_timestamp, reversible);
+        write_json_optional_field(&mut map, "SourcePicoseconds", &self.source_picoseconds, reversible);
+        write_json_optional_field(&mut map, "ServerTimestamp", &self.server_timestamp, reversible);
+        write_json_optional_field(&mut map, "ServerPicoseconds", &self.server_picoseconds, reversible);
+        if map.is_empty() { JsonValue::Null } else { JsonValue::Object(map) }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DataValue {
+            value: read_json_optional_field(value, "Value")?,
+            status: read_json_optional_field(value, "Status")?,
+            source_timestamp: read_json_optional_field(value, "SourceTimestamp")?,
+            source_picoseconds: read_json_optional_field(value, "SourcePicoseconds")?,
+            server_timestamp: read_json_optional_field(value, "ServerTimestamp")?,
+            server_picoseconds: read_json_optional_field(value, "ServerPicoseconds")?,
+        })
+    }
+}
+
+
+impl XmlEncoder<DataValue> for DataValue {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_optional_field(node, "Value", &self.value);
+        write_xml_optional_field(node, "StatusCode", &self.status);
+        write_xml_optional_field(node, "SourceTimestamp", &self.source_timestamp);
+        write_xml_optional_field(node, "SourcePicoseconds", &self.source_picoseconds);
+        write_xml_optional_field(node, "ServerTimestamp", &self.server_timestamp);
+        write_xml_optional_field(node, "ServerPicoseconds", &self.server_picoseconds);
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DataValue {
+            value: read_xml_optional_field(node, "Value")?,
+            status: read_xml_optional_field(node, "StatusCode")?,
+            source_timestamp: read_xml_optional_field(node, "SourceTimestamp")?,
+            source_picoseconds: read_xml_optional_field(node, "SourcePicoseconds")?,
+            server_timestamp: read_xml_optional_field(node, "ServerTimestamp")?,
+            server_picoseconds: read_xml_optional_field(node, "ServerPicoseconds")?,
+        })
+    }
+}
+
 impl From<Variant> for DataValue {
     fn from(v: Variant) -> Self {
         DataValue::new(v)
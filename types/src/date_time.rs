@@ -4,6 +4,9 @@ use chrono::{self, Utc, TimeZone, Datelike, Timelike};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use encoding::*;
+use json::{JsonEncoder, JsonValue, read_json_string};
+use xml::{XmlEncoder, XmlNode, read_xml_text};
+use status_codes::StatusCode::BadDecodingError;
 use basic_types::*;
 
 const NANOS_PER_SECOND: i64 = 1_000_000_000;
@@ -62,6 +65,37 @@ impl BinaryEncoder<DateTime> for DateTime {
     }
 }
 
+/// DateTime encoded as an ISO 8601 string in UTC
+impl JsonEncoder<DateTime> for DateTime {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        JsonValue::from(self.to_iso8601())
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if let Some(value) = read_json_string(value)? {
+            DateTime::from_iso8601(value)
+        } else {
+            Ok(DateTime::default())
+        }
+    }
+}
+
+/// DateTime encoded as an xs:dateTime in UTC
+impl XmlEncoder<DateTime> for DateTime {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        node.text = Some(self.to_iso8601());
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let value = read_xml_text(node);
+        if value.is_empty() {
+            Ok(DateTime::default())
+        } else {
+            DateTime::from_iso8601(value)
+        }
+    }
+}
+
 impl Default for DateTime {
     fn default() -> Self {
         DateTime::epoch()
@@ -238,4 +272,18 @@ impl DateTime {
         // Put it back together in ticks
         seconds * TICKS_PER_SECOND + nanos / NANOS_PER_TICK
     }
+
+    /// The ISO 8601 form used by the JSON and XML encodings
+    fn to_iso8601(&self) -> String {
+        self.date_time.format("%Y-%m-%dT%H:%M:%S%.fZ").to_string()
+    }
+
+    fn from_iso8601(value: &str) -> EncodingResult<DateTime> {
+        if let Ok(date_time) = chrono::DateTime::parse_from_rfc3339(value) {
+            Ok(DateTime::from(date_time.with_timezone(&Utc)))
+        } else {
+            error!("DateTime {} is not a valid ISO 8601 date time", value);
+            Err(BadDecodingError)
+        }
+    }
 }
@@ -0,0 +1,585 @@
+//! Structures whose types are not known at compile time, decoded and encoded at runtime from the
+//! OPC Binary type dictionaries of a server.
+//!
+//! `ExtensionObject::decode_inner()` needs the Rust type of the body. A `DataTypeRegistry` instead
+//! holds the dictionaries and the binary encoding ids of their types, and turns an
+//! `ExtensionObject` of any of those types into a `DynamicStructure` of field names and values,
+//! and back again.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Write};
+
+use basic_types::*;
+use byte_string::ByteString;
+use constants;
+use data_value::DataValue;
+use date_time::DateTime;
+use encoding::*;
+use extension_object::{ExtensionObject, ExtensionObjectEncoding};
+use guid::Guid;
+use node_id::{NodeId, ExpandedNodeId};
+use status_codes::StatusCode;
+use status_codes::StatusCode::*;
+use string::{UAString, XmlElement};
+use type_dictionary::*;
+use variant::{Variant, VariantTypeId};
+
+/// Structures may contain structures, but not endlessly
+const MAX_NESTING_DEPTH: usize = 32;
+
+/// The value of a structured type as its fields and their values, in the order of the type.
+///
+/// Fields of built-in types hold the value of that type, enumerations hold their `Int32` value
+/// and fields of structured types hold an `ExtensionObject` of the field's type that can be
+/// decoded in turn. Array fields hold an array, or `Variant::Empty` for a null array. Optional
+/// fields and the members of a union that are not encoded are left out. The fields holding the
+/// length of arrays, the switch of a union and the bits of optional fields are not exposed, they
+/// follow from the other fields when the structure is encoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicStructure {
+    pub type_name: TypeName,
+    pub fields: Vec<(String, Variant)>,
+}
+
+impl DynamicStructure {
+    /// Creates a structure of the type with no fields
+    pub fn new(type_name: TypeName) -> DynamicStructure {
+        DynamicStructure {
+            type_name,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Returns the value of a field
+    pub fn get(&self, name: &str) -> Option<&Variant> {
+        self.fields.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref v)| v)
+    }
+
+    /// Sets the value of a field, adding the field if it is not there
+    pub fn set<V>(&mut self, name: &str, value: V) where V: Into<Variant> {
+        let value = value.into();
+        if let Some(field) = self.fields.iter_mut().find(|&&mut (ref n, _)| n == name) {
+            field.1 = value;
+            return;
+        }
+        self.fields.push((name.to_string(), value));
+    }
+
+    /// Removes a field, e.g. to leave out an optional field, returning its value
+    pub fn remove(&mut self, name: &str) -> Option<Variant> {
+        if let Some(idx) = self.fields.iter().position(|&(ref n, _)| n == name) {
+            Some(self.fields.remove(idx).1)
+        } else {
+            None
+        }
+    }
+}
+
+/// The kind of a built-in type named in a dictionary
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BuiltInType {
+    Value(VariantTypeId),
+    Variant,
+}
+
+/// The built-in types of the OPC Binary namespace and the UA namespace. Other names are types
+/// defined by a dictionary.
+fn built_in_type(type_name: &TypeName) -> Option<BuiltInType> {
+    let variant_type = if type_name.namespace_uri == BINARY_SCHEMA_NAMESPACE {
+        match type_name.name.as_ref() {
+            "Boolean" => VariantTypeId::Boolean,
+            "SByte" => VariantTypeId::SByte,
+            "Byte" | "Char" => VariantTypeId::Byte,
+            "Int16" => VariantTypeId::Int16,
+            "UInt16" => VariantTypeId::UInt16,
+            "Int32" => VariantTypeId::Int32,
+            "UInt32" => VariantTypeId::UInt32,
+            "Int64" => VariantTypeId::Int64,
+            "UInt64" => VariantTypeId::UInt64,
+            "Float" => VariantTypeId::Float,
+            "Double" => VariantTypeId::Double,
+            "String" | "CharArray" => VariantTypeId::String,
+            "DateTime" => VariantTypeId::DateTime,
+            "Guid" => VariantTypeId::Guid,
+            "ByteString" => VariantTypeId::ByteString,
+            _ => return None
+        }
+    } else if type_name.namespace_uri == UA_TYPES_NAMESPACE {
+        match type_name.name.as_ref() {
+            "XmlElement" => VariantTypeId::XmlElement,
+            "NodeId" => VariantTypeId::NodeId,
+            "ExpandedNodeId" => VariantTypeId::ExpandedNodeId,
+            "StatusCode" => VariantTypeId::StatusCode,
+            "QualifiedName" => VariantTypeId::QualifiedName,
+            "LocalizedText" => VariantTypeId::LocalizedText,
+            "ExtensionObject" => VariantTypeId::ExtensionObject,
+            "DataValue" => VariantTypeId::DataValue,
+            "Variant" => return Some(BuiltInType::Variant),
+            _ => return None
+        }
+    } else {
+        return None;
+    };
+    Some(BuiltInType::Value(variant_type))
+}
+
+fn decode_built_in(variant_type: VariantTypeId, stream: &mut Cursor<&[u8]>) -> EncodingResult<Variant> {
+    let value = match variant_type {
+        VariantTypeId::Boolean => Variant::from(Boolean::decode(stream)?),
+        VariantTypeId::SByte => Variant::from(SByte::decode(stream)?),
+        VariantTypeId::Byte => Variant::from(Byte::decode(stream)?),
+        VariantTypeId::Int16 => Variant::from(Int16::decode(stream)?),
+        VariantTypeId::UInt16 => Variant::from(UInt16::decode(stream)?),
+        VariantTypeId::Int32 => Variant::from(Int32::decode(stream)?),
+        VariantTypeId::UInt32 => Variant::from(UInt32::decode(stream)?),
+        VariantTypeId::Int64 => Variant::from(Int64::decode(stream)?),
+        VariantTypeId::UInt64 => Variant::from(UInt64::decode(stream)?),
+        VariantTypeId::Float => Variant::from(Float::decode(stream)?),
+        VariantTypeId::Double => Variant::from(Double::decode(stream)?),
+        VariantTypeId::String => Variant::from(UAString::decode(stream)?),
+        VariantTypeId::DateTime => Variant::from(DateTime::decode(stream)?),
+        VariantTypeId::Guid => Variant::from(Guid::decode(stream)?),
+        VariantTypeId::StatusCode => Variant::from(StatusCode::decode(stream)?),
+        VariantTypeId::ByteString => Variant::from(ByteString::decode(stream)?),
+        VariantTypeId::XmlElement => Variant::XmlElement(XmlElement::decode(stream)?),
+        VariantTypeId::QualifiedName => Variant::from(QualifiedName::decode(stream)?),
+        VariantTypeId::LocalizedText => Variant::from(LocalizedText::decode(stream)?),
+        VariantTypeId::NodeId => Variant::from(NodeId::decode(stream)?),
+        VariantTypeId::ExpandedNodeId => Variant::from(ExpandedNodeId::decode(stream)?),
+        VariantTypeId::ExtensionObject => Variant::from(ExtensionObject::decode(stream)?),
+        VariantTypeId::DataValue => Variant::from(DataValue::decode(stream)?),
+        _ => return Err(BadDataTypeIdUnknown)
+    };
+    Ok(value)
+}
+
+fn encode_built_in<S: Write>(variant_type: VariantTypeId, value: &Variant, stream: &mut S) -> EncodingResult<usize> {
+    // An XmlElement is a string so a string value is accepted for it
+    let value = match (variant_type, value) {
+        (VariantTypeId::XmlElement, &Variant::String(ref value)) => Variant::XmlElement(value.clone()),
+        (variant_type, value) => value.convert(variant_type)?
+    };
+    match value {
+        Variant::Boolean(ref value) => value.encode(stream),
+        Variant::SByte(ref value) => value.encode(stream),
+        Variant::Byte(ref value) => value.encode(stream),
+        Variant::Int16(ref value) => value.encode(stream),
+        Variant::UInt16(ref value) => value.encode(stream),
+        Variant::Int32(ref value) => value.encode(stream),
+        Variant::UInt32(ref value) => value.encode(stream),
+        Variant::Int64(ref value) => value.encode(stream),
+        Variant::UInt64(ref value) => value.encode(stream),
+        Variant::Float(ref value) => value.encode(stream),
+        Variant::Double(ref value) => value.encode(stream),
+        Variant::String(ref value) => value.encode(stream),
+        Variant::DateTime(ref value) => value.encode(stream),
+        Variant::Guid(ref value) => value.encode(stream),
+        Variant::StatusCode(ref value) => value.encode(stream),
+        Variant::ByteString(ref value) => value.encode(stream),
+        Variant::XmlElement(ref value) => value.encode(stream),
+        Variant::QualifiedName(ref value) => value.encode(stream),
+        Variant::LocalizedText(ref value) => value.encode(stream),
+        Variant::NodeId(ref value) => value.encode(stream),
+        Variant::ExpandedNodeId(ref value) => value.encode(stream),
+        Variant::ExtensionObject(ref value) => value.encode(stream),
+        Variant::DataValue(ref value) => value.encode(stream),
+        value => {
+            error!("Value {:?} is not a {:?}", value, variant_type);
+            Err(BadTypeMismatch)
+        }
+    }
+}
+
+/// Reads fields of `opc:Bit` from a stream, least significant bit first. The bits of consecutive
+/// bit fields share bytes, and the next field that is not a bit field starts on a new byte.
+struct BitReader {
+    byte: u8,
+    offset: u8,
+}
+
+impl BitReader {
+    fn new() -> BitReader {
+        BitReader { byte: 0, offset: 8 }
+    }
+
+    fn read(&mut self, stream: &mut Cursor<&[u8]>, bits: UInt32) -> EncodingResult<Int64> {
+        let mut value = 0;
+        for i in 0..bits {
+            if self.offset == 8 {
+                self.byte = read_u8(stream)?;
+                self.offset = 0;
+            }
+            if i < 64 {
+                value |= (((self.byte >> self.offset) & 1) as Int64) << i;
+            }
+            self.offset += 1;
+        }
+        Ok(value)
+    }
+
+    fn align(&mut self) {
+        self.offset = 8;
+    }
+}
+
+/// Writes fields of `opc:Bit` to a stream, the counterpart of `BitReader`
+struct BitWriter {
+    byte: u8,
+    offset: u8,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter { byte: 0, offset: 0 }
+    }
+
+    fn write<S: Write>(&mut self, stream: &mut S, bits: UInt32, value: Int64) -> EncodingResult<usize> {
+        let mut size = 0;
+        for i in 0..bits {
+            if i < 64 && (value >> i) & 1 != 0 {
+                self.byte |= 1 << self.offset;
+            }
+            self.offset += 1;
+            if self.offset == 8 {
+                size += self.align(stream)?;
+            }
+        }
+        Ok(size)
+    }
+
+    fn align<S: Write>(&mut self, stream: &mut S) -> EncodingResult<usize> {
+        if self.offset == 0 {
+            return Ok(0);
+        }
+        let size = write_u8(stream, self.byte)?;
+        self.byte = 0;
+        self.offset = 0;
+        Ok(size)
+    }
+}
+
+fn is_bit_field(field: &StructuredField) -> bool {
+    field.type_name.namespace_uri == BINARY_SCHEMA_NAMESPACE && field.type_name.name == "Bit"
+}
+
+/// The names of the fields that are referred to as the length of an array or as a switch. Their
+/// values follow from the fields that refer to them.
+fn hidden_fields(structured_type: &StructuredType) -> HashSet<&str> {
+    structured_type.fields.iter().flat_map(|f| {
+        f.length_field.iter().chain(f.switch_field.iter()).map(|n| n.as_ref())
+    }).collect()
+}
+
+/// Tests if a field is encoded, given the values of the switch fields before it
+fn is_switched_on(field: &StructuredField, hidden_values: &HashMap<String, Int64>) -> bool {
+    if let Some(ref switch_field) = field.switch_field {
+        let switch = hidden_values.get(switch_field).cloned().unwrap_or(0);
+        match field.switch_value {
+            Some(switch_value) => switch == switch_value as Int64,
+            None => switch != 0,
+        }
+    } else {
+        true
+    }
+}
+
+fn integer_value(value: &Variant) -> EncodingResult<Int64> {
+    match value.convert(VariantTypeId::Int64)? {
+        Variant::Int64(value) => Ok(value),
+        _ => Err(BadDecodingError)
+    }
+}
+
+/// Holds the type dictionaries of a server and the binary encoding ids of their structured
+/// types, and decodes and encodes the values of those types.
+///
+/// A client fills a registry with `Session::load_data_types()`. The registry can then decode the
+/// `ExtensionObject` values of custom types that are read, or received in data change
+/// notifications, and encode values to write.
+#[derive(Debug, Clone, Default)]
+pub struct DataTypeRegistry {
+    dictionaries: Vec<TypeDictionary>,
+    encodings: HashMap<NodeId, TypeName>,
+}
+
+impl DataTypeRegistry {
+    pub fn new() -> DataTypeRegistry {
+        DataTypeRegistry::default()
+    }
+
+    /// Adds a dictionary, replacing any dictionary of the same target namespace
+    pub fn add_dictionary(&mut self, dictionary: TypeDictionary) {
+        self.dictionaries.retain(|d| d.target_namespace != dictionary.target_namespace);
+        self.dictionaries.push(dictionary);
+    }
+
+    /// Adds the binary encoding id of a structured type, i.e. the node id of `ExtensionObject`
+    /// values of the type
+    pub fn add_encoding<N>(&mut self, encoding_id: N, type_name: TypeName) where N: Into<NodeId> {
+        self.encodings.insert(encoding_id.into(), type_name);
+    }
+
+    /// Returns the dictionary of a namespace
+    pub fn dictionary(&self, namespace_uri: &str) -> Option<&TypeDictionary> {
+        self.dictionaries.iter().find(|d| d.target_namespace == namespace_uri)
+    }
+
+    /// Returns the type of the values with the binary encoding id
+    pub fn type_name(&self, encoding_id: &NodeId) -> Option<&TypeName> {
+        self.encodings.get(encoding_id)
+    }
+
+    /// Returns the binary encoding id of a type
+    pub fn encoding_id(&self, type_name: &TypeName) -> Option<&NodeId> {
+        self.encodings.iter().find(|&(_, t)| t == type_name).map(|(encoding_id, _)| encoding_id)
+    }
+
+    /// Tests if the registry can decode the extension object
+    pub fn is_known(&self, extension_object: &ExtensionObject) -> bool {
+        self.type_name(&extension_object.node_id).map_or(false, |t| self.find_structured_type(t).is_some())
+    }
+
+    /// Decodes the binary body of an extension object of a type in the registry. An unknown
+    /// type is `BadDataTypeIdUnknown`.
+    pub fn decode(&self, extension_object: &ExtensionObject) -> EncodingResult<DynamicStructure> {
+        let type_name = if let Some(type_name) = self.type_name(&extension_object.node_id) {
+            type_name
+        } else {
+            error!("Extension object has an unknown encoding id {:?}", extension_object.node_id);
+            return Err(BadDataTypeIdUnknown);
+        };
+        let body: &[u8] = match extension_object.body {
+            ExtensionObjectEncoding::ByteString(ByteString { value: Some(ref value) }) => value,
+            _ => {
+                error!("Extension object of {:?} has no binary body", type_name);
+                return Err(BadDecodingError);
+            }
+        };
+        let mut stream = Cursor::new(body);
+        self.decode_structure(type_name, &mut stream, 0)
+    }
+
+    /// Encodes a structure as an extension object with a binary body. A type that is not in the
+    /// registry is `BadDataTypeIdUnknown`, a missing field is `BadEncodingError` and a value that
+    /// cannot be converted to the type of its field is `BadTypeMismatch`.
+    pub fn encode(&self, structure: &DynamicStructure) -> EncodingResult<ExtensionObject> {
+        let encoding_id = if let Some(encoding_id) = self.encoding_id(&structure.type_name) {
+            encoding_id.clone()
+        } else {
+            error!("Structure type {:?} has no known encoding id", structure.type_name);
+            return Err(BadDataTypeIdUnknown);
+        };
+        let mut stream = Cursor::new(Vec::new());
+        self.encode_structure(structure, &mut stream, 0)?;
+        Ok(ExtensionObject {
+            node_id: encoding_id,
+            body: ExtensionObjectEncoding::ByteString(ByteString::from(stream.into_inner())),
+        })
+    }
+
+    fn find_structured_type(&self, type_name: &TypeName) -> Option<&StructuredType> {
+        self.dictionary(&type_name.namespace_uri).and_then(|d| d.find_structured_type(&type_name.name))
+    }
+
+    fn find_enumerated_type(&self, type_name: &TypeName) -> Option<&EnumeratedType> {
+        self.dictionary(&type_name.namespace_uri).and_then(|d| d.find_enumerated_type(&type_name.name))
+    }
+
+    fn decode_structure(&self, type_name: &TypeName, stream: &mut Cursor<&[u8]>, depth: usize) -> EncodingResult<DynamicStructure> {
+        if depth > MAX_NESTING_DEPTH {
+            error!("Structure {:?} is nested too deeply", type_name);
+            return Err(BadEncodingLimitsExceeded);
+        }
+        let structured_type = if let Some(structured_type) = self.find_structured_type(type_name) {
+            structured_type
+        } else {
+            error!("Structure type {:?} is not in a dictionary", type_name);
+            return Err(BadDataTypeIdUnknown);
+        };
+        let hidden_fields = hidden_fields(structured_type);
+        let mut hidden_values = HashMap::new();
+        let mut bits = BitReader::new();
+        let mut structure = DynamicStructure::new(type_name.clone());
+        for field in structured_type.fields.iter() {
+            if !is_switched_on(field, &hidden_values) {
+                continue;
+            }
+            if is_bit_field(field) {
+                let value = bits.read(stream, field.length.unwrap_or(1))?;
+                hidden_values.insert(field.name.clone(), value);
+                continue;
+            }
+            bits.align();
+
+            let length = if let Some(ref length_field) = field.length_field {
+                Some(hidden_values.get(length_field).cloned().unwrap_or(-1))
+            } else {
+                field.length.map(|length| length as Int64)
+            };
+            let value = if let Some(length) = length {
+                if length < 0 {
+                    Variant::Empty
+                } else if length > constants::MAX_ARRAY_LENGTH as Int64 {
+                    error!("Array field {} has length {} which exceeds the maximum", field.name, length);
+                    return Err(BadEncodingLimitsExceeded);
+                } else {
+                    let values = (0..length).map(|_| self.decode_value(&field.type_name, stream, depth)).collect::<EncodingResult<Vec<Variant>>>()?;
+                    Variant::Array(values)
+                }
+            } else {
+                self.decode_value(&field.type_name, stream, depth)?
+            };
+
+            if hidden_fields.contains(field.name.as_str()) {
+                hidden_values.insert(field.name.clone(), integer_value(&value)?);
+            } else {
+                structure.fields.push((field.name.clone(), value));
+            }
+        }
+        Ok(structure)
+    }
+
+    fn decode_value(&self, type_name: &TypeName, stream: &mut Cursor<&[u8]>, depth: usize) -> EncodingResult<Variant> {
+        match built_in_type(type_name) {
+            Some(BuiltInType::Value(variant_type)) => decode_built_in(variant_type, stream),
+            Some(BuiltInType::Variant) => Variant::decode(stream),
+            None => {
+                if let Some(enumerated_type) = self.find_enumerated_type(type_name) {
+                    let value = match enumerated_type.length_in_bits {
+                        8 => read_u8(stream)? as i8 as Int32,
+                        16 => Int16::decode(stream)? as Int32,
+                        _ => Int32::decode(stream)?,
+                    };
+                    return Ok(Variant::from(value));
+                }
+                // A nested structure is kept in its encoded form
+                let encoding_id = if let Some(encoding_id) = self.encoding_id(type_name) {
+                    encoding_id.clone()
+                } else {
+                    error!("Type {:?} is not a built-in type, or a structure with a known encoding id", type_name);
+                    return Err(BadDataTypeIdUnknown);
+                };
+                let start = stream.position() as usize;
+                self.decode_structure(type_name, stream, depth + 1)?;
+                let end = stream.position() as usize;
+                let body = stream.get_ref()[start..end].to_vec();
+                Ok(Variant::from(ExtensionObject {
+                    node_id: encoding_id,
+                    body: ExtensionObjectEncoding::ByteString(ByteString::from(body)),
+                }))
+            }
+        }
+    }
+
+    fn encode_structure<S: Write>(&self, structure: &DynamicStructure, stream: &mut S, depth: usize) -> EncodingResult<usize> {
+        if depth > MAX_NESTING_DEPTH {
+            error!("Structure {:?} is nested too deeply", structure.type_name);
+            return Err(BadEncodingLimitsExceeded);
+        }
+        let structured_type = if let Some(structured_type) = self.find_structured_type(&structure.type_name) {
+            structured_type
+        } else {
+            error!("Structure type {:?} is not in a dictionary", structure.type_name);
+            return Err(BadDataTypeIdUnknown);
+        };
+        let hidden_fields = hidden_fields(structured_type);
+
+        // The lengths and switches are found from the fields that refer to them
+        let mut hidden_values = HashMap::new();
+        for field in structured_type.fields.iter().filter(|f| !hidden_fields.contains(f.name.as_str())) {
+            let value = structure.get(&field.name);
+            if let Some(ref length_field) = field.length_field {
+                let length = match value {
+                    Some(&Variant::Array(ref values)) => values.len() as Int64,
+                    _ => -1
+                };
+                hidden_values.insert(length_field.clone(), length);
+            }
+            if let Some(ref switch_field) = field.switch_field {
+                if value.is_some() {
+                    hidden_values.insert(switch_field.clone(), field.switch_value.unwrap_or(1) as Int64);
+                }
+            }
+        }
+
+        let mut size = 0;
+        let mut bits = BitWriter::new();
+        for field in structured_type.fields.iter() {
+            if !is_switched_on(field, &hidden_values) {
+                continue;
+            }
+            if is_bit_field(field) {
+                let value = hidden_values.get(&field.name).cloned().unwrap_or(0);
+                size += bits.write(stream, field.length.unwrap_or(1), value)?;
+                continue;
+            }
+            size += bits.align(stream)?;
+
+            if hidden_fields.contains(field.name.as_str()) {
+                let value = Variant::from(hidden_values.get(&field.name).cloned().unwrap_or(0));
+                size += self.encode_value(&field.type_name, &value, stream, depth)?;
+                continue;
+            }
+            let value = if let Some(value) = structure.get(&field.name) {
+                value
+            } else {
+                error!("Structure {:?} has no value for field {}", structure.type_name, field.name);
+                return Err(BadEncodingError);
+            };
+            if field.length_field.is_some() || field.length.is_some() {
+                match *value {
+                    Variant::Array(ref values) => {
+                        if field.length.map_or(false, |length| length as usize != values.len()) {
+                            error!("Array field {} must have {} values", field.name, field.length.unwrap());
+                            return Err(BadEncodingError);
+                        }
+                        for value in values.iter() {
+                            size += self.encode_value(&field.type_name, value, stream, depth)?;
+                        }
+                    }
+                    // A null array
+                    Variant::Empty if field.length.is_none() => {}
+                    _ => {
+                        error!("Field {} of structure {:?} is not an array", field.name, structure.type_name);
+                        return Err(BadTypeMismatch);
+                    }
+                }
+            } else {
+                size += self.encode_value(&field.type_name, value, stream, depth)?;
+            }
+        }
+        size += bits.align(stream)?;
+        Ok(size)
+    }
+
+    fn encode_value<S: Write>(&self, type_name: &TypeName, value: &Variant, stream: &mut S, depth: usize) -> EncodingResult<usize> {
+        match built_in_type(type_name) {
+            Some(BuiltInType::Value(variant_type)) => encode_built_in(variant_type, value, stream),
+            Some(BuiltInType::Variant) => value.encode(stream),
+            None => {
+                if let Some(enumerated_type) = self.find_enumerated_type(type_name) {
+                    let value = match value.convert(VariantTypeId::Int32)? {
+                        Variant::Int32(value) => value,
+                        _ => return Err(BadTypeMismatch)
+                    };
+                    return match enumerated_type.length_in_bits {
+                        8 => write_u8(stream, value as u8),
+                        16 => (value as Int16).encode(stream),
+                        _ => value.encode(stream),
+                    };
+                }
+                // A nested structure is written from its encoded form
+                match *value {
+                    Variant::ExtensionObject(ref extension_object) if self.type_name(&extension_object.node_id) == Some(type_name) => {
+                        let structure = self.decode(extension_object)?;
+                        self.encode_structure(&structure, stream, depth + 1)
+                    }
+                    _ => {
+                        error!("Value {:?} is not an extension object of type {:?}", value, type_name);
+                        Err(BadTypeMismatch)
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,9 @@ use std::io::{Read, Write, Cursor};
 
 use basic_types::Byte;
 use encoding::*;
+use json::*;
+use xml::*;
+use service_types::extension_objects;
 use string::XmlElement;
 use node_id::NodeId;
 use byte_string::ByteString;
@@ -98,6 +101,161 @@ impl BinaryEncoder<ExtensionObject> for ExtensionObject {
     }
 }
 
+/// ExtensionObject encoded as an object holding the TypeId, the Encoding (omitted for a JSON body)
+/// and the Body. A binary body of a known type is encoded as a JSON body with the data type id as
+/// the TypeId. Non-reversible encoding is just the body.
+impl JsonEncoder<ExtensionObject> for ExtensionObject {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        if self.is_null() {
+            return JsonValue::Null;
+        }
+        let (type_id, encoding, body) = match self.body {
+            ExtensionObjectEncoding::None => (self.node_id.clone(), 0, JsonValue::Null),
+            ExtensionObjectEncoding::ByteString(ref value) => {
+                let json_body = if let Ok(object_id) = self.node_id.as_object_id() {
+                    extension_objects::encode_json_body(object_id, value, reversible)
+                } else {
+                    None
+                };
+                if let Some((data_type_id, Ok(json_body))) = json_body {
+                    (data_type_id.into(), 0, json_body)
+                } else {
+                    (self.node_id.clone(), 1, value.encode_json(reversible))
+                }
+            }
+            ExtensionObjectEncoding::XmlElement(ref value) => (self.node_id.clone(), 2, value.encode_json(reversible))
+        };
+        if reversible {
+            let mut map = JsonMap::new();
+            write_json_field(&mut map, "TypeId", &type_id, reversible);
+            if encoding != 0 {
+                map.insert("Encoding".to_string(), JsonValue::from(encoding));
+            }
+            if !body.is_null() {
+                map.insert("Body".to_string(), body);
+            }
+            JsonValue::Object(map)
+        } else {
+            body
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if value.is_null() {
+            return Ok(ExtensionObject::null());
+        }
+        check_json_object(value)?;
+        let mut node_id: NodeId = read_json_field(value, "TypeId")?;
+        let encoding: Byte = read_json_field(value, "Encoding")?;
+        let body = match encoding {
+            0x0 => {
+                match value.get("Body") {
+                    None | Some(&JsonValue::Null) => ExtensionObjectEncoding::None,
+                    Some(body) => {
+                        // The TypeId of a JSON body is the data type, and the decoded binary
+                        // body takes the binary encoding id of the type
+                        let binary_body = if let Ok(data_type_id) = node_id.as_data_type_id() {
+                            extension_objects::decode_json_body(data_type_id, body)
+                        } else {
+                            None
+                        };
+                        if let Some((object_id, binary_body)) = binary_body {
+                            node_id = object_id.into();
+                            ExtensionObjectEncoding::ByteString(binary_body?)
+                        } else {
+                            error!("Cannot decode JSON body of unknown type {:?}", node_id);
+                            return Err(BadDecodingError);
+                        }
+                    }
+                }
+            }
+            0x1 => ExtensionObjectEncoding::ByteString(read_json_field(value, "Body")?),
+            0x2 => ExtensionObjectEncoding::XmlElement(read_json_field(value, "Body")?),
+            _ => {
+                error!("Invalid encoding type {} in JSON", encoding);
+                return Err(BadDecodingError);
+            }
+        };
+        Ok(ExtensionObject {
+            node_id,
+            body,
+        })
+    }
+}
+
+
+/// ExtensionObject encoded as a TypeId and a Body element. A binary body of a known type is
+/// encoded as the element of the type with the XML encoding id as the TypeId, and an XML body of
+/// a known type is decoded back into a binary body. Other binary bodies are a ByteString element.
+impl XmlEncoder<ExtensionObject> for ExtensionObject {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        if self.is_null() {
+            return;
+        }
+        let mut body_node = XmlNode::new("Body");
+        let node_id = match self.body {
+            ExtensionObjectEncoding::None => self.node_id.clone(),
+            ExtensionObjectEncoding::ByteString(ref value) => {
+                let xml_body = if let Ok(object_id) = self.node_id.as_object_id() {
+                    extension_objects::encode_xml_body(object_id, value)
+                } else {
+                    None
+                };
+                if let Some((xml_object_id, Ok(xml_body))) = xml_body {
+                    body_node.children.push(xml_body);
+                    xml_object_id.into()
+                } else {
+                    write_xml_field(&mut body_node, "ByteString", value);
+                    self.node_id.clone()
+                }
+            }
+            ExtensionObjectEncoding::XmlElement(ref value) => {
+                write_xml_element(&mut body_node, value);
+                self.node_id.clone()
+            }
+        };
+        write_xml_field(node, "TypeId", &node_id);
+        if !body_node.children.is_empty() || body_node.text.is_some() {
+            node.children.push(body_node);
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let node_id: NodeId = read_xml_field(node, "TypeId")?;
+        let body = if let Some(body) = node.get_child("Body").and_then(|body| body.children.first()) {
+            body
+        } else {
+            return Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::None,
+            });
+        };
+        if body.name == "ByteString" {
+            return Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::ByteString(ByteString::decode_xml(body)?),
+            });
+        }
+        let binary_body = if let Ok(object_id) = node_id.as_object_id() {
+            extension_objects::decode_xml_body(object_id, body)
+        } else {
+            None
+        };
+        if let Some((binary_object_id, binary_body)) = binary_body {
+            Ok(ExtensionObject {
+                node_id: binary_object_id.into(),
+                body: ExtensionObjectEncoding::ByteString(binary_body?),
+            })
+        } else {
+            // The body of an unknown type is kept as XML
+            Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::XmlElement(XmlElement::from(xml_to_string(body)?)),
+            })
+        }
+    }
+}
+
 impl ExtensionObject {
     /// Creates a null extension object, i.e. one with no value or payload
     pub fn null() -> ExtensionObject {
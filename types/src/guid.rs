@@ -5,6 +5,10 @@ use std::io::{Read, Write};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use encoding::*;
+use json::{JsonEncoder, JsonValue, read_json_string};
+use xml::{XmlEncoder, XmlNode, write_xml_field, read_xml_field};
+use string::UAString;
+use status_codes::StatusCode::BadDecodingError;
 use uuid::Uuid;
 
 /// A 16 byte value that can be used as a globally unique identifier.
@@ -73,6 +77,37 @@ impl BinaryEncoder<Guid> for Guid {
     }
 }
 
+impl JsonEncoder<Guid> for Guid {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        JsonValue::from(self.to_string())
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if let Some(value) = read_json_string(value)? {
+            Guid::from_str(value).map_err(|_| BadDecodingError)
+        } else {
+            Ok(Guid::null())
+        }
+    }
+}
+
+
+/// Guid encoded as a String element holding its text form
+impl XmlEncoder<Guid> for Guid {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "String", &UAString::from(self.to_string()));
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let value: UAString = read_xml_field(node, "String")?;
+        if value.is_null() {
+            Ok(Guid::null())
+        } else {
+            Guid::from_str(value.as_ref().trim()).map_err(|_| BadDecodingError)
+        }
+    }
+}
+
 impl FromStr for Guid {
     type Err = ();
 
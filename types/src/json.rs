@@ -0,0 +1,333 @@
+//! The JSON encoding described in OPC UA Part 6 section 5.4.
+//!
+//! Types implement `JsonEncoder` to encode to and decode from a `serde_json::Value`. There are two
+//! forms of encoding. The reversible form can be decoded back into the same value. The
+//! non-reversible form is for consumers that only read values and do not care about every
+//! detail of the type, e.g. a `LocalizedText` becomes just its text and an enumeration becomes
+//! its name. Only the reversible form can be decoded.
+//!
+//! Namespace indices are written as indices in both forms because types do not have access to
+//! the server's namespace table.
+
+use std::io::Cursor;
+
+use serde_json;
+
+use encoding::*;
+use basic_types::*;
+use byte_string::ByteString;
+use data_types::{MessageSecurityMode, MonitoringMode};
+use service_types::*;
+use status_codes::StatusCode;
+use status_codes::StatusCode::*;
+
+pub use serde_json::Value as JsonValue;
+
+pub type JsonMap = serde_json::Map<String, JsonValue>;
+
+/// OPC UA JSON Encoding interface. Anything that encodes to JSON must implement this.
+pub trait JsonEncoder<T> {
+    /// Encodes the instance to a JSON value, in the reversible or non-reversible form. A value
+    /// that is null, i.e. which would be omitted from its containing structure, is encoded
+    /// as `JsonValue::Null`.
+    fn encode_json(&self, reversible: bool) -> JsonValue;
+    /// Decodes an instance from a JSON value in the reversible form. A `JsonValue::Null` decodes
+    /// to the default value of the type.
+    fn decode_json(value: &JsonValue) -> EncodingResult<T>;
+}
+
+/// Writes a field into a JSON object. Null values are omitted.
+pub fn write_json_field<T>(map: &mut JsonMap, name: &str, value: &T, reversible: bool) where T: JsonEncoder<T> {
+    let value = value.encode_json(reversible);
+    if !value.is_null() {
+        map.insert(name.to_string(), value);
+    }
+}
+
+/// Writes an array field into a JSON object. A null array is omitted.
+pub fn write_json_array_field<T>(map: &mut JsonMap, name: &str, values: &Option<Vec<T>>, reversible: bool) where T: JsonEncoder<T> {
+    if let Some(ref values) = *values {
+        map.insert(name.to_string(), json_array(values, reversible));
+    }
+}
+
+/// Writes an optional field into a JSON object. None is omitted.
+pub fn write_json_optional_field<T>(map: &mut JsonMap, name: &str, value: &Option<T>, reversible: bool) where T: JsonEncoder<T> {
+    if let Some(ref value) = *value {
+        write_json_field(map, name, value, reversible);
+    }
+}
+
+/// Reads a field from a JSON object. A missing field is decoded from null.
+pub fn read_json_field<T>(value: &JsonValue, name: &str) -> EncodingResult<T> where T: JsonEncoder<T> {
+    if let Some(value) = value.get(name) {
+        T::decode_json(value)
+    } else {
+        T::decode_json(&JsonValue::Null)
+    }
+}
+
+/// Reads an optional field from a JSON object. A missing field is None.
+pub fn read_json_optional_field<T>(value: &JsonValue, name: &str) -> EncodingResult<Option<T>> where T: JsonEncoder<T> {
+    match value.get(name) {
+        None | Some(&JsonValue::Null) => Ok(None),
+        Some(value) => Ok(Some(T::decode_json(value)?))
+    }
+}
+
+/// Reads an array field from a JSON object. A missing field is a null array.
+pub fn read_json_array_field<T>(value: &JsonValue, name: &str) -> EncodingResult<Option<Vec<T>>> where T: JsonEncoder<T> {
+    match value.get(name) {
+        None | Some(&JsonValue::Null) => Ok(None),
+        Some(value) => Ok(Some(read_json_array(value)?))
+    }
+}
+
+/// Encodes the values as a JSON array
+pub fn json_array<T>(values: &[T], reversible: bool) -> JsonValue where T: JsonEncoder<T> {
+    JsonValue::Array(values.iter().map(|v| v.encode_json(reversible)).collect())
+}
+
+/// Decodes the values of a JSON array
+pub fn read_json_array<T>(value: &JsonValue) -> EncodingResult<Vec<T>> where T: JsonEncoder<T> {
+    if let JsonValue::Array(ref values) = *value {
+        values.iter().map(|v| T::decode_json(v)).collect()
+    } else {
+        error!("Expected a JSON array, got {}", value);
+        Err(BadDecodingError)
+    }
+}
+
+/// Tests that the value is a JSON object, or null which is treated as an empty object
+pub fn check_json_object(value: &JsonValue) -> EncodingResult<()> {
+    if value.is_object() || value.is_null() {
+        Ok(())
+    } else {
+        error!("Expected a JSON object, got {}", value);
+        Err(BadDecodingError)
+    }
+}
+
+/// Decodes a JSON string, or null
+pub fn read_json_string(value: &JsonValue) -> EncodingResult<Option<&str>> {
+    match *value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(ref value) => Ok(Some(value)),
+        _ => {
+            error!("Expected a JSON string, got {}", value);
+            Err(BadDecodingError)
+        }
+    }
+}
+
+/// Decodes the binary body of an extension object and encodes it as JSON
+pub fn binary_body_to_json<T>(body: &ByteString, reversible: bool) -> EncodingResult<JsonValue> where T: BinaryEncoder<T> + JsonEncoder<T> {
+    let mut stream = Cursor::new(body.as_ref());
+    let value = T::decode(&mut stream)?;
+    Ok(value.encode_json(reversible))
+}
+
+/// Decodes a JSON body of an extension object and encodes it as binary
+pub fn json_body_to_binary<T>(body: &JsonValue) -> EncodingResult<ByteString> where T: BinaryEncoder<T> + JsonEncoder<T> {
+    let value = T::decode_json(body)?;
+    let mut stream = Cursor::new(vec![0u8; value.byte_len()]);
+    let _ = value.encode(&mut stream)?;
+    Ok(ByteString::from(stream.into_inner()))
+}
+
+fn read_json_i64(value: &JsonValue) -> EncodingResult<i64> {
+    let result = match *value {
+        JsonValue::Null => Some(0),
+        JsonValue::Number(ref value) => value.as_i64(),
+        JsonValue::String(ref value) => value.parse::<i64>().ok(),
+        _ => None
+    };
+    result.ok_or_else(|| {
+        error!("Expected a JSON integer, got {}", value);
+        BadDecodingError
+    })
+}
+
+fn read_json_u64(value: &JsonValue) -> EncodingResult<u64> {
+    let result = match *value {
+        JsonValue::Null => Some(0),
+        JsonValue::Number(ref value) => value.as_u64(),
+        JsonValue::String(ref value) => value.parse::<u64>().ok(),
+        _ => None
+    };
+    result.ok_or_else(|| {
+        error!("Expected a JSON unsigned integer, got {}", value);
+        BadDecodingError
+    })
+}
+
+fn read_json_f64(value: &JsonValue) -> EncodingResult<f64> {
+    let result = match *value {
+        JsonValue::Null => Some(0f64),
+        JsonValue::Number(ref value) => value.as_f64(),
+        JsonValue::String(ref value) => {
+            match value.as_ref() {
+                "NaN" => Some(::std::f64::NAN),
+                "Infinity" => Some(::std::f64::INFINITY),
+                "-Infinity" => Some(::std::f64::NEG_INFINITY),
+                _ => None
+            }
+        }
+        _ => None
+    };
+    result.ok_or_else(|| {
+        error!("Expected a JSON number, got {}", value);
+        BadDecodingError
+    })
+}
+
+fn write_json_f64(value: f64) -> JsonValue {
+    if value.is_nan() {
+        JsonValue::from("NaN")
+    } else if value.is_infinite() {
+        JsonValue::from(if value > 0f64 { "Infinity" } else { "-Infinity" })
+    } else {
+        JsonValue::from(value)
+    }
+}
+
+impl JsonEncoder<Boolean> for Boolean {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        JsonValue::Bool(*self)
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        match *value {
+            JsonValue::Null => Ok(false),
+            JsonValue::Bool(value) => Ok(value),
+            _ => {
+                error!("Expected a JSON boolean, got {}", value);
+                Err(BadDecodingError)
+            }
+        }
+    }
+}
+
+/// Integers up to 32-bits are JSON numbers
+macro_rules! json_integer_impl {
+    ( $t: ty, $read: ident, $wide: ty ) => {
+        impl JsonEncoder<$t> for $t {
+            fn encode_json(&self, _: bool) -> JsonValue {
+                JsonValue::from(*self)
+            }
+
+            fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+                let v = $read(value)?;
+                if v < <$t>::min_value() as $wide || v > <$t>::max_value() as $wide {
+                    error!("Value {} is out of range for {}", v, stringify!($t));
+                    Err(BadDecodingError)
+                } else {
+                    Ok(v as $t)
+                }
+            }
+        }
+    }
+}
+
+json_integer_impl!(SByte, read_json_i64, i64);
+json_integer_impl!(Byte, read_json_u64, u64);
+json_integer_impl!(Int16, read_json_i64, i64);
+json_integer_impl!(UInt16, read_json_u64, u64);
+json_integer_impl!(Int32, read_json_i64, i64);
+json_integer_impl!(UInt32, read_json_u64, u64);
+
+// 64-bit integers are strings because JSON parsers may hold numbers as doubles
+
+impl JsonEncoder<Int64> for Int64 {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        JsonValue::String(self.to_string())
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        read_json_i64(value)
+    }
+}
+
+impl JsonEncoder<UInt64> for UInt64 {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        JsonValue::String(self.to_string())
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        read_json_u64(value)
+    }
+}
+
+impl JsonEncoder<Float> for Float {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        write_json_f64(*self as f64)
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        Ok(read_json_f64(value)? as f32)
+    }
+}
+
+impl JsonEncoder<Double> for Double {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        write_json_f64(*self)
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        read_json_f64(value)
+    }
+}
+
+// StatusCode is in a generated file so its implementation lives here
+
+impl JsonEncoder<StatusCode> for StatusCode {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        if *self == Good {
+            JsonValue::Null
+        } else if reversible {
+            JsonValue::from(*self as u32)
+        } else {
+            let mut map = JsonMap::new();
+            map.insert("Code".to_string(), JsonValue::from(*self as u32));
+            map.insert("Symbol".to_string(), JsonValue::from(self.name()));
+            JsonValue::Object(map)
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        let code = UInt32::decode_json(value)?;
+        StatusCode::from_u32(code).map_err(|_| {
+            error!("Status code {} is unknown", code);
+            BadDecodingError
+        })
+    }
+}
+
+/// Enumerations are numbers when reversible, or "Name_Value" strings when not. Decoding goes
+/// through the binary decoder so the same values are accepted and rejected.
+macro_rules! json_enum_impl {
+    ( $( $t: ident ),* ) => {
+        $(
+        impl JsonEncoder<$t> for $t {
+            fn encode_json(&self, reversible: bool) -> JsonValue {
+                if reversible {
+                    JsonValue::from(*self as Int32)
+                } else {
+                    JsonValue::from(format!("{:?}_{}", self, *self as Int32))
+                }
+            }
+
+            fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+                let value = Int32::decode_json(value)?;
+                let mut stream = Cursor::new(vec![0u8; 4]);
+                let _ = value.encode(&mut stream)?;
+                stream.set_position(0);
+                $t::decode(&mut stream)
+            }
+        }
+        )*
+    }
+}
+
+json_enum_impl!(MessageSecurityMode, MonitoringMode, UserTokenType, ApplicationType, TimestampsToReturn,
+    NodeClass, DataChangeTrigger, FilterOperator, BrowseDirection, SecurityTokenRequestType, ServerState);
@@ -14,11 +14,12 @@ extern crate serde;
 extern crate byteorder;
 extern crate chrono;
 extern crate regex;
-extern crate ring;
+extern crate rand_core;
 extern crate uuid;
 extern crate url as url_external;
 extern crate base64;
-#[cfg(test)]
+extern crate xmltree;
+#[cfg_attr(test, macro_use)]
 extern crate serde_json;
 
 #[macro_export]
@@ -145,6 +146,10 @@ pub mod supported_message;
 pub mod numeric_range;
 pub mod url;
 pub mod argument;
+pub mod json;
+pub mod xml;
+pub mod type_dictionary;
+pub mod dynamic_structure;
 
 pub use encoding::*;
 pub use basic_types::*;
@@ -162,6 +167,10 @@ pub use supported_message::*;
 pub use numeric_range::*;
 pub use url::*;
 pub use argument::*;
+pub use json::*;
+pub use xml::*;
+pub use type_dictionary::*;
+pub use dynamic_structure::*;
 
 // These mods are not use'd into this mod - too many types
 pub mod service_types;
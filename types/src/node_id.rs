@@ -1,10 +1,12 @@
 use basic_types::*;
 use byte_string::ByteString;
 use encoding::*;
+use json::*;
+use xml::*;
 use guid::Guid;
-use node_ids::{ObjectId, ReferenceTypeId};
+use node_ids::{DataTypeId, ObjectId, ReferenceTypeId};
 use status_codes::StatusCode;
-use status_codes::StatusCode::BadNodeIdInvalid;
+use status_codes::StatusCode::{BadNodeIdInvalid, BadDecodingError};
 use std;
 use std::io::{Read, Write};
 use std::str::FromStr;
@@ -182,6 +184,55 @@ impl BinaryEncoder<NodeId> for NodeId {
     }
 }
 
+/// NodeId encoded as an object with an IdType (omitted for numeric), an Id and a Namespace
+/// (omitted for namespace 0)
+impl JsonEncoder<NodeId> for NodeId {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            let mut map = JsonMap::new();
+            self.write_json_fields(&mut map);
+            JsonValue::Object(map)
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if value.is_null() {
+            Ok(NodeId::null())
+        } else {
+            check_json_object(value)?;
+            if let Some(&JsonValue::String(_)) = value.get("Namespace") {
+                error!("NodeId namespace must be an index");
+                return Err(BadDecodingError);
+            }
+            NodeId::read_json_fields(value)
+        }
+    }
+}
+
+
+/// NodeId encoded as an Identifier element holding its text form, e.g. "ns=1;s=Hello"
+impl XmlEncoder<NodeId> for NodeId {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        if !self.is_null() {
+            write_xml_field(node, "Identifier", &UAString::from(self.to_string()));
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let identifier: UAString = read_xml_field(node, "Identifier")?;
+        if identifier.is_null() {
+            Ok(NodeId::null())
+        } else {
+            NodeId::from_str(identifier.as_ref().trim()).map_err(|_| {
+                error!("NodeId {} is invalid", identifier.as_ref());
+                BadDecodingError
+            })
+        }
+    }
+}
+
 impl FromStr for NodeId {
     type Err = StatusCode;
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -301,6 +352,44 @@ impl<'a> From<(UInt16, &'a str)> for NodeId {
 static NEXT_NODE_ID_NUMERIC: AtomicUsize = ATOMIC_USIZE_INIT;
 
 impl NodeId {
+    /// Writes the IdType, Id and Namespace of the JSON encoding
+    fn write_json_fields(&self, map: &mut JsonMap) {
+        let (id_type, id) = match self.identifier {
+            Identifier::Numeric(ref value) => (0, value.encode_json(true)),
+            Identifier::String(ref value) => (1, value.encode_json(true)),
+            Identifier::Guid(ref value) => (2, value.encode_json(true)),
+            Identifier::ByteString(ref value) => (3, value.encode_json(true)),
+        };
+        if id_type != 0 {
+            map.insert("IdType".to_string(), JsonValue::from(id_type));
+        }
+        map.insert("Id".to_string(), id);
+        if self.namespace != 0 {
+            map.insert("Namespace".to_string(), JsonValue::from(self.namespace));
+        }
+    }
+
+    /// Reads the IdType, Id and Namespace of the JSON encoding. A Namespace which is not an
+    /// index is left for the caller.
+    fn read_json_fields(value: &JsonValue) -> EncodingResult<NodeId> {
+        let id_type: UInt32 = read_json_field(value, "IdType")?;
+        let identifier = match id_type {
+            0 => Identifier::Numeric(read_json_field(value, "Id")?),
+            1 => Identifier::String(read_json_field(value, "Id")?),
+            2 => Identifier::Guid(read_json_field(value, "Id")?),
+            3 => Identifier::ByteString(read_json_field(value, "Id")?),
+            _ => {
+                error!("NodeId IdType {} is invalid", id_type);
+                return Err(BadDecodingError);
+            }
+        };
+        let namespace = match value.get("Namespace") {
+            Some(&JsonValue::String(_)) | None => 0,
+            Some(namespace) => UInt16::decode_json(namespace)?
+        };
+        Ok(NodeId { namespace, identifier })
+    }
+
     // Constructs a new NodeId from anything that can be turned into Identifier
     // UInt32, Guid, ByteString or String
     pub fn new<T>(namespace: UInt16, value: T) -> NodeId where T: 'static + Into<Identifier> {
@@ -339,6 +428,14 @@ impl NodeId {
         }
     }
 
+    /// Extracts a DataTypeId from a node id, providing the node id holds a data type id
+    pub fn as_data_type_id(&self) -> std::result::Result<DataTypeId, ()> {
+        match self.identifier {
+            Identifier::Numeric(id) if self.namespace == 0 => DataTypeId::from_u32(id),
+            _ => Err(())
+        }
+    }
+
     pub fn as_reference_type_id(&self) -> std::result::Result<ReferenceTypeId, ()> {
         match self.identifier {
             Identifier::Numeric(id) if self.namespace == 0 => ReferenceTypeId::from_u32(id),
@@ -507,6 +604,94 @@ impl BinaryEncoder<ExpandedNodeId> for ExpandedNodeId {
     }
 }
 
+/// ExpandedNodeId encoded like a NodeId, except the Namespace is a uri if there is one, and a
+/// ServerUri holds the server index if it is not 0
+impl JsonEncoder<ExpandedNodeId> for ExpandedNodeId {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            let mut map = JsonMap::new();
+            self.node_id.write_json_fields(&mut map);
+            if let Some(ref namespace_uri) = self.namespace_uri.value {
+                map.insert("Namespace".to_string(), JsonValue::String(namespace_uri.clone()));
+            }
+            if self.server_index != 0 {
+                map.insert("ServerUri".to_string(), JsonValue::from(self.server_index));
+            }
+            JsonValue::Object(map)
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if value.is_null() {
+            Ok(ExpandedNodeId::null())
+        } else {
+            check_json_object(value)?;
+            let node_id = NodeId::read_json_fields(value)?;
+            let namespace_uri = if let Some(&JsonValue::String(ref namespace_uri)) = value.get("Namespace") {
+                UAString::from(namespace_uri.as_ref())
+            } else {
+                UAString::null()
+            };
+            let server_index = read_json_field(value, "ServerUri")?;
+            Ok(ExpandedNodeId {
+                node_id,
+                namespace_uri,
+                server_index,
+            })
+        }
+    }
+}
+
+
+/// ExpandedNodeId encoded as an Identifier element holding its text form. The server index and
+/// namespace uri are prefixes, e.g. "svr=1;nsu=urn:foo;s=Hello"
+impl XmlEncoder<ExpandedNodeId> for ExpandedNodeId {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        if !self.is_null() {
+            let mut identifier = String::new();
+            if self.server_index != 0 {
+                identifier.push_str(&format!("svr={};", self.server_index));
+            }
+            if !self.namespace_uri.is_null() {
+                identifier.push_str(&format!("nsu={};", self.namespace_uri.as_ref().replace(";", "%3B")));
+            }
+            identifier.push_str(&self.node_id.to_string());
+            write_xml_field(node, "Identifier", &UAString::from(identifier));
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let identifier: UAString = read_xml_field(node, "Identifier")?;
+        if identifier.is_null() {
+            return Ok(ExpandedNodeId::null());
+        }
+        let mut identifier = identifier.as_ref().trim();
+        let mut server_index = 0;
+        let mut namespace_uri = UAString::null();
+        if identifier.starts_with("svr=") {
+            let end = identifier.find(';').ok_or(BadDecodingError)?;
+            server_index = identifier[4..end].parse::<UInt32>().map_err(|_| BadDecodingError)?;
+            identifier = &identifier[end + 1..];
+        }
+        if identifier.starts_with("nsu=") {
+            let end = identifier.find(';').ok_or(BadDecodingError)?;
+            namespace_uri = UAString::from(identifier[4..end].replace("%3B", ";"));
+            identifier = &identifier[end + 1..];
+        }
+        let node_id = NodeId::from_str(identifier).map_err(|_| {
+            error!("ExpandedNodeId {} is invalid", identifier);
+            BadDecodingError
+        })?;
+        Ok(ExpandedNodeId {
+            node_id,
+            namespace_uri,
+            server_index,
+        })
+    }
+}
+
 impl<'a> Into<ExpandedNodeId> for &'a NodeId {
     fn into(self) -> ExpandedNodeId {
         self.clone().into()
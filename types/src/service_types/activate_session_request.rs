@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -73,3 +75,54 @@ impl BinaryEncoder<ActivateSessionRequest> for ActivateSessionRequest {
         })
     }
 }
+
+impl JsonEncoder<ActivateSessionRequest> for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "ClientSignature", &self.client_signature, reversible);
+        write_json_array_field(&mut map, "ClientSoftwareCertificates", &self.client_software_certificates, reversible);
+        write_json_array_field(&mut map, "LocaleIds", &self.locale_ids, reversible);
+        write_json_field(&mut map, "UserIdentityToken", &self.user_identity_token, reversible);
+        write_json_field(&mut map, "UserTokenSignature", &self.user_token_signature, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ActivateSessionRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            client_signature: read_json_field(value, "ClientSignature")?,
+            client_software_certificates: read_json_array_field(value, "ClientSoftwareCertificates")?,
+            locale_ids: read_json_array_field(value, "LocaleIds")?,
+            user_identity_token: read_json_field(value, "UserIdentityToken")?,
+            user_token_signature: read_json_field(value, "UserTokenSignature")?,
+        })
+    }
+}
+
+impl XmlEncoder<ActivateSessionRequest> for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "ClientSignature", &self.client_signature);
+        write_xml_array_field(node, "ClientSoftwareCertificates", "SignedSoftwareCertificate", &self.client_software_certificates);
+        write_xml_array_field(node, "LocaleIds", "String", &self.locale_ids);
+        write_xml_field(node, "UserIdentityToken", &self.user_identity_token);
+        write_xml_field(node, "UserTokenSignature", &self.user_token_signature);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ActivateSessionRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            client_signature: read_xml_field(node, "ClientSignature")?,
+            client_software_certificates: read_xml_array_field(node, "ClientSoftwareCertificates")?,
+            locale_ids: read_xml_array_field(node, "LocaleIds")?,
+            user_identity_token: read_xml_field(node, "UserIdentityToken")?,
+            user_token_signature: read_xml_field(node, "UserTokenSignature")?,
+        })
+    }
+}
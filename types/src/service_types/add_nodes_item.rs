@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -78,3 +80,58 @@ impl BinaryEncoder<AddNodesItem> for AddNodesItem {
         })
     }
 }
+
+impl JsonEncoder<AddNodesItem> for AddNodesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ParentNodeId", &self.parent_node_id, reversible);
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "RequestedNewNodeId", &self.requested_new_node_id, reversible);
+        write_json_field(&mut map, "BrowseName", &self.browse_name, reversible);
+        write_json_field(&mut map, "NodeClass", &self.node_class, reversible);
+        write_json_field(&mut map, "NodeAttributes", &self.node_attributes, reversible);
+        write_json_field(&mut map, "TypeDefinition", &self.type_definition, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AddNodesItem {
+            parent_node_id: read_json_field(value, "ParentNodeId")?,
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            requested_new_node_id: read_json_field(value, "RequestedNewNodeId")?,
+            browse_name: read_json_field(value, "BrowseName")?,
+            node_class: read_json_field(value, "NodeClass")?,
+            node_attributes: read_json_field(value, "NodeAttributes")?,
+            type_definition: read_json_field(value, "TypeDefinition")?,
+        })
+    }
+}
+
+impl XmlEncoder<AddNodesItem> for AddNodesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ParentNodeId", &self.parent_node_id);
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "RequestedNewNodeId", &self.requested_new_node_id);
+        write_xml_field(node, "BrowseName", &self.browse_name);
+        write_xml_field(node, "NodeClass", &self.node_class);
+        write_xml_field(node, "NodeAttributes", &self.node_attributes);
+        write_xml_field(node, "TypeDefinition", &self.type_definition);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AddNodesItem {
+            parent_node_id: read_xml_field(node, "ParentNodeId")?,
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            requested_new_node_id: read_xml_field(node, "RequestedNewNodeId")?,
+            browse_name: read_xml_field(node, "BrowseName")?,
+            node_class: read_xml_field(node, "NodeClass")?,
+            node_attributes: read_xml_field(node, "NodeAttributes")?,
+            type_definition: read_xml_field(node, "TypeDefinition")?,
+        })
+    }
+}
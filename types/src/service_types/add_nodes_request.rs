@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<AddNodesRequest> for AddNodesRequest {
         })
     }
 }
+
+impl JsonEncoder<AddNodesRequest> for AddNodesRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "NodesToAdd", &self.nodes_to_add, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AddNodesRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            nodes_to_add: read_json_array_field(value, "NodesToAdd")?,
+        })
+    }
+}
+
+impl XmlEncoder<AddNodesRequest> for AddNodesRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "NodesToAdd", "AddNodesItem", &self.nodes_to_add);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AddNodesRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            nodes_to_add: read_xml_array_field(node, "NodesToAdd")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<AddNodesResult> for AddNodesResult {
         })
     }
 }
+
+impl JsonEncoder<AddNodesResult> for AddNodesResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_field(&mut map, "AddedNodeId", &self.added_node_id, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AddNodesResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            added_node_id: read_json_field(value, "AddedNodeId")?,
+        })
+    }
+}
+
+impl XmlEncoder<AddNodesResult> for AddNodesResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_field(node, "AddedNodeId", &self.added_node_id);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AddNodesResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            added_node_id: read_xml_field(node, "AddedNodeId")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -72,3 +74,54 @@ impl BinaryEncoder<AddReferencesItem> for AddReferencesItem {
         })
     }
 }
+
+impl JsonEncoder<AddReferencesItem> for AddReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SourceNodeId", &self.source_node_id, reversible);
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "IsForward", &self.is_forward, reversible);
+        write_json_field(&mut map, "TargetServerUri", &self.target_server_uri, reversible);
+        write_json_field(&mut map, "TargetNodeId", &self.target_node_id, reversible);
+        write_json_field(&mut map, "TargetNodeClass", &self.target_node_class, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AddReferencesItem {
+            source_node_id: read_json_field(value, "SourceNodeId")?,
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            is_forward: read_json_field(value, "IsForward")?,
+            target_server_uri: read_json_field(value, "TargetServerUri")?,
+            target_node_id: read_json_field(value, "TargetNodeId")?,
+            target_node_class: read_json_field(value, "TargetNodeClass")?,
+        })
+    }
+}
+
+impl XmlEncoder<AddReferencesItem> for AddReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SourceNodeId", &self.source_node_id);
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "IsForward", &self.is_forward);
+        write_xml_field(node, "TargetServerUri", &self.target_server_uri);
+        write_xml_field(node, "TargetNodeId", &self.target_node_id);
+        write_xml_field(node, "TargetNodeClass", &self.target_node_class);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AddReferencesItem {
+            source_node_id: read_xml_field(node, "SourceNodeId")?,
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            is_forward: read_xml_field(node, "IsForward")?,
+            target_server_uri: read_xml_field(node, "TargetServerUri")?,
+            target_node_id: read_xml_field(node, "TargetNodeId")?,
+            target_node_class: read_xml_field(node, "TargetNodeClass")?,
+        })
+    }
+}
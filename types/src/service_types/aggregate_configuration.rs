@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -62,3 +64,50 @@ impl BinaryEncoder<AggregateConfiguration> for AggregateConfiguration {
         })
     }
 }
+
+impl JsonEncoder<AggregateConfiguration> for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "UseServerCapabilitiesDefaults", &self.use_server_capabilities_defaults, reversible);
+        write_json_field(&mut map, "TreatUncertainAsBad", &self.treat_uncertain_as_bad, reversible);
+        write_json_field(&mut map, "PercentDataBad", &self.percent_data_bad, reversible);
+        write_json_field(&mut map, "PercentDataGood", &self.percent_data_good, reversible);
+        write_json_field(&mut map, "UseSlopedExtrapolation", &self.use_sloped_extrapolation, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AggregateConfiguration {
+            use_server_capabilities_defaults: read_json_field(value, "UseServerCapabilitiesDefaults")?,
+            treat_uncertain_as_bad: read_json_field(value, "TreatUncertainAsBad")?,
+            percent_data_bad: read_json_field(value, "PercentDataBad")?,
+            percent_data_good: read_json_field(value, "PercentDataGood")?,
+            use_sloped_extrapolation: read_json_field(value, "UseSlopedExtrapolation")?,
+        })
+    }
+}
+
+impl XmlEncoder<AggregateConfiguration> for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "UseServerCapabilitiesDefaults", &self.use_server_capabilities_defaults);
+        write_xml_field(node, "TreatUncertainAsBad", &self.treat_uncertain_as_bad);
+        write_xml_field(node, "PercentDataBad", &self.percent_data_bad);
+        write_xml_field(node, "PercentDataGood", &self.percent_data_good);
+        write_xml_field(node, "UseSlopedExtrapolation", &self.use_sloped_extrapolation);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AggregateConfiguration {
+            use_server_capabilities_defaults: read_xml_field(node, "UseServerCapabilitiesDefaults")?,
+            treat_uncertain_as_bad: read_xml_field(node, "TreatUncertainAsBad")?,
+            percent_data_bad: read_xml_field(node, "PercentDataBad")?,
+            percent_data_good: read_xml_field(node, "PercentDataGood")?,
+            use_sloped_extrapolation: read_xml_field(node, "UseSlopedExtrapolation")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use date_time::DateTime;
@@ -52,3 +54,46 @@ impl BinaryEncoder<AggregateFilter> for AggregateFilter {
         })
     }
 }
+
+impl JsonEncoder<AggregateFilter> for AggregateFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StartTime", &self.start_time, reversible);
+        write_json_field(&mut map, "AggregateType", &self.aggregate_type, reversible);
+        write_json_field(&mut map, "ProcessingInterval", &self.processing_interval, reversible);
+        write_json_field(&mut map, "AggregateConfiguration", &self.aggregate_configuration, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AggregateFilter {
+            start_time: read_json_field(value, "StartTime")?,
+            aggregate_type: read_json_field(value, "AggregateType")?,
+            processing_interval: read_json_field(value, "ProcessingInterval")?,
+            aggregate_configuration: read_json_field(value, "AggregateConfiguration")?,
+        })
+    }
+}
+
+impl XmlEncoder<AggregateFilter> for AggregateFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StartTime", &self.start_time);
+        write_xml_field(node, "AggregateType", &self.aggregate_type);
+        write_xml_field(node, "ProcessingInterval", &self.processing_interval);
+        write_xml_field(node, "AggregateConfiguration", &self.aggregate_configuration);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AggregateFilter {
+            start_time: read_xml_field(node, "StartTime")?,
+            aggregate_type: read_xml_field(node, "AggregateType")?,
+            processing_interval: read_xml_field(node, "ProcessingInterval")?,
+            aggregate_configuration: read_xml_field(node, "AggregateConfiguration")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use date_time::DateTime;
@@ -46,3 +48,42 @@ impl BinaryEncoder<AggregateFilterResult> for AggregateFilterResult {
         })
     }
 }
+
+impl JsonEncoder<AggregateFilterResult> for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RevisedStartTime", &self.revised_start_time, reversible);
+        write_json_field(&mut map, "RevisedProcessingInterval", &self.revised_processing_interval, reversible);
+        write_json_field(&mut map, "RevisedAggregateConfiguration", &self.revised_aggregate_configuration, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AggregateFilterResult {
+            revised_start_time: read_json_field(value, "RevisedStartTime")?,
+            revised_processing_interval: read_json_field(value, "RevisedProcessingInterval")?,
+            revised_aggregate_configuration: read_json_field(value, "RevisedAggregateConfiguration")?,
+        })
+    }
+}
+
+impl XmlEncoder<AggregateFilterResult> for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RevisedStartTime", &self.revised_start_time);
+        write_xml_field(node, "RevisedProcessingInterval", &self.revised_processing_interval);
+        write_xml_field(node, "RevisedAggregateConfiguration", &self.revised_aggregate_configuration);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AggregateFilterResult {
+            revised_start_time: read_xml_field(node, "RevisedStartTime")?,
+            revised_processing_interval: read_xml_field(node, "RevisedProcessingInterval")?,
+            revised_aggregate_configuration: read_xml_field(node, "RevisedAggregateConfiguration")?,
+        })
+    }
+}
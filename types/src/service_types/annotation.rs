@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<Annotation> for Annotation {
         })
     }
 }
+
+impl JsonEncoder<Annotation> for Annotation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Message", &self.message, reversible);
+        write_json_field(&mut map, "UserName", &self.user_name, reversible);
+        write_json_field(&mut map, "AnnotationTime", &self.annotation_time, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(Annotation {
+            message: read_json_field(value, "Message")?,
+            user_name: read_json_field(value, "UserName")?,
+            annotation_time: read_json_field(value, "AnnotationTime")?,
+        })
+    }
+}
+
+impl XmlEncoder<Annotation> for Annotation {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Message", &self.message);
+        write_xml_field(node, "UserName", &self.user_name);
+        write_xml_field(node, "AnnotationTime", &self.annotation_time);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(Annotation {
+            message: read_xml_field(node, "Message")?,
+            user_name: read_xml_field(node, "UserName")?,
+            annotation_time: read_xml_field(node, "AnnotationTime")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use string::UAString;
@@ -36,3 +38,34 @@ impl BinaryEncoder<AnonymousIdentityToken> for AnonymousIdentityToken {
         })
     }
 }
+
+impl JsonEncoder<AnonymousIdentityToken> for AnonymousIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "PolicyId", &self.policy_id, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AnonymousIdentityToken {
+            policy_id: read_json_field(value, "PolicyId")?,
+        })
+    }
+}
+
+impl XmlEncoder<AnonymousIdentityToken> for AnonymousIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "PolicyId", &self.policy_id);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AnonymousIdentityToken {
+            policy_id: read_xml_field(node, "PolicyId")?,
+        })
+    }
+}
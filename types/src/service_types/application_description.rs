@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -76,3 +78,58 @@ impl BinaryEncoder<ApplicationDescription> for ApplicationDescription {
         })
     }
 }
+
+impl JsonEncoder<ApplicationDescription> for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ApplicationUri", &self.application_uri, reversible);
+        write_json_field(&mut map, "ProductUri", &self.product_uri, reversible);
+        write_json_field(&mut map, "ApplicationName", &self.application_name, reversible);
+        write_json_field(&mut map, "ApplicationType", &self.application_type, reversible);
+        write_json_field(&mut map, "GatewayServerUri", &self.gateway_server_uri, reversible);
+        write_json_field(&mut map, "DiscoveryProfileUri", &self.discovery_profile_uri, reversible);
+        write_json_array_field(&mut map, "DiscoveryUrls", &self.discovery_urls, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ApplicationDescription {
+            application_uri: read_json_field(value, "ApplicationUri")?,
+            product_uri: read_json_field(value, "ProductUri")?,
+            application_name: read_json_field(value, "ApplicationName")?,
+            application_type: read_json_field(value, "ApplicationType")?,
+            gateway_server_uri: read_json_field(value, "GatewayServerUri")?,
+            discovery_profile_uri: read_json_field(value, "DiscoveryProfileUri")?,
+            discovery_urls: read_json_array_field(value, "DiscoveryUrls")?,
+        })
+    }
+}
+
+impl XmlEncoder<ApplicationDescription> for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ApplicationUri", &self.application_uri);
+        write_xml_field(node, "ProductUri", &self.product_uri);
+        write_xml_field(node, "ApplicationName", &self.application_name);
+        write_xml_field(node, "ApplicationType", &self.application_type);
+        write_xml_field(node, "GatewayServerUri", &self.gateway_server_uri);
+        write_xml_field(node, "DiscoveryProfileUri", &self.discovery_profile_uri);
+        write_xml_array_field(node, "DiscoveryUrls", "String", &self.discovery_urls);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ApplicationDescription {
+            application_uri: read_xml_field(node, "ApplicationUri")?,
+            product_uri: read_xml_field(node, "ProductUri")?,
+            application_name: read_xml_field(node, "ApplicationName")?,
+            application_type: read_xml_field(node, "ApplicationType")?,
+            gateway_server_uri: read_xml_field(node, "GatewayServerUri")?,
+            discovery_profile_uri: read_xml_field(node, "DiscoveryProfileUri")?,
+            discovery_urls: read_xml_array_field(node, "DiscoveryUrls")?,
+        })
+    }
+}
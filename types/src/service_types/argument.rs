@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -66,3 +68,50 @@ impl BinaryEncoder<Argument> for Argument {
         })
     }
 }
+
+impl JsonEncoder<Argument> for Argument {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Name", &self.name, reversible);
+        write_json_field(&mut map, "DataType", &self.data_type, reversible);
+        write_json_field(&mut map, "ValueRank", &self.value_rank, reversible);
+        write_json_array_field(&mut map, "ArrayDimensions", &self.array_dimensions, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(Argument {
+            name: read_json_field(value, "Name")?,
+            data_type: read_json_field(value, "DataType")?,
+            value_rank: read_json_field(value, "ValueRank")?,
+            array_dimensions: read_json_array_field(value, "ArrayDimensions")?,
+            description: read_json_field(value, "Description")?,
+        })
+    }
+}
+
+impl XmlEncoder<Argument> for Argument {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Name", &self.name);
+        write_xml_field(node, "DataType", &self.data_type);
+        write_xml_field(node, "ValueRank", &self.value_rank);
+        write_xml_array_field(node, "ArrayDimensions", "UInt32", &self.array_dimensions);
+        write_xml_field(node, "Description", &self.description);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(Argument {
+            name: read_xml_field(node, "Name")?,
+            data_type: read_xml_field(node, "DataType")?,
+            value_rank: read_xml_field(node, "ValueRank")?,
+            array_dimensions: read_xml_array_field(node, "ArrayDimensions")?,
+            description: read_xml_field(node, "Description")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use node_id::NodeId;
@@ -57,3 +59,50 @@ impl BinaryEncoder<AttributeOperand> for AttributeOperand {
         })
     }
 }
+
+impl JsonEncoder<AttributeOperand> for AttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "Alias", &self.alias, reversible);
+        write_json_field(&mut map, "BrowsePath", &self.browse_path, reversible);
+        write_json_field(&mut map, "AttributeId", &self.attribute_id, reversible);
+        write_json_field(&mut map, "IndexRange", &self.index_range, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(AttributeOperand {
+            node_id: read_json_field(value, "NodeId")?,
+            alias: read_json_field(value, "Alias")?,
+            browse_path: read_json_field(value, "BrowsePath")?,
+            attribute_id: read_json_field(value, "AttributeId")?,
+            index_range: read_json_field(value, "IndexRange")?,
+        })
+    }
+}
+
+impl XmlEncoder<AttributeOperand> for AttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "Alias", &self.alias);
+        write_xml_field(node, "BrowsePath", &self.browse_path);
+        write_xml_field(node, "AttributeId", &self.attribute_id);
+        write_xml_field(node, "IndexRange", &self.index_range);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(AttributeOperand {
+            node_id: read_xml_field(node, "NodeId")?,
+            alias: read_xml_field(node, "Alias")?,
+            browse_path: read_xml_field(node, "BrowsePath")?,
+            attribute_id: read_xml_field(node, "AttributeId")?,
+            index_range: read_xml_field(node, "IndexRange")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -70,3 +72,54 @@ impl BinaryEncoder<BrowseDescription> for BrowseDescription {
         })
     }
 }
+
+impl JsonEncoder<BrowseDescription> for BrowseDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "BrowseDirection", &self.browse_direction, reversible);
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "IncludeSubtypes", &self.include_subtypes, reversible);
+        write_json_field(&mut map, "NodeClassMask", &self.node_class_mask, reversible);
+        write_json_field(&mut map, "ResultMask", &self.result_mask, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowseDescription {
+            node_id: read_json_field(value, "NodeId")?,
+            browse_direction: read_json_field(value, "BrowseDirection")?,
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            include_subtypes: read_json_field(value, "IncludeSubtypes")?,
+            node_class_mask: read_json_field(value, "NodeClassMask")?,
+            result_mask: read_json_field(value, "ResultMask")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowseDescription> for BrowseDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "BrowseDirection", &self.browse_direction);
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "IncludeSubtypes", &self.include_subtypes);
+        write_xml_field(node, "NodeClassMask", &self.node_class_mask);
+        write_xml_field(node, "ResultMask", &self.result_mask);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowseDescription {
+            node_id: read_xml_field(node, "NodeId")?,
+            browse_direction: read_xml_field(node, "BrowseDirection")?,
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            include_subtypes: read_xml_field(node, "IncludeSubtypes")?,
+            node_class_mask: read_xml_field(node, "NodeClassMask")?,
+            result_mask: read_xml_field(node, "ResultMask")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -55,3 +57,42 @@ impl BinaryEncoder<BrowseNextRequest> for BrowseNextRequest {
         })
     }
 }
+
+impl JsonEncoder<BrowseNextRequest> for BrowseNextRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "ReleaseContinuationPoints", &self.release_continuation_points, reversible);
+        write_json_array_field(&mut map, "ContinuationPoints", &self.continuation_points, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowseNextRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            release_continuation_points: read_json_field(value, "ReleaseContinuationPoints")?,
+            continuation_points: read_json_array_field(value, "ContinuationPoints")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowseNextRequest> for BrowseNextRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "ReleaseContinuationPoints", &self.release_continuation_points);
+        write_xml_array_field(node, "ContinuationPoints", "ByteString", &self.continuation_points);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowseNextRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            release_continuation_points: read_xml_field(node, "ReleaseContinuationPoints")?,
+            continuation_points: read_xml_array_field(node, "ContinuationPoints")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<BrowsePath> for BrowsePath {
         })
     }
 }
+
+impl JsonEncoder<BrowsePath> for BrowsePath {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StartingNode", &self.starting_node, reversible);
+        write_json_field(&mut map, "RelativePath", &self.relative_path, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowsePath {
+            starting_node: read_json_field(value, "StartingNode")?,
+            relative_path: read_json_field(value, "RelativePath")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowsePath> for BrowsePath {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StartingNode", &self.starting_node);
+        write_xml_field(node, "RelativePath", &self.relative_path);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowsePath {
+            starting_node: read_xml_field(node, "StartingNode")?,
+            relative_path: read_xml_field(node, "RelativePath")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<BrowsePathResult> for BrowsePathResult {
         })
     }
 }
+
+impl JsonEncoder<BrowsePathResult> for BrowsePathResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_array_field(&mut map, "Targets", &self.targets, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowsePathResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            targets: read_json_array_field(value, "Targets")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowsePathResult> for BrowsePathResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_array_field(node, "Targets", "BrowsePathTarget", &self.targets);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowsePathResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            targets: read_xml_array_field(node, "Targets")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<BrowsePathTarget> for BrowsePathTarget {
         })
     }
 }
+
+impl JsonEncoder<BrowsePathTarget> for BrowsePathTarget {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "TargetId", &self.target_id, reversible);
+        write_json_field(&mut map, "RemainingPathIndex", &self.remaining_path_index, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowsePathTarget {
+            target_id: read_json_field(value, "TargetId")?,
+            remaining_path_index: read_json_field(value, "RemainingPathIndex")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowsePathTarget> for BrowsePathTarget {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "TargetId", &self.target_id);
+        write_xml_field(node, "RemainingPathIndex", &self.remaining_path_index);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowsePathTarget {
+            target_id: read_xml_field(node, "TargetId")?,
+            remaining_path_index: read_xml_field(node, "RemainingPathIndex")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -61,3 +63,46 @@ impl BinaryEncoder<BrowseRequest> for BrowseRequest {
         })
     }
 }
+
+impl JsonEncoder<BrowseRequest> for BrowseRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "View", &self.view, reversible);
+        write_json_field(&mut map, "RequestedMaxReferencesPerNode", &self.requested_max_references_per_node, reversible);
+        write_json_array_field(&mut map, "NodesToBrowse", &self.nodes_to_browse, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowseRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            view: read_json_field(value, "View")?,
+            requested_max_references_per_node: read_json_field(value, "RequestedMaxReferencesPerNode")?,
+            nodes_to_browse: read_json_array_field(value, "NodesToBrowse")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowseRequest> for BrowseRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "View", &self.view);
+        write_xml_field(node, "RequestedMaxReferencesPerNode", &self.requested_max_references_per_node);
+        write_xml_array_field(node, "NodesToBrowse", "BrowseDescription", &self.nodes_to_browse);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowseRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            view: read_xml_field(node, "View")?,
+            requested_max_references_per_node: read_xml_field(node, "RequestedMaxReferencesPerNode")?,
+            nodes_to_browse: read_xml_array_field(node, "NodesToBrowse")?,
+        })
+    }
+}
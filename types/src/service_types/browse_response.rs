@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -56,3 +58,42 @@ impl BinaryEncoder<BrowseResponse> for BrowseResponse {
         })
     }
 }
+
+impl JsonEncoder<BrowseResponse> for BrowseResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "Results", &self.results, reversible);
+        write_json_array_field(&mut map, "DiagnosticInfos", &self.diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowseResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            results: read_json_array_field(value, "Results")?,
+            diagnostic_infos: read_json_array_field(value, "DiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowseResponse> for BrowseResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "Results", "BrowseResult", &self.results);
+        write_xml_array_field(node, "DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowseResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            results: read_xml_array_field(node, "Results")?,
+            diagnostic_infos: read_xml_array_field(node, "DiagnosticInfos")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -56,3 +58,42 @@ impl BinaryEncoder<BrowseResult> for BrowseResult {
         })
     }
 }
+
+impl JsonEncoder<BrowseResult> for BrowseResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_field(&mut map, "ContinuationPoint", &self.continuation_point, reversible);
+        write_json_array_field(&mut map, "References", &self.references, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BrowseResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            continuation_point: read_json_field(value, "ContinuationPoint")?,
+            references: read_json_array_field(value, "References")?,
+        })
+    }
+}
+
+impl XmlEncoder<BrowseResult> for BrowseResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_field(node, "ContinuationPoint", &self.continuation_point);
+        write_xml_array_field(node, "References", "ReferenceDescription", &self.references);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BrowseResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            continuation_point: read_xml_field(node, "ContinuationPoint")?,
+            references: read_xml_array_field(node, "References")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -69,3 +71,54 @@ impl BinaryEncoder<BuildInfo> for BuildInfo {
         })
     }
 }
+
+impl JsonEncoder<BuildInfo> for BuildInfo {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ProductUri", &self.product_uri, reversible);
+        write_json_field(&mut map, "ManufacturerName", &self.manufacturer_name, reversible);
+        write_json_field(&mut map, "ProductName", &self.product_name, reversible);
+        write_json_field(&mut map, "SoftwareVersion", &self.software_version, reversible);
+        write_json_field(&mut map, "BuildNumber", &self.build_number, reversible);
+        write_json_field(&mut map, "BuildDate", &self.build_date, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(BuildInfo {
+            product_uri: read_json_field(value, "ProductUri")?,
+            manufacturer_name: read_json_field(value, "ManufacturerName")?,
+            product_name: read_json_field(value, "ProductName")?,
+            software_version: read_json_field(value, "SoftwareVersion")?,
+            build_number: read_json_field(value, "BuildNumber")?,
+            build_date: read_json_field(value, "BuildDate")?,
+        })
+    }
+}
+
+impl XmlEncoder<BuildInfo> for BuildInfo {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ProductUri", &self.product_uri);
+        write_xml_field(node, "ManufacturerName", &self.manufacturer_name);
+        write_xml_field(node, "ProductName", &self.product_name);
+        write_xml_field(node, "SoftwareVersion", &self.software_version);
+        write_xml_field(node, "BuildNumber", &self.build_number);
+        write_xml_field(node, "BuildDate", &self.build_date);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(BuildInfo {
+            product_uri: read_xml_field(node, "ProductUri")?,
+            manufacturer_name: read_xml_field(node, "ManufacturerName")?,
+            product_name: read_xml_field(node, "ProductName")?,
+            software_version: read_xml_field(node, "SoftwareVersion")?,
+            build_number: read_xml_field(node, "BuildNumber")?,
+            build_date: read_xml_field(node, "BuildDate")?,
+        })
+    }
+}
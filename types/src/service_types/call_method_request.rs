@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<CallMethodRequest> for CallMethodRequest {
         })
     }
 }
+
+impl JsonEncoder<CallMethodRequest> for CallMethodRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ObjectId", &self.object_id, reversible);
+        write_json_field(&mut map, "MethodId", &self.method_id, reversible);
+        write_json_array_field(&mut map, "InputArguments", &self.input_arguments, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CallMethodRequest {
+            object_id: read_json_field(value, "ObjectId")?,
+            method_id: read_json_field(value, "MethodId")?,
+            input_arguments: read_json_array_field(value, "InputArguments")?,
+        })
+    }
+}
+
+impl XmlEncoder<CallMethodRequest> for CallMethodRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ObjectId", &self.object_id);
+        write_xml_field(node, "MethodId", &self.method_id);
+        write_xml_array_field(node, "InputArguments", "Variant", &self.input_arguments);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CallMethodRequest {
+            object_id: read_xml_field(node, "ObjectId")?,
+            method_id: read_xml_field(node, "MethodId")?,
+            input_arguments: read_xml_array_field(node, "InputArguments")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -60,3 +62,46 @@ impl BinaryEncoder<CallMethodResult> for CallMethodResult {
         })
     }
 }
+
+impl JsonEncoder<CallMethodResult> for CallMethodResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_array_field(&mut map, "InputArgumentResults", &self.input_argument_results, reversible);
+        write_json_array_field(&mut map, "InputArgumentDiagnosticInfos", &self.input_argument_diagnostic_infos, reversible);
+        write_json_array_field(&mut map, "OutputArguments", &self.output_arguments, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CallMethodResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            input_argument_results: read_json_array_field(value, "InputArgumentResults")?,
+            input_argument_diagnostic_infos: read_json_array_field(value, "InputArgumentDiagnosticInfos")?,
+            output_arguments: read_json_array_field(value, "OutputArguments")?,
+        })
+    }
+}
+
+impl XmlEncoder<CallMethodResult> for CallMethodResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_array_field(node, "InputArgumentResults", "StatusCode", &self.input_argument_results);
+        write_xml_array_field(node, "InputArgumentDiagnosticInfos", "DiagnosticInfo", &self.input_argument_diagnostic_infos);
+        write_xml_array_field(node, "OutputArguments", "Variant", &self.output_arguments);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CallMethodResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            input_argument_results: read_xml_array_field(node, "InputArgumentResults")?,
+            input_argument_diagnostic_infos: read_xml_array_field(node, "InputArgumentDiagnosticInfos")?,
+            output_arguments: read_xml_array_field(node, "OutputArguments")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<CallRequest> for CallRequest {
         })
     }
 }
+
+impl JsonEncoder<CallRequest> for CallRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "MethodsToCall", &self.methods_to_call, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CallRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            methods_to_call: read_json_array_field(value, "MethodsToCall")?,
+        })
+    }
+}
+
+impl XmlEncoder<CallRequest> for CallRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "MethodsToCall", "CallMethodRequest", &self.methods_to_call);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CallRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            methods_to_call: read_xml_array_field(node, "MethodsToCall")?,
+        })
+    }
+}
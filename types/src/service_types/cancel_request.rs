@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<CancelRequest> for CancelRequest {
         })
     }
 }
+
+impl JsonEncoder<CancelRequest> for CancelRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "RequestHandle", &self.request_handle, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CancelRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            request_handle: read_json_field(value, "RequestHandle")?,
+        })
+    }
+}
+
+impl XmlEncoder<CancelRequest> for CancelRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "RequestHandle", &self.request_handle);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CancelRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            request_handle: read_xml_field(node, "RequestHandle")?,
+        })
+    }
+}
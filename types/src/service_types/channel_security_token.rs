@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -59,3 +61,46 @@ impl BinaryEncoder<ChannelSecurityToken> for ChannelSecurityToken {
         })
     }
 }
+
+impl JsonEncoder<ChannelSecurityToken> for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ChannelId", &self.channel_id, reversible);
+        write_json_field(&mut map, "TokenId", &self.token_id, reversible);
+        write_json_field(&mut map, "CreatedAt", &self.created_at, reversible);
+        write_json_field(&mut map, "RevisedLifetime", &self.revised_lifetime, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ChannelSecurityToken {
+            channel_id: read_json_field(value, "ChannelId")?,
+            token_id: read_json_field(value, "TokenId")?,
+            created_at: read_json_field(value, "CreatedAt")?,
+            revised_lifetime: read_json_field(value, "RevisedLifetime")?,
+        })
+    }
+}
+
+impl XmlEncoder<ChannelSecurityToken> for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ChannelId", &self.channel_id);
+        write_xml_field(node, "TokenId", &self.token_id);
+        write_xml_field(node, "CreatedAt", &self.created_at);
+        write_xml_field(node, "RevisedLifetime", &self.revised_lifetime);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ChannelSecurityToken {
+            channel_id: read_xml_field(node, "ChannelId")?,
+            token_id: read_xml_field(node, "TokenId")?,
+            created_at: read_xml_field(node, "CreatedAt")?,
+            revised_lifetime: read_xml_field(node, "RevisedLifetime")?,
+        })
+    }
+}
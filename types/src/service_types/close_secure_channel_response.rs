@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -44,3 +46,34 @@ impl BinaryEncoder<CloseSecureChannelResponse> for CloseSecureChannelResponse {
         })
     }
 }
+
+impl JsonEncoder<CloseSecureChannelResponse> for CloseSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CloseSecureChannelResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+        })
+    }
+}
+
+impl XmlEncoder<CloseSecureChannelResponse> for CloseSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CloseSecureChannelResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<CloseSessionRequest> for CloseSessionRequest {
         })
     }
 }
+
+impl JsonEncoder<CloseSessionRequest> for CloseSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "DeleteSubscriptions", &self.delete_subscriptions, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CloseSessionRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            delete_subscriptions: read_json_field(value, "DeleteSubscriptions")?,
+        })
+    }
+}
+
+impl XmlEncoder<CloseSessionRequest> for CloseSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "DeleteSubscriptions", &self.delete_subscriptions);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CloseSessionRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            delete_subscriptions: read_xml_field(node, "DeleteSubscriptions")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -47,3 +49,38 @@ impl BinaryEncoder<ComplexNumberType> for ComplexNumberType {
         })
     }
 }
+
+impl JsonEncoder<ComplexNumberType> for ComplexNumberType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Real", &self.real, reversible);
+        write_json_field(&mut map, "Imaginary", &self.imaginary, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ComplexNumberType {
+            real: read_json_field(value, "Real")?,
+            imaginary: read_json_field(value, "Imaginary")?,
+        })
+    }
+}
+
+impl XmlEncoder<ComplexNumberType> for ComplexNumberType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Real", &self.real);
+        write_xml_field(node, "Imaginary", &self.imaginary);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ComplexNumberType {
+            real: read_xml_field(node, "Real")?,
+            imaginary: read_xml_field(node, "Imaginary")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -43,3 +45,34 @@ impl BinaryEncoder<ContentFilter> for ContentFilter {
         })
     }
 }
+
+impl JsonEncoder<ContentFilter> for ContentFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "Elements", &self.elements, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ContentFilter {
+            elements: read_json_array_field(value, "Elements")?,
+        })
+    }
+}
+
+impl XmlEncoder<ContentFilter> for ContentFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "Elements", "ContentFilterElement", &self.elements);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ContentFilter {
+            elements: read_xml_array_field(node, "Elements")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<ContentFilterElement> for ContentFilterElement {
         })
     }
 }
+
+impl JsonEncoder<ContentFilterElement> for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "FilterOperator", &self.filter_operator, reversible);
+        write_json_array_field(&mut map, "FilterOperands", &self.filter_operands, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ContentFilterElement {
+            filter_operator: read_json_field(value, "FilterOperator")?,
+            filter_operands: read_json_array_field(value, "FilterOperands")?,
+        })
+    }
+}
+
+impl XmlEncoder<ContentFilterElement> for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "FilterOperator", &self.filter_operator);
+        write_xml_array_field(node, "FilterOperands", "ExtensionObject", &self.filter_operands);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ContentFilterElement {
+            filter_operator: read_xml_field(node, "FilterOperator")?,
+            filter_operands: read_xml_array_field(node, "FilterOperands")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<ContentFilterElementResult> for ContentFilterElementResult {
         })
     }
 }
+
+impl JsonEncoder<ContentFilterElementResult> for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_array_field(&mut map, "OperandStatusCodes", &self.operand_status_codes, reversible);
+        write_json_array_field(&mut map, "OperandDiagnosticInfos", &self.operand_diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ContentFilterElementResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            operand_status_codes: read_json_array_field(value, "OperandStatusCodes")?,
+            operand_diagnostic_infos: read_json_array_field(value, "OperandDiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<ContentFilterElementResult> for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_array_field(node, "OperandStatusCodes", "StatusCode", &self.operand_status_codes);
+        write_xml_array_field(node, "OperandDiagnosticInfos", "DiagnosticInfo", &self.operand_diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ContentFilterElementResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            operand_status_codes: read_xml_array_field(node, "OperandStatusCodes")?,
+            operand_diagnostic_infos: read_xml_array_field(node, "OperandDiagnosticInfos")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<ContentFilterResult> for ContentFilterResult {
         })
     }
 }
+
+impl JsonEncoder<ContentFilterResult> for ContentFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "ElementResults", &self.element_results, reversible);
+        write_json_array_field(&mut map, "ElementDiagnosticInfos", &self.element_diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ContentFilterResult {
+            element_results: read_json_array_field(value, "ElementResults")?,
+            element_diagnostic_infos: read_json_array_field(value, "ElementDiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<ContentFilterResult> for ContentFilterResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "ElementResults", "ContentFilterElementResult", &self.element_results);
+        write_xml_array_field(node, "ElementDiagnosticInfos", "DiagnosticInfo", &self.element_diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ContentFilterResult {
+            element_results: read_xml_array_field(node, "ElementResults")?,
+            element_diagnostic_infos: read_xml_array_field(node, "ElementDiagnosticInfos")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -60,3 +62,46 @@ impl BinaryEncoder<CreateMonitoredItemsRequest> for CreateMonitoredItemsRequest
         })
     }
 }
+
+impl JsonEncoder<CreateMonitoredItemsRequest> for CreateMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_field(&mut map, "TimestampsToReturn", &self.timestamps_to_return, reversible);
+        write_json_array_field(&mut map, "ItemsToCreate", &self.items_to_create, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CreateMonitoredItemsRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            timestamps_to_return: read_json_field(value, "TimestampsToReturn")?,
+            items_to_create: read_json_array_field(value, "ItemsToCreate")?,
+        })
+    }
+}
+
+impl XmlEncoder<CreateMonitoredItemsRequest> for CreateMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_field(node, "TimestampsToReturn", &self.timestamps_to_return);
+        write_xml_array_field(node, "ItemsToCreate", "MonitoredItemCreateRequest", &self.items_to_create);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CreateMonitoredItemsRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            timestamps_to_return: read_xml_field(node, "TimestampsToReturn")?,
+            items_to_create: read_xml_array_field(node, "ItemsToCreate")?,
+        })
+    }
+}
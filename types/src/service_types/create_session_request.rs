@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -87,3 +89,66 @@ impl BinaryEncoder<CreateSessionRequest> for CreateSessionRequest {
         })
     }
 }
+
+impl JsonEncoder<CreateSessionRequest> for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "ClientDescription", &self.client_description, reversible);
+        write_json_field(&mut map, "ServerUri", &self.server_uri, reversible);
+        write_json_field(&mut map, "EndpointUrl", &self.endpoint_url, reversible);
+        write_json_field(&mut map, "SessionName", &self.session_name, reversible);
+        write_json_field(&mut map, "ClientNonce", &self.client_nonce, reversible);
+        write_json_field(&mut map, "ClientCertificate", &self.client_certificate, reversible);
+        write_json_field(&mut map, "RequestedSessionTimeout", &self.requested_session_timeout, reversible);
+        write_json_field(&mut map, "MaxResponseMessageSize", &self.max_response_message_size, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CreateSessionRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            client_description: read_json_field(value, "ClientDescription")?,
+            server_uri: read_json_field(value, "ServerUri")?,
+            endpoint_url: read_json_field(value, "EndpointUrl")?,
+            session_name: read_json_field(value, "SessionName")?,
+            client_nonce: read_json_field(value, "ClientNonce")?,
+            client_certificate: read_json_field(value, "ClientCertificate")?,
+            requested_session_timeout: read_json_field(value, "RequestedSessionTimeout")?,
+            max_response_message_size: read_json_field(value, "MaxResponseMessageSize")?,
+        })
+    }
+}
+
+impl XmlEncoder<CreateSessionRequest> for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "ClientDescription", &self.client_description);
+        write_xml_field(node, "ServerUri", &self.server_uri);
+        write_xml_field(node, "EndpointUrl", &self.endpoint_url);
+        write_xml_field(node, "SessionName", &self.session_name);
+        write_xml_field(node, "ClientNonce", &self.client_nonce);
+        write_xml_field(node, "ClientCertificate", &self.client_certificate);
+        write_xml_field(node, "RequestedSessionTimeout", &self.requested_session_timeout);
+        write_xml_field(node, "MaxResponseMessageSize", &self.max_response_message_size);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CreateSessionRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            client_description: read_xml_field(node, "ClientDescription")?,
+            server_uri: read_xml_field(node, "ServerUri")?,
+            endpoint_url: read_xml_field(node, "EndpointUrl")?,
+            session_name: read_xml_field(node, "SessionName")?,
+            client_nonce: read_xml_field(node, "ClientNonce")?,
+            client_certificate: read_xml_field(node, "ClientCertificate")?,
+            requested_session_timeout: read_xml_field(node, "RequestedSessionTimeout")?,
+            max_response_message_size: read_xml_field(node, "MaxResponseMessageSize")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -94,3 +96,70 @@ impl BinaryEncoder<CreateSessionResponse> for CreateSessionResponse {
         })
     }
 }
+
+impl JsonEncoder<CreateSessionResponse> for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_field(&mut map, "SessionId", &self.session_id, reversible);
+        write_json_field(&mut map, "AuthenticationToken", &self.authentication_token, reversible);
+        write_json_field(&mut map, "RevisedSessionTimeout", &self.revised_session_timeout, reversible);
+        write_json_field(&mut map, "ServerNonce", &self.server_nonce, reversible);
+        write_json_field(&mut map, "ServerCertificate", &self.server_certificate, reversible);
+        write_json_array_field(&mut map, "ServerEndpoints", &self.server_endpoints, reversible);
+        write_json_array_field(&mut map, "ServerSoftwareCertificates", &self.server_software_certificates, reversible);
+        write_json_field(&mut map, "ServerSignature", &self.server_signature, reversible);
+        write_json_field(&mut map, "MaxRequestMessageSize", &self.max_request_message_size, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CreateSessionResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            session_id: read_json_field(value, "SessionId")?,
+            authentication_token: read_json_field(value, "AuthenticationToken")?,
+            revised_session_timeout: read_json_field(value, "RevisedSessionTimeout")?,
+            server_nonce: read_json_field(value, "ServerNonce")?,
+            server_certificate: read_json_field(value, "ServerCertificate")?,
+            server_endpoints: read_json_array_field(value, "ServerEndpoints")?,
+            server_software_certificates: read_json_array_field(value, "ServerSoftwareCertificates")?,
+            server_signature: read_json_field(value, "ServerSignature")?,
+            max_request_message_size: read_json_field(value, "MaxRequestMessageSize")?,
+        })
+    }
+}
+
+impl XmlEncoder<CreateSessionResponse> for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_field(node, "SessionId", &self.session_id);
+        write_xml_field(node, "AuthenticationToken", &self.authentication_token);
+        write_xml_field(node, "RevisedSessionTimeout", &self.revised_session_timeout);
+        write_xml_field(node, "ServerNonce", &self.server_nonce);
+        write_xml_field(node, "ServerCertificate", &self.server_certificate);
+        write_xml_array_field(node, "ServerEndpoints", "EndpointDescription", &self.server_endpoints);
+        write_xml_array_field(node, "ServerSoftwareCertificates", "SignedSoftwareCertificate", &self.server_software_certificates);
+        write_xml_field(node, "ServerSignature", &self.server_signature);
+        write_xml_field(node, "MaxRequestMessageSize", &self.max_request_message_size);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CreateSessionResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            session_id: read_xml_field(node, "SessionId")?,
+            authentication_token: read_xml_field(node, "AuthenticationToken")?,
+            revised_session_timeout: read_xml_field(node, "RevisedSessionTimeout")?,
+            server_nonce: read_xml_field(node, "ServerNonce")?,
+            server_certificate: read_xml_field(node, "ServerCertificate")?,
+            server_endpoints: read_xml_array_field(node, "ServerEndpoints")?,
+            server_software_certificates: read_xml_array_field(node, "ServerSoftwareCertificates")?,
+            server_signature: read_xml_field(node, "ServerSignature")?,
+            max_request_message_size: read_xml_field(node, "MaxRequestMessageSize")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -73,3 +75,58 @@ impl BinaryEncoder<CreateSubscriptionRequest> for CreateSubscriptionRequest {
         })
     }
 }
+
+impl JsonEncoder<CreateSubscriptionRequest> for CreateSubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "RequestedPublishingInterval", &self.requested_publishing_interval, reversible);
+        write_json_field(&mut map, "RequestedLifetimeCount", &self.requested_lifetime_count, reversible);
+        write_json_field(&mut map, "RequestedMaxKeepAliveCount", &self.requested_max_keep_alive_count, reversible);
+        write_json_field(&mut map, "MaxNotificationsPerPublish", &self.max_notifications_per_publish, reversible);
+        write_json_field(&mut map, "PublishingEnabled", &self.publishing_enabled, reversible);
+        write_json_field(&mut map, "Priority", &self.priority, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(CreateSubscriptionRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            requested_publishing_interval: read_json_field(value, "RequestedPublishingInterval")?,
+            requested_lifetime_count: read_json_field(value, "RequestedLifetimeCount")?,
+            requested_max_keep_alive_count: read_json_field(value, "RequestedMaxKeepAliveCount")?,
+            max_notifications_per_publish: read_json_field(value, "MaxNotificationsPerPublish")?,
+            publishing_enabled: read_json_field(value, "PublishingEnabled")?,
+            priority: read_json_field(value, "Priority")?,
+        })
+    }
+}
+
+impl XmlEncoder<CreateSubscriptionRequest> for CreateSubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "RequestedPublishingInterval", &self.requested_publishing_interval);
+        write_xml_field(node, "RequestedLifetimeCount", &self.requested_lifetime_count);
+        write_xml_field(node, "RequestedMaxKeepAliveCount", &self.requested_max_keep_alive_count);
+        write_xml_field(node, "MaxNotificationsPerPublish", &self.max_notifications_per_publish);
+        write_xml_field(node, "PublishingEnabled", &self.publishing_enabled);
+        write_xml_field(node, "Priority", &self.priority);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(CreateSubscriptionRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            requested_publishing_interval: read_xml_field(node, "RequestedPublishingInterval")?,
+            requested_lifetime_count: read_xml_field(node, "RequestedLifetimeCount")?,
+            requested_max_keep_alive_count: read_xml_field(node, "RequestedMaxKeepAliveCount")?,
+            max_notifications_per_publish: read_xml_field(node, "MaxNotificationsPerPublish")?,
+            publishing_enabled: read_xml_field(node, "PublishingEnabled")?,
+            priority: read_xml_field(node, "Priority")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::enums::DataChangeTrigger;
@@ -45,3 +47,42 @@ impl BinaryEncoder<DataChangeFilter> for DataChangeFilter {
         })
     }
 }
+
+impl JsonEncoder<DataChangeFilter> for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Trigger", &self.trigger, reversible);
+        write_json_field(&mut map, "DeadbandType", &self.deadband_type, reversible);
+        write_json_field(&mut map, "DeadbandValue", &self.deadband_value, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DataChangeFilter {
+            trigger: read_json_field(value, "Trigger")?,
+            deadband_type: read_json_field(value, "DeadbandType")?,
+            deadband_value: read_json_field(value, "DeadbandValue")?,
+        })
+    }
+}
+
+impl XmlEncoder<DataChangeFilter> for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Trigger", &self.trigger);
+        write_xml_field(node, "DeadbandType", &self.deadband_type);
+        write_xml_field(node, "DeadbandValue", &self.deadband_value);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DataChangeFilter {
+            trigger: read_xml_field(node, "Trigger")?,
+            deadband_type: read_xml_field(node, "DeadbandType")?,
+            deadband_value: read_xml_field(node, "DeadbandValue")?,
+        })
+    }
+}
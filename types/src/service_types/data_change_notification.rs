@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use basic_types::DiagnosticInfo;
@@ -41,3 +43,38 @@ impl BinaryEncoder<DataChangeNotification> for DataChangeNotification {
         })
     }
 }
+
+impl JsonEncoder<DataChangeNotification> for DataChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "MonitoredItems", &self.monitored_items, reversible);
+        write_json_array_field(&mut map, "DiagnosticInfos", &self.diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DataChangeNotification {
+            monitored_items: read_json_array_field(value, "MonitoredItems")?,
+            diagnostic_infos: read_json_array_field(value, "DiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<DataChangeNotification> for DataChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "MonitoredItems", "MonitoredItemNotification", &self.monitored_items);
+        write_xml_array_field(node, "DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DataChangeNotification {
+            monitored_items: read_xml_array_field(node, "MonitoredItems")?,
+            diagnostic_infos: read_xml_array_field(node, "DiagnosticInfos")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use basic_types::LocalizedText;
@@ -61,3 +63,54 @@ impl BinaryEncoder<DataTypeAttributes> for DataTypeAttributes {
         })
     }
 }
+
+impl JsonEncoder<DataTypeAttributes> for DataTypeAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SpecifiedAttributes", &self.specified_attributes, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        write_json_field(&mut map, "WriteMask", &self.write_mask, reversible);
+        write_json_field(&mut map, "UserWriteMask", &self.user_write_mask, reversible);
+        write_json_field(&mut map, "IsAbstract", &self.is_abstract, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DataTypeAttributes {
+            specified_attributes: read_json_field(value, "SpecifiedAttributes")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            description: read_json_field(value, "Description")?,
+            write_mask: read_json_field(value, "WriteMask")?,
+            user_write_mask: read_json_field(value, "UserWriteMask")?,
+            is_abstract: read_json_field(value, "IsAbstract")?,
+        })
+    }
+}
+
+impl XmlEncoder<DataTypeAttributes> for DataTypeAttributes {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SpecifiedAttributes", &self.specified_attributes);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "Description", &self.description);
+        write_xml_field(node, "WriteMask", &self.write_mask);
+        write_xml_field(node, "UserWriteMask", &self.user_write_mask);
+        write_xml_field(node, "IsAbstract", &self.is_abstract);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DataTypeAttributes {
+            specified_attributes: read_xml_field(node, "SpecifiedAttributes")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            description: read_xml_field(node, "Description")?,
+            write_mask: read_xml_field(node, "WriteMask")?,
+            user_write_mask: read_xml_field(node, "UserWriteMask")?,
+            is_abstract: read_xml_field(node, "IsAbstract")?,
+        })
+    }
+}
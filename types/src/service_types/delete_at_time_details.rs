@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use node_id::NodeId;
@@ -41,3 +43,38 @@ impl BinaryEncoder<DeleteAtTimeDetails> for DeleteAtTimeDetails {
         })
     }
 }
+
+impl JsonEncoder<DeleteAtTimeDetails> for DeleteAtTimeDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_array_field(&mut map, "ReqTimes", &self.req_times, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteAtTimeDetails {
+            node_id: read_json_field(value, "NodeId")?,
+            req_times: read_json_array_field(value, "ReqTimes")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteAtTimeDetails> for DeleteAtTimeDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_array_field(node, "ReqTimes", "DateTime", &self.req_times);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteAtTimeDetails {
+            node_id: read_xml_field(node, "NodeId")?,
+            req_times: read_xml_array_field(node, "ReqTimes")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use node_id::NodeId;
@@ -41,3 +43,38 @@ impl BinaryEncoder<DeleteEventDetails> for DeleteEventDetails {
         })
     }
 }
+
+impl JsonEncoder<DeleteEventDetails> for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_array_field(&mut map, "EventIds", &self.event_ids, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteEventDetails {
+            node_id: read_json_field(value, "NodeId")?,
+            event_ids: read_json_array_field(value, "EventIds")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteEventDetails> for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_array_field(node, "EventIds", "ByteString", &self.event_ids);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteEventDetails {
+            node_id: read_xml_field(node, "NodeId")?,
+            event_ids: read_xml_array_field(node, "EventIds")?,
+        })
+    }
+}
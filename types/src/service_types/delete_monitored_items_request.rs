@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -53,3 +55,42 @@ impl BinaryEncoder<DeleteMonitoredItemsRequest> for DeleteMonitoredItemsRequest
         })
     }
 }
+
+impl JsonEncoder<DeleteMonitoredItemsRequest> for DeleteMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_array_field(&mut map, "MonitoredItemIds", &self.monitored_item_ids, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteMonitoredItemsRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            monitored_item_ids: read_json_array_field(value, "MonitoredItemIds")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteMonitoredItemsRequest> for DeleteMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_array_field(node, "MonitoredItemIds", "UInt32", &self.monitored_item_ids);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteMonitoredItemsRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            monitored_item_ids: read_xml_array_field(node, "MonitoredItemIds")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<DeleteNodesItem> for DeleteNodesItem {
         })
     }
 }
+
+impl JsonEncoder<DeleteNodesItem> for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "DeleteTargetReferences", &self.delete_target_references, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteNodesItem {
+            node_id: read_json_field(value, "NodeId")?,
+            delete_target_references: read_json_field(value, "DeleteTargetReferences")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteNodesItem> for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "DeleteTargetReferences", &self.delete_target_references);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteNodesItem {
+            node_id: read_xml_field(node, "NodeId")?,
+            delete_target_references: read_xml_field(node, "DeleteTargetReferences")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use node_id::NodeId;
@@ -51,3 +53,46 @@ impl BinaryEncoder<DeleteRawModifiedDetails> for DeleteRawModifiedDetails {
         })
     }
 }
+
+impl JsonEncoder<DeleteRawModifiedDetails> for DeleteRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "IsDeleteModified", &self.is_delete_modified, reversible);
+        write_json_field(&mut map, "StartTime", &self.start_time, reversible);
+        write_json_field(&mut map, "EndTime", &self.end_time, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteRawModifiedDetails {
+            node_id: read_json_field(value, "NodeId")?,
+            is_delete_modified: read_json_field(value, "IsDeleteModified")?,
+            start_time: read_json_field(value, "StartTime")?,
+            end_time: read_json_field(value, "EndTime")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteRawModifiedDetails> for DeleteRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "IsDeleteModified", &self.is_delete_modified);
+        write_xml_field(node, "StartTime", &self.start_time);
+        write_xml_field(node, "EndTime", &self.end_time);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteRawModifiedDetails {
+            node_id: read_xml_field(node, "NodeId")?,
+            is_delete_modified: read_xml_field(node, "IsDeleteModified")?,
+            start_time: read_xml_field(node, "StartTime")?,
+            end_time: read_xml_field(node, "EndTime")?,
+        })
+    }
+}
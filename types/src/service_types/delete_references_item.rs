@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -65,3 +67,50 @@ impl BinaryEncoder<DeleteReferencesItem> for DeleteReferencesItem {
         })
     }
 }
+
+impl JsonEncoder<DeleteReferencesItem> for DeleteReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SourceNodeId", &self.source_node_id, reversible);
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "IsForward", &self.is_forward, reversible);
+        write_json_field(&mut map, "TargetNodeId", &self.target_node_id, reversible);
+        write_json_field(&mut map, "DeleteBidirectional", &self.delete_bidirectional, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteReferencesItem {
+            source_node_id: read_json_field(value, "SourceNodeId")?,
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            is_forward: read_json_field(value, "IsForward")?,
+            target_node_id: read_json_field(value, "TargetNodeId")?,
+            delete_bidirectional: read_json_field(value, "DeleteBidirectional")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteReferencesItem> for DeleteReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SourceNodeId", &self.source_node_id);
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "IsForward", &self.is_forward);
+        write_xml_field(node, "TargetNodeId", &self.target_node_id);
+        write_xml_field(node, "DeleteBidirectional", &self.delete_bidirectional);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteReferencesItem {
+            source_node_id: read_xml_field(node, "SourceNodeId")?,
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            is_forward: read_xml_field(node, "IsForward")?,
+            target_node_id: read_xml_field(node, "TargetNodeId")?,
+            delete_bidirectional: read_xml_field(node, "DeleteBidirectional")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<DeleteReferencesRequest> for DeleteReferencesRequest {
         })
     }
 }
+
+impl JsonEncoder<DeleteReferencesRequest> for DeleteReferencesRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "ReferencesToDelete", &self.references_to_delete, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DeleteReferencesRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            references_to_delete: read_json_array_field(value, "ReferencesToDelete")?,
+        })
+    }
+}
+
+impl XmlEncoder<DeleteReferencesRequest> for DeleteReferencesRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "ReferencesToDelete", "DeleteReferencesItem", &self.references_to_delete);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DeleteReferencesRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            references_to_delete: read_xml_array_field(node, "ReferencesToDelete")?,
+        })
+    }
+}
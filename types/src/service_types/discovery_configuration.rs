@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -36,3 +38,29 @@ impl BinaryEncoder<DiscoveryConfiguration> for DiscoveryConfiguration {
         })
     }
 }
+
+impl JsonEncoder<DiscoveryConfiguration> for DiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        JsonValue::Object(JsonMap::new())
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(DiscoveryConfiguration {
+        })
+    }
+}
+
+impl XmlEncoder<DiscoveryConfiguration> for DiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(DiscoveryConfiguration {
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 
@@ -34,3 +36,34 @@ impl BinaryEncoder<ElementOperand> for ElementOperand {
         })
     }
 }
+
+impl JsonEncoder<ElementOperand> for ElementOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Index", &self.index, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ElementOperand {
+            index: read_json_field(value, "Index")?,
+        })
+    }
+}
+
+impl XmlEncoder<ElementOperand> for ElementOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Index", &self.index);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ElementOperand {
+            index: read_xml_field(node, "Index")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -82,3 +84,66 @@ impl BinaryEncoder<EndpointConfiguration> for EndpointConfiguration {
         })
     }
 }
+
+impl JsonEncoder<EndpointConfiguration> for EndpointConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "OperationTimeout", &self.operation_timeout, reversible);
+        write_json_field(&mut map, "UseBinaryEncoding", &self.use_binary_encoding, reversible);
+        write_json_field(&mut map, "MaxStringLength", &self.max_string_length, reversible);
+        write_json_field(&mut map, "MaxByteStringLength", &self.max_byte_string_length, reversible);
+        write_json_field(&mut map, "MaxArrayLength", &self.max_array_length, reversible);
+        write_json_field(&mut map, "MaxMessageSize", &self.max_message_size, reversible);
+        write_json_field(&mut map, "MaxBufferSize", &self.max_buffer_size, reversible);
+        write_json_field(&mut map, "ChannelLifetime", &self.channel_lifetime, reversible);
+        write_json_field(&mut map, "SecurityTokenLifetime", &self.security_token_lifetime, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EndpointConfiguration {
+            operation_timeout: read_json_field(value, "OperationTimeout")?,
+            use_binary_encoding: read_json_field(value, "UseBinaryEncoding")?,
+            max_string_length: read_json_field(value, "MaxStringLength")?,
+            max_byte_string_length: read_json_field(value, "MaxByteStringLength")?,
+            max_array_length: read_json_field(value, "MaxArrayLength")?,
+            max_message_size: read_json_field(value, "MaxMessageSize")?,
+            max_buffer_size: read_json_field(value, "MaxBufferSize")?,
+            channel_lifetime: read_json_field(value, "ChannelLifetime")?,
+            security_token_lifetime: read_json_field(value, "SecurityTokenLifetime")?,
+        })
+    }
+}
+
+impl XmlEncoder<EndpointConfiguration> for EndpointConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "OperationTimeout", &self.operation_timeout);
+        write_xml_field(node, "UseBinaryEncoding", &self.use_binary_encoding);
+        write_xml_field(node, "MaxStringLength", &self.max_string_length);
+        write_xml_field(node, "MaxByteStringLength", &self.max_byte_string_length);
+        write_xml_field(node, "MaxArrayLength", &self.max_array_length);
+        write_xml_field(node, "MaxMessageSize", &self.max_message_size);
+        write_xml_field(node, "MaxBufferSize", &self.max_buffer_size);
+        write_xml_field(node, "ChannelLifetime", &self.channel_lifetime);
+        write_xml_field(node, "SecurityTokenLifetime", &self.security_token_lifetime);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EndpointConfiguration {
+            operation_timeout: read_xml_field(node, "OperationTimeout")?,
+            use_binary_encoding: read_xml_field(node, "UseBinaryEncoding")?,
+            max_string_length: read_xml_field(node, "MaxStringLength")?,
+            max_byte_string_length: read_xml_field(node, "MaxByteStringLength")?,
+            max_array_length: read_xml_field(node, "MaxArrayLength")?,
+            max_message_size: read_xml_field(node, "MaxMessageSize")?,
+            max_buffer_size: read_xml_field(node, "MaxBufferSize")?,
+            channel_lifetime: read_xml_field(node, "ChannelLifetime")?,
+            security_token_lifetime: read_xml_field(node, "SecurityTokenLifetime")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -83,3 +85,62 @@ impl BinaryEncoder<EndpointDescription> for EndpointDescription {
         })
     }
 }
+
+impl JsonEncoder<EndpointDescription> for EndpointDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "EndpointUrl", &self.endpoint_url, reversible);
+        write_json_field(&mut map, "Server", &self.server, reversible);
+        write_json_field(&mut map, "ServerCertificate", &self.server_certificate, reversible);
+        write_json_field(&mut map, "SecurityMode", &self.security_mode, reversible);
+        write_json_field(&mut map, "SecurityPolicyUri", &self.security_policy_uri, reversible);
+        write_json_array_field(&mut map, "UserIdentityTokens", &self.user_identity_tokens, reversible);
+        write_json_field(&mut map, "TransportProfileUri", &self.transport_profile_uri, reversible);
+        write_json_field(&mut map, "SecurityLevel", &self.security_level, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EndpointDescription {
+            endpoint_url: read_json_field(value, "EndpointUrl")?,
+            server: read_json_field(value, "Server")?,
+            server_certificate: read_json_field(value, "ServerCertificate")?,
+            security_mode: read_json_field(value, "SecurityMode")?,
+            security_policy_uri: read_json_field(value, "SecurityPolicyUri")?,
+            user_identity_tokens: read_json_array_field(value, "UserIdentityTokens")?,
+            transport_profile_uri: read_json_field(value, "TransportProfileUri")?,
+            security_level: read_json_field(value, "SecurityLevel")?,
+        })
+    }
+}
+
+impl XmlEncoder<EndpointDescription> for EndpointDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "EndpointUrl", &self.endpoint_url);
+        write_xml_field(node, "Server", &self.server);
+        write_xml_field(node, "ServerCertificate", &self.server_certificate);
+        write_xml_field(node, "SecurityMode", &self.security_mode);
+        write_xml_field(node, "SecurityPolicyUri", &self.security_policy_uri);
+        write_xml_array_field(node, "UserIdentityTokens", "UserTokenPolicy", &self.user_identity_tokens);
+        write_xml_field(node, "TransportProfileUri", &self.transport_profile_uri);
+        write_xml_field(node, "SecurityLevel", &self.security_level);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EndpointDescription {
+            endpoint_url: read_xml_field(node, "EndpointUrl")?,
+            server: read_xml_field(node, "Server")?,
+            server_certificate: read_xml_field(node, "ServerCertificate")?,
+            security_mode: read_xml_field(node, "SecurityMode")?,
+            security_policy_uri: read_xml_field(node, "SecurityPolicyUri")?,
+            user_identity_tokens: read_xml_array_field(node, "UserIdentityTokens")?,
+            transport_profile_uri: read_xml_field(node, "TransportProfileUri")?,
+            security_level: read_xml_field(node, "SecurityLevel")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -43,3 +45,34 @@ impl BinaryEncoder<EndpointUrlListDataType> for EndpointUrlListDataType {
         })
     }
 }
+
+impl JsonEncoder<EndpointUrlListDataType> for EndpointUrlListDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "EndpointUrlList", &self.endpoint_url_list, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EndpointUrlListDataType {
+            endpoint_url_list: read_json_array_field(value, "EndpointUrlList")?,
+        })
+    }
+}
+
+impl XmlEncoder<EndpointUrlListDataType> for EndpointUrlListDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "EndpointUrlList", "String", &self.endpoint_url_list);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EndpointUrlListDataType {
+            endpoint_url_list: read_xml_array_field(node, "EndpointUrlList")?,
+        })
+    }
+}
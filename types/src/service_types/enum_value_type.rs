@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<EnumValueType> for EnumValueType {
         })
     }
 }
+
+impl JsonEncoder<EnumValueType> for EnumValueType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EnumValueType {
+            value: read_json_field(value, "Value")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            description: read_json_field(value, "Description")?,
+        })
+    }
+}
+
+impl XmlEncoder<EnumValueType> for EnumValueType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Value", &self.value);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "Description", &self.description);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EnumValueType {
+            value: read_xml_field(node, "Value")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            description: read_xml_field(node, "Description")?,
+        })
+    }
+}
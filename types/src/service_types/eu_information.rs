@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -59,3 +61,46 @@ impl BinaryEncoder<EUInformation> for EUInformation {
         })
     }
 }
+
+impl JsonEncoder<EUInformation> for EUInformation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NamespaceUri", &self.namespace_uri, reversible);
+        write_json_field(&mut map, "UnitId", &self.unit_id, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EUInformation {
+            namespace_uri: read_json_field(value, "NamespaceUri")?,
+            unit_id: read_json_field(value, "UnitId")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            description: read_json_field(value, "Description")?,
+        })
+    }
+}
+
+impl XmlEncoder<EUInformation> for EUInformation {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NamespaceUri", &self.namespace_uri);
+        write_xml_field(node, "UnitId", &self.unit_id);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "Description", &self.description);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EUInformation {
+            namespace_uri: read_xml_field(node, "NamespaceUri")?,
+            unit_id: read_xml_field(node, "UnitId")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            description: read_xml_field(node, "Description")?,
+        })
+    }
+}
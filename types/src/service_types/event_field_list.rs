@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -48,3 +50,38 @@ impl BinaryEncoder<EventFieldList> for EventFieldList {
         })
     }
 }
+
+impl JsonEncoder<EventFieldList> for EventFieldList {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ClientHandle", &self.client_handle, reversible);
+        write_json_array_field(&mut map, "EventFields", &self.event_fields, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EventFieldList {
+            client_handle: read_json_field(value, "ClientHandle")?,
+            event_fields: read_json_array_field(value, "EventFields")?,
+        })
+    }
+}
+
+impl XmlEncoder<EventFieldList> for EventFieldList {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ClientHandle", &self.client_handle);
+        write_xml_array_field(node, "EventFields", "Variant", &self.event_fields);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EventFieldList {
+            client_handle: read_xml_field(node, "ClientHandle")?,
+            event_fields: read_xml_array_field(node, "EventFields")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::SimpleAttributeOperand;
@@ -41,3 +43,38 @@ impl BinaryEncoder<EventFilter> for EventFilter {
         })
     }
 }
+
+impl JsonEncoder<EventFilter> for EventFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "SelectClauses", &self.select_clauses, reversible);
+        write_json_field(&mut map, "WhereClause", &self.where_clause, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EventFilter {
+            select_clauses: read_json_array_field(value, "SelectClauses")?,
+            where_clause: read_json_field(value, "WhereClause")?,
+        })
+    }
+}
+
+impl XmlEncoder<EventFilter> for EventFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "SelectClauses", "SimpleAttributeOperand", &self.select_clauses);
+        write_xml_field(node, "WhereClause", &self.where_clause);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EventFilter {
+            select_clauses: read_xml_array_field(node, "SelectClauses")?,
+            where_clause: read_xml_field(node, "WhereClause")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use status_codes::StatusCode;
@@ -47,3 +49,42 @@ impl BinaryEncoder<EventFilterResult> for EventFilterResult {
         })
     }
 }
+
+impl JsonEncoder<EventFilterResult> for EventFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "SelectClauseResults", &self.select_clause_results, reversible);
+        write_json_array_field(&mut map, "SelectClauseDiagnosticInfos", &self.select_clause_diagnostic_infos, reversible);
+        write_json_field(&mut map, "WhereClauseResult", &self.where_clause_result, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EventFilterResult {
+            select_clause_results: read_json_array_field(value, "SelectClauseResults")?,
+            select_clause_diagnostic_infos: read_json_array_field(value, "SelectClauseDiagnosticInfos")?,
+            where_clause_result: read_json_field(value, "WhereClauseResult")?,
+        })
+    }
+}
+
+impl XmlEncoder<EventFilterResult> for EventFilterResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "SelectClauseResults", "StatusCode", &self.select_clause_results);
+        write_xml_array_field(node, "SelectClauseDiagnosticInfos", "DiagnosticInfo", &self.select_clause_diagnostic_infos);
+        write_xml_field(node, "WhereClauseResult", &self.where_clause_result);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EventFilterResult {
+            select_clause_results: read_xml_array_field(node, "SelectClauseResults")?,
+            select_clause_diagnostic_infos: read_xml_array_field(node, "SelectClauseDiagnosticInfos")?,
+            where_clause_result: read_xml_field(node, "WhereClauseResult")?,
+        })
+    }
+}
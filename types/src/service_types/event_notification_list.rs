@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::EventFieldList;
@@ -35,3 +37,34 @@ impl BinaryEncoder<EventNotificationList> for EventNotificationList {
         })
     }
 }
+
+impl JsonEncoder<EventNotificationList> for EventNotificationList {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "Events", &self.events, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(EventNotificationList {
+            events: read_json_array_field(value, "Events")?,
+        })
+    }
+}
+
+impl XmlEncoder<EventNotificationList> for EventNotificationList {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "Events", "EventFieldList", &self.events);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(EventNotificationList {
+            events: read_xml_array_field(node, "Events")?,
+        })
+    }
+}
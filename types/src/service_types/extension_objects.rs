@@ -0,0 +1,643 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use encoding::*;
+use json::*;
+use xml::*;
+use byte_string::ByteString;
+use node_ids::{DataTypeId, ObjectId};
+use service_types::*;
+
+/// Encodes the binary body of an extension object as JSON. Returns the data type id of the type
+/// with the body, or None if the object id is not the binary encoding of a known type.
+pub fn encode_json_body(object_id: ObjectId, body: &ByteString, reversible: bool) -> Option<(DataTypeId, EncodingResult<JsonValue>)> {
+    match object_id {
+        ObjectId::TrustListDataType_Encoding_DefaultBinary => Some((DataTypeId::TrustListDataType, binary_body_to_json::<TrustListDataType>(body, reversible))),
+        ObjectId::Argument_Encoding_DefaultBinary => Some((DataTypeId::Argument, binary_body_to_json::<Argument>(body, reversible))),
+        ObjectId::EnumValueType_Encoding_DefaultBinary => Some((DataTypeId::EnumValueType, binary_body_to_json::<EnumValueType>(body, reversible))),
+        ObjectId::OptionSet_Encoding_DefaultBinary => Some((DataTypeId::OptionSet, binary_body_to_json::<OptionSet>(body, reversible))),
+        ObjectId::TimeZoneDataType_Encoding_DefaultBinary => Some((DataTypeId::TimeZoneDataType, binary_body_to_json::<TimeZoneDataType>(body, reversible))),
+        ObjectId::ApplicationDescription_Encoding_DefaultBinary => Some((DataTypeId::ApplicationDescription, binary_body_to_json::<ApplicationDescription>(body, reversible))),
+        ObjectId::ServiceFault_Encoding_DefaultBinary => Some((DataTypeId::ServiceFault, binary_body_to_json::<ServiceFault>(body, reversible))),
+        ObjectId::FindServersRequest_Encoding_DefaultBinary => Some((DataTypeId::FindServersRequest, binary_body_to_json::<FindServersRequest>(body, reversible))),
+        ObjectId::FindServersResponse_Encoding_DefaultBinary => Some((DataTypeId::FindServersResponse, binary_body_to_json::<FindServersResponse>(body, reversible))),
+        ObjectId::ServerOnNetwork_Encoding_DefaultBinary => Some((DataTypeId::ServerOnNetwork, binary_body_to_json::<ServerOnNetwork>(body, reversible))),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary => Some((DataTypeId::FindServersOnNetworkRequest, binary_body_to_json::<FindServersOnNetworkRequest>(body, reversible))),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary => Some((DataTypeId::FindServersOnNetworkResponse, binary_body_to_json::<FindServersOnNetworkResponse>(body, reversible))),
+        ObjectId::UserTokenPolicy_Encoding_DefaultBinary => Some((DataTypeId::UserTokenPolicy, binary_body_to_json::<UserTokenPolicy>(body, reversible))),
+        ObjectId::EndpointDescription_Encoding_DefaultBinary => Some((DataTypeId::EndpointDescription, binary_body_to_json::<EndpointDescription>(body, reversible))),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => Some((DataTypeId::GetEndpointsRequest, binary_body_to_json::<GetEndpointsRequest>(body, reversible))),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultBinary => Some((DataTypeId::GetEndpointsResponse, binary_body_to_json::<GetEndpointsResponse>(body, reversible))),
+        ObjectId::RegisteredServer_Encoding_DefaultBinary => Some((DataTypeId::RegisteredServer, binary_body_to_json::<RegisteredServer>(body, reversible))),
+        ObjectId::RegisterServerRequest_Encoding_DefaultBinary => Some((DataTypeId::RegisterServerRequest, binary_body_to_json::<RegisterServerRequest>(body, reversible))),
+        ObjectId::RegisterServerResponse_Encoding_DefaultBinary => Some((DataTypeId::RegisterServerResponse, binary_body_to_json::<RegisterServerResponse>(body, reversible))),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary => Some((DataTypeId::DiscoveryConfiguration, binary_body_to_json::<DiscoveryConfiguration>(body, reversible))),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultBinary => Some((DataTypeId::ChannelSecurityToken, binary_body_to_json::<ChannelSecurityToken>(body, reversible))),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => Some((DataTypeId::OpenSecureChannelRequest, binary_body_to_json::<OpenSecureChannelRequest>(body, reversible))),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary => Some((DataTypeId::OpenSecureChannelResponse, binary_body_to_json::<OpenSecureChannelResponse>(body, reversible))),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => Some((DataTypeId::CloseSecureChannelRequest, binary_body_to_json::<CloseSecureChannelRequest>(body, reversible))),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary => Some((DataTypeId::CloseSecureChannelResponse, binary_body_to_json::<CloseSecureChannelResponse>(body, reversible))),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary => Some((DataTypeId::SignedSoftwareCertificate, binary_body_to_json::<SignedSoftwareCertificate>(body, reversible))),
+        ObjectId::SignatureData_Encoding_DefaultBinary => Some((DataTypeId::SignatureData, binary_body_to_json::<SignatureData>(body, reversible))),
+        ObjectId::CreateSessionRequest_Encoding_DefaultBinary => Some((DataTypeId::CreateSessionRequest, binary_body_to_json::<CreateSessionRequest>(body, reversible))),
+        ObjectId::CreateSessionResponse_Encoding_DefaultBinary => Some((DataTypeId::CreateSessionResponse, binary_body_to_json::<CreateSessionResponse>(body, reversible))),
+        ObjectId::UserIdentityToken_Encoding_DefaultBinary => Some((DataTypeId::UserIdentityToken, binary_body_to_json::<UserIdentityToken>(body, reversible))),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => Some((DataTypeId::ActivateSessionRequest, binary_body_to_json::<ActivateSessionRequest>(body, reversible))),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultBinary => Some((DataTypeId::ActivateSessionResponse, binary_body_to_json::<ActivateSessionResponse>(body, reversible))),
+        ObjectId::CloseSessionRequest_Encoding_DefaultBinary => Some((DataTypeId::CloseSessionRequest, binary_body_to_json::<CloseSessionRequest>(body, reversible))),
+        ObjectId::CloseSessionResponse_Encoding_DefaultBinary => Some((DataTypeId::CloseSessionResponse, binary_body_to_json::<CloseSessionResponse>(body, reversible))),
+        ObjectId::CancelRequest_Encoding_DefaultBinary => Some((DataTypeId::CancelRequest, binary_body_to_json::<CancelRequest>(body, reversible))),
+        ObjectId::CancelResponse_Encoding_DefaultBinary => Some((DataTypeId::CancelResponse, binary_body_to_json::<CancelResponse>(body, reversible))),
+        ObjectId::NodeAttributes_Encoding_DefaultBinary => Some((DataTypeId::NodeAttributes, binary_body_to_json::<NodeAttributes>(body, reversible))),
+        ObjectId::AddNodesItem_Encoding_DefaultBinary => Some((DataTypeId::AddNodesItem, binary_body_to_json::<AddNodesItem>(body, reversible))),
+        ObjectId::AddNodesResult_Encoding_DefaultBinary => Some((DataTypeId::AddNodesResult, binary_body_to_json::<AddNodesResult>(body, reversible))),
+        ObjectId::AddNodesRequest_Encoding_DefaultBinary => Some((DataTypeId::AddNodesRequest, binary_body_to_json::<AddNodesRequest>(body, reversible))),
+        ObjectId::AddNodesResponse_Encoding_DefaultBinary => Some((DataTypeId::AddNodesResponse, binary_body_to_json::<AddNodesResponse>(body, reversible))),
+        ObjectId::AddReferencesItem_Encoding_DefaultBinary => Some((DataTypeId::AddReferencesItem, binary_body_to_json::<AddReferencesItem>(body, reversible))),
+        ObjectId::AddReferencesRequest_Encoding_DefaultBinary => Some((DataTypeId::AddReferencesRequest, binary_body_to_json::<AddReferencesRequest>(body, reversible))),
+        ObjectId::AddReferencesResponse_Encoding_DefaultBinary => Some((DataTypeId::AddReferencesResponse, binary_body_to_json::<AddReferencesResponse>(body, reversible))),
+        ObjectId::DeleteNodesItem_Encoding_DefaultBinary => Some((DataTypeId::DeleteNodesItem, binary_body_to_json::<DeleteNodesItem>(body, reversible))),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => Some((DataTypeId::DeleteNodesRequest, binary_body_to_json::<DeleteNodesRequest>(body, reversible))),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => Some((DataTypeId::DeleteNodesResponse, binary_body_to_json::<DeleteNodesResponse>(body, reversible))),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultBinary => Some((DataTypeId::DeleteReferencesItem, binary_body_to_json::<DeleteReferencesItem>(body, reversible))),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => Some((DataTypeId::DeleteReferencesRequest, binary_body_to_json::<DeleteReferencesRequest>(body, reversible))),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => Some((DataTypeId::DeleteReferencesResponse, binary_body_to_json::<DeleteReferencesResponse>(body, reversible))),
+        ObjectId::ViewDescription_Encoding_DefaultBinary => Some((DataTypeId::ViewDescription, binary_body_to_json::<ViewDescription>(body, reversible))),
+        ObjectId::BrowseDescription_Encoding_DefaultBinary => Some((DataTypeId::BrowseDescription, binary_body_to_json::<BrowseDescription>(body, reversible))),
+        ObjectId::ReferenceDescription_Encoding_DefaultBinary => Some((DataTypeId::ReferenceDescription, binary_body_to_json::<ReferenceDescription>(body, reversible))),
+        ObjectId::BrowseResult_Encoding_DefaultBinary => Some((DataTypeId::BrowseResult, binary_body_to_json::<BrowseResult>(body, reversible))),
+        ObjectId::BrowseRequest_Encoding_DefaultBinary => Some((DataTypeId::BrowseRequest, binary_body_to_json::<BrowseRequest>(body, reversible))),
+        ObjectId::BrowseResponse_Encoding_DefaultBinary => Some((DataTypeId::BrowseResponse, binary_body_to_json::<BrowseResponse>(body, reversible))),
+        ObjectId::BrowseNextRequest_Encoding_DefaultBinary => Some((DataTypeId::BrowseNextRequest, binary_body_to_json::<BrowseNextRequest>(body, reversible))),
+        ObjectId::BrowseNextResponse_Encoding_DefaultBinary => Some((DataTypeId::BrowseNextResponse, binary_body_to_json::<BrowseNextResponse>(body, reversible))),
+        ObjectId::RelativePathElement_Encoding_DefaultBinary => Some((DataTypeId::RelativePathElement, binary_body_to_json::<RelativePathElement>(body, reversible))),
+        ObjectId::RelativePath_Encoding_DefaultBinary => Some((DataTypeId::RelativePath, binary_body_to_json::<RelativePath>(body, reversible))),
+        ObjectId::BrowsePath_Encoding_DefaultBinary => Some((DataTypeId::BrowsePath, binary_body_to_json::<BrowsePath>(body, reversible))),
+        ObjectId::BrowsePathTarget_Encoding_DefaultBinary => Some((DataTypeId::BrowsePathTarget, binary_body_to_json::<BrowsePathTarget>(body, reversible))),
+        ObjectId::BrowsePathResult_Encoding_DefaultBinary => Some((DataTypeId::BrowsePathResult, binary_body_to_json::<BrowsePathResult>(body, reversible))),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => Some((DataTypeId::TranslateBrowsePathsToNodeIdsRequest, binary_body_to_json::<TranslateBrowsePathsToNodeIdsRequest>(body, reversible))),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => Some((DataTypeId::TranslateBrowsePathsToNodeIdsResponse, binary_body_to_json::<TranslateBrowsePathsToNodeIdsResponse>(body, reversible))),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => Some((DataTypeId::RegisterNodesRequest, binary_body_to_json::<RegisterNodesRequest>(body, reversible))),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => Some((DataTypeId::RegisterNodesResponse, binary_body_to_json::<RegisterNodesResponse>(body, reversible))),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => Some((DataTypeId::UnregisterNodesRequest, binary_body_to_json::<UnregisterNodesRequest>(body, reversible))),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => Some((DataTypeId::UnregisterNodesResponse, binary_body_to_json::<UnregisterNodesResponse>(body, reversible))),
+        ObjectId::EndpointConfiguration_Encoding_DefaultBinary => Some((DataTypeId::EndpointConfiguration, binary_body_to_json::<EndpointConfiguration>(body, reversible))),
+        ObjectId::QueryDataDescription_Encoding_DefaultBinary => Some((DataTypeId::QueryDataDescription, binary_body_to_json::<QueryDataDescription>(body, reversible))),
+        ObjectId::NodeTypeDescription_Encoding_DefaultBinary => Some((DataTypeId::NodeTypeDescription, binary_body_to_json::<NodeTypeDescription>(body, reversible))),
+        ObjectId::QueryDataSet_Encoding_DefaultBinary => Some((DataTypeId::QueryDataSet, binary_body_to_json::<QueryDataSet>(body, reversible))),
+        ObjectId::NodeReference_Encoding_DefaultBinary => Some((DataTypeId::NodeReference, binary_body_to_json::<NodeReference>(body, reversible))),
+        ObjectId::ContentFilterElement_Encoding_DefaultBinary => Some((DataTypeId::ContentFilterElement, binary_body_to_json::<ContentFilterElement>(body, reversible))),
+        ObjectId::ContentFilter_Encoding_DefaultBinary => Some((DataTypeId::ContentFilter, binary_body_to_json::<ContentFilter>(body, reversible))),
+        ObjectId::FilterOperand_Encoding_DefaultBinary => Some((DataTypeId::FilterOperand, binary_body_to_json::<FilterOperand>(body, reversible))),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultBinary => Some((DataTypeId::ContentFilterElementResult, binary_body_to_json::<ContentFilterElementResult>(body, reversible))),
+        ObjectId::ContentFilterResult_Encoding_DefaultBinary => Some((DataTypeId::ContentFilterResult, binary_body_to_json::<ContentFilterResult>(body, reversible))),
+        ObjectId::ParsingResult_Encoding_DefaultBinary => Some((DataTypeId::ParsingResult, binary_body_to_json::<ParsingResult>(body, reversible))),
+        ObjectId::QueryFirstRequest_Encoding_DefaultBinary => Some((DataTypeId::QueryFirstRequest, binary_body_to_json::<QueryFirstRequest>(body, reversible))),
+        ObjectId::QueryFirstResponse_Encoding_DefaultBinary => Some((DataTypeId::QueryFirstResponse, binary_body_to_json::<QueryFirstResponse>(body, reversible))),
+        ObjectId::QueryNextRequest_Encoding_DefaultBinary => Some((DataTypeId::QueryNextRequest, binary_body_to_json::<QueryNextRequest>(body, reversible))),
+        ObjectId::QueryNextResponse_Encoding_DefaultBinary => Some((DataTypeId::QueryNextResponse, binary_body_to_json::<QueryNextResponse>(body, reversible))),
+        ObjectId::ReadValueId_Encoding_DefaultBinary => Some((DataTypeId::ReadValueId, binary_body_to_json::<ReadValueId>(body, reversible))),
+        ObjectId::ReadRequest_Encoding_DefaultBinary => Some((DataTypeId::ReadRequest, binary_body_to_json::<ReadRequest>(body, reversible))),
+        ObjectId::ReadResponse_Encoding_DefaultBinary => Some((DataTypeId::ReadResponse, binary_body_to_json::<ReadResponse>(body, reversible))),
+        ObjectId::WriteValue_Encoding_DefaultBinary => Some((DataTypeId::WriteValue, binary_body_to_json::<WriteValue>(body, reversible))),
+        ObjectId::WriteRequest_Encoding_DefaultBinary => Some((DataTypeId::WriteRequest, binary_body_to_json::<WriteRequest>(body, reversible))),
+        ObjectId::WriteResponse_Encoding_DefaultBinary => Some((DataTypeId::WriteResponse, binary_body_to_json::<WriteResponse>(body, reversible))),
+        ObjectId::CallMethodRequest_Encoding_DefaultBinary => Some((DataTypeId::CallMethodRequest, binary_body_to_json::<CallMethodRequest>(body, reversible))),
+        ObjectId::CallMethodResult_Encoding_DefaultBinary => Some((DataTypeId::CallMethodResult, binary_body_to_json::<CallMethodResult>(body, reversible))),
+        ObjectId::CallRequest_Encoding_DefaultBinary => Some((DataTypeId::CallRequest, binary_body_to_json::<CallRequest>(body, reversible))),
+        ObjectId::CallResponse_Encoding_DefaultBinary => Some((DataTypeId::CallResponse, binary_body_to_json::<CallResponse>(body, reversible))),
+        ObjectId::MonitoringFilter_Encoding_DefaultBinary => Some((DataTypeId::MonitoringFilter, binary_body_to_json::<MonitoringFilter>(body, reversible))),
+        ObjectId::AggregateConfiguration_Encoding_DefaultBinary => Some((DataTypeId::AggregateConfiguration, binary_body_to_json::<AggregateConfiguration>(body, reversible))),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultBinary => Some((DataTypeId::MonitoringFilterResult, binary_body_to_json::<MonitoringFilterResult>(body, reversible))),
+        ObjectId::MonitoringParameters_Encoding_DefaultBinary => Some((DataTypeId::MonitoringParameters, binary_body_to_json::<MonitoringParameters>(body, reversible))),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary => Some((DataTypeId::MonitoredItemCreateRequest, binary_body_to_json::<MonitoredItemCreateRequest>(body, reversible))),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary => Some((DataTypeId::MonitoredItemCreateResult, binary_body_to_json::<MonitoredItemCreateResult>(body, reversible))),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => Some((DataTypeId::CreateMonitoredItemsRequest, binary_body_to_json::<CreateMonitoredItemsRequest>(body, reversible))),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary => Some((DataTypeId::CreateMonitoredItemsResponse, binary_body_to_json::<CreateMonitoredItemsResponse>(body, reversible))),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary => Some((DataTypeId::MonitoredItemModifyRequest, binary_body_to_json::<MonitoredItemModifyRequest>(body, reversible))),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary => Some((DataTypeId::MonitoredItemModifyResult, binary_body_to_json::<MonitoredItemModifyResult>(body, reversible))),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => Some((DataTypeId::ModifyMonitoredItemsRequest, binary_body_to_json::<ModifyMonitoredItemsRequest>(body, reversible))),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary => Some((DataTypeId::ModifyMonitoredItemsResponse, binary_body_to_json::<ModifyMonitoredItemsResponse>(body, reversible))),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => Some((DataTypeId::SetMonitoringModeRequest, binary_body_to_json::<SetMonitoringModeRequest>(body, reversible))),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => Some((DataTypeId::SetMonitoringModeResponse, binary_body_to_json::<SetMonitoringModeResponse>(body, reversible))),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => Some((DataTypeId::SetTriggeringRequest, binary_body_to_json::<SetTriggeringRequest>(body, reversible))),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => Some((DataTypeId::SetTriggeringResponse, binary_body_to_json::<SetTriggeringResponse>(body, reversible))),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => Some((DataTypeId::DeleteMonitoredItemsRequest, binary_body_to_json::<DeleteMonitoredItemsRequest>(body, reversible))),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => Some((DataTypeId::DeleteMonitoredItemsResponse, binary_body_to_json::<DeleteMonitoredItemsResponse>(body, reversible))),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => Some((DataTypeId::CreateSubscriptionRequest, binary_body_to_json::<CreateSubscriptionRequest>(body, reversible))),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary => Some((DataTypeId::CreateSubscriptionResponse, binary_body_to_json::<CreateSubscriptionResponse>(body, reversible))),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => Some((DataTypeId::ModifySubscriptionRequest, binary_body_to_json::<ModifySubscriptionRequest>(body, reversible))),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary => Some((DataTypeId::ModifySubscriptionResponse, binary_body_to_json::<ModifySubscriptionResponse>(body, reversible))),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => Some((DataTypeId::SetPublishingModeRequest, binary_body_to_json::<SetPublishingModeRequest>(body, reversible))),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary => Some((DataTypeId::SetPublishingModeResponse, binary_body_to_json::<SetPublishingModeResponse>(body, reversible))),
+        ObjectId::NotificationMessage_Encoding_DefaultBinary => Some((DataTypeId::NotificationMessage, binary_body_to_json::<NotificationMessage>(body, reversible))),
+        ObjectId::NotificationData_Encoding_DefaultBinary => Some((DataTypeId::NotificationData, binary_body_to_json::<NotificationData>(body, reversible))),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultBinary => Some((DataTypeId::MonitoredItemNotification, binary_body_to_json::<MonitoredItemNotification>(body, reversible))),
+        ObjectId::EventFieldList_Encoding_DefaultBinary => Some((DataTypeId::EventFieldList, binary_body_to_json::<EventFieldList>(body, reversible))),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary => Some((DataTypeId::SubscriptionAcknowledgement, binary_body_to_json::<SubscriptionAcknowledgement>(body, reversible))),
+        ObjectId::PublishRequest_Encoding_DefaultBinary => Some((DataTypeId::PublishRequest, binary_body_to_json::<PublishRequest>(body, reversible))),
+        ObjectId::PublishResponse_Encoding_DefaultBinary => Some((DataTypeId::PublishResponse, binary_body_to_json::<PublishResponse>(body, reversible))),
+        ObjectId::RepublishRequest_Encoding_DefaultBinary => Some((DataTypeId::RepublishRequest, binary_body_to_json::<RepublishRequest>(body, reversible))),
+        ObjectId::RepublishResponse_Encoding_DefaultBinary => Some((DataTypeId::RepublishResponse, binary_body_to_json::<RepublishResponse>(body, reversible))),
+        ObjectId::TransferResult_Encoding_DefaultBinary => Some((DataTypeId::TransferResult, binary_body_to_json::<TransferResult>(body, reversible))),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => Some((DataTypeId::TransferSubscriptionsRequest, binary_body_to_json::<TransferSubscriptionsRequest>(body, reversible))),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => Some((DataTypeId::TransferSubscriptionsResponse, binary_body_to_json::<TransferSubscriptionsResponse>(body, reversible))),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => Some((DataTypeId::DeleteSubscriptionsRequest, binary_body_to_json::<DeleteSubscriptionsRequest>(body, reversible))),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary => Some((DataTypeId::DeleteSubscriptionsResponse, binary_body_to_json::<DeleteSubscriptionsResponse>(body, reversible))),
+        ObjectId::BuildInfo_Encoding_DefaultBinary => Some((DataTypeId::BuildInfo, binary_body_to_json::<BuildInfo>(body, reversible))),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary => Some((DataTypeId::EndpointUrlListDataType, binary_body_to_json::<EndpointUrlListDataType>(body, reversible))),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultBinary => Some((DataTypeId::NetworkGroupDataType, binary_body_to_json::<NetworkGroupDataType>(body, reversible))),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary => Some((DataTypeId::SamplingIntervalDiagnosticsDataType, binary_body_to_json::<SamplingIntervalDiagnosticsDataType>(body, reversible))),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary => Some((DataTypeId::ServerDiagnosticsSummaryDataType, binary_body_to_json::<ServerDiagnosticsSummaryDataType>(body, reversible))),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary => Some((DataTypeId::SessionDiagnosticsDataType, binary_body_to_json::<SessionDiagnosticsDataType>(body, reversible))),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary => Some((DataTypeId::SessionSecurityDiagnosticsDataType, binary_body_to_json::<SessionSecurityDiagnosticsDataType>(body, reversible))),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultBinary => Some((DataTypeId::ServiceCounterDataType, binary_body_to_json::<ServiceCounterDataType>(body, reversible))),
+        ObjectId::StatusResult_Encoding_DefaultBinary => Some((DataTypeId::StatusResult, binary_body_to_json::<StatusResult>(body, reversible))),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary => Some((DataTypeId::SubscriptionDiagnosticsDataType, binary_body_to_json::<SubscriptionDiagnosticsDataType>(body, reversible))),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary => Some((DataTypeId::ModelChangeStructureDataType, binary_body_to_json::<ModelChangeStructureDataType>(body, reversible))),
+        ObjectId::Range_Encoding_DefaultBinary => Some((DataTypeId::Range, binary_body_to_json::<Range>(body, reversible))),
+        ObjectId::EUInformation_Encoding_DefaultBinary => Some((DataTypeId::EUInformation, binary_body_to_json::<EUInformation>(body, reversible))),
+        ObjectId::ComplexNumberType_Encoding_DefaultBinary => Some((DataTypeId::ComplexNumberType, binary_body_to_json::<ComplexNumberType>(body, reversible))),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary => Some((DataTypeId::DoubleComplexNumberType, binary_body_to_json::<DoubleComplexNumberType>(body, reversible))),
+        ObjectId::XVType_Encoding_DefaultBinary => Some((DataTypeId::XVType, binary_body_to_json::<XVType>(body, reversible))),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary => Some((DataTypeId::ProgramDiagnosticDataType, binary_body_to_json::<ProgramDiagnosticDataType>(body, reversible))),
+        ObjectId::Annotation_Encoding_DefaultBinary => Some((DataTypeId::Annotation, binary_body_to_json::<Annotation>(body, reversible))),
+        _ => None
+    }
+}
+
+/// Decodes the JSON body of an extension object into a binary body. Returns the binary encoding id
+/// of the type with the body, or None if the data type id is not a known type.
+pub fn decode_json_body(data_type_id: DataTypeId, body: &JsonValue) -> Option<(ObjectId, EncodingResult<ByteString>)> {
+    match data_type_id {
+        DataTypeId::TrustListDataType => Some((ObjectId::TrustListDataType_Encoding_DefaultBinary, json_body_to_binary::<TrustListDataType>(body))),
+        DataTypeId::Argument => Some((ObjectId::Argument_Encoding_DefaultBinary, json_body_to_binary::<Argument>(body))),
+        DataTypeId::EnumValueType => Some((ObjectId::EnumValueType_Encoding_DefaultBinary, json_body_to_binary::<EnumValueType>(body))),
+        DataTypeId::OptionSet => Some((ObjectId::OptionSet_Encoding_DefaultBinary, json_body_to_binary::<OptionSet>(body))),
+        DataTypeId::TimeZoneDataType => Some((ObjectId::TimeZoneDataType_Encoding_DefaultBinary, json_body_to_binary::<TimeZoneDataType>(body))),
+        DataTypeId::ApplicationDescription => Some((ObjectId::ApplicationDescription_Encoding_DefaultBinary, json_body_to_binary::<ApplicationDescription>(body))),
+        DataTypeId::ServiceFault => Some((ObjectId::ServiceFault_Encoding_DefaultBinary, json_body_to_binary::<ServiceFault>(body))),
+        DataTypeId::FindServersRequest => Some((ObjectId::FindServersRequest_Encoding_DefaultBinary, json_body_to_binary::<FindServersRequest>(body))),
+        DataTypeId::FindServersResponse => Some((ObjectId::FindServersResponse_Encoding_DefaultBinary, json_body_to_binary::<FindServersResponse>(body))),
+        DataTypeId::ServerOnNetwork => Some((ObjectId::ServerOnNetwork_Encoding_DefaultBinary, json_body_to_binary::<ServerOnNetwork>(body))),
+        DataTypeId::FindServersOnNetworkRequest => Some((ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary, json_body_to_binary::<FindServersOnNetworkRequest>(body))),
+        DataTypeId::FindServersOnNetworkResponse => Some((ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary, json_body_to_binary::<FindServersOnNetworkResponse>(body))),
+        DataTypeId::UserTokenPolicy => Some((ObjectId::UserTokenPolicy_Encoding_DefaultBinary, json_body_to_binary::<UserTokenPolicy>(body))),
+        DataTypeId::EndpointDescription => Some((ObjectId::EndpointDescription_Encoding_DefaultBinary, json_body_to_binary::<EndpointDescription>(body))),
+        DataTypeId::GetEndpointsRequest => Some((ObjectId::GetEndpointsRequest_Encoding_DefaultBinary, json_body_to_binary::<GetEndpointsRequest>(body))),
+        DataTypeId::GetEndpointsResponse => Some((ObjectId::GetEndpointsResponse_Encoding_DefaultBinary, json_body_to_binary::<GetEndpointsResponse>(body))),
+        DataTypeId::RegisteredServer => Some((ObjectId::RegisteredServer_Encoding_DefaultBinary, json_body_to_binary::<RegisteredServer>(body))),
+        DataTypeId::RegisterServerRequest => Some((ObjectId::RegisterServerRequest_Encoding_DefaultBinary, json_body_to_binary::<RegisterServerRequest>(body))),
+        DataTypeId::RegisterServerResponse => Some((ObjectId::RegisterServerResponse_Encoding_DefaultBinary, json_body_to_binary::<RegisterServerResponse>(body))),
+        DataTypeId::DiscoveryConfiguration => Some((ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary, json_body_to_binary::<DiscoveryConfiguration>(body))),
+        DataTypeId::ChannelSecurityToken => Some((ObjectId::ChannelSecurityToken_Encoding_DefaultBinary, json_body_to_binary::<ChannelSecurityToken>(body))),
+        DataTypeId::OpenSecureChannelRequest => Some((ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary, json_body_to_binary::<OpenSecureChannelRequest>(body))),
+        DataTypeId::OpenSecureChannelResponse => Some((ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary, json_body_to_binary::<OpenSecureChannelResponse>(body))),
+        DataTypeId::CloseSecureChannelRequest => Some((ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary, json_body_to_binary::<CloseSecureChannelRequest>(body))),
+        DataTypeId::CloseSecureChannelResponse => Some((ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary, json_body_to_binary::<CloseSecureChannelResponse>(body))),
+        DataTypeId::SignedSoftwareCertificate => Some((ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary, json_body_to_binary::<SignedSoftwareCertificate>(body))),
+        DataTypeId::SignatureData => Some((ObjectId::SignatureData_Encoding_DefaultBinary, json_body_to_binary::<SignatureData>(body))),
+        DataTypeId::CreateSessionRequest => Some((ObjectId::CreateSessionRequest_Encoding_DefaultBinary, json_body_to_binary::<CreateSessionRequest>(body))),
+        DataTypeId::CreateSessionResponse => Some((ObjectId::CreateSessionResponse_Encoding_DefaultBinary, json_body_to_binary::<CreateSessionResponse>(body))),
+        DataTypeId::UserIdentityToken => Some((ObjectId::UserIdentityToken_Encoding_DefaultBinary, json_body_to_binary::<UserIdentityToken>(body))),
+        DataTypeId::ActivateSessionRequest => Some((ObjectId::ActivateSessionRequest_Encoding_DefaultBinary, json_body_to_binary::<ActivateSessionRequest>(body))),
+        DataTypeId::ActivateSessionResponse => Some((ObjectId::ActivateSessionResponse_Encoding_DefaultBinary, json_body_to_binary::<ActivateSessionResponse>(body))),
+        DataTypeId::CloseSessionRequest => Some((ObjectId::CloseSessionRequest_Encoding_DefaultBinary, json_body_to_binary::<CloseSessionRequest>(body))),
+        DataTypeId::CloseSessionResponse => Some((ObjectId::CloseSessionResponse_Encoding_DefaultBinary, json_body_to_binary::<CloseSessionResponse>(body))),
+        DataTypeId::CancelRequest => Some((ObjectId::CancelRequest_Encoding_DefaultBinary, json_body_to_binary::<CancelRequest>(body))),
+        DataTypeId::CancelResponse => Some((ObjectId::CancelResponse_Encoding_DefaultBinary, json_body_to_binary::<CancelResponse>(body))),
+        DataTypeId::NodeAttributes => Some((ObjectId::NodeAttributes_Encoding_DefaultBinary, json_body_to_binary::<NodeAttributes>(body))),
+        DataTypeId::AddNodesItem => Some((ObjectId::AddNodesItem_Encoding_DefaultBinary, json_body_to_binary::<AddNodesItem>(body))),
+        DataTypeId::AddNodesResult => Some((ObjectId::AddNodesResult_Encoding_DefaultBinary, json_body_to_binary::<AddNodesResult>(body))),
+        DataTypeId::AddNodesRequest => Some((ObjectId::AddNodesRequest_Encoding_DefaultBinary, json_body_to_binary::<AddNodesRequest>(body))),
+        DataTypeId::AddNodesResponse => Some((ObjectId::AddNodesResponse_Encoding_DefaultBinary, json_body_to_binary::<AddNodesResponse>(body))),
+        DataTypeId::AddReferencesItem => Some((ObjectId::AddReferencesItem_Encoding_DefaultBinary, json_body_to_binary::<AddReferencesItem>(body))),
+        DataTypeId::AddReferencesRequest => Some((ObjectId::AddReferencesRequest_Encoding_DefaultBinary, json_body_to_binary::<AddReferencesRequest>(body))),
+        DataTypeId::AddReferencesResponse => Some((ObjectId::AddReferencesResponse_Encoding_DefaultBinary, json_body_to_binary::<AddReferencesResponse>(body))),
+        DataTypeId::DeleteNodesItem => Some((ObjectId::DeleteNodesItem_Encoding_DefaultBinary, json_body_to_binary::<DeleteNodesItem>(body))),
+        DataTypeId::DeleteNodesRequest => Some((ObjectId::DeleteNodesRequest_Encoding_DefaultBinary, json_body_to_binary::<DeleteNodesRequest>(body))),
+        DataTypeId::DeleteNodesResponse => Some((ObjectId::DeleteNodesResponse_Encoding_DefaultBinary, json_body_to_binary::<DeleteNodesResponse>(body))),
+        DataTypeId::DeleteReferencesItem => Some((ObjectId::DeleteReferencesItem_Encoding_DefaultBinary, json_body_to_binary::<DeleteReferencesItem>(body))),
+        DataTypeId::DeleteReferencesRequest => Some((ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary, json_body_to_binary::<DeleteReferencesRequest>(body))),
+        DataTypeId::DeleteReferencesResponse => Some((ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary, json_body_to_binary::<DeleteReferencesResponse>(body))),
+        DataTypeId::ViewDescription => Some((ObjectId::ViewDescription_Encoding_DefaultBinary, json_body_to_binary::<ViewDescription>(body))),
+        DataTypeId::BrowseDescription => Some((ObjectId::BrowseDescription_Encoding_DefaultBinary, json_body_to_binary::<BrowseDescription>(body))),
+        DataTypeId::ReferenceDescription => Some((ObjectId::ReferenceDescription_Encoding_DefaultBinary, json_body_to_binary::<ReferenceDescription>(body))),
+        DataTypeId::BrowseResult => Some((ObjectId::BrowseResult_Encoding_DefaultBinary, json_body_to_binary::<BrowseResult>(body))),
+        DataTypeId::BrowseRequest => Some((ObjectId::BrowseRequest_Encoding_DefaultBinary, json_body_to_binary::<BrowseRequest>(body))),
+        DataTypeId::BrowseResponse => Some((ObjectId::BrowseResponse_Encoding_DefaultBinary, json_body_to_binary::<BrowseResponse>(body))),
+        DataTypeId::BrowseNextRequest => Some((ObjectId::BrowseNextRequest_Encoding_DefaultBinary, json_body_to_binary::<BrowseNextRequest>(body))),
+        DataTypeId::BrowseNextResponse => Some((ObjectId::BrowseNextResponse_Encoding_DefaultBinary, json_body_to_binary::<BrowseNextResponse>(body))),
+        DataTypeId::RelativePathElement => Some((ObjectId::RelativePathElement_Encoding_DefaultBinary, json_body_to_binary::<RelativePathElement>(body))),
+        DataTypeId::RelativePath => Some((ObjectId::RelativePath_Encoding_DefaultBinary, json_body_to_binary::<RelativePath>(body))),
+        DataTypeId::BrowsePath => Some((ObjectId::BrowsePath_Encoding_DefaultBinary, json_body_to_binary::<BrowsePath>(body))),
+        DataTypeId::BrowsePathTarget => Some((ObjectId::BrowsePathTarget_Encoding_DefaultBinary, json_body_to_binary::<BrowsePathTarget>(body))),
+        DataTypeId::BrowsePathResult => Some((ObjectId::BrowsePathResult_Encoding_DefaultBinary, json_body_to_binary::<BrowsePathResult>(body))),
+        DataTypeId::TranslateBrowsePathsToNodeIdsRequest => Some((ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary, json_body_to_binary::<TranslateBrowsePathsToNodeIdsRequest>(body))),
+        DataTypeId::TranslateBrowsePathsToNodeIdsResponse => Some((ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary, json_body_to_binary::<TranslateBrowsePathsToNodeIdsResponse>(body))),
+        DataTypeId::RegisterNodesRequest => Some((ObjectId::RegisterNodesRequest_Encoding_DefaultBinary, json_body_to_binary::<RegisterNodesRequest>(body))),
+        DataTypeId::RegisterNodesResponse => Some((ObjectId::RegisterNodesResponse_Encoding_DefaultBinary, json_body_to_binary::<RegisterNodesResponse>(body))),
+        DataTypeId::UnregisterNodesRequest => Some((ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary, json_body_to_binary::<UnregisterNodesRequest>(body))),
+        DataTypeId::UnregisterNodesResponse => Some((ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary, json_body_to_binary::<UnregisterNodesResponse>(body))),
+        DataTypeId::EndpointConfiguration => Some((ObjectId::EndpointConfiguration_Encoding_DefaultBinary, json_body_to_binary::<EndpointConfiguration>(body))),
+        DataTypeId::QueryDataDescription => Some((ObjectId::QueryDataDescription_Encoding_DefaultBinary, json_body_to_binary::<QueryDataDescription>(body))),
+        DataTypeId::NodeTypeDescription => Some((ObjectId::NodeTypeDescription_Encoding_DefaultBinary, json_body_to_binary::<NodeTypeDescription>(body))),
+        DataTypeId::QueryDataSet => Some((ObjectId::QueryDataSet_Encoding_DefaultBinary, json_body_to_binary::<QueryDataSet>(body))),
+        DataTypeId::NodeReference => Some((ObjectId::NodeReference_Encoding_DefaultBinary, json_body_to_binary::<NodeReference>(body))),
+        DataTypeId::ContentFilterElement => Some((ObjectId::ContentFilterElement_Encoding_DefaultBinary, json_body_to_binary::<ContentFilterElement>(body))),
+        DataTypeId::ContentFilter => Some((ObjectId::ContentFilter_Encoding_DefaultBinary, json_body_to_binary::<ContentFilter>(body))),
+        DataTypeId::FilterOperand => Some((ObjectId::FilterOperand_Encoding_DefaultBinary, json_body_to_binary::<FilterOperand>(body))),
+        DataTypeId::ContentFilterElementResult => Some((ObjectId::ContentFilterElementResult_Encoding_DefaultBinary, json_body_to_binary::<ContentFilterElementResult>(body))),
+        DataTypeId::ContentFilterResult => Some((ObjectId::ContentFilterResult_Encoding_DefaultBinary, json_body_to_binary::<ContentFilterResult>(body))),
+        DataTypeId::ParsingResult => Some((ObjectId::ParsingResult_Encoding_DefaultBinary, json_body_to_binary::<ParsingResult>(body))),
+        DataTypeId::QueryFirstRequest => Some((ObjectId::QueryFirstRequest_Encoding_DefaultBinary, json_body_to_binary::<QueryFirstRequest>(body))),
+        DataTypeId::QueryFirstResponse => Some((ObjectId::QueryFirstResponse_Encoding_DefaultBinary, json_body_to_binary::<QueryFirstResponse>(body))),
+        DataTypeId::QueryNextRequest => Some((ObjectId::QueryNextRequest_Encoding_DefaultBinary, json_body_to_binary::<QueryNextRequest>(body))),
+        DataTypeId::QueryNextResponse => Some((ObjectId::QueryNextResponse_Encoding_DefaultBinary, json_body_to_binary::<QueryNextResponse>(body))),
+        DataTypeId::ReadValueId => Some((ObjectId::ReadValueId_Encoding_DefaultBinary, json_body_to_binary::<ReadValueId>(body))),
+        DataTypeId::ReadRequest => Some((ObjectId::ReadRequest_Encoding_DefaultBinary, json_body_to_binary::<ReadRequest>(body))),
+        DataTypeId::ReadResponse => Some((ObjectId::ReadResponse_Encoding_DefaultBinary, json_body_to_binary::<ReadResponse>(body))),
+        DataTypeId::WriteValue => Some((ObjectId::WriteValue_Encoding_DefaultBinary, json_body_to_binary::<WriteValue>(body))),
+        DataTypeId::WriteRequest => Some((ObjectId::WriteRequest_Encoding_DefaultBinary, json_body_to_binary::<WriteRequest>(body))),
+        DataTypeId::WriteResponse => Some((ObjectId::WriteResponse_Encoding_DefaultBinary, json_body_to_binary::<WriteResponse>(body))),
+        DataTypeId::CallMethodRequest => Some((ObjectId::CallMethodRequest_Encoding_DefaultBinary, json_body_to_binary::<CallMethodRequest>(body))),
+        DataTypeId::CallMethodResult => Some((ObjectId::CallMethodResult_Encoding_DefaultBinary, json_body_to_binary::<CallMethodResult>(body))),
+        DataTypeId::CallRequest => Some((ObjectId::CallRequest_Encoding_DefaultBinary, json_body_to_binary::<CallRequest>(body))),
+        DataTypeId::CallResponse => Some((ObjectId::CallResponse_Encoding_DefaultBinary, json_body_to_binary::<CallResponse>(body))),
+        DataTypeId::MonitoringFilter => Some((ObjectId::MonitoringFilter_Encoding_DefaultBinary, json_body_to_binary::<MonitoringFilter>(body))),
+        DataTypeId::AggregateConfiguration => Some((ObjectId::AggregateConfiguration_Encoding_DefaultBinary, json_body_to_binary::<AggregateConfiguration>(body))),
+        DataTypeId::MonitoringFilterResult => Some((ObjectId::MonitoringFilterResult_Encoding_DefaultBinary, json_body_to_binary::<MonitoringFilterResult>(body))),
+        DataTypeId::MonitoringParameters => Some((ObjectId::MonitoringParameters_Encoding_DefaultBinary, json_body_to_binary::<MonitoringParameters>(body))),
+        DataTypeId::MonitoredItemCreateRequest => Some((ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary, json_body_to_binary::<MonitoredItemCreateRequest>(body))),
+        DataTypeId::MonitoredItemCreateResult => Some((ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary, json_body_to_binary::<MonitoredItemCreateResult>(body))),
+        DataTypeId::CreateMonitoredItemsRequest => Some((ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary, json_body_to_binary::<CreateMonitoredItemsRequest>(body))),
+        DataTypeId::CreateMonitoredItemsResponse => Some((ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary, json_body_to_binary::<CreateMonitoredItemsResponse>(body))),
+        DataTypeId::MonitoredItemModifyRequest => Some((ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary, json_body_to_binary::<MonitoredItemModifyRequest>(body))),
+        DataTypeId::MonitoredItemModifyResult => Some((ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary, json_body_to_binary::<MonitoredItemModifyResult>(body))),
+        DataTypeId::ModifyMonitoredItemsRequest => Some((ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary, json_body_to_binary::<ModifyMonitoredItemsRequest>(body))),
+        DataTypeId::ModifyMonitoredItemsResponse => Some((ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary, json_body_to_binary::<ModifyMonitoredItemsResponse>(body))),
+        DataTypeId::SetMonitoringModeRequest => Some((ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary, json_body_to_binary::<SetMonitoringModeRequest>(body))),
+        DataTypeId::SetMonitoringModeResponse => Some((ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary, json_body_to_binary::<SetMonitoringModeResponse>(body))),
+        DataTypeId::SetTriggeringRequest => Some((ObjectId::SetTriggeringRequest_Encoding_DefaultBinary, json_body_to_binary::<SetTriggeringRequest>(body))),
+        DataTypeId::SetTriggeringResponse => Some((ObjectId::SetTriggeringResponse_Encoding_DefaultBinary, json_body_to_binary::<SetTriggeringResponse>(body))),
+        DataTypeId::DeleteMonitoredItemsRequest => Some((ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary, json_body_to_binary::<DeleteMonitoredItemsRequest>(body))),
+        DataTypeId::DeleteMonitoredItemsResponse => Some((ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary, json_body_to_binary::<DeleteMonitoredItemsResponse>(body))),
+        DataTypeId::CreateSubscriptionRequest => Some((ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary, json_body_to_binary::<CreateSubscriptionRequest>(body))),
+        DataTypeId::CreateSubscriptionResponse => Some((ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary, json_body_to_binary::<CreateSubscriptionResponse>(body))),
+        DataTypeId::ModifySubscriptionRequest => Some((ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary, json_body_to_binary::<ModifySubscriptionRequest>(body))),
+        DataTypeId::ModifySubscriptionResponse => Some((ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary, json_body_to_binary::<ModifySubscriptionResponse>(body))),
+        DataTypeId::SetPublishingModeRequest => Some((ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary, json_body_to_binary::<SetPublishingModeRequest>(body))),
+        DataTypeId::SetPublishingModeResponse => Some((ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary, json_body_to_binary::<SetPublishingModeResponse>(body))),
+        DataTypeId::NotificationMessage => Some((ObjectId::NotificationMessage_Encoding_DefaultBinary, json_body_to_binary::<NotificationMessage>(body))),
+        DataTypeId::NotificationData => Some((ObjectId::NotificationData_Encoding_DefaultBinary, json_body_to_binary::<NotificationData>(body))),
+        DataTypeId::MonitoredItemNotification => Some((ObjectId::MonitoredItemNotification_Encoding_DefaultBinary, json_body_to_binary::<MonitoredItemNotification>(body))),
+        DataTypeId::EventFieldList => Some((ObjectId::EventFieldList_Encoding_DefaultBinary, json_body_to_binary::<EventFieldList>(body))),
+        DataTypeId::SubscriptionAcknowledgement => Some((ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary, json_body_to_binary::<SubscriptionAcknowledgement>(body))),
+        DataTypeId::PublishRequest => Some((ObjectId::PublishRequest_Encoding_DefaultBinary, json_body_to_binary::<PublishRequest>(body))),
+        DataTypeId::PublishResponse => Some((ObjectId::PublishResponse_Encoding_DefaultBinary, json_body_to_binary::<PublishResponse>(body))),
+        DataTypeId::RepublishRequest => Some((ObjectId::RepublishRequest_Encoding_DefaultBinary, json_body_to_binary::<RepublishRequest>(body))),
+        DataTypeId::RepublishResponse => Some((ObjectId::RepublishResponse_Encoding_DefaultBinary, json_body_to_binary::<RepublishResponse>(body))),
+        DataTypeId::TransferResult => Some((ObjectId::TransferResult_Encoding_DefaultBinary, json_body_to_binary::<TransferResult>(body))),
+        DataTypeId::TransferSubscriptionsRequest => Some((ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary, json_body_to_binary::<TransferSubscriptionsRequest>(body))),
+        DataTypeId::TransferSubscriptionsResponse => Some((ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary, json_body_to_binary::<TransferSubscriptionsResponse>(body))),
+        DataTypeId::DeleteSubscriptionsRequest => Some((ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary, json_body_to_binary::<DeleteSubscriptionsRequest>(body))),
+        DataTypeId::DeleteSubscriptionsResponse => Some((ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary, json_body_to_binary::<DeleteSubscriptionsResponse>(body))),
+        DataTypeId::BuildInfo => Some((ObjectId::BuildInfo_Encoding_DefaultBinary, json_body_to_binary::<BuildInfo>(body))),
+        DataTypeId::EndpointUrlListDataType => Some((ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary, json_body_to_binary::<EndpointUrlListDataType>(body))),
+        DataTypeId::NetworkGroupDataType => Some((ObjectId::NetworkGroupDataType_Encoding_DefaultBinary, json_body_to_binary::<NetworkGroupDataType>(body))),
+        DataTypeId::SamplingIntervalDiagnosticsDataType => Some((ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary, json_body_to_binary::<SamplingIntervalDiagnosticsDataType>(body))),
+        DataTypeId::ServerDiagnosticsSummaryDataType => Some((ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary, json_body_to_binary::<ServerDiagnosticsSummaryDataType>(body))),
+        DataTypeId::SessionDiagnosticsDataType => Some((ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary, json_body_to_binary::<SessionDiagnosticsDataType>(body))),
+        DataTypeId::SessionSecurityDiagnosticsDataType => Some((ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary, json_body_to_binary::<SessionSecurityDiagnosticsDataType>(body))),
+        DataTypeId::ServiceCounterDataType => Some((ObjectId::ServiceCounterDataType_Encoding_DefaultBinary, json_body_to_binary::<ServiceCounterDataType>(body))),
+        DataTypeId::StatusResult => Some((ObjectId::StatusResult_Encoding_DefaultBinary, json_body_to_binary::<StatusResult>(body))),
+        DataTypeId::SubscriptionDiagnosticsDataType => Some((ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary, json_body_to_binary::<SubscriptionDiagnosticsDataType>(body))),
+        DataTypeId::ModelChangeStructureDataType => Some((ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary, json_body_to_binary::<ModelChangeStructureDataType>(body))),
+        DataTypeId::Range => Some((ObjectId::Range_Encoding_DefaultBinary, json_body_to_binary::<Range>(body))),
+        DataTypeId::EUInformation => Some((ObjectId::EUInformation_Encoding_DefaultBinary, json_body_to_binary::<EUInformation>(body))),
+        DataTypeId::ComplexNumberType => Some((ObjectId::ComplexNumberType_Encoding_DefaultBinary, json_body_to_binary::<ComplexNumberType>(body))),
+        DataTypeId::DoubleComplexNumberType => Some((ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary, json_body_to_binary::<DoubleComplexNumberType>(body))),
+        DataTypeId::XVType => Some((ObjectId::XVType_Encoding_DefaultBinary, json_body_to_binary::<XVType>(body))),
+        DataTypeId::ProgramDiagnosticDataType => Some((ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary, json_body_to_binary::<ProgramDiagnosticDataType>(body))),
+        DataTypeId::Annotation => Some((ObjectId::Annotation_Encoding_DefaultBinary, json_body_to_binary::<Annotation>(body))),
+        _ => None
+    }
+}
+
+/// Encodes the binary body of an extension object as the XML element of its type. Returns the
+/// XML encoding id of the type with the element, or None if the object id is not the binary
+/// encoding of a known type.
+pub fn encode_xml_body(object_id: ObjectId, body: &ByteString) -> Option<(ObjectId, EncodingResult<XmlNode>)> {
+    match object_id {
+        ObjectId::TrustListDataType_Encoding_DefaultBinary => Some((ObjectId::TrustListDataType_Encoding_DefaultXml, binary_body_to_xml::<TrustListDataType>(body, "TrustListDataType"))),
+        ObjectId::Argument_Encoding_DefaultBinary => Some((ObjectId::Argument_Encoding_DefaultXml, binary_body_to_xml::<Argument>(body, "Argument"))),
+        ObjectId::EnumValueType_Encoding_DefaultBinary => Some((ObjectId::EnumValueType_Encoding_DefaultXml, binary_body_to_xml::<EnumValueType>(body, "EnumValueType"))),
+        ObjectId::OptionSet_Encoding_DefaultBinary => Some((ObjectId::OptionSet_Encoding_DefaultXml, binary_body_to_xml::<OptionSet>(body, "OptionSet"))),
+        ObjectId::TimeZoneDataType_Encoding_DefaultBinary => Some((ObjectId::TimeZoneDataType_Encoding_DefaultXml, binary_body_to_xml::<TimeZoneDataType>(body, "TimeZoneDataType"))),
+        ObjectId::ApplicationDescription_Encoding_DefaultBinary => Some((ObjectId::ApplicationDescription_Encoding_DefaultXml, binary_body_to_xml::<ApplicationDescription>(body, "ApplicationDescription"))),
+        ObjectId::ServiceFault_Encoding_DefaultBinary => Some((ObjectId::ServiceFault_Encoding_DefaultXml, binary_body_to_xml::<ServiceFault>(body, "ServiceFault"))),
+        ObjectId::FindServersRequest_Encoding_DefaultBinary => Some((ObjectId::FindServersRequest_Encoding_DefaultXml, binary_body_to_xml::<FindServersRequest>(body, "FindServersRequest"))),
+        ObjectId::FindServersResponse_Encoding_DefaultBinary => Some((ObjectId::FindServersResponse_Encoding_DefaultXml, binary_body_to_xml::<FindServersResponse>(body, "FindServersResponse"))),
+        ObjectId::ServerOnNetwork_Encoding_DefaultBinary => Some((ObjectId::ServerOnNetwork_Encoding_DefaultXml, binary_body_to_xml::<ServerOnNetwork>(body, "ServerOnNetwork"))),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary => Some((ObjectId::FindServersOnNetworkRequest_Encoding_DefaultXml, binary_body_to_xml::<FindServersOnNetworkRequest>(body, "FindServersOnNetworkRequest"))),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary => Some((ObjectId::FindServersOnNetworkResponse_Encoding_DefaultXml, binary_body_to_xml::<FindServersOnNetworkResponse>(body, "FindServersOnNetworkResponse"))),
+        ObjectId::UserTokenPolicy_Encoding_DefaultBinary => Some((ObjectId::UserTokenPolicy_Encoding_DefaultXml, binary_body_to_xml::<UserTokenPolicy>(body, "UserTokenPolicy"))),
+        ObjectId::EndpointDescription_Encoding_DefaultBinary => Some((ObjectId::EndpointDescription_Encoding_DefaultXml, binary_body_to_xml::<EndpointDescription>(body, "EndpointDescription"))),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => Some((ObjectId::GetEndpointsRequest_Encoding_DefaultXml, binary_body_to_xml::<GetEndpointsRequest>(body, "GetEndpointsRequest"))),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultBinary => Some((ObjectId::GetEndpointsResponse_Encoding_DefaultXml, binary_body_to_xml::<GetEndpointsResponse>(body, "GetEndpointsResponse"))),
+        ObjectId::RegisteredServer_Encoding_DefaultBinary => Some((ObjectId::RegisteredServer_Encoding_DefaultXml, binary_body_to_xml::<RegisteredServer>(body, "RegisteredServer"))),
+        ObjectId::RegisterServerRequest_Encoding_DefaultBinary => Some((ObjectId::RegisterServerRequest_Encoding_DefaultXml, binary_body_to_xml::<RegisterServerRequest>(body, "RegisterServerRequest"))),
+        ObjectId::RegisterServerResponse_Encoding_DefaultBinary => Some((ObjectId::RegisterServerResponse_Encoding_DefaultXml, binary_body_to_xml::<RegisterServerResponse>(body, "RegisterServerResponse"))),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary => Some((ObjectId::DiscoveryConfiguration_Encoding_DefaultXml, binary_body_to_xml::<DiscoveryConfiguration>(body, "DiscoveryConfiguration"))),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultBinary => Some((ObjectId::ChannelSecurityToken_Encoding_DefaultXml, binary_body_to_xml::<ChannelSecurityToken>(body, "ChannelSecurityToken"))),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => Some((ObjectId::OpenSecureChannelRequest_Encoding_DefaultXml, binary_body_to_xml::<OpenSecureChannelRequest>(body, "OpenSecureChannelRequest"))),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary => Some((ObjectId::OpenSecureChannelResponse_Encoding_DefaultXml, binary_body_to_xml::<OpenSecureChannelResponse>(body, "OpenSecureChannelResponse"))),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => Some((ObjectId::CloseSecureChannelRequest_Encoding_DefaultXml, binary_body_to_xml::<CloseSecureChannelRequest>(body, "CloseSecureChannelRequest"))),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary => Some((ObjectId::CloseSecureChannelResponse_Encoding_DefaultXml, binary_body_to_xml::<CloseSecureChannelResponse>(body, "CloseSecureChannelResponse"))),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary => Some((ObjectId::SignedSoftwareCertificate_Encoding_DefaultXml, binary_body_to_xml::<SignedSoftwareCertificate>(body, "SignedSoftwareCertificate"))),
+        ObjectId::SignatureData_Encoding_DefaultBinary => Some((ObjectId::SignatureData_Encoding_DefaultXml, binary_body_to_xml::<SignatureData>(body, "SignatureData"))),
+        ObjectId::CreateSessionRequest_Encoding_DefaultBinary => Some((ObjectId::CreateSessionRequest_Encoding_DefaultXml, binary_body_to_xml::<CreateSessionRequest>(body, "CreateSessionRequest"))),
+        ObjectId::CreateSessionResponse_Encoding_DefaultBinary => Some((ObjectId::CreateSessionResponse_Encoding_DefaultXml, binary_body_to_xml::<CreateSessionResponse>(body, "CreateSessionResponse"))),
+        ObjectId::UserIdentityToken_Encoding_DefaultBinary => Some((ObjectId::UserIdentityToken_Encoding_DefaultXml, binary_body_to_xml::<UserIdentityToken>(body, "UserIdentityToken"))),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => Some((ObjectId::ActivateSessionRequest_Encoding_DefaultXml, binary_body_to_xml::<ActivateSessionRequest>(body, "ActivateSessionRequest"))),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultBinary => Some((ObjectId::ActivateSessionResponse_Encoding_DefaultXml, binary_body_to_xml::<ActivateSessionResponse>(body, "ActivateSessionResponse"))),
+        ObjectId::CloseSessionRequest_Encoding_DefaultBinary => Some((ObjectId::CloseSessionRequest_Encoding_DefaultXml, binary_body_to_xml::<CloseSessionRequest>(body, "CloseSessionRequest"))),
+        ObjectId::CloseSessionResponse_Encoding_DefaultBinary => Some((ObjectId::CloseSessionResponse_Encoding_DefaultXml, binary_body_to_xml::<CloseSessionResponse>(body, "CloseSessionResponse"))),
+        ObjectId::CancelRequest_Encoding_DefaultBinary => Some((ObjectId::CancelRequest_Encoding_DefaultXml, binary_body_to_xml::<CancelRequest>(body, "CancelRequest"))),
+        ObjectId::CancelResponse_Encoding_DefaultBinary => Some((ObjectId::CancelResponse_Encoding_DefaultXml, binary_body_to_xml::<CancelResponse>(body, "CancelResponse"))),
+        ObjectId::NodeAttributes_Encoding_DefaultBinary => Some((ObjectId::NodeAttributes_Encoding_DefaultXml, binary_body_to_xml::<NodeAttributes>(body, "NodeAttributes"))),
+        ObjectId::AddNodesItem_Encoding_DefaultBinary => Some((ObjectId::AddNodesItem_Encoding_DefaultXml, binary_body_to_xml::<AddNodesItem>(body, "AddNodesItem"))),
+        ObjectId::AddNodesResult_Encoding_DefaultBinary => Some((ObjectId::AddNodesResult_Encoding_DefaultXml, binary_body_to_xml::<AddNodesResult>(body, "AddNodesResult"))),
+        ObjectId::AddNodesRequest_Encoding_DefaultBinary => Some((ObjectId::AddNodesRequest_Encoding_DefaultXml, binary_body_to_xml::<AddNodesRequest>(body, "AddNodesRequest"))),
+        ObjectId::AddNodesResponse_Encoding_DefaultBinary => Some((ObjectId::AddNodesResponse_Encoding_DefaultXml, binary_body_to_xml::<AddNodesResponse>(body, "AddNodesResponse"))),
+        ObjectId::AddReferencesItem_Encoding_DefaultBinary => Some((ObjectId::AddReferencesItem_Encoding_DefaultXml, binary_body_to_xml::<AddReferencesItem>(body, "AddReferencesItem"))),
+        ObjectId::AddReferencesRequest_Encoding_DefaultBinary => Some((ObjectId::AddReferencesRequest_Encoding_DefaultXml, binary_body_to_xml::<AddReferencesRequest>(body, "AddReferencesRequest"))),
+        ObjectId::AddReferencesResponse_Encoding_DefaultBinary => Some((ObjectId::AddReferencesResponse_Encoding_DefaultXml, binary_body_to_xml::<AddReferencesResponse>(body, "AddReferencesResponse"))),
+        ObjectId::DeleteNodesItem_Encoding_DefaultBinary => Some((ObjectId::DeleteNodesItem_Encoding_DefaultXml, binary_body_to_xml::<DeleteNodesItem>(body, "DeleteNodesItem"))),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteNodesRequest_Encoding_DefaultXml, binary_body_to_xml::<DeleteNodesRequest>(body, "DeleteNodesRequest"))),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteNodesResponse_Encoding_DefaultXml, binary_body_to_xml::<DeleteNodesResponse>(body, "DeleteNodesResponse"))),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultBinary => Some((ObjectId::DeleteReferencesItem_Encoding_DefaultXml, binary_body_to_xml::<DeleteReferencesItem>(body, "DeleteReferencesItem"))),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteReferencesRequest_Encoding_DefaultXml, binary_body_to_xml::<DeleteReferencesRequest>(body, "DeleteReferencesRequest"))),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteReferencesResponse_Encoding_DefaultXml, binary_body_to_xml::<DeleteReferencesResponse>(body, "DeleteReferencesResponse"))),
+        ObjectId::ViewDescription_Encoding_DefaultBinary => Some((ObjectId::ViewDescription_Encoding_DefaultXml, binary_body_to_xml::<ViewDescription>(body, "ViewDescription"))),
+        ObjectId::BrowseDescription_Encoding_DefaultBinary => Some((ObjectId::BrowseDescription_Encoding_DefaultXml, binary_body_to_xml::<BrowseDescription>(body, "BrowseDescription"))),
+        ObjectId::ReferenceDescription_Encoding_DefaultBinary => Some((ObjectId::ReferenceDescription_Encoding_DefaultXml, binary_body_to_xml::<ReferenceDescription>(body, "ReferenceDescription"))),
+        ObjectId::BrowseResult_Encoding_DefaultBinary => Some((ObjectId::BrowseResult_Encoding_DefaultXml, binary_body_to_xml::<BrowseResult>(body, "BrowseResult"))),
+        ObjectId::BrowseRequest_Encoding_DefaultBinary => Some((ObjectId::BrowseRequest_Encoding_DefaultXml, binary_body_to_xml::<BrowseRequest>(body, "BrowseRequest"))),
+        ObjectId::BrowseResponse_Encoding_DefaultBinary => Some((ObjectId::BrowseResponse_Encoding_DefaultXml, binary_body_to_xml::<BrowseResponse>(body, "BrowseResponse"))),
+        ObjectId::BrowseNextRequest_Encoding_DefaultBinary => Some((ObjectId::BrowseNextRequest_Encoding_DefaultXml, binary_body_to_xml::<BrowseNextRequest>(body, "BrowseNextRequest"))),
+        ObjectId::BrowseNextResponse_Encoding_DefaultBinary => Some((ObjectId::BrowseNextResponse_Encoding_DefaultXml, binary_body_to_xml::<BrowseNextResponse>(body, "BrowseNextResponse"))),
+        ObjectId::RelativePathElement_Encoding_DefaultBinary => Some((ObjectId::RelativePathElement_Encoding_DefaultXml, binary_body_to_xml::<RelativePathElement>(body, "RelativePathElement"))),
+        ObjectId::RelativePath_Encoding_DefaultBinary => Some((ObjectId::RelativePath_Encoding_DefaultXml, binary_body_to_xml::<RelativePath>(body, "RelativePath"))),
+        ObjectId::BrowsePath_Encoding_DefaultBinary => Some((ObjectId::BrowsePath_Encoding_DefaultXml, binary_body_to_xml::<BrowsePath>(body, "BrowsePath"))),
+        ObjectId::BrowsePathTarget_Encoding_DefaultBinary => Some((ObjectId::BrowsePathTarget_Encoding_DefaultXml, binary_body_to_xml::<BrowsePathTarget>(body, "BrowsePathTarget"))),
+        ObjectId::BrowsePathResult_Encoding_DefaultBinary => Some((ObjectId::BrowsePathResult_Encoding_DefaultXml, binary_body_to_xml::<BrowsePathResult>(body, "BrowsePathResult"))),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => Some((ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultXml, binary_body_to_xml::<TranslateBrowsePathsToNodeIdsRequest>(body, "TranslateBrowsePathsToNodeIdsRequest"))),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => Some((ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultXml, binary_body_to_xml::<TranslateBrowsePathsToNodeIdsResponse>(body, "TranslateBrowsePathsToNodeIdsResponse"))),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => Some((ObjectId::RegisterNodesRequest_Encoding_DefaultXml, binary_body_to_xml::<RegisterNodesRequest>(body, "RegisterNodesRequest"))),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => Some((ObjectId::RegisterNodesResponse_Encoding_DefaultXml, binary_body_to_xml::<RegisterNodesResponse>(body, "RegisterNodesResponse"))),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => Some((ObjectId::UnregisterNodesRequest_Encoding_DefaultXml, binary_body_to_xml::<UnregisterNodesRequest>(body, "UnregisterNodesRequest"))),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => Some((ObjectId::UnregisterNodesResponse_Encoding_DefaultXml, binary_body_to_xml::<UnregisterNodesResponse>(body, "UnregisterNodesResponse"))),
+        ObjectId::EndpointConfiguration_Encoding_DefaultBinary => Some((ObjectId::EndpointConfiguration_Encoding_DefaultXml, binary_body_to_xml::<EndpointConfiguration>(body, "EndpointConfiguration"))),
+        ObjectId::QueryDataDescription_Encoding_DefaultBinary => Some((ObjectId::QueryDataDescription_Encoding_DefaultXml, binary_body_to_xml::<QueryDataDescription>(body, "QueryDataDescription"))),
+        ObjectId::NodeTypeDescription_Encoding_DefaultBinary => Some((ObjectId::NodeTypeDescription_Encoding_DefaultXml, binary_body_to_xml::<NodeTypeDescription>(body, "NodeTypeDescription"))),
+        ObjectId::QueryDataSet_Encoding_DefaultBinary => Some((ObjectId::QueryDataSet_Encoding_DefaultXml, binary_body_to_xml::<QueryDataSet>(body, "QueryDataSet"))),
+        ObjectId::NodeReference_Encoding_DefaultBinary => Some((ObjectId::NodeReference_Encoding_DefaultXml, binary_body_to_xml::<NodeReference>(body, "NodeReference"))),
+        ObjectId::ContentFilterElement_Encoding_DefaultBinary => Some((ObjectId::ContentFilterElement_Encoding_DefaultXml, binary_body_to_xml::<ContentFilterElement>(body, "ContentFilterElement"))),
+        ObjectId::ContentFilter_Encoding_DefaultBinary => Some((ObjectId::ContentFilter_Encoding_DefaultXml, binary_body_to_xml::<ContentFilter>(body, "ContentFilter"))),
+        ObjectId::FilterOperand_Encoding_DefaultBinary => Some((ObjectId::FilterOperand_Encoding_DefaultXml, binary_body_to_xml::<FilterOperand>(body, "FilterOperand"))),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultBinary => Some((ObjectId::ContentFilterElementResult_Encoding_DefaultXml, binary_body_to_xml::<ContentFilterElementResult>(body, "ContentFilterElementResult"))),
+        ObjectId::ContentFilterResult_Encoding_DefaultBinary => Some((ObjectId::ContentFilterResult_Encoding_DefaultXml, binary_body_to_xml::<ContentFilterResult>(body, "ContentFilterResult"))),
+        ObjectId::ParsingResult_Encoding_DefaultBinary => Some((ObjectId::ParsingResult_Encoding_DefaultXml, binary_body_to_xml::<ParsingResult>(body, "ParsingResult"))),
+        ObjectId::QueryFirstRequest_Encoding_DefaultBinary => Some((ObjectId::QueryFirstRequest_Encoding_DefaultXml, binary_body_to_xml::<QueryFirstRequest>(body, "QueryFirstRequest"))),
+        ObjectId::QueryFirstResponse_Encoding_DefaultBinary => Some((ObjectId::QueryFirstResponse_Encoding_DefaultXml, binary_body_to_xml::<QueryFirstResponse>(body, "QueryFirstResponse"))),
+        ObjectId::QueryNextRequest_Encoding_DefaultBinary => Some((ObjectId::QueryNextRequest_Encoding_DefaultXml, binary_body_to_xml::<QueryNextRequest>(body, "QueryNextRequest"))),
+        ObjectId::QueryNextResponse_Encoding_DefaultBinary => Some((ObjectId::QueryNextResponse_Encoding_DefaultXml, binary_body_to_xml::<QueryNextResponse>(body, "QueryNextResponse"))),
+        ObjectId::ReadValueId_Encoding_DefaultBinary => Some((ObjectId::ReadValueId_Encoding_DefaultXml, binary_body_to_xml::<ReadValueId>(body, "ReadValueId"))),
+        ObjectId::ReadRequest_Encoding_DefaultBinary => Some((ObjectId::ReadRequest_Encoding_DefaultXml, binary_body_to_xml::<ReadRequest>(body, "ReadRequest"))),
+        ObjectId::ReadResponse_Encoding_DefaultBinary => Some((ObjectId::ReadResponse_Encoding_DefaultXml, binary_body_to_xml::<ReadResponse>(body, "ReadResponse"))),
+        ObjectId::WriteValue_Encoding_DefaultBinary => Some((ObjectId::WriteValue_Encoding_DefaultXml, binary_body_to_xml::<WriteValue>(body, "WriteValue"))),
+        ObjectId::WriteRequest_Encoding_DefaultBinary => Some((ObjectId::WriteRequest_Encoding_DefaultXml, binary_body_to_xml::<WriteRequest>(body, "WriteRequest"))),
+        ObjectId::WriteResponse_Encoding_DefaultBinary => Some((ObjectId::WriteResponse_Encoding_DefaultXml, binary_body_to_xml::<WriteResponse>(body, "WriteResponse"))),
+        ObjectId::CallMethodRequest_Encoding_DefaultBinary => Some((ObjectId::CallMethodRequest_Encoding_DefaultXml, binary_body_to_xml::<CallMethodRequest>(body, "CallMethodRequest"))),
+        ObjectId::CallMethodResult_Encoding_DefaultBinary => Some((ObjectId::CallMethodResult_Encoding_DefaultXml, binary_body_to_xml::<CallMethodResult>(body, "CallMethodResult"))),
+        ObjectId::CallRequest_Encoding_DefaultBinary => Some((ObjectId::CallRequest_Encoding_DefaultXml, binary_body_to_xml::<CallRequest>(body, "CallRequest"))),
+        ObjectId::CallResponse_Encoding_DefaultBinary => Some((ObjectId::CallResponse_Encoding_DefaultXml, binary_body_to_xml::<CallResponse>(body, "CallResponse"))),
+        ObjectId::MonitoringFilter_Encoding_DefaultBinary => Some((ObjectId::MonitoringFilter_Encoding_DefaultXml, binary_body_to_xml::<MonitoringFilter>(body, "MonitoringFilter"))),
+        ObjectId::AggregateConfiguration_Encoding_DefaultBinary => Some((ObjectId::AggregateConfiguration_Encoding_DefaultXml, binary_body_to_xml::<AggregateConfiguration>(body, "AggregateConfiguration"))),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultBinary => Some((ObjectId::MonitoringFilterResult_Encoding_DefaultXml, binary_body_to_xml::<MonitoringFilterResult>(body, "MonitoringFilterResult"))),
+        ObjectId::MonitoringParameters_Encoding_DefaultBinary => Some((ObjectId::MonitoringParameters_Encoding_DefaultXml, binary_body_to_xml::<MonitoringParameters>(body, "MonitoringParameters"))),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemCreateRequest_Encoding_DefaultXml, binary_body_to_xml::<MonitoredItemCreateRequest>(body, "MonitoredItemCreateRequest"))),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemCreateResult_Encoding_DefaultXml, binary_body_to_xml::<MonitoredItemCreateResult>(body, "MonitoredItemCreateResult"))),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => Some((ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultXml, binary_body_to_xml::<CreateMonitoredItemsRequest>(body, "CreateMonitoredItemsRequest"))),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary => Some((ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultXml, binary_body_to_xml::<CreateMonitoredItemsResponse>(body, "CreateMonitoredItemsResponse"))),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemModifyRequest_Encoding_DefaultXml, binary_body_to_xml::<MonitoredItemModifyRequest>(body, "MonitoredItemModifyRequest"))),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemModifyResult_Encoding_DefaultXml, binary_body_to_xml::<MonitoredItemModifyResult>(body, "MonitoredItemModifyResult"))),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => Some((ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultXml, binary_body_to_xml::<ModifyMonitoredItemsRequest>(body, "ModifyMonitoredItemsRequest"))),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary => Some((ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultXml, binary_body_to_xml::<ModifyMonitoredItemsResponse>(body, "ModifyMonitoredItemsResponse"))),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => Some((ObjectId::SetMonitoringModeRequest_Encoding_DefaultXml, binary_body_to_xml::<SetMonitoringModeRequest>(body, "SetMonitoringModeRequest"))),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => Some((ObjectId::SetMonitoringModeResponse_Encoding_DefaultXml, binary_body_to_xml::<SetMonitoringModeResponse>(body, "SetMonitoringModeResponse"))),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => Some((ObjectId::SetTriggeringRequest_Encoding_DefaultXml, binary_body_to_xml::<SetTriggeringRequest>(body, "SetTriggeringRequest"))),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => Some((ObjectId::SetTriggeringResponse_Encoding_DefaultXml, binary_body_to_xml::<SetTriggeringResponse>(body, "SetTriggeringResponse"))),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultXml, binary_body_to_xml::<DeleteMonitoredItemsRequest>(body, "DeleteMonitoredItemsRequest"))),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultXml, binary_body_to_xml::<DeleteMonitoredItemsResponse>(body, "DeleteMonitoredItemsResponse"))),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => Some((ObjectId::CreateSubscriptionRequest_Encoding_DefaultXml, binary_body_to_xml::<CreateSubscriptionRequest>(body, "CreateSubscriptionRequest"))),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary => Some((ObjectId::CreateSubscriptionResponse_Encoding_DefaultXml, binary_body_to_xml::<CreateSubscriptionResponse>(body, "CreateSubscriptionResponse"))),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => Some((ObjectId::ModifySubscriptionRequest_Encoding_DefaultXml, binary_body_to_xml::<ModifySubscriptionRequest>(body, "ModifySubscriptionRequest"))),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary => Some((ObjectId::ModifySubscriptionResponse_Encoding_DefaultXml, binary_body_to_xml::<ModifySubscriptionResponse>(body, "ModifySubscriptionResponse"))),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => Some((ObjectId::SetPublishingModeRequest_Encoding_DefaultXml, binary_body_to_xml::<SetPublishingModeRequest>(body, "SetPublishingModeRequest"))),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary => Some((ObjectId::SetPublishingModeResponse_Encoding_DefaultXml, binary_body_to_xml::<SetPublishingModeResponse>(body, "SetPublishingModeResponse"))),
+        ObjectId::NotificationMessage_Encoding_DefaultBinary => Some((ObjectId::NotificationMessage_Encoding_DefaultXml, binary_body_to_xml::<NotificationMessage>(body, "NotificationMessage"))),
+        ObjectId::NotificationData_Encoding_DefaultBinary => Some((ObjectId::NotificationData_Encoding_DefaultXml, binary_body_to_xml::<NotificationData>(body, "NotificationData"))),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemNotification_Encoding_DefaultXml, binary_body_to_xml::<MonitoredItemNotification>(body, "MonitoredItemNotification"))),
+        ObjectId::EventFieldList_Encoding_DefaultBinary => Some((ObjectId::EventFieldList_Encoding_DefaultXml, binary_body_to_xml::<EventFieldList>(body, "EventFieldList"))),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary => Some((ObjectId::SubscriptionAcknowledgement_Encoding_DefaultXml, binary_body_to_xml::<SubscriptionAcknowledgement>(body, "SubscriptionAcknowledgement"))),
+        ObjectId::PublishRequest_Encoding_DefaultBinary => Some((ObjectId::PublishRequest_Encoding_DefaultXml, binary_body_to_xml::<PublishRequest>(body, "PublishRequest"))),
+        ObjectId::PublishResponse_Encoding_DefaultBinary => Some((ObjectId::PublishResponse_Encoding_DefaultXml, binary_body_to_xml::<PublishResponse>(body, "PublishResponse"))),
+        ObjectId::RepublishRequest_Encoding_DefaultBinary => Some((ObjectId::RepublishRequest_Encoding_DefaultXml, binary_body_to_xml::<RepublishRequest>(body, "RepublishRequest"))),
+        ObjectId::RepublishResponse_Encoding_DefaultBinary => Some((ObjectId::RepublishResponse_Encoding_DefaultXml, binary_body_to_xml::<RepublishResponse>(body, "RepublishResponse"))),
+        ObjectId::TransferResult_Encoding_DefaultBinary => Some((ObjectId::TransferResult_Encoding_DefaultXml, binary_body_to_xml::<TransferResult>(body, "TransferResult"))),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => Some((ObjectId::TransferSubscriptionsRequest_Encoding_DefaultXml, binary_body_to_xml::<TransferSubscriptionsRequest>(body, "TransferSubscriptionsRequest"))),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => Some((ObjectId::TransferSubscriptionsResponse_Encoding_DefaultXml, binary_body_to_xml::<TransferSubscriptionsResponse>(body, "TransferSubscriptionsResponse"))),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultXml, binary_body_to_xml::<DeleteSubscriptionsRequest>(body, "DeleteSubscriptionsRequest"))),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultXml, binary_body_to_xml::<DeleteSubscriptionsResponse>(body, "DeleteSubscriptionsResponse"))),
+        ObjectId::BuildInfo_Encoding_DefaultBinary => Some((ObjectId::BuildInfo_Encoding_DefaultXml, binary_body_to_xml::<BuildInfo>(body, "BuildInfo"))),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary => Some((ObjectId::EndpointUrlListDataType_Encoding_DefaultXml, binary_body_to_xml::<EndpointUrlListDataType>(body, "EndpointUrlListDataType"))),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultBinary => Some((ObjectId::NetworkGroupDataType_Encoding_DefaultXml, binary_body_to_xml::<NetworkGroupDataType>(body, "NetworkGroupDataType"))),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultXml, binary_body_to_xml::<SamplingIntervalDiagnosticsDataType>(body, "SamplingIntervalDiagnosticsDataType"))),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary => Some((ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultXml, binary_body_to_xml::<ServerDiagnosticsSummaryDataType>(body, "ServerDiagnosticsSummaryDataType"))),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SessionDiagnosticsDataType_Encoding_DefaultXml, binary_body_to_xml::<SessionDiagnosticsDataType>(body, "SessionDiagnosticsDataType"))),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultXml, binary_body_to_xml::<SessionSecurityDiagnosticsDataType>(body, "SessionSecurityDiagnosticsDataType"))),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultBinary => Some((ObjectId::ServiceCounterDataType_Encoding_DefaultXml, binary_body_to_xml::<ServiceCounterDataType>(body, "ServiceCounterDataType"))),
+        ObjectId::StatusResult_Encoding_DefaultBinary => Some((ObjectId::StatusResult_Encoding_DefaultXml, binary_body_to_xml::<StatusResult>(body, "StatusResult"))),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultXml, binary_body_to_xml::<SubscriptionDiagnosticsDataType>(body, "SubscriptionDiagnosticsDataType"))),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary => Some((ObjectId::ModelChangeStructureDataType_Encoding_DefaultXml, binary_body_to_xml::<ModelChangeStructureDataType>(body, "ModelChangeStructureDataType"))),
+        ObjectId::Range_Encoding_DefaultBinary => Some((ObjectId::Range_Encoding_DefaultXml, binary_body_to_xml::<Range>(body, "Range"))),
+        ObjectId::EUInformation_Encoding_DefaultBinary => Some((ObjectId::EUInformation_Encoding_DefaultXml, binary_body_to_xml::<EUInformation>(body, "EUInformation"))),
+        ObjectId::ComplexNumberType_Encoding_DefaultBinary => Some((ObjectId::ComplexNumberType_Encoding_DefaultXml, binary_body_to_xml::<ComplexNumberType>(body, "ComplexNumberType"))),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary => Some((ObjectId::DoubleComplexNumberType_Encoding_DefaultXml, binary_body_to_xml::<DoubleComplexNumberType>(body, "DoubleComplexNumberType"))),
+        ObjectId::XVType_Encoding_DefaultBinary => Some((ObjectId::XVType_Encoding_DefaultXml, binary_body_to_xml::<XVType>(body, "XVType"))),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary => Some((ObjectId::ProgramDiagnosticDataType_Encoding_DefaultXml, binary_body_to_xml::<ProgramDiagnosticDataType>(body, "ProgramDiagnosticDataType"))),
+        ObjectId::Annotation_Encoding_DefaultBinary => Some((ObjectId::Annotation_Encoding_DefaultXml, binary_body_to_xml::<Annotation>(body, "Annotation"))),
+        _ => None
+    }
+}
+
+/// Decodes the XML element of an extension object body into a binary body. Returns the binary
+/// encoding id of the type with the body, or None if the object id is not an encoding of a
+/// known type.
+pub fn decode_xml_body(object_id: ObjectId, body: &XmlNode) -> Option<(ObjectId, EncodingResult<ByteString>)> {
+    match object_id {
+        ObjectId::TrustListDataType_Encoding_DefaultXml | ObjectId::TrustListDataType_Encoding_DefaultBinary => Some((ObjectId::TrustListDataType_Encoding_DefaultBinary, xml_body_to_binary::<TrustListDataType>(body))),
+        ObjectId::Argument_Encoding_DefaultXml | ObjectId::Argument_Encoding_DefaultBinary => Some((ObjectId::Argument_Encoding_DefaultBinary, xml_body_to_binary::<Argument>(body))),
+        ObjectId::EnumValueType_Encoding_DefaultXml | ObjectId::EnumValueType_Encoding_DefaultBinary => Some((ObjectId::EnumValueType_Encoding_DefaultBinary, xml_body_to_binary::<EnumValueType>(body))),
+        ObjectId::OptionSet_Encoding_DefaultXml | ObjectId::OptionSet_Encoding_DefaultBinary => Some((ObjectId::OptionSet_Encoding_DefaultBinary, xml_body_to_binary::<OptionSet>(body))),
+        ObjectId::TimeZoneDataType_Encoding_DefaultXml | ObjectId::TimeZoneDataType_Encoding_DefaultBinary => Some((ObjectId::TimeZoneDataType_Encoding_DefaultBinary, xml_body_to_binary::<TimeZoneDataType>(body))),
+        ObjectId::ApplicationDescription_Encoding_DefaultXml | ObjectId::ApplicationDescription_Encoding_DefaultBinary => Some((ObjectId::ApplicationDescription_Encoding_DefaultBinary, xml_body_to_binary::<ApplicationDescription>(body))),
+        ObjectId::ServiceFault_Encoding_DefaultXml | ObjectId::ServiceFault_Encoding_DefaultBinary => Some((ObjectId::ServiceFault_Encoding_DefaultBinary, xml_body_to_binary::<ServiceFault>(body))),
+        ObjectId::FindServersRequest_Encoding_DefaultXml | ObjectId::FindServersRequest_Encoding_DefaultBinary => Some((ObjectId::FindServersRequest_Encoding_DefaultBinary, xml_body_to_binary::<FindServersRequest>(body))),
+        ObjectId::FindServersResponse_Encoding_DefaultXml | ObjectId::FindServersResponse_Encoding_DefaultBinary => Some((ObjectId::FindServersResponse_Encoding_DefaultBinary, xml_body_to_binary::<FindServersResponse>(body))),
+        ObjectId::ServerOnNetwork_Encoding_DefaultXml | ObjectId::ServerOnNetwork_Encoding_DefaultBinary => Some((ObjectId::ServerOnNetwork_Encoding_DefaultBinary, xml_body_to_binary::<ServerOnNetwork>(body))),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultXml | ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary => Some((ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary, xml_body_to_binary::<FindServersOnNetworkRequest>(body))),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultXml | ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary => Some((ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary, xml_body_to_binary::<FindServersOnNetworkResponse>(body))),
+        ObjectId::UserTokenPolicy_Encoding_DefaultXml | ObjectId::UserTokenPolicy_Encoding_DefaultBinary => Some((ObjectId::UserTokenPolicy_Encoding_DefaultBinary, xml_body_to_binary::<UserTokenPolicy>(body))),
+        ObjectId::EndpointDescription_Encoding_DefaultXml | ObjectId::EndpointDescription_Encoding_DefaultBinary => Some((ObjectId::EndpointDescription_Encoding_DefaultBinary, xml_body_to_binary::<EndpointDescription>(body))),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultXml | ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => Some((ObjectId::GetEndpointsRequest_Encoding_DefaultBinary, xml_body_to_binary::<GetEndpointsRequest>(body))),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultXml | ObjectId::GetEndpointsResponse_Encoding_DefaultBinary => Some((ObjectId::GetEndpointsResponse_Encoding_DefaultBinary, xml_body_to_binary::<GetEndpointsResponse>(body))),
+        ObjectId::RegisteredServer_Encoding_DefaultXml | ObjectId::RegisteredServer_Encoding_DefaultBinary => Some((ObjectId::RegisteredServer_Encoding_DefaultBinary, xml_body_to_binary::<RegisteredServer>(body))),
+        ObjectId::RegisterServerRequest_Encoding_DefaultXml | ObjectId::RegisterServerRequest_Encoding_DefaultBinary => Some((ObjectId::RegisterServerRequest_Encoding_DefaultBinary, xml_body_to_binary::<RegisterServerRequest>(body))),
+        ObjectId::RegisterServerResponse_Encoding_DefaultXml | ObjectId::RegisterServerResponse_Encoding_DefaultBinary => Some((ObjectId::RegisterServerResponse_Encoding_DefaultBinary, xml_body_to_binary::<RegisterServerResponse>(body))),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultXml | ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary => Some((ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary, xml_body_to_binary::<DiscoveryConfiguration>(body))),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultXml | ObjectId::ChannelSecurityToken_Encoding_DefaultBinary => Some((ObjectId::ChannelSecurityToken_Encoding_DefaultBinary, xml_body_to_binary::<ChannelSecurityToken>(body))),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultXml | ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => Some((ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary, xml_body_to_binary::<OpenSecureChannelRequest>(body))),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultXml | ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary => Some((ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary, xml_body_to_binary::<OpenSecureChannelResponse>(body))),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultXml | ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => Some((ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary, xml_body_to_binary::<CloseSecureChannelRequest>(body))),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultXml | ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary => Some((ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary, xml_body_to_binary::<CloseSecureChannelResponse>(body))),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultXml | ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary => Some((ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary, xml_body_to_binary::<SignedSoftwareCertificate>(body))),
+        ObjectId::SignatureData_Encoding_DefaultXml | ObjectId::SignatureData_Encoding_DefaultBinary => Some((ObjectId::SignatureData_Encoding_DefaultBinary, xml_body_to_binary::<SignatureData>(body))),
+        ObjectId::CreateSessionRequest_Encoding_DefaultXml | ObjectId::CreateSessionRequest_Encoding_DefaultBinary => Some((ObjectId::CreateSessionRequest_Encoding_DefaultBinary, xml_body_to_binary::<CreateSessionRequest>(body))),
+        ObjectId::CreateSessionResponse_Encoding_DefaultXml | ObjectId::CreateSessionResponse_Encoding_DefaultBinary => Some((ObjectId::CreateSessionResponse_Encoding_DefaultBinary, xml_body_to_binary::<CreateSessionResponse>(body))),
+        ObjectId::UserIdentityToken_Encoding_DefaultXml | ObjectId::UserIdentityToken_Encoding_DefaultBinary => Some((ObjectId::UserIdentityToken_Encoding_DefaultBinary, xml_body_to_binary::<UserIdentityToken>(body))),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultXml | ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => Some((ObjectId::ActivateSessionRequest_Encoding_DefaultBinary, xml_body_to_binary::<ActivateSessionRequest>(body))),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultXml | ObjectId::ActivateSessionResponse_Encoding_DefaultBinary => Some((ObjectId::ActivateSessionResponse_Encoding_DefaultBinary, xml_body_to_binary::<ActivateSessionResponse>(body))),
+        ObjectId::CloseSessionRequest_Encoding_DefaultXml | ObjectId::CloseSessionRequest_Encoding_DefaultBinary => Some((ObjectId::CloseSessionRequest_Encoding_DefaultBinary, xml_body_to_binary::<CloseSessionRequest>(body))),
+        ObjectId::CloseSessionResponse_Encoding_DefaultXml | ObjectId::CloseSessionResponse_Encoding_DefaultBinary => Some((ObjectId::CloseSessionResponse_Encoding_DefaultBinary, xml_body_to_binary::<CloseSessionResponse>(body))),
+        ObjectId::CancelRequest_Encoding_DefaultXml | ObjectId::CancelRequest_Encoding_DefaultBinary => Some((ObjectId::CancelRequest_Encoding_DefaultBinary, xml_body_to_binary::<CancelRequest>(body))),
+        ObjectId::CancelResponse_Encoding_DefaultXml | ObjectId::CancelResponse_Encoding_DefaultBinary => Some((ObjectId::CancelResponse_Encoding_DefaultBinary, xml_body_to_binary::<CancelResponse>(body))),
+        ObjectId::NodeAttributes_Encoding_DefaultXml | ObjectId::NodeAttributes_Encoding_DefaultBinary => Some((ObjectId::NodeAttributes_Encoding_DefaultBinary, xml_body_to_binary::<NodeAttributes>(body))),
+        ObjectId::AddNodesItem_Encoding_DefaultXml | ObjectId::AddNodesItem_Encoding_DefaultBinary => Some((ObjectId::AddNodesItem_Encoding_DefaultBinary, xml_body_to_binary::<AddNodesItem>(body))),
+        ObjectId::AddNodesResult_Encoding_DefaultXml | ObjectId::AddNodesResult_Encoding_DefaultBinary => Some((ObjectId::AddNodesResult_Encoding_DefaultBinary, xml_body_to_binary::<AddNodesResult>(body))),
+        ObjectId::AddNodesRequest_Encoding_DefaultXml | ObjectId::AddNodesRequest_Encoding_DefaultBinary => Some((ObjectId::AddNodesRequest_Encoding_DefaultBinary, xml_body_to_binary::<AddNodesRequest>(body))),
+        ObjectId::AddNodesResponse_Encoding_DefaultXml | ObjectId::AddNodesResponse_Encoding_DefaultBinary => Some((ObjectId::AddNodesResponse_Encoding_DefaultBinary, xml_body_to_binary::<AddNodesResponse>(body))),
+        ObjectId::AddReferencesItem_Encoding_DefaultXml | ObjectId::AddReferencesItem_Encoding_DefaultBinary => Some((ObjectId::AddReferencesItem_Encoding_DefaultBinary, xml_body_to_binary::<AddReferencesItem>(body))),
+        ObjectId::AddReferencesRequest_Encoding_DefaultXml | ObjectId::AddReferencesRequest_Encoding_DefaultBinary => Some((ObjectId::AddReferencesRequest_Encoding_DefaultBinary, xml_body_to_binary::<AddReferencesRequest>(body))),
+        ObjectId::AddReferencesResponse_Encoding_DefaultXml | ObjectId::AddReferencesResponse_Encoding_DefaultBinary => Some((ObjectId::AddReferencesResponse_Encoding_DefaultBinary, xml_body_to_binary::<AddReferencesResponse>(body))),
+        ObjectId::DeleteNodesItem_Encoding_DefaultXml | ObjectId::DeleteNodesItem_Encoding_DefaultBinary => Some((ObjectId::DeleteNodesItem_Encoding_DefaultBinary, xml_body_to_binary::<DeleteNodesItem>(body))),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultXml | ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteNodesRequest_Encoding_DefaultBinary, xml_body_to_binary::<DeleteNodesRequest>(body))),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultXml | ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteNodesResponse_Encoding_DefaultBinary, xml_body_to_binary::<DeleteNodesResponse>(body))),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultXml | ObjectId::DeleteReferencesItem_Encoding_DefaultBinary => Some((ObjectId::DeleteReferencesItem_Encoding_DefaultBinary, xml_body_to_binary::<DeleteReferencesItem>(body))),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultXml | ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary, xml_body_to_binary::<DeleteReferencesRequest>(body))),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultXml | ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary, xml_body_to_binary::<DeleteReferencesResponse>(body))),
+        ObjectId::ViewDescription_Encoding_DefaultXml | ObjectId::ViewDescription_Encoding_DefaultBinary => Some((ObjectId::ViewDescription_Encoding_DefaultBinary, xml_body_to_binary::<ViewDescription>(body))),
+        ObjectId::BrowseDescription_Encoding_DefaultXml | ObjectId::BrowseDescription_Encoding_DefaultBinary => Some((ObjectId::BrowseDescription_Encoding_DefaultBinary, xml_body_to_binary::<BrowseDescription>(body))),
+        ObjectId::ReferenceDescription_Encoding_DefaultXml | ObjectId::ReferenceDescription_Encoding_DefaultBinary => Some((ObjectId::ReferenceDescription_Encoding_DefaultBinary, xml_body_to_binary::<ReferenceDescription>(body))),
+        ObjectId::BrowseResult_Encoding_DefaultXml | ObjectId::BrowseResult_Encoding_DefaultBinary => Some((ObjectId::BrowseResult_Encoding_DefaultBinary, xml_body_to_binary::<BrowseResult>(body))),
+        ObjectId::BrowseRequest_Encoding_DefaultXml | ObjectId::BrowseRequest_Encoding_DefaultBinary => Some((ObjectId::BrowseRequest_Encoding_DefaultBinary, xml_body_to_binary::<BrowseRequest>(body))),
+        ObjectId::BrowseResponse_Encoding_DefaultXml | ObjectId::BrowseResponse_Encoding_DefaultBinary => Some((ObjectId::BrowseResponse_Encoding_DefaultBinary, xml_body_to_binary::<BrowseResponse>(body))),
+        ObjectId::BrowseNextRequest_Encoding_DefaultXml | ObjectId::BrowseNextRequest_Encoding_DefaultBinary => Some((ObjectId::BrowseNextRequest_Encoding_DefaultBinary, xml_body_to_binary::<BrowseNextRequest>(body))),
+        ObjectId::BrowseNextResponse_Encoding_DefaultXml | ObjectId::BrowseNextResponse_Encoding_DefaultBinary => Some((ObjectId::BrowseNextResponse_Encoding_DefaultBinary, xml_body_to_binary::<BrowseNextResponse>(body))),
+        ObjectId::RelativePathElement_Encoding_DefaultXml | ObjectId::RelativePathElement_Encoding_DefaultBinary => Some((ObjectId::RelativePathElement_Encoding_DefaultBinary, xml_body_to_binary::<RelativePathElement>(body))),
+        ObjectId::RelativePath_Encoding_DefaultXml | ObjectId::RelativePath_Encoding_DefaultBinary => Some((ObjectId::RelativePath_Encoding_DefaultBinary, xml_body_to_binary::<RelativePath>(body))),
+        ObjectId::BrowsePath_Encoding_DefaultXml | ObjectId::BrowsePath_Encoding_DefaultBinary => Some((ObjectId::BrowsePath_Encoding_DefaultBinary, xml_body_to_binary::<BrowsePath>(body))),
+        ObjectId::BrowsePathTarget_Encoding_DefaultXml | ObjectId::BrowsePathTarget_Encoding_DefaultBinary => Some((ObjectId::BrowsePathTarget_Encoding_DefaultBinary, xml_body_to_binary::<BrowsePathTarget>(body))),
+        ObjectId::BrowsePathResult_Encoding_DefaultXml | ObjectId::BrowsePathResult_Encoding_DefaultBinary => Some((ObjectId::BrowsePathResult_Encoding_DefaultBinary, xml_body_to_binary::<BrowsePathResult>(body))),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultXml | ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => Some((ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary, xml_body_to_binary::<TranslateBrowsePathsToNodeIdsRequest>(body))),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultXml | ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => Some((ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary, xml_body_to_binary::<TranslateBrowsePathsToNodeIdsResponse>(body))),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultXml | ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => Some((ObjectId::RegisterNodesRequest_Encoding_DefaultBinary, xml_body_to_binary::<RegisterNodesRequest>(body))),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultXml | ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => Some((ObjectId::RegisterNodesResponse_Encoding_DefaultBinary, xml_body_to_binary::<RegisterNodesResponse>(body))),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultXml | ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => Some((ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary, xml_body_to_binary::<UnregisterNodesRequest>(body))),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultXml | ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => Some((ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary, xml_body_to_binary::<UnregisterNodesResponse>(body))),
+        ObjectId::EndpointConfiguration_Encoding_DefaultXml | ObjectId::EndpointConfiguration_Encoding_DefaultBinary => Some((ObjectId::EndpointConfiguration_Encoding_DefaultBinary, xml_body_to_binary::<EndpointConfiguration>(body))),
+        ObjectId::QueryDataDescription_Encoding_DefaultXml | ObjectId::QueryDataDescription_Encoding_DefaultBinary => Some((ObjectId::QueryDataDescription_Encoding_DefaultBinary, xml_body_to_binary::<QueryDataDescription>(body))),
+        ObjectId::NodeTypeDescription_Encoding_DefaultXml | ObjectId::NodeTypeDescription_Encoding_DefaultBinary => Some((ObjectId::NodeTypeDescription_Encoding_DefaultBinary, xml_body_to_binary::<NodeTypeDescription>(body))),
+        ObjectId::QueryDataSet_Encoding_DefaultXml | ObjectId::QueryDataSet_Encoding_DefaultBinary => Some((ObjectId::QueryDataSet_Encoding_DefaultBinary, xml_body_to_binary::<QueryDataSet>(body))),
+        ObjectId::NodeReference_Encoding_DefaultXml | ObjectId::NodeReference_Encoding_DefaultBinary => Some((ObjectId::NodeReference_Encoding_DefaultBinary, xml_body_to_binary::<NodeReference>(body))),
+        ObjectId::ContentFilterElement_Encoding_DefaultXml | ObjectId::ContentFilterElement_Encoding_DefaultBinary => Some((ObjectId::ContentFilterElement_Encoding_DefaultBinary, xml_body_to_binary::<ContentFilterElement>(body))),
+        ObjectId::ContentFilter_Encoding_DefaultXml | ObjectId::ContentFilter_Encoding_DefaultBinary => Some((ObjectId::ContentFilter_Encoding_DefaultBinary, xml_body_to_binary::<ContentFilter>(body))),
+        ObjectId::FilterOperand_Encoding_DefaultXml | ObjectId::FilterOperand_Encoding_DefaultBinary => Some((ObjectId::FilterOperand_Encoding_DefaultBinary, xml_body_to_binary::<FilterOperand>(body))),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultXml | ObjectId::ContentFilterElementResult_Encoding_DefaultBinary => Some((ObjectId::ContentFilterElementResult_Encoding_DefaultBinary, xml_body_to_binary::<ContentFilterElementResult>(body))),
+        ObjectId::ContentFilterResult_Encoding_DefaultXml | ObjectId::ContentFilterResult_Encoding_DefaultBinary => Some((ObjectId::ContentFilterResult_Encoding_DefaultBinary, xml_body_to_binary::<ContentFilterResult>(body))),
+        ObjectId::ParsingResult_Encoding_DefaultXml | ObjectId::ParsingResult_Encoding_DefaultBinary => Some((ObjectId::ParsingResult_Encoding_DefaultBinary, xml_body_to_binary::<ParsingResult>(body))),
+        ObjectId::QueryFirstRequest_Encoding_DefaultXml | ObjectId::QueryFirstRequest_Encoding_DefaultBinary => Some((ObjectId::QueryFirstRequest_Encoding_DefaultBinary, xml_body_to_binary::<QueryFirstRequest>(body))),
+        ObjectId::QueryFirstResponse_Encoding_DefaultXml | ObjectId::QueryFirstResponse_Encoding_DefaultBinary => Some((ObjectId::QueryFirstResponse_Encoding_DefaultBinary, xml_body_to_binary::<QueryFirstResponse>(body))),
+        ObjectId::QueryNextRequest_Encoding_DefaultXml | ObjectId::QueryNextRequest_Encoding_DefaultBinary => Some((ObjectId::QueryNextRequest_Encoding_DefaultBinary, xml_body_to_binary::<QueryNextRequest>(body))),
+        ObjectId::QueryNextResponse_Encoding_DefaultXml | ObjectId::QueryNextResponse_Encoding_DefaultBinary => Some((ObjectId::QueryNextResponse_Encoding_DefaultBinary, xml_body_to_binary::<QueryNextResponse>(body))),
+        ObjectId::ReadValueId_Encoding_DefaultXml | ObjectId::ReadValueId_Encoding_DefaultBinary => Some((ObjectId::ReadValueId_Encoding_DefaultBinary, xml_body_to_binary::<ReadValueId>(body))),
+        ObjectId::ReadRequest_Encoding_DefaultXml | ObjectId::ReadRequest_Encoding_DefaultBinary => Some((ObjectId::ReadRequest_Encoding_DefaultBinary, xml_body_to_binary::<ReadRequest>(body))),
+        ObjectId::ReadResponse_Encoding_DefaultXml | ObjectId::ReadResponse_Encoding_DefaultBinary => Some((ObjectId::ReadResponse_Encoding_DefaultBinary, xml_body_to_binary::<ReadResponse>(body))),
+        ObjectId::WriteValue_Encoding_DefaultXml | ObjectId::WriteValue_Encoding_DefaultBinary => Some((ObjectId::WriteValue_Encoding_DefaultBinary, xml_body_to_binary::<WriteValue>(body))),
+        ObjectId::WriteRequest_Encoding_DefaultXml | ObjectId::WriteRequest_Encoding_DefaultBinary => Some((ObjectId::WriteRequest_Encoding_DefaultBinary, xml_body_to_binary::<WriteRequest>(body))),
+        ObjectId::WriteResponse_Encoding_DefaultXml | ObjectId::WriteResponse_Encoding_DefaultBinary => Some((ObjectId::WriteResponse_Encoding_DefaultBinary, xml_body_to_binary::<WriteResponse>(body))),
+        ObjectId::CallMethodRequest_Encoding_DefaultXml | ObjectId::CallMethodRequest_Encoding_DefaultBinary => Some((ObjectId::CallMethodRequest_Encoding_DefaultBinary, xml_body_to_binary::<CallMethodRequest>(body))),
+        ObjectId::CallMethodResult_Encoding_DefaultXml | ObjectId::CallMethodResult_Encoding_DefaultBinary => Some((ObjectId::CallMethodResult_Encoding_DefaultBinary, xml_body_to_binary::<CallMethodResult>(body))),
+        ObjectId::CallRequest_Encoding_DefaultXml | ObjectId::CallRequest_Encoding_DefaultBinary => Some((ObjectId::CallRequest_Encoding_DefaultBinary, xml_body_to_binary::<CallRequest>(body))),
+        ObjectId::CallResponse_Encoding_DefaultXml | ObjectId::CallResponse_Encoding_DefaultBinary => Some((ObjectId::CallResponse_Encoding_DefaultBinary, xml_body_to_binary::<CallResponse>(body))),
+        ObjectId::MonitoringFilter_Encoding_DefaultXml | ObjectId::MonitoringFilter_Encoding_DefaultBinary => Some((ObjectId::MonitoringFilter_Encoding_DefaultBinary, xml_body_to_binary::<MonitoringFilter>(body))),
+        ObjectId::AggregateConfiguration_Encoding_DefaultXml | ObjectId::AggregateConfiguration_Encoding_DefaultBinary => Some((ObjectId::AggregateConfiguration_Encoding_DefaultBinary, xml_body_to_binary::<AggregateConfiguration>(body))),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultXml | ObjectId::MonitoringFilterResult_Encoding_DefaultBinary => Some((ObjectId::MonitoringFilterResult_Encoding_DefaultBinary, xml_body_to_binary::<MonitoringFilterResult>(body))),
+        ObjectId::MonitoringParameters_Encoding_DefaultXml | ObjectId::MonitoringParameters_Encoding_DefaultBinary => Some((ObjectId::MonitoringParameters_Encoding_DefaultBinary, xml_body_to_binary::<MonitoringParameters>(body))),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultXml | ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary, xml_body_to_binary::<MonitoredItemCreateRequest>(body))),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultXml | ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary, xml_body_to_binary::<MonitoredItemCreateResult>(body))),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultXml | ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => Some((ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary, xml_body_to_binary::<CreateMonitoredItemsRequest>(body))),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultXml | ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary => Some((ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary, xml_body_to_binary::<CreateMonitoredItemsResponse>(body))),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultXml | ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary, xml_body_to_binary::<MonitoredItemModifyRequest>(body))),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultXml | ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary, xml_body_to_binary::<MonitoredItemModifyResult>(body))),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultXml | ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => Some((ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary, xml_body_to_binary::<ModifyMonitoredItemsRequest>(body))),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultXml | ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary => Some((ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary, xml_body_to_binary::<ModifyMonitoredItemsResponse>(body))),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultXml | ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => Some((ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary, xml_body_to_binary::<SetMonitoringModeRequest>(body))),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultXml | ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => Some((ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary, xml_body_to_binary::<SetMonitoringModeResponse>(body))),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultXml | ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => Some((ObjectId::SetTriggeringRequest_Encoding_DefaultBinary, xml_body_to_binary::<SetTriggeringRequest>(body))),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultXml | ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => Some((ObjectId::SetTriggeringResponse_Encoding_DefaultBinary, xml_body_to_binary::<SetTriggeringResponse>(body))),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultXml | ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary, xml_body_to_binary::<DeleteMonitoredItemsRequest>(body))),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultXml | ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary, xml_body_to_binary::<DeleteMonitoredItemsResponse>(body))),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultXml | ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => Some((ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary, xml_body_to_binary::<CreateSubscriptionRequest>(body))),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultXml | ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary => Some((ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary, xml_body_to_binary::<CreateSubscriptionResponse>(body))),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultXml | ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => Some((ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary, xml_body_to_binary::<ModifySubscriptionRequest>(body))),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultXml | ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary => Some((ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary, xml_body_to_binary::<ModifySubscriptionResponse>(body))),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultXml | ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => Some((ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary, xml_body_to_binary::<SetPublishingModeRequest>(body))),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultXml | ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary => Some((ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary, xml_body_to_binary::<SetPublishingModeResponse>(body))),
+        ObjectId::NotificationMessage_Encoding_DefaultXml | ObjectId::NotificationMessage_Encoding_DefaultBinary => Some((ObjectId::NotificationMessage_Encoding_DefaultBinary, xml_body_to_binary::<NotificationMessage>(body))),
+        ObjectId::NotificationData_Encoding_DefaultXml | ObjectId::NotificationData_Encoding_DefaultBinary => Some((ObjectId::NotificationData_Encoding_DefaultBinary, xml_body_to_binary::<NotificationData>(body))),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultXml | ObjectId::MonitoredItemNotification_Encoding_DefaultBinary => Some((ObjectId::MonitoredItemNotification_Encoding_DefaultBinary, xml_body_to_binary::<MonitoredItemNotification>(body))),
+        ObjectId::EventFieldList_Encoding_DefaultXml | ObjectId::EventFieldList_Encoding_DefaultBinary => Some((ObjectId::EventFieldList_Encoding_DefaultBinary, xml_body_to_binary::<EventFieldList>(body))),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultXml | ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary => Some((ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary, xml_body_to_binary::<SubscriptionAcknowledgement>(body))),
+        ObjectId::PublishRequest_Encoding_DefaultXml | ObjectId::PublishRequest_Encoding_DefaultBinary => Some((ObjectId::PublishRequest_Encoding_DefaultBinary, xml_body_to_binary::<PublishRequest>(body))),
+        ObjectId::PublishResponse_Encoding_DefaultXml | ObjectId::PublishResponse_Encoding_DefaultBinary => Some((ObjectId::PublishResponse_Encoding_DefaultBinary, xml_body_to_binary::<PublishResponse>(body))),
+        ObjectId::RepublishRequest_Encoding_DefaultXml | ObjectId::RepublishRequest_Encoding_DefaultBinary => Some((ObjectId::RepublishRequest_Encoding_DefaultBinary, xml_body_to_binary::<RepublishRequest>(body))),
+        ObjectId::RepublishResponse_Encoding_DefaultXml | ObjectId::RepublishResponse_Encoding_DefaultBinary => Some((ObjectId::RepublishResponse_Encoding_DefaultBinary, xml_body_to_binary::<RepublishResponse>(body))),
+        ObjectId::TransferResult_Encoding_DefaultXml | ObjectId::TransferResult_Encoding_DefaultBinary => Some((ObjectId::TransferResult_Encoding_DefaultBinary, xml_body_to_binary::<TransferResult>(body))),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultXml | ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => Some((ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary, xml_body_to_binary::<TransferSubscriptionsRequest>(body))),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultXml | ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => Some((ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary, xml_body_to_binary::<TransferSubscriptionsResponse>(body))),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultXml | ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => Some((ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary, xml_body_to_binary::<DeleteSubscriptionsRequest>(body))),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultXml | ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary => Some((ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary, xml_body_to_binary::<DeleteSubscriptionsResponse>(body))),
+        ObjectId::BuildInfo_Encoding_DefaultXml | ObjectId::BuildInfo_Encoding_DefaultBinary => Some((ObjectId::BuildInfo_Encoding_DefaultBinary, xml_body_to_binary::<BuildInfo>(body))),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultXml | ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary => Some((ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary, xml_body_to_binary::<EndpointUrlListDataType>(body))),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultXml | ObjectId::NetworkGroupDataType_Encoding_DefaultBinary => Some((ObjectId::NetworkGroupDataType_Encoding_DefaultBinary, xml_body_to_binary::<NetworkGroupDataType>(body))),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultXml | ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary, xml_body_to_binary::<SamplingIntervalDiagnosticsDataType>(body))),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultXml | ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary => Some((ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary, xml_body_to_binary::<ServerDiagnosticsSummaryDataType>(body))),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultXml | ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary, xml_body_to_binary::<SessionDiagnosticsDataType>(body))),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultXml | ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary, xml_body_to_binary::<SessionSecurityDiagnosticsDataType>(body))),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultXml | ObjectId::ServiceCounterDataType_Encoding_DefaultBinary => Some((ObjectId::ServiceCounterDataType_Encoding_DefaultBinary, xml_body_to_binary::<ServiceCounterDataType>(body))),
+        ObjectId::StatusResult_Encoding_DefaultXml | ObjectId::StatusResult_Encoding_DefaultBinary => Some((ObjectId::StatusResult_Encoding_DefaultBinary, xml_body_to_binary::<StatusResult>(body))),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultXml | ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary => Some((ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary, xml_body_to_binary::<SubscriptionDiagnosticsDataType>(body))),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultXml | ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary => Some((ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary, xml_body_to_binary::<ModelChangeStructureDataType>(body))),
+        ObjectId::Range_Encoding_DefaultXml | ObjectId::Range_Encoding_DefaultBinary => Some((ObjectId::Range_Encoding_DefaultBinary, xml_body_to_binary::<Range>(body))),
+        ObjectId::EUInformation_Encoding_DefaultXml | ObjectId::EUInformation_Encoding_DefaultBinary => Some((ObjectId::EUInformation_Encoding_DefaultBinary, xml_body_to_binary::<EUInformation>(body))),
+        ObjectId::ComplexNumberType_Encoding_DefaultXml | ObjectId::ComplexNumberType_Encoding_DefaultBinary => Some((ObjectId::ComplexNumberType_Encoding_DefaultBinary, xml_body_to_binary::<ComplexNumberType>(body))),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultXml | ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary => Some((ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary, xml_body_to_binary::<DoubleComplexNumberType>(body))),
+        ObjectId::XVType_Encoding_DefaultXml | ObjectId::XVType_Encoding_DefaultBinary => Some((ObjectId::XVType_Encoding_DefaultBinary, xml_body_to_binary::<XVType>(body))),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultXml | ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary => Some((ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary, xml_body_to_binary::<ProgramDiagnosticDataType>(body))),
+        ObjectId::Annotation_Encoding_DefaultXml | ObjectId::Annotation_Encoding_DefaultBinary => Some((ObjectId::Annotation_Encoding_DefaultBinary, xml_body_to_binary::<Annotation>(body))),
+        _ => None
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -59,3 +61,46 @@ impl BinaryEncoder<FindServersOnNetworkRequest> for FindServersOnNetworkRequest
         })
     }
 }
+
+impl JsonEncoder<FindServersOnNetworkRequest> for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "StartingRecordId", &self.starting_record_id, reversible);
+        write_json_field(&mut map, "MaxRecordsToReturn", &self.max_records_to_return, reversible);
+        write_json_array_field(&mut map, "ServerCapabilityFilter", &self.server_capability_filter, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(FindServersOnNetworkRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            starting_record_id: read_json_field(value, "StartingRecordId")?,
+            max_records_to_return: read_json_field(value, "MaxRecordsToReturn")?,
+            server_capability_filter: read_json_array_field(value, "ServerCapabilityFilter")?,
+        })
+    }
+}
+
+impl XmlEncoder<FindServersOnNetworkRequest> for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "StartingRecordId", &self.starting_record_id);
+        write_xml_field(node, "MaxRecordsToReturn", &self.max_records_to_return);
+        write_xml_array_field(node, "ServerCapabilityFilter", "String", &self.server_capability_filter);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(FindServersOnNetworkRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            starting_record_id: read_xml_field(node, "StartingRecordId")?,
+            max_records_to_return: read_xml_field(node, "MaxRecordsToReturn")?,
+            server_capability_filter: read_xml_array_field(node, "ServerCapabilityFilter")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -55,3 +57,42 @@ impl BinaryEncoder<FindServersOnNetworkResponse> for FindServersOnNetworkRespons
         })
     }
 }
+
+impl JsonEncoder<FindServersOnNetworkResponse> for FindServersOnNetworkResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_field(&mut map, "LastCounterResetTime", &self.last_counter_reset_time, reversible);
+        write_json_array_field(&mut map, "Servers", &self.servers, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(FindServersOnNetworkResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            last_counter_reset_time: read_json_field(value, "LastCounterResetTime")?,
+            servers: read_json_array_field(value, "Servers")?,
+        })
+    }
+}
+
+impl XmlEncoder<FindServersOnNetworkResponse> for FindServersOnNetworkResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_field(node, "LastCounterResetTime", &self.last_counter_reset_time);
+        write_xml_array_field(node, "Servers", "ServerOnNetwork", &self.servers);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(FindServersOnNetworkResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            last_counter_reset_time: read_xml_field(node, "LastCounterResetTime")?,
+            servers: read_xml_array_field(node, "Servers")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -60,3 +62,46 @@ impl BinaryEncoder<FindServersRequest> for FindServersRequest {
         })
     }
 }
+
+impl JsonEncoder<FindServersRequest> for FindServersRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "EndpointUrl", &self.endpoint_url, reversible);
+        write_json_array_field(&mut map, "LocaleIds", &self.locale_ids, reversible);
+        write_json_array_field(&mut map, "ServerUris", &self.server_uris, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(FindServersRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            endpoint_url: read_json_field(value, "EndpointUrl")?,
+            locale_ids: read_json_array_field(value, "LocaleIds")?,
+            server_uris: read_json_array_field(value, "ServerUris")?,
+        })
+    }
+}
+
+impl XmlEncoder<FindServersRequest> for FindServersRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "EndpointUrl", &self.endpoint_url);
+        write_xml_array_field(node, "LocaleIds", "String", &self.locale_ids);
+        write_xml_array_field(node, "ServerUris", "String", &self.server_uris);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(FindServersRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            endpoint_url: read_xml_field(node, "EndpointUrl")?,
+            locale_ids: read_xml_array_field(node, "LocaleIds")?,
+            server_uris: read_xml_array_field(node, "ServerUris")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<FindServersResponse> for FindServersResponse {
         })
     }
 }
+
+impl JsonEncoder<FindServersResponse> for FindServersResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "Servers", &self.servers, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(FindServersResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            servers: read_json_array_field(value, "Servers")?,
+        })
+    }
+}
+
+impl XmlEncoder<FindServersResponse> for FindServersResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "Servers", "ApplicationDescription", &self.servers);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(FindServersResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            servers: read_xml_array_field(node, "Servers")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<GetEndpointsResponse> for GetEndpointsResponse {
         })
     }
 }
+
+impl JsonEncoder<GetEndpointsResponse> for GetEndpointsResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "Endpoints", &self.endpoints, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(GetEndpointsResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            endpoints: read_json_array_field(value, "Endpoints")?,
+        })
+    }
+}
+
+impl XmlEncoder<GetEndpointsResponse> for GetEndpointsResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "Endpoints", "EndpointDescription", &self.endpoints);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(GetEndpointsResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            endpoints: read_xml_array_field(node, "Endpoints")?,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use data_types::*;
 use data_value::DataValue;
 use date_time::DateTime;
 use encoding::*;
+use json::*;
+use xml::*;
 use node_id::NodeId;
 use node_ids::ObjectId;
 use profiles;
@@ -157,6 +159,58 @@ impl BinaryEncoder<RequestHeader> for RequestHeader {
     }
 }
 
+impl JsonEncoder<RequestHeader> for RequestHeader {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "AuthenticationToken", &self.authentication_token, reversible);
+        write_json_field(&mut map, "Timestamp", &self.timestamp, reversible);
+        write_json_field(&mut map, "RequestHandle", &self.request_handle, reversible);
+        write_json_field(&mut map, "ReturnDiagnostics", &self.return_diagnostics, reversible);
+        write_json_field(&mut map, "AuditEntryId", &self.audit_entry_id, reversible);
+        write_json_field(&mut map, "TimeoutHint", &self.timeout_hint, reversible);
+        write_json_field(&mut map, "AdditionalHeader", &self.additional_header, reversible);
+        JsonValue::Object(map)
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RequestHeader {
+            authentication_token: read_json_field(value, "AuthenticationToken")?,
+            timestamp: read_json_field(value, "Timestamp")?,
+            request_handle: read_json_field(value, "RequestHandle")?,
+            return_diagnostics: read_json_field(value, "ReturnDiagnostics")?,
+            audit_entry_id: read_json_field(value, "AuditEntryId")?,
+            timeout_hint: read_json_field(value, "TimeoutHint")?,
+            additional_header: read_json_field(value, "AdditionalHeader")?,
+        })
+    }
+}
+
+
+impl XmlEncoder<RequestHeader> for RequestHeader {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "AuthenticationToken", &self.authentication_token);
+        write_xml_field(node, "Timestamp", &self.timestamp);
+        write_xml_field(node, "RequestHandle", &self.request_handle);
+        write_xml_field(node, "ReturnDiagnostics", &self.return_diagnostics);
+        write_xml_field(node, "AuditEntryId", &self.audit_entry_id);
+        write_xml_field(node, "TimeoutHint", &self.timeout_hint);
+        write_xml_field(node, "AdditionalHeader", &self.additional_header);
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RequestHeader {
+            authentication_token: read_xml_field(node, "AuthenticationToken")?,
+            timestamp: read_xml_field(node, "Timestamp")?,
+            request_handle: read_xml_field(node, "RequestHandle")?,
+            return_diagnostics: read_xml_field(node, "ReturnDiagnostics")?,
+            audit_entry_id: read_xml_field(node, "AuditEntryId")?,
+            timeout_hint: read_xml_field(node, "TimeoutHint")?,
+            additional_header: read_xml_field(node, "AdditionalHeader")?,
+        })
+    }
+}
+
 impl RequestHeader {
     pub fn new(authentication_token: &NodeId, timestamp: &DateTime, request_handle: IntegerId) -> RequestHeader {
         RequestHeader {
@@ -224,6 +278,54 @@ impl BinaryEncoder<ResponseHeader> for ResponseHeader {
     }
 }
 
+impl JsonEncoder<ResponseHeader> for ResponseHeader {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Timestamp", &self.timestamp, reversible);
+        write_json_field(&mut map, "RequestHandle", &self.request_handle, reversible);
+        write_json_field(&mut map, "ServiceResult", &self.service_result, reversible);
+        write_json_field(&mut map, "ServiceDiagnostics", &self.service_diagnostics, reversible);
+        write_json_array_field(&mut map, "StringTable", &self.string_table, reversible);
+        write_json_field(&mut map, "AdditionalHeader", &self.additional_header, reversible);
+        JsonValue::Object(map)
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ResponseHeader {
+            timestamp: read_json_field(value, "Timestamp")?,
+            request_handle: read_json_field(value, "RequestHandle")?,
+            service_result: read_json_field(value, "ServiceResult")?,
+            service_diagnostics: read_json_field(value, "ServiceDiagnostics")?,
+            string_table: read_json_array_field(value, "StringTable")?,
+            additional_header: read_json_field(value, "AdditionalHeader")?,
+        })
+    }
+}
+
+
+impl XmlEncoder<ResponseHeader> for ResponseHeader {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Timestamp", &self.timestamp);
+        write_xml_field(node, "RequestHandle", &self.request_handle);
+        write_xml_field(node, "ServiceResult", &self.service_result);
+        write_xml_field(node, "ServiceDiagnostics", &self.service_diagnostics);
+        write_xml_array_field(node, "StringTable", "String", &self.string_table);
+        write_xml_field(node, "AdditionalHeader", &self.additional_header);
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ResponseHeader {
+            timestamp: read_xml_field(node, "Timestamp")?,
+            request_handle: read_xml_field(node, "RequestHandle")?,
+            service_result: read_xml_field(node, "ServiceResult")?,
+            service_diagnostics: read_xml_field(node, "ServiceDiagnostics")?,
+            string_table: read_xml_array_field(node, "StringTable")?,
+            additional_header: read_xml_field(node, "AdditionalHeader")?,
+        })
+    }
+}
+
 impl ResponseHeader {
     pub fn new_good(request_header: &RequestHeader) -> ResponseHeader {
         ResponseHeader::new_service_result(request_header, Good)
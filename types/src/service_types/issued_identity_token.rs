@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use string::UAString;
@@ -47,3 +49,42 @@ impl BinaryEncoder<IssuedIdentityToken> for IssuedIdentityToken {
         })
     }
 }
+
+impl JsonEncoder<IssuedIdentityToken> for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "PolicyId", &self.policy_id, reversible);
+        write_json_field(&mut map, "TokenData", &self.token_data, reversible);
+        write_json_field(&mut map, "EncryptionAlgorithm", &self.encryption_algorithm, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(IssuedIdentityToken {
+            policy_id: read_json_field(value, "PolicyId")?,
+            token_data: read_json_field(value, "TokenData")?,
+            encryption_algorithm: read_json_field(value, "EncryptionAlgorithm")?,
+        })
+    }
+}
+
+impl XmlEncoder<IssuedIdentityToken> for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "PolicyId", &self.policy_id);
+        write_xml_field(node, "TokenData", &self.token_data);
+        write_xml_field(node, "EncryptionAlgorithm", &self.encryption_algorithm);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(IssuedIdentityToken {
+            policy_id: read_xml_field(node, "PolicyId")?,
+            token_data: read_xml_field(node, "TokenData")?,
+            encryption_algorithm: read_xml_field(node, "EncryptionAlgorithm")?,
+        })
+    }
+}
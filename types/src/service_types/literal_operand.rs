@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use variant::Variant;
@@ -35,3 +37,34 @@ impl BinaryEncoder<LiteralOperand> for LiteralOperand {
         })
     }
 }
+
+impl JsonEncoder<LiteralOperand> for LiteralOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(LiteralOperand {
+            value: read_json_field(value, "Value")?,
+        })
+    }
+}
+
+impl XmlEncoder<LiteralOperand> for LiteralOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Value", &self.value);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(LiteralOperand {
+            value: read_xml_field(node, "Value")?,
+        })
+    }
+}
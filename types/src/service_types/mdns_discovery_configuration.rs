@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use string::UAString;
@@ -41,3 +43,38 @@ impl BinaryEncoder<MdnsDiscoveryConfiguration> for MdnsDiscoveryConfiguration {
         })
     }
 }
+
+impl JsonEncoder<MdnsDiscoveryConfiguration> for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "MdnsServerName", &self.mdns_server_name, reversible);
+        write_json_array_field(&mut map, "ServerCapabilities", &self.server_capabilities, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MdnsDiscoveryConfiguration {
+            mdns_server_name: read_json_field(value, "MdnsServerName")?,
+            server_capabilities: read_json_array_field(value, "ServerCapabilities")?,
+        })
+    }
+}
+
+impl XmlEncoder<MdnsDiscoveryConfiguration> for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "MdnsServerName", &self.mdns_server_name);
+        write_xml_array_field(node, "ServerCapabilities", "String", &self.server_capabilities);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MdnsDiscoveryConfiguration {
+            mdns_server_name: read_xml_field(node, "MdnsServerName")?,
+            server_capabilities: read_xml_array_field(node, "ServerCapabilities")?,
+        })
+    }
+}
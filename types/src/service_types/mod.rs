@@ -8,6 +8,9 @@ mod impls;
 pub use self::enums::*;
 pub use self::impls::*;
 
+// Generated dispatch of extension object bodies to their types
+pub mod extension_objects;
+
 // All of the remaining are generated by script
 
 mod trust_list_data_type;
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -53,3 +55,42 @@ impl BinaryEncoder<ModelChangeStructureDataType> for ModelChangeStructureDataTyp
         })
     }
 }
+
+impl JsonEncoder<ModelChangeStructureDataType> for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Affected", &self.affected, reversible);
+        write_json_field(&mut map, "AffectedType", &self.affected_type, reversible);
+        write_json_field(&mut map, "Verb", &self.verb, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ModelChangeStructureDataType {
+            affected: read_json_field(value, "Affected")?,
+            affected_type: read_json_field(value, "AffectedType")?,
+            verb: read_json_field(value, "Verb")?,
+        })
+    }
+}
+
+impl XmlEncoder<ModelChangeStructureDataType> for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Affected", &self.affected);
+        write_xml_field(node, "AffectedType", &self.affected_type);
+        write_xml_field(node, "Verb", &self.verb);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ModelChangeStructureDataType {
+            affected: read_xml_field(node, "Affected")?,
+            affected_type: read_xml_field(node, "AffectedType")?,
+            verb: read_xml_field(node, "Verb")?,
+        })
+    }
+}
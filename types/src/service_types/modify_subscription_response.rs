@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -58,3 +60,46 @@ impl BinaryEncoder<ModifySubscriptionResponse> for ModifySubscriptionResponse {
         })
     }
 }
+
+impl JsonEncoder<ModifySubscriptionResponse> for ModifySubscriptionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_field(&mut map, "RevisedPublishingInterval", &self.revised_publishing_interval, reversible);
+        write_json_field(&mut map, "RevisedLifetimeCount", &self.revised_lifetime_count, reversible);
+        write_json_field(&mut map, "RevisedMaxKeepAliveCount", &self.revised_max_keep_alive_count, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ModifySubscriptionResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            revised_publishing_interval: read_json_field(value, "RevisedPublishingInterval")?,
+            revised_lifetime_count: read_json_field(value, "RevisedLifetimeCount")?,
+            revised_max_keep_alive_count: read_json_field(value, "RevisedMaxKeepAliveCount")?,
+        })
+    }
+}
+
+impl XmlEncoder<ModifySubscriptionResponse> for ModifySubscriptionResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_field(node, "RevisedPublishingInterval", &self.revised_publishing_interval);
+        write_xml_field(node, "RevisedLifetimeCount", &self.revised_lifetime_count);
+        write_xml_field(node, "RevisedMaxKeepAliveCount", &self.revised_max_keep_alive_count);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ModifySubscriptionResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            revised_publishing_interval: read_xml_field(node, "RevisedPublishingInterval")?,
+            revised_lifetime_count: read_xml_field(node, "RevisedLifetimeCount")?,
+            revised_max_keep_alive_count: read_xml_field(node, "RevisedMaxKeepAliveCount")?,
+        })
+    }
+}
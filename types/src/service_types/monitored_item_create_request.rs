@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -55,3 +57,42 @@ impl BinaryEncoder<MonitoredItemCreateRequest> for MonitoredItemCreateRequest {
         })
     }
 }
+
+impl JsonEncoder<MonitoredItemCreateRequest> for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ItemToMonitor", &self.item_to_monitor, reversible);
+        write_json_field(&mut map, "MonitoringMode", &self.monitoring_mode, reversible);
+        write_json_field(&mut map, "RequestedParameters", &self.requested_parameters, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MonitoredItemCreateRequest {
+            item_to_monitor: read_json_field(value, "ItemToMonitor")?,
+            monitoring_mode: read_json_field(value, "MonitoringMode")?,
+            requested_parameters: read_json_field(value, "RequestedParameters")?,
+        })
+    }
+}
+
+impl XmlEncoder<MonitoredItemCreateRequest> for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ItemToMonitor", &self.item_to_monitor);
+        write_xml_field(node, "MonitoringMode", &self.monitoring_mode);
+        write_xml_field(node, "RequestedParameters", &self.requested_parameters);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MonitoredItemCreateRequest {
+            item_to_monitor: read_xml_field(node, "ItemToMonitor")?,
+            monitoring_mode: read_xml_field(node, "MonitoringMode")?,
+            requested_parameters: read_xml_field(node, "RequestedParameters")?,
+        })
+    }
+}
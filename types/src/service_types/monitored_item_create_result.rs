@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -64,3 +66,50 @@ impl BinaryEncoder<MonitoredItemCreateResult> for MonitoredItemCreateResult {
         })
     }
 }
+
+impl JsonEncoder<MonitoredItemCreateResult> for MonitoredItemCreateResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_field(&mut map, "MonitoredItemId", &self.monitored_item_id, reversible);
+        write_json_field(&mut map, "RevisedSamplingInterval", &self.revised_sampling_interval, reversible);
+        write_json_field(&mut map, "RevisedQueueSize", &self.revised_queue_size, reversible);
+        write_json_field(&mut map, "FilterResult", &self.filter_result, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MonitoredItemCreateResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            monitored_item_id: read_json_field(value, "MonitoredItemId")?,
+            revised_sampling_interval: read_json_field(value, "RevisedSamplingInterval")?,
+            revised_queue_size: read_json_field(value, "RevisedQueueSize")?,
+            filter_result: read_json_field(value, "FilterResult")?,
+        })
+    }
+}
+
+impl XmlEncoder<MonitoredItemCreateResult> for MonitoredItemCreateResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_field(node, "MonitoredItemId", &self.monitored_item_id);
+        write_xml_field(node, "RevisedSamplingInterval", &self.revised_sampling_interval);
+        write_xml_field(node, "RevisedQueueSize", &self.revised_queue_size);
+        write_xml_field(node, "FilterResult", &self.filter_result);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MonitoredItemCreateResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            monitored_item_id: read_xml_field(node, "MonitoredItemId")?,
+            revised_sampling_interval: read_xml_field(node, "RevisedSamplingInterval")?,
+            revised_queue_size: read_xml_field(node, "RevisedQueueSize")?,
+            filter_result: read_xml_field(node, "FilterResult")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -48,3 +50,38 @@ impl BinaryEncoder<MonitoredItemModifyRequest> for MonitoredItemModifyRequest {
         })
     }
 }
+
+impl JsonEncoder<MonitoredItemModifyRequest> for MonitoredItemModifyRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "MonitoredItemId", &self.monitored_item_id, reversible);
+        write_json_field(&mut map, "RequestedParameters", &self.requested_parameters, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MonitoredItemModifyRequest {
+            monitored_item_id: read_json_field(value, "MonitoredItemId")?,
+            requested_parameters: read_json_field(value, "RequestedParameters")?,
+        })
+    }
+}
+
+impl XmlEncoder<MonitoredItemModifyRequest> for MonitoredItemModifyRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "MonitoredItemId", &self.monitored_item_id);
+        write_xml_field(node, "RequestedParameters", &self.requested_parameters);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MonitoredItemModifyRequest {
+            monitored_item_id: read_xml_field(node, "MonitoredItemId")?,
+            requested_parameters: read_xml_field(node, "RequestedParameters")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -48,3 +50,38 @@ impl BinaryEncoder<MonitoredItemNotification> for MonitoredItemNotification {
         })
     }
 }
+
+impl JsonEncoder<MonitoredItemNotification> for MonitoredItemNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ClientHandle", &self.client_handle, reversible);
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MonitoredItemNotification {
+            client_handle: read_json_field(value, "ClientHandle")?,
+            value: read_json_field(value, "Value")?,
+        })
+    }
+}
+
+impl XmlEncoder<MonitoredItemNotification> for MonitoredItemNotification {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ClientHandle", &self.client_handle);
+        write_xml_field(node, "Value", &self.value);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MonitoredItemNotification {
+            client_handle: read_xml_field(node, "ClientHandle")?,
+            value: read_xml_field(node, "Value")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -35,3 +37,29 @@ impl BinaryEncoder<MonitoringFilter> for MonitoringFilter {
         })
     }
 }
+
+impl JsonEncoder<MonitoringFilter> for MonitoringFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        JsonValue::Object(JsonMap::new())
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MonitoringFilter {
+        })
+    }
+}
+
+impl XmlEncoder<MonitoringFilter> for MonitoringFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MonitoringFilter {
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -63,3 +65,50 @@ impl BinaryEncoder<MonitoringParameters> for MonitoringParameters {
         })
     }
 }
+
+impl JsonEncoder<MonitoringParameters> for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ClientHandle", &self.client_handle, reversible);
+        write_json_field(&mut map, "SamplingInterval", &self.sampling_interval, reversible);
+        write_json_field(&mut map, "Filter", &self.filter, reversible);
+        write_json_field(&mut map, "QueueSize", &self.queue_size, reversible);
+        write_json_field(&mut map, "DiscardOldest", &self.discard_oldest, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(MonitoringParameters {
+            client_handle: read_json_field(value, "ClientHandle")?,
+            sampling_interval: read_json_field(value, "SamplingInterval")?,
+            filter: read_json_field(value, "Filter")?,
+            queue_size: read_json_field(value, "QueueSize")?,
+            discard_oldest: read_json_field(value, "DiscardOldest")?,
+        })
+    }
+}
+
+impl XmlEncoder<MonitoringParameters> for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ClientHandle", &self.client_handle);
+        write_xml_field(node, "SamplingInterval", &self.sampling_interval);
+        write_xml_field(node, "Filter", &self.filter);
+        write_xml_field(node, "QueueSize", &self.queue_size);
+        write_xml_field(node, "DiscardOldest", &self.discard_oldest);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(MonitoringParameters {
+            client_handle: read_xml_field(node, "ClientHandle")?,
+            sampling_interval: read_xml_field(node, "SamplingInterval")?,
+            filter: read_xml_field(node, "Filter")?,
+            queue_size: read_xml_field(node, "QueueSize")?,
+            discard_oldest: read_xml_field(node, "DiscardOldest")?,
+        })
+    }
+}
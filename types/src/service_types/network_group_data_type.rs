@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<NetworkGroupDataType> for NetworkGroupDataType {
         })
     }
 }
+
+impl JsonEncoder<NetworkGroupDataType> for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ServerUri", &self.server_uri, reversible);
+        write_json_array_field(&mut map, "NetworkPaths", &self.network_paths, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(NetworkGroupDataType {
+            server_uri: read_json_field(value, "ServerUri")?,
+            network_paths: read_json_array_field(value, "NetworkPaths")?,
+        })
+    }
+}
+
+impl XmlEncoder<NetworkGroupDataType> for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ServerUri", &self.server_uri);
+        write_xml_array_field(node, "NetworkPaths", "EndpointUrlListDataType", &self.network_paths);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(NetworkGroupDataType {
+            server_uri: read_xml_field(node, "ServerUri")?,
+            network_paths: read_xml_array_field(node, "NetworkPaths")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -58,3 +60,46 @@ impl BinaryEncoder<NodeReference> for NodeReference {
         })
     }
 }
+
+impl JsonEncoder<NodeReference> for NodeReference {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "IsForward", &self.is_forward, reversible);
+        write_json_array_field(&mut map, "ReferencedNodeIds", &self.referenced_node_ids, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(NodeReference {
+            node_id: read_json_field(value, "NodeId")?,
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            is_forward: read_json_field(value, "IsForward")?,
+            referenced_node_ids: read_json_array_field(value, "ReferencedNodeIds")?,
+        })
+    }
+}
+
+impl XmlEncoder<NodeReference> for NodeReference {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "IsForward", &self.is_forward);
+        write_xml_array_field(node, "ReferencedNodeIds", "NodeId", &self.referenced_node_ids);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(NodeReference {
+            node_id: read_xml_field(node, "NodeId")?,
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            is_forward: read_xml_field(node, "IsForward")?,
+            referenced_node_ids: read_xml_array_field(node, "ReferencedNodeIds")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<NodeTypeDescription> for NodeTypeDescription {
         })
     }
 }
+
+impl JsonEncoder<NodeTypeDescription> for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "TypeDefinitionNode", &self.type_definition_node, reversible);
+        write_json_field(&mut map, "IncludeSubTypes", &self.include_sub_types, reversible);
+        write_json_array_field(&mut map, "DataToReturn", &self.data_to_return, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(NodeTypeDescription {
+            type_definition_node: read_json_field(value, "TypeDefinitionNode")?,
+            include_sub_types: read_json_field(value, "IncludeSubTypes")?,
+            data_to_return: read_json_array_field(value, "DataToReturn")?,
+        })
+    }
+}
+
+impl XmlEncoder<NodeTypeDescription> for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "TypeDefinitionNode", &self.type_definition_node);
+        write_xml_field(node, "IncludeSubTypes", &self.include_sub_types);
+        write_xml_array_field(node, "DataToReturn", "QueryDataDescription", &self.data_to_return);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(NodeTypeDescription {
+            type_definition_node: read_xml_field(node, "TypeDefinitionNode")?,
+            include_sub_types: read_xml_field(node, "IncludeSubTypes")?,
+            data_to_return: read_xml_array_field(node, "DataToReturn")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<NotificationMessage> for NotificationMessage {
         })
     }
 }
+
+impl JsonEncoder<NotificationMessage> for NotificationMessage {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SequenceNumber", &self.sequence_number, reversible);
+        write_json_field(&mut map, "PublishTime", &self.publish_time, reversible);
+        write_json_array_field(&mut map, "NotificationData", &self.notification_data, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(NotificationMessage {
+            sequence_number: read_json_field(value, "SequenceNumber")?,
+            publish_time: read_json_field(value, "PublishTime")?,
+            notification_data: read_json_array_field(value, "NotificationData")?,
+        })
+    }
+}
+
+impl XmlEncoder<NotificationMessage> for NotificationMessage {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SequenceNumber", &self.sequence_number);
+        write_xml_field(node, "PublishTime", &self.publish_time);
+        write_xml_array_field(node, "NotificationData", "ExtensionObject", &self.notification_data);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(NotificationMessage {
+            sequence_number: read_xml_field(node, "SequenceNumber")?,
+            publish_time: read_xml_field(node, "PublishTime")?,
+            notification_data: read_xml_array_field(node, "NotificationData")?,
+        })
+    }
+}
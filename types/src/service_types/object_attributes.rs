@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use basic_types::LocalizedText;
@@ -61,3 +63,54 @@ impl BinaryEncoder<ObjectAttributes> for ObjectAttributes {
         })
     }
 }
+
+impl JsonEncoder<ObjectAttributes> for ObjectAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SpecifiedAttributes", &self.specified_attributes, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        write_json_field(&mut map, "WriteMask", &self.write_mask, reversible);
+        write_json_field(&mut map, "UserWriteMask", &self.user_write_mask, reversible);
+        write_json_field(&mut map, "EventNotifier", &self.event_notifier, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ObjectAttributes {
+            specified_attributes: read_json_field(value, "SpecifiedAttributes")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            description: read_json_field(value, "Description")?,
+            write_mask: read_json_field(value, "WriteMask")?,
+            user_write_mask: read_json_field(value, "UserWriteMask")?,
+            event_notifier: read_json_field(value, "EventNotifier")?,
+        })
+    }
+}
+
+impl XmlEncoder<ObjectAttributes> for ObjectAttributes {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SpecifiedAttributes", &self.specified_attributes);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "Description", &self.description);
+        write_xml_field(node, "WriteMask", &self.write_mask);
+        write_xml_field(node, "UserWriteMask", &self.user_write_mask);
+        write_xml_field(node, "EventNotifier", &self.event_notifier);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ObjectAttributes {
+            specified_attributes: read_xml_field(node, "SpecifiedAttributes")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            description: read_xml_field(node, "Description")?,
+            write_mask: read_xml_field(node, "WriteMask")?,
+            user_write_mask: read_xml_field(node, "UserWriteMask")?,
+            event_notifier: read_xml_field(node, "EventNotifier")?,
+        })
+    }
+}
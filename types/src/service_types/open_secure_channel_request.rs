@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -72,3 +74,54 @@ impl BinaryEncoder<OpenSecureChannelRequest> for OpenSecureChannelRequest {
         })
     }
 }
+
+impl JsonEncoder<OpenSecureChannelRequest> for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "ClientProtocolVersion", &self.client_protocol_version, reversible);
+        write_json_field(&mut map, "RequestType", &self.request_type, reversible);
+        write_json_field(&mut map, "SecurityMode", &self.security_mode, reversible);
+        write_json_field(&mut map, "ClientNonce", &self.client_nonce, reversible);
+        write_json_field(&mut map, "RequestedLifetime", &self.requested_lifetime, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(OpenSecureChannelRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            client_protocol_version: read_json_field(value, "ClientProtocolVersion")?,
+            request_type: read_json_field(value, "RequestType")?,
+            security_mode: read_json_field(value, "SecurityMode")?,
+            client_nonce: read_json_field(value, "ClientNonce")?,
+            requested_lifetime: read_json_field(value, "RequestedLifetime")?,
+        })
+    }
+}
+
+impl XmlEncoder<OpenSecureChannelRequest> for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "ClientProtocolVersion", &self.client_protocol_version);
+        write_xml_field(node, "RequestType", &self.request_type);
+        write_xml_field(node, "SecurityMode", &self.security_mode);
+        write_xml_field(node, "ClientNonce", &self.client_nonce);
+        write_xml_field(node, "RequestedLifetime", &self.requested_lifetime);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(OpenSecureChannelRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            client_protocol_version: read_xml_field(node, "ClientProtocolVersion")?,
+            request_type: read_xml_field(node, "RequestType")?,
+            security_mode: read_xml_field(node, "SecurityMode")?,
+            client_nonce: read_xml_field(node, "ClientNonce")?,
+            requested_lifetime: read_xml_field(node, "RequestedLifetime")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -61,3 +63,46 @@ impl BinaryEncoder<OpenSecureChannelResponse> for OpenSecureChannelResponse {
         })
     }
 }
+
+impl JsonEncoder<OpenSecureChannelResponse> for OpenSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_field(&mut map, "ServerProtocolVersion", &self.server_protocol_version, reversible);
+        write_json_field(&mut map, "SecurityToken", &self.security_token, reversible);
+        write_json_field(&mut map, "ServerNonce", &self.server_nonce, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(OpenSecureChannelResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            server_protocol_version: read_json_field(value, "ServerProtocolVersion")?,
+            security_token: read_json_field(value, "SecurityToken")?,
+            server_nonce: read_json_field(value, "ServerNonce")?,
+        })
+    }
+}
+
+impl XmlEncoder<OpenSecureChannelResponse> for OpenSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_field(node, "ServerProtocolVersion", &self.server_protocol_version);
+        write_xml_field(node, "SecurityToken", &self.security_token);
+        write_xml_field(node, "ServerNonce", &self.server_nonce);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(OpenSecureChannelResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            server_protocol_version: read_xml_field(node, "ServerProtocolVersion")?,
+            security_token: read_xml_field(node, "SecurityToken")?,
+            server_nonce: read_xml_field(node, "ServerNonce")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<OptionSet> for OptionSet {
         })
     }
 }
+
+impl JsonEncoder<OptionSet> for OptionSet {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        write_json_field(&mut map, "ValidBits", &self.valid_bits, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(OptionSet {
+            value: read_json_field(value, "Value")?,
+            valid_bits: read_json_field(value, "ValidBits")?,
+        })
+    }
+}
+
+impl XmlEncoder<OptionSet> for OptionSet {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Value", &self.value);
+        write_xml_field(node, "ValidBits", &self.valid_bits);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(OptionSet {
+            value: read_xml_field(node, "Value")?,
+            valid_bits: read_xml_field(node, "ValidBits")?,
+        })
+    }
+}
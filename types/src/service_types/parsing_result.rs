@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<ParsingResult> for ParsingResult {
         })
     }
 }
+
+impl JsonEncoder<ParsingResult> for ParsingResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_array_field(&mut map, "DataStatusCodes", &self.data_status_codes, reversible);
+        write_json_array_field(&mut map, "DataDiagnosticInfos", &self.data_diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ParsingResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            data_status_codes: read_json_array_field(value, "DataStatusCodes")?,
+            data_diagnostic_infos: read_json_array_field(value, "DataDiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<ParsingResult> for ParsingResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_array_field(node, "DataStatusCodes", "StatusCode", &self.data_status_codes);
+        write_xml_array_field(node, "DataDiagnosticInfos", "DiagnosticInfo", &self.data_diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ParsingResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            data_status_codes: read_xml_array_field(node, "DataStatusCodes")?,
+            data_diagnostic_infos: read_xml_array_field(node, "DataDiagnosticInfos")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -92,3 +94,70 @@ impl BinaryEncoder<ProgramDiagnosticDataType> for ProgramDiagnosticDataType {
         })
     }
 }
+
+impl JsonEncoder<ProgramDiagnosticDataType> for ProgramDiagnosticDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "CreateSessionId", &self.create_session_id, reversible);
+        write_json_field(&mut map, "CreateClientName", &self.create_client_name, reversible);
+        write_json_field(&mut map, "InvocationCreationTime", &self.invocation_creation_time, reversible);
+        write_json_field(&mut map, "LastTransitionTime", &self.last_transition_time, reversible);
+        write_json_field(&mut map, "LastMethodCall", &self.last_method_call, reversible);
+        write_json_field(&mut map, "LastMethodSessionId", &self.last_method_session_id, reversible);
+        write_json_array_field(&mut map, "LastMethodInputArguments", &self.last_method_input_arguments, reversible);
+        write_json_array_field(&mut map, "LastMethodOutputArguments", &self.last_method_output_arguments, reversible);
+        write_json_field(&mut map, "LastMethodCallTime", &self.last_method_call_time, reversible);
+        write_json_field(&mut map, "LastMethodReturnStatus", &self.last_method_return_status, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ProgramDiagnosticDataType {
+            create_session_id: read_json_field(value, "CreateSessionId")?,
+            create_client_name: read_json_field(value, "CreateClientName")?,
+            invocation_creation_time: read_json_field(value, "InvocationCreationTime")?,
+            last_transition_time: read_json_field(value, "LastTransitionTime")?,
+            last_method_call: read_json_field(value, "LastMethodCall")?,
+            last_method_session_id: read_json_field(value, "LastMethodSessionId")?,
+            last_method_input_arguments: read_json_array_field(value, "LastMethodInputArguments")?,
+            last_method_output_arguments: read_json_array_field(value, "LastMethodOutputArguments")?,
+            last_method_call_time: read_json_field(value, "LastMethodCallTime")?,
+            last_method_return_status: read_json_field(value, "LastMethodReturnStatus")?,
+        })
+    }
+}
+
+impl XmlEncoder<ProgramDiagnosticDataType> for ProgramDiagnosticDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "CreateSessionId", &self.create_session_id);
+        write_xml_field(node, "CreateClientName", &self.create_client_name);
+        write_xml_field(node, "InvocationCreationTime", &self.invocation_creation_time);
+        write_xml_field(node, "LastTransitionTime", &self.last_transition_time);
+        write_xml_field(node, "LastMethodCall", &self.last_method_call);
+        write_xml_field(node, "LastMethodSessionId", &self.last_method_session_id);
+        write_xml_array_field(node, "LastMethodInputArguments", "Argument", &self.last_method_input_arguments);
+        write_xml_array_field(node, "LastMethodOutputArguments", "Argument", &self.last_method_output_arguments);
+        write_xml_field(node, "LastMethodCallTime", &self.last_method_call_time);
+        write_xml_field(node, "LastMethodReturnStatus", &self.last_method_return_status);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ProgramDiagnosticDataType {
+            create_session_id: read_xml_field(node, "CreateSessionId")?,
+            create_client_name: read_xml_field(node, "CreateClientName")?,
+            invocation_creation_time: read_xml_field(node, "InvocationCreationTime")?,
+            last_transition_time: read_xml_field(node, "LastTransitionTime")?,
+            last_method_call: read_xml_field(node, "LastMethodCall")?,
+            last_method_session_id: read_xml_field(node, "LastMethodSessionId")?,
+            last_method_input_arguments: read_xml_array_field(node, "LastMethodInputArguments")?,
+            last_method_output_arguments: read_xml_array_field(node, "LastMethodOutputArguments")?,
+            last_method_call_time: read_xml_field(node, "LastMethodCallTime")?,
+            last_method_return_status: read_xml_field(node, "LastMethodReturnStatus")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<PublishRequest> for PublishRequest {
         })
     }
 }
+
+impl JsonEncoder<PublishRequest> for PublishRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "SubscriptionAcknowledgements", &self.subscription_acknowledgements, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(PublishRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            subscription_acknowledgements: read_json_array_field(value, "SubscriptionAcknowledgements")?,
+        })
+    }
+}
+
+impl XmlEncoder<PublishRequest> for PublishRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "SubscriptionAcknowledgements", "SubscriptionAcknowledgement", &self.subscription_acknowledgements);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(PublishRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            subscription_acknowledgements: read_xml_array_field(node, "SubscriptionAcknowledgements")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -76,3 +78,58 @@ impl BinaryEncoder<PublishResponse> for PublishResponse {
         })
     }
 }
+
+impl JsonEncoder<PublishResponse> for PublishResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_array_field(&mut map, "AvailableSequenceNumbers", &self.available_sequence_numbers, reversible);
+        write_json_field(&mut map, "MoreNotifications", &self.more_notifications, reversible);
+        write_json_field(&mut map, "NotificationMessage", &self.notification_message, reversible);
+        write_json_array_field(&mut map, "Results", &self.results, reversible);
+        write_json_array_field(&mut map, "DiagnosticInfos", &self.diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(PublishResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            available_sequence_numbers: read_json_array_field(value, "AvailableSequenceNumbers")?,
+            more_notifications: read_json_field(value, "MoreNotifications")?,
+            notification_message: read_json_field(value, "NotificationMessage")?,
+            results: read_json_array_field(value, "Results")?,
+            diagnostic_infos: read_json_array_field(value, "DiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<PublishResponse> for PublishResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_array_field(node, "AvailableSequenceNumbers", "UInt32", &self.available_sequence_numbers);
+        write_xml_field(node, "MoreNotifications", &self.more_notifications);
+        write_xml_field(node, "NotificationMessage", &self.notification_message);
+        write_xml_array_field(node, "Results", "StatusCode", &self.results);
+        write_xml_array_field(node, "DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(PublishResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            available_sequence_numbers: read_xml_array_field(node, "AvailableSequenceNumbers")?,
+            more_notifications: read_xml_field(node, "MoreNotifications")?,
+            notification_message: read_xml_field(node, "NotificationMessage")?,
+            results: read_xml_array_field(node, "Results")?,
+            diagnostic_infos: read_xml_array_field(node, "DiagnosticInfos")?,
+        })
+    }
+}
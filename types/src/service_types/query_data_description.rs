@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<QueryDataDescription> for QueryDataDescription {
         })
     }
 }
+
+impl JsonEncoder<QueryDataDescription> for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RelativePath", &self.relative_path, reversible);
+        write_json_field(&mut map, "AttributeId", &self.attribute_id, reversible);
+        write_json_field(&mut map, "IndexRange", &self.index_range, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QueryDataDescription {
+            relative_path: read_json_field(value, "RelativePath")?,
+            attribute_id: read_json_field(value, "AttributeId")?,
+            index_range: read_json_field(value, "IndexRange")?,
+        })
+    }
+}
+
+impl XmlEncoder<QueryDataDescription> for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RelativePath", &self.relative_path);
+        write_xml_field(node, "AttributeId", &self.attribute_id);
+        write_xml_field(node, "IndexRange", &self.index_range);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QueryDataDescription {
+            relative_path: read_xml_field(node, "RelativePath")?,
+            attribute_id: read_xml_field(node, "AttributeId")?,
+            index_range: read_xml_field(node, "IndexRange")?,
+        })
+    }
+}
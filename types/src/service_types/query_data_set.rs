@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<QueryDataSet> for QueryDataSet {
         })
     }
 }
+
+impl JsonEncoder<QueryDataSet> for QueryDataSet {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "TypeDefinitionNode", &self.type_definition_node, reversible);
+        write_json_array_field(&mut map, "Values", &self.values, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QueryDataSet {
+            node_id: read_json_field(value, "NodeId")?,
+            type_definition_node: read_json_field(value, "TypeDefinitionNode")?,
+            values: read_json_array_field(value, "Values")?,
+        })
+    }
+}
+
+impl XmlEncoder<QueryDataSet> for QueryDataSet {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "TypeDefinitionNode", &self.type_definition_node);
+        write_xml_array_field(node, "Values", "Variant", &self.values);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QueryDataSet {
+            node_id: read_xml_field(node, "NodeId")?,
+            type_definition_node: read_xml_field(node, "TypeDefinitionNode")?,
+            values: read_xml_array_field(node, "Values")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -71,3 +73,54 @@ impl BinaryEncoder<QueryFirstRequest> for QueryFirstRequest {
         })
     }
 }
+
+impl JsonEncoder<QueryFirstRequest> for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "View", &self.view, reversible);
+        write_json_array_field(&mut map, "NodeTypes", &self.node_types, reversible);
+        write_json_field(&mut map, "Filter", &self.filter, reversible);
+        write_json_field(&mut map, "MaxDataSetsToReturn", &self.max_data_sets_to_return, reversible);
+        write_json_field(&mut map, "MaxReferencesToReturn", &self.max_references_to_return, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QueryFirstRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            view: read_json_field(value, "View")?,
+            node_types: read_json_array_field(value, "NodeTypes")?,
+            filter: read_json_field(value, "Filter")?,
+            max_data_sets_to_return: read_json_field(value, "MaxDataSetsToReturn")?,
+            max_references_to_return: read_json_field(value, "MaxReferencesToReturn")?,
+        })
+    }
+}
+
+impl XmlEncoder<QueryFirstRequest> for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "View", &self.view);
+        write_xml_array_field(node, "NodeTypes", "NodeTypeDescription", &self.node_types);
+        write_xml_field(node, "Filter", &self.filter);
+        write_xml_field(node, "MaxDataSetsToReturn", &self.max_data_sets_to_return);
+        write_xml_field(node, "MaxReferencesToReturn", &self.max_references_to_return);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QueryFirstRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            view: read_xml_field(node, "View")?,
+            node_types: read_xml_array_field(node, "NodeTypes")?,
+            filter: read_xml_field(node, "Filter")?,
+            max_data_sets_to_return: read_xml_field(node, "MaxDataSetsToReturn")?,
+            max_references_to_return: read_xml_field(node, "MaxReferencesToReturn")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -73,3 +75,54 @@ impl BinaryEncoder<QueryFirstResponse> for QueryFirstResponse {
         })
     }
 }
+
+impl JsonEncoder<QueryFirstResponse> for QueryFirstResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "QueryDataSets", &self.query_data_sets, reversible);
+        write_json_field(&mut map, "ContinuationPoint", &self.continuation_point, reversible);
+        write_json_array_field(&mut map, "ParsingResults", &self.parsing_results, reversible);
+        write_json_array_field(&mut map, "DiagnosticInfos", &self.diagnostic_infos, reversible);
+        write_json_field(&mut map, "FilterResult", &self.filter_result, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QueryFirstResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            query_data_sets: read_json_array_field(value, "QueryDataSets")?,
+            continuation_point: read_json_field(value, "ContinuationPoint")?,
+            parsing_results: read_json_array_field(value, "ParsingResults")?,
+            diagnostic_infos: read_json_array_field(value, "DiagnosticInfos")?,
+            filter_result: read_json_field(value, "FilterResult")?,
+        })
+    }
+}
+
+impl XmlEncoder<QueryFirstResponse> for QueryFirstResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "QueryDataSets", "QueryDataSet", &self.query_data_sets);
+        write_xml_field(node, "ContinuationPoint", &self.continuation_point);
+        write_xml_array_field(node, "ParsingResults", "ParsingResult", &self.parsing_results);
+        write_xml_array_field(node, "DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos);
+        write_xml_field(node, "FilterResult", &self.filter_result);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QueryFirstResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            query_data_sets: read_xml_array_field(node, "QueryDataSets")?,
+            continuation_point: read_xml_field(node, "ContinuationPoint")?,
+            parsing_results: read_xml_array_field(node, "ParsingResults")?,
+            diagnostic_infos: read_xml_array_field(node, "DiagnosticInfos")?,
+            filter_result: read_xml_field(node, "FilterResult")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -54,3 +56,42 @@ impl BinaryEncoder<QueryNextRequest> for QueryNextRequest {
         })
     }
 }
+
+impl JsonEncoder<QueryNextRequest> for QueryNextRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "ReleaseContinuationPoint", &self.release_continuation_point, reversible);
+        write_json_field(&mut map, "ContinuationPoint", &self.continuation_point, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QueryNextRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            release_continuation_point: read_json_field(value, "ReleaseContinuationPoint")?,
+            continuation_point: read_json_field(value, "ContinuationPoint")?,
+        })
+    }
+}
+
+impl XmlEncoder<QueryNextRequest> for QueryNextRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "ReleaseContinuationPoint", &self.release_continuation_point);
+        write_xml_field(node, "ContinuationPoint", &self.continuation_point);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QueryNextRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            release_continuation_point: read_xml_field(node, "ReleaseContinuationPoint")?,
+            continuation_point: read_xml_field(node, "ContinuationPoint")?,
+        })
+    }
+}
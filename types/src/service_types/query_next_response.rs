@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -55,3 +57,42 @@ impl BinaryEncoder<QueryNextResponse> for QueryNextResponse {
         })
     }
 }
+
+impl JsonEncoder<QueryNextResponse> for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "QueryDataSets", &self.query_data_sets, reversible);
+        write_json_field(&mut map, "RevisedContinuationPoint", &self.revised_continuation_point, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(QueryNextResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            query_data_sets: read_json_array_field(value, "QueryDataSets")?,
+            revised_continuation_point: read_json_field(value, "RevisedContinuationPoint")?,
+        })
+    }
+}
+
+impl XmlEncoder<QueryNextResponse> for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "QueryDataSets", "QueryDataSet", &self.query_data_sets);
+        write_xml_field(node, "RevisedContinuationPoint", &self.revised_continuation_point);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(QueryNextResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            query_data_sets: read_xml_array_field(node, "QueryDataSets")?,
+            revised_continuation_point: read_xml_field(node, "RevisedContinuationPoint")?,
+        })
+    }
+}
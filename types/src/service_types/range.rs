@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -47,3 +49,38 @@ impl BinaryEncoder<Range> for Range {
         })
     }
 }
+
+impl JsonEncoder<Range> for Range {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Low", &self.low, reversible);
+        write_json_field(&mut map, "High", &self.high, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(Range {
+            low: read_json_field(value, "Low")?,
+            high: read_json_field(value, "High")?,
+        })
+    }
+}
+
+impl XmlEncoder<Range> for Range {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Low", &self.low);
+        write_xml_field(node, "High", &self.high);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(Range {
+            low: read_xml_field(node, "Low")?,
+            high: read_xml_field(node, "High")?,
+        })
+    }
+}
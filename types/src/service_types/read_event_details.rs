@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use date_time::DateTime;
@@ -51,3 +53,46 @@ impl BinaryEncoder<ReadEventDetails> for ReadEventDetails {
         })
     }
 }
+
+impl JsonEncoder<ReadEventDetails> for ReadEventDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NumValuesPerNode", &self.num_values_per_node, reversible);
+        write_json_field(&mut map, "StartTime", &self.start_time, reversible);
+        write_json_field(&mut map, "EndTime", &self.end_time, reversible);
+        write_json_field(&mut map, "Filter", &self.filter, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ReadEventDetails {
+            num_values_per_node: read_json_field(value, "NumValuesPerNode")?,
+            start_time: read_json_field(value, "StartTime")?,
+            end_time: read_json_field(value, "EndTime")?,
+            filter: read_json_field(value, "Filter")?,
+        })
+    }
+}
+
+impl XmlEncoder<ReadEventDetails> for ReadEventDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NumValuesPerNode", &self.num_values_per_node);
+        write_xml_field(node, "StartTime", &self.start_time);
+        write_xml_field(node, "EndTime", &self.end_time);
+        write_xml_field(node, "Filter", &self.filter);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ReadEventDetails {
+            num_values_per_node: read_xml_field(node, "NumValuesPerNode")?,
+            start_time: read_xml_field(node, "StartTime")?,
+            end_time: read_xml_field(node, "EndTime")?,
+            filter: read_xml_field(node, "Filter")?,
+        })
+    }
+}
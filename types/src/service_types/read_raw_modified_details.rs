@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use date_time::DateTime;
@@ -55,3 +57,50 @@ impl BinaryEncoder<ReadRawModifiedDetails> for ReadRawModifiedDetails {
         })
     }
 }
+
+impl JsonEncoder<ReadRawModifiedDetails> for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "IsReadModified", &self.is_read_modified, reversible);
+        write_json_field(&mut map, "StartTime", &self.start_time, reversible);
+        write_json_field(&mut map, "EndTime", &self.end_time, reversible);
+        write_json_field(&mut map, "NumValuesPerNode", &self.num_values_per_node, reversible);
+        write_json_field(&mut map, "ReturnBounds", &self.return_bounds, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ReadRawModifiedDetails {
+            is_read_modified: read_json_field(value, "IsReadModified")?,
+            start_time: read_json_field(value, "StartTime")?,
+            end_time: read_json_field(value, "EndTime")?,
+            num_values_per_node: read_json_field(value, "NumValuesPerNode")?,
+            return_bounds: read_json_field(value, "ReturnBounds")?,
+        })
+    }
+}
+
+impl XmlEncoder<ReadRawModifiedDetails> for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "IsReadModified", &self.is_read_modified);
+        write_xml_field(node, "StartTime", &self.start_time);
+        write_xml_field(node, "EndTime", &self.end_time);
+        write_xml_field(node, "NumValuesPerNode", &self.num_values_per_node);
+        write_xml_field(node, "ReturnBounds", &self.return_bounds);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ReadRawModifiedDetails {
+            is_read_modified: read_xml_field(node, "IsReadModified")?,
+            start_time: read_xml_field(node, "StartTime")?,
+            end_time: read_xml_field(node, "EndTime")?,
+            num_values_per_node: read_xml_field(node, "NumValuesPerNode")?,
+            return_bounds: read_xml_field(node, "ReturnBounds")?,
+        })
+    }
+}
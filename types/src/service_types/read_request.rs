@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -60,3 +62,46 @@ impl BinaryEncoder<ReadRequest> for ReadRequest {
         })
     }
 }
+
+impl JsonEncoder<ReadRequest> for ReadRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "MaxAge", &self.max_age, reversible);
+        write_json_field(&mut map, "TimestampsToReturn", &self.timestamps_to_return, reversible);
+        write_json_array_field(&mut map, "NodesToRead", &self.nodes_to_read, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ReadRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            max_age: read_json_field(value, "MaxAge")?,
+            timestamps_to_return: read_json_field(value, "TimestampsToReturn")?,
+            nodes_to_read: read_json_array_field(value, "NodesToRead")?,
+        })
+    }
+}
+
+impl XmlEncoder<ReadRequest> for ReadRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "MaxAge", &self.max_age);
+        write_xml_field(node, "TimestampsToReturn", &self.timestamps_to_return);
+        write_xml_array_field(node, "NodesToRead", "ReadValueId", &self.nodes_to_read);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ReadRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            max_age: read_xml_field(node, "MaxAge")?,
+            timestamps_to_return: read_xml_field(node, "TimestampsToReturn")?,
+            nodes_to_read: read_xml_array_field(node, "NodesToRead")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -78,3 +80,58 @@ impl BinaryEncoder<ReferenceDescription> for ReferenceDescription {
         })
     }
 }
+
+impl JsonEncoder<ReferenceDescription> for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "IsForward", &self.is_forward, reversible);
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "BrowseName", &self.browse_name, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "NodeClass", &self.node_class, reversible);
+        write_json_field(&mut map, "TypeDefinition", &self.type_definition, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ReferenceDescription {
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            is_forward: read_json_field(value, "IsForward")?,
+            node_id: read_json_field(value, "NodeId")?,
+            browse_name: read_json_field(value, "BrowseName")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            node_class: read_json_field(value, "NodeClass")?,
+            type_definition: read_json_field(value, "TypeDefinition")?,
+        })
+    }
+}
+
+impl XmlEncoder<ReferenceDescription> for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "IsForward", &self.is_forward);
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "BrowseName", &self.browse_name);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "NodeClass", &self.node_class);
+        write_xml_field(node, "TypeDefinition", &self.type_definition);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ReferenceDescription {
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            is_forward: read_xml_field(node, "IsForward")?,
+            node_id: read_xml_field(node, "NodeId")?,
+            browse_name: read_xml_field(node, "BrowseName")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            node_class: read_xml_field(node, "NodeClass")?,
+            type_definition: read_xml_field(node, "TypeDefinition")?,
+        })
+    }
+}
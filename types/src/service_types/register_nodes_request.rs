@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<RegisterNodesRequest> for RegisterNodesRequest {
         })
     }
 }
+
+impl JsonEncoder<RegisterNodesRequest> for RegisterNodesRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "NodesToRegister", &self.nodes_to_register, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RegisterNodesRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            nodes_to_register: read_json_array_field(value, "NodesToRegister")?,
+        })
+    }
+}
+
+impl XmlEncoder<RegisterNodesRequest> for RegisterNodesRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "NodesToRegister", "NodeId", &self.nodes_to_register);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RegisterNodesRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            nodes_to_register: read_xml_array_field(node, "NodesToRegister")?,
+        })
+    }
+}
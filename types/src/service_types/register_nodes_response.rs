@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<RegisterNodesResponse> for RegisterNodesResponse {
         })
     }
 }
+
+impl JsonEncoder<RegisterNodesResponse> for RegisterNodesResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "RegisteredNodeIds", &self.registered_node_ids, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RegisterNodesResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            registered_node_ids: read_json_array_field(value, "RegisteredNodeIds")?,
+        })
+    }
+}
+
+impl XmlEncoder<RegisterNodesResponse> for RegisterNodesResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "RegisteredNodeIds", "NodeId", &self.registered_node_ids);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RegisterNodesResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            registered_node_ids: read_xml_array_field(node, "RegisteredNodeIds")?,
+        })
+    }
+}
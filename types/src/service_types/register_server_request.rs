@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<RegisterServerRequest> for RegisterServerRequest {
         })
     }
 }
+
+impl JsonEncoder<RegisterServerRequest> for RegisterServerRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "Server", &self.server, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RegisterServerRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            server: read_json_field(value, "Server")?,
+        })
+    }
+}
+
+impl XmlEncoder<RegisterServerRequest> for RegisterServerRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "Server", &self.server);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RegisterServerRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            server: read_xml_field(node, "Server")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -81,3 +83,62 @@ impl BinaryEncoder<RegisteredServer> for RegisteredServer {
         })
     }
 }
+
+impl JsonEncoder<RegisteredServer> for RegisteredServer {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ServerUri", &self.server_uri, reversible);
+        write_json_field(&mut map, "ProductUri", &self.product_uri, reversible);
+        write_json_array_field(&mut map, "ServerNames", &self.server_names, reversible);
+        write_json_field(&mut map, "ServerType", &self.server_type, reversible);
+        write_json_field(&mut map, "GatewayServerUri", &self.gateway_server_uri, reversible);
+        write_json_array_field(&mut map, "DiscoveryUrls", &self.discovery_urls, reversible);
+        write_json_field(&mut map, "SemaphoreFilePath", &self.semaphore_file_path, reversible);
+        write_json_field(&mut map, "IsOnline", &self.is_online, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RegisteredServer {
+            server_uri: read_json_field(value, "ServerUri")?,
+            product_uri: read_json_field(value, "ProductUri")?,
+            server_names: read_json_array_field(value, "ServerNames")?,
+            server_type: read_json_field(value, "ServerType")?,
+            gateway_server_uri: read_json_field(value, "GatewayServerUri")?,
+            discovery_urls: read_json_array_field(value, "DiscoveryUrls")?,
+            semaphore_file_path: read_json_field(value, "SemaphoreFilePath")?,
+            is_online: read_json_field(value, "IsOnline")?,
+        })
+    }
+}
+
+impl XmlEncoder<RegisteredServer> for RegisteredServer {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ServerUri", &self.server_uri);
+        write_xml_field(node, "ProductUri", &self.product_uri);
+        write_xml_array_field(node, "ServerNames", "LocalizedText", &self.server_names);
+        write_xml_field(node, "ServerType", &self.server_type);
+        write_xml_field(node, "GatewayServerUri", &self.gateway_server_uri);
+        write_xml_array_field(node, "DiscoveryUrls", "String", &self.discovery_urls);
+        write_xml_field(node, "SemaphoreFilePath", &self.semaphore_file_path);
+        write_xml_field(node, "IsOnline", &self.is_online);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RegisteredServer {
+            server_uri: read_xml_field(node, "ServerUri")?,
+            product_uri: read_xml_field(node, "ProductUri")?,
+            server_names: read_xml_array_field(node, "ServerNames")?,
+            server_type: read_xml_field(node, "ServerType")?,
+            gateway_server_uri: read_xml_field(node, "GatewayServerUri")?,
+            discovery_urls: read_xml_array_field(node, "DiscoveryUrls")?,
+            semaphore_file_path: read_xml_field(node, "SemaphoreFilePath")?,
+            is_online: read_xml_field(node, "IsOnline")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -44,3 +46,34 @@ impl BinaryEncoder<RelativePath> for RelativePath {
         })
     }
 }
+
+impl JsonEncoder<RelativePath> for RelativePath {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_array_field(&mut map, "Elements", &self.elements, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RelativePath {
+            elements: read_json_array_field(value, "Elements")?,
+        })
+    }
+}
+
+impl XmlEncoder<RelativePath> for RelativePath {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_array_field(node, "Elements", "RelativePathElement", &self.elements);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RelativePath {
+            elements: read_xml_array_field(node, "Elements")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -60,3 +62,46 @@ impl BinaryEncoder<RelativePathElement> for RelativePathElement {
         })
     }
 }
+
+impl JsonEncoder<RelativePathElement> for RelativePathElement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ReferenceTypeId", &self.reference_type_id, reversible);
+        write_json_field(&mut map, "IsInverse", &self.is_inverse, reversible);
+        write_json_field(&mut map, "IncludeSubtypes", &self.include_subtypes, reversible);
+        write_json_field(&mut map, "TargetName", &self.target_name, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RelativePathElement {
+            reference_type_id: read_json_field(value, "ReferenceTypeId")?,
+            is_inverse: read_json_field(value, "IsInverse")?,
+            include_subtypes: read_json_field(value, "IncludeSubtypes")?,
+            target_name: read_json_field(value, "TargetName")?,
+        })
+    }
+}
+
+impl XmlEncoder<RelativePathElement> for RelativePathElement {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ReferenceTypeId", &self.reference_type_id);
+        write_xml_field(node, "IsInverse", &self.is_inverse);
+        write_xml_field(node, "IncludeSubtypes", &self.include_subtypes);
+        write_xml_field(node, "TargetName", &self.target_name);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RelativePathElement {
+            reference_type_id: read_xml_field(node, "ReferenceTypeId")?,
+            is_inverse: read_xml_field(node, "IsInverse")?,
+            include_subtypes: read_xml_field(node, "IncludeSubtypes")?,
+            target_name: read_xml_field(node, "TargetName")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -53,3 +55,42 @@ impl BinaryEncoder<RepublishRequest> for RepublishRequest {
         })
     }
 }
+
+impl JsonEncoder<RepublishRequest> for RepublishRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_field(&mut map, "RetransmitSequenceNumber", &self.retransmit_sequence_number, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RepublishRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            retransmit_sequence_number: read_json_field(value, "RetransmitSequenceNumber")?,
+        })
+    }
+}
+
+impl XmlEncoder<RepublishRequest> for RepublishRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_field(node, "RetransmitSequenceNumber", &self.retransmit_sequence_number);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RepublishRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            retransmit_sequence_number: read_xml_field(node, "RetransmitSequenceNumber")?,
+        })
+    }
+}
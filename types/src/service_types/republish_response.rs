@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<RepublishResponse> for RepublishResponse {
         })
     }
 }
+
+impl JsonEncoder<RepublishResponse> for RepublishResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_field(&mut map, "NotificationMessage", &self.notification_message, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(RepublishResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            notification_message: read_json_field(value, "NotificationMessage")?,
+        })
+    }
+}
+
+impl XmlEncoder<RepublishResponse> for RepublishResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_field(node, "NotificationMessage", &self.notification_message);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(RepublishResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            notification_message: read_xml_field(node, "NotificationMessage")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -57,3 +59,46 @@ impl BinaryEncoder<SamplingIntervalDiagnosticsDataType> for SamplingIntervalDiag
         })
     }
 }
+
+impl JsonEncoder<SamplingIntervalDiagnosticsDataType> for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SamplingInterval", &self.sampling_interval, reversible);
+        write_json_field(&mut map, "MonitoredItemCount", &self.monitored_item_count, reversible);
+        write_json_field(&mut map, "MaxMonitoredItemCount", &self.max_monitored_item_count, reversible);
+        write_json_field(&mut map, "DisabledMonitoredItemCount", &self.disabled_monitored_item_count, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SamplingIntervalDiagnosticsDataType {
+            sampling_interval: read_json_field(value, "SamplingInterval")?,
+            monitored_item_count: read_json_field(value, "MonitoredItemCount")?,
+            max_monitored_item_count: read_json_field(value, "MaxMonitoredItemCount")?,
+            disabled_monitored_item_count: read_json_field(value, "DisabledMonitoredItemCount")?,
+        })
+    }
+}
+
+impl XmlEncoder<SamplingIntervalDiagnosticsDataType> for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SamplingInterval", &self.sampling_interval);
+        write_xml_field(node, "MonitoredItemCount", &self.monitored_item_count);
+        write_xml_field(node, "MaxMonitoredItemCount", &self.max_monitored_item_count);
+        write_xml_field(node, "DisabledMonitoredItemCount", &self.disabled_monitored_item_count);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SamplingIntervalDiagnosticsDataType {
+            sampling_interval: read_xml_field(node, "SamplingInterval")?,
+            monitored_item_count: read_xml_field(node, "MonitoredItemCount")?,
+            max_monitored_item_count: read_xml_field(node, "MaxMonitoredItemCount")?,
+            disabled_monitored_item_count: read_xml_field(node, "DisabledMonitoredItemCount")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -97,3 +99,78 @@ impl BinaryEncoder<ServerDiagnosticsSummaryDataType> for ServerDiagnosticsSummar
         })
     }
 }
+
+impl JsonEncoder<ServerDiagnosticsSummaryDataType> for ServerDiagnosticsSummaryDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ServerViewCount", &self.server_view_count, reversible);
+        write_json_field(&mut map, "CurrentSessionCount", &self.current_session_count, reversible);
+        write_json_field(&mut map, "CumulatedSessionCount", &self.cumulated_session_count, reversible);
+        write_json_field(&mut map, "SecurityRejectedSessionCount", &self.security_rejected_session_count, reversible);
+        write_json_field(&mut map, "RejectedSessionCount", &self.rejected_session_count, reversible);
+        write_json_field(&mut map, "SessionTimeoutCount", &self.session_timeout_count, reversible);
+        write_json_field(&mut map, "SessionAbortCount", &self.session_abort_count, reversible);
+        write_json_field(&mut map, "CurrentSubscriptionCount", &self.current_subscription_count, reversible);
+        write_json_field(&mut map, "CumulatedSubscriptionCount", &self.cumulated_subscription_count, reversible);
+        write_json_field(&mut map, "PublishingIntervalCount", &self.publishing_interval_count, reversible);
+        write_json_field(&mut map, "SecurityRejectedRequestsCount", &self.security_rejected_requests_count, reversible);
+        write_json_field(&mut map, "RejectedRequestsCount", &self.rejected_requests_count, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ServerDiagnosticsSummaryDataType {
+            server_view_count: read_json_field(value, "ServerViewCount")?,
+            current_session_count: read_json_field(value, "CurrentSessionCount")?,
+            cumulated_session_count: read_json_field(value, "CumulatedSessionCount")?,
+            security_rejected_session_count: read_json_field(value, "SecurityRejectedSessionCount")?,
+            rejected_session_count: read_json_field(value, "RejectedSessionCount")?,
+            session_timeout_count: read_json_field(value, "SessionTimeoutCount")?,
+            session_abort_count: read_json_field(value, "SessionAbortCount")?,
+            current_subscription_count: read_json_field(value, "CurrentSubscriptionCount")?,
+            cumulated_subscription_count: read_json_field(value, "CumulatedSubscriptionCount")?,
+            publishing_interval_count: read_json_field(value, "PublishingIntervalCount")?,
+            security_rejected_requests_count: read_json_field(value, "SecurityRejectedRequestsCount")?,
+            rejected_requests_count: read_json_field(value, "RejectedRequestsCount")?,
+        })
+    }
+}
+
+impl XmlEncoder<ServerDiagnosticsSummaryDataType> for ServerDiagnosticsSummaryDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ServerViewCount", &self.server_view_count);
+        write_xml_field(node, "CurrentSessionCount", &self.current_session_count);
+        write_xml_field(node, "CumulatedSessionCount", &self.cumulated_session_count);
+        write_xml_field(node, "SecurityRejectedSessionCount", &self.security_rejected_session_count);
+        write_xml_field(node, "RejectedSessionCount", &self.rejected_session_count);
+        write_xml_field(node, "SessionTimeoutCount", &self.session_timeout_count);
+        write_xml_field(node, "SessionAbortCount", &self.session_abort_count);
+        write_xml_field(node, "CurrentSubscriptionCount", &self.current_subscription_count);
+        write_xml_field(node, "CumulatedSubscriptionCount", &self.cumulated_subscription_count);
+        write_xml_field(node, "PublishingIntervalCount", &self.publishing_interval_count);
+        write_xml_field(node, "SecurityRejectedRequestsCount", &self.security_rejected_requests_count);
+        write_xml_field(node, "RejectedRequestsCount", &self.rejected_requests_count);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ServerDiagnosticsSummaryDataType {
+            server_view_count: read_xml_field(node, "ServerViewCount")?,
+            current_session_count: read_xml_field(node, "CurrentSessionCount")?,
+            cumulated_session_count: read_xml_field(node, "CumulatedSessionCount")?,
+            security_rejected_session_count: read_xml_field(node, "SecurityRejectedSessionCount")?,
+            rejected_session_count: read_xml_field(node, "RejectedSessionCount")?,
+            session_timeout_count: read_xml_field(node, "SessionTimeoutCount")?,
+            session_abort_count: read_xml_field(node, "SessionAbortCount")?,
+            current_subscription_count: read_xml_field(node, "CurrentSubscriptionCount")?,
+            cumulated_subscription_count: read_xml_field(node, "CumulatedSubscriptionCount")?,
+            publishing_interval_count: read_xml_field(node, "PublishingIntervalCount")?,
+            security_rejected_requests_count: read_xml_field(node, "SecurityRejectedRequestsCount")?,
+            rejected_requests_count: read_xml_field(node, "RejectedRequestsCount")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -58,3 +60,46 @@ impl BinaryEncoder<ServerOnNetwork> for ServerOnNetwork {
         })
     }
 }
+
+impl JsonEncoder<ServerOnNetwork> for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RecordId", &self.record_id, reversible);
+        write_json_field(&mut map, "ServerName", &self.server_name, reversible);
+        write_json_field(&mut map, "DiscoveryUrl", &self.discovery_url, reversible);
+        write_json_array_field(&mut map, "ServerCapabilities", &self.server_capabilities, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ServerOnNetwork {
+            record_id: read_json_field(value, "RecordId")?,
+            server_name: read_json_field(value, "ServerName")?,
+            discovery_url: read_json_field(value, "DiscoveryUrl")?,
+            server_capabilities: read_json_array_field(value, "ServerCapabilities")?,
+        })
+    }
+}
+
+impl XmlEncoder<ServerOnNetwork> for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RecordId", &self.record_id);
+        write_xml_field(node, "ServerName", &self.server_name);
+        write_xml_field(node, "DiscoveryUrl", &self.discovery_url);
+        write_xml_array_field(node, "ServerCapabilities", "String", &self.server_capabilities);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ServerOnNetwork {
+            record_id: read_xml_field(node, "RecordId")?,
+            server_name: read_xml_field(node, "ServerName")?,
+            discovery_url: read_xml_field(node, "DiscoveryUrl")?,
+            server_capabilities: read_xml_array_field(node, "ServerCapabilities")?,
+        })
+    }
+}
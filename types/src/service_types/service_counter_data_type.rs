@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -47,3 +49,38 @@ impl BinaryEncoder<ServiceCounterDataType> for ServiceCounterDataType {
         })
     }
 }
+
+impl JsonEncoder<ServiceCounterDataType> for ServiceCounterDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "TotalCount", &self.total_count, reversible);
+        write_json_field(&mut map, "ErrorCount", &self.error_count, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ServiceCounterDataType {
+            total_count: read_json_field(value, "TotalCount")?,
+            error_count: read_json_field(value, "ErrorCount")?,
+        })
+    }
+}
+
+impl XmlEncoder<ServiceCounterDataType> for ServiceCounterDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "TotalCount", &self.total_count);
+        write_xml_field(node, "ErrorCount", &self.error_count);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ServiceCounterDataType {
+            total_count: read_xml_field(node, "TotalCount")?,
+            error_count: read_xml_field(node, "ErrorCount")?,
+        })
+    }
+}
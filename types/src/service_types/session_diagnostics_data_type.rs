@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -257,3 +259,202 @@ impl BinaryEncoder<SessionDiagnosticsDataType> for SessionDiagnosticsDataType {
         })
     }
 }
+
+impl JsonEncoder<SessionDiagnosticsDataType> for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SessionId", &self.session_id, reversible);
+        write_json_field(&mut map, "SessionName", &self.session_name, reversible);
+        write_json_field(&mut map, "ClientDescription", &self.client_description, reversible);
+        write_json_field(&mut map, "ServerUri", &self.server_uri, reversible);
+        write_json_field(&mut map, "EndpointUrl", &self.endpoint_url, reversible);
+        write_json_array_field(&mut map, "LocaleIds", &self.locale_ids, reversible);
+        write_json_field(&mut map, "ActualSessionTimeout", &self.actual_session_timeout, reversible);
+        write_json_field(&mut map, "MaxResponseMessageSize", &self.max_response_message_size, reversible);
+        write_json_field(&mut map, "ClientConnectionTime", &self.client_connection_time, reversible);
+        write_json_field(&mut map, "ClientLastContactTime", &self.client_last_contact_time, reversible);
+        write_json_field(&mut map, "CurrentSubscriptionsCount", &self.current_subscriptions_count, reversible);
+        write_json_field(&mut map, "CurrentMonitoredItemsCount", &self.current_monitored_items_count, reversible);
+        write_json_field(&mut map, "CurrentPublishRequestsInQueue", &self.current_publish_requests_in_queue, reversible);
+        write_json_field(&mut map, "TotalRequestCount", &self.total_request_count, reversible);
+        write_json_field(&mut map, "UnauthorizedRequestCount", &self.unauthorized_request_count, reversible);
+        write_json_field(&mut map, "ReadCount", &self.read_count, reversible);
+        write_json_field(&mut map, "HistoryReadCount", &self.history_read_count, reversible);
+        write_json_field(&mut map, "WriteCount", &self.write_count, reversible);
+        write_json_field(&mut map, "HistoryUpdateCount", &self.history_update_count, reversible);
+        write_json_field(&mut map, "CallCount", &self.call_count, reversible);
+        write_json_field(&mut map, "CreateMonitoredItemsCount", &self.create_monitored_items_count, reversible);
+        write_json_field(&mut map, "ModifyMonitoredItemsCount", &self.modify_monitored_items_count, reversible);
+        write_json_field(&mut map, "SetMonitoringModeCount", &self.set_monitoring_mode_count, reversible);
+        write_json_field(&mut map, "SetTriggeringCount", &self.set_triggering_count, reversible);
+        write_json_field(&mut map, "DeleteMonitoredItemsCount", &self.delete_monitored_items_count, reversible);
+        write_json_field(&mut map, "CreateSubscriptionCount", &self.create_subscription_count, reversible);
+        write_json_field(&mut map, "ModifySubscriptionCount", &self.modify_subscription_count, reversible);
+        write_json_field(&mut map, "SetPublishingModeCount", &self.set_publishing_mode_count, reversible);
+        write_json_field(&mut map, "PublishCount", &self.publish_count, reversible);
+        write_json_field(&mut map, "RepublishCount", &self.republish_count, reversible);
+        write_json_field(&mut map, "TransferSubscriptionsCount", &self.transfer_subscriptions_count, reversible);
+        write_json_field(&mut map, "DeleteSubscriptionsCount", &self.delete_subscriptions_count, reversible);
+        write_json_field(&mut map, "AddNodesCount", &self.add_nodes_count, reversible);
+        write_json_field(&mut map, "AddReferencesCount", &self.add_references_count, reversible);
+        write_json_field(&mut map, "DeleteNodesCount", &self.delete_nodes_count, reversible);
+        write_json_field(&mut map, "DeleteReferencesCount", &self.delete_references_count, reversible);
+        write_json_field(&mut map, "BrowseCount", &self.browse_count, reversible);
+        write_json_field(&mut map, "BrowseNextCount", &self.browse_next_count, reversible);
+        write_json_field(&mut map, "TranslateBrowsePathsToNodeIdsCount", &self.translate_browse_paths_to_node_ids_count, reversible);
+        write_json_field(&mut map, "QueryFirstCount", &self.query_first_count, reversible);
+        write_json_field(&mut map, "QueryNextCount", &self.query_next_count, reversible);
+        write_json_field(&mut map, "RegisterNodesCount", &self.register_nodes_count, reversible);
+        write_json_field(&mut map, "UnregisterNodesCount", &self.unregister_nodes_count, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SessionDiagnosticsDataType {
+            session_id: read_json_field(value, "SessionId")?,
+            session_name: read_json_field(value, "SessionName")?,
+            client_description: read_json_field(value, "ClientDescription")?,
+            server_uri: read_json_field(value, "ServerUri")?,
+            endpoint_url: read_json_field(value, "EndpointUrl")?,
+            locale_ids: read_json_array_field(value, "LocaleIds")?,
+            actual_session_timeout: read_json_field(value, "ActualSessionTimeout")?,
+            max_response_message_size: read_json_field(value, "MaxResponseMessageSize")?,
+            client_connection_time: read_json_field(value, "ClientConnectionTime")?,
+            client_last_contact_time: read_json_field(value, "ClientLastContactTime")?,
+            current_subscriptions_count: read_json_field(value, "CurrentSubscriptionsCount")?,
+            current_monitored_items_count: read_json_field(value, "CurrentMonitoredItemsCount")?,
+            current_publish_requests_in_queue: read_json_field(value, "CurrentPublishRequestsInQueue")?,
+            total_request_count: read_json_field(value, "TotalRequestCount")?,
+            unauthorized_request_count: read_json_field(value, "UnauthorizedRequestCount")?,
+            read_count: read_json_field(value, "ReadCount")?,
+            history_read_count: read_json_field(value, "HistoryReadCount")?,
+            write_count: read_json_field(value, "WriteCount")?,
+            history_update_count: read_json_field(value, "HistoryUpdateCount")?,
+            call_count: read_json_field(value, "CallCount")?,
+            create_monitored_items_count: read_json_field(value, "CreateMonitoredItemsCount")?,
+            modify_monitored_items_count: read_json_field(value, "ModifyMonitoredItemsCount")?,
+            set_monitoring_mode_count: read_json_field(value, "SetMonitoringModeCount")?,
+            set_triggering_count: read_json_field(value, "SetTriggeringCount")?,
+            delete_monitored_items_count: read_json_field(value, "DeleteMonitoredItemsCount")?,
+            create_subscription_count: read_json_field(value, "CreateSubscriptionCount")?,
+            modify_subscription_count: read_json_field(value, "ModifySubscriptionCount")?,
+            set_publishing_mode_count: read_json_field(value, "SetPublishingModeCount")?,
+            publish_count: read_json_field(value, "PublishCount")?,
+            republish_count: read_json_field(value, "RepublishCount")?,
+            transfer_subscriptions_count: read_json_field(value, "TransferSubscriptionsCount")?,
+            delete_subscriptions_count: read_json_field(value, "DeleteSubscriptionsCount")?,
+            add_nodes_count: read_json_field(value, "AddNodesCount")?,
+            add_references_count: read_json_field(value, "AddReferencesCount")?,
+            delete_nodes_count: read_json_field(value, "DeleteNodesCount")?,
+            delete_references_count: read_json_field(value, "DeleteReferencesCount")?,
+            browse_count: read_json_field(value, "BrowseCount")?,
+            browse_next_count: read_json_field(value, "BrowseNextCount")?,
+            translate_browse_paths_to_node_ids_count: read_json_field(value, "TranslateBrowsePathsToNodeIdsCount")?,
+            query_first_count: read_json_field(value, "QueryFirstCount")?,
+            query_next_count: read_json_field(value, "QueryNextCount")?,
+            register_nodes_count: read_json_field(value, "RegisterNodesCount")?,
+            unregister_nodes_count: read_json_field(value, "UnregisterNodesCount")?,
+        })
+    }
+}
+
+impl XmlEncoder<SessionDiagnosticsDataType> for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SessionId", &self.session_id);
+        write_xml_field(node, "SessionName", &self.session_name);
+        write_xml_field(node, "ClientDescription", &self.client_description);
+        write_xml_field(node, "ServerUri", &self.server_uri);
+        write_xml_field(node, "EndpointUrl", &self.endpoint_url);
+        write_xml_array_field(node, "LocaleIds", "String", &self.locale_ids);
+        write_xml_field(node, "ActualSessionTimeout", &self.actual_session_timeout);
+        write_xml_field(node, "MaxResponseMessageSize", &self.max_response_message_size);
+        write_xml_field(node, "ClientConnectionTime", &self.client_connection_time);
+        write_xml_field(node, "ClientLastContactTime", &self.client_last_contact_time);
+        write_xml_field(node, "CurrentSubscriptionsCount", &self.current_subscriptions_count);
+        write_xml_field(node, "CurrentMonitoredItemsCount", &self.current_monitored_items_count);
+        write_xml_field(node, "CurrentPublishRequestsInQueue", &self.current_publish_requests_in_queue);
+        write_xml_field(node, "TotalRequestCount", &self.total_request_count);
+        write_xml_field(node, "UnauthorizedRequestCount", &self.unauthorized_request_count);
+        write_xml_field(node, "ReadCount", &self.read_count);
+        write_xml_field(node, "HistoryReadCount", &self.history_read_count);
+        write_xml_field(node, "WriteCount", &self.write_count);
+        write_xml_field(node, "HistoryUpdateCount", &self.history_update_count);
+        write_xml_field(node, "CallCount", &self.call_count);
+        write_xml_field(node, "CreateMonitoredItemsCount", &self.create_monitored_items_count);
+        write_xml_field(node, "ModifyMonitoredItemsCount", &self.modify_monitored_items_count);
+        write_xml_field(node, "SetMonitoringModeCount", &self.set_monitoring_mode_count);
+        write_xml_field(node, "SetTriggeringCount", &self.set_triggering_count);
+        write_xml_field(node, "DeleteMonitoredItemsCount", &self.delete_monitored_items_count);
+        write_xml_field(node, "CreateSubscriptionCount", &self.create_subscription_count);
+        write_xml_field(node, "ModifySubscriptionCount", &self.modify_subscription_count);
+        write_xml_field(node, "SetPublishingModeCount", &self.set_publishing_mode_count);
+        write_xml_field(node, "PublishCount", &self.publish_count);
+        write_xml_field(node, "RepublishCount", &self.republish_count);
+        write_xml_field(node, "TransferSubscriptionsCount", &self.transfer_subscriptions_count);
+        write_xml_field(node, "DeleteSubscriptionsCount", &self.delete_subscriptions_count);
+        write_xml_field(node, "AddNodesCount", &self.add_nodes_count);
+        write_xml_field(node, "AddReferencesCount", &self.add_references_count);
+        write_xml_field(node, "DeleteNodesCount", &self.delete_nodes_count);
+        write_xml_field(node, "DeleteReferencesCount", &self.delete_references_count);
+        write_xml_field(node, "BrowseCount", &self.browse_count);
+        write_xml_field(node, "BrowseNextCount", &self.browse_next_count);
+        write_xml_field(node, "TranslateBrowsePathsToNodeIdsCount", &self.translate_browse_paths_to_node_ids_count);
+        write_xml_field(node, "QueryFirstCount", &self.query_first_count);
+        write_xml_field(node, "QueryNextCount", &self.query_next_count);
+        write_xml_field(node, "RegisterNodesCount", &self.register_nodes_count);
+        write_xml_field(node, "UnregisterNodesCount", &self.unregister_nodes_count);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SessionDiagnosticsDataType {
+            session_id: read_xml_field(node, "SessionId")?,
+            session_name: read_xml_field(node, "SessionName")?,
+            client_description: read_xml_field(node, "ClientDescription")?,
+            server_uri: read_xml_field(node, "ServerUri")?,
+            endpoint_url: read_xml_field(node, "EndpointUrl")?,
+            locale_ids: read_xml_array_field(node, "LocaleIds")?,
+            actual_session_timeout: read_xml_field(node, "ActualSessionTimeout")?,
+            max_response_message_size: read_xml_field(node, "MaxResponseMessageSize")?,
+            client_connection_time: read_xml_field(node, "ClientConnectionTime")?,
+            client_last_contact_time: read_xml_field(node, "ClientLastContactTime")?,
+            current_subscriptions_count: read_xml_field(node, "CurrentSubscriptionsCount")?,
+            current_monitored_items_count: read_xml_field(node, "CurrentMonitoredItemsCount")?,
+            current_publish_requests_in_queue: read_xml_field(node, "CurrentPublishRequestsInQueue")?,
+            total_request_count: read_xml_field(node, "TotalRequestCount")?,
+            unauthorized_request_count: read_xml_field(node, "UnauthorizedRequestCount")?,
+            read_count: read_xml_field(node, "ReadCount")?,
+            history_read_count: read_xml_field(node, "HistoryReadCount")?,
+            write_count: read_xml_field(node, "WriteCount")?,
+            history_update_count: read_xml_field(node, "HistoryUpdateCount")?,
+            call_count: read_xml_field(node, "CallCount")?,
+            create_monitored_items_count: read_xml_field(node, "CreateMonitoredItemsCount")?,
+            modify_monitored_items_count: read_xml_field(node, "ModifyMonitoredItemsCount")?,
+            set_monitoring_mode_count: read_xml_field(node, "SetMonitoringModeCount")?,
+            set_triggering_count: read_xml_field(node, "SetTriggeringCount")?,
+            delete_monitored_items_count: read_xml_field(node, "DeleteMonitoredItemsCount")?,
+            create_subscription_count: read_xml_field(node, "CreateSubscriptionCount")?,
+            modify_subscription_count: read_xml_field(node, "ModifySubscriptionCount")?,
+            set_publishing_mode_count: read_xml_field(node, "SetPublishingModeCount")?,
+            publish_count: read_xml_field(node, "PublishCount")?,
+            republish_count: read_xml_field(node, "RepublishCount")?,
+            transfer_subscriptions_count: read_xml_field(node, "TransferSubscriptionsCount")?,
+            delete_subscriptions_count: read_xml_field(node, "DeleteSubscriptionsCount")?,
+            add_nodes_count: read_xml_field(node, "AddNodesCount")?,
+            add_references_count: read_xml_field(node, "AddReferencesCount")?,
+            delete_nodes_count: read_xml_field(node, "DeleteNodesCount")?,
+            delete_references_count: read_xml_field(node, "DeleteReferencesCount")?,
+            browse_count: read_xml_field(node, "BrowseCount")?,
+            browse_next_count: read_xml_field(node, "BrowseNextCount")?,
+            translate_browse_paths_to_node_ids_count: read_xml_field(node, "TranslateBrowsePathsToNodeIdsCount")?,
+            query_first_count: read_xml_field(node, "QueryFirstCount")?,
+            query_next_count: read_xml_field(node, "QueryNextCount")?,
+            register_nodes_count: read_xml_field(node, "RegisterNodesCount")?,
+            unregister_nodes_count: read_xml_field(node, "UnregisterNodesCount")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -86,3 +88,66 @@ impl BinaryEncoder<SessionSecurityDiagnosticsDataType> for SessionSecurityDiagno
         })
     }
 }
+
+impl JsonEncoder<SessionSecurityDiagnosticsDataType> for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SessionId", &self.session_id, reversible);
+        write_json_field(&mut map, "ClientUserIdOfSession", &self.client_user_id_of_session, reversible);
+        write_json_array_field(&mut map, "ClientUserIdHistory", &self.client_user_id_history, reversible);
+        write_json_field(&mut map, "AuthenticationMechanism", &self.authentication_mechanism, reversible);
+        write_json_field(&mut map, "Encoding", &self.encoding, reversible);
+        write_json_field(&mut map, "TransportProtocol", &self.transport_protocol, reversible);
+        write_json_field(&mut map, "SecurityMode", &self.security_mode, reversible);
+        write_json_field(&mut map, "SecurityPolicyUri", &self.security_policy_uri, reversible);
+        write_json_field(&mut map, "ClientCertificate", &self.client_certificate, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SessionSecurityDiagnosticsDataType {
+            session_id: read_json_field(value, "SessionId")?,
+            client_user_id_of_session: read_json_field(value, "ClientUserIdOfSession")?,
+            client_user_id_history: read_json_array_field(value, "ClientUserIdHistory")?,
+            authentication_mechanism: read_json_field(value, "AuthenticationMechanism")?,
+            encoding: read_json_field(value, "Encoding")?,
+            transport_protocol: read_json_field(value, "TransportProtocol")?,
+            security_mode: read_json_field(value, "SecurityMode")?,
+            security_policy_uri: read_json_field(value, "SecurityPolicyUri")?,
+            client_certificate: read_json_field(value, "ClientCertificate")?,
+        })
+    }
+}
+
+impl XmlEncoder<SessionSecurityDiagnosticsDataType> for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SessionId", &self.session_id);
+        write_xml_field(node, "ClientUserIdOfSession", &self.client_user_id_of_session);
+        write_xml_array_field(node, "ClientUserIdHistory", "String", &self.client_user_id_history);
+        write_xml_field(node, "AuthenticationMechanism", &self.authentication_mechanism);
+        write_xml_field(node, "Encoding", &self.encoding);
+        write_xml_field(node, "TransportProtocol", &self.transport_protocol);
+        write_xml_field(node, "SecurityMode", &self.security_mode);
+        write_xml_field(node, "SecurityPolicyUri", &self.security_policy_uri);
+        write_xml_field(node, "ClientCertificate", &self.client_certificate);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SessionSecurityDiagnosticsDataType {
+            session_id: read_xml_field(node, "SessionId")?,
+            client_user_id_of_session: read_xml_field(node, "ClientUserIdOfSession")?,
+            client_user_id_history: read_xml_array_field(node, "ClientUserIdHistory")?,
+            authentication_mechanism: read_xml_field(node, "AuthenticationMechanism")?,
+            encoding: read_xml_field(node, "Encoding")?,
+            transport_protocol: read_xml_field(node, "TransportProtocol")?,
+            security_mode: read_xml_field(node, "SecurityMode")?,
+            security_policy_uri: read_xml_field(node, "SecurityPolicyUri")?,
+            client_certificate: read_xml_field(node, "ClientCertificate")?,
+        })
+    }
+}
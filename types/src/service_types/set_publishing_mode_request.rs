@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -53,3 +55,42 @@ impl BinaryEncoder<SetPublishingModeRequest> for SetPublishingModeRequest {
         })
     }
 }
+
+impl JsonEncoder<SetPublishingModeRequest> for SetPublishingModeRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "PublishingEnabled", &self.publishing_enabled, reversible);
+        write_json_array_field(&mut map, "SubscriptionIds", &self.subscription_ids, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SetPublishingModeRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            publishing_enabled: read_json_field(value, "PublishingEnabled")?,
+            subscription_ids: read_json_array_field(value, "SubscriptionIds")?,
+        })
+    }
+}
+
+impl XmlEncoder<SetPublishingModeRequest> for SetPublishingModeRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "PublishingEnabled", &self.publishing_enabled);
+        write_xml_array_field(node, "SubscriptionIds", "UInt32", &self.subscription_ids);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SetPublishingModeRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            publishing_enabled: read_xml_field(node, "PublishingEnabled")?,
+            subscription_ids: read_xml_array_field(node, "SubscriptionIds")?,
+        })
+    }
+}
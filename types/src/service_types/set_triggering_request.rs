@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -63,3 +65,50 @@ impl BinaryEncoder<SetTriggeringRequest> for SetTriggeringRequest {
         })
     }
 }
+
+impl JsonEncoder<SetTriggeringRequest> for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_field(&mut map, "TriggeringItemId", &self.triggering_item_id, reversible);
+        write_json_array_field(&mut map, "LinksToAdd", &self.links_to_add, reversible);
+        write_json_array_field(&mut map, "LinksToRemove", &self.links_to_remove, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SetTriggeringRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            triggering_item_id: read_json_field(value, "TriggeringItemId")?,
+            links_to_add: read_json_array_field(value, "LinksToAdd")?,
+            links_to_remove: read_json_array_field(value, "LinksToRemove")?,
+        })
+    }
+}
+
+impl XmlEncoder<SetTriggeringRequest> for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_field(node, "TriggeringItemId", &self.triggering_item_id);
+        write_xml_array_field(node, "LinksToAdd", "UInt32", &self.links_to_add);
+        write_xml_array_field(node, "LinksToRemove", "UInt32", &self.links_to_remove);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SetTriggeringRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            triggering_item_id: read_xml_field(node, "TriggeringItemId")?,
+            links_to_add: read_xml_array_field(node, "LinksToAdd")?,
+            links_to_remove: read_xml_array_field(node, "LinksToRemove")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -65,3 +67,50 @@ impl BinaryEncoder<SetTriggeringResponse> for SetTriggeringResponse {
         })
     }
 }
+
+impl JsonEncoder<SetTriggeringResponse> for SetTriggeringResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ResponseHeader", &self.response_header, reversible);
+        write_json_array_field(&mut map, "AddResults", &self.add_results, reversible);
+        write_json_array_field(&mut map, "AddDiagnosticInfos", &self.add_diagnostic_infos, reversible);
+        write_json_array_field(&mut map, "RemoveResults", &self.remove_results, reversible);
+        write_json_array_field(&mut map, "RemoveDiagnosticInfos", &self.remove_diagnostic_infos, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SetTriggeringResponse {
+            response_header: read_json_field(value, "ResponseHeader")?,
+            add_results: read_json_array_field(value, "AddResults")?,
+            add_diagnostic_infos: read_json_array_field(value, "AddDiagnosticInfos")?,
+            remove_results: read_json_array_field(value, "RemoveResults")?,
+            remove_diagnostic_infos: read_json_array_field(value, "RemoveDiagnosticInfos")?,
+        })
+    }
+}
+
+impl XmlEncoder<SetTriggeringResponse> for SetTriggeringResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ResponseHeader", &self.response_header);
+        write_xml_array_field(node, "AddResults", "StatusCode", &self.add_results);
+        write_xml_array_field(node, "AddDiagnosticInfos", "DiagnosticInfo", &self.add_diagnostic_infos);
+        write_xml_array_field(node, "RemoveResults", "StatusCode", &self.remove_results);
+        write_xml_array_field(node, "RemoveDiagnosticInfos", "DiagnosticInfo", &self.remove_diagnostic_infos);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SetTriggeringResponse {
+            response_header: read_xml_field(node, "ResponseHeader")?,
+            add_results: read_xml_array_field(node, "AddResults")?,
+            add_diagnostic_infos: read_xml_array_field(node, "AddDiagnosticInfos")?,
+            remove_results: read_xml_array_field(node, "RemoveResults")?,
+            remove_diagnostic_infos: read_xml_array_field(node, "RemoveDiagnosticInfos")?,
+        })
+    }
+}
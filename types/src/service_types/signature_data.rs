@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -50,3 +52,38 @@ impl BinaryEncoder<SignatureData> for SignatureData {
         })
     }
 }
+
+impl JsonEncoder<SignatureData> for SignatureData {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Algorithm", &self.algorithm, reversible);
+        write_json_field(&mut map, "Signature", &self.signature, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SignatureData {
+            algorithm: read_json_field(value, "Algorithm")?,
+            signature: read_json_field(value, "Signature")?,
+        })
+    }
+}
+
+impl XmlEncoder<SignatureData> for SignatureData {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Algorithm", &self.algorithm);
+        write_xml_field(node, "Signature", &self.signature);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SignatureData {
+            algorithm: read_xml_field(node, "Algorithm")?,
+            signature: read_xml_field(node, "Signature")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<SignedSoftwareCertificate> for SignedSoftwareCertificate {
         })
     }
 }
+
+impl JsonEncoder<SignedSoftwareCertificate> for SignedSoftwareCertificate {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "CertificateData", &self.certificate_data, reversible);
+        write_json_field(&mut map, "Signature", &self.signature, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SignedSoftwareCertificate {
+            certificate_data: read_json_field(value, "CertificateData")?,
+            signature: read_json_field(value, "Signature")?,
+        })
+    }
+}
+
+impl XmlEncoder<SignedSoftwareCertificate> for SignedSoftwareCertificate {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "CertificateData", &self.certificate_data);
+        write_xml_field(node, "Signature", &self.signature);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SignedSoftwareCertificate {
+            certificate_data: read_xml_field(node, "CertificateData")?,
+            signature: read_xml_field(node, "Signature")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use node_id::NodeId;
@@ -52,3 +54,46 @@ impl BinaryEncoder<SimpleAttributeOperand> for SimpleAttributeOperand {
         })
     }
 }
+
+impl JsonEncoder<SimpleAttributeOperand> for SimpleAttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "TypeDefinitionId", &self.type_definition_id, reversible);
+        write_json_array_field(&mut map, "BrowsePath", &self.browse_path, reversible);
+        write_json_field(&mut map, "AttributeId", &self.attribute_id, reversible);
+        write_json_field(&mut map, "IndexRange", &self.index_range, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SimpleAttributeOperand {
+            type_definition_id: read_json_field(value, "TypeDefinitionId")?,
+            browse_path: read_json_array_field(value, "BrowsePath")?,
+            attribute_id: read_json_field(value, "AttributeId")?,
+            index_range: read_json_field(value, "IndexRange")?,
+        })
+    }
+}
+
+impl XmlEncoder<SimpleAttributeOperand> for SimpleAttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "TypeDefinitionId", &self.type_definition_id);
+        write_xml_array_field(node, "BrowsePath", "QualifiedName", &self.browse_path);
+        write_xml_field(node, "AttributeId", &self.attribute_id);
+        write_xml_field(node, "IndexRange", &self.index_range);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SimpleAttributeOperand {
+            type_definition_id: read_xml_field(node, "TypeDefinitionId")?,
+            browse_path: read_xml_array_field(node, "BrowsePath")?,
+            attribute_id: read_xml_field(node, "AttributeId")?,
+            index_range: read_xml_field(node, "IndexRange")?,
+        })
+    }
+}
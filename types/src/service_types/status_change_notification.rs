@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use status_codes::StatusCode;
@@ -41,3 +43,38 @@ impl BinaryEncoder<StatusChangeNotification> for StatusChangeNotification {
         })
     }
 }
+
+impl JsonEncoder<StatusChangeNotification> for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Status", &self.status, reversible);
+        write_json_field(&mut map, "DiagnosticInfo", &self.diagnostic_info, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(StatusChangeNotification {
+            status: read_json_field(value, "Status")?,
+            diagnostic_info: read_json_field(value, "DiagnosticInfo")?,
+        })
+    }
+}
+
+impl XmlEncoder<StatusChangeNotification> for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Status", &self.status);
+        write_xml_field(node, "DiagnosticInfo", &self.diagnostic_info);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(StatusChangeNotification {
+            status: read_xml_field(node, "Status")?,
+            diagnostic_info: read_xml_field(node, "DiagnosticInfo")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<StatusResult> for StatusResult {
         })
     }
 }
+
+impl JsonEncoder<StatusResult> for StatusResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_field(&mut map, "DiagnosticInfo", &self.diagnostic_info, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(StatusResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            diagnostic_info: read_json_field(value, "DiagnosticInfo")?,
+        })
+    }
+}
+
+impl XmlEncoder<StatusResult> for StatusResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_field(node, "DiagnosticInfo", &self.diagnostic_info);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(StatusResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            diagnostic_info: read_xml_field(node, "DiagnosticInfo")?,
+        })
+    }
+}
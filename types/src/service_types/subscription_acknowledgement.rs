@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -47,3 +49,38 @@ impl BinaryEncoder<SubscriptionAcknowledgement> for SubscriptionAcknowledgement
         })
     }
 }
+
+impl JsonEncoder<SubscriptionAcknowledgement> for SubscriptionAcknowledgement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_field(&mut map, "SequenceNumber", &self.sequence_number, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SubscriptionAcknowledgement {
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            sequence_number: read_json_field(value, "SequenceNumber")?,
+        })
+    }
+}
+
+impl XmlEncoder<SubscriptionAcknowledgement> for SubscriptionAcknowledgement {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_field(node, "SequenceNumber", &self.sequence_number);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SubscriptionAcknowledgement {
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            sequence_number: read_xml_field(node, "SequenceNumber")?,
+        })
+    }
+}
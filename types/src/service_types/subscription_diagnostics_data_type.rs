@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -193,3 +195,154 @@ impl BinaryEncoder<SubscriptionDiagnosticsDataType> for SubscriptionDiagnosticsD
         })
     }
 }
+
+impl JsonEncoder<SubscriptionDiagnosticsDataType> for SubscriptionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SessionId", &self.session_id, reversible);
+        write_json_field(&mut map, "SubscriptionId", &self.subscription_id, reversible);
+        write_json_field(&mut map, "Priority", &self.priority, reversible);
+        write_json_field(&mut map, "PublishingInterval", &self.publishing_interval, reversible);
+        write_json_field(&mut map, "MaxKeepAliveCount", &self.max_keep_alive_count, reversible);
+        write_json_field(&mut map, "MaxLifetimeCount", &self.max_lifetime_count, reversible);
+        write_json_field(&mut map, "MaxNotificationsPerPublish", &self.max_notifications_per_publish, reversible);
+        write_json_field(&mut map, "PublishingEnabled", &self.publishing_enabled, reversible);
+        write_json_field(&mut map, "ModifyCount", &self.modify_count, reversible);
+        write_json_field(&mut map, "EnableCount", &self.enable_count, reversible);
+        write_json_field(&mut map, "DisableCount", &self.disable_count, reversible);
+        write_json_field(&mut map, "RepublishRequestCount", &self.republish_request_count, reversible);
+        write_json_field(&mut map, "RepublishMessageRequestCount", &self.republish_message_request_count, reversible);
+        write_json_field(&mut map, "RepublishMessageCount", &self.republish_message_count, reversible);
+        write_json_field(&mut map, "TransferRequestCount", &self.transfer_request_count, reversible);
+        write_json_field(&mut map, "TransferredToAltClientCount", &self.transferred_to_alt_client_count, reversible);
+        write_json_field(&mut map, "TransferredToSameClientCount", &self.transferred_to_same_client_count, reversible);
+        write_json_field(&mut map, "PublishRequestCount", &self.publish_request_count, reversible);
+        write_json_field(&mut map, "DataChangeNotificationsCount", &self.data_change_notifications_count, reversible);
+        write_json_field(&mut map, "EventNotificationsCount", &self.event_notifications_count, reversible);
+        write_json_field(&mut map, "NotificationsCount", &self.notifications_count, reversible);
+        write_json_field(&mut map, "LatePublishRequestCount", &self.late_publish_request_count, reversible);
+        write_json_field(&mut map, "CurrentKeepAliveCount", &self.current_keep_alive_count, reversible);
+        write_json_field(&mut map, "CurrentLifetimeCount", &self.current_lifetime_count, reversible);
+        write_json_field(&mut map, "UnacknowledgedMessageCount", &self.unacknowledged_message_count, reversible);
+        write_json_field(&mut map, "DiscardedMessageCount", &self.discarded_message_count, reversible);
+        write_json_field(&mut map, "MonitoredItemCount", &self.monitored_item_count, reversible);
+        write_json_field(&mut map, "DisabledMonitoredItemCount", &self.disabled_monitored_item_count, reversible);
+        write_json_field(&mut map, "MonitoringQueueOverflowCount", &self.monitoring_queue_overflow_count, reversible);
+        write_json_field(&mut map, "NextSequenceNumber", &self.next_sequence_number, reversible);
+        write_json_field(&mut map, "EventQueueOverFlowCount", &self.event_queue_over_flow_count, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(SubscriptionDiagnosticsDataType {
+            session_id: read_json_field(value, "SessionId")?,
+            subscription_id: read_json_field(value, "SubscriptionId")?,
+            priority: read_json_field(value, "Priority")?,
+            publishing_interval: read_json_field(value, "PublishingInterval")?,
+            max_keep_alive_count: read_json_field(value, "MaxKeepAliveCount")?,
+            max_lifetime_count: read_json_field(value, "MaxLifetimeCount")?,
+            max_notifications_per_publish: read_json_field(value, "MaxNotificationsPerPublish")?,
+            publishing_enabled: read_json_field(value, "PublishingEnabled")?,
+            modify_count: read_json_field(value, "ModifyCount")?,
+            enable_count: read_json_field(value, "EnableCount")?,
+            disable_count: read_json_field(value, "DisableCount")?,
+            republish_request_count: read_json_field(value, "RepublishRequestCount")?,
+            republish_message_request_count: read_json_field(value, "RepublishMessageRequestCount")?,
+            republish_message_count: read_json_field(value, "RepublishMessageCount")?,
+            transfer_request_count: read_json_field(value, "TransferRequestCount")?,
+            transferred_to_alt_client_count: read_json_field(value, "TransferredToAltClientCount")?,
+            transferred_to_same_client_count: read_json_field(value, "TransferredToSameClientCount")?,
+            publish_request_count: read_json_field(value, "PublishRequestCount")?,
+            data_change_notifications_count: read_json_field(value, "DataChangeNotificationsCount")?,
+            event_notifications_count: read_json_field(value, "EventNotificationsCount")?,
+            notifications_count: read_json_field(value, "NotificationsCount")?,
+            late_publish_request_count: read_json_field(value, "LatePublishRequestCount")?,
+            current_keep_alive_count: read_json_field(value, "CurrentKeepAliveCount")?,
+            current_lifetime_count: read_json_field(value, "CurrentLifetimeCount")?,
+            unacknowledged_message_count: read_json_field(value, "UnacknowledgedMessageCount")?,
+            discarded_message_count: read_json_field(value, "DiscardedMessageCount")?,
+            monitored_item_count: read_json_field(value, "MonitoredItemCount")?,
+            disabled_monitored_item_count: read_json_field(value, "DisabledMonitoredItemCount")?,
+            monitoring_queue_overflow_count: read_json_field(value, "MonitoringQueueOverflowCount")?,
+            next_sequence_number: read_json_field(value, "NextSequenceNumber")?,
+            event_queue_over_flow_count: read_json_field(value, "EventQueueOverFlowCount")?,
+        })
+    }
+}
+
+impl XmlEncoder<SubscriptionDiagnosticsDataType> for SubscriptionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SessionId", &self.session_id);
+        write_xml_field(node, "SubscriptionId", &self.subscription_id);
+        write_xml_field(node, "Priority", &self.priority);
+        write_xml_field(node, "PublishingInterval", &self.publishing_interval);
+        write_xml_field(node, "MaxKeepAliveCount", &self.max_keep_alive_count);
+        write_xml_field(node, "MaxLifetimeCount", &self.max_lifetime_count);
+        write_xml_field(node, "MaxNotificationsPerPublish", &self.max_notifications_per_publish);
+        write_xml_field(node, "PublishingEnabled", &self.publishing_enabled);
+        write_xml_field(node, "ModifyCount", &self.modify_count);
+        write_xml_field(node, "EnableCount", &self.enable_count);
+        write_xml_field(node, "DisableCount", &self.disable_count);
+        write_xml_field(node, "RepublishRequestCount", &self.republish_request_count);
+        write_xml_field(node, "RepublishMessageRequestCount", &self.republish_message_request_count);
+        write_xml_field(node, "RepublishMessageCount", &self.republish_message_count);
+        write_xml_field(node, "TransferRequestCount", &self.transfer_request_count);
+        write_xml_field(node, "TransferredToAltClientCount", &self.transferred_to_alt_client_count);
+        write_xml_field(node, "TransferredToSameClientCount", &self.transferred_to_same_client_count);
+        write_xml_field(node, "PublishRequestCount", &self.publish_request_count);
+        write_xml_field(node, "DataChangeNotificationsCount", &self.data_change_notifications_count);
+        write_xml_field(node, "EventNotificationsCount", &self.event_notifications_count);
+        write_xml_field(node, "NotificationsCount", &self.notifications_count);
+        write_xml_field(node, "LatePublishRequestCount", &self.late_publish_request_count);
+        write_xml_field(node, "CurrentKeepAliveCount", &self.current_keep_alive_count);
+        write_xml_field(node, "CurrentLifetimeCount", &self.current_lifetime_count);
+        write_xml_field(node, "UnacknowledgedMessageCount", &self.unacknowledged_message_count);
+        write_xml_field(node, "DiscardedMessageCount", &self.discarded_message_count);
+        write_xml_field(node, "MonitoredItemCount", &self.monitored_item_count);
+        write_xml_field(node, "DisabledMonitoredItemCount", &self.disabled_monitored_item_count);
+        write_xml_field(node, "MonitoringQueueOverflowCount", &self.monitoring_queue_overflow_count);
+        write_xml_field(node, "NextSequenceNumber", &self.next_sequence_number);
+        write_xml_field(node, "EventQueueOverFlowCount", &self.event_queue_over_flow_count);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(SubscriptionDiagnosticsDataType {
+            session_id: read_xml_field(node, "SessionId")?,
+            subscription_id: read_xml_field(node, "SubscriptionId")?,
+            priority: read_xml_field(node, "Priority")?,
+            publishing_interval: read_xml_field(node, "PublishingInterval")?,
+            max_keep_alive_count: read_xml_field(node, "MaxKeepAliveCount")?,
+            max_lifetime_count: read_xml_field(node, "MaxLifetimeCount")?,
+            max_notifications_per_publish: read_xml_field(node, "MaxNotificationsPerPublish")?,
+            publishing_enabled: read_xml_field(node, "PublishingEnabled")?,
+            modify_count: read_xml_field(node, "ModifyCount")?,
+            enable_count: read_xml_field(node, "EnableCount")?,
+            disable_count: read_xml_field(node, "DisableCount")?,
+            republish_request_count: read_xml_field(node, "RepublishRequestCount")?,
+            republish_message_request_count: read_xml_field(node, "RepublishMessageRequestCount")?,
+            republish_message_count: read_xml_field(node, "RepublishMessageCount")?,
+            transfer_request_count: read_xml_field(node, "TransferRequestCount")?,
+            transferred_to_alt_client_count: read_xml_field(node, "TransferredToAltClientCount")?,
+            transferred_to_same_client_count: read_xml_field(node, "TransferredToSameClientCount")?,
+            publish_request_count: read_xml_field(node, "PublishRequestCount")?,
+            data_change_notifications_count: read_xml_field(node, "DataChangeNotificationsCount")?,
+            event_notifications_count: read_xml_field(node, "EventNotificationsCount")?,
+            notifications_count: read_xml_field(node, "NotificationsCount")?,
+            late_publish_request_count: read_xml_field(node, "LatePublishRequestCount")?,
+            current_keep_alive_count: read_xml_field(node, "CurrentKeepAliveCount")?,
+            current_lifetime_count: read_xml_field(node, "CurrentLifetimeCount")?,
+            unacknowledged_message_count: read_xml_field(node, "UnacknowledgedMessageCount")?,
+            discarded_message_count: read_xml_field(node, "DiscardedMessageCount")?,
+            monitored_item_count: read_xml_field(node, "MonitoredItemCount")?,
+            disabled_monitored_item_count: read_xml_field(node, "DisabledMonitoredItemCount")?,
+            monitoring_queue_overflow_count: read_xml_field(node, "MonitoringQueueOverflowCount")?,
+            next_sequence_number: read_xml_field(node, "NextSequenceNumber")?,
+            event_queue_over_flow_count: read_xml_field(node, "EventQueueOverFlowCount")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -47,3 +49,38 @@ impl BinaryEncoder<TimeZoneDataType> for TimeZoneDataType {
         })
     }
 }
+
+impl JsonEncoder<TimeZoneDataType> for TimeZoneDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "Offset", &self.offset, reversible);
+        write_json_field(&mut map, "DaylightSavingInOffset", &self.daylight_saving_in_offset, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(TimeZoneDataType {
+            offset: read_json_field(value, "Offset")?,
+            daylight_saving_in_offset: read_json_field(value, "DaylightSavingInOffset")?,
+        })
+    }
+}
+
+impl XmlEncoder<TimeZoneDataType> for TimeZoneDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Offset", &self.offset);
+        write_xml_field(node, "DaylightSavingInOffset", &self.daylight_saving_in_offset);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(TimeZoneDataType {
+            offset: read_xml_field(node, "Offset")?,
+            daylight_saving_in_offset: read_xml_field(node, "DaylightSavingInOffset")?,
+        })
+    }
+}
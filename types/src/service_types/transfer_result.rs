@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -48,3 +50,38 @@ impl BinaryEncoder<TransferResult> for TransferResult {
         })
     }
 }
+
+impl JsonEncoder<TransferResult> for TransferResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "StatusCode", &self.status_code, reversible);
+        write_json_array_field(&mut map, "AvailableSequenceNumbers", &self.available_sequence_numbers, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(TransferResult {
+            status_code: read_json_field(value, "StatusCode")?,
+            available_sequence_numbers: read_json_array_field(value, "AvailableSequenceNumbers")?,
+        })
+    }
+}
+
+impl XmlEncoder<TransferResult> for TransferResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "StatusCode", &self.status_code);
+        write_xml_array_field(node, "AvailableSequenceNumbers", "UInt32", &self.available_sequence_numbers);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(TransferResult {
+            status_code: read_xml_field(node, "StatusCode")?,
+            available_sequence_numbers: read_xml_array_field(node, "AvailableSequenceNumbers")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -53,3 +55,42 @@ impl BinaryEncoder<TransferSubscriptionsRequest> for TransferSubscriptionsReques
         })
     }
 }
+
+impl JsonEncoder<TransferSubscriptionsRequest> for TransferSubscriptionsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "SubscriptionIds", &self.subscription_ids, reversible);
+        write_json_field(&mut map, "SendInitialValues", &self.send_initial_values, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(TransferSubscriptionsRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            subscription_ids: read_json_array_field(value, "SubscriptionIds")?,
+            send_initial_values: read_json_field(value, "SendInitialValues")?,
+        })
+    }
+}
+
+impl XmlEncoder<TransferSubscriptionsRequest> for TransferSubscriptionsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "SubscriptionIds", "UInt32", &self.subscription_ids);
+        write_xml_field(node, "SendInitialValues", &self.send_initial_values);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(TransferSubscriptionsRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            subscription_ids: read_xml_array_field(node, "SubscriptionIds")?,
+            send_initial_values: read_xml_field(node, "SendInitialValues")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -63,3 +65,50 @@ impl BinaryEncoder<TrustListDataType> for TrustListDataType {
         })
     }
 }
+
+impl JsonEncoder<TrustListDataType> for TrustListDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SpecifiedLists", &self.specified_lists, reversible);
+        write_json_array_field(&mut map, "TrustedCertificates", &self.trusted_certificates, reversible);
+        write_json_array_field(&mut map, "TrustedCrls", &self.trusted_crls, reversible);
+        write_json_array_field(&mut map, "IssuerCertificates", &self.issuer_certificates, reversible);
+        write_json_array_field(&mut map, "IssuerCrls", &self.issuer_crls, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(TrustListDataType {
+            specified_lists: read_json_field(value, "SpecifiedLists")?,
+            trusted_certificates: read_json_array_field(value, "TrustedCertificates")?,
+            trusted_crls: read_json_array_field(value, "TrustedCrls")?,
+            issuer_certificates: read_json_array_field(value, "IssuerCertificates")?,
+            issuer_crls: read_json_array_field(value, "IssuerCrls")?,
+        })
+    }
+}
+
+impl XmlEncoder<TrustListDataType> for TrustListDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SpecifiedLists", &self.specified_lists);
+        write_xml_array_field(node, "TrustedCertificates", "ByteString", &self.trusted_certificates);
+        write_xml_array_field(node, "TrustedCrls", "ByteString", &self.trusted_crls);
+        write_xml_array_field(node, "IssuerCertificates", "ByteString", &self.issuer_certificates);
+        write_xml_array_field(node, "IssuerCrls", "ByteString", &self.issuer_crls);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(TrustListDataType {
+            specified_lists: read_xml_field(node, "SpecifiedLists")?,
+            trusted_certificates: read_xml_array_field(node, "TrustedCertificates")?,
+            trusted_crls: read_xml_array_field(node, "TrustedCrls")?,
+            issuer_certificates: read_xml_array_field(node, "IssuerCertificates")?,
+            issuer_crls: read_xml_array_field(node, "IssuerCrls")?,
+        })
+    }
+}
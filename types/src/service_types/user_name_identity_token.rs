@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use string::UAString;
@@ -52,3 +54,46 @@ impl BinaryEncoder<UserNameIdentityToken> for UserNameIdentityToken {
         })
     }
 }
+
+impl JsonEncoder<UserNameIdentityToken> for UserNameIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "PolicyId", &self.policy_id, reversible);
+        write_json_field(&mut map, "UserName", &self.user_name, reversible);
+        write_json_field(&mut map, "Password", &self.password, reversible);
+        write_json_field(&mut map, "EncryptionAlgorithm", &self.encryption_algorithm, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(UserNameIdentityToken {
+            policy_id: read_json_field(value, "PolicyId")?,
+            user_name: read_json_field(value, "UserName")?,
+            password: read_json_field(value, "Password")?,
+            encryption_algorithm: read_json_field(value, "EncryptionAlgorithm")?,
+        })
+    }
+}
+
+impl XmlEncoder<UserNameIdentityToken> for UserNameIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "PolicyId", &self.policy_id);
+        write_xml_field(node, "UserName", &self.user_name);
+        write_xml_field(node, "Password", &self.password);
+        write_xml_field(node, "EncryptionAlgorithm", &self.encryption_algorithm);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(UserNameIdentityToken {
+            policy_id: read_xml_field(node, "PolicyId")?,
+            user_name: read_xml_field(node, "UserName")?,
+            password: read_xml_field(node, "Password")?,
+            encryption_algorithm: read_xml_field(node, "EncryptionAlgorithm")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -65,3 +67,50 @@ impl BinaryEncoder<UserTokenPolicy> for UserTokenPolicy {
         })
     }
 }
+
+impl JsonEncoder<UserTokenPolicy> for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "PolicyId", &self.policy_id, reversible);
+        write_json_field(&mut map, "TokenType", &self.token_type, reversible);
+        write_json_field(&mut map, "IssuedTokenType", &self.issued_token_type, reversible);
+        write_json_field(&mut map, "IssuerEndpointUrl", &self.issuer_endpoint_url, reversible);
+        write_json_field(&mut map, "SecurityPolicyUri", &self.security_policy_uri, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(UserTokenPolicy {
+            policy_id: read_json_field(value, "PolicyId")?,
+            token_type: read_json_field(value, "TokenType")?,
+            issued_token_type: read_json_field(value, "IssuedTokenType")?,
+            issuer_endpoint_url: read_json_field(value, "IssuerEndpointUrl")?,
+            security_policy_uri: read_json_field(value, "SecurityPolicyUri")?,
+        })
+    }
+}
+
+impl XmlEncoder<UserTokenPolicy> for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "PolicyId", &self.policy_id);
+        write_xml_field(node, "TokenType", &self.token_type);
+        write_xml_field(node, "IssuedTokenType", &self.issued_token_type);
+        write_xml_field(node, "IssuerEndpointUrl", &self.issuer_endpoint_url);
+        write_xml_field(node, "SecurityPolicyUri", &self.security_policy_uri);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(UserTokenPolicy {
+            policy_id: read_xml_field(node, "PolicyId")?,
+            token_type: read_xml_field(node, "TokenType")?,
+            issued_token_type: read_xml_field(node, "IssuedTokenType")?,
+            issuer_endpoint_url: read_xml_field(node, "IssuerEndpointUrl")?,
+            security_policy_uri: read_xml_field(node, "SecurityPolicyUri")?,
+        })
+    }
+}
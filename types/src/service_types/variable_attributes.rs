@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use basic_types::LocalizedText;
@@ -98,3 +100,82 @@ impl BinaryEncoder<VariableAttributes> for VariableAttributes {
         })
     }
 }
+
+impl JsonEncoder<VariableAttributes> for VariableAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SpecifiedAttributes", &self.specified_attributes, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        write_json_field(&mut map, "WriteMask", &self.write_mask, reversible);
+        write_json_field(&mut map, "UserWriteMask", &self.user_write_mask, reversible);
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        write_json_field(&mut map, "DataType", &self.data_type, reversible);
+        write_json_field(&mut map, "ValueRank", &self.value_rank, reversible);
+        write_json_array_field(&mut map, "ArrayDimensions", &self.array_dimensions, reversible);
+        write_json_field(&mut map, "AccessLevel", &self.access_level, reversible);
+        write_json_field(&mut map, "UserAccessLevel", &self.user_access_level, reversible);
+        write_json_field(&mut map, "MinimumSamplingInterval", &self.minimum_sampling_interval, reversible);
+        write_json_field(&mut map, "Historizing", &self.historizing, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(VariableAttributes {
+            specified_attributes: read_json_field(value, "SpecifiedAttributes")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            description: read_json_field(value, "Description")?,
+            write_mask: read_json_field(value, "WriteMask")?,
+            user_write_mask: read_json_field(value, "UserWriteMask")?,
+            value: read_json_field(value, "Value")?,
+            data_type: read_json_field(value, "DataType")?,
+            value_rank: read_json_field(value, "ValueRank")?,
+            array_dimensions: read_json_array_field(value, "ArrayDimensions")?,
+            access_level: read_json_field(value, "AccessLevel")?,
+            user_access_level: read_json_field(value, "UserAccessLevel")?,
+            minimum_sampling_interval: read_json_field(value, "MinimumSamplingInterval")?,
+            historizing: read_json_field(value, "Historizing")?,
+        })
+    }
+}
+
+impl XmlEncoder<VariableAttributes> for VariableAttributes {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SpecifiedAttributes", &self.specified_attributes);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "Description", &self.description);
+        write_xml_field(node, "WriteMask", &self.write_mask);
+        write_xml_field(node, "UserWriteMask", &self.user_write_mask);
+        write_xml_field(node, "Value", &self.value);
+        write_xml_field(node, "DataType", &self.data_type);
+        write_xml_field(node, "ValueRank", &self.value_rank);
+        write_xml_array_field(node, "ArrayDimensions", "UInt32", &self.array_dimensions);
+        write_xml_field(node, "AccessLevel", &self.access_level);
+        write_xml_field(node, "UserAccessLevel", &self.user_access_level);
+        write_xml_field(node, "MinimumSamplingInterval", &self.minimum_sampling_interval);
+        write_xml_field(node, "Historizing", &self.historizing);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(VariableAttributes {
+            specified_attributes: read_xml_field(node, "SpecifiedAttributes")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            description: read_xml_field(node, "Description")?,
+            write_mask: read_xml_field(node, "WriteMask")?,
+            user_write_mask: read_xml_field(node, "UserWriteMask")?,
+            value: read_xml_field(node, "Value")?,
+            data_type: read_xml_field(node, "DataType")?,
+            value_rank: read_xml_field(node, "ValueRank")?,
+            array_dimensions: read_xml_array_field(node, "ArrayDimensions")?,
+            access_level: read_xml_field(node, "AccessLevel")?,
+            user_access_level: read_xml_field(node, "UserAccessLevel")?,
+            minimum_sampling_interval: read_xml_field(node, "MinimumSamplingInterval")?,
+            historizing: read_xml_field(node, "Historizing")?,
+        })
+    }
+}
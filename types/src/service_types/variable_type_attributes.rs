@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use basic_types::LocalizedText;
@@ -83,3 +85,70 @@ impl BinaryEncoder<VariableTypeAttributes> for VariableTypeAttributes {
         })
     }
 }
+
+impl JsonEncoder<VariableTypeAttributes> for VariableTypeAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "SpecifiedAttributes", &self.specified_attributes, reversible);
+        write_json_field(&mut map, "DisplayName", &self.display_name, reversible);
+        write_json_field(&mut map, "Description", &self.description, reversible);
+        write_json_field(&mut map, "WriteMask", &self.write_mask, reversible);
+        write_json_field(&mut map, "UserWriteMask", &self.user_write_mask, reversible);
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        write_json_field(&mut map, "DataType", &self.data_type, reversible);
+        write_json_field(&mut map, "ValueRank", &self.value_rank, reversible);
+        write_json_array_field(&mut map, "ArrayDimensions", &self.array_dimensions, reversible);
+        write_json_field(&mut map, "IsAbstract", &self.is_abstract, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(VariableTypeAttributes {
+            specified_attributes: read_json_field(value, "SpecifiedAttributes")?,
+            display_name: read_json_field(value, "DisplayName")?,
+            description: read_json_field(value, "Description")?,
+            write_mask: read_json_field(value, "WriteMask")?,
+            user_write_mask: read_json_field(value, "UserWriteMask")?,
+            value: read_json_field(value, "Value")?,
+            data_type: read_json_field(value, "DataType")?,
+            value_rank: read_json_field(value, "ValueRank")?,
+            array_dimensions: read_json_array_field(value, "ArrayDimensions")?,
+            is_abstract: read_json_field(value, "IsAbstract")?,
+        })
+    }
+}
+
+impl XmlEncoder<VariableTypeAttributes> for VariableTypeAttributes {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "SpecifiedAttributes", &self.specified_attributes);
+        write_xml_field(node, "DisplayName", &self.display_name);
+        write_xml_field(node, "Description", &self.description);
+        write_xml_field(node, "WriteMask", &self.write_mask);
+        write_xml_field(node, "UserWriteMask", &self.user_write_mask);
+        write_xml_field(node, "Value", &self.value);
+        write_xml_field(node, "DataType", &self.data_type);
+        write_xml_field(node, "ValueRank", &self.value_rank);
+        write_xml_array_field(node, "ArrayDimensions", "UInt32", &self.array_dimensions);
+        write_xml_field(node, "IsAbstract", &self.is_abstract);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(VariableTypeAttributes {
+            specified_attributes: read_xml_field(node, "SpecifiedAttributes")?,
+            display_name: read_xml_field(node, "DisplayName")?,
+            description: read_xml_field(node, "Description")?,
+            write_mask: read_xml_field(node, "WriteMask")?,
+            user_write_mask: read_xml_field(node, "UserWriteMask")?,
+            value: read_xml_field(node, "Value")?,
+            data_type: read_xml_field(node, "DataType")?,
+            value_rank: read_xml_field(node, "ValueRank")?,
+            array_dimensions: read_xml_array_field(node, "ArrayDimensions")?,
+            is_abstract: read_xml_field(node, "IsAbstract")?,
+        })
+    }
+}
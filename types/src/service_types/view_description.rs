@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -55,3 +57,42 @@ impl BinaryEncoder<ViewDescription> for ViewDescription {
         })
     }
 }
+
+impl JsonEncoder<ViewDescription> for ViewDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "ViewId", &self.view_id, reversible);
+        write_json_field(&mut map, "Timestamp", &self.timestamp, reversible);
+        write_json_field(&mut map, "ViewVersion", &self.view_version, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(ViewDescription {
+            view_id: read_json_field(value, "ViewId")?,
+            timestamp: read_json_field(value, "Timestamp")?,
+            view_version: read_json_field(value, "ViewVersion")?,
+        })
+    }
+}
+
+impl XmlEncoder<ViewDescription> for ViewDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "ViewId", &self.view_id);
+        write_xml_field(node, "Timestamp", &self.timestamp);
+        write_xml_field(node, "ViewVersion", &self.view_version);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(ViewDescription {
+            view_id: read_xml_field(node, "ViewId")?,
+            timestamp: read_xml_field(node, "Timestamp")?,
+            view_version: read_xml_field(node, "ViewVersion")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -49,3 +51,38 @@ impl BinaryEncoder<WriteRequest> for WriteRequest {
         })
     }
 }
+
+impl JsonEncoder<WriteRequest> for WriteRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "RequestHeader", &self.request_header, reversible);
+        write_json_array_field(&mut map, "NodesToWrite", &self.nodes_to_write, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(WriteRequest {
+            request_header: read_json_field(value, "RequestHeader")?,
+            nodes_to_write: read_json_array_field(value, "NodesToWrite")?,
+        })
+    }
+}
+
+impl XmlEncoder<WriteRequest> for WriteRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "RequestHeader", &self.request_header);
+        write_xml_array_field(node, "NodesToWrite", "WriteValue", &self.nodes_to_write);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(WriteRequest {
+            request_header: read_xml_field(node, "RequestHeader")?,
+            nodes_to_write: read_xml_array_field(node, "NodesToWrite")?,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -60,3 +62,46 @@ impl BinaryEncoder<WriteValue> for WriteValue {
         })
     }
 }
+
+impl JsonEncoder<WriteValue> for WriteValue {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "NodeId", &self.node_id, reversible);
+        write_json_field(&mut map, "AttributeId", &self.attribute_id, reversible);
+        write_json_field(&mut map, "IndexRange", &self.index_range, reversible);
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(WriteValue {
+            node_id: read_json_field(value, "NodeId")?,
+            attribute_id: read_json_field(value, "AttributeId")?,
+            index_range: read_json_field(value, "IndexRange")?,
+            value: read_json_field(value, "Value")?,
+        })
+    }
+}
+
+impl XmlEncoder<WriteValue> for WriteValue {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "NodeId", &self.node_id);
+        write_xml_field(node, "AttributeId", &self.attribute_id);
+        write_xml_field(node, "IndexRange", &self.index_range);
+        write_xml_field(node, "Value", &self.value);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(WriteValue {
+            node_id: read_xml_field(node, "NodeId")?,
+            attribute_id: read_xml_field(node, "AttributeId")?,
+            index_range: read_xml_field(node, "IndexRange")?,
+            value: read_xml_field(node, "Value")?,
+        })
+    }
+}
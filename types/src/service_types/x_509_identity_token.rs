@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use string::UAString;
@@ -42,3 +44,38 @@ impl BinaryEncoder<X509IdentityToken> for X509IdentityToken {
         })
     }
 }
+
+impl JsonEncoder<X509IdentityToken> for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "PolicyId", &self.policy_id, reversible);
+        write_json_field(&mut map, "CertificateData", &self.certificate_data, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(X509IdentityToken {
+            policy_id: read_json_field(value, "PolicyId")?,
+            certificate_data: read_json_field(value, "CertificateData")?,
+        })
+    }
+}
+
+impl XmlEncoder<X509IdentityToken> for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "PolicyId", &self.policy_id);
+        write_xml_field(node, "CertificateData", &self.certificate_data);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(X509IdentityToken {
+            policy_id: read_xml_field(node, "PolicyId")?,
+            certificate_data: read_xml_field(node, "CertificateData")?,
+        })
+    }
+}
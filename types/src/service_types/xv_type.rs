@@ -4,6 +4,8 @@
 use std::io::{Read, Write};
 
 use encoding::*;
+use json::*;
+use xml::*;
 #[allow(unused_imports)]
 use basic_types::*;
 use service_types::impls::MessageInfo;
@@ -47,3 +49,38 @@ impl BinaryEncoder<XVType> for XVType {
         })
     }
 }
+
+impl JsonEncoder<XVType> for XVType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let mut map = JsonMap::new();
+        write_json_field(&mut map, "X", &self.x, reversible);
+        write_json_field(&mut map, "Value", &self.value, reversible);
+        JsonValue::Object(map)
+    }
+
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        check_json_object(value)?;
+        Ok(XVType {
+            x: read_json_field(value, "X")?,
+            value: read_json_field(value, "Value")?,
+        })
+    }
+}
+
+impl XmlEncoder<XVType> for XVType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "X", &self.x);
+        write_xml_field(node, "Value", &self.value);
+    }
+
+    #[allow(unused_variables)]
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(XVType {
+            x: read_xml_field(node, "X")?,
+            value: read_xml_field(node, "Value")?,
+        })
+    }
+}
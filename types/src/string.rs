@@ -2,6 +2,8 @@ use std::io::{Read, Write};
 use std::fmt;
 
 use encoding::{write_i32, BinaryEncoder, EncodingResult, process_encode_io_result, process_decode_io_result};
+use json::{JsonEncoder, JsonValue, read_json_string};
+use xml::{XmlEncoder, XmlNode};
 use basic_types::Int32;
 use constants;
 use status_codes::StatusCode::{BadDecodingError, BadEncodingLimitsExceeded};
@@ -73,6 +75,35 @@ impl BinaryEncoder<UAString> for UAString {
     }
 }
 
+impl JsonEncoder<UAString> for UAString {
+    fn encode_json(&self, _: bool) -> JsonValue {
+        if let Some(ref value) = self.value {
+            JsonValue::String(value.clone())
+        } else {
+            JsonValue::Null
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        Ok(UAString {
+            value: read_json_string(value)?.map(|value| value.to_string())
+        })
+    }
+}
+
+
+impl XmlEncoder<UAString> for UAString {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        node.text = self.value.clone();
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        Ok(UAString {
+            value: node.text.clone()
+        })
+    }
+}
+
 impl AsRef<str> for UAString {
     fn as_ref(&self) -> &str {
         if self.is_null() { "" } else { self.value.as_ref().unwrap() }
@@ -0,0 +1,235 @@
+use std::io::Cursor;
+
+use service_types::EnumValueType;
+
+use super::*;
+
+const VENDOR_NAMESPACE: &'static str = "urn:vendor:types";
+
+const VENDOR_DICTIONARY: &'static str = r#"<?xml version="1.0" encoding="utf-8"?>
+<opc:TypeDictionary xmlns:opc="http://opcfoundation.org/BinarySchema/" xmlns:ua="http://opcfoundation.org/UA/" xmlns:v="urn:vendor:types" DefaultByteOrder="LittleEndian" TargetNamespace="urn:vendor:types">
+  <opc:Import Namespace="http://opcfoundation.org/UA/"/>
+  <opc:EnumeratedType Name="Color" LengthInBits="32">
+    <opc:Documentation>A color</opc:Documentation>
+    <opc:EnumeratedValue Name="Red" Value="1"/>
+    <opc:EnumeratedValue Name="Green" Value="2"/>
+  </opc:EnumeratedType>
+  <opc:StructuredType Name="Point" BaseType="ua:ExtensionObject">
+    <opc:Field Name="X" TypeName="opc:Double"/>
+    <opc:Field Name="Y" TypeName="opc:Double"/>
+  </opc:StructuredType>
+  <opc:StructuredType Name="Shape" BaseType="ua:ExtensionObject">
+    <opc:Field Name="ColorSpecified" TypeName="opc:Bit"/>
+    <opc:Field Name="Reserved1" TypeName="opc:Bit" Length="31"/>
+    <opc:Field Name="Name" TypeName="opc:String"/>
+    <opc:Field Name="Color" TypeName="v:Color" SwitchField="ColorSpecified"/>
+    <opc:Field Name="NoOfPoints" TypeName="opc:Int32"/>
+    <opc:Field Name="Points" TypeName="v:Point" LengthField="NoOfPoints"/>
+    <opc:Field Name="Id" TypeName="ua:NodeId"/>
+  </opc:StructuredType>
+  <opc:StructuredType Name="Setpoint" BaseType="ua:ExtensionObject">
+    <opc:Field Name="SwitchField" TypeName="opc:UInt32"/>
+    <opc:Field Name="Level" TypeName="opc:Int32" SwitchField="SwitchField" SwitchValue="1"/>
+    <opc:Field Name="Label" TypeName="opc:String" SwitchField="SwitchField" SwitchValue="2"/>
+  </opc:StructuredType>
+</opc:TypeDictionary>"#;
+
+fn vendor_registry() -> DataTypeRegistry {
+    let mut registry = DataTypeRegistry::new();
+    registry.add_dictionary(TypeDictionary::parse(VENDOR_DICTIONARY).unwrap());
+    registry.add_encoding(NodeId::new(2, 5001), TypeName::new(VENDOR_NAMESPACE, "Point"));
+    registry.add_encoding(NodeId::new(2, 5002), TypeName::new(VENDOR_NAMESPACE, "Shape"));
+    registry.add_encoding(NodeId::new(2, 5003), TypeName::new(VENDOR_NAMESPACE, "Setpoint"));
+    registry
+}
+
+fn point(registry: &DataTypeRegistry, x: Double, y: Double) -> Variant {
+    let mut point = DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Point"));
+    point.set("X", x);
+    point.set("Y", y);
+    Variant::from(registry.encode(&point).unwrap())
+}
+
+fn body(extension_object: &ExtensionObject) -> Vec<u8> {
+    if let ExtensionObjectEncoding::ByteString(ref value) = extension_object.body {
+        value.value.clone().unwrap()
+    } else {
+        panic!("Extension object has no binary body");
+    }
+}
+
+#[test]
+fn parse_type_dictionary() {
+    let dictionary = TypeDictionary::parse(VENDOR_DICTIONARY).unwrap();
+    assert_eq!(dictionary.target_namespace, VENDOR_NAMESPACE);
+    assert_eq!(dictionary.structured_types.len(), 3);
+
+    let color = dictionary.find_enumerated_type("Color").unwrap();
+    assert_eq!(color.length_in_bits, 32);
+    assert_eq!(color.values, vec![("Red".to_string(), 1), ("Green".to_string(), 2)]);
+
+    // Prefixes are resolved to the namespaces they are bound to
+    let shape = dictionary.find_structured_type("Shape").unwrap();
+    assert_eq!(shape.base_type, Some(TypeName::new(UA_TYPES_NAMESPACE, "ExtensionObject")));
+    assert_eq!(shape.fields.len(), 7);
+    assert_eq!(shape.fields[0].type_name, TypeName::new(BINARY_SCHEMA_NAMESPACE, "Bit"));
+    assert_eq!(shape.fields[1].length, Some(31));
+    assert_eq!(shape.fields[3].type_name, TypeName::new(VENDOR_NAMESPACE, "Color"));
+    assert_eq!(shape.fields[3].switch_field, Some("ColorSpecified".to_string()));
+    assert_eq!(shape.fields[5].length_field, Some("NoOfPoints".to_string()));
+    assert_eq!(shape.fields[6].type_name, TypeName::new(UA_TYPES_NAMESPACE, "NodeId"));
+
+    let setpoint = dictionary.find_structured_type("Setpoint").unwrap();
+    assert_eq!(setpoint.fields[2].switch_value, Some(2));
+
+    assert_eq!(TypeDictionary::parse("<Schema/>").unwrap_err(), BadDecodingError);
+    assert_eq!(TypeDictionary::parse("<opc:TypeDictionary xmlns:opc=\"http://opcfoundation.org/BinarySchema/\"/>").unwrap_err(), BadDecodingError);
+}
+
+#[test]
+fn decode_standard_structures() {
+    // The dictionary of namespace 0 describes the generated types, so decoding with it must
+    // agree with them
+    let dictionary = TypeDictionary::parse(include_str!("../../../schemas/1.0.3/Opc.Ua.Types.bsd.xml")).unwrap();
+    assert_eq!(dictionary.target_namespace, UA_TYPES_NAMESPACE);
+    let mut registry = DataTypeRegistry::new();
+    registry.add_dictionary(dictionary);
+    registry.add_encoding(ObjectId::Argument_Encoding_DefaultBinary, TypeName::new(UA_TYPES_NAMESPACE, "Argument"));
+    registry.add_encoding(ObjectId::EnumValueType_Encoding_DefaultBinary, TypeName::new(UA_TYPES_NAMESPACE, "EnumValueType"));
+
+    let argument = ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, ::service_types::Argument {
+        name: UAString::from("Speed"),
+        data_type: DataTypeId::Double.into(),
+        value_rank: 1,
+        array_dimensions: Some(vec![3]),
+        description: LocalizedText::new("en", "The speed"),
+    });
+    assert!(registry.is_known(&argument));
+    let structure = registry.decode(&argument).unwrap();
+    assert_eq!(structure.type_name, TypeName::new(UA_TYPES_NAMESPACE, "Argument"));
+    assert_eq!(structure.fields.len(), 5);
+    assert_eq!(structure.get("Name"), Some(&Variant::from("Speed")));
+    let data_type: NodeId = DataTypeId::Double.into();
+    assert_eq!(structure.get("DataType"), Some(&Variant::from(data_type)));
+    assert_eq!(structure.get("ValueRank"), Some(&Variant::from(1i32)));
+    assert_eq!(structure.get("ArrayDimensions"), Some(&Variant::from(vec![Variant::from(3u32)])));
+    assert_eq!(structure.get("Description"), Some(&Variant::from(LocalizedText::new("en", "The speed"))));
+    assert!(structure.get("NoOfArrayDimensions").is_none());
+    assert_eq!(registry.encode(&structure).unwrap(), argument);
+
+    // A null array round trips as an empty value
+    let mut structure = structure;
+    structure.set("ArrayDimensions", Variant::Empty);
+    let value = registry.encode(&structure).unwrap();
+    let argument = value.decode_inner::<::service_types::Argument>().unwrap();
+    assert_eq!(argument.array_dimensions, None);
+
+    // Values are converted to the type of the field
+    let mut enum_value = DynamicStructure::new(TypeName::new(UA_TYPES_NAMESPACE, "EnumValueType"));
+    enum_value.set("Value", 5i32);
+    enum_value.set("DisplayName", LocalizedText::new("", "Five"));
+    enum_value.set("Description", LocalizedText::new("", ""));
+    let value = registry.encode(&enum_value).unwrap();
+    assert_eq!(value.node_id, ObjectId::EnumValueType_Encoding_DefaultBinary.into());
+    let enum_value = value.decode_inner::<EnumValueType>().unwrap();
+    assert_eq!(enum_value.value, 5);
+    assert_eq!(enum_value.display_name.text, UAString::from("Five"));
+}
+
+#[test]
+fn dynamic_structure_round_trip() {
+    let registry = vendor_registry();
+    let mut shape = DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Shape"));
+    shape.set("Name", "Triangle");
+    shape.set("Color", 2i32);
+    shape.set("Points", vec![point(&registry, 0.0, 0.0), point(&registry, 1.0, 0.0), point(&registry, 0.0, 1.0)]);
+    shape.set("Id", NodeId::new_string(2, "Triangle"));
+
+    let value = registry.encode(&shape).unwrap();
+    assert_eq!(value.node_id, NodeId::new(2, 5002));
+    let bytes = body(&value);
+    // The color specified bit, padded to 32 bits
+    assert_eq!(&bytes[0..4], &[1, 0, 0, 0]);
+    // The name follows, then the color
+    let mut stream = Cursor::new(&bytes[4..]);
+    assert_eq!(UAString::decode(&mut stream).unwrap(), UAString::from("Triangle"));
+    assert_eq!(Int32::decode(&mut stream).unwrap(), 2);
+    assert_eq!(Int32::decode(&mut stream).unwrap(), 3);
+
+    let decoded = registry.decode(&value).unwrap();
+    assert_eq!(decoded, shape);
+
+    // Nested structures are extension objects that decode in turn
+    if let Some(&Variant::Array(ref points)) = decoded.get("Points") {
+        if let Variant::ExtensionObject(ref point) = points[2] {
+            let point = registry.decode(point).unwrap();
+            assert_eq!(point.get("X"), Some(&Variant::from(0.0f64)));
+            assert_eq!(point.get("Y"), Some(&Variant::from(1.0f64)));
+        } else {
+            panic!("Point is not an extension object");
+        }
+    } else {
+        panic!("Points is not an array");
+    }
+
+    // An optional field that is left out is not encoded
+    shape.remove("Color");
+    shape.set("Points", Variant::from(Vec::<Variant>::new()));
+    let value = registry.encode(&shape).unwrap();
+    assert_eq!(&body(&value)[0..4], &[0, 0, 0, 0]);
+    let decoded = registry.decode(&value).unwrap();
+    assert!(decoded.get("Color").is_none());
+    assert_eq!(decoded, shape);
+}
+
+#[test]
+fn dynamic_structure_union() {
+    let registry = vendor_registry();
+    let mut setpoint = DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Setpoint"));
+    setpoint.set("Label", "High");
+    let value = registry.encode(&setpoint).unwrap();
+    let bytes = body(&value);
+    assert_eq!(&bytes[0..4], &[2, 0, 0, 0]);
+    let decoded = registry.decode(&value).unwrap();
+    assert_eq!(decoded.fields, vec![("Label".to_string(), Variant::from("High"))]);
+
+    let mut setpoint = DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Setpoint"));
+    setpoint.set("Level", 10u8);
+    let decoded = registry.decode(&registry.encode(&setpoint).unwrap()).unwrap();
+    assert_eq!(decoded.fields, vec![("Level".to_string(), Variant::from(10i32))]);
+}
+
+#[test]
+fn dynamic_structure_errors() {
+    let registry = vendor_registry();
+
+    // Types that are not in the registry
+    let unknown = ExtensionObject::from_encodable(ObjectId::EnumValueType_Encoding_DefaultBinary, EnumValueType {
+        value: 1,
+        display_name: LocalizedText::new("", "One"),
+        description: LocalizedText::new("", ""),
+    });
+    assert!(!registry.is_known(&unknown));
+    assert_eq!(registry.decode(&unknown).unwrap_err(), BadDataTypeIdUnknown);
+    assert_eq!(registry.encode(&DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Line"))).unwrap_err(), BadDataTypeIdUnknown);
+
+    // A missing field, a value of the wrong type and a value out of the range of the field
+    let mut point = DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Point"));
+    point.set("X", 1.0f64);
+    assert_eq!(registry.encode(&point).unwrap_err(), BadEncodingError);
+    point.set("Y", "one");
+    assert_eq!(registry.encode(&point).unwrap_err(), BadTypeMismatch);
+    let mut shape = DynamicStructure::new(TypeName::new(VENDOR_NAMESPACE, "Shape"));
+    shape.set("Name", "Square");
+    shape.set("Points", Variant::Empty);
+    shape.set("Id", NodeId::null());
+    shape.set("Color", Int64::max_value());
+    assert_eq!(registry.encode(&shape).unwrap_err(), BadOutOfRange);
+
+    // A body that ends early
+    let value = ExtensionObject {
+        node_id: NodeId::new(2, 5001),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![0u8; 12])),
+    };
+    assert_eq!(registry.decode(&value).unwrap_err(), BadDecodingError);
+}
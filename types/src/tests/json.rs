@@ -0,0 +1,223 @@
+use std::fmt::Debug;
+
+use serde_json;
+
+use status_codes::StatusCode;
+use service_types::*;
+
+use super::*;
+
+fn json_test<T>(value: T) where T: JsonEncoder<T> + Debug + PartialEq {
+    let json = value.encode_json(true);
+    println!("value = {:?}", value);
+    println!("json = {}", json);
+    // Round trip through a string to be sure the JSON is complete
+    let json: JsonValue = serde_json::from_str(&json.to_string()).unwrap();
+    let new_value = T::decode_json(&json).unwrap();
+    assert_eq!(value, new_value);
+}
+
+#[test]
+fn json_built_in_types() {
+    json_test(true);
+    json_test(-12 as SByte);
+    json_test(200 as Byte);
+    json_test(-30000 as Int16);
+    json_test(60000 as UInt16);
+    json_test(-2000000000 as Int32);
+    json_test(4000000000 as UInt32);
+    json_test(-9000000000000000000 as Int64);
+    json_test(18000000000000000000 as UInt64);
+    json_test(1.5 as Float);
+    json_test(-0.25 as Double);
+    json_test(UAString::from("Hello \"world\""));
+    json_test(UAString::null());
+    json_test(ByteString::from(vec![0u8, 1, 2, 254, 255]));
+    json_test(Guid::new());
+    json_test(DateTime::now());
+    json_test(StatusCode::BadNodeIdUnknown);
+    json_test(QualifiedName::new(2, "Name"));
+    json_test(LocalizedText::new("en", "Text"));
+}
+
+#[test]
+fn json_integer_forms() {
+    assert_eq!(10i32.encode_json(true), JsonValue::from(10));
+    assert_eq!(10i64.encode_json(true), JsonValue::from("10"));
+    assert_eq!(Byte::decode_json(&JsonValue::from(256)).unwrap_err(), BadDecodingError);
+    assert_eq!(UInt32::decode_json(&JsonValue::from(-1)).unwrap_err(), BadDecodingError);
+    assert_eq!(Int32::decode_json(&JsonValue::from("x")).unwrap_err(), BadDecodingError);
+    assert_eq!(Int64::decode_json(&JsonValue::from("-5")).unwrap(), -5);
+}
+
+#[test]
+fn json_double_special_values() {
+    assert_eq!(::std::f64::INFINITY.encode_json(true), JsonValue::from("Infinity"));
+    assert_eq!(::std::f64::NEG_INFINITY.encode_json(true), JsonValue::from("-Infinity"));
+    assert_eq!(::std::f64::NAN.encode_json(true), JsonValue::from("NaN"));
+    assert!(Double::decode_json(&JsonValue::from("NaN")).unwrap().is_nan());
+    assert_eq!(Double::decode_json(&JsonValue::from("-Infinity")).unwrap(), ::std::f64::NEG_INFINITY);
+}
+
+#[test]
+fn json_node_id() {
+    json_test(NodeId::new(0, 2253));
+    json_test(NodeId::new(1, 99));
+    json_test(NodeId::new_string(2, "Hello"));
+    json_test(NodeId::new(3, Guid::new()));
+    json_test(NodeId::new(4, ByteString::from(vec![1u8, 2, 3])));
+
+    // Numeric ids omit the IdType and namespace 0 omits the Namespace
+    assert_eq!(NodeId::new(0, 2253).encode_json(true), json!({"Id": 2253}));
+    assert_eq!(NodeId::new_string(2, "Hello").encode_json(true), json!({"IdType": 1, "Id": "Hello", "Namespace": 2}));
+
+    assert_eq!(NodeId::decode_json(&json!({"IdType": 9, "Id": 1})).unwrap_err(), BadDecodingError);
+}
+
+#[test]
+fn json_expanded_node_id() {
+    json_test(ExpandedNodeId::new(NodeId::new(1, 99)));
+    // The namespace uri replaces the namespace index
+    let mut node_id = ExpandedNodeId::new(NodeId::new_string(0, "Hello"));
+    node_id.namespace_uri = UAString::from("urn:foo");
+    node_id.server_index = 2;
+    json_test(node_id.clone());
+
+    let json = node_id.encode_json(true);
+    assert_eq!(json["Namespace"], JsonValue::from("urn:foo"));
+    assert_eq!(json["ServerUri"], JsonValue::from(2));
+}
+
+#[test]
+fn json_status_code() {
+    assert_eq!(StatusCode::Good.encode_json(true), JsonValue::Null);
+    assert_eq!(StatusCode::BadNodeIdUnknown.encode_json(true), JsonValue::from(StatusCode::BadNodeIdUnknown as u32));
+    assert_eq!(StatusCode::BadNodeIdUnknown.encode_json(false), json!({"Code": StatusCode::BadNodeIdUnknown as u32, "Symbol": "BadNodeIdUnknown"}));
+    assert_eq!(StatusCode::decode_json(&JsonValue::Null).unwrap(), StatusCode::Good);
+}
+
+#[test]
+fn json_localized_text() {
+    let text = LocalizedText::new("en", "Hello");
+    assert_eq!(text.encode_json(true), json!({"Locale": "en", "Text": "Hello"}));
+    assert_eq!(text.encode_json(false), JsonValue::from("Hello"));
+}
+
+#[test]
+fn json_diagnostic_info() {
+    let mut inner = DiagnosticInfo::new();
+    inner.symbolic_id = Some(5);
+    let mut diagnostic_info = DiagnosticInfo::new();
+    diagnostic_info.additional_info = Some(UAString::from("Info"));
+    diagnostic_info.inner_status_code = Some(StatusCode::BadUnexpectedError);
+    diagnostic_info.inner_diagnostic_info = Some(Box::new(inner));
+    json_test(diagnostic_info);
+    json_test(DiagnosticInfo::new());
+}
+
+#[test]
+fn json_enum() {
+    assert_eq!(MessageSecurityMode::SignAndEncrypt.encode_json(true), JsonValue::from(3));
+    assert_eq!(MessageSecurityMode::SignAndEncrypt.encode_json(false), JsonValue::from("SignAndEncrypt_3"));
+    json_test(NodeClass::Variable);
+    json_test(TimestampsToReturn::Both);
+}
+
+#[test]
+fn json_variant() {
+    json_test(Variant::Empty);
+    json_test(Variant::Boolean(true));
+    json_test(Variant::Int64(-100));
+    json_test(Variant::Double(1.25));
+    json_test(Variant::from("Hello"));
+    json_test(Variant::from(NodeId::new_string(1, "Hello")));
+    json_test(Variant::from(LocalizedText::new("en", "Hello")));
+    json_test(Variant::from(DateTime::now()));
+    json_test(Variant::from(StatusCode::BadNodeIdUnknown));
+    json_test(Variant::from(vec![1u32, 2, 3]));
+    json_test(Variant::Array(vec![Variant::from("a"), Variant::String(UAString::null())]));
+
+    assert_eq!(Variant::Int32(5).encode_json(true), json!({"Type": 6, "Body": 5}));
+    assert_eq!(Variant::Int32(5).encode_json(false), JsonValue::from(5));
+    assert_eq!(Variant::Array(vec![Variant::Int32(1), Variant::Int32(2)]).encode_json(true), json!({"Type": 6, "Body": [1, 2]}));
+}
+
+#[test]
+fn json_variant_multi_dimension_array() {
+    let values = (0..6).map(|i| Variant::Int32(i)).collect();
+    let variant = Variant::new_multi_dimension_array(values, vec![2, 3]);
+    json_test(variant.clone());
+
+    assert_eq!(variant.encode_json(true), json!({"Type": 6, "Body": [0, 1, 2, 3, 4, 5], "Dimensions": [2, 3]}));
+    assert_eq!(variant.encode_json(false), json!([[0, 1, 2], [3, 4, 5]]));
+
+    // Dimensions must match the number of values
+    let json = json!({"Type": 6, "Body": [0, 1, 2], "Dimensions": [2, 3]});
+    assert_eq!(Variant::decode_json(&json).unwrap_err(), BadDecodingError);
+
+    // A zero dimension is an empty array and a negative dimension cannot be encoded
+    let variant = Variant::new_multi_dimension_array(Vec::new(), vec![2, 0]);
+    assert_eq!(variant.encode_json(false), json!([]));
+    let values = (0..6).map(|i| Variant::Int32(i)).collect();
+    let variant = Variant::new_multi_dimension_array(values, vec![-2, -3]);
+    assert_eq!(variant.encode_json(false), JsonValue::Null);
+    assert_eq!(variant.encode_json(true), JsonValue::Null);
+}
+
+#[test]
+fn json_data_value() {
+    let mut data_value = DataValue::new(Variant::Int32(10));
+    data_value.status = Some(StatusCode::UncertainLastUsableValue);
+    data_value.source_picoseconds = Some(100);
+    json_test(data_value);
+    json_test(DataValue::null());
+}
+
+#[test]
+fn json_generated_struct() {
+    let read_value_id = ReadValueId {
+        node_id: NodeId::new_string(1, "v1"),
+        attribute_id: AttributeId::Value as UInt32,
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+    };
+    json_test(read_value_id.clone());
+
+    let json = read_value_id.encode_json(true);
+    assert_eq!(json["NodeId"], json!({"IdType": 1, "Id": "v1", "Namespace": 1}));
+    assert_eq!(json["AttributeId"], JsonValue::from(13));
+    assert!(json.get("IndexRange").is_none());
+
+    let request = ReadRequest {
+        request_header: RequestHeader::new(&NodeId::new(0, 1), &DateTime::now(), 5),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Server,
+        nodes_to_read: Some(vec![read_value_id]),
+    };
+    json_test(request);
+}
+
+#[test]
+fn json_extension_object() {
+    json_test(ExtensionObject::null());
+
+    // A known binary body is written as a JSON body
+    let read_value_id = ReadValueId::from(NodeId::new_string(1, "v1"));
+    let extension_object = ExtensionObject::from_encodable(ObjectId::ReadValueId_Encoding_DefaultBinary, read_value_id.clone());
+    json_test(extension_object.clone());
+
+    let json = extension_object.encode_json(true);
+    let type_id: NodeId = DataTypeId::ReadValueId.into();
+    assert_eq!(json["TypeId"], type_id.encode_json(true));
+    assert!(json.get("Encoding").is_none());
+    assert_eq!(json["Body"], read_value_id.encode_json(true));
+    assert_eq!(extension_object.encode_json(false), read_value_id.encode_json(false));
+
+    // An unknown binary body is written as a ByteString
+    let extension_object = ExtensionObject {
+        node_id: NodeId::new(1, 100),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![1u8, 2, 3])),
+    };
+    json_test(extension_object.clone());
+    assert_eq!(extension_object.encode_json(true)["Encoding"], JsonValue::from(1));
+}
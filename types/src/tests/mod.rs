@@ -3,6 +3,9 @@ mod date_time;
 mod parse;
 mod serialize;
 mod variant;
+mod json;
+mod xml;
+mod dynamic_structure;
 
 use std::fmt::Debug;
 use std::cmp::PartialEq;
@@ -136,4 +136,36 @@ fn variant_multi_dimensional_array() {
     assert!(v.is_array());
     assert!(v.is_numeric_array());
     assert!(!v.is_valid());
-}
\ No newline at end of file
+}
+#[test]
+fn variant_convert() {
+    use status_codes::StatusCode;
+
+    // Same type, integers within range and widening
+    assert_eq!(Variant::from(10i32).convert(VariantTypeId::Int32), Ok(Variant::from(10i32)));
+    assert_eq!(Variant::from(200i32).convert(VariantTypeId::Byte), Ok(Variant::from(200u8)));
+    assert_eq!(Variant::from(-5i64).convert(VariantTypeId::SByte), Ok(Variant::from(-5i8)));
+    assert_eq!(Variant::from(true).convert(VariantTypeId::UInt16), Ok(Variant::from(1u16)));
+    assert_eq!(Variant::from(3u64).convert(VariantTypeId::Double), Ok(Variant::from(3f64)));
+    assert_eq!(Variant::from(1.5f32).convert(VariantTypeId::Double), Ok(Variant::from(1.5f64)));
+    assert_eq!(Variant::Empty.convert(VariantTypeId::Double), Ok(Variant::Empty));
+
+    // Integers out of range
+    assert_eq!(Variant::from(256i32).convert(VariantTypeId::Byte), Err(StatusCode::BadOutOfRange));
+    assert_eq!(Variant::from(-1i32).convert(VariantTypeId::UInt64), Err(StatusCode::BadOutOfRange));
+    assert_eq!(Variant::from(u64::max_value()).convert(VariantTypeId::Int64), Err(StatusCode::BadOutOfRange));
+
+    // No implicit conversion
+    assert_eq!(Variant::from(1.5f64).convert(VariantTypeId::Float), Err(StatusCode::BadTypeMismatch));
+    assert_eq!(Variant::from(1.5f64).convert(VariantTypeId::Int32), Err(StatusCode::BadTypeMismatch));
+    assert_eq!(Variant::from("10").convert(VariantTypeId::Int32), Err(StatusCode::BadTypeMismatch));
+    assert_eq!(Variant::from(1i32).convert(VariantTypeId::Boolean), Err(StatusCode::BadTypeMismatch));
+
+    // Arrays convert each element
+    let v = Variant::from(vec![Variant::from(1i32), Variant::from(2i32)]);
+    assert_eq!(v.convert(VariantTypeId::Int16), Ok(Variant::from(vec![Variant::from(1i16), Variant::from(2i16)])));
+    let v = Variant::from(vec![Variant::from(1i32), Variant::from(-2i32)]);
+    assert_eq!(v.convert(VariantTypeId::UInt16), Err(StatusCode::BadOutOfRange));
+    let v = Variant::new_multi_dimension_array(vec![Variant::from(1u8), Variant::from(2u8)], vec![1, 2]);
+    assert_eq!(v.convert(VariantTypeId::Int32), Ok(Variant::new_multi_dimension_array(vec![Variant::from(1i32), Variant::from(2i32)], vec![1, 2])));
+}
@@ -0,0 +1,254 @@
+use std::fmt::Debug;
+
+use status_codes::StatusCode;
+use service_types::*;
+
+use super::*;
+
+fn xml_test<T>(value: T) where T: XmlEncoder<T> + Debug + PartialEq {
+    let mut node = new_xml_root("Test");
+    value.encode_xml(&mut node);
+    println!("value = {:?}", value);
+    // Round trip through a string to be sure the XML is complete
+    let xml = xml_to_string(&node).unwrap();
+    println!("xml = {}", xml);
+    let node = parse_xml(&xml).unwrap();
+    let new_value = T::decode_xml(&node).unwrap();
+    assert_eq!(value, new_value);
+}
+
+fn xml_text<T>(value: T) -> String where T: XmlEncoder<T> {
+    let mut node = XmlNode::new("Test");
+    value.encode_xml(&mut node);
+    xml_to_string(&node).unwrap()
+}
+
+#[test]
+fn xml_built_in_types() {
+    xml_test(true);
+    xml_test(-12 as SByte);
+    xml_test(200 as Byte);
+    xml_test(-30000 as Int16);
+    xml_test(60000 as UInt16);
+    xml_test(-2000000000 as Int32);
+    xml_test(4000000000 as UInt32);
+    xml_test(-9000000000000000000 as Int64);
+    xml_test(18000000000000000000 as UInt64);
+    xml_test(1.5 as Float);
+    xml_test(-0.25 as Double);
+    xml_test(::std::f64::INFINITY);
+    xml_test(UAString::from("Hello <world> & \"friends\""));
+    xml_test(UAString::null());
+    xml_test(ByteString::from(vec![0u8, 1, 2, 254, 255]));
+    xml_test(ByteString::null());
+    xml_test(Guid::new());
+    xml_test(DateTime::now());
+    xml_test(StatusCode::BadNodeIdUnknown);
+    xml_test(QualifiedName::new(2, "Name"));
+    xml_test(LocalizedText::new("en", "Text"));
+    xml_test(NodeClass::Variable);
+}
+
+#[test]
+fn xml_forms() {
+    assert_eq!(xml_text(10i32), "<Test>10</Test>");
+    assert_eq!(xml_text(::std::f64::NEG_INFINITY), "<Test>-INF</Test>");
+    assert_eq!(xml_text(TimestampsToReturn::Both), "<Test>Both_2</Test>");
+    assert_eq!(xml_text(NodeId::new_string(1, "Hello")), "<Test><Identifier>ns=1;s=Hello</Identifier></Test>");
+    assert_eq!(xml_text(StatusCode::BadNodeIdUnknown), format!("<Test><Code>{}</Code></Test>", StatusCode::BadNodeIdUnknown as u32));
+}
+
+#[test]
+fn xml_parse_errors() {
+    assert_eq!(Int32::decode_xml(&parse_xml("<Test>x</Test>").unwrap()).unwrap_err(), BadDecodingError);
+    assert_eq!(Boolean::decode_xml(&parse_xml("<Test>yes</Test>").unwrap()).unwrap_err(), BadDecodingError);
+    assert_eq!(NodeId::decode_xml(&parse_xml("<Test><Identifier>x=1</Identifier></Test>").unwrap()).unwrap_err(), BadDecodingError);
+    assert_eq!(parse_xml("<Test>").unwrap_err(), BadDecodingError);
+    // Empty elements are default values
+    assert_eq!(Int32::decode_xml(&parse_xml("<Test/>").unwrap()).unwrap(), 0);
+    assert_eq!(StatusCode::decode_xml(&parse_xml("<Test/>").unwrap()).unwrap(), StatusCode::Good);
+}
+
+#[test]
+fn xml_node_id() {
+    xml_test(NodeId::null());
+    xml_test(NodeId::new(0, 2253));
+    xml_test(NodeId::new_string(2, "Hello"));
+    xml_test(NodeId::new(3, Guid::new()));
+    xml_test(NodeId::new(4, ByteString::from(vec![1u8, 2, 3])));
+
+    xml_test(ExpandedNodeId::new(NodeId::new(1, 99)));
+    let mut node_id = ExpandedNodeId::new(NodeId::new_string(1, "Hello"));
+    node_id.namespace_uri = UAString::from("urn:foo;bar");
+    node_id.server_index = 2;
+    xml_test(node_id);
+}
+
+#[test]
+fn xml_diagnostic_info() {
+    let mut inner = DiagnosticInfo::new();
+    inner.symbolic_id = Some(5);
+    let mut diagnostic_info = DiagnosticInfo::new();
+    diagnostic_info.additional_info = Some(UAString::from("Info"));
+    diagnostic_info.inner_status_code = Some(StatusCode::BadUnexpectedError);
+    diagnostic_info.inner_diagnostic_info = Some(Box::new(inner));
+    xml_test(diagnostic_info);
+    xml_test(DiagnosticInfo::new());
+}
+
+#[test]
+fn xml_variant() {
+    xml_test(Variant::Empty);
+    xml_test(Variant::Boolean(true));
+    xml_test(Variant::Int64(-100));
+    xml_test(Variant::Double(1.25));
+    xml_test(Variant::from("Hello"));
+    xml_test(Variant::from(Guid::new()));
+    xml_test(Variant::from(NodeId::new_string(1, "Hello")));
+    xml_test(Variant::from(LocalizedText::new("en", "Hello")));
+    xml_test(Variant::from(QualifiedName::new(1, "Hello")));
+    xml_test(Variant::from(DateTime::now()));
+    xml_test(Variant::from(StatusCode::BadNodeIdUnknown));
+    xml_test(Variant::from(DataValue::new(Variant::Int32(1))));
+    xml_test(Variant::XmlElement(XmlElement::from("<Foo xmlns=\"urn:foo\"><Bar>1</Bar></Foo>")));
+    xml_test(Variant::from(vec![1u32, 2, 3]));
+    xml_test(Variant::Array(vec![Variant::from("a"), Variant::from("b")]));
+    xml_test(Variant::Array(vec![]));
+
+    assert_eq!(xml_text(Variant::Int32(5)), "<Test><Value><Int32>5</Int32></Value></Test>");
+    assert_eq!(xml_text(Variant::from(vec![1u32, 2])), "<Test><Value><ListOfUInt32><UInt32>1</UInt32><UInt32>2</UInt32></ListOfUInt32></Value></Test>");
+    assert_eq!(xml_text(Variant::Empty), "<Test />");
+
+    // The bare form without the Value element is only for nodesets
+    let node = parse_xml("<Test><Int32>5</Int32></Test>").unwrap();
+    assert_eq!(Variant::decode_xml(&node).unwrap_err(), BadDecodingError);
+    assert_eq!(Variant::decode_nodeset_xml(&node).unwrap(), Variant::Int32(5));
+}
+
+#[test]
+fn xml_variant_spec_form() {
+    // Variants in a DataValue and in a list of variants, as written by other stacks
+    let xml = r#"<DataValue xmlns="http://opcfoundation.org/UA/2008/02/Types.xsd">
+        <Value>
+          <Value>
+            <ListOfVariant>
+              <Variant><Value><Int32>5</Int32></Value></Variant>
+              <Variant><Value><String>Hello</String></Value></Variant>
+            </ListOfVariant>
+          </Value>
+        </Value>
+        <StatusCode><Code>0</Code></StatusCode>
+      </DataValue>"#;
+    let data_value = DataValue::decode_xml(&parse_xml(xml).unwrap()).unwrap();
+    assert_eq!(data_value.value, Some(Variant::Array(vec![Variant::Int32(5), Variant::from("Hello")])));
+    assert_eq!(data_value.status, Some(StatusCode::Good));
+}
+
+#[test]
+fn xml_variant_multi_dimension_array() {
+    let values = (0..6).map(|i| Variant::Int32(i)).collect();
+    let variant = Variant::new_multi_dimension_array(values, vec![2, 3]);
+    xml_test(variant);
+
+    let node = parse_xml(r#"<Value><Matrix><Dimensions><Int32>2</Int32><Int32>2</Int32></Dimensions><Elements><Int32>1</Int32></Elements></Matrix></Value>"#).unwrap();
+    assert_eq!(Variant::decode_nodeset_xml(&node).unwrap_err(), BadDecodingError);
+}
+
+#[test]
+fn xml_data_value() {
+    let mut data_value = DataValue::new(Variant::Int32(10));
+    data_value.status = Some(StatusCode::UncertainLastUsableValue);
+    data_value.source_picoseconds = Some(100);
+    xml_test(data_value);
+}
+
+#[test]
+fn xml_generated_struct() {
+    let read_value_id = ReadValueId {
+        node_id: NodeId::new_string(1, "v1"),
+        attribute_id: AttributeId::Value as UInt32,
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+    };
+    xml_test(read_value_id.clone());
+
+    let request = ReadRequest {
+        request_header: RequestHeader::new(&NodeId::new(0, 1), &DateTime::now(), 5),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Server,
+        nodes_to_read: Some(vec![read_value_id.clone(), read_value_id]),
+    };
+    xml_test(request.clone());
+
+    let xml = xml_text(request);
+    assert!(xml.contains("<NodesToRead><ReadValueId><NodeId><Identifier>ns=1;s=v1</Identifier></NodeId>"));
+    assert!(xml.contains("<TimestampsToReturn>Server_1</TimestampsToReturn>"));
+}
+
+#[test]
+fn xml_extension_object() {
+    xml_test(ExtensionObject::null());
+
+    // A known binary body is written as the element of its type with the XML encoding id
+    let read_value_id = ReadValueId::from(NodeId::new_string(1, "v1"));
+    let extension_object = ExtensionObject::from_encodable(ObjectId::ReadValueId_Encoding_DefaultBinary, read_value_id);
+    xml_test(extension_object.clone());
+    let xml = xml_text(extension_object);
+    assert!(xml.contains(&format!("<TypeId><Identifier>i={}</Identifier></TypeId>", ObjectId::ReadValueId_Encoding_DefaultXml as u32)));
+    assert!(xml.contains("<Body><ReadValueId xmlns=\"http://opcfoundation.org/UA/2008/02/Types.xsd\">"));
+
+    // An unknown binary body is written as a ByteString
+    xml_test(ExtensionObject {
+        node_id: NodeId::new(1, 100),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![1u8, 2, 3])),
+    });
+
+    // An unknown XML body is kept as XML
+    xml_test(ExtensionObject {
+        node_id: NodeId::new(1, 100),
+        body: ExtensionObjectEncoding::XmlElement(XmlElement::from("<Foo xmlns=\"urn:foo\"><Bar>1</Bar></Foo>")),
+    });
+}
+
+#[test]
+fn xml_nodeset_value() {
+    // A value as it appears in a nodeset, with a prefix for the types namespace
+    let xml = r#"<Value xmlns:uax="http://opcfoundation.org/UA/2008/02/Types.xsd">
+        <uax:ListOfExtensionObject>
+          <uax:ExtensionObject>
+            <uax:TypeId>
+              <uax:Identifier>i=297</uax:Identifier>
+            </uax:TypeId>
+            <uax:Body>
+              <uax:Argument>
+                <uax:Name>SubscriptionId</uax:Name>
+                <uax:DataType>
+                  <uax:Identifier>i=7</uax:Identifier>
+                </uax:DataType>
+                <uax:ValueRank>-1</uax:ValueRank>
+                <uax:ArrayDimensions />
+                <uax:Description>
+                  <uax:Locale>en</uax:Locale>
+                  <uax:Text>The subscription</uax:Text>
+                </uax:Description>
+              </uax:Argument>
+            </uax:Body>
+          </uax:ExtensionObject>
+        </uax:ListOfExtensionObject>
+      </Value>"#;
+    let value = Variant::decode_nodeset_xml(&parse_xml(xml).unwrap()).unwrap();
+    let values = if let Variant::Array(values) = value { values } else { panic!() };
+    assert_eq!(values.len(), 1);
+    let extension_object = if let Variant::ExtensionObject(ref extension_object) = values[0] { extension_object } else { panic!() };
+    assert_eq!(extension_object.node_id, ObjectId::Argument_Encoding_DefaultBinary.into());
+    let argument: service_types::Argument = extension_object.decode_inner().unwrap();
+    assert_eq!(argument.name.as_ref(), "SubscriptionId");
+    assert_eq!(argument.data_type, NodeId::new(0, 7));
+    assert_eq!(argument.value_rank, -1);
+    assert_eq!(argument.array_dimensions, Some(vec![]));
+    assert_eq!(argument.description, LocalizedText::new("en", "The subscription"));
+
+    let xml = r#"<Value><uax:LocalizedText xmlns:uax="http://opcfoundation.org/UA/2008/02/Types.xsd"><uax:Text>Hi</uax:Text></uax:LocalizedText></Value>"#;
+    let value = Variant::decode_nodeset_xml(&parse_xml(xml).unwrap()).unwrap();
+    assert_eq!(value, Variant::from(LocalizedText { locale: UAString::null(), text: UAString::from("Hi") }));
+}
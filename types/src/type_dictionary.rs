@@ -0,0 +1,191 @@
+//! The OPC Binary type dictionaries described in OPC UA Part 3 Annex C.
+//!
+//! A server describes the binary encoding of its custom structured and enumerated types in a
+//! dictionary for each namespace, held as the value of a DataTypeDictionary variable under the OPC
+//! Binary type system. A `TypeDictionary` is parsed from one so that values of types unknown at
+//! compile time can be decoded with a `DataTypeRegistry`.
+
+use basic_types::*;
+use encoding::EncodingResult;
+use status_codes::StatusCode::*;
+use xml::*;
+
+/// The namespace of the OPC Binary schema, which holds the primitive types
+pub const BINARY_SCHEMA_NAMESPACE: &'static str = "http://opcfoundation.org/BinarySchema/";
+
+/// The namespace of the types of OPC UA, which holds the built-in types that are not primitive
+pub const UA_TYPES_NAMESPACE: &'static str = "http://opcfoundation.org/UA/";
+
+/// The name of a type qualified by the namespace of the dictionary that defines it
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeName {
+    pub namespace_uri: String,
+    pub name: String,
+}
+
+impl TypeName {
+    pub fn new(namespace_uri: &str, name: &str) -> TypeName {
+        TypeName {
+            namespace_uri: namespace_uri.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// A field of a structured type
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredField {
+    pub name: String,
+    pub type_name: TypeName,
+    /// The field holding the length of the array, if the field is an array
+    pub length_field: Option<String>,
+    /// The field deciding if the field is encoded, if the field is optional or a union member
+    pub switch_field: Option<String>,
+    /// The value of the switch field for which the field is encoded. Without one the field is
+    /// encoded when the switch field is not 0.
+    pub switch_value: Option<UInt32>,
+    /// The number of bits of an `opc:Bit` field, or the fixed length of an array
+    pub length: Option<UInt32>,
+}
+
+/// A structured type, the fields of which are encoded one after the other
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredType {
+    pub name: String,
+    pub base_type: Option<TypeName>,
+    pub fields: Vec<StructuredField>,
+}
+
+/// An enumerated type and its named values
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumeratedType {
+    pub name: String,
+    pub length_in_bits: UInt32,
+    pub values: Vec<(String, Int32)>,
+}
+
+/// The structured and enumerated types of an OPC Binary type dictionary
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDictionary {
+    pub target_namespace: String,
+    pub structured_types: Vec<StructuredType>,
+    pub enumerated_types: Vec<EnumeratedType>,
+}
+
+impl TypeDictionary {
+    /// Parses the XML of a dictionary. Type names are resolved against the namespace prefixes
+    /// declared in the document, so `tns:Point` and `ua:NodeId` become names in the target
+    /// namespace and the UA namespace. Documentation and opaque types are ignored.
+    pub fn parse(xml: &str) -> EncodingResult<TypeDictionary> {
+        let root = parse_xml(xml)?;
+        if root.name != "TypeDictionary" {
+            error!("Expected a TypeDictionary element but found {}", root.name);
+            return Err(BadDecodingError);
+        }
+        let target_namespace = if let Some(target_namespace) = root.attributes.get("TargetNamespace") {
+            target_namespace.clone()
+        } else {
+            error!("Type dictionary has no TargetNamespace");
+            return Err(BadDecodingError);
+        };
+
+        let mut structured_types = Vec::new();
+        let mut enumerated_types = Vec::new();
+        for child in root.children.iter() {
+            match child.name.as_ref() {
+                "StructuredType" => {
+                    let base_type = if let Some(base_type) = child.attributes.get("BaseType") {
+                        Some(resolve_type_name(child, &target_namespace, base_type))
+                    } else {
+                        None
+                    };
+                    let fields = child.children.iter().filter(|c| c.name == "Field").map(|field| {
+                        let type_name = if let Some(type_name) = field.attributes.get("TypeName") {
+                            type_name
+                        } else {
+                            error!("Field of structured type has no TypeName");
+                            return Err(BadDecodingError);
+                        };
+                        Ok(StructuredField {
+                            name: required_attribute(field, "Name")?,
+                            type_name: resolve_type_name(field, &target_namespace, type_name),
+                            length_field: field.attributes.get("LengthField").cloned(),
+                            switch_field: field.attributes.get("SwitchField").cloned(),
+                            switch_value: optional_number_attribute(field, "SwitchValue")?,
+                            length: optional_number_attribute(field, "Length")?,
+                        })
+                    }).collect::<EncodingResult<Vec<StructuredField>>>()?;
+                    structured_types.push(StructuredType {
+                        name: required_attribute(child, "Name")?,
+                        base_type,
+                        fields,
+                    });
+                }
+                "EnumeratedType" => {
+                    let values = child.children.iter().filter(|c| c.name == "EnumeratedValue").map(|value| {
+                        let name = required_attribute(value, "Name")?;
+                        let value = optional_number_attribute(value, "Value")?.unwrap_or(0);
+                        Ok((name, value))
+                    }).collect::<EncodingResult<Vec<(String, Int32)>>>()?;
+                    enumerated_types.push(EnumeratedType {
+                        name: required_attribute(child, "Name")?,
+                        length_in_bits: optional_number_attribute(child, "LengthInBits")?.unwrap_or(32),
+                        values,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(TypeDictionary {
+            target_namespace,
+            structured_types,
+            enumerated_types,
+        })
+    }
+
+    /// Finds a structured type by name
+    pub fn find_structured_type(&self, name: &str) -> Option<&StructuredType> {
+        self.structured_types.iter().find(|t| t.name == name)
+    }
+
+    /// Finds an enumerated type by name
+    pub fn find_enumerated_type(&self, name: &str) -> Option<&EnumeratedType> {
+        self.enumerated_types.iter().find(|t| t.name == name)
+    }
+}
+
+fn required_attribute(node: &XmlNode, name: &str) -> EncodingResult<String> {
+    if let Some(value) = node.attributes.get(name) {
+        Ok(value.clone())
+    } else {
+        error!("{} element has no {} attribute", node.name, name);
+        Err(BadDecodingError)
+    }
+}
+
+fn optional_number_attribute<T>(node: &XmlNode, name: &str) -> EncodingResult<Option<T>> where T: ::std::str::FromStr {
+    if let Some(value) = node.attributes.get(name) {
+        value.trim().parse::<T>().map(Some).map_err(|_| {
+            error!("{} attribute of {} element is not a number, {}", name, node.name, value);
+            BadDecodingError
+        })
+    } else {
+        Ok(None)
+    }
+}
+
+/// Resolves a type name such as "tns:Point" to the namespace its prefix is bound to. A name
+/// without a prefix, or with a prefix that is not declared, is in the target namespace.
+fn resolve_type_name(node: &XmlNode, target_namespace: &str, type_name: &str) -> TypeName {
+    let (prefix, name) = if let Some(idx) = type_name.find(':') {
+        (&type_name[..idx], &type_name[idx + 1..])
+    } else {
+        ("", type_name)
+    };
+    let namespace_uri = match node.namespaces.as_ref().and_then(|namespaces| namespaces.get(prefix)) {
+        Some(namespace_uri) if !namespace_uri.is_empty() => namespace_uri,
+        _ => target_namespace
+    };
+    TypeName::new(namespace_uri, name)
+}
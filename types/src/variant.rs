@@ -8,6 +8,8 @@ use data_value::DataValue;
 use date_time::DateTime;
 use encoding::*;
 use guid::Guid;
+use json::*;
+use xml::*;
 use node_id::{ExpandedNodeId, NodeId};
 use node_ids::DataTypeId;
 use status_codes::StatusCode;
@@ -489,6 +491,100 @@ impl BinaryEncoder<Variant> for Variant {
     }
 }
 
+/// Variant encoded as an object holding the built-in Type of the value, its Body and the
+/// Dimensions of a multi-dimensional array. Non-reversible encoding is just the body with
+/// multi-dimensional arrays as nested arrays.
+impl JsonEncoder<Variant> for Variant {
+    fn encode_json(&self, reversible: bool) -> JsonValue {
+        let body = match *self {
+            Variant::Empty => {
+                return JsonValue::Null;
+            }
+            Variant::Array(ref values) => {
+                JsonValue::Array(values.iter().map(|v| v.encode_json_value(reversible)).collect())
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                if !Self::is_valid_json_dimensions(mda) {
+                    error!("Cannot encode variant array with dimensions {:?} and {} values", mda.dimensions, mda.values.len());
+                    return JsonValue::Null;
+                }
+                if reversible {
+                    JsonValue::Array(mda.values.iter().map(|v| v.encode_json_value(reversible)).collect())
+                } else {
+                    Self::encode_json_nested_array(&mda.values, &mda.dimensions)
+                }
+            }
+            _ => self.encode_json_value(reversible)
+        };
+        if reversible {
+            let mut map = JsonMap::new();
+            let type_id = self.get_encoding_mask() & !(ARRAY_VALUES_BIT | ARRAY_DIMENSIONS_BIT);
+            map.insert("Type".to_string(), JsonValue::from(type_id));
+            if !body.is_null() {
+                map.insert("Body".to_string(), body);
+            }
+            if let Variant::MultiDimensionArray(ref mda) = *self {
+                map.insert("Dimensions".to_string(), json_array(&mda.dimensions, reversible));
+            }
+            JsonValue::Object(map)
+        } else {
+            body
+        }
+    }
+
+    fn decode_json(value: &JsonValue) -> EncodingResult<Self> {
+        if value.is_null() {
+            return Ok(Variant::Empty);
+        }
+        check_json_object(value)?;
+        let type_id: Byte = read_json_field(value, "Type")?;
+        let dimensions: Option<Vec<Int32>> = read_json_array_field(value, "Dimensions")?;
+        match value.get("Body") {
+            Some(&JsonValue::Array(ref values)) => {
+                let values = values.iter().map(|v| Self::decode_json_value(type_id, v)).collect::<EncodingResult<Vec<Variant>>>()?;
+                if let Some(dimensions) = dimensions {
+                    let mda = MultiDimensionArray::new(values, dimensions);
+                    if !mda.is_valid_dimensions() {
+                        error!("Variant array dimensions do not match the number of values");
+                        Err(StatusCode::BadDecodingError)
+                    } else {
+                        Ok(Variant::from(mda))
+                    }
+                } else {
+                    Ok(Variant::Array(values))
+                }
+            }
+            Some(body) => Self::decode_json_value(type_id, body),
+            None => Self::decode_json_value(type_id, &JsonValue::Null)
+        }
+    }
+}
+
+
+/// Variant encoded as the `<Value>` element that `Opc.Ua.Types.xsd` defines for Variant fields of
+/// structures and DataValue, which holds the value in its bare form. A missing `<Value>` element is
+/// an empty variant.
+impl XmlEncoder<Variant> for Variant {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        if *self != Variant::Empty {
+            let mut value = XmlNode::new("Value");
+            self.encode_nodeset_xml(&mut value);
+            node.children.push(value);
+        }
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        if let Some(value) = node.get_child("Value") {
+            Self::decode_nodeset_xml(value)
+        } else if node.children.is_empty() {
+            Ok(Variant::Empty)
+        } else {
+            error!("Variant has no Value element");
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
 impl Default for Variant {
     fn default() -> Self {
         Variant::Empty
@@ -524,6 +620,42 @@ impl Variant {
         value.into()
     }
 
+    /// Writes the variant in its bare form, as a child element named after the type of the
+    /// value, e.g. `<Int32>`. Arrays are a `<ListOfInt32>` element and multi-dimensional arrays
+    /// are a `<Matrix>` element with the Dimensions and the Elements. This is the form of the
+    /// `<Value>` of a `UAVariable` in a NodeSet.
+    pub fn encode_nodeset_xml(&self, node: &mut XmlNode) {
+        match *self {
+            Variant::Empty => {}
+            Variant::Array(ref values) => {
+                let type_name = values.first().map_or("Variant", |v| v.xml_type_name());
+                let mut list = XmlNode::new(&format!("ListOf{}", type_name));
+                values.iter().for_each(|v| v.encode_xml_value(&mut list));
+                node.children.push(list);
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                let mut matrix = XmlNode::new("Matrix");
+                let mut dimensions = XmlNode::new("Dimensions");
+                mda.dimensions.iter().for_each(|d| write_xml_field(&mut dimensions, "Int32", d));
+                let mut elements = XmlNode::new("Elements");
+                mda.values.iter().for_each(|v| v.encode_xml_value(&mut elements));
+                matrix.children.push(dimensions);
+                matrix.children.push(elements);
+                node.children.push(matrix);
+            }
+            _ => self.encode_xml_value(node)
+        }
+    }
+
+    /// Reads the variant from its bare form, see `encode_nodeset_xml()`
+    pub fn decode_nodeset_xml(node: &XmlNode) -> EncodingResult<Self> {
+        if let Some(child) = node.children.first() {
+            Self::decode_xml_value(child)
+        } else {
+            Ok(Variant::Empty)
+        }
+    }
+
     /// Test the flag (convenience method)
     pub fn test_encoding_flag(encoding_mask: u8, data_type_id: DataTypeId) -> bool {
         encoding_mask == data_type_id as u8
@@ -598,6 +730,240 @@ impl Variant {
         }
     }
 
+    /// Encodes just the variant value as JSON
+    fn encode_json_value(&self, reversible: bool) -> JsonValue {
+        match *self {
+            Variant::Boolean(ref value) => value.encode_json(reversible),
+            Variant::SByte(ref value) => value.encode_json(reversible),
+            Variant::Byte(ref value) => value.encode_json(reversible),
+            Variant::Int16(ref value) => value.encode_json(reversible),
+            Variant::UInt16(ref value) => value.encode_json(reversible),
+            Variant::Int32(ref value) => value.encode_json(reversible),
+            Variant::UInt32(ref value) => value.encode_json(reversible),
+            Variant::Int64(ref value) => value.encode_json(reversible),
+            Variant::UInt64(ref value) => value.encode_json(reversible),
+            Variant::Float(ref value) => value.encode_json(reversible),
+            Variant::Double(ref value) => value.encode_json(reversible),
+            Variant::String(ref value) => value.encode_json(reversible),
+            Variant::DateTime(ref value) => value.encode_json(reversible),
+            Variant::Guid(ref value) => value.encode_json(reversible),
+            Variant::ByteString(ref value) => value.encode_json(reversible),
+            Variant::XmlElement(ref value) => value.encode_json(reversible),
+            Variant::NodeId(ref value) => value.encode_json(reversible),
+            Variant::ExpandedNodeId(ref value) => value.encode_json(reversible),
+            Variant::StatusCode(ref value) => value.encode_json(reversible),
+            Variant::QualifiedName(ref value) => value.encode_json(reversible),
+            Variant::LocalizedText(ref value) => value.encode_json(reversible),
+            Variant::ExtensionObject(ref value) => value.encode_json(reversible),
+            Variant::DataValue(ref value) => value.encode_json(reversible),
+            _ => {
+                warn!("Cannot encode this variant value type (probably nested array)");
+                JsonValue::Null
+            }
+        }
+    }
+
+    /// Tests that the dimensions of an array can be encoded as JSON, i.e. none is negative and
+    /// they match the number of values. A zero dimension is allowed for an array with no values.
+    fn is_valid_json_dimensions(mda: &MultiDimensionArray) -> bool {
+        let mut length: usize = 1;
+        for d in &mda.dimensions {
+            if *d < 0 {
+                return false;
+            }
+            length = if let Some(length) = length.checked_mul(*d as usize) { length } else { return false; };
+        }
+        length == mda.values.len()
+    }
+
+    /// Encodes the values of a multi-dimensional array as nested JSON arrays, higher rank first.
+    /// The dimensions must be valid. An array with a zero dimension is an empty array.
+    fn encode_json_nested_array(values: &[Variant], dimensions: &[Int32]) -> JsonValue {
+        if values.is_empty() {
+            JsonValue::Array(Vec::new())
+        } else if dimensions.len() <= 1 {
+            JsonValue::Array(values.iter().map(|v| v.encode_json_value(false)).collect())
+        } else {
+            let chunk_size = dimensions[1..].iter().fold(1, |size, d| size * (*d as usize));
+            JsonValue::Array(values.chunks(chunk_size).map(|chunk| Self::encode_json_nested_array(chunk, &dimensions[1..])).collect())
+        }
+    }
+
+    /// Decodes just the variant value from JSON
+    fn decode_json_value(type_id: Byte, value: &JsonValue) -> EncodingResult<Self> {
+        let result = if type_id == 0 {
+            Variant::Empty
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Boolean) {
+            Self::new(Boolean::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::SByte) {
+            Self::new(SByte::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Byte) {
+            Self::new(Byte::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Int16) {
+            Self::new(Int16::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::UInt16) {
+            Self::new(UInt16::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Int32) {
+            Self::new(Int32::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::UInt32) {
+            Self::new(UInt32::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Int64) {
+            Self::new(Int64::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::UInt64) {
+            Self::new(UInt64::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Float) {
+            Self::new(Float::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Double) {
+            Self::new(Double::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::String) {
+            Self::new(UAString::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::DateTime) {
+            Self::new(DateTime::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::Guid) {
+            Self::new(Guid::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::ByteString) {
+            Self::new(ByteString::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::XmlElement) {
+            Variant::XmlElement(XmlElement::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::NodeId) {
+            Self::new(NodeId::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::ExpandedNodeId) {
+            Self::new(ExpandedNodeId::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::StatusCode) {
+            Self::new(StatusCode::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::QualifiedName) {
+            Self::new(QualifiedName::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::LocalizedText) {
+            Self::new(LocalizedText::decode_json(value)?)
+        } else if type_id == 22 {
+            Self::new(ExtensionObject::decode_json(value)?)
+        } else if Self::test_encoding_flag(type_id, DataTypeId::DataValue) {
+            Self::new(DataValue::decode_json(value)?)
+        } else {
+            error!("Variant type {} cannot be decoded from JSON", type_id);
+            return Err(StatusCode::BadDecodingError);
+        };
+        Ok(result)
+    }
+
+    /// The name of the XML element of the value. Values that are not a single built-in type
+    /// are a nested Variant element.
+    fn xml_type_name(&self) -> &'static str {
+        match *self {
+            Variant::Boolean(_) => "Boolean",
+            Variant::SByte(_) => "SByte",
+            Variant::Byte(_) => "Byte",
+            Variant::Int16(_) => "Int16",
+            Variant::UInt16(_) => "UInt16",
+            Variant::Int32(_) => "Int32",
+            Variant::UInt32(_) => "UInt32",
+            Variant::Int64(_) => "Int64",
+            Variant::UInt64(_) => "UInt64",
+            Variant::Float(_) => "Float",
+            Variant::Double(_) => "Double",
+            Variant::String(_) => "String",
+            Variant::DateTime(_) => "DateTime",
+            Variant::Guid(_) => "Guid",
+            Variant::ByteString(_) => "ByteString",
+            Variant::XmlElement(_) => "XmlElement",
+            Variant::NodeId(_) => "NodeId",
+            Variant::ExpandedNodeId(_) => "ExpandedNodeId",
+            Variant::StatusCode(_) => "StatusCode",
+            Variant::QualifiedName(_) => "QualifiedName",
+            Variant::LocalizedText(_) => "LocalizedText",
+            Variant::ExtensionObject(_) => "ExtensionObject",
+            Variant::DataValue(_) => "DataValue",
+            Variant::Empty | Variant::Array(_) | Variant::MultiDimensionArray(_) => "Variant",
+        }
+    }
+
+    /// Encodes just the variant value as a child element named after its type
+    fn encode_xml_value(&self, node: &mut XmlNode) {
+        let mut child = XmlNode::new(self.xml_type_name());
+        match *self {
+            Variant::Boolean(ref value) => value.encode_xml(&mut child),
+            Variant::SByte(ref value) => value.encode_xml(&mut child),
+            Variant::Byte(ref value) => value.encode_xml(&mut child),
+            Variant::Int16(ref value) => value.encode_xml(&mut child),
+            Variant::UInt16(ref value) => value.encode_xml(&mut child),
+            Variant::Int32(ref value) => value.encode_xml(&mut child),
+            Variant::UInt32(ref value) => value.encode_xml(&mut child),
+            Variant::Int64(ref value) => value.encode_xml(&mut child),
+            Variant::UInt64(ref value) => value.encode_xml(&mut child),
+            Variant::Float(ref value) => value.encode_xml(&mut child),
+            Variant::Double(ref value) => value.encode_xml(&mut child),
+            Variant::String(ref value) => value.encode_xml(&mut child),
+            Variant::DateTime(ref value) => value.encode_xml(&mut child),
+            Variant::Guid(ref value) => value.encode_xml(&mut child),
+            Variant::ByteString(ref value) => value.encode_xml(&mut child),
+            Variant::XmlElement(ref value) => write_xml_element(&mut child, value),
+            Variant::NodeId(ref value) => value.encode_xml(&mut child),
+            Variant::ExpandedNodeId(ref value) => value.encode_xml(&mut child),
+            Variant::StatusCode(ref value) => value.encode_xml(&mut child),
+            Variant::QualifiedName(ref value) => value.encode_xml(&mut child),
+            Variant::LocalizedText(ref value) => value.encode_xml(&mut child),
+            Variant::ExtensionObject(ref value) => value.encode_xml(&mut child),
+            Variant::DataValue(ref value) => value.encode_xml(&mut child),
+            Variant::Empty | Variant::Array(_) | Variant::MultiDimensionArray(_) => self.encode_xml(&mut child),
+        }
+        node.children.push(child);
+    }
+
+    /// Decodes just the variant value from an element named after its type
+    fn decode_xml_value(node: &XmlNode) -> EncodingResult<Self> {
+        let result = match node.name.as_ref() {
+            "Boolean" => Self::new(Boolean::decode_xml(node)?),
+            "SByte" => Self::new(SByte::decode_xml(node)?),
+            "Byte" => Self::new(Byte::decode_xml(node)?),
+            "Int16" => Self::new(Int16::decode_xml(node)?),
+            "UInt16" => Self::new(UInt16::decode_xml(node)?),
+            "Int32" => Self::new(Int32::decode_xml(node)?),
+            "UInt32" => Self::new(UInt32::decode_xml(node)?),
+            "Int64" => Self::new(Int64::decode_xml(node)?),
+            "UInt64" => Self::new(UInt64::decode_xml(node)?),
+            "Float" => Self::new(Float::decode_xml(node)?),
+            "Double" => Self::new(Double::decode_xml(node)?),
+            "String" => Self::new(UAString::decode_xml(node)?),
+            "DateTime" => Self::new(DateTime::decode_xml(node)?),
+            "Guid" => Self::new(Guid::decode_xml(node)?),
+            "ByteString" => Self::new(ByteString::decode_xml(node)?),
+            "XmlElement" => Variant::XmlElement(read_xml_element(node)?),
+            "NodeId" => Self::new(NodeId::decode_xml(node)?),
+            "ExpandedNodeId" => Self::new(ExpandedNodeId::decode_xml(node)?),
+            "StatusCode" => Self::new(StatusCode::decode_xml(node)?),
+            "QualifiedName" => Self::new(QualifiedName::decode_xml(node)?),
+            "LocalizedText" => Self::new(LocalizedText::decode_xml(node)?),
+            "ExtensionObject" => Self::new(ExtensionObject::decode_xml(node)?),
+            "DataValue" => Self::new(DataValue::decode_xml(node)?),
+            "Variant" => Self::decode_xml(node)?,
+            "Matrix" => {
+                let dimensions: Option<Vec<Int32>> = read_xml_array_field(node, "Dimensions")?;
+                // Older schemas call the elements Value
+                let elements = node.get_child("Elements").or_else(|| node.get_child("Value"));
+                let values = if let Some(elements) = elements {
+                    elements.children.iter().map(|v| Self::decode_xml_value(v)).collect::<EncodingResult<Vec<Variant>>>()?
+                } else {
+                    Vec::new()
+                };
+                let mda = MultiDimensionArray::new(values, dimensions.unwrap_or_default());
+                if !mda.is_valid_dimensions() {
+                    error!("Variant matrix dimensions do not match the number of values");
+                    return Err(StatusCode::BadDecodingError);
+                }
+                Variant::from(mda)
+            }
+            name if name.starts_with("ListOf") => {
+                let values = node.children.iter().map(|v| Self::decode_xml_value(v)).collect::<EncodingResult<Vec<Variant>>>()?;
+                Variant::Array(values)
+            }
+            name => {
+                error!("Variant type {} cannot be decoded from XML", name);
+                return Err(StatusCode::BadDecodingError);
+            }
+        };
+        Ok(result)
+    }
+
     /// Reads just the variant value from the stream
     fn decode_variant_value<S: Read>(stream: &mut S, encoding_mask: Byte) -> EncodingResult<Self> {
         let result = if encoding_mask == 0 {
@@ -701,6 +1067,78 @@ impl Variant {
         }
     }
 
+    /// Converts the variant to another built-in type where Part 4 allows an implicit conversion,
+    /// i.e. Boolean and integer values to any numeric type providing integers are within the range
+    /// of the target type, and Float to Double. Arrays are converted element by element.
+    ///
+    /// Returns `BadOutOfRange` if a value does not fit the target type, or `BadTypeMismatch` if
+    /// there is no implicit conversion.
+    pub fn convert(&self, target_type: VariantTypeId) -> Result<Variant, StatusCode> {
+        if self.type_id() == target_type {
+            return Ok(self.clone());
+        }
+        match *self {
+            Variant::Empty => {
+                return Ok(Variant::Empty);
+            }
+            Variant::Array(ref values) => {
+                let values = values.iter().map(|v| v.convert(target_type)).collect::<Result<Vec<Variant>, StatusCode>>()?;
+                return Ok(Variant::Array(values));
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                let values = mda.values.iter().map(|v| v.convert(target_type)).collect::<Result<Vec<Variant>, StatusCode>>()?;
+                return Ok(Variant::new_multi_dimension_array(values, mda.dimensions.clone()));
+            }
+            Variant::Float(v) => {
+                return if target_type == VariantTypeId::Double {
+                    Ok(Variant::Double(v as Double))
+                } else {
+                    Err(StatusCode::BadTypeMismatch)
+                };
+            }
+            _ => {}
+        }
+
+        let value: i128 = match *self {
+            Variant::Boolean(v) => if v { 1 } else { 0 },
+            Variant::SByte(v) => v as i128,
+            Variant::Byte(v) => v as i128,
+            Variant::Int16(v) => v as i128,
+            Variant::UInt16(v) => v as i128,
+            Variant::Int32(v) => v as i128,
+            Variant::UInt32(v) => v as i128,
+            Variant::Int64(v) => v as i128,
+            Variant::UInt64(v) => v as i128,
+            _ => {
+                return Err(StatusCode::BadTypeMismatch);
+            }
+        };
+
+        macro_rules! in_range {
+            ( $t: ident, $variant_type: ident ) => {
+                if value >= $t::min_value() as i128 && value <= $t::max_value() as i128 {
+                    Ok(Variant::$variant_type(value as $t))
+                } else {
+                    Err(StatusCode::BadOutOfRange)
+                }
+            }
+        }
+
+        match target_type {
+            VariantTypeId::SByte => in_range!(i8, SByte),
+            VariantTypeId::Byte => in_range!(u8, Byte),
+            VariantTypeId::Int16 => in_range!(i16, Int16),
+            VariantTypeId::UInt16 => in_range!(u16, UInt16),
+            VariantTypeId::Int32 => in_range!(i32, Int32),
+            VariantTypeId::UInt32 => in_range!(u32, UInt32),
+            VariantTypeId::Int64 => in_range!(i64, Int64),
+            VariantTypeId::UInt64 => in_range!(u64, UInt64),
+            VariantTypeId::Float => Ok(Variant::Float(value as Float)),
+            VariantTypeId::Double => Ok(Variant::Double(value as Double)),
+            _ => Err(StatusCode::BadTypeMismatch)
+        }
+    }
+
     /// Test if the variant holds an array
     pub fn is_array(&self) -> bool {
         match *self {
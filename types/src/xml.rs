@@ -0,0 +1,296 @@
+//! The XML encoding described in OPC UA Part 6 section 5.3 and defined by the schema
+//! `schemas/1.0.3/Opc.Ua.Types.xsd`.
+//!
+//! Types implement `XmlEncoder` to write themselves as the content of an element and to read
+//! themselves back from one. The element itself is named by the caller, e.g. a structure names it
+//! after the field. Elements are matched by their local name, so documents from other stacks
+//! decode regardless of the prefix they bind to the types namespace.
+
+use std::io::Cursor;
+use std::str::FromStr;
+
+use xmltree::{EmitterConfig, Namespace};
+
+use encoding::*;
+use basic_types::*;
+use byte_string::ByteString;
+use data_types::{MessageSecurityMode, MonitoringMode};
+use service_types::*;
+use status_codes::StatusCode;
+use status_codes::StatusCode::*;
+use string::{UAString, XmlElement};
+
+pub use xmltree::Element as XmlNode;
+
+/// The namespace of the elements in `Opc.Ua.Types.xsd`
+pub const TYPES_NAMESPACE: &'static str = "http://opcfoundation.org/UA/2008/02/Types.xsd";
+
+/// OPC UA XML Encoding interface. Anything that encodes to XML must implement this.
+pub trait XmlEncoder<T> {
+    /// Writes the instance as the text or child elements of the element.
+    fn encode_xml(&self, node: &mut XmlNode);
+    /// Reads an instance from the text or child elements of the element. An empty element
+    /// decodes to the default value of the type.
+    fn decode_xml(node: &XmlNode) -> EncodingResult<T>;
+}
+
+/// Writes a field as a child element
+pub fn write_xml_field<T>(node: &mut XmlNode, name: &str, value: &T) where T: XmlEncoder<T> {
+    let mut child = XmlNode::new(name);
+    value.encode_xml(&mut child);
+    node.children.push(child);
+}
+
+/// Writes an optional field as a child element. None is omitted.
+pub fn write_xml_optional_field<T>(node: &mut XmlNode, name: &str, value: &Option<T>) where T: XmlEncoder<T> {
+    if let Some(ref value) = *value {
+        write_xml_field(node, name, value);
+    }
+}
+
+/// Writes an array field as a child element holding an element per value, each named after
+/// the type of the values. A null array is omitted.
+pub fn write_xml_array_field<T>(node: &mut XmlNode, name: &str, type_name: &str, values: &Option<Vec<T>>) where T: XmlEncoder<T> {
+    if let Some(ref values) = *values {
+        let mut child = XmlNode::new(name);
+        values.iter().for_each(|v| write_xml_field(&mut child, type_name, v));
+        node.children.push(child);
+    }
+}
+
+/// Reads a field from a child element. A missing element is decoded from an empty element.
+pub fn read_xml_field<T>(node: &XmlNode, name: &str) -> EncodingResult<T> where T: XmlEncoder<T> {
+    if let Some(child) = node.get_child(name) {
+        T::decode_xml(child)
+    } else {
+        T::decode_xml(&XmlNode::new(name))
+    }
+}
+
+/// Reads an optional field from a child element. A missing element is None.
+pub fn read_xml_optional_field<T>(node: &XmlNode, name: &str) -> EncodingResult<Option<T>> where T: XmlEncoder<T> {
+    if let Some(child) = node.get_child(name) {
+        Ok(Some(T::decode_xml(child)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads an array field from a child element. A missing element is a null array.
+pub fn read_xml_array_field<T>(node: &XmlNode, name: &str) -> EncodingResult<Option<Vec<T>>> where T: XmlEncoder<T> {
+    if let Some(child) = node.get_child(name) {
+        Ok(Some(read_xml_array(child)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Decodes every child element of the element
+pub fn read_xml_array<T>(node: &XmlNode) -> EncodingResult<Vec<T>> where T: XmlEncoder<T> {
+    node.children.iter().map(|v| T::decode_xml(v)).collect()
+}
+
+/// Returns the trimmed text of the element, which is empty if there is none
+pub fn read_xml_text(node: &XmlNode) -> &str {
+    if let Some(ref text) = node.text { text.trim() } else { "" }
+}
+
+/// Creates an element in the types namespace to be the root of a document
+pub fn new_xml_root(name: &str) -> XmlNode {
+    let mut namespaces = Namespace::empty();
+    let _ = namespaces.put("", TYPES_NAMESPACE);
+    let mut node = XmlNode::new(name);
+    node.namespace = Some(TYPES_NAMESPACE.to_string());
+    node.namespaces = Some(namespaces);
+    node
+}
+
+/// Parses an element from a string
+pub fn parse_xml(xml: &str) -> EncodingResult<XmlNode> {
+    XmlNode::parse(xml.as_bytes()).map_err(|err| {
+        error!("Cannot parse XML, error = {:?}", err);
+        BadDecodingError
+    })
+}
+
+/// Writes an element to a string without an XML declaration
+pub fn xml_to_string(node: &XmlNode) -> EncodingResult<String> {
+    let mut buffer = Vec::new();
+    node.write_with_config(&mut buffer, EmitterConfig::new()).map_err(|err| {
+        error!("Cannot write XML, error = {:?}", err);
+        BadEncodingError
+    })?;
+    let xml = String::from_utf8(buffer).map_err(|_| BadEncodingError)?;
+    // The writer always starts the document with a declaration
+    if xml.starts_with("<?xml") {
+        if let Some(end) = xml.find("?>") {
+            return Ok(xml[end + 2..].to_string());
+        }
+    }
+    Ok(xml)
+}
+
+/// Writes an `XmlElement`, i.e. a string of XML, as the child of the element. A string that is not
+/// well formed XML is written as text.
+pub fn write_xml_element(node: &mut XmlNode, value: &XmlElement) {
+    if !value.is_null() {
+        if let Ok(child) = parse_xml(value.as_ref()) {
+            node.children.push(child);
+        } else {
+            node.text = value.value.clone();
+        }
+    }
+}
+
+/// Reads the child of the element as an `XmlElement`
+pub fn read_xml_element(node: &XmlNode) -> EncodingResult<XmlElement> {
+    if let Some(child) = node.children.first() {
+        Ok(XmlElement::from(xml_to_string(child)?))
+    } else {
+        UAString::decode_xml(node)
+    }
+}
+
+/// Decodes the binary body of an extension object and encodes it as the XML element of its type
+pub fn binary_body_to_xml<T>(body: &ByteString, type_name: &str) -> EncodingResult<XmlNode> where T: BinaryEncoder<T> + XmlEncoder<T> {
+    let mut stream = Cursor::new(body.as_ref());
+    let value = T::decode(&mut stream)?;
+    let mut node = new_xml_root(type_name);
+    value.encode_xml(&mut node);
+    Ok(node)
+}
+
+/// Decodes the XML element of an extension object body and encodes it as binary
+pub fn xml_body_to_binary<T>(body: &XmlNode) -> EncodingResult<ByteString> where T: BinaryEncoder<T> + XmlEncoder<T> {
+    let value = T::decode_xml(body)?;
+    let mut stream = Cursor::new(vec![0u8; value.byte_len()]);
+    let _ = value.encode(&mut stream)?;
+    Ok(ByteString::from(stream.into_inner()))
+}
+
+fn parse_xml_text<T>(node: &XmlNode, type_name: &str) -> EncodingResult<T> where T: FromStr + Default {
+    let text = read_xml_text(node);
+    if text.is_empty() {
+        Ok(T::default())
+    } else {
+        text.parse::<T>().map_err(|_| {
+            error!("Expected {} in element {}, got {}", type_name, node.name, text);
+            BadDecodingError
+        })
+    }
+}
+
+impl XmlEncoder<Boolean> for Boolean {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        node.text = Some(if *self { "true" } else { "false" }.to_string());
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        match read_xml_text(node) {
+            "" | "false" | "0" => Ok(false),
+            "true" | "1" => Ok(true),
+            text => {
+                error!("Expected a boolean in element {}, got {}", node.name, text);
+                Err(BadDecodingError)
+            }
+        }
+    }
+}
+
+/// Integers are their decimal text
+macro_rules! xml_integer_impl {
+    ( $( $t: ident ),* ) => {
+        $(
+        impl XmlEncoder<$t> for $t {
+            fn encode_xml(&self, node: &mut XmlNode) {
+                node.text = Some(self.to_string());
+            }
+
+            fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+                parse_xml_text(node, stringify!($t))
+            }
+        }
+        )*
+    }
+}
+
+xml_integer_impl!(SByte, Byte, Int16, UInt16, Int32, UInt32, Int64, UInt64);
+
+/// Floating point numbers use the xs:double names for infinity and NaN
+macro_rules! xml_float_impl {
+    ( $t: ident, $m: ident ) => {
+        impl XmlEncoder<$t> for $t {
+            fn encode_xml(&self, node: &mut XmlNode) {
+                let text = if self.is_nan() {
+                    "NaN".to_string()
+                } else if self.is_infinite() {
+                    if *self > 0.0 { "INF" } else { "-INF" }.to_string()
+                } else {
+                    self.to_string()
+                };
+                node.text = Some(text);
+            }
+
+            fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+                match read_xml_text(node) {
+                    "NaN" => Ok(::std::$m::NAN),
+                    "INF" => Ok(::std::$m::INFINITY),
+                    "-INF" => Ok(::std::$m::NEG_INFINITY),
+                    _ => parse_xml_text(node, stringify!($t))
+                }
+            }
+        }
+    }
+}
+
+xml_float_impl!(Float, f32);
+xml_float_impl!(Double, f64);
+
+// StatusCode is in a generated file so its implementation lives here
+
+impl XmlEncoder<StatusCode> for StatusCode {
+    fn encode_xml(&self, node: &mut XmlNode) {
+        write_xml_field(node, "Code", &(*self as UInt32));
+    }
+
+    fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+        let code: UInt32 = read_xml_field(node, "Code")?;
+        StatusCode::from_u32(code).map_err(|_| {
+            error!("Status code {} is unknown", code);
+            BadDecodingError
+        })
+    }
+}
+
+/// Enumerations are "Name_Value" strings. Decoding only uses the value and goes through the
+/// binary decoder so the same values are accepted and rejected.
+macro_rules! xml_enum_impl {
+    ( $( $t: ident ),* ) => {
+        $(
+        impl XmlEncoder<$t> for $t {
+            fn encode_xml(&self, node: &mut XmlNode) {
+                node.text = Some(format!("{:?}_{}", self, *self as Int32));
+            }
+
+            fn decode_xml(node: &XmlNode) -> EncodingResult<Self> {
+                let text = read_xml_text(node);
+                let value = if text.is_empty() {
+                    0
+                } else {
+                    text.rsplit('_').next().unwrap().parse::<Int32>().map_err(|_| {
+                        error!("Expected an enumeration in element {}, got {}", node.name, text);
+                        BadDecodingError
+                    })?
+                };
+                let mut stream = Cursor::new(vec![0u8; 4]);
+                let _ = value.encode(&mut stream)?;
+                stream.set_position(0);
+                $t::decode(&mut stream)
+            }
+        }
+        )*
+    }
+}
+
+xml_enum_impl!(MessageSecurityMode, MonitoringMode, UserTokenType, ApplicationType, TimestampsToReturn,
+    NodeClass, DataChangeTrigger, FilterOperator, BrowseDirection, SecurityTokenRequestType, ServerState);